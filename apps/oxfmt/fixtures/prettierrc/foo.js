@@ -0,0 +1,4 @@
+const a = {b:1,c:"x"}
+if (a) {
+foo()
+}
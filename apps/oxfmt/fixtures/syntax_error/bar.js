@@ -0,0 +1 @@
+const b = 1;
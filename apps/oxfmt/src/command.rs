@@ -0,0 +1,168 @@
+use std::{ffi::OsString, path::PathBuf};
+
+use bpaf::Bpaf;
+
+pub const VERSION: &str = match option_env!("OXC_VERSION") {
+    Some(v) => v,
+    None => "dev",
+};
+
+#[derive(Debug, Clone, Bpaf)]
+#[bpaf(options, version(VERSION))]
+pub struct FormatCommand {
+    #[bpaf(external)]
+    pub output_options: OutputOptions,
+
+    #[bpaf(external)]
+    pub config_options: ConfigOptions,
+
+    #[bpaf(external)]
+    pub ignore_options: IgnoreOptions,
+
+    #[bpaf(external)]
+    pub misc_options: MiscOptions,
+
+    /// Single file, single path or list of paths.
+    /// Formats the current working directory if no path is provided.
+    #[bpaf(positional("PATH"), many, guard(validate_paths, PATHS_ERROR_MESSAGE))]
+    pub paths: Vec<PathBuf>,
+}
+
+impl FormatCommand {
+    pub fn handle_threads(&self) {
+        Self::set_rayon_threads(self.misc_options.threads);
+    }
+
+    fn set_rayon_threads(threads: Option<usize>) {
+        if let Some(threads) = threads {
+            rayon::ThreadPoolBuilder::new().num_threads(threads).build_global().unwrap();
+        }
+    }
+}
+
+/// Output
+///
+/// Without any of these options, the formatted code is printed to stdout.
+#[derive(Debug, Clone, Bpaf)]
+pub struct OutputOptions {
+    /// Check if the files are formatted, and print the paths of the files which are not.
+    /// Exits with a non-zero exit code if any file is not formatted.
+    #[bpaf(short, long, switch)]
+    pub check: bool,
+
+    /// Print the paths of the files which are not formatted.
+    /// Exits with a non-zero exit code if any file is not formatted.
+    #[bpaf(short, long, switch)]
+    pub list_different: bool,
+
+    /// Format the files in place.
+    #[bpaf(short, long, switch)]
+    pub write: bool,
+}
+
+/// Config
+#[derive(Debug, Clone, Bpaf)]
+pub struct ConfigOptions {
+    /// Path to a Prettier configuration file.
+    ///
+    /// If not provided, the nearest `.prettierrc`, `.prettierrc.json`, `.prettierrc.yaml`,
+    /// `.prettierrc.yml` or `package.json` with a `prettier` key is used for each file.
+    #[bpaf(argument("./.prettierrc"), hide_usage)]
+    pub config: Option<PathBuf>,
+
+    /// Do not look for a configuration file.
+    #[bpaf(switch, hide_usage)]
+    pub no_config: bool,
+
+    /// Do not read `.editorconfig` files.
+    #[bpaf(switch, hide_usage)]
+    pub no_editorconfig: bool,
+}
+
+/// Ignore Files
+#[derive(Debug, Clone, Bpaf)]
+pub struct IgnoreOptions {
+    /// Specify the file to use as your .prettierignore.
+    /// Files ignored by .gitignore are also ignored.
+    #[bpaf(argument("PATH"), fallback(".prettierignore".into()), hide_usage)]
+    pub ignore_path: OsString,
+
+    /// Format files in `node_modules` directories, which are ignored by default.
+    #[bpaf(switch, hide_usage)]
+    pub with_node_modules: bool,
+
+    /// Follow symbolic links. Oxfmt ignores symbolic links by default.
+    #[bpaf(switch, hide_usage)]
+    pub symlinks: bool,
+}
+
+/// Miscellaneous
+#[derive(Debug, Clone, Bpaf)]
+pub struct MiscOptions {
+    /// Number of threads to use. Set to 1 for using only 1 CPU core
+    #[bpaf(argument("INT"), hide_usage)]
+    pub threads: Option<usize>,
+}
+
+#[expect(clippy::ptr_arg)]
+fn validate_paths(paths: &Vec<PathBuf>) -> bool {
+    if paths.is_empty() {
+        true
+    } else {
+        paths.iter().all(|p| p.components().all(|c| c != std::path::Component::ParentDir))
+    }
+}
+
+const PATHS_ERROR_MESSAGE: &str = "PATH must not contain \"..\"";
+
+#[cfg(test)]
+mod test {
+    use std::{ffi::OsString, path::PathBuf};
+
+    use super::{FormatCommand, format_command};
+
+    fn get_command(arg: &str) -> FormatCommand {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        format_command().run_inner(args.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn default() {
+        let command = get_command(".");
+        assert!(!command.output_options.check);
+        assert!(!command.output_options.list_different);
+        assert!(!command.output_options.write);
+        assert!(command.config_options.config.is_none());
+        assert_eq!(command.ignore_options.ignore_path, OsString::from(".prettierignore"));
+        assert_eq!(command.paths, vec![PathBuf::from(".")]);
+    }
+
+    #[test]
+    fn output_options() {
+        let command = get_command("--check --write foo.js");
+        assert!(command.output_options.check);
+        assert!(command.output_options.write);
+        let command = get_command("-l foo.js");
+        assert!(command.output_options.list_different);
+    }
+
+    #[test]
+    fn config_options() {
+        let command = get_command("--config .prettierrc.json --no-editorconfig foo.js");
+        assert_eq!(command.config_options.config, Some(PathBuf::from(".prettierrc.json")));
+        assert!(command.config_options.no_editorconfig);
+        assert!(!command.config_options.no_config);
+    }
+
+    #[test]
+    fn threads() {
+        let command = get_command("--threads 4 .");
+        assert_eq!(command.misc_options.threads, Some(4));
+    }
+
+    #[test]
+    fn parent_dir_path() {
+        let args = ["../foo.js"].map(String::from);
+        assert!(format_command().run_inner(args.as_slice()).is_err());
+    }
+}
@@ -0,0 +1,236 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use cow_utils::CowUtils;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_formatter::{FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth};
+
+use super::matches_glob;
+
+/// An `.editorconfig` file.
+///
+/// Only the properties which Prettier reads are supported:
+/// `indent_style`, `indent_size`, `tab_width`, `end_of_line` and `max_line_length`.
+///
+/// <https://spec.editorconfig.org>
+#[derive(Debug)]
+pub struct EditorConfig {
+    /// The directory of the `.editorconfig` file, which section globs are relative to.
+    dir: PathBuf,
+    root: bool,
+    sections: Vec<(String, EditorConfigProperties)>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct EditorConfigProperties {
+    indent_style: Option<IndentStyle>,
+    indent_size: Option<IndentSize>,
+    tab_width: Option<u8>,
+    end_of_line: Option<LineEnding>,
+    max_line_length: Option<u16>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum IndentSize {
+    /// Use `tab_width`.
+    Tab,
+    Width(u8),
+}
+
+impl EditorConfig {
+    /// Returns the `.editorconfig` file in `dir`, if there is one.
+    ///
+    /// # Errors
+    ///
+    /// * The file cannot be read.
+    pub fn find_in_directory(dir: &Path) -> Result<Option<Self>, OxcDiagnostic> {
+        let path = dir.join(".editorconfig");
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let source_text = fs::read_to_string(&path).map_err(|err| {
+            OxcDiagnostic::error(format!("Failed to read {}: {err}", path.display()))
+        })?;
+        Ok(Some(Self::parse(dir.to_path_buf(), &source_text)))
+    }
+
+    /// Parses an `.editorconfig` file. Invalid lines and values are ignored.
+    pub fn parse(dir: PathBuf, source_text: &str) -> Self {
+        let mut root = false;
+        let mut sections: Vec<(String, EditorConfigProperties)> = vec![];
+        for line in source_text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(['#', ';']) {
+                continue;
+            }
+            if let Some(glob) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+                sections.push((normalize_glob(glob), EditorConfigProperties::default()));
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else { continue };
+            let key = key.trim().cow_to_ascii_lowercase();
+            let value = value.trim().cow_to_ascii_lowercase();
+            match sections.last_mut() {
+                Some((_, properties)) => properties.set(&key, &value),
+                None => {
+                    if key == "root" {
+                        root = value == "true";
+                    }
+                }
+            }
+        }
+        Self { dir, root, sections }
+    }
+
+    /// Whether parent directories should not be searched for more `.editorconfig` files.
+    pub fn is_root(&self) -> bool {
+        self.root
+    }
+
+    /// Merges the properties of the sections matching `path` into `properties`.
+    /// Later sections override earlier sections.
+    pub fn merge_properties(&self, path: &Path, properties: &mut EditorConfigProperties) {
+        for (glob, section) in &self.sections {
+            if matches_glob(glob, &self.dir, path) {
+                properties.merge(section);
+            }
+        }
+    }
+}
+
+impl EditorConfigProperties {
+    fn set(&mut self, key: &str, value: &str) {
+        match key {
+            "indent_style" => {
+                self.indent_style = match value {
+                    "tab" => Some(IndentStyle::Tab),
+                    "space" => Some(IndentStyle::Space),
+                    _ => return,
+                };
+            }
+            "indent_size" => {
+                self.indent_size = match value {
+                    "tab" => Some(IndentSize::Tab),
+                    _ => value.parse().ok().map(IndentSize::Width),
+                };
+            }
+            "tab_width" => self.tab_width = value.parse().ok(),
+            "end_of_line" => self.end_of_line = value.parse().ok(),
+            "max_line_length" => self.max_line_length = value.parse().ok(),
+            _ => {}
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.indent_style = other.indent_style.or(self.indent_style);
+        self.indent_size = other.indent_size.or(self.indent_size);
+        self.tab_width = other.tab_width.or(self.tab_width);
+        self.end_of_line = other.end_of_line.or(self.end_of_line);
+        self.max_line_length = other.max_line_length.or(self.max_line_length);
+    }
+
+    /// Applies the properties to `options` the same way as Prettier.
+    /// Out of range values are ignored.
+    pub fn apply(&self, options: &mut FormatOptions) {
+        if let Some(indent_style) = self.indent_style {
+            options.indent_style = indent_style;
+        }
+        let indent_width = match self.indent_size {
+            Some(IndentSize::Width(width)) => Some(width),
+            Some(IndentSize::Tab) | None => self.tab_width,
+        };
+        if let Some(indent_width) = indent_width.and_then(|w| IndentWidth::try_from(w).ok()) {
+            options.indent_width = indent_width;
+        }
+        if let Some(line_ending) = self.end_of_line {
+            options.line_ending = line_ending;
+        }
+        if let Some(line_width) = self.max_line_length.and_then(|w| LineWidth::try_from(w).ok()) {
+            options.line_width = line_width;
+        }
+    }
+}
+
+/// In `.editorconfig` files, `**` matches any string including `/`,
+/// e.g. `lib/**.js` matches `lib/a/foo.js`, so it is rewritten to `lib/**/*.js`.
+fn normalize_glob(glob: &str) -> String {
+    let mut normalized = String::with_capacity(glob.len());
+    let mut rest = glob;
+    while let Some(index) = rest.find("**") {
+        normalized.push_str(&rest[..index + 2]);
+        rest = &rest[index + 2..];
+        if !rest.is_empty() && !rest.starts_with('/') {
+            normalized.push_str("/*");
+        }
+    }
+    normalized.push_str(rest);
+    normalized
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use oxc_formatter::{FormatOptions, IndentStyle, LineEnding};
+
+    use super::{EditorConfig, EditorConfigProperties};
+
+    fn resolve(source_text: &str, path: &str) -> FormatOptions {
+        let editorconfig = EditorConfig::parse(PathBuf::from("/project"), source_text);
+        let mut properties = EditorConfigProperties::default();
+        editorconfig.merge_properties(&Path::new("/project").join(path), &mut properties);
+        let mut options = FormatOptions::default();
+        properties.apply(&mut options);
+        options
+    }
+
+    #[test]
+    fn parse() {
+        let source_text = "
+# comment
+root = true
+
+[*]
+indent_style = tab
+tab_width = 8
+end_of_line = CRLF
+max_line_length = off
+
+[*.{ts,tsx}]
+indent_style = space
+indent_size = 4
+
+[lib/**.js]
+max_line_length = 120
+indent_size = tab
+";
+        assert!(EditorConfig::parse(PathBuf::from("/project"), source_text).is_root());
+
+        let options = resolve(source_text, "src/foo.js");
+        assert_eq!(options.indent_style, IndentStyle::Tab);
+        assert_eq!(options.indent_width.value(), 8);
+        assert_eq!(options.line_ending, LineEnding::Crlf);
+        assert_eq!(options.line_width.value(), 80);
+
+        let options = resolve(source_text, "src/foo.ts");
+        assert_eq!(options.indent_style, IndentStyle::Space);
+        assert_eq!(options.indent_width.value(), 4);
+
+        let options = resolve(source_text, "lib/a/foo.js");
+        assert_eq!(options.indent_width.value(), 8);
+        assert_eq!(options.line_width.value(), 120);
+    }
+
+    #[test]
+    fn invalid_values() {
+        let source_text =
+            "[*]\nindent_style = tabs\nindent_size = 100\nfoo\nmax_line_length = 1000";
+        assert!(!EditorConfig::parse(PathBuf::from("/project"), source_text).is_root());
+        let options = resolve(source_text, "foo.js");
+        assert_eq!(options.indent_style, IndentStyle::Space);
+        assert_eq!(options.indent_width.value(), 2);
+        assert_eq!(options.line_width.value(), 80);
+    }
+}
@@ -0,0 +1,194 @@
+mod editorconfig;
+mod prettier;
+
+use std::{
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use fast_glob::glob_match;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_formatter::FormatOptions;
+use rustc_hash::FxHashMap;
+
+pub use self::{
+    editorconfig::{EditorConfig, EditorConfigProperties},
+    prettier::PrettierConfig,
+};
+
+/// Resolves the [FormatOptions] of each file from Prettier configuration files and
+/// `.editorconfig` files.
+///
+/// Like Prettier, the options from `.editorconfig` files are overridden by the Prettier configuration.
+/// Configuration files are cached by directory, so each file is only read once.
+pub struct ConfigResolver {
+    /// The configuration passed with `--config`, which is used for all files.
+    config: Option<Rc<PrettierConfig>>,
+    /// Whether to search for the nearest Prettier configuration of each file.
+    search_config: bool,
+    use_editorconfig: bool,
+    /// The nearest Prettier configuration of each directory.
+    prettier_configs: FxHashMap<PathBuf, Option<Rc<PrettierConfig>>>,
+    /// The `.editorconfig` files which apply to each directory, from the outermost to the innermost.
+    editorconfigs: FxHashMap<PathBuf, Rc<[Rc<EditorConfig>]>>,
+}
+
+impl ConfigResolver {
+    /// # Errors
+    ///
+    /// * `config` is not a valid Prettier configuration file.
+    pub fn new(
+        config: Option<&Path>,
+        search_config: bool,
+        use_editorconfig: bool,
+    ) -> Result<Self, OxcDiagnostic> {
+        let config = config.map(PrettierConfig::from_file).transpose()?.map(Rc::new);
+        Ok(Self {
+            config,
+            search_config,
+            use_editorconfig,
+            prettier_configs: FxHashMap::default(),
+            editorconfigs: FxHashMap::default(),
+        })
+    }
+
+    /// Returns the options for formatting the file at `path`, which must be absolute.
+    ///
+    /// # Errors
+    ///
+    /// * A configuration file which applies to `path` cannot be read or parsed.
+    pub fn resolve(&mut self, path: &Path) -> Result<FormatOptions, OxcDiagnostic> {
+        let dir = path.parent().unwrap_or(path);
+        let mut options = FormatOptions::default();
+
+        if self.use_editorconfig {
+            let mut properties = EditorConfigProperties::default();
+            for editorconfig in self.editorconfigs(dir)?.iter() {
+                editorconfig.merge_properties(path, &mut properties);
+            }
+            properties.apply(&mut options);
+        }
+
+        let config = match &self.config {
+            Some(config) => Some(Rc::clone(config)),
+            None if self.search_config => self.prettier_config(dir)?,
+            None => None,
+        };
+        if let Some(config) = config {
+            config.apply(path, &mut options)?;
+        }
+
+        Ok(options)
+    }
+
+    fn prettier_config(&mut self, dir: &Path) -> Result<Option<Rc<PrettierConfig>>, OxcDiagnostic> {
+        if let Some(config) = self.prettier_configs.get(dir) {
+            return Ok(config.clone());
+        }
+        let config = match PrettierConfig::find_in_directory(dir)? {
+            Some(config) => Some(Rc::new(config)),
+            None => match dir.parent() {
+                Some(parent) => self.prettier_config(parent)?,
+                None => None,
+            },
+        };
+        self.prettier_configs.insert(dir.to_path_buf(), config.clone());
+        Ok(config)
+    }
+
+    fn editorconfigs(&mut self, dir: &Path) -> Result<Rc<[Rc<EditorConfig>]>, OxcDiagnostic> {
+        if let Some(editorconfigs) = self.editorconfigs.get(dir) {
+            return Ok(Rc::clone(editorconfigs));
+        }
+        let editorconfig = EditorConfig::find_in_directory(dir)?;
+        let is_root = editorconfig.as_ref().is_some_and(EditorConfig::is_root);
+        let mut editorconfigs = match dir.parent() {
+            Some(parent) if !is_root => self.editorconfigs(parent)?.to_vec(),
+            _ => vec![],
+        };
+        editorconfigs.extend(editorconfig.map(Rc::new));
+        let editorconfigs = Rc::<[_]>::from(editorconfigs);
+        self.editorconfigs.insert(dir.to_path_buf(), Rc::clone(&editorconfigs));
+        Ok(editorconfigs)
+    }
+}
+
+/// Matches `glob` against `path`, relative to the directory of the configuration file.
+///
+/// Globs without a `/` match the file name in any directory, e.g. `*.ts` matches `src/foo.ts`.
+fn matches_glob(glob: &str, dir: &Path, path: &Path) -> bool {
+    let Ok(relative_path) = path.strip_prefix(dir) else { return false };
+    if glob.contains('/') {
+        let glob = glob.trim_start_matches("./").trim_start_matches('/');
+        let relative_path = relative_path.to_string_lossy();
+        #[cfg(windows)]
+        let relative_path = cow_utils::CowUtils::cow_replace(relative_path.as_ref(), '\\', "/");
+        glob_match(glob, relative_path.as_ref())
+    } else {
+        relative_path
+            .file_name()
+            .is_some_and(|file_name| glob_match(glob, file_name.to_string_lossy().as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, path::Path};
+
+    use oxc_formatter::{IndentStyle, LineEnding, QuoteStyle, Semicolons};
+
+    use super::{ConfigResolver, matches_glob};
+
+    #[test]
+    fn test_matches_glob() {
+        let dir = Path::new("/project");
+        assert!(matches_glob("*.ts", dir, Path::new("/project/src/foo.ts")));
+        assert!(matches_glob("*.{js,ts}", dir, Path::new("/project/foo.js")));
+        assert!(!matches_glob("*.ts", dir, Path::new("/project/src/foo.js")));
+        assert!(matches_glob("src/*.ts", dir, Path::new("/project/src/foo.ts")));
+        assert!(matches_glob("/src/**/*.ts", dir, Path::new("/project/src/a/foo.ts")));
+        assert!(!matches_glob("src/*.ts", dir, Path::new("/project/lib/src/foo.ts")));
+        assert!(!matches_glob("*.ts", dir, Path::new("/other/foo.ts")));
+    }
+
+    #[test]
+    fn resolve_prettierrc_and_editorconfig() {
+        let fixture = env::current_dir().unwrap().join("fixtures/editorconfig");
+        let mut resolver = ConfigResolver::new(None, true, true).unwrap();
+
+        // `indent_style = tab` from `.editorconfig`, `semi: false` from `.prettierrc`.
+        let options = resolver.resolve(&fixture.join("foo.js")).unwrap();
+        assert_eq!(options.indent_style, IndentStyle::Tab);
+        assert_eq!(options.semicolons, Semicolons::AsNeeded);
+        assert_eq!(options.line_width.value(), 100);
+
+        // `useTabs: false` in the `.prettierrc` overrides `.editorconfig`.
+        let options = resolver.resolve(&fixture.join("foo.ts")).unwrap();
+        assert_eq!(options.indent_style, IndentStyle::Space);
+        assert_eq!(options.indent_width.value(), 4);
+
+        // The nested `.editorconfig` is merged with the outer one.
+        let options = resolver.resolve(&fixture.join("nested/foo.js")).unwrap();
+        assert_eq!(options.indent_style, IndentStyle::Tab);
+        assert_eq!(options.line_ending, LineEnding::Crlf);
+
+        let mut resolver = ConfigResolver::new(None, true, false).unwrap();
+        let options = resolver.resolve(&fixture.join("foo.js")).unwrap();
+        assert_eq!(options.indent_style, IndentStyle::Space);
+        assert_eq!(options.semicolons, Semicolons::AsNeeded);
+
+        let mut resolver = ConfigResolver::new(None, false, false).unwrap();
+        let options = resolver.resolve(&fixture.join("foo.js")).unwrap();
+        assert_eq!(options.semicolons, Semicolons::Always);
+    }
+
+    #[test]
+    fn resolve_explicit_config() {
+        let fixture = env::current_dir().unwrap().join("fixtures/prettierrc");
+        let config = fixture.join("single_quote.json");
+        let mut resolver = ConfigResolver::new(Some(&config), true, false).unwrap();
+        let options = resolver.resolve(&fixture.join("foo.js")).unwrap();
+        assert_eq!(options.quote_style, QuoteStyle::Single);
+        assert_eq!(options.semicolons, Semicolons::Always);
+    }
+}
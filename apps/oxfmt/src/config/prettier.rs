@@ -0,0 +1,437 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_formatter::{
+    ArrowParentheses, BracketSameLine, BracketSpacing, EmbeddedLanguageFormatting, Expand,
+    FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth, QuoteProperties, QuoteStyle,
+    Semicolons, TrailingCommas,
+};
+use saphyr::{LoadableYamlNode, Scalar, Yaml};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::matches_glob;
+
+/// Configuration files in the order Prettier searches for them in each directory.
+///
+/// `package.json` is only used if it has a `prettier` key.
+/// JavaScript, JSON5 and TOML configuration files are found, but cannot be loaded.
+const CONFIG_FILES: &[&str] = &[
+    "package.json",
+    ".prettierrc",
+    ".prettierrc.json",
+    ".prettierrc.yaml",
+    ".prettierrc.yml",
+    ".prettierrc.json5",
+    ".prettierrc.js",
+    ".prettierrc.ts",
+    ".prettierrc.mjs",
+    ".prettierrc.mts",
+    ".prettierrc.cjs",
+    ".prettierrc.cts",
+    "prettier.config.js",
+    "prettier.config.ts",
+    "prettier.config.mjs",
+    "prettier.config.mts",
+    "prettier.config.cjs",
+    "prettier.config.cts",
+    ".prettierrc.toml",
+];
+
+/// A Prettier configuration file.
+///
+/// Options which are not supported by the formatter, such as `plugins`, are ignored.
+///
+/// <https://prettier.io/docs/configuration>
+#[derive(Debug)]
+pub struct PrettierConfig {
+    /// The directory of the configuration file, which `overrides` globs are relative to.
+    dir: PathBuf,
+    options: PrettierOptions,
+    overrides: Vec<PrettierOverride>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PrettierConfigJson {
+    #[serde(flatten)]
+    options: PrettierOptions,
+    #[serde(default)]
+    overrides: Vec<PrettierOverride>,
+}
+
+/// <https://prettier.io/docs/configuration#configuration-overrides>
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PrettierOverride {
+    files: Globs,
+    #[serde(default)]
+    exclude_files: Globs,
+    #[serde(default)]
+    options: PrettierOptions,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Globs {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Default for Globs {
+    fn default() -> Self {
+        Self::Many(vec![])
+    }
+}
+
+impl Globs {
+    fn matches(&self, dir: &Path, path: &Path) -> bool {
+        match self {
+            Self::One(glob) => matches_glob(glob, dir, path),
+            Self::Many(globs) => globs.iter().any(|glob| matches_glob(glob, dir, path)),
+        }
+    }
+}
+
+/// <https://prettier.io/docs/options>
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PrettierOptions {
+    print_width: Option<u16>,
+    tab_width: Option<u8>,
+    use_tabs: Option<bool>,
+    semi: Option<bool>,
+    single_quote: Option<bool>,
+    jsx_single_quote: Option<bool>,
+    quote_props: Option<PrettierQuoteProps>,
+    trailing_comma: Option<PrettierTrailingComma>,
+    bracket_spacing: Option<bool>,
+    bracket_same_line: Option<bool>,
+    object_wrap: Option<PrettierObjectWrap>,
+    arrow_parens: Option<PrettierArrowParens>,
+    end_of_line: Option<PrettierEndOfLine>,
+    embedded_language_formatting: Option<PrettierEmbeddedLanguageFormatting>,
+}
+
+/// `"consistent"` is not supported.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum PrettierQuoteProps {
+    AsNeeded,
+    Preserve,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum PrettierTrailingComma {
+    All,
+    Es5,
+    None,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum PrettierObjectWrap {
+    Preserve,
+    Collapse,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum PrettierArrowParens {
+    Always,
+    Avoid,
+}
+
+/// `"auto"` keeps the default line ending.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum PrettierEndOfLine {
+    Lf,
+    Crlf,
+    Cr,
+    Auto,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum PrettierEmbeddedLanguageFormatting {
+    Auto,
+    Off,
+}
+
+impl PrettierConfig {
+    /// Returns the Prettier configuration in `dir`, if there is one.
+    ///
+    /// # Errors
+    ///
+    /// * The configuration file cannot be read or parsed.
+    pub fn find_in_directory(dir: &Path) -> Result<Option<Self>, OxcDiagnostic> {
+        for file_name in CONFIG_FILES {
+            let path = dir.join(file_name);
+            if !path.is_file() {
+                continue;
+            }
+            if *file_name == "package.json" {
+                let json = Self::read_json(&path)?;
+                match json.get("prettier") {
+                    Some(Value::Object(_)) => {}
+                    // Shared configurations, e.g. `"prettier": "@company/prettier-config"`.
+                    Some(_) => return Err(Self::unsupported_error(&path)),
+                    None => continue,
+                }
+            }
+            return Self::from_file(&path).map(Some);
+        }
+        Ok(None)
+    }
+
+    /// # Errors
+    ///
+    /// * The configuration file cannot be read or parsed, or is not a JSON or YAML file.
+    pub fn from_file(path: &Path) -> Result<Self, OxcDiagnostic> {
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        let json = match file_name.rsplit_once('.').map(|(_, ext)| ext) {
+            _ if file_name == "package.json" => {
+                let mut json = Self::read_json(path)?;
+                json.get_mut("prettier").map(Value::take).unwrap_or_default()
+            }
+            Some("json") => Self::read_json(path)?,
+            Some("yaml" | "yml") => Self::read_yaml(path)?,
+            // `.prettierrc` is either JSON or YAML.
+            Some("prettierrc") | None => {
+                Self::read_json(path).or_else(|_| Self::read_yaml(path))?
+            }
+            Some(_) => return Err(Self::unsupported_error(path)),
+        };
+
+        let config =
+            PrettierConfigJson::deserialize(&json).map_err(|err| Self::parse_error(path, err))?;
+
+        Ok(Self {
+            dir: path.parent().map(Path::to_path_buf).unwrap_or_default(),
+            options: config.options,
+            overrides: config.overrides,
+        })
+    }
+
+    /// Applies the options, and the options of the matching `overrides`, to `options`.
+    ///
+    /// # Errors
+    ///
+    /// * An option is out of range, e.g. `printWidth: 1000`.
+    pub fn apply(&self, path: &Path, options: &mut FormatOptions) -> Result<(), OxcDiagnostic> {
+        self.options.apply(options).map_err(|err| self.invalid_option_error(&err))?;
+        for r#override in &self.overrides {
+            if r#override.files.matches(&self.dir, path)
+                && !r#override.exclude_files.matches(&self.dir, path)
+            {
+                r#override.options.apply(options).map_err(|err| self.invalid_option_error(&err))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn read_json(path: &Path) -> Result<Value, OxcDiagnostic> {
+        let mut source_text = Self::read_to_string(path)?;
+        json_strip_comments::strip(&mut source_text).map_err(|err| Self::parse_error(path, err))?;
+        serde_json::from_str(&source_text).map_err(|err| Self::parse_error(path, err))
+    }
+
+    fn read_yaml(path: &Path) -> Result<Value, OxcDiagnostic> {
+        let source_text = Self::read_to_string(path)?;
+        let documents =
+            Yaml::load_from_str(&source_text).map_err(|err| Self::parse_error(path, err))?;
+        Ok(documents.first().map(yaml_to_json).unwrap_or_default())
+    }
+
+    fn read_to_string(path: &Path) -> Result<String, OxcDiagnostic> {
+        fs::read_to_string(path).map_err(|err| {
+            OxcDiagnostic::error(format!("Failed to read {}: {err}", path.display()))
+        })
+    }
+
+    fn parse_error(path: &Path, err: impl std::fmt::Display) -> OxcDiagnostic {
+        OxcDiagnostic::error(format!(
+            "Failed to parse Prettier configuration {}: {err}",
+            path.display()
+        ))
+    }
+
+    fn unsupported_error(path: &Path) -> OxcDiagnostic {
+        OxcDiagnostic::error(format!("Unsupported Prettier configuration {}", path.display()))
+            .with_help("Only JSON and YAML configuration files are supported.")
+    }
+
+    fn invalid_option_error(&self, err: &str) -> OxcDiagnostic {
+        OxcDiagnostic::error(format!(
+            "Invalid option in the Prettier configuration in {}: {err}",
+            self.dir.display()
+        ))
+    }
+}
+
+impl PrettierOptions {
+    fn apply(&self, options: &mut FormatOptions) -> Result<(), String> {
+        if let Some(print_width) = self.print_width {
+            options.line_width = LineWidth::try_from(print_width).map_err(|_| {
+                format!("`printWidth` must be between {} and {}", LineWidth::MIN, LineWidth::MAX)
+            })?;
+        }
+        if let Some(tab_width) = self.tab_width {
+            options.indent_width = IndentWidth::try_from(tab_width).map_err(|_| {
+                format!("`tabWidth` must be between {} and {}", IndentWidth::MIN, IndentWidth::MAX)
+            })?;
+        }
+        if let Some(use_tabs) = self.use_tabs {
+            options.indent_style = if use_tabs { IndentStyle::Tab } else { IndentStyle::Space };
+        }
+        if let Some(semi) = self.semi {
+            options.semicolons = if semi { Semicolons::Always } else { Semicolons::AsNeeded };
+        }
+        if let Some(single_quote) = self.single_quote {
+            options.quote_style =
+                if single_quote { QuoteStyle::Single } else { QuoteStyle::Double };
+        }
+        if let Some(jsx_single_quote) = self.jsx_single_quote {
+            options.jsx_quote_style =
+                if jsx_single_quote { QuoteStyle::Single } else { QuoteStyle::Double };
+        }
+        if let Some(quote_props) = self.quote_props {
+            options.quote_properties = match quote_props {
+                PrettierQuoteProps::AsNeeded => QuoteProperties::AsNeeded,
+                PrettierQuoteProps::Preserve => QuoteProperties::Preserve,
+            };
+        }
+        if let Some(trailing_comma) = self.trailing_comma {
+            options.trailing_commas = match trailing_comma {
+                PrettierTrailingComma::All => TrailingCommas::All,
+                PrettierTrailingComma::Es5 => TrailingCommas::Es5,
+                PrettierTrailingComma::None => TrailingCommas::None,
+            };
+        }
+        if let Some(bracket_spacing) = self.bracket_spacing {
+            options.bracket_spacing = BracketSpacing::from(bracket_spacing);
+        }
+        if let Some(bracket_same_line) = self.bracket_same_line {
+            options.bracket_same_line = BracketSameLine::from(bracket_same_line);
+        }
+        if let Some(object_wrap) = self.object_wrap {
+            options.expand = match object_wrap {
+                PrettierObjectWrap::Preserve => Expand::Auto,
+                PrettierObjectWrap::Collapse => Expand::Never,
+            };
+        }
+        if let Some(arrow_parens) = self.arrow_parens {
+            options.arrow_parentheses = match arrow_parens {
+                PrettierArrowParens::Always => ArrowParentheses::Always,
+                PrettierArrowParens::Avoid => ArrowParentheses::AsNeeded,
+            };
+        }
+        match self.end_of_line {
+            Some(PrettierEndOfLine::Lf) => options.line_ending = LineEnding::Lf,
+            Some(PrettierEndOfLine::Crlf) => options.line_ending = LineEnding::Crlf,
+            Some(PrettierEndOfLine::Cr) => options.line_ending = LineEnding::Cr,
+            Some(PrettierEndOfLine::Auto) | None => {}
+        }
+        if let Some(embedded_language_formatting) = self.embedded_language_formatting {
+            options.embedded_language_formatting = match embedded_language_formatting {
+                PrettierEmbeddedLanguageFormatting::Auto => EmbeddedLanguageFormatting::Auto,
+                PrettierEmbeddedLanguageFormatting::Off => EmbeddedLanguageFormatting::Off,
+            };
+        }
+        Ok(())
+    }
+}
+
+fn yaml_to_json(yaml: &Yaml) -> Value {
+    match yaml {
+        Yaml::Value(Scalar::Boolean(b)) => Value::Bool(*b),
+        Yaml::Value(Scalar::Integer(i)) => Value::from(*i),
+        Yaml::Value(Scalar::FloatingPoint(f)) => Value::from(f.into_inner()),
+        Yaml::Value(Scalar::String(s)) => Value::String(s.to_string()),
+        Yaml::Sequence(sequence) => Value::Array(sequence.iter().map(yaml_to_json).collect()),
+        Yaml::Mapping(mapping) => Value::Object(
+            mapping
+                .iter()
+                .filter_map(|(key, value)| Some((key.as_str()?.to_string(), yaml_to_json(value))))
+                .collect(),
+        ),
+        _ => Value::Null,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, path::Path};
+
+    use oxc_formatter::{
+        ArrowParentheses, EmbeddedLanguageFormatting, FormatOptions, IndentStyle, QuoteStyle,
+        Semicolons, TrailingCommas,
+    };
+
+    use super::PrettierConfig;
+
+    fn resolve(config: &str, file: &str) -> Result<FormatOptions, String> {
+        let fixture = env::current_dir().unwrap().join("fixtures/prettierrc");
+        let config = PrettierConfig::from_file(&fixture.join(config)).map_err(|e| e.to_string())?;
+        let mut options = FormatOptions::default();
+        config.apply(&fixture.join(file), &mut options).map_err(|e| e.to_string())?;
+        Ok(options)
+    }
+
+    #[test]
+    fn json() {
+        let options = resolve(".prettierrc", "foo.js").unwrap();
+        assert_eq!(options.semicolons, Semicolons::AsNeeded);
+        assert_eq!(options.quote_style, QuoteStyle::Single);
+        assert_eq!(options.trailing_commas, TrailingCommas::Es5);
+        assert_eq!(options.line_width.value(), 100);
+    }
+
+    #[test]
+    fn overrides() {
+        let options = resolve(".prettierrc", "foo.ts").unwrap();
+        assert_eq!(options.semicolons, Semicolons::Always);
+        assert_eq!(options.quote_style, QuoteStyle::Single);
+
+        // Excluded by `excludeFiles`.
+        let options = resolve(".prettierrc", "legacy/foo.ts").unwrap();
+        assert_eq!(options.semicolons, Semicolons::AsNeeded);
+    }
+
+    #[test]
+    fn yaml() {
+        let options = resolve("yaml.yaml", "foo.js").unwrap();
+        assert_eq!(options.indent_style, IndentStyle::Tab);
+        assert_eq!(options.indent_width.value(), 4);
+        assert_eq!(options.arrow_parentheses, ArrowParentheses::AsNeeded);
+        assert_eq!(options.embedded_language_formatting, EmbeddedLanguageFormatting::Off);
+    }
+
+    #[test]
+    fn package_json() {
+        let fixture = env::current_dir().unwrap().join("fixtures/package_json");
+        let config = PrettierConfig::find_in_directory(&fixture).unwrap().unwrap();
+        let mut options = FormatOptions::default();
+        config.apply(&fixture.join("foo.js"), &mut options).unwrap();
+        assert_eq!(options.indent_width.value(), 4);
+
+        // `package.json` without a `prettier` key is skipped.
+        let fixture = env::current_dir().unwrap().join("fixtures/package_json/no_prettier");
+        let config = PrettierConfig::find_in_directory(&fixture).unwrap().unwrap();
+        assert_eq!(config.dir, fixture);
+    }
+
+    #[test]
+    fn errors() {
+        let err = resolve("invalid_option.json", "foo.js").unwrap_err();
+        assert!(err.contains("unknown variant `consistent`"), "{err}");
+        let err = resolve("out_of_range.json", "foo.js").unwrap_err();
+        assert!(err.contains("`printWidth` must be between 1 and 320"), "{err}");
+        assert!(PrettierConfig::from_file(Path::new("prettier.config.js")).is_err());
+    }
+}
@@ -0,0 +1,348 @@
+use std::{
+    cell::RefCell,
+    env,
+    fmt::Write as _,
+    fs,
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Instant,
+};
+
+use oxc_allocator::Allocator;
+use oxc_diagnostics::{GraphicalReportHandler, NamedSource, OxcDiagnostic};
+use oxc_formatter::{FormatOptions, Formatter};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use rayon::prelude::*;
+
+use crate::{
+    cli::{CliRunResult, FormatCommand},
+    config::ConfigResolver,
+    walk::Walk,
+};
+
+thread_local! {
+    /// Each thread reuses one allocator for all the files it formats.
+    static ALLOCATOR: RefCell<Allocator> = RefCell::new(Allocator::default());
+}
+
+#[derive(Debug)]
+pub struct FormatRunner {
+    options: FormatCommand,
+    cwd: PathBuf,
+}
+
+/// The result of formatting a file.
+struct FormattedFile {
+    /// The formatted code, which is only kept when it is printed to stdout.
+    code: String,
+    changed: bool,
+}
+
+impl FormatRunner {
+    /// # Panics
+    ///
+    /// * The current working directory cannot be read.
+    pub fn new(options: FormatCommand) -> Self {
+        Self { options, cwd: env::current_dir().expect("Failed to get current working directory") }
+    }
+
+    #[must_use]
+    pub fn with_cwd(mut self, cwd: PathBuf) -> Self {
+        self.cwd = cwd;
+        self
+    }
+
+    pub fn run(self, stdout: &mut dyn Write) -> CliRunResult {
+        let FormatCommand { output_options, config_options, ignore_options, paths, .. } =
+            self.options;
+        let now = Instant::now();
+
+        let paths = if paths.is_empty() {
+            vec![self.cwd.clone()]
+        } else {
+            paths.iter().map(|path| self.cwd.join(path)).collect()
+        };
+        let mut paths = Walk::new(&paths, &ignore_options).paths();
+        paths.sort_unstable();
+
+        if paths.is_empty() {
+            Self::print_and_flush(stdout, "No files found to format.\n");
+            return CliRunResult::FormatNoFilesFound;
+        }
+
+        let config = config_options.config.as_ref().map(|config| self.cwd.join(config));
+        let resolver = ConfigResolver::new(
+            config.as_deref(),
+            !config_options.no_config,
+            !config_options.no_editorconfig,
+        );
+        let files = resolver.and_then(|mut resolver| {
+            paths
+                .into_iter()
+                .map(|path| {
+                    let path = PathBuf::from(&path);
+                    resolver.resolve(&path).map(|options| (path, options))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        });
+        let files = match files {
+            Ok(files) => files,
+            Err(diagnostic) => {
+                let message = format!(
+                    "Failed to parse configuration file.\n{}\n",
+                    Self::render_diagnostic(&diagnostic.into())
+                );
+                Self::print_and_flush(stdout, &message);
+                return CliRunResult::InvalidOptionConfig;
+            }
+        };
+
+        let number_of_files = files.len();
+        let print_code =
+            !output_options.check && !output_options.list_different && !output_options.write;
+        let results = files
+            .into_par_iter()
+            .map(|(path, options)| {
+                let result = Self::format_file(&path, options, &self.cwd, output_options.write)
+                    .map(|mut formatted| {
+                        if !print_code {
+                            formatted.code = String::new();
+                        }
+                        formatted
+                    });
+                (path, result)
+            })
+            .collect::<Vec<_>>();
+
+        let mut output = String::new();
+        if output_options.check {
+            output.push_str("Checking formatting...\n");
+        }
+        let mut number_of_changed_files = 0;
+        let mut number_of_errors = 0;
+        for (path, result) in results {
+            let formatted = match result {
+                Ok(formatted) => formatted,
+                Err(error) => {
+                    number_of_errors += 1;
+                    output.push_str(&error);
+                    continue;
+                }
+            };
+            if print_code {
+                output.push_str(&formatted.code);
+                continue;
+            }
+            if !formatted.changed {
+                continue;
+            }
+            number_of_changed_files += 1;
+            let path = path.strip_prefix(&self.cwd).unwrap_or(&path).display();
+            if output_options.check {
+                writeln!(output, "[warn] {path}").unwrap();
+            } else if output_options.list_different {
+                writeln!(output, "{path}").unwrap();
+            }
+        }
+
+        if output_options.check {
+            let files = Self::files(number_of_changed_files);
+            if number_of_changed_files > 0 {
+                if output_options.write {
+                    writeln!(output, "[warn] Code style issues fixed in {files}.").unwrap();
+                } else {
+                    writeln!(
+                        output,
+                        "[warn] Code style issues found in {files}. Run `oxfmt --write` to fix."
+                    )
+                    .unwrap();
+                }
+            } else if number_of_errors == 0 {
+                output.push_str("All matched files use the correct format.\n");
+            }
+            if number_of_errors > 0 {
+                writeln!(output, "[error] Failed to check {}.", Self::files(number_of_errors))
+                    .unwrap();
+            }
+        } else if output_options.write && !output_options.list_different {
+            writeln!(
+                output,
+                "Finished in {}ms on {} using {} threads.",
+                now.elapsed().as_millis(),
+                Self::files(number_of_files),
+                rayon::current_num_threads()
+            )
+            .unwrap();
+        }
+        Self::print_and_flush(stdout, &output);
+
+        if number_of_errors > 0 {
+            CliRunResult::FormatFailed
+        } else if number_of_changed_files > 0
+            && (output_options.check || output_options.list_different)
+            && !output_options.write
+        {
+            CliRunResult::FormatMismatch
+        } else {
+            CliRunResult::FormatSucceeded
+        }
+    }
+
+    /// Formats the file at `path`, and writes it back if `write` is set and the code is changed.
+    /// Returns the rendered diagnostics if the file cannot be read, parsed or written.
+    fn format_file(
+        path: &Path,
+        options: FormatOptions,
+        cwd: &Path,
+        write: bool,
+    ) -> Result<FormattedFile, String> {
+        let display_path = path.strip_prefix(cwd).unwrap_or(path).to_string_lossy().to_string();
+        let source_text = fs::read_to_string(path).map_err(|err| {
+            Self::render_diagnostic(
+                &OxcDiagnostic::error(format!("Failed to read {display_path}: {err}")).into(),
+            )
+        })?;
+        // Only files with valid extensions are walked.
+        let source_type = SourceType::from_path(path).unwrap();
+
+        let code = ALLOCATOR.with_borrow_mut(|allocator| {
+            allocator.reset();
+            let ret = Parser::new(allocator, &source_text, source_type).parse();
+            if !ret.errors.is_empty() {
+                let source = Arc::new(NamedSource::new(&display_path, source_text.clone()));
+                return Err(ret
+                    .errors
+                    .into_iter()
+                    .map(|error| {
+                        Self::render_diagnostic(&error.with_source_code(Arc::clone(&source)))
+                    })
+                    .collect::<String>());
+            }
+            Ok(Formatter::new(allocator, options).build(&ret.program))
+        })?;
+
+        let changed = code != source_text;
+        if write && changed {
+            fs::write(path, &code).map_err(|err| {
+                Self::render_diagnostic(
+                    &OxcDiagnostic::error(format!("Failed to write {display_path}: {err}")).into(),
+                )
+            })?;
+        }
+        Ok(FormattedFile { code, changed })
+    }
+
+    fn render_diagnostic(diagnostic: &miette::Report) -> String {
+        let handler = GraphicalReportHandler::new();
+        let mut output = String::new();
+        handler.render_report(&mut output, diagnostic.as_ref()).unwrap();
+        output
+    }
+
+    fn files(n: usize) -> String {
+        if n == 1 { "1 file".to_string() } else { format!("{n} files") }
+    }
+
+    fn print_and_flush(stdout: &mut dyn Write, message: &str) {
+        stdout.write_all(message.as_bytes()).or_else(Self::check_for_writer_error).unwrap();
+        stdout.flush().unwrap();
+    }
+
+    fn check_for_writer_error(error: std::io::Error) -> Result<(), std::io::Error> {
+        // Do not panic when the process is killed (e.g. piping into `less`).
+        if matches!(error.kind(), ErrorKind::Interrupted | ErrorKind::BrokenPipe) {
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::FormatRunner;
+    use crate::{cli::format_command, tester::Tester};
+
+    #[test]
+    fn print_code() {
+        let args = &["foo.js"];
+        Tester::new().with_cwd("fixtures/check".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn check() {
+        let args = &["--check"];
+        Tester::new().with_cwd("fixtures/check".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn check_formatted() {
+        let args = &["--check", "formatted.js"];
+        Tester::new().with_cwd("fixtures/check".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn list_different() {
+        let args = &["--list-different", "."];
+        Tester::new().with_cwd("fixtures/check".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn prettierrc() {
+        let args = &["foo.js", "foo.ts"];
+        Tester::new().with_cwd("fixtures/prettierrc".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn no_config() {
+        let args = &["--no-config", "foo.js"];
+        Tester::new().with_cwd("fixtures/prettierrc".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn editorconfig() {
+        let args = &["foo.js", "nested/foo.js"];
+        Tester::new().with_cwd("fixtures/editorconfig".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn invalid_config() {
+        let args = &["--config", "invalid_option.json", "foo.js"];
+        Tester::new().with_cwd("fixtures/prettierrc".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn syntax_error() {
+        let args = &["--check"];
+        Tester::new().with_cwd("fixtures/syntax_error".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn no_files_found() {
+        let args = &["--check", "foo.txt"];
+        Tester::new().with_cwd("fixtures/check".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn write() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("foo.js"), "const  a  =  1\n").unwrap();
+        fs::write(dir.path().join("bar.js"), "const a = 1;\n").unwrap();
+
+        let options = format_command().run_inner(&["--write", "--list-different"]).unwrap();
+        let mut output = Vec::new();
+        FormatRunner::new(options).with_cwd(dir.path().to_path_buf()).run(&mut output);
+
+        assert_eq!(String::from_utf8(output).unwrap(), "foo.js\n");
+        assert_eq!(fs::read_to_string(dir.path().join("foo.js")).unwrap(), "const a = 1;\n");
+
+        let options = format_command().run_inner(&["--check"]).unwrap();
+        let mut output = Vec::new();
+        let result = FormatRunner::new(options).with_cwd(dir.path().to_path_buf()).run(&mut output);
+        assert!(matches!(result, crate::cli::CliRunResult::FormatSucceeded));
+    }
+}
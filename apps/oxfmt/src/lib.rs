@@ -0,0 +1,10 @@
+mod command;
+mod config;
+mod format;
+mod result;
+mod tester;
+mod walk;
+
+pub mod cli {
+    pub use crate::{command::*, format::FormatRunner, result::CliRunResult};
+}
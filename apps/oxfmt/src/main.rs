@@ -0,0 +1,24 @@
+// NOTE: Miri does not support custom allocators
+#[cfg(all(feature = "allocator", not(miri), not(target_family = "wasm")))]
+#[global_allocator]
+static GLOBAL: mimalloc_safe::MiMalloc = mimalloc_safe::MiMalloc;
+
+use oxfmt::cli::{CliRunResult, FormatRunner};
+use std::io::BufWriter;
+
+fn main() -> CliRunResult {
+    init_miette();
+
+    let command = oxfmt::cli::format_command().run();
+    command.handle_threads();
+    // stdio is blocked by LineWriter, use a BufWriter to reduce syscalls.
+    // See `https://github.com/rust-lang/rust/issues/60673`.
+    let mut stdout = BufWriter::new(std::io::stdout());
+
+    FormatRunner::new(command).run(&mut stdout)
+}
+
+// Initialize the data which relies on `is_atty` system calls so they don't block subsequent threads.
+fn init_miette() {
+    miette::set_hook(Box::new(|_| Box::new(miette::MietteHandlerOpts::new().build()))).unwrap();
+}
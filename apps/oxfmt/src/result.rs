@@ -0,0 +1,23 @@
+use std::process::{ExitCode, Termination};
+
+#[derive(Debug)]
+pub enum CliRunResult {
+    None,
+    InvalidOptionConfig,
+    FormatSucceeded,
+    /// `--check` or `--list-different` found files which are not formatted.
+    FormatMismatch,
+    /// Some files could not be read, parsed or written.
+    FormatFailed,
+    FormatNoFilesFound,
+}
+
+impl Termination for CliRunResult {
+    fn report(self) -> ExitCode {
+        match self {
+            Self::None | Self::FormatSucceeded | Self::FormatNoFilesFound => ExitCode::SUCCESS,
+            Self::FormatMismatch => ExitCode::FAILURE,
+            Self::InvalidOptionConfig | Self::FormatFailed => ExitCode::from(2),
+        }
+    }
+}
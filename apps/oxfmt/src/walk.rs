@@ -0,0 +1,124 @@
+use std::{ffi::OsStr, path::PathBuf, sync::Arc, sync::mpsc};
+
+use ignore::DirEntry;
+use oxc_span::VALID_EXTENSIONS;
+
+use crate::cli::IgnoreOptions;
+
+pub struct Walk {
+    inner: ignore::WalkParallel,
+}
+
+struct WalkBuilder {
+    sender: mpsc::Sender<Vec<Arc<OsStr>>>,
+}
+
+impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
+    fn build(&mut self) -> Box<dyn ignore::ParallelVisitor + 's> {
+        Box::new(WalkCollector { paths: vec![], sender: self.sender.clone() })
+    }
+}
+
+struct WalkCollector {
+    paths: Vec<Arc<OsStr>>,
+    sender: mpsc::Sender<Vec<Arc<OsStr>>>,
+}
+
+impl Drop for WalkCollector {
+    fn drop(&mut self) {
+        let paths = std::mem::take(&mut self.paths);
+        self.sender.send(paths).unwrap();
+    }
+}
+
+impl ignore::ParallelVisitor for WalkCollector {
+    fn visit(&mut self, entry: Result<ignore::DirEntry, ignore::Error>) -> ignore::WalkState {
+        match entry {
+            Ok(entry) => {
+                if Walk::is_wanted_entry(&entry) {
+                    self.paths.push(entry.path().as_os_str().into());
+                }
+                ignore::WalkState::Continue
+            }
+            Err(_err) => ignore::WalkState::Skip,
+        }
+    }
+}
+
+impl Walk {
+    /// Will not canonicalize paths.
+    /// # Panics
+    pub fn new(paths: &[PathBuf], options: &IgnoreOptions) -> Self {
+        assert!(!paths.is_empty(), "At least one path must be provided to Walk::new");
+
+        let mut inner = ignore::WalkBuilder::new(&paths[0]);
+        for path in &paths[1..] {
+            inner.add(path);
+        }
+
+        inner.add_custom_ignore_filename(&options.ignore_path);
+
+        if !options.with_node_modules {
+            inner.filter_entry(|entry| entry.file_name() != "node_modules");
+        }
+
+        // Like Prettier, `.gitignore` files are respected outside of git repositories too.
+        let inner = inner
+            .ignore(false)
+            .git_global(false)
+            .require_git(false)
+            .follow_links(options.symlinks)
+            .build_parallel();
+        Self { inner }
+    }
+
+    pub fn paths(self) -> Vec<Arc<OsStr>> {
+        let (sender, receiver) = mpsc::channel::<Vec<Arc<OsStr>>>();
+        let mut builder = WalkBuilder { sender };
+        self.inner.visit(&mut builder);
+        drop(builder);
+        receiver.into_iter().flatten().collect()
+    }
+
+    fn is_wanted_entry(dir_entry: &DirEntry) -> bool {
+        let Some(file_type) = dir_entry.file_type() else { return false };
+        if file_type.is_dir() {
+            return false;
+        }
+        let Some(extension) = dir_entry.path().extension() else { return false };
+        let extension = extension.to_string_lossy();
+        VALID_EXTENSIONS.contains(&extension.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, ffi::OsString, path::Path};
+
+    use super::Walk;
+    use crate::cli::IgnoreOptions;
+
+    fn walk(with_node_modules: bool) -> Vec<String> {
+        let fixture = env::current_dir().unwrap().join("fixtures/ignore");
+        let options = IgnoreOptions {
+            ignore_path: OsString::from(".prettierignore"),
+            with_node_modules,
+            symlinks: false,
+        };
+        let mut paths = Walk::new(&[fixture.clone()], &options)
+            .paths()
+            .into_iter()
+            .map(|path| {
+                Path::new(&path).strip_prefix(&fixture).unwrap().to_string_lossy().to_string()
+            })
+            .collect::<Vec<_>>();
+        paths.sort();
+        paths
+    }
+
+    #[test]
+    fn test_walk() {
+        assert_eq!(walk(false), vec!["foo.js", "nested/bar.ts"]);
+        assert_eq!(walk(true), vec!["foo.js", "nested/bar.ts", "node_modules/dep/index.js"]);
+    }
+}
@@ -0,0 +1,65 @@
+export default {
+  meta: { name: 'eslint-plugin-local' },
+  rules: {
+    'no-foo': {
+      meta: {
+        fixable: 'code',
+        messages: { noFoo: 'Unexpected identifier `{{ name }}`.' },
+      },
+      create(context) {
+        return {
+          Identifier(node) {
+            if (node.name === 'foo') {
+              context.report({
+                node,
+                messageId: 'noFoo',
+                data: { name: node.name },
+                fix: (fixer) => fixer.replaceText(node, 'bar'),
+              });
+            }
+          },
+        };
+      },
+    },
+    'max-params': {
+      meta: { hasSuggestions: true },
+      create(context) {
+        const max = context.options[0]?.max ?? 3;
+        const check = (node) => {
+          if (node.params.length <= max) return;
+          context.report({
+            node,
+            message: `Function has too many parameters (${node.params.length}). Maximum allowed is ${max}.`,
+            suggest: [
+              {
+                desc: 'Remove the last parameter.',
+                fix: (fixer) => fixer.removeRange([node.params.at(-2).range[1], node.params.at(-1).range[1]]),
+              },
+            ],
+          });
+        };
+        return { 'FunctionDeclaration, ArrowFunctionExpression': check };
+      },
+    },
+    'no-todo-comments': {
+      create(context) {
+        const { sourceCode } = context;
+        return {
+          'Program:exit'() {
+            for (const comment of sourceCode.getAllComments()) {
+              if (comment.value.includes('TODO')) {
+                context.report({
+                  loc: {
+                    start: sourceCode.getLocFromIndex(comment.range[0]),
+                    end: sourceCode.getLocFromIndex(comment.range[1]),
+                  },
+                  message: `Unexpected TODO comment: ${comment.value.trim()}`,
+                });
+              }
+            }
+          },
+        };
+      },
+    },
+  },
+};
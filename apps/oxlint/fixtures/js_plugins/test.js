@@ -0,0 +1,9 @@
+// TODO: rename
+const ñame = 'ü'; foo(ñame);
+
+function add(a, b) {
+  return a + b;
+}
+
+// eslint-disable-next-line local/no-foo
+foo();
@@ -0,0 +1,10 @@
+async function fetchData(): Promise<string> {
+  return 'data';
+}
+
+async function main() {
+  fetchData();
+  await 'value';
+}
+
+void main();
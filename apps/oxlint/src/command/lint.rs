@@ -35,6 +35,18 @@ pub struct LintCommand {
     #[bpaf(external)]
     pub output_options: OutputOptions,
 
+    #[bpaf(external)]
+    pub cache_options: CacheOptions,
+
+    #[bpaf(external)]
+    pub baseline_options: BaselineOptions,
+
+    #[bpaf(external, guard(validate_changed_options, CHANGED_LINES_ONLY_ERROR_MESSAGE))]
+    pub changed_options: ChangedOptions,
+
+    #[bpaf(external)]
+    pub stdin_options: StdinOptions,
+
     /// list all the rules that are currently registered
     #[bpaf(long("rules"), switch, hide_usage)]
     pub list_rules: bool,
@@ -46,6 +58,11 @@ pub struct LintCommand {
     #[bpaf(switch, hide_usage)]
     pub disable_nested_config: bool,
 
+    /// Enables rules which need type information, such as `typescript/no-floating-promises`.
+    /// Types are inferred from each file on its own.
+    #[bpaf(switch, hide_usage)]
+    pub type_aware: bool,
+
     #[bpaf(external)]
     pub inline_config_options: InlineConfigOptions,
 
@@ -194,11 +211,76 @@ pub struct WarningOptions {
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
     /// Use a specific output format. Possible values:
-    /// `checkstyle`, `default`, `github`, `json`, `junit`, `stylish`, `unix`
+    /// `checkstyle`, `default`, `github`, `json`, `junit`, `sarif`, `stylish`, `unix`
     #[bpaf(long, short, fallback(OutputFormat::Default), hide_usage)]
     pub format: OutputFormat,
 }
 
+/// Caching
+#[derive(Debug, Clone, Bpaf)]
+pub struct CacheOptions {
+    /// Only lint files which changed since the last run with `--cache`,
+    /// and report the stored results for the others
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Path to the cache file, or to a directory to store it in. Defaults to `.oxlintcache`
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub cache_location: Option<PathBuf>,
+}
+
+/// Baseline
+#[derive(Debug, Clone, Bpaf)]
+pub struct BaselineOptions {
+    /// Only report diagnostics which are not recorded in the baseline file at PATH.
+    /// The file is created with the current diagnostics if it does not exist
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub baseline: Option<PathBuf>,
+
+    /// Record all current diagnostics of the linted files in the baseline file.
+    /// Defaults to `.oxlint-baseline.json` without `--baseline`
+    #[bpaf(switch, hide_usage)]
+    pub update_baseline: bool,
+
+    /// Remove entries of the linted files which no longer occur from the baseline file.
+    /// Defaults to `.oxlint-baseline.json` without `--baseline`
+    #[bpaf(switch, hide_usage)]
+    pub prune_baseline: bool,
+}
+
+/// Changed Files
+#[derive(Debug, Clone, Bpaf)]
+pub struct ChangedOptions {
+    /// Only lint files which changed since the git revision REV, including uncommitted and
+    /// untracked files. Files are compared with the merge base of REV and `HEAD`
+    #[bpaf(argument("REV"), hide_usage)]
+    pub changed_since: Option<String>,
+
+    /// With `--changed-since`, only report diagnostics on added or modified lines
+    #[bpaf(switch, hide_usage)]
+    pub changed_lines_only: bool,
+}
+
+const CHANGED_LINES_ONLY_ERROR_MESSAGE: &str = "`--changed-lines-only` requires `--changed-since`";
+
+fn validate_changed_options(options: &ChangedOptions) -> bool {
+    !options.changed_lines_only || options.changed_since.is_some()
+}
+
+/// Standard Input
+#[derive(Debug, Clone, Bpaf)]
+pub struct StdinOptions {
+    /// Lint the source text read from stdin instead of files.
+    /// With `--fix`, the fixed source text is written to stdout instead of the diagnostics
+    #[bpaf(switch, hide_usage)]
+    pub stdin: bool,
+
+    /// Path of the source text read from stdin, used to find its configuration and to detect its
+    /// language. The file does not need to exist. Defaults to `stdin.js`
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub stdin_filename: Option<PathBuf>,
+}
+
 /// Enable Plugins
 #[expect(clippy::struct_field_names)]
 #[derive(Debug, Default, Clone, Bpaf)]
@@ -546,6 +628,16 @@ mod lint_options {
         ));
     }
 
+    #[test]
+    fn changed_lines_only_without_changed_since() {
+        let options = get_lint_options("--changed-since main --changed-lines-only");
+        assert!(options.changed_options.changed_lines_only);
+        let args = ["--changed-lines-only".to_string()];
+        let result = lint_command().run_inner(args.as_slice());
+        assert!(result.is_err_and(|err| err.unwrap_stderr()
+            == "check failed: `--changed-lines-only` requires `--changed-since`"));
+    }
+
     #[test]
     fn list_rules() {
         let options = get_lint_options("--rules");
@@ -559,6 +651,14 @@ mod lint_options {
         let options = get_lint_options(".");
         assert!(!options.disable_nested_config);
     }
+
+    #[test]
+    fn type_aware() {
+        let options = get_lint_options("--type-aware");
+        assert!(options.type_aware);
+        let options = get_lint_options(".");
+        assert!(!options.type_aware);
+    }
 }
 
 #[cfg(test)]
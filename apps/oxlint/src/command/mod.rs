@@ -7,10 +7,13 @@ use bpaf::Bpaf;
 
 pub use self::{
     ignore::IgnoreOptions,
-    lint::{LintCommand, OutputOptions, ReportUnusedDirectives, WarningOptions, lint_command},
+    lint::{
+        BaselineOptions, CacheOptions, ChangedOptions, LintCommand, OutputOptions,
+        ReportUnusedDirectives, StdinOptions, WarningOptions, lint_command,
+    },
 };
 
-const VERSION: &str = match option_env!("OXC_VERSION") {
+pub const VERSION: &str = match option_env!("OXC_VERSION") {
     Some(v) => v,
     None => "dev",
 };
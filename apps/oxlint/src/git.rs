@@ -0,0 +1,152 @@
+use std::{
+    ops::Range,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use rustc_hash::FxHashMap;
+
+/// Files in the working directory which changed since a git revision.
+pub struct Changes {
+    /// Paths relative to the working directory, with the ranges of added or modified lines
+    /// (counted from 1). `None` for untracked files.
+    files: FxHashMap<PathBuf, Option<Vec<Range<usize>>>>,
+}
+
+impl Changes {
+    /// Compare the working tree, including uncommitted and untracked files, with the merge base
+    /// of `revision` and `HEAD`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error message if `cwd` is not in a git repository, or `revision` is unknown.
+    pub fn since(cwd: &Path, revision: &str) -> Result<Self, String> {
+        let base = git(cwd, &["merge-base", revision, "HEAD"])?;
+        let diff = git(
+            cwd,
+            &[
+                "-c",
+                "core.quotePath=false",
+                "diff",
+                "--no-color",
+                "--no-ext-diff",
+                "--no-textconv",
+                "--unified=0",
+                "--diff-filter=d",
+                "--relative",
+                "--src-prefix=a/",
+                "--dst-prefix=b/",
+                base.trim(),
+                "--",
+            ],
+        )?;
+        let mut files = parse_diff(&diff);
+
+        let untracked = git(cwd, &["ls-files", "--others", "--exclude-standard", "-z"])?;
+        files.extend(
+            untracked.split('\0').filter(|path| !path.is_empty()).map(|path| (path.into(), None)),
+        );
+        Ok(Self { files })
+    }
+
+    /// Changed files, relative to the working directory.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.files.keys().map(PathBuf::as_path)
+    }
+
+    /// Ranges of added or modified lines of tracked files, relative to the working directory.
+    pub fn changed_lines(&self) -> FxHashMap<PathBuf, Vec<Range<usize>>> {
+        self.files.iter().filter_map(|(path, lines)| Some((path.clone(), lines.clone()?))).collect()
+    }
+}
+
+fn git(cwd: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .map_err(|err| format!("Failed to run git: {err}"))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Collect the added lines of each file from a diff with `--unified=0`.
+fn parse_diff(diff: &str) -> FxHashMap<PathBuf, Option<Vec<Range<usize>>>> {
+    let mut files = FxHashMap::<PathBuf, Option<Vec<Range<usize>>>>::default();
+    let mut current = None;
+    // Whether `line` is in the header of a file, rather than in a hunk.
+    let mut in_header = false;
+    for line in diff.lines() {
+        if line.starts_with("diff ") {
+            current = None;
+            in_header = true;
+        } else if let Some(path) = line.strip_prefix("+++ ").filter(|_| in_header) {
+            // Names with special characters are quoted, and names with spaces end with a tab.
+            let path = path.trim_end_matches('\t').trim_matches('"');
+            current = path.strip_prefix("b/").map(PathBuf::from);
+            if let Some(path) = &current {
+                files.insert(path.clone(), Some(vec![]));
+            }
+        } else if let Some(hunk) = line.strip_prefix("@@ ") {
+            in_header = false;
+            let Some(lines) = current.as_ref().and_then(|path| files.get_mut(path)?.as_mut())
+            else {
+                continue;
+            };
+            // `@@ -start[,count] +start[,count] @@`
+            let Some(added) = hunk.split(' ').find_map(|range| range.strip_prefix('+')) else {
+                continue;
+            };
+            let (start, count) = added.split_once(',').unwrap_or((added, "1"));
+            if let (Ok(start), Ok(count)) = (start.parse::<usize>(), count.parse::<usize>()) {
+                if count > 0 {
+                    lines.push(start..start + count);
+                }
+            }
+        }
+    }
+    files
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::parse_diff;
+
+    #[test]
+    #[expect(clippy::single_range_in_vec_init)]
+    fn test_parse_diff() {
+        let diff = "\
+diff --git a/a.js b/a.js
+index 0000000..1111111 100644
+--- a/a.js
++++ b/a.js
+@@ -1 +1 @@
+-debugger;
++debugger ;
+@@ -3,2 +2,0 @@
+-a;
+-b;
+@@ -10,0 +10,3 @@ function foo() {
+++++ b/a;
++b;
++c;
+diff --git a/bin.png b/bin.png
+Binary files a/bin.png and b/bin.png differ
+diff --git a/dir/new file.ts b/dir/new file.ts
+new file mode 100644
+--- /dev/null
++++ b/dir/new file.ts\t
+@@ -0,0 +1,2 @@
++let a;
++let b;
+";
+        let files = parse_diff(diff);
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[Path::new("a.js")], Some(vec![1..2, 10..13]));
+        assert_eq!(files[Path::new("dir/new file.ts")], Some(vec![1..3]));
+    }
+}
@@ -1,4 +1,5 @@
 mod command;
+mod git;
 mod lint;
 mod output_formatter;
 mod result;
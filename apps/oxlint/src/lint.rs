@@ -1,7 +1,8 @@
 use std::{
     env, fs,
-    io::{ErrorKind, Write},
+    io::{self, ErrorKind, Read, Write},
     path::{Path, PathBuf, absolute},
+    sync::{Arc, mpsc},
     time::Instant,
 };
 
@@ -9,15 +10,20 @@ use cow_utils::CowUtils;
 use ignore::{gitignore::Gitignore, overrides::OverrideBuilder};
 use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler, OxcDiagnostic};
 use oxc_linter::{
-    AllowWarnDeny, ConfigStore, ConfigStoreBuilder, InvalidFilterKind, LintFilter, LintOptions,
-    LintService, LintServiceOptions, Linter, Oxlintrc,
+    AllowWarnDeny, Baseline, BaselineMode, ConfigStore, ConfigStoreBuilder, FixKind,
+    InvalidFilterKind, LintFilter, LintOptions, LintService, LintServiceOptions, Linter, Oxlintrc,
 };
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde_json::Value;
 
 use crate::{
-    cli::{CliRunResult, LintCommand, MiscOptions, ReportUnusedDirectives, Runner, WarningOptions},
-    output_formatter::{LintCommandInfo, OutputFormatter},
+    cli::{
+        BaselineOptions, CacheOptions, CliRunResult, LintCommand, MiscOptions,
+        ReportUnusedDirectives, Runner, WarningOptions,
+    },
+    command::VERSION,
+    git::Changes,
+    output_formatter::{LintCommandInfo, OutputFormat, OutputFormatter},
     walk::Walk,
 };
 
@@ -25,18 +31,35 @@ use crate::{
 pub struct LintRunner {
     options: LintCommand,
     cwd: PathBuf,
+    /// Source text for `--stdin`, instead of reading it from stdin.
+    stdin: Option<String>,
 }
 
 impl Runner for LintRunner {
     type Options = LintCommand;
 
     fn new(options: Self::Options) -> Self {
-        Self { options, cwd: env::current_dir().expect("Failed to get current working directory") }
+        Self {
+            options,
+            cwd: env::current_dir().expect("Failed to get current working directory"),
+            stdin: None,
+        }
     }
 
     fn run(self, stdout: &mut dyn Write) -> CliRunResult {
+        self.run_with_stderr(stdout, &mut io::stderr())
+    }
+}
+
+impl LintRunner {
+    /// Like [`Runner::run`], writing the diagnostics of `--stdin --fix` to `stderr`.
+    pub(crate) fn run_with_stderr(
+        self,
+        stdout: &mut dyn Write,
+        stderr: &mut dyn Write,
+    ) -> CliRunResult {
         let format_str = self.options.output_options.format;
-        let output_formatter = OutputFormatter::new(format_str);
+        let output_formatter = OutputFormatter::new(format_str, &self.cwd);
 
         if self.options.list_rules {
             if let Some(output) = output_formatter.all_rules() {
@@ -54,8 +77,13 @@ impl Runner for LintRunner {
             ignore_options,
             fix_options,
             enable_plugins,
+            cache_options,
+            baseline_options,
+            changed_options,
+            stdin_options,
             misc_options,
             disable_nested_config,
+            type_aware,
             inline_config_options,
             ..
         } = self.options;
@@ -66,6 +94,44 @@ impl Runner for LintRunner {
             basic_options.config.is_none();
 
         let mut paths = paths;
+        let stdin_source = if stdin_options.stdin {
+            if !paths.is_empty() {
+                stdout
+                    .write_all(b"Paths cannot be used together with `--stdin`.\n")
+                    .or_else(Self::check_for_writer_error)
+                    .unwrap();
+                stdout.flush().unwrap();
+
+                return CliRunResult::InvalidOptionConfig;
+            }
+            let source_text = if let Some(source_text) = self.stdin {
+                source_text
+            } else {
+                let mut source_text = String::new();
+                if let Err(err) = io::stdin().read_to_string(&mut source_text) {
+                    stdout
+                        .write_all(format!("Failed to read from stdin.\n{err}\n").as_bytes())
+                        .or_else(Self::check_for_writer_error)
+                        .unwrap();
+                    stdout.flush().unwrap();
+
+                    return CliRunResult::InvalidOptionConfig;
+                }
+                source_text
+            };
+            // The virtual path is linted like a path given on the command line, so that it is
+            // ignored and configured like the file.
+            paths.push(
+                stdin_options
+                    .stdin_filename
+                    .unwrap_or_else(|| PathBuf::from(Self::DEFAULT_STDIN_FILENAME)),
+            );
+            Some(source_text)
+        } else {
+            None
+        };
+        // With `--stdin --fix`, stdout is the fixed source text.
+        let stdin_fix = stdin_source.is_some() && fix_options.is_enabled();
         let provided_path_count = paths.len();
         let now = Instant::now();
 
@@ -148,6 +214,15 @@ impl Runner for LintRunner {
             // If explicit paths were provided, but all have been
             // filtered, return early.
             if provided_path_count > 0 {
+                if let Some(source_text) = stdin_source.as_ref().filter(|_| stdin_fix) {
+                    stdout
+                        .write_all(source_text.as_bytes())
+                        .or_else(Self::check_for_writer_error)
+                        .unwrap();
+                    stdout.flush().unwrap();
+
+                    return CliRunResult::LintNoFilesFound;
+                }
                 if let Some(end) = output_formatter.lint_command_info(&LintCommandInfo {
                     number_of_files: 0,
                     number_of_rules: None,
@@ -164,8 +239,41 @@ impl Runner for LintRunner {
             paths.push(self.cwd.clone());
         }
 
-        let walker = Walk::new(&paths, &ignore_options, override_builder);
-        let paths = walker.paths();
+        let changes = match changed_options
+            .changed_since
+            .as_deref()
+            .map(|revision| Changes::since(&self.cwd, revision))
+            .transpose()
+        {
+            Ok(changes) => changes,
+            Err(err) => {
+                stdout
+                    .write_all(format!("Failed to get the changed files.\n{err}\n").as_bytes())
+                    .or_else(Self::check_for_writer_error)
+                    .unwrap();
+                stdout.flush().unwrap();
+
+                return CliRunResult::InvalidOptionConfig;
+            }
+        };
+
+        if changes.is_some() {
+            // Paths are still relative with `--no-ignore`, but changed files are compared by
+            // their absolute paths.
+            paths.iter_mut().for_each(|path| *path = self.cwd.join(&*path));
+        }
+        let stdin_path = stdin_source.as_ref().map(|_| self.cwd.join(&paths[0]));
+        let paths = if let Some(stdin_path) = &stdin_path {
+            // The file does not need to exist, so it is not walked.
+            vec![Arc::from(stdin_path.as_os_str())]
+        } else {
+            let mut walker = Walk::new(&paths, &ignore_options, override_builder);
+            if let Some(changes) = &changes {
+                walker =
+                    walker.with_files(changes.paths().map(|path| self.cwd.join(path)).collect());
+            }
+            walker.paths()
+        };
 
         let number_of_files = paths.len();
 
@@ -320,8 +428,42 @@ impl Runner for LintRunner {
         } else {
             config_builder.plugins().has_import()
         };
+        // The cache compares the files on disk, which are not what is linted with `--stdin`.
+        let cache_location = (cache_options.cache && stdin_source.is_none())
+            .then(|| Self::cache_location(&self.cwd, &cache_options));
         let mut options =
             LintServiceOptions::new(self.cwd, paths).with_cross_module(use_cross_module);
+        if let Some(cache_location) = cache_location {
+            options = options.with_cache(cache_location, VERSION);
+        }
+        if let (Some(path), Some(source_text)) = (&stdin_path, &stdin_source) {
+            options = options.with_source_text(path, source_text.clone());
+        }
+        if let Some(changes) = changes.as_ref().filter(|_| changed_options.changed_lines_only) {
+            options = options.with_changed_lines(changes.changed_lines());
+        }
+        if let Some((location, mode)) = Self::baseline(options.cwd(), &baseline_options) {
+            match Baseline::load(location, mode) {
+                Ok(baseline) => options = options.with_baseline(baseline),
+                Err(diagnostic) => {
+                    let handler = GraphicalReportHandler::new();
+                    let mut err = String::new();
+                    handler.render_report(&mut err, &diagnostic).unwrap();
+                    stdout
+                        .write_all(format!("Failed to load baseline file.\n{err}\n").as_bytes())
+                        .or_else(Self::check_for_writer_error)
+                        .unwrap();
+                    stdout.flush().unwrap();
+
+                    return CliRunResult::InvalidOptionConfig;
+                }
+            }
+        }
+        // SARIF reports include fixes, so compute them without writing them unless asked to.
+        let report_fixes = format_str == OutputFormat::Sarif && !fix_options.is_enabled();
+        let fix_kind =
+            if report_fixes { FixKind::SafeFixOrSuggestion } else { fix_options.fix_kind() };
+        options = options.with_report_fixes(report_fixes);
 
         let lint_config = match config_builder.build() {
             Ok(config) => config,
@@ -347,12 +489,14 @@ impl Runner for LintRunner {
 
         let linter = if use_nested_config {
             Linter::new_with_nested_configs(LintOptions::default(), lint_config, nested_configs)
-                .with_fix(fix_options.fix_kind())
+                .with_fix(fix_kind)
                 .with_report_unused_directives(report_unused_directives)
+                .with_type_aware(type_aware)
         } else {
             Linter::new(LintOptions::default(), lint_config)
-                .with_fix(fix_options.fix_kind())
+                .with_fix(fix_kind)
                 .with_report_unused_directives(report_unused_directives)
+                .with_type_aware(type_aware)
         };
 
         let tsconfig = basic_options.tsconfig;
@@ -377,17 +521,30 @@ impl Runner for LintRunner {
 
         let number_of_rules = lint_service.linter().number_of_rules();
 
+        let (tx_fixed, rx_fixed) = mpsc::channel();
         // Spawn linting in another thread so diagnostics can be printed immediately from diagnostic_service.run.
         rayon::spawn({
             let tx_error = diagnostic_service.sender().clone();
+            let stdin_path = stdin_path.filter(|_| stdin_fix);
             move || {
                 lint_service.run(&tx_error);
+                if let Some(path) = stdin_path {
+                    tx_fixed.send(lint_service.take_fixed_source_text(&path)).unwrap();
+                }
             }
         });
 
-        let diagnostic_result = diagnostic_service.run(stdout);
+        // With `--stdin --fix`, stdout is the fixed source text, so the remaining diagnostics
+        // are printed to stderr.
+        let diagnostic_result =
+            if stdin_fix { diagnostic_service.run(stderr) } else { diagnostic_service.run(stdout) };
 
-        if let Some(end) = output_formatter.lint_command_info(&LintCommandInfo {
+        if stdin_fix {
+            let fixed_source_text = rx_fixed.recv().unwrap();
+            let source_text = fixed_source_text.as_ref().or(stdin_source.as_ref()).unwrap();
+            stdout.write_all(source_text.as_bytes()).or_else(Self::check_for_writer_error).unwrap();
+            stdout.flush().unwrap();
+        } else if let Some(end) = output_formatter.lint_command_info(&LintCommandInfo {
             number_of_files,
             number_of_rules,
             threads_count: rayon::current_num_threads(),
@@ -411,6 +568,9 @@ impl Runner for LintRunner {
 
 impl LintRunner {
     const DEFAULT_OXLINTRC: &'static str = ".oxlintrc.json";
+    const DEFAULT_CACHE_FILE: &'static str = ".oxlintcache";
+    const DEFAULT_BASELINE_FILE: &'static str = ".oxlint-baseline.json";
+    const DEFAULT_STDIN_FILENAME: &'static str = "stdin.js";
 
     #[must_use]
     pub fn with_cwd(mut self, cwd: PathBuf) -> Self {
@@ -418,6 +578,38 @@ impl LintRunner {
         self
     }
 
+    /// Use `source_text` for `--stdin` instead of reading stdin.
+    #[must_use]
+    pub fn with_stdin(mut self, source_text: String) -> Self {
+        self.stdin = Some(source_text);
+        self
+    }
+
+    fn cache_location(cwd: &Path, cache_options: &CacheOptions) -> PathBuf {
+        let location = cache_options
+            .cache_location
+            .as_ref()
+            .map_or_else(|| cwd.join(Self::DEFAULT_CACHE_FILE), |location| cwd.join(location));
+        if location.is_dir() { location.join(Self::DEFAULT_CACHE_FILE) } else { location }
+    }
+
+    fn baseline(cwd: &Path, baseline_options: &BaselineOptions) -> Option<(PathBuf, BaselineMode)> {
+        let mode = if baseline_options.update_baseline {
+            BaselineMode::Update
+        } else if baseline_options.prune_baseline {
+            BaselineMode::Prune
+        } else if baseline_options.baseline.is_some() {
+            BaselineMode::Check
+        } else {
+            return None;
+        };
+        let location = baseline_options
+            .baseline
+            .as_ref()
+            .map_or_else(|| cwd.join(Self::DEFAULT_BASELINE_FILE), |location| cwd.join(location));
+        Some((location, mode))
+    }
+
     fn get_diagnostic_service(
         reporter: &OutputFormatter,
         warning_options: &WarningOptions,
@@ -792,6 +984,16 @@ mod test {
         Tester::new().test_and_snapshot(args);
     }
 
+    #[test]
+    fn lint_component_templates() {
+        let args = &[
+            "fixtures/vue/template.vue",
+            "fixtures/svelte/template.svelte",
+            "fixtures/astro/template.astro",
+        ];
+        Tester::new().test_and_snapshot(args);
+    }
+
     #[test]
     fn test_tsconfig_option() {
         // passed
@@ -1005,6 +1207,224 @@ mod test {
         Tester::new().with_cwd("fixtures/report_unused_directives".into()).test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_type_aware() {
+        let args_1 = &["-c", ".oxlintrc.json", "test.ts"];
+        let args_2 = &["-c", ".oxlintrc.json", "--type-aware", "test.ts"];
+        Tester::new()
+            .with_cwd("fixtures/type_aware".into())
+            .test_and_snapshot_multiple(&[args_1, args_2]);
+    }
+
+    #[test]
+    fn test_cache() {
+        use cow_utils::CowUtils;
+
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, source: &str| fs::write(dir.path().join(name), source).unwrap();
+        write(
+            ".oxlintrc.json",
+            r#"{ "plugins": ["import"], "rules": { "import/no-cycle": "error" } }"#,
+        );
+        write("a.js", "import { b } from './b';\nexport const a = b;\ndebugger;\n");
+        write("b.js", "import { c } from './c';\nexport const b = c;\n");
+        write("c.js", "export const c = 1;\n");
+
+        let tester = Tester::new().with_cwd(dir.path().to_path_buf());
+        let args = &["--cache", "--format", "unix"];
+        let output = tester.test_output(args);
+        assert!(output.contains("a.js:3:1: `debugger` statement is not allowed"));
+        assert_eq!(tester.test_output(args), output);
+
+        // Unchanged files are not linted again, their results come from the cache file.
+        let cache_file = dir.path().join(".oxlintcache");
+        let cache = fs::read_to_string(&cache_file).unwrap();
+        fs::write(
+            &cache_file,
+            cache.cow_replace("is not allowed", "is not allowed (cached)").as_ref(),
+        )
+        .unwrap();
+        assert!(tester.test_output(args).contains("is not allowed (cached)"));
+
+        // Only `c.js` changed, but it adds a cycle through the files importing it.
+        write("c.js", "import { a } from './a';\nexport const c = a;\n");
+        let output = tester.test_output(args);
+        assert_eq!(output.matches("Dependency cycle detected").count(), 3);
+        assert!(!output.contains("(cached)"));
+        assert_eq!(tester.test_output(args), output);
+
+        // The cache is not read without `--cache`, and not reused for a different configuration.
+        assert!(
+            !tester.test_output(&["--format", "unix", "-A", "no-debugger"]).contains("debugger")
+        );
+        assert!(
+            !tester
+                .test_output(&["--cache", "--format", "unix", "-A", "no-debugger"])
+                .contains("debugger")
+        );
+    }
+
+    #[test]
+    fn test_cache_js_plugins() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, source: &str| fs::write(dir.path().join(name), source).unwrap();
+        let plugin = |message: &str| {
+            format!(
+                "export default {{ rules: {{ 'no-program': {{ create: (context) => ({{ \
+                 Program: (node) => context.report({{ node, message: '{message}' }}) }}) }} }} }};"
+            )
+        };
+        write(
+            ".oxlintrc.json",
+            r#"{ "jsPlugins": ["./plugin.mjs"], "rules": { "plugin/no-program": "error" } }"#,
+        );
+        write("plugin.mjs", &plugin("first message"));
+        write("a.js", "a;\n");
+
+        let tester = Tester::new().with_cwd(dir.path().to_path_buf());
+        let args = &["--cache", "--format", "unix"];
+        assert!(tester.test_output(args).contains("first message"));
+
+        // Cached results are discarded when a JS plugin changes.
+        write("plugin.mjs", &plugin("second message"));
+        let output = tester.test_output(args);
+        assert!(output.contains("second message"));
+        assert!(!output.contains("first message"));
+    }
+
+    #[test]
+    fn test_baseline() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, source: &str| fs::write(dir.path().join(name), source).unwrap();
+        write(".oxlintrc.json", r#"{ "rules": { "no-debugger": "error" } }"#);
+        write("a.js", "debugger;\n");
+        write("b.js", "debugger;\n");
+
+        let tester = Tester::new().with_cwd(dir.path().to_path_buf());
+        let args = &["--baseline", "baseline.json", "--format", "unix"];
+        // The baseline file is created with the existing diagnostics, which are not reported.
+        assert!(!tester.test_output(args).contains("debugger"));
+        assert!(dir.path().join("baseline.json").is_file());
+        assert!(!tester.test_output(args).contains("debugger"));
+
+        // Moved code stays in the baseline, new occurrences are reported.
+        write("a.js", "\n\n  debugger;\n");
+        write("b.js", "debugger;\ndebugger;\n");
+        let output = tester.test_output(args);
+        assert!(!output.contains("a.js"));
+        assert_eq!(output.matches("b.js:").count(), 1);
+
+        // Pruning removes the entries which no longer occur.
+        write("a.js", "\n");
+        tester.test_output(&["--baseline", "baseline.json", "--prune-baseline"]);
+        write("a.js", "debugger;\n");
+        assert!(tester.test_output(args).contains("a.js:1:1: `debugger` statement is not allowed"));
+
+        // Updating records all current diagnostics.
+        tester.test_output(&["--baseline", "baseline.json", "--update-baseline"]);
+        assert!(!tester.test_output(args).contains("debugger"));
+    }
+
+    #[test]
+    fn test_changed_since() {
+        use std::process::Command;
+
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, source: &str| fs::write(dir.path().join(name), source).unwrap();
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .current_dir(dir.path())
+                .args(["-c", "user.name=oxlint", "-c", "user.email=oxlint@example.com"])
+                .args(args)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {args:?} failed");
+        };
+        write(".oxlintrc.json", r#"{ "rules": { "no-debugger": "error" } }"#);
+        write("a.js", "debugger;\n");
+        write("b.js", "debugger;\n");
+        git(&["init", "-q"]);
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "init"]);
+        git(&["tag", "base"]);
+
+        write("b.js", "debugger;\nb();\ndebugger;\n");
+        write("c.js", "debugger;\n");
+
+        let tester = Tester::new().with_cwd(dir.path().to_path_buf());
+        let output = tester.test_output(&["--changed-since", "base", "--format", "unix"]);
+        assert!(!output.contains("a.js"));
+        assert_eq!(output.matches("b.js:").count(), 2);
+        assert!(output.contains("c.js:1:1"));
+
+        let output = tester.test_output(&[
+            "--changed-since",
+            "base",
+            "--changed-lines-only",
+            "--format",
+            "unix",
+        ]);
+        assert!(!output.contains("a.js"));
+        assert!(!output.contains("b.js:1:1"));
+        assert!(output.contains("b.js:3:1"));
+        assert!(output.contains("c.js:1:1"));
+
+        assert!(
+            tester
+                .test_output(&["--changed-since", "unknown"])
+                .starts_with("Failed to get the changed files.")
+        );
+    }
+
+    #[test]
+    fn test_stdin() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join(".oxlintrc.json"),
+            r#"{
+                "rules": { "no-debugger": "error" },
+                "overrides": [{ "files": ["*.ts"], "rules": { "no-debugger": "off" } }]
+            }"#,
+        )
+        .unwrap();
+        let tester = Tester::new().with_cwd(dir.path().to_path_buf());
+
+        let source = "debugger;\nlet a: number = 1;\nconsole.log(a);\n";
+        let output = tester.test_output_with_stdin(&["--stdin", "--format", "unix"], "debugger;\n");
+        assert!(output.contains("stdin.js:1:1: `debugger` statement is not allowed"));
+        // The configuration and the language come from the virtual path.
+        let args = &["--stdin", "--stdin-filename", "src/test.ts", "--format", "unix"];
+        assert_eq!(tester.test_output_with_stdin(args, source), "");
+        let args = &["--stdin", "--stdin-filename", "src/test.js", "--format", "unix"];
+        assert!(tester.test_output_with_stdin(args, source).contains("src/test.js:2:6: "));
+        let args = &["--stdin", "--stdin-filename", "test.vue", "--format", "unix"];
+        let output = tester.test_output_with_stdin(args, "<script>\ndebugger;\n</script>\n");
+        assert!(output.contains("test.vue:2:1: `debugger` statement is not allowed"));
+
+        // The fixed source text is written to stdout instead of the file.
+        let args = &["--stdin", "--stdin-filename", "src/test.js", "--fix"];
+        assert_eq!(tester.test_output_with_stdin(args, "debugger;\nfoo();\n"), "\nfoo();\n");
+        assert_eq!(tester.test_output_with_stdin(args, "foo();\n"), "foo();\n");
+        assert!(!dir.path().join("src").exists());
+        // The remaining diagnostics are printed to stderr.
+        let args = &["--stdin", "--stdin-filename", "src/test.js", "--fix", "--format", "unix"];
+        let (output, stderr) =
+            tester.test_output_with_stdin_and_stderr(args, "debugger;\nif (x = 1) {}\n");
+        assert_eq!(output, "\nif (x = 1) {}\n");
+        assert!(stderr.contains("src/test.js:2:5: "), "{stderr}");
+        assert!(!stderr.contains("debugger"));
+
+        let output = tester.test_output_with_stdin(&["--stdin", "test.js"], "");
+        assert_eq!(output, "Paths cannot be used together with `--stdin`.\n");
+    }
+
+    #[test]
+    fn test_js_plugins() {
+        let args = &["-c", ".oxlintrc.json", "test.js"];
+        Tester::new().with_cwd("fixtures/js_plugins".into()).test_and_snapshot(args);
+    }
+
     #[test]
     fn test_adjust_ignore_patterns() {
         let base = PathBuf::from("/project/root");
@@ -3,16 +3,19 @@ mod default;
 mod github;
 mod json;
 mod junit;
+mod sarif;
 mod stylish;
 mod unix;
 mod xml_utils;
 
+use std::path::Path;
 use std::str::FromStr;
 use std::time::Duration;
 
 use checkstyle::CheckStyleOutputFormatter;
 use github::GithubOutputFormatter;
 use junit::JUnitOutputFormatter;
+use sarif::SarifOutputFormatter;
 use stylish::StylishOutputFormatter;
 use unix::UnixOutputFormatter;
 
@@ -31,6 +34,9 @@ pub enum OutputFormat {
     Checkstyle,
     Stylish,
     JUnit,
+    /// SARIF 2.1.0, for code scanning tools
+    /// <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+    Sarif,
 }
 
 impl FromStr for OutputFormat {
@@ -45,6 +51,7 @@ impl FromStr for OutputFormat {
             "github" => Ok(Self::Github),
             "stylish" => Ok(Self::Stylish),
             "junit" => Ok(Self::JUnit),
+            "sarif" => Ok(Self::Sarif),
             _ => Err(format!("'{s}' is not a known format")),
         }
     }
@@ -87,11 +94,12 @@ pub struct OutputFormatter {
 }
 
 impl OutputFormatter {
-    pub fn new(format: OutputFormat) -> Self {
-        Self { internal: Self::get_internal_formatter(format) }
+    /// `cwd` is the directory file names in diagnostics are relative to.
+    pub fn new(format: OutputFormat, cwd: &Path) -> Self {
+        Self { internal: Self::get_internal_formatter(format, cwd) }
     }
 
-    fn get_internal_formatter(format: OutputFormat) -> Box<dyn InternalFormatter> {
+    fn get_internal_formatter(format: OutputFormat, cwd: &Path) -> Box<dyn InternalFormatter> {
         match format {
             OutputFormat::Json => Box::<JsonOutputFormatter>::default(),
             OutputFormat::Checkstyle => Box::<CheckStyleOutputFormatter>::default(),
@@ -100,6 +108,7 @@ impl OutputFormatter {
             OutputFormat::Default => Box::new(DefaultOutputFormatter),
             OutputFormat::Stylish => Box::<StylishOutputFormatter>::default(),
             OutputFormat::JUnit => Box::<JUnitOutputFormatter>::default(),
+            OutputFormat::Sarif => Box::new(SarifOutputFormatter::new(cwd)),
         }
     }
 
@@ -172,4 +181,11 @@ mod test {
 
         Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
     }
+
+    #[test]
+    fn test_output_formatter_diagnostic_sarif() {
+        let args = &["--format=sarif", "test.js"];
+
+        Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
+    }
 }
@@ -0,0 +1,323 @@
+use std::{
+    fmt::Write,
+    path::{Component, Path, PathBuf},
+};
+
+use miette::{SourceCode, SourceSpan};
+use rustc_hash::FxHashMap;
+use serde_json::{Value, json};
+
+use oxc_diagnostics::{
+    Error, FixableDiagnostic, Severity,
+    reporter::{DiagnosticReporter, DiagnosticResult},
+};
+use oxc_linter::{plugin_name_to_prefix, rules::RULES};
+
+use crate::{command::VERSION, output_formatter::InternalFormatter};
+
+/// Base of the URIs of files relative to the working directory.
+const SRCROOT: &str = "%SRCROOT%";
+
+#[derive(Debug)]
+pub struct SarifOutputFormatter {
+    cwd: PathBuf,
+}
+
+impl SarifOutputFormatter {
+    pub fn new(cwd: &Path) -> Self {
+        Self { cwd: cwd.to_path_buf() }
+    }
+}
+
+impl InternalFormatter for SarifOutputFormatter {
+    fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
+        Box::new(SarifReporter { cwd: self.cwd.clone(), diagnostics: vec![] })
+    }
+}
+
+/// Renders reports as a SARIF 2.1.0 log with a single run.
+///
+/// Columns are counted in UTF-16 code units, as declared by the run's `columnKind`.
+/// Files in the working directory have URIs relative to [`SRCROOT`], which is defined by the
+/// run's `originalUriBaseIds`, other files have absolute `file:` URIs.
+///
+/// SARIF specification: <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+struct SarifReporter {
+    cwd: PathBuf,
+    diagnostics: Vec<Error>,
+}
+
+impl DiagnosticReporter for SarifReporter {
+    fn finish(&mut self, _: &DiagnosticResult) -> Option<String> {
+        Some(format_sarif(&self.diagnostics, &self.cwd))
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.diagnostics.push(error);
+        None
+    }
+}
+
+fn format_sarif(diagnostics: &[Error], cwd: &Path) -> String {
+    let mut rules = vec![];
+    let mut rule_indices = FxHashMap::<String, usize>::default();
+    let results = diagnostics
+        .iter()
+        .map(|error| {
+            let mut result = json!({
+                "level": level(error.severity().unwrap_or(Severity::Error)),
+                "message": { "text": error.to_string() },
+            });
+            if let Some(code) = error.code().map(|code| code.to_string()) {
+                let index = *rule_indices.entry(code.clone()).or_insert_with(|| {
+                    rules.push(rule_descriptor(&code, error));
+                    rules.len() - 1
+                });
+                result["ruleId"] = json!(code);
+                result["ruleIndex"] = json!(index);
+            }
+            if let Some(location) = location(error) {
+                result["locations"] = json!([location]);
+            }
+            if let Some(fix) = fix(error) {
+                result["fixes"] = json!([fix]);
+            }
+            result
+        })
+        .collect::<Vec<_>>();
+
+    let log = json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "oxlint",
+                    "informationUri": "https://oxc.rs",
+                    "version": VERSION,
+                    "rules": rules,
+                },
+            },
+            "originalUriBaseIds": {
+                SRCROOT: { "uri": format!("{}/", file_uri(cwd).trim_end_matches('/')) },
+            },
+            "columnKind": "utf16CodeUnits",
+            "results": results,
+        }],
+    });
+    let mut output = serde_json::to_string_pretty(&log).expect("Failed to serialize");
+    output.push('\n');
+    output
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Advice => "note",
+    }
+}
+
+/// `reportingDescriptor` for the diagnostic code `id`, e.g. `eslint(no-debugger)`.
+fn rule_descriptor(id: &str, error: &Error) -> Value {
+    let mut descriptor = json!({ "id": id });
+    let rule = RULES.iter().find(|rule| {
+        let prefix = plugin_name_to_prefix(rule.plugin_name());
+        id.strip_prefix(prefix)
+            .and_then(|rest| rest.strip_prefix('('))
+            .and_then(|rest| rest.strip_suffix(')'))
+            .is_some_and(|name| name == rule.name())
+    });
+    if let Some(rule) = rule {
+        descriptor["name"] = json!(rule.name());
+        if let Some(documentation) = rule.documentation() {
+            descriptor["fullDescription"] = json!({ "text": documentation.trim() });
+        }
+        descriptor["properties"] = json!({
+            "plugin": rule.plugin_name(),
+            "category": rule.category(),
+        });
+    }
+    if let Some(url) = error.url() {
+        descriptor["helpUri"] = json!(url.to_string());
+    }
+    descriptor
+}
+
+/// `location` of the primary (or first) label.
+fn location(error: &Error) -> Option<Value> {
+    let source = error.source_code()?;
+    let label = error
+        .labels()?
+        .reduce(|first, label| if label.primary() && !first.primary() { label } else { first })?;
+    let name = source.read_span(label.inner(), 0, 0).ok()?.name()?.to_string();
+    Some(json!({
+        "physicalLocation": {
+            "artifactLocation": artifact_location(&name),
+            "region": region(source, label.offset(), label.offset() + label.len())?,
+        },
+    }))
+}
+
+/// `fix` for diagnostics of rules which provide one.
+fn fix(error: &Error) -> Option<Value> {
+    let diagnostic = error.downcast_ref::<FixableDiagnostic>()?;
+    let source = error.source_code()?;
+    let fix = diagnostic.fix();
+    let (start, end) = (fix.start as usize, fix.end as usize);
+    let name = source.read_span(&SourceSpan::from((start, 0)), 0, 0).ok()?.name()?.to_string();
+    let mut value = json!({
+        "artifactChanges": [{
+            "artifactLocation": artifact_location(&name),
+            "replacements": [{
+                "deletedRegion": region(source, start, end)?,
+                "insertedContent": { "text": fix.content },
+            }],
+        }],
+    });
+    if let Some(message) = &fix.message {
+        value["description"] = json!({ "text": message });
+    }
+    Some(value)
+}
+
+/// `artifactLocation` of a file name in diagnostics, which is relative to the working directory
+/// or absolute.
+fn artifact_location(name: &str) -> Value {
+    let path = Path::new(name);
+    if path.is_absolute() {
+        json!({ "uri": file_uri(path) })
+    } else {
+        json!({ "uri": encode_path(path), "uriBaseId": SRCROOT })
+    }
+}
+
+/// `file:` URI of an absolute path, e.g. `file:///home/user/project` or `file:///C:/project`.
+fn file_uri(path: &Path) -> String {
+    let path = encode_path(path);
+    if path.starts_with('/') { format!("file://{path}") } else { format!("file:///{path}") }
+}
+
+/// Percent-encode the components of `path`, separated by `/`.
+fn encode_path(path: &Path) -> String {
+    let mut uri = String::new();
+    for component in path.components() {
+        match component {
+            // Windows drive letters, e.g. `C:`, are kept as is.
+            Component::Prefix(prefix) => uri.push_str(&prefix.as_os_str().to_string_lossy()),
+            Component::RootDir => uri.push('/'),
+            Component::CurDir => {}
+            Component::ParentDir | Component::Normal(_) => {
+                if !uri.is_empty() && !uri.ends_with('/') {
+                    uri.push('/');
+                }
+                for byte in component.as_os_str().to_string_lossy().bytes() {
+                    if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+                        uri.push(char::from(byte));
+                    } else {
+                        let _ = write!(uri, "%{byte:02X}");
+                    }
+                }
+            }
+        }
+    }
+    uri
+}
+
+fn region(source: &dyn SourceCode, start: usize, end: usize) -> Option<Value> {
+    let (start_line, start_column) = position(source, start)?;
+    let (end_line, end_column) = position(source, end)?;
+    Some(json!({
+        "startLine": start_line,
+        "startColumn": start_column,
+        "endLine": end_line,
+        "endColumn": end_column,
+    }))
+}
+
+/// 1-based line and UTF-16 column of the byte `offset`.
+fn position(source: &dyn SourceCode, offset: usize) -> Option<(usize, usize)> {
+    let contents = source.read_span(&SourceSpan::from((offset, 0)), 0, 0).ok()?;
+    let line = contents.line() + 1;
+    // `column` counts bytes, so re-read the start of the line to count UTF-16 code units.
+    let byte_column = contents.column();
+    if byte_column == 0 {
+        return Some((line, 1));
+    }
+    let prefix =
+        source.read_span(&SourceSpan::from((offset - byte_column, byte_column)), 0, 0).ok()?;
+    let column = String::from_utf8_lossy(prefix.data()).encode_utf16().count();
+    Some((line, column + 1))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use oxc_diagnostics::{
+        NamedSource, OxcDiagnostic,
+        reporter::{DiagnosticReporter, DiagnosticResult},
+    };
+    use oxc_span::Span;
+
+    use super::{SarifReporter, file_uri};
+
+    fn report(file_name: &str) -> serde_json::Value {
+        let cwd = if cfg!(windows) { "C:\\project" } else { "/project" };
+        let mut reporter = SarifReporter { cwd: PathBuf::from(cwd), diagnostics: vec![] };
+
+        let error = OxcDiagnostic::warn("error message")
+            .with_label(Span::new(8, 16))
+            .with_source_code(NamedSource::new(file_name, "'😀'; debugger;"));
+
+        let first_result = reporter.render_error(error);
+
+        // reporter keeps it in memory
+        assert!(first_result.is_none());
+
+        // report not gives us all diagnostics at ones
+        let second_result = reporter.finish(&DiagnosticResult::default());
+
+        assert!(second_result.is_some());
+        serde_json::from_str(&second_result.unwrap()).unwrap()
+    }
+
+    #[test]
+    fn reporter() {
+        let log = report("test.ts");
+        let run = &log["runs"][0];
+        let result = &run["results"][0];
+        assert_eq!(result["level"], "warning");
+        assert_eq!(result["message"]["text"], "error message");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(
+            location["artifactLocation"],
+            serde_json::json!({ "uri": "test.ts", "uriBaseId": "%SRCROOT%" })
+        );
+        assert_eq!(
+            location["region"],
+            serde_json::json!({ "startLine": 1, "startColumn": 7, "endLine": 1, "endColumn": 15 })
+        );
+        let srcroot = if cfg!(windows) { "file:///C:/project/" } else { "file:///project/" };
+        assert_eq!(run["originalUriBaseIds"]["%SRCROOT%"]["uri"], srcroot);
+    }
+
+    #[test]
+    fn uri_encoding() {
+        let log = report("src/my file ü#1.ts");
+        let location = &log["runs"][0]["results"][0]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/my%20file%20%C3%BC%231.ts");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn absolute_uri() {
+        let log = report("/other/a b.ts");
+        let location = &log["runs"][0]["results"][0]["locations"][0]["physicalLocation"];
+        assert_eq!(
+            location["artifactLocation"],
+            serde_json::json!({ "uri": "file:///other/a%20b.ts" })
+        );
+        assert_eq!(file_uri(Path::new("/")), "file:///");
+    }
+}
@@ -40,6 +40,41 @@ impl Tester {
         let _ = LintRunner::new(options).with_cwd(self.cwd.clone()).run(&mut output);
     }
 
+    /// Returns the output of running with `args`, for tests comparing the output of several runs.
+    pub fn test_output(&self, args: &[&str]) -> String {
+        let options = lint_command().run_inner(args).unwrap();
+        let mut output = Vec::new();
+        let _ = LintRunner::new(options).with_cwd(self.cwd.clone()).run(&mut output);
+        String::from_utf8(output).unwrap()
+    }
+
+    /// Like [`Tester::test_output`], with `stdin` as the source text for `--stdin`.
+    pub fn test_output_with_stdin(&self, args: &[&str], stdin: &str) -> String {
+        let options = lint_command().run_inner(args).unwrap();
+        let mut output = Vec::new();
+        let _ = LintRunner::new(options)
+            .with_cwd(self.cwd.clone())
+            .with_stdin(stdin.to_string())
+            .run(&mut output);
+        String::from_utf8(output).unwrap()
+    }
+
+    /// Like [`Tester::test_output_with_stdin`], also returning what is written to stderr.
+    pub fn test_output_with_stdin_and_stderr(
+        &self,
+        args: &[&str],
+        stdin: &str,
+    ) -> (String, String) {
+        let options = lint_command().run_inner(args).unwrap();
+        let mut output = Vec::new();
+        let mut stderr = Vec::new();
+        let _ = LintRunner::new(options)
+            .with_cwd(self.cwd.clone())
+            .with_stdin(stdin.to_string())
+            .run_with_stderr(&mut output, &mut stderr);
+        (String::from_utf8(output).unwrap(), String::from_utf8(stderr).unwrap())
+    }
+
     pub fn test_and_snapshot(&self, args: &[&str]) {
         self.test_and_snapshot_multiple(&[args]);
     }
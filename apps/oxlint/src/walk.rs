@@ -2,6 +2,7 @@ use std::{ffi::OsStr, path::PathBuf, sync::Arc, sync::mpsc};
 
 use ignore::{DirEntry, overrides::Override};
 use oxc_linter::LINTABLE_EXTENSIONS;
+use rustc_hash::FxHashSet;
 
 use crate::cli::IgnoreOptions;
 
@@ -18,11 +19,14 @@ pub struct Walk {
     inner: ignore::WalkParallel,
     /// The file extensions to include during the traversal.
     extensions: Extensions,
+    /// If set, only these files are included.
+    files: Option<Arc<FxHashSet<PathBuf>>>,
 }
 
 struct WalkBuilder {
     sender: mpsc::Sender<Vec<Arc<OsStr>>>,
     extensions: Extensions,
+    files: Option<Arc<FxHashSet<PathBuf>>>,
 }
 
 impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
@@ -31,6 +35,7 @@ impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
             paths: vec![],
             sender: self.sender.clone(),
             extensions: self.extensions.clone(),
+            files: self.files.clone(),
         })
     }
 }
@@ -39,6 +44,7 @@ struct WalkCollector {
     paths: Vec<Arc<OsStr>>,
     sender: mpsc::Sender<Vec<Arc<OsStr>>>,
     extensions: Extensions,
+    files: Option<Arc<FxHashSet<PathBuf>>>,
 }
 
 impl Drop for WalkCollector {
@@ -52,7 +58,9 @@ impl ignore::ParallelVisitor for WalkCollector {
     fn visit(&mut self, entry: Result<ignore::DirEntry, ignore::Error>) -> ignore::WalkState {
         match entry {
             Ok(entry) => {
-                if Walk::is_wanted_entry(&entry, &self.extensions) {
+                if Walk::is_wanted_entry(&entry, &self.extensions)
+                    && self.files.as_ref().is_none_or(|files| files.contains(entry.path()))
+                {
                     self.paths.push(entry.path().as_os_str().into());
                 }
                 ignore::WalkState::Continue
@@ -97,12 +105,12 @@ impl Walk {
         // * it is super rare to have symlinked source code
         let inner =
             inner.ignore(false).git_global(false).follow_links(options.symlinks).build_parallel();
-        Self { inner, extensions: Extensions::default() }
+        Self { inner, extensions: Extensions::default(), files: None }
     }
 
     pub fn paths(self) -> Vec<Arc<OsStr>> {
         let (sender, receiver) = mpsc::channel::<Vec<Arc<OsStr>>>();
-        let mut builder = WalkBuilder { sender, extensions: self.extensions };
+        let mut builder = WalkBuilder { sender, extensions: self.extensions, files: self.files };
         self.inner.visit(&mut builder);
         drop(builder);
         receiver.into_iter().flatten().collect()
@@ -114,6 +122,13 @@ impl Walk {
         self
     }
 
+    /// Only include `files`, e.g. the files changed since a git revision.
+    /// They must have the same prefix as the paths to walk.
+    pub fn with_files(mut self, files: FxHashSet<PathBuf>) -> Self {
+        self.files = Some(Arc::new(files));
+        self
+    }
+
     fn is_wanted_entry(dir_entry: &DirEntry, extensions: &Extensions) -> bool {
         let Some(file_type) = dir_entry.file_type() else { return false };
         if file_type.is_dir() {
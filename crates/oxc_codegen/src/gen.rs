@@ -1637,7 +1637,11 @@ impl Gen for ObjectProperty<'_> {
             if key.name == "__proto__" {
                 shorthand = self.shorthand;
             } else if let Expression::Identifier(ident) = self.value.without_parentheses() {
-                if key.name == p.get_identifier_reference_name(ident) {
+                // `{ a: a }` is only printed as `{ a }` when minifying, as shorthand properties
+                // are not valid in ES5
+                if (self.shorthand || p.options.minify)
+                    && key.name == p.get_identifier_reference_name(ident)
+                {
                     shorthand = true;
                 }
             }
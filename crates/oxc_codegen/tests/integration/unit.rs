@@ -130,7 +130,8 @@ fn if_stmt() {
 fn shorthand() {
     test("let _ = { x }", "let _ = { x };\n");
     test("let { x } = y", "let { x } = y;\n");
-    test("({ x: (x) })", "({ x });\n");
+    test("({ x: (x) })", "({ x: x });\n");
+    test_minify("({ x: (x) })", "({x});");
     test("({ x } = y)", "({x} = y);\n");
     // https://github.com/tc39/test262/blob/05c45a4c430ab6fee3e0c7f0d47d8a30d8876a6d/test/language/expressions/object/__proto__-permitted-dup-shorthand.js
     test("var obj = { __proto__, __proto__, };", "var obj = {\n\t__proto__,\n\t__proto__\n};\n");
@@ -0,0 +1,84 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    sync::Arc,
+};
+
+use miette::{Diagnostic, LabeledSpan, NamedSource, Severity, SourceCode};
+
+use crate::OxcDiagnostic;
+
+/// A fix for the problem reported by a diagnostic: replace `start..end` of the source text with
+/// `content`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DiagnosticFix {
+    pub start: u32,
+    pub end: u32,
+    pub content: Cow<'static, str>,
+    /// A brief description of the fix.
+    pub message: Option<Cow<'static, str>>,
+}
+
+/// An [`OxcDiagnostic`] with its source code and a fix.
+///
+/// Created by [`DiagnosticService::wrap_diagnostics_with_fixes`](crate::DiagnosticService::wrap_diagnostics_with_fixes).
+/// Reporters can get it back from an [`Error`](crate::Error) with `error.downcast_ref::<FixableDiagnostic>()`.
+#[derive(Debug)]
+pub struct FixableDiagnostic {
+    diagnostic: OxcDiagnostic,
+    source_code: Arc<NamedSource<String>>,
+    fix: DiagnosticFix,
+}
+
+impl FixableDiagnostic {
+    pub fn new(
+        diagnostic: OxcDiagnostic,
+        source_code: Arc<NamedSource<String>>,
+        fix: DiagnosticFix,
+    ) -> Self {
+        Self { diagnostic, source_code, fix }
+    }
+
+    pub fn diagnostic(&self) -> &OxcDiagnostic {
+        &self.diagnostic
+    }
+
+    /// The fix, with offsets into the whole source text.
+    pub fn fix(&self) -> &DiagnosticFix {
+        &self.fix
+    }
+}
+
+impl Display for FixableDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.diagnostic.fmt(f)
+    }
+}
+
+impl std::error::Error for FixableDiagnostic {}
+
+impl Diagnostic for FixableDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&*self.source_code)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+}
@@ -48,6 +48,7 @@
 //! service.run();
 //! ```
 
+mod fix;
 mod service;
 
 use std::{
@@ -58,7 +59,10 @@ use std::{
 
 pub mod reporter;
 
-pub use crate::service::{DiagnosticSender, DiagnosticService, DiagnosticTuple};
+pub use crate::{
+    fix::{DiagnosticFix, FixableDiagnostic},
+    service::{DiagnosticSender, DiagnosticService, DiagnosticTuple},
+};
 
 pub type Error = miette::Error;
 pub type Severity = miette::Severity;
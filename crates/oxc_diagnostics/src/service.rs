@@ -8,7 +8,7 @@ use cow_utils::CowUtils;
 use miette::LabeledSpan;
 
 use crate::{
-    Error, NamedSource, OxcDiagnostic, Severity,
+    DiagnosticFix, Error, FixableDiagnostic, NamedSource, OxcDiagnostic, Severity,
     reporter::{DiagnosticReporter, DiagnosticResult},
 };
 
@@ -133,6 +133,24 @@ impl DiagnosticService {
         source_text: &str,
         source_start: u32,
         diagnostics: Vec<OxcDiagnostic>,
+    ) -> (PathBuf, Vec<Error>) {
+        Self::wrap_diagnostics_with_fixes(
+            path,
+            source_text,
+            source_start,
+            diagnostics.into_iter().map(|diagnostic| (diagnostic, None)).collect(),
+        )
+    }
+
+    /// Like [`wrap_diagnostics`](DiagnosticService::wrap_diagnostics), but diagnostics with a
+    /// fix are wrapped into a [`FixableDiagnostic`], so reporters can output the fix.
+    ///
+    /// Like labels, fixes are relative to `source_start`.
+    pub fn wrap_diagnostics_with_fixes<P: AsRef<Path>>(
+        path: P,
+        source_text: &str,
+        source_start: u32,
+        diagnostics: Vec<(OxcDiagnostic, Option<DiagnosticFix>)>,
     ) -> (PathBuf, Vec<Error>) {
         let path = path.as_ref();
         let path_display = path.to_string_lossy();
@@ -143,14 +161,9 @@ impl DiagnosticService {
         let source = Arc::new(NamedSource::new(path_display, source_text.to_owned()));
         let diagnostics = diagnostics
             .into_iter()
-            .map(|diagnostic| {
-                if source_start == 0 {
-                    return diagnostic.with_source_code(Arc::clone(&source));
-                }
-
-                match &diagnostic.labels {
-                    None => diagnostic.with_source_code(Arc::clone(&source)),
-                    Some(labels) => {
+            .map(|(mut diagnostic, fix)| {
+                if source_start != 0 {
+                    if let Some(labels) = &diagnostic.labels {
                         let new_labels = labels
                             .iter()
                             .map(|labeled_span| {
@@ -161,10 +174,22 @@ impl DiagnosticService {
                                 )
                             })
                             .collect::<Vec<_>>();
-
-                        diagnostic.with_labels(new_labels).with_source_code(Arc::clone(&source))
+                        diagnostic = diagnostic.with_labels(new_labels);
                     }
                 }
+
+                match fix {
+                    None => diagnostic.with_source_code(Arc::clone(&source)),
+                    Some(fix) => Error::new(FixableDiagnostic::new(
+                        diagnostic,
+                        Arc::clone(&source),
+                        DiagnosticFix {
+                            start: fix.start + source_start,
+                            end: fix.end + source_start,
+                            ..fix
+                        },
+                    )),
+                }
             })
             .collect();
         (path.to_path_buf(), diagnostics)
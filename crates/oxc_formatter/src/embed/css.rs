@@ -0,0 +1,394 @@
+use super::{EmbeddedFormatter, PLACEHOLDER_PREFIX, indent_unit};
+use crate::options::FormatOptions;
+
+/// A formatter for the CSS in `` styled.div`...` `` and `` css`...` `` template literals.
+///
+/// Declarations, rules and at-rules are printed one per line and nested blocks are indented.
+/// Selector lists are split over multiple lines, and whitespace in values is normalized.
+/// Comments and strings are kept as is.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CssFormatter;
+
+impl EmbeddedFormatter for CssFormatter {
+    fn format(&self, source_text: &str, options: &FormatOptions) -> Option<String> {
+        let mut printer =
+            CssPrinter { source_text, pos: 0, indent: indent_unit(options), code: String::new() };
+        printer.print_block(0)?;
+        Some(printer.code)
+    }
+}
+
+struct CssPrinter<'s> {
+    source_text: &'s str,
+    pos: usize,
+    indent: String,
+    code: String,
+}
+
+/// How an item, such as a declaration or a rule, ends.
+enum ItemEnd {
+    /// `;`
+    Semicolon,
+    /// `{`
+    Block,
+    /// `}` or the end of the source text, which is not consumed.
+    BlockEnd,
+    /// A line break after a placeholder, e.g. `${mixin}`.
+    LineBreak,
+}
+
+impl<'s> CssPrinter<'s> {
+    /// Prints the items of a block until the closing `}`, or until the end of the source text
+    /// if `depth` is 0.
+    fn print_block(&mut self, depth: usize) -> Option<()> {
+        let mut first = true;
+        loop {
+            let whitespace = self.skip_whitespace();
+            let rest = &self.source_text[self.pos..];
+            if rest.is_empty() {
+                return (depth == 0).then_some(());
+            }
+            if rest.starts_with('}') {
+                self.pos += 1;
+                return (depth > 0).then_some(());
+            }
+            if !first && whitespace.matches('\n').count() > 1 {
+                self.code.push('\n');
+            }
+            first = false;
+
+            if rest.starts_with("/*") {
+                let end = rest.find("*/")? + 2;
+                self.push_line(depth, &rest[..end]);
+                self.pos += end;
+                continue;
+            }
+            if rest.starts_with("//") {
+                let end = rest.find('\n').unwrap_or(rest.len());
+                self.push_line(depth, rest[..end].trim_end());
+                self.pos += end;
+                continue;
+            }
+
+            let (text, end) = self.scan_item()?;
+            let text = text.trim();
+            match end {
+                ItemEnd::Block => {
+                    let head = if text.starts_with('@') {
+                        format_at_rule(text)
+                    } else {
+                        format_selector(text, &self.indent.repeat(depth))
+                    };
+                    self.push_line(depth, &format!("{head} {{"));
+                    self.print_block(depth + 1)?;
+                    self.push_line(depth, "}");
+                }
+                ItemEnd::Semicolon | ItemEnd::BlockEnd if text.is_empty() => {}
+                ItemEnd::Semicolon | ItemEnd::BlockEnd => {
+                    let statement = if text.starts_with('@') {
+                        format_at_rule(text)
+                    } else {
+                        format_declaration(text)
+                    };
+                    self.push_line(depth, &format!("{statement};"));
+                }
+                ItemEnd::LineBreak => self.push_line(depth, text),
+            }
+        }
+    }
+
+    /// Scans an item from the current position, and consumes its `;` or `{`.
+    fn scan_item(&mut self) -> Option<(&'s str, ItemEnd)> {
+        let source_text = self.source_text;
+        let rest = &source_text[self.pos..];
+
+        // A placeholder on its own line, e.g. `${mixin}`.
+        if let Some(after_prefix) = rest.strip_prefix(PLACEHOLDER_PREFIX) {
+            let len = PLACEHOLDER_PREFIX.len()
+                + after_prefix
+                    .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                    .unwrap_or(after_prefix.len());
+            let after = rest[len..].trim_start_matches([' ', '\t']);
+            if after.is_empty() || after.starts_with(['\n', '\r', '}']) {
+                self.pos += len;
+                return Some((&rest[..len], ItemEnd::LineBreak));
+            }
+        }
+
+        let bytes = rest.as_bytes();
+        let mut parens = 0usize;
+        let mut i = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                quote @ (b'"' | b'\'') => i = skip_string(bytes, i, quote)?,
+                b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                    i += rest[i..].find("*/")? + 1;
+                }
+                b'(' | b'[' => parens += 1,
+                b')' | b']' => parens = parens.checked_sub(1)?,
+                b';' if parens == 0 => {
+                    self.pos += i + 1;
+                    return Some((&rest[..i], ItemEnd::Semicolon));
+                }
+                b'{' if parens == 0 => {
+                    self.pos += i + 1;
+                    return Some((&rest[..i], ItemEnd::Block));
+                }
+                b'}' if parens == 0 => {
+                    self.pos += i;
+                    return Some((&rest[..i], ItemEnd::BlockEnd));
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+        if parens > 0 {
+            return None;
+        }
+        self.pos += rest.len();
+        Some((rest, ItemEnd::BlockEnd))
+    }
+
+    /// Skips whitespace, and returns it.
+    fn skip_whitespace(&mut self) -> &'s str {
+        let source_text = self.source_text;
+        let rest = &source_text[self.pos..];
+        let len = rest.len() - rest.trim_start().len();
+        self.pos += len;
+        &rest[..len]
+    }
+
+    fn push_line(&mut self, depth: usize, line: &str) {
+        if !self.code.is_empty() {
+            self.code.push('\n');
+        }
+        for _ in 0..depth {
+            self.code.push_str(&self.indent);
+        }
+        self.code.push_str(line);
+    }
+}
+
+/// Returns the index of the closing quote of the string starting at `start`.
+fn skip_string(bytes: &[u8], start: usize, quote: u8) -> Option<usize> {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b'\n' => return None,
+            b if b == quote => return Some(i),
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// `color:red` -> `color: red`
+fn format_declaration(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut parens = 0usize;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'"' | b'\'') => match skip_string(bytes, i, quote) {
+                Some(end) => i = end,
+                None => break,
+            },
+            b'(' | b'[' => parens += 1,
+            b')' | b']' => parens = parens.saturating_sub(1),
+            b':' if parens == 0 => {
+                let property = text[..i].trim_end();
+                let value = &text[i + 1..];
+                // Custom properties can contain anything.
+                if property.starts_with("--") {
+                    return format!("{property}: {}", value.trim());
+                }
+                return format!("{property}: {}", format_value(value));
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    collapse_whitespace(text)
+}
+
+/// Collapses whitespace, and puts a space after commas and before `!important`.
+/// Strings, comments and `url()` are kept as is.
+fn format_value(text: &str) -> String {
+    let mut value = String::with_capacity(text.len());
+    let mut pending_space = false;
+    let mut rest = text.trim();
+    while let Some(c) = rest.chars().next() {
+        let verbatim_len = if c == '"' || c == '\'' {
+            skip_string(rest.as_bytes(), 0, c as u8).map(|end| end + 1)
+        } else if rest.starts_with("/*") {
+            rest.find("*/").map(|end| end + 2)
+        } else if rest.len() >= 4 && rest[..4].eq_ignore_ascii_case("url(") {
+            rest.find(')').map(|end| end + 1)
+        } else {
+            None
+        };
+        if let Some(len) = verbatim_len {
+            if pending_space {
+                value.push(' ');
+                pending_space = false;
+            }
+            value.push_str(&rest[..len]);
+            rest = &rest[len..];
+            continue;
+        }
+        match c {
+            c if c.is_whitespace() => pending_space = !value.is_empty(),
+            ',' => {
+                value.push(',');
+                pending_space = true;
+            }
+            '!' => {
+                if !value.is_empty() {
+                    value.push(' ');
+                }
+                value.push('!');
+                pending_space = false;
+            }
+            c => {
+                if pending_space {
+                    value.push(' ');
+                    pending_space = false;
+                }
+                value.push(c);
+            }
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+    value
+}
+
+/// Puts each selector of a selector list on its own line, and spaces around combinators.
+fn format_selector(text: &str, indent: &str) -> String {
+    let mut selectors = vec![];
+    let mut selector = String::new();
+    let mut pending_space = false;
+    let mut parens = 0usize;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' | '\'' => {
+                let end = skip_string(text.as_bytes(), i, c as u8).unwrap_or(text.len() - 1);
+                if pending_space {
+                    selector.push(' ');
+                    pending_space = false;
+                }
+                selector.push_str(&text[i..=end]);
+                while chars.peek().is_some_and(|(j, _)| *j <= end) {
+                    chars.next();
+                }
+            }
+            ',' if parens == 0 => {
+                selectors.push(std::mem::take(&mut selector));
+                pending_space = false;
+            }
+            '>' | '+' | '~' if parens == 0 => {
+                if !selector.is_empty() {
+                    selector.push(' ');
+                }
+                selector.push(c);
+                pending_space = true;
+            }
+            c if c.is_whitespace() => pending_space = !selector.is_empty(),
+            c => {
+                if matches!(c, '(' | '[') {
+                    parens += 1;
+                } else if matches!(c, ')' | ']') {
+                    parens = parens.saturating_sub(1);
+                }
+                if pending_space {
+                    selector.push(' ');
+                    pending_space = false;
+                }
+                selector.push(c);
+            }
+        }
+    }
+    selectors.push(selector);
+    selectors.join(&format!(",\n{indent}"))
+}
+
+/// `@media (max-width:100px)` -> `@media (max-width: 100px)`
+fn format_at_rule(text: &str) -> String {
+    let collapsed = collapse_whitespace(text);
+    let mut at_rule = String::with_capacity(collapsed.len());
+    let mut parens = 0usize;
+    let mut chars = collapsed.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '(' => parens += 1,
+            ')' => parens = parens.saturating_sub(1),
+            _ => {}
+        }
+        at_rule.push(c);
+        if c == ':' && parens > 0 && chars.peek().is_some_and(|c| *c != ' ') {
+            at_rule.push(' ');
+        }
+    }
+    at_rule
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CssFormatter, EmbeddedFormatter};
+    use crate::options::FormatOptions;
+
+    fn format(source_text: &str) -> Option<String> {
+        CssFormatter.format(source_text, &FormatOptions::default())
+    }
+
+    #[test]
+    fn rules() {
+        assert_eq!(
+            format(
+                "
+  display:flex ;color : rgba(0,0,0,.5)!important;
+
+
+  &:hover,a>b{ color:red }
+  @media (max-width:100px){a{margin:0 auto}}
+  font-family: 'Foo  Bar',serif"
+            )
+            .unwrap(),
+            "display: flex;
+color: rgba(0, 0, 0, .5) !important;
+
+&:hover,
+a > b {
+  color: red;
+}
+@media (max-width: 100px) {
+  a {
+    margin: 0 auto;
+  }
+}
+font-family: 'Foo  Bar', serif;"
+        );
+    }
+
+    #[test]
+    fn placeholders() {
+        assert_eq!(
+            format("__oxc_placeholder_0__\n  color:__oxc_placeholder_1__;__oxc_placeholder_2__;")
+                .unwrap(),
+            "__oxc_placeholder_0__\ncolor: __oxc_placeholder_1__;\n__oxc_placeholder_2__;"
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(format("a { color: red;"), None);
+        assert_eq!(format("color: red; }"), None);
+        assert_eq!(format("content: \"foo"), None);
+    }
+}
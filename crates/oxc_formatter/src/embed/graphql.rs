@@ -0,0 +1,406 @@
+use super::{EmbeddedFormatter, PLACEHOLDER_PREFIX, indent_unit};
+use crate::options::FormatOptions;
+
+/// A formatter for the GraphQL in `` gql`...` `` and `` graphql`...` `` template literals.
+///
+/// Selections are printed one per line and selection sets are indented,
+/// while arguments, variable definitions, lists and objects are printed on one line.
+/// Blank lines between definitions and selections are kept.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GraphQLFormatter;
+
+impl EmbeddedFormatter for GraphQLFormatter {
+    fn format(&self, source_text: &str, options: &FormatOptions) -> Option<String> {
+        let tokens = tokenize(source_text)?;
+        let mut printer = GraphQLPrinter {
+            source_text,
+            indent: indent_unit(options),
+            code: String::new(),
+            stack: vec![],
+            line_start: true,
+        };
+        for i in 0..tokens.len() {
+            let prev = i.checked_sub(1).map(|i| &tokens[i]);
+            let prev_prev = i.checked_sub(2).map(|i| &tokens[i]);
+            printer.print_token(&tokens[i], prev, prev_prev)?;
+        }
+        printer.stack.is_empty().then_some(printer.code)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum TokenKind {
+    Name,
+    /// `$name`
+    Variable,
+    /// `@name`
+    Directive,
+    Number,
+    /// `"..."` and `"""..."""`
+    String,
+    /// `...`
+    Spread,
+    /// `# ...`
+    Comment,
+    Punctuator,
+}
+
+#[derive(Debug)]
+struct Token<'s> {
+    kind: TokenKind,
+    text: &'s str,
+    start: usize,
+    end: usize,
+}
+
+impl Token<'_> {
+    fn is(&self, punctuator: &str) -> bool {
+        self.kind == TokenKind::Punctuator && self.text == punctuator
+    }
+
+    fn is_placeholder(&self) -> bool {
+        self.kind == TokenKind::Name && self.text.starts_with(PLACEHOLDER_PREFIX)
+    }
+
+    /// Whether the token can end a value or a selection.
+    fn ends_item(&self) -> bool {
+        match self.kind {
+            TokenKind::Name
+            | TokenKind::Variable
+            | TokenKind::Directive
+            | TokenKind::Number
+            | TokenKind::String => true,
+            TokenKind::Punctuator => matches!(self.text, ")" | "]" | "}" | "!"),
+            TokenKind::Spread | TokenKind::Comment => false,
+        }
+    }
+
+    /// Whether the token can start a value or a selection.
+    fn starts_item(&self) -> bool {
+        match self.kind {
+            TokenKind::Name
+            | TokenKind::Variable
+            | TokenKind::Number
+            | TokenKind::String
+            | TokenKind::Spread => true,
+            TokenKind::Punctuator => matches!(self.text, "[" | "{"),
+            TokenKind::Directive | TokenKind::Comment => false,
+        }
+    }
+}
+
+/// Commas are insignificant in GraphQL, so they are skipped and printed where needed.
+fn tokenize(source_text: &str) -> Option<Vec<Token<'_>>> {
+    let bytes = source_text.as_bytes();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        let kind = match bytes[i] {
+            b if b.is_ascii_whitespace() || b == b',' => {
+                i += 1;
+                continue;
+            }
+            b'#' => {
+                i = source_text[i..].find('\n').map_or(bytes.len(), |end| i + end);
+                TokenKind::Comment
+            }
+            b'"' if source_text[i..].starts_with("\"\"\"") => {
+                let mut end = i + 3;
+                loop {
+                    end += source_text[end..].find("\"\"\"")?;
+                    if bytes[end - 1] != b'\\' {
+                        break;
+                    }
+                    end += 3;
+                }
+                i = end + 3;
+                TokenKind::String
+            }
+            b'"' => {
+                i += 1;
+                loop {
+                    match bytes.get(i)? {
+                        b'\\' => i += 2,
+                        b'"' => break,
+                        b'\n' => return None,
+                        _ => i += 1,
+                    }
+                }
+                i += 1;
+                TokenKind::String
+            }
+            b'.' if source_text[i..].starts_with("...") => {
+                i += 3;
+                TokenKind::Spread
+            }
+            b'$' | b'@' => {
+                i += 1 + name_len(&bytes[i + 1..]);
+                if i == start + 1 {
+                    return None;
+                }
+                if bytes[start] == b'$' { TokenKind::Variable } else { TokenKind::Directive }
+            }
+            b'-' | b'0'..=b'9' => {
+                i += 1;
+                while i < bytes.len()
+                    && (bytes[i].is_ascii_alphanumeric()
+                        || bytes[i] == b'.'
+                        || (matches!(bytes[i], b'+' | b'-') && matches!(bytes[i - 1], b'e' | b'E')))
+                {
+                    i += 1;
+                }
+                TokenKind::Number
+            }
+            b if b == b'_' || b.is_ascii_alphabetic() => {
+                i += name_len(&bytes[i..]);
+                TokenKind::Name
+            }
+            b'{' | b'}' | b'(' | b')' | b'[' | b']' | b':' | b'=' | b'!' | b'|' | b'&' => {
+                i += 1;
+                TokenKind::Punctuator
+            }
+            _ => return None,
+        };
+        tokens.push(Token { kind, text: &source_text[start..i], start, end: i });
+    }
+    Some(tokens)
+}
+
+fn name_len(bytes: &[u8]) -> usize {
+    bytes.iter().take_while(|b| b.is_ascii_alphanumeric() || **b == b'_').count()
+}
+
+const DEFINITION_KEYWORDS: [&str; 13] = [
+    "query",
+    "mutation",
+    "subscription",
+    "fragment",
+    "schema",
+    "scalar",
+    "type",
+    "interface",
+    "union",
+    "enum",
+    "input",
+    "directive",
+    "extend",
+];
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Context {
+    /// `{ ... }` of a selection set, or of a type definition.
+    Selections,
+    /// `(...)`, `[...]`, or `{ ... }` of an object value.
+    Inline,
+}
+
+struct GraphQLPrinter<'s> {
+    source_text: &'s str,
+    indent: String,
+    code: String,
+    stack: Vec<Context>,
+    line_start: bool,
+}
+
+impl GraphQLPrinter<'_> {
+    fn print_token(
+        &mut self,
+        token: &Token<'_>,
+        prev: Option<&Token<'_>>,
+        prev_prev: Option<&Token<'_>>,
+    ) -> Option<()> {
+        let blank_line = prev
+            .is_some_and(|prev| self.source_text[prev.end..token.start].matches('\n').count() > 1);
+
+        if self.stack.last() == Some(&Context::Inline) {
+            return self.print_inline_token(token, prev?);
+        }
+
+        if token.kind == TokenKind::Comment {
+            match prev {
+                Some(prev) if !self.source_text[prev.end..token.start].contains('\n') => {
+                    self.code.push(' ');
+                    self.line_start = false;
+                }
+                Some(_) => self.newline(blank_line),
+                None => {}
+            }
+            self.push(token.text);
+            self.line_start = true;
+            return Some(());
+        }
+
+        if token.is("}") {
+            self.stack.pop()?;
+            self.newline(false);
+            self.push("}");
+            return Some(());
+        }
+
+        let Some(prev) = prev else {
+            self.push(token.text);
+            if token.is("{") {
+                self.stack.push(Context::Selections);
+                self.line_start = true;
+            }
+            return Some(());
+        };
+
+        let new_item = if prev.kind == TokenKind::Comment {
+            true
+        } else if self.stack.is_empty() {
+            // A new definition.
+            token.is_placeholder()
+                || (token.kind == TokenKind::Name
+                    && DEFINITION_KEYWORDS.contains(&token.text)
+                    && prev.text != "extend")
+                || (token.is("{") && (prev.is("}") || prev.is_placeholder()))
+                || (token.kind == TokenKind::String && !prev.is("="))
+                || prev.kind == TokenKind::String
+        } else {
+            // A new selection or field definition, e.g. `... on User` is one selection.
+            let starts_selection =
+                matches!(token.kind, TokenKind::Name | TokenKind::Spread | TokenKind::String);
+            let type_condition =
+                prev.text == "on" && prev_prev.is_some_and(|t| t.kind == TokenKind::Spread);
+            (starts_selection && prev.ends_item() && !type_condition)
+                || prev.kind == TokenKind::String
+        };
+
+        if new_item || self.line_start {
+            self.newline(blank_line);
+        } else if !(token.is("(")
+            || token.is(":")
+            || token.is("!")
+            || (prev.kind == TokenKind::Spread
+                && token.kind == TokenKind::Name
+                && token.text != "on"))
+        {
+            self.code.push(' ');
+        }
+        self.push(token.text);
+
+        if token.is("{") {
+            self.stack.push(Context::Selections);
+            self.line_start = true;
+        } else if token.is("(") || token.is("[") {
+            self.stack.push(Context::Inline);
+        }
+        Some(())
+    }
+
+    /// Prints a token of arguments, variable definitions, lists or objects on the same line.
+    fn print_inline_token(&mut self, token: &Token<'_>, prev: &Token<'_>) -> Option<()> {
+        if token.kind == TokenKind::Comment {
+            return None;
+        }
+        if token.is(")") || token.is("]") || token.is("}") {
+            self.stack.pop()?;
+            if token.is("}") && !prev.is("{") {
+                self.code.push(' ');
+            }
+            self.push(token.text);
+            return Some(());
+        }
+
+        if prev.ends_item() && token.starts_item() {
+            self.code.push_str(", ");
+        } else if prev.is("{")
+            || prev.is(":")
+            || prev.is("=")
+            || token.is("=")
+            || (token.kind == TokenKind::Directive && prev.ends_item())
+        {
+            self.code.push(' ');
+        }
+        self.push(token.text);
+
+        if token.is("(") || token.is("[") || token.is("{") {
+            self.stack.push(Context::Inline);
+        }
+        Some(())
+    }
+
+    fn newline(&mut self, blank_line: bool) {
+        if !self.code.is_empty() {
+            self.code.push('\n');
+            if blank_line {
+                self.code.push('\n');
+            }
+        }
+        self.line_start = true;
+    }
+
+    fn push(&mut self, text: &str) {
+        if self.line_start {
+            for _ in 0..self.stack.iter().filter(|c| **c == Context::Selections).count() {
+                self.code.push_str(&self.indent);
+            }
+            self.line_start = false;
+        }
+        self.code.push_str(text);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{EmbeddedFormatter, GraphQLFormatter};
+    use crate::options::FormatOptions;
+
+    fn format(source_text: &str) -> Option<String> {
+        GraphQLFormatter.format(source_text, &FormatOptions::default())
+    }
+
+    #[test]
+    fn operations() {
+        assert_eq!(
+            format(
+                r#"
+  query User($id:ID!,$first:Int=10) @live {
+    user(id:$id,filter:{name:"foo",tags:[a b]}) { id, name
+      ...UserFields
+      ... on Admin @include(if:$admin) { role }
+
+      # friends
+      friends(first:$first) { edges { node { id } } }
+    }
+  }
+  __oxc_placeholder_0__
+  fragment UserFields on User { avatar(size:64) }
+"#
+            )
+            .unwrap(),
+            r#"query User($id: ID!, $first: Int = 10) @live {
+  user(id: $id, filter: { name: "foo", tags: [a, b] }) {
+    id
+    name
+    ...UserFields
+    ... on Admin @include(if: $admin) {
+      role
+    }
+
+    # friends
+    friends(first: $first) {
+      edges {
+        node {
+          id
+        }
+      }
+    }
+  }
+}
+__oxc_placeholder_0__
+fragment UserFields on User {
+  avatar(size: 64)
+}"#
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(format("query { user"), None);
+        assert_eq!(format("query { user } }"), None);
+        assert_eq!(format("query { user(id: ?) }"), None);
+    }
+}
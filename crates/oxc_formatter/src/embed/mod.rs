@@ -0,0 +1,138 @@
+//! Formatting of languages embedded in template literals, such as
+//! `` styled.div`color: red;` `` or `` gql`query { user }` ``.
+//!
+//! The expressions of the template literal are replaced with [placeholders](placeholder)
+//! before the content is handed to an [`EmbeddedFormatter`], and put back afterwards.
+
+mod css;
+mod graphql;
+
+use std::{fmt, rc::Rc};
+
+pub use self::{css::CssFormatter, graphql::GraphQLFormatter};
+use crate::options::FormatOptions;
+
+const PLACEHOLDER_PREFIX: &str = "__oxc_placeholder_";
+const PLACEHOLDER_SUFFIX: &str = "__";
+
+/// A language which can be embedded in a template literal.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum EmbeddedLanguage {
+    /// `` css`...` ``, `` styled.div`...` ``, `` keyframes`...` ``, etc.
+    Css,
+    /// `` gql`...` ``, `` graphql`...` `` and `` /* GraphQL */ `...` ``.
+    GraphQL,
+    /// `` html`...` `` and `` /* HTML */ `...` ``.
+    Html,
+}
+
+/// Formats the content of a template literal in an embedded language.
+pub trait EmbeddedFormatter {
+    /// Formats `source_text`, in which the expressions of the template literal are replaced with
+    /// [placeholders](placeholder). The placeholders must be kept in the output, and the output
+    /// must start at indentation level zero, because it is indented to the template literal by the caller.
+    ///
+    /// Returns `None` if `source_text` cannot be formatted, e.g. because of a syntax error,
+    /// in which case the template literal is printed as is.
+    fn format(&self, source_text: &str, options: &FormatOptions) -> Option<String>;
+}
+
+/// Returns the placeholder for the expression at `index` of a template literal,
+/// which is a valid identifier in both CSS and GraphQL.
+pub fn placeholder(index: usize) -> String {
+    format!("{PLACEHOLDER_PREFIX}{index}{PLACEHOLDER_SUFFIX}")
+}
+
+/// A piece of a line of formatted embedded code.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum EmbeddedPiece<'s> {
+    Text(&'s str),
+    /// The index of an expression.
+    Placeholder(usize),
+}
+
+/// Splits `line` into text and placeholders.
+pub fn split_placeholders(line: &str) -> Vec<EmbeddedPiece<'_>> {
+    let mut pieces = vec![];
+    let mut rest = line;
+    while let Some(start) = rest.find(PLACEHOLDER_PREFIX) {
+        let after_prefix = &rest[start + PLACEHOLDER_PREFIX.len()..];
+        let digits = after_prefix.bytes().take_while(u8::is_ascii_digit).count();
+        let index = after_prefix[..digits].parse::<usize>().ok();
+        let (Some(index), true) = (index, after_prefix[digits..].starts_with(PLACEHOLDER_SUFFIX))
+        else {
+            // Not a placeholder, e.g. `__oxc_placeholder_foo`.
+            let end = start + PLACEHOLDER_PREFIX.len();
+            pieces.push(EmbeddedPiece::Text(&rest[..end]));
+            rest = &rest[end..];
+            continue;
+        };
+        if start > 0 {
+            pieces.push(EmbeddedPiece::Text(&rest[..start]));
+        }
+        pieces.push(EmbeddedPiece::Placeholder(index));
+        rest = &after_prefix[digits + PLACEHOLDER_SUFFIX.len()..];
+    }
+    if !rest.is_empty() {
+        pieces.push(EmbeddedPiece::Text(rest));
+    }
+    pieces
+}
+
+/// The formatters used for embedded languages.
+///
+/// CSS and GraphQL are formatted with the built-in [`CssFormatter`] and [`GraphQLFormatter`] by default,
+/// and HTML is only formatted when a formatter is provided.
+#[derive(Clone)]
+pub struct EmbeddedFormatters {
+    css: Option<Rc<dyn EmbeddedFormatter>>,
+    graphql: Option<Rc<dyn EmbeddedFormatter>>,
+    html: Option<Rc<dyn EmbeddedFormatter>>,
+}
+
+impl Default for EmbeddedFormatters {
+    fn default() -> Self {
+        Self {
+            css: Some(Rc::new(CssFormatter)),
+            graphql: Some(Rc::new(GraphQLFormatter)),
+            html: None,
+        }
+    }
+}
+
+impl fmt::Debug for EmbeddedFormatters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EmbeddedFormatters")
+            .field("css", &self.css.is_some())
+            .field("graphql", &self.graphql.is_some())
+            .field("html", &self.html.is_some())
+            .finish()
+    }
+}
+
+impl EmbeddedFormatters {
+    pub fn get(&self, language: EmbeddedLanguage) -> Option<Rc<dyn EmbeddedFormatter>> {
+        match language {
+            EmbeddedLanguage::Css => self.css.clone(),
+            EmbeddedLanguage::GraphQL => self.graphql.clone(),
+            EmbeddedLanguage::Html => self.html.clone(),
+        }
+    }
+
+    pub fn set(&mut self, language: EmbeddedLanguage, formatter: Rc<dyn EmbeddedFormatter>) {
+        let slot = match language {
+            EmbeddedLanguage::Css => &mut self.css,
+            EmbeddedLanguage::GraphQL => &mut self.graphql,
+            EmbeddedLanguage::Html => &mut self.html,
+        };
+        *slot = Some(formatter);
+    }
+}
+
+/// Returns the indentation of one level.
+fn indent_unit(options: &FormatOptions) -> String {
+    match options.indent_style {
+        crate::IndentStyle::Tab => "\t".to_string(),
+        crate::IndentStyle::Space => " ".repeat(usize::from(options.indent_width.value())),
+    }
+}
@@ -1,7 +1,7 @@
 use oxc_ast::ast::Program;
 
 use super::Comments;
-use crate::options::FormatOptions;
+use crate::{embed::EmbeddedFormatters, options::FormatOptions};
 
 /// Context object storing data relevant when formatting an object.
 #[derive(Debug, Clone)]
@@ -11,6 +11,8 @@ pub struct FormatContext<'ast> {
     source_text: &'ast str,
 
     comments: Comments,
+
+    embedded_formatters: EmbeddedFormatters,
 }
 
 impl<'ast> FormatContext<'ast> {
@@ -19,9 +21,16 @@ impl<'ast> FormatContext<'ast> {
             options,
             source_text: program.source_text,
             comments: Comments::from_oxc_comments(program),
+            embedded_formatters: EmbeddedFormatters::default(),
         }
     }
 
+    #[must_use]
+    pub fn with_embedded_formatters(mut self, embedded_formatters: EmbeddedFormatters) -> Self {
+        self.embedded_formatters = embedded_formatters;
+        self
+    }
+
     /// Returns the formatting options
     pub fn options(&self) -> &FormatOptions {
         &self.options
@@ -32,6 +41,11 @@ impl<'ast> FormatContext<'ast> {
         &self.comments
     }
 
+    /// Returns the formatters for languages embedded in template literals.
+    pub fn embedded_formatters(&self) -> &EmbeddedFormatters {
+        &self.embedded_formatters
+    }
+
     /// Returns the formatting options
     pub fn source_text(&self) -> &'ast str {
         self.source_text
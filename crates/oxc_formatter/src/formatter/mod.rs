@@ -117,16 +117,15 @@ impl Formatted<'_> {
     }
 
     pub fn print_with_indent(&self, indent: u16) -> PrintResult<Printed> {
-        todo!()
-        // let print_options = self.context.options().as_print_options();
-        // let printed = Printer::new(print_options).print_with_indent(&self.document, indent)?;
+        let print_options = self.context.options().as_print_options();
+        let printed = Printer::new(print_options).print_with_indent(&self.document, indent)?;
 
         // let printed = match self.context.source_map() {
         // Some(source_map) => source_map.map_printed(printed),
         // None => printed,
         // };
 
-        // Ok(printed)
+        Ok(printed)
     }
 }
 pub type PrintResult<T> = Result<T, PrintError>;
@@ -12,31 +12,58 @@
 mod generated {
     pub mod format;
 }
+mod embed;
 mod formatter;
 mod options;
+mod range;
 mod write;
 
+use std::rc::Rc;
+
 use oxc_allocator::Allocator;
 use oxc_ast::ast::Program;
 
-use crate::formatter::FormatContext;
-pub use crate::options::*;
+use crate::{embed::EmbeddedFormatters, formatter::FormatContext};
+pub use crate::{
+    embed::{CssFormatter, EmbeddedFormatter, EmbeddedLanguage, GraphQLFormatter, placeholder},
+    options::*,
+    range::TextEdit,
+};
 
 pub struct Formatter<'a> {
     allocator: &'a Allocator,
     source_text: &'a str,
     options: FormatOptions,
+    embedded_formatters: EmbeddedFormatters,
 }
 
 impl<'a> Formatter<'a> {
     pub fn new(allocator: &'a Allocator, options: FormatOptions) -> Self {
-        Self { allocator, source_text: "", options }
+        Self {
+            allocator,
+            source_text: "",
+            options,
+            embedded_formatters: EmbeddedFormatters::default(),
+        }
+    }
+
+    /// Formats the template literals of `language` with `formatter`,
+    /// instead of the built-in formatter or printing them as is.
+    #[must_use]
+    pub fn with_embedded_formatter(
+        mut self,
+        language: EmbeddedLanguage,
+        formatter: impl EmbeddedFormatter + 'static,
+    ) -> Self {
+        self.embedded_formatters.set(language, Rc::new(formatter));
+        self
     }
 
     pub fn build(mut self, program: &Program<'a>) -> String {
         let source_text = program.source_text;
         self.source_text = source_text;
-        let context = FormatContext::new(program, self.options);
+        let context = FormatContext::new(program, self.options)
+            .with_embedded_formatters(self.embedded_formatters);
         let formatted = formatter::format(
             program,
             context,
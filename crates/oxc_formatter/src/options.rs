@@ -56,6 +56,12 @@ pub struct FormatOptions {
 
     /// Whether to expand object and array literals to multiple lines. Defaults to "auto".
     pub expand: Expand,
+
+    /// Whether to format code embedded in template literals, such as CSS and GraphQL. Defaults to "auto".
+    pub embedded_language_formatting: EmbeddedLanguageFormatting,
+
+    /// How to sort import declarations. Defaults to `None`, which keeps the imports in their order.
+    pub sort_imports: Option<SortImports>,
 }
 
 impl FormatOptions {
@@ -75,6 +81,8 @@ impl FormatOptions {
             bracket_same_line: BracketSameLine::default(),
             attribute_position: AttributePosition::default(),
             expand: Expand::default(),
+            embedded_language_formatting: EmbeddedLanguageFormatting::default(),
+            sort_imports: None,
         }
     }
 
@@ -98,7 +106,12 @@ impl fmt::Display for FormatOptions {
         writeln!(f, "Bracket spacing: {}", self.bracket_spacing.value())?;
         writeln!(f, "Bracket same line: {}", self.bracket_same_line.value())?;
         writeln!(f, "Attribute Position: {}", self.attribute_position)?;
-        writeln!(f, "Expand lists: {}", self.expand)
+        writeln!(f, "Expand lists: {}", self.expand)?;
+        writeln!(f, "Embedded language formatting: {}", self.embedded_language_formatting)?;
+        match &self.sort_imports {
+            Some(sort_imports) => writeln!(f, "Sort imports: {sort_imports}"),
+            None => writeln!(f, "Sort imports: Off"),
+        }
     }
 }
 
@@ -601,6 +614,135 @@ impl fmt::Display for ArrowParentheses {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum EmbeddedLanguageFormatting {
+    /// Format embedded code if it is identified, e.g. by the tag of a template literal.
+    #[default]
+    Auto,
+    /// Never format embedded code.
+    Off,
+}
+
+impl EmbeddedLanguageFormatting {
+    pub const fn is_auto(&self) -> bool {
+        matches!(self, Self::Auto)
+    }
+
+    pub const fn is_off(&self) -> bool {
+        matches!(self, Self::Off)
+    }
+}
+
+impl FromStr for EmbeddedLanguageFormatting {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "off" => Ok(Self::Off),
+            _ => Err(
+                "Value not supported for Embedded language formatting. Supported values are 'auto' and 'off'.",
+            ),
+        }
+    }
+}
+
+impl fmt::Display for EmbeddedLanguageFormatting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            EmbeddedLanguageFormatting::Auto => "Auto",
+            EmbeddedLanguageFormatting::Off => "Off",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Options for sorting contiguous import declarations.
+///
+/// Imports are ordered by [groups](ImportGroup), and by their source in each group.
+/// Side-effect imports, such as `import "./polyfill"`, are kept in place and the imports
+/// before and after them are sorted separately. Named specifiers are sorted as well.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SortImports {
+    /// The order of the groups. Imports of a group which is not listed are put after the others.
+    /// Defaults to builtin, external, internal and relative.
+    pub groups: Vec<ImportGroup>,
+
+    /// The prefixes of the sources of [internal](ImportGroup::Internal) imports,
+    /// such as path aliases. Defaults to `~/`, `@/` and `#`.
+    pub internal_prefixes: Vec<String>,
+
+    /// Whether to separate the groups with a blank line. Defaults to true.
+    pub newlines_between: bool,
+}
+
+impl Default for SortImports {
+    fn default() -> Self {
+        Self {
+            groups: vec![
+                ImportGroup::Builtin,
+                ImportGroup::External,
+                ImportGroup::Internal,
+                ImportGroup::Relative,
+            ],
+            internal_prefixes: vec!["~/".to_string(), "@/".to_string(), "#".to_string()],
+            newlines_between: true,
+        }
+    }
+}
+
+impl fmt::Display for SortImports {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let groups = self.groups.iter().map(ToString::to_string).collect::<Vec<_>>();
+        f.write_str(&groups.join(", "))?;
+        if self.newlines_between {
+            f.write_str(" (newlines between)")?;
+        }
+        Ok(())
+    }
+}
+
+/// The group of an import declaration, determined by its source.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ImportGroup {
+    /// Node.js builtin modules, e.g. `fs` and `node:path`.
+    Builtin,
+    /// Packages, e.g. `react` and `@scope/package`.
+    External,
+    /// Sources starting with one of the [internal prefixes](SortImports::internal_prefixes), e.g. `~/utils`.
+    Internal,
+    /// Relative paths, e.g. `./foo` and `../bar`.
+    Relative,
+}
+
+impl FromStr for ImportGroup {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "builtin" => Ok(Self::Builtin),
+            "external" => Ok(Self::External),
+            "internal" => Ok(Self::Internal),
+            "relative" => Ok(Self::Relative),
+            _ => Err(
+                "Value not supported for Import group. Supported values are 'builtin', 'external', 'internal' and 'relative'.",
+            ),
+        }
+    }
+}
+
+impl fmt::Display for ImportGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ImportGroup::Builtin => "Builtin",
+            ImportGroup::External => "External",
+            ImportGroup::Internal => "Internal",
+            ImportGroup::Relative => "Relative",
+        };
+        f.write_str(s)
+    }
+}
+
 /// This enum is used within formatting functions to print or omit trailing commas.
 #[derive(Debug, Copy, Clone)]
 pub enum FormatTrailingCommas {
@@ -0,0 +1,287 @@
+//! Range formatting, which backs editor requests such as
+//! [`textDocument/rangeFormatting`](https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#textDocument_rangeFormatting) and
+//! [`textDocument/onTypeFormatting`](https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#textDocument_onTypeFormatting).
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::ast::{Program, Statement};
+use oxc_ast_visit::{Visit, walk};
+use oxc_span::{GetSpan, Span};
+use similar::{DiffTag, TextDiff};
+
+use crate::{
+    Formatter,
+    embed::EmbeddedFormatters,
+    formatter::{self, Comments, FormatContext},
+};
+
+/// An edit which replaces the source text in `span` with `text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub span: Span,
+    pub text: String,
+}
+
+impl<'a> Formatter<'a> {
+    /// Formats the statements overlapping `range`, and returns the edits to apply to the source text.
+    ///
+    /// The range is expanded to whole statements of the innermost statement list (program, block,
+    /// function body, etc.) containing it, so the formatted statements start and end on their own lines.
+    /// They are formatted with the indentation of the first statement in the source text.
+    /// The whole program is formatted if there is no such statement list.
+    ///
+    /// Returns no edits if `range` does not overlap any statement, or if the code is already formatted.
+    pub fn format_range(self, program: &Program<'a>, range: Span) -> Vec<TextEdit> {
+        let source_text = program.source_text;
+        let comments = Comments::from_oxc_comments(program);
+        let mut finder = SelectionFinder {
+            source_text,
+            comments: &comments,
+            range,
+            overlapping: false,
+            selection: None,
+        };
+        finder.visit_program(program);
+
+        if !finder.overlapping {
+            return vec![];
+        }
+        let Some(selection) = finder.selection else {
+            let code = self.build(program);
+            return text_edits(source_text, 0, &code);
+        };
+
+        let indent = self.indent_level(source_text, selection.source.start);
+        let mut printer = SelectionFormatter {
+            program,
+            options: &self.options,
+            embedded_formatters: &self.embedded_formatters,
+            selection,
+            indent,
+            code: None,
+        };
+        printer.visit_program(program);
+        let Some(code) = printer.code else { return vec![] };
+        text_edits(selection.source.source_text(source_text), selection.source.start, &code)
+    }
+
+    /// Formats the statement completed by the character typed right before `offset`, e.g. `;` or `}`.
+    ///
+    /// See [Formatter::format_range].
+    pub fn format_on_type(self, program: &Program<'a>, offset: u32) -> Vec<TextEdit> {
+        self.format_range(program, Span::new(offset.saturating_sub(1), offset))
+    }
+
+    /// Returns the indentation level of the line containing `offset`.
+    /// A tab is one level, and spaces are counted in units of the indent width.
+    fn indent_level(&self, source_text: &str, offset: u32) -> u16 {
+        let line = &source_text[line_start(source_text, offset) as usize..offset as usize];
+        let tabs = line.bytes().filter(|&b| b == b'\t').count();
+        let spaces = line.len() - tabs;
+        let levels = tabs + spaces / usize::from(self.options.indent_width.value());
+        u16::try_from(levels).unwrap_or(u16::MAX)
+    }
+}
+
+/// Statements selected for formatting.
+#[derive(Debug, Clone, Copy)]
+struct Selection {
+    /// From the start of the first statement to the end of the last statement.
+    statements: Span,
+    /// Same as `statements`, but including their leading and trailing comments.
+    source: Span,
+}
+
+/// Finds the innermost statement list whose statements can be formatted for the range.
+struct SelectionFinder<'s> {
+    source_text: &'s str,
+    comments: &'s Comments,
+    range: Span,
+    /// Whether any statement overlaps the range.
+    overlapping: bool,
+    /// `None` if no statement list can be formatted on its own lines.
+    selection: Option<Selection>,
+}
+
+impl<'a> Visit<'a> for SelectionFinder<'_> {
+    fn visit_statements(&mut self, stmts: &ArenaVec<'a, Statement<'a>>) {
+        let Some(first) = stmts.iter().position(|stmt| overlaps(stmt.span(), self.range)) else {
+            return;
+        };
+        // Statements are sorted, so the overlapping statements are contiguous.
+        let last = stmts.iter().rposition(|stmt| overlaps(stmt.span(), self.range)).unwrap();
+        self.overlapping = true;
+        if let Some(selection) = self.select(stmts, first, last) {
+            self.selection = Some(selection);
+        }
+        // Nested statement lists can only be inside the overlapping statements.
+        for stmt in &stmts[first..=last] {
+            self.visit_statement(stmt);
+        }
+    }
+}
+
+impl SelectionFinder<'_> {
+    /// Expands `stmts[first..=last]` to statements starting and ending on their own lines.
+    fn select(
+        &self,
+        stmts: &[Statement<'_>],
+        mut first: usize,
+        mut last: usize,
+    ) -> Option<Selection> {
+        let source_text = self.source_text;
+        let start = loop {
+            let start = self.leading_start(&stmts[first]);
+            if is_line_start(source_text, start) {
+                break start;
+            }
+            first = first.checked_sub(1)?;
+        };
+        let end = loop {
+            let end = self.trailing_end(&stmts[last]);
+            if is_line_end(source_text, end) {
+                break end;
+            }
+            last += 1;
+            if last == stmts.len() {
+                return None;
+            }
+        };
+        // The range must not extend beyond the lines of the statements, e.g. to the closing `}` of a block.
+        if self.range.start < line_start(source_text, start)
+            || self.range.end > line_end(source_text, end)
+        {
+            return None;
+        }
+        Some(Selection {
+            statements: Span::new(stmts[first].span().start, stmts[last].span().end),
+            source: Span::new(start, end),
+        })
+    }
+
+    fn leading_start(&self, stmt: &Statement<'_>) -> u32 {
+        let start = stmt.span().start;
+        self.comments.leading_comments(start).first().map_or(start, |comment| comment.span.start)
+    }
+
+    fn trailing_end(&self, stmt: &Statement<'_>) -> u32 {
+        let end = stmt.span().end;
+        self.comments.trailing_comments(end).last().map_or(end, |comment| comment.span.end)
+    }
+}
+
+/// Formats the selected statements.
+struct SelectionFormatter<'s, 'a> {
+    program: &'s Program<'a>,
+    options: &'s crate::FormatOptions,
+    embedded_formatters: &'s EmbeddedFormatters,
+    selection: Selection,
+    indent: u16,
+    code: Option<String>,
+}
+
+impl<'a> Visit<'a> for SelectionFormatter<'_, 'a> {
+    fn visit_statements(&mut self, stmts: &ArenaVec<'a, Statement<'a>>) {
+        if self.code.is_some() {
+            return;
+        }
+        let Span { start, end, .. } = self.selection.statements;
+        let first = stmts.iter().position(|stmt| stmt.span().start == start);
+        let last = stmts.iter().rposition(|stmt| stmt.span().end == end);
+        if let (Some(first), Some(last)) = (first, last) {
+            debug_assert!(first <= last);
+            let stmts = &stmts[first..=last];
+            let context = FormatContext::new(self.program, self.options.clone())
+                .with_embedded_formatters(self.embedded_formatters.clone());
+            let formatted = formatter::format(
+                self.program,
+                context,
+                formatter::Arguments::new(&[formatter::Argument::new(&stmts)]),
+            )
+            .unwrap();
+            self.code = Some(formatted.print_with_indent(self.indent).unwrap().into_code());
+            return;
+        }
+        walk::walk_statements(self, stmts);
+    }
+}
+
+/// Returns the line diff between `source_text` and `formatted`, as edits with the common
+/// prefix and suffix of each changed hunk trimmed. `offset` is the offset of `source_text`.
+fn text_edits(source_text: &str, offset: u32, formatted: &str) -> Vec<TextEdit> {
+    let diff = TextDiff::from_lines(source_text, formatted);
+    let mut line_offsets = vec![0];
+    line_offsets.extend(diff.old_slices().iter().scan(0, |offset, line| {
+        *offset += line.len();
+        Some(*offset)
+    }));
+    let new_lines = diff.new_slices();
+
+    let mut edits = vec![];
+    for op in diff.ops() {
+        if op.tag() == DiffTag::Equal {
+            continue;
+        }
+        let old_range = op.old_range();
+        let old_start = line_offsets[old_range.start];
+        let old = &source_text[old_start..line_offsets[old_range.end]];
+        let new = new_lines[op.new_range()].concat();
+
+        let prefix = common_prefix_len(old, &new);
+        let suffix = common_suffix_len(&old[prefix..], &new[prefix..]);
+        #[expect(clippy::cast_possible_truncation)]
+        let span = Span::new(
+            offset + (old_start + prefix) as u32,
+            offset + (old_start + old.len() - suffix) as u32,
+        );
+        edits.push(TextEdit { span, text: new[prefix..new.len() - suffix].to_string() });
+    }
+    edits
+}
+
+fn common_prefix_len(a: &str, b: &str) -> usize {
+    a.char_indices()
+        .zip(b.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or_else(|| a.len().min(b.len()), |((i, _), _)| i)
+}
+
+fn common_suffix_len(a: &str, b: &str) -> usize {
+    a.chars()
+        .rev()
+        .zip(b.chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(c, _)| c.len_utf8())
+        .sum()
+}
+
+/// A cursor (empty range) overlaps the statements it touches.
+fn overlaps(span: Span, range: Span) -> bool {
+    if range.is_empty() {
+        span.contains_inclusive(range)
+    } else {
+        span.start < range.end && range.start < span.end
+    }
+}
+
+fn is_line_start(source_text: &str, offset: u32) -> bool {
+    let before = source_text[..offset as usize].trim_end_matches([' ', '\t']);
+    before.is_empty() || before.ends_with(['\n', '\r'])
+}
+
+fn is_line_end(source_text: &str, offset: u32) -> bool {
+    let rest = source_text[offset as usize..].trim_start_matches([' ', '\t']);
+    rest.is_empty() || rest.starts_with(['\n', '\r'])
+}
+
+#[expect(clippy::cast_possible_truncation)]
+fn line_start(source_text: &str, offset: u32) -> u32 {
+    source_text[..offset as usize].rfind(['\n', '\r']).map_or(0, |i| i as u32 + 1)
+}
+
+/// Returns the offset after the line break of the line containing `offset`.
+#[expect(clippy::cast_possible_truncation)]
+fn line_end(source_text: &str, offset: u32) -> u32 {
+    source_text[offset as usize..]
+        .find('\n')
+        .map_or(source_text.len() as u32, |i| offset + i as u32 + 1)
+}
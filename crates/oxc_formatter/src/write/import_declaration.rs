@@ -0,0 +1,162 @@
+use oxc_ast::ast::*;
+use oxc_span::GetSpan;
+
+use super::{FormatWrite, semicolon::OptionalSemicolon, sort_imports::compare_names};
+use crate::{
+    format_args,
+    formatter::{
+        Buffer, Format, FormatResult, Formatter, prelude::*, separated::FormatSeparatedIter,
+    },
+    options::FormatTrailingCommas,
+    write,
+};
+
+impl<'a> FormatWrite<'a> for ImportDeclaration<'a> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(f, "import")?;
+        if self.import_kind.is_type() {
+            write!(f, [space(), "type"])?;
+        }
+        match self.phase {
+            Some(ImportPhase::Source) => write!(f, [space(), "source"])?,
+            Some(ImportPhase::Defer) => write!(f, [space(), "defer"])?,
+            None => {}
+        }
+        if let Some(specifiers) = &self.specifiers {
+            write!(f, [space(), ImportSpecifiers(specifiers), space(), "from"])?;
+        }
+        write!(f, [space(), self.source, self.with_clause, OptionalSemicolon])
+    }
+}
+
+/// The specifiers of an import declaration, e.g. `a, { b, c as d }`.
+struct ImportSpecifiers<'a, 'b>(&'b [ImportDeclarationSpecifier<'a>]);
+
+impl<'a> Format<'a> for ImportSpecifiers<'a, '_> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let mut named = vec![];
+        let mut has_binding = false;
+        for specifier in self.0 {
+            match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => named.push(specifier),
+                // The default specifier always comes before the namespace specifier.
+                _ => {
+                    if has_binding {
+                        write!(f, [",", space()])?;
+                    }
+                    write!(f, specifier)?;
+                    has_binding = true;
+                }
+            }
+        }
+
+        if named.is_empty() {
+            return if has_binding { Ok(()) } else { write!(f, "{}") };
+        }
+        if has_binding {
+            write!(f, [",", space()])?;
+        }
+
+        if f.options().sort_imports.is_some() {
+            named.sort_by(|a, b| compare_names(&a.imported.name(), &b.imported.name()));
+        }
+
+        let bracket_spacing = f.options().bracket_spacing.value();
+        // `import { a } from "a"` is kept on one line, like Prettier.
+        if !has_binding && named.len() == 1 && !f.comments().has_comments(named[0].span) {
+            return if bracket_spacing {
+                write!(f, ["{", space(), named[0], space(), "}"])
+            } else {
+                write!(f, ["{", named[0], "}"])
+            };
+        }
+
+        let trailing_separator = FormatTrailingCommas::ES5.trailing_separator(f.options());
+        let specifiers = format_with(|f| {
+            f.join_with(soft_line_break_or_space())
+                .entries(
+                    FormatSeparatedIter::new(named.iter(), ",")
+                        .with_trailing_separator(trailing_separator),
+                )
+                .finish()
+        });
+        write!(
+            f,
+            group(&format_args!(
+                "{",
+                soft_block_indent_with_maybe_space(&specifiers, bracket_spacing),
+                "}"
+            ))
+        )
+    }
+}
+
+impl<'a> FormatWrite<'a> for ImportDeclarationSpecifier<'a> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        match self {
+            Self::ImportSpecifier(specifier) => specifier.fmt(f),
+            Self::ImportDefaultSpecifier(specifier) => specifier.fmt(f),
+            Self::ImportNamespaceSpecifier(specifier) => specifier.fmt(f),
+        }
+    }
+}
+
+impl<'a> FormatWrite<'a> for ImportSpecifier<'a> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let Self { imported, local, import_kind, .. } = self;
+        if import_kind.is_type() {
+            write!(f, ["type", space()])?;
+        }
+        write!(f, imported)?;
+        if imported.span() != local.span {
+            write!(f, [space(), "as", space(), local])?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> FormatWrite<'a> for ImportDefaultSpecifier<'a> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(f, self.local)
+    }
+}
+
+impl<'a> FormatWrite<'a> for ImportNamespaceSpecifier<'a> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(f, ["*", space(), "as", space(), self.local])
+    }
+}
+
+impl<'a> FormatWrite<'a> for WithClause<'a> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(f, [space(), self.attributes_keyword, space()])?;
+        if self.with_entries.is_empty() {
+            return write!(f, "{}");
+        }
+        let bracket_spacing = f.options().bracket_spacing.value();
+        write!(f, "{")?;
+        if bracket_spacing {
+            write!(f, space())?;
+        }
+        f.join_with(&format_args!(",", space())).entries(self.with_entries.iter()).finish()?;
+        if bracket_spacing {
+            write!(f, space())?;
+        }
+        write!(f, "}")
+    }
+}
+
+impl<'a> FormatWrite<'a> for ImportAttribute<'a> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(f, [self.key, ":", space(), self.value])
+    }
+}
+
+impl<'a> FormatWrite<'a> for ImportAttributeKey<'a> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        match self {
+            Self::Identifier(ident) => ident.fmt(f),
+            Self::StringLiteral(literal) => literal.fmt(f),
+        }
+    }
+}
@@ -2,8 +2,11 @@ mod array_element_list;
 mod block_statement;
 mod directive;
 mod function;
+mod import_declaration;
 mod object_like;
 mod semicolon;
+mod sort_imports;
+mod template;
 mod utils;
 mod variable_declaration;
 
@@ -23,7 +26,7 @@ use crate::{
 
 use self::{
     array_element_list::ArrayElementList, object_like::ObjectLike, semicolon::OptionalSemicolon,
-    utils::FormatStatementBody,
+    sort_imports::StatementSeparator, utils::FormatStatementBody,
 };
 
 impl<'a, T: Format<'a>> Format<'a> for Box<'a, T> {
@@ -214,24 +217,6 @@ impl<'a> FormatWrite<'a> for PropertyKey<'a> {
     }
 }
 
-impl<'a> FormatWrite<'a> for TemplateLiteral<'a> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        Ok(())
-    }
-}
-
-impl<'a> FormatWrite<'a> for TaggedTemplateExpression<'a> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        Ok(())
-    }
-}
-
-impl<'a> FormatWrite<'a> for TemplateElement<'a> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        Ok(())
-    }
-}
-
 impl<'a> FormatWrite<'a> for MemberExpression<'a> {
     fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         match self {
@@ -513,11 +498,36 @@ impl<'a> FormatWrite<'a> for ParenthesizedExpression<'a> {
 }
 
 impl<'a> Format<'a> for Vec<'a, Statement<'a>> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        Format::fmt(self.as_slice(), f)
+    }
+}
+
+impl<'a> Format<'a> for [Statement<'a>] {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         let source_text = f.context().source_text();
+        let sorted = f
+            .options()
+            .sort_imports
+            .as_ref()
+            .map(|options| sort_imports::sort_statements(self, options));
         let mut join = f.join_nodes_with_hardline();
-        for stmt in self {
-            join.entry(stmt.span(), source_text, stmt);
+        let Some(sorted) = sorted else {
+            for stmt in self {
+                join.entry(stmt.span(), source_text, stmt);
+            }
+            return join.finish();
+        };
+        for (stmt, separator) in sorted {
+            match separator {
+                StatementSeparator::Source(span) => join.entry(span, source_text, stmt),
+                StatementSeparator::LineBreak => {
+                    join.entry_no_separator(&format_args!(hard_line_break(), stmt));
+                }
+                StatementSeparator::EmptyLine => {
+                    join.entry_no_separator(&format_args!(empty_line(), stmt));
+                }
+            }
         }
         join.finish()
     }
@@ -1075,59 +1085,6 @@ impl<'a> FormatWrite<'a> for ImportExpression<'a> {
     }
 }
 
-impl<'a> FormatWrite<'a> for ImportDeclaration<'a> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        write!(f, "import")
-    }
-}
-
-impl<'a> FormatWrite<'a> for ImportDeclarationSpecifier<'a> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        Ok(())
-    }
-}
-
-impl<'a> FormatWrite<'a> for ImportSpecifier<'a> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        let Self { imported, local, .. } = self;
-        write!(f, imported)?;
-        if imported.span() != local.span {
-            write!(f, [space(), "as", imported])?;
-        }
-        Ok(())
-    }
-}
-
-impl<'a> FormatWrite<'a> for ImportDefaultSpecifier<'a> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        Ok(())
-    }
-}
-
-impl<'a> FormatWrite<'a> for ImportNamespaceSpecifier<'a> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        Ok(())
-    }
-}
-
-impl<'a> FormatWrite<'a> for WithClause<'a> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        Ok(())
-    }
-}
-
-impl<'a> FormatWrite<'a> for ImportAttribute<'a> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        Ok(())
-    }
-}
-
-impl<'a> FormatWrite<'a> for ImportAttributeKey<'a> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        Ok(())
-    }
-}
-
 impl<'a> FormatWrite<'a> for ExportNamedDeclaration<'a> {
     fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         write!(f, "export ")?;
@@ -0,0 +1,153 @@
+use std::cmp::Ordering;
+
+use oxc_ast::ast::{ImportDeclaration, Statement};
+use oxc_span::{GetSpan, Span};
+
+use crate::options::{ImportGroup, SortImports};
+
+/// Node.js builtin modules which can be imported without the `node:` prefix.
+const NODE_BUILTIN_MODULES: [&str; 42] = [
+    "assert",
+    "async_hooks",
+    "buffer",
+    "child_process",
+    "cluster",
+    "console",
+    "constants",
+    "crypto",
+    "dgram",
+    "diagnostics_channel",
+    "dns",
+    "domain",
+    "events",
+    "fs",
+    "http",
+    "http2",
+    "https",
+    "inspector",
+    "module",
+    "net",
+    "os",
+    "path",
+    "perf_hooks",
+    "process",
+    "punycode",
+    "querystring",
+    "readline",
+    "repl",
+    "stream",
+    "string_decoder",
+    "sys",
+    "timers",
+    "tls",
+    "trace_events",
+    "tty",
+    "url",
+    "util",
+    "v8",
+    "vm",
+    "wasi",
+    "worker_threads",
+    "zlib",
+];
+
+/// How a statement is separated from the previous statement.
+#[derive(Debug, Clone, Copy)]
+pub enum StatementSeparator {
+    /// A line break, or an empty line if there is one before `span` in the source text.
+    Source(Span),
+    LineBreak,
+    EmptyLine,
+}
+
+/// Sorts each run of contiguous import declarations, and returns the statements with
+/// how they are separated from the previous statement.
+///
+/// Side-effect imports end a run, because the order in which they are evaluated matters.
+pub fn sort_statements<'s, 'a>(
+    stmts: &'s [Statement<'a>],
+    options: &SortImports,
+) -> Vec<(&'s Statement<'a>, StatementSeparator)> {
+    let mut sorted = Vec::with_capacity(stmts.len());
+    let mut rest = stmts;
+    while let Some(first) = rest.first() {
+        let run_len = rest.iter().take_while(|stmt| sortable_import(stmt).is_some()).count();
+        if run_len == 0 {
+            sorted.push((first, StatementSeparator::Source(first.span())));
+            rest = &rest[1..];
+            continue;
+        }
+
+        let (run, after) = rest.split_at(run_len);
+        let mut imports = run
+            .iter()
+            .filter_map(|stmt| {
+                let import = sortable_import(stmt)?;
+                Some((stmt, import, group_rank(&import.source.value, options)))
+            })
+            .collect::<Vec<_>>();
+        // Value imports come before type imports of the same source.
+        imports.sort_by(|(_, a, a_rank), (_, b, b_rank)| {
+            a_rank
+                .cmp(b_rank)
+                .then_with(|| compare_names(&a.source.value, &b.source.value))
+                .then_with(|| a.import_kind.is_type().cmp(&b.import_kind.is_type()))
+        });
+
+        let mut prev_rank = None;
+        for (stmt, _, rank) in imports {
+            let separator = match prev_rank {
+                // Keep the lines before the run.
+                None => StatementSeparator::Source(first.span()),
+                Some(prev_rank) if options.newlines_between && prev_rank != rank => {
+                    StatementSeparator::EmptyLine
+                }
+                Some(_) => StatementSeparator::LineBreak,
+            };
+            sorted.push((stmt, separator));
+            prev_rank = Some(rank);
+        }
+        rest = after;
+    }
+    sorted
+}
+
+/// Compares names case-insensitively, and falls back to a case-sensitive comparison for equal names.
+pub fn compare_names(a: &str, b: &str) -> Ordering {
+    a.bytes()
+        .map(|b| b.to_ascii_lowercase())
+        .cmp(b.bytes().map(|b| b.to_ascii_lowercase()))
+        .then_with(|| a.cmp(b))
+}
+
+fn sortable_import<'s, 'a>(stmt: &'s Statement<'a>) -> Option<&'s ImportDeclaration<'a>> {
+    match stmt {
+        Statement::ImportDeclaration(import) if import.specifiers.is_some() => Some(import),
+        _ => None,
+    }
+}
+
+/// Returns the position of the import's group in [SortImports::groups].
+/// Imports of unlisted groups are put after the others.
+fn group_rank(source: &str, options: &SortImports) -> usize {
+    let group = import_group(source, options);
+    options.groups.iter().position(|g| *g == group).unwrap_or(options.groups.len())
+}
+
+fn import_group(source: &str, options: &SortImports) -> ImportGroup {
+    if options.internal_prefixes.iter().any(|prefix| source.starts_with(prefix.as_str())) {
+        ImportGroup::Internal
+    } else if source.starts_with('.') {
+        ImportGroup::Relative
+    } else if source.starts_with("node:") || is_node_builtin_module(source) {
+        ImportGroup::Builtin
+    } else {
+        ImportGroup::External
+    }
+}
+
+/// `fs` and `fs/promises` are builtin modules.
+fn is_node_builtin_module(source: &str) -> bool {
+    let name = source.split('/').next().unwrap_or(source);
+    NODE_BUILTIN_MODULES.contains(&name)
+}
@@ -0,0 +1,166 @@
+use oxc_ast::{AstKind, ast::*};
+use oxc_span::GetSpan;
+
+use super::FormatWrite;
+use crate::{
+    embed::{EmbeddedFormatter, EmbeddedLanguage, EmbeddedPiece, placeholder, split_placeholders},
+    formatter::{Buffer, FormatResult, Formatter, prelude::*},
+    write,
+};
+
+impl<'a> FormatWrite<'a> for TemplateLiteral<'a> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        if f.options().embedded_language_formatting.is_auto() {
+            let formatter = embedded_language(self, f)
+                .and_then(|language| f.context().embedded_formatters().get(language));
+            if let Some(formatter) = formatter {
+                if write_embedded(self, formatter.as_ref(), f)? {
+                    return Ok(());
+                }
+            }
+        }
+
+        write!(f, "`")?;
+        let mut expressions = self.expressions.iter();
+        for quasi in &self.quasis {
+            write!(f, quasi)?;
+            if let Some(expression) = expressions.next() {
+                write!(f, ["${", expression, "}"])?;
+            }
+        }
+        write!(f, "`")
+    }
+}
+
+impl<'a> FormatWrite<'a> for TaggedTemplateExpression<'a> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(f, [self.tag, self.type_arguments, self.quasi])
+    }
+}
+
+impl<'a> FormatWrite<'a> for TemplateElement<'a> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(f, dynamic_text(self.value.raw.as_str(), self.span.start))
+    }
+}
+
+/// Returns the language embedded in the template literal, which is identified by
+/// the tag (`` css`...` ``) or a comment (`` /* GraphQL */ `...` ``) like Prettier.
+fn embedded_language(
+    template: &TemplateLiteral<'_>,
+    f: &Formatter<'_, '_>,
+) -> Option<EmbeddedLanguage> {
+    if let AstKind::TaggedTemplateExpression(tagged) = f.parent_kind() {
+        if tagged.quasi.span == template.span {
+            return language_of_tag(&tagged.tag);
+        }
+    }
+    let comment = f.comments().leading_comments(template.span.start).last()?;
+    match comment.span.source_text(f.context().source_text()) {
+        "/* GraphQL */" => Some(EmbeddedLanguage::GraphQL),
+        "/* HTML */" => Some(EmbeddedLanguage::Html),
+        _ => None,
+    }
+}
+
+fn language_of_tag(tag: &Expression<'_>) -> Option<EmbeddedLanguage> {
+    match tag {
+        Expression::Identifier(ident) => match ident.name.as_str() {
+            "css" | "keyframes" | "injectGlobal" | "createGlobalStyle" => {
+                Some(EmbeddedLanguage::Css)
+            }
+            "gql" | "graphql" => Some(EmbeddedLanguage::GraphQL),
+            "html" => Some(EmbeddedLanguage::Html),
+            _ => None,
+        },
+        // `styled.div`, `css.global`
+        Expression::StaticMemberExpression(member) => {
+            let is_css = is_styled(&member.object) || member.object.is_specific_id("css");
+            is_css.then_some(EmbeddedLanguage::Css)
+        }
+        // `styled(Component)`, `styled.div.attrs(...)`, `styled(Component).attrs(...)`
+        Expression::CallExpression(call) => {
+            let is_styled_call = is_styled(&call.callee)
+                || call.callee.as_member_expression().is_some_and(|member| {
+                    match member.object().without_parentheses() {
+                        Expression::StaticMemberExpression(object) => is_styled(&object.object),
+                        Expression::CallExpression(object) => is_styled(&object.callee),
+                        _ => false,
+                    }
+                });
+            is_styled_call.then_some(EmbeddedLanguage::Css)
+        }
+        _ => None,
+    }
+}
+
+fn is_styled(expression: &Expression<'_>) -> bool {
+    expression.is_specific_id("styled")
+}
+
+/// Formats the content of the template literal with `formatter`, and writes it on its own lines.
+/// Returns `false` if the content cannot be formatted.
+fn write_embedded<'a>(
+    template: &TemplateLiteral<'a>,
+    formatter: &dyn EmbeddedFormatter,
+    f: &mut Formatter<'_, 'a>,
+) -> FormatResult<bool> {
+    let mut content = String::new();
+    for (index, quasi) in template.quasis.iter().enumerate() {
+        content.push_str(quasi.value.raw.as_str());
+        if index < template.expressions.len() {
+            content.push_str(&placeholder(index));
+        }
+    }
+    let Some(code) = formatter.format(&content, f.options()) else {
+        return Ok(false);
+    };
+
+    let lines = code.trim().lines().map(split_placeholders).collect::<Vec<_>>();
+    // Each expression must be printed exactly once.
+    let mut counts = vec![0; template.expressions.len()];
+    for piece in lines.iter().flatten() {
+        if let EmbeddedPiece::Placeholder(index) = piece {
+            match counts.get_mut(*index) {
+                Some(count) => *count += 1,
+                None => return Ok(false),
+            }
+        }
+    }
+    if counts.iter().any(|count| *count != 1) {
+        return Ok(false);
+    }
+
+    if lines.is_empty() {
+        write!(f, "``")?;
+        return Ok(true);
+    }
+
+    let position = template.span.start;
+    let content = format_with(|f| {
+        let mut empty_line_before = false;
+        for line in &lines {
+            if line.is_empty() {
+                empty_line_before = true;
+                continue;
+            }
+            if empty_line_before {
+                write!(f, empty_line())?;
+                empty_line_before = false;
+            } else {
+                write!(f, hard_line_break())?;
+            }
+            for piece in line {
+                match piece {
+                    EmbeddedPiece::Text(text) => write!(f, dynamic_text(text, position))?,
+                    EmbeddedPiece::Placeholder(index) => {
+                        write!(f, ["${", template.expressions[*index], "}"])?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    });
+    write!(f, ["`", indent(&content), hard_line_break(), "`"])?;
+    Ok(true)
+}
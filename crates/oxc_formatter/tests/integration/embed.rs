@@ -0,0 +1,78 @@
+use oxc_allocator::Allocator;
+use oxc_formatter::{
+    EmbeddedFormatter, EmbeddedLanguage, EmbeddedLanguageFormatting, FormatOptions, Formatter,
+};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn format_with(source_text: &str, options: FormatOptions) -> String {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    assert!(ret.errors.is_empty());
+    Formatter::new(&allocator, options).build(&ret.program)
+}
+
+fn format(source_text: &str) -> String {
+    format_with(source_text, FormatOptions::default())
+}
+
+#[test]
+fn css() {
+    assert_eq!(
+        format("const Button = styled.a`\n    display:block;\n  color : ${color};\n  ${mixin}\n`;"),
+        "const Button = styled.a`\n  display: block;\n  color: ${color};\n  ${mixin}\n`;\n"
+    );
+    assert_eq!(
+        format("styled(Button).attrs(props)`a{ color:red }`;"),
+        "styled(Button).attrs(props)`\n  a {\n    color: red;\n  }\n`;\n"
+    );
+    assert_eq!(format("css`  `;"), "css``;\n");
+}
+
+#[test]
+fn graphql() {
+    assert_eq!(
+        format("function foo() {\n  return gql`query { user { name } } ${fragment}`;\n}"),
+        "function foo() {\n  return gql`\n    query {\n      user {\n        name\n      }\n    }\n    ${fragment}\n  `;\n}\n"
+    );
+    assert_eq!(
+        format("const q = /* GraphQL */ `{ a }`;"),
+        "const q = /* GraphQL */ `\n  {\n    a\n  }\n`;\n"
+    );
+}
+
+#[test]
+fn verbatim() {
+    // Not an embedded language.
+    assert_eq!(format("foo`a{ color:red }`;"), "foo`a{ color:red }`;\n");
+    assert_eq!(format("`hello ${name}\n  world`;"), "`hello ${name}\n  world`;\n");
+    // Syntax errors.
+    assert_eq!(format("css`a{ color:red`;"), "css`a{ color:red`;\n");
+    // No built-in HTML formatter.
+    assert_eq!(format("html`<div>${a}</div>`;"), "html`<div>${a}</div>`;\n");
+
+    let options = FormatOptions {
+        embedded_language_formatting: EmbeddedLanguageFormatting::Off,
+        ..FormatOptions::default()
+    };
+    assert_eq!(format_with("css`a{ color:red }`;", options), "css`a{ color:red }`;\n");
+}
+
+struct HtmlFormatter;
+
+impl EmbeddedFormatter for HtmlFormatter {
+    fn format(&self, source_text: &str, _options: &FormatOptions) -> Option<String> {
+        Some(source_text.split("</div>").collect::<Vec<_>>().join("</div>\n"))
+    }
+}
+
+#[test]
+fn custom_formatter() {
+    let source_text = "html`<div>${a}</div><p></p>`;";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let code = Formatter::new(&allocator, FormatOptions::default())
+        .with_embedded_formatter(EmbeddedLanguage::Html, HtmlFormatter)
+        .build(&ret.program);
+    assert_eq!(code, "html`\n  <div>${a}</div>\n  <p></p>\n`;\n");
+}
@@ -0,0 +1,3 @@
+mod embed;
+mod range;
+mod sort_imports;
@@ -0,0 +1,83 @@
+use oxc_allocator::Allocator;
+use oxc_formatter::{FormatOptions, Formatter, TextEdit};
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span};
+
+fn edits(source_text: &str, range: Span) -> Vec<TextEdit> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    assert!(ret.errors.is_empty());
+    Formatter::new(&allocator, FormatOptions::default()).format_range(&ret.program, range)
+}
+
+/// Formats the range between the first occurrences of `start` and `end` in `source_text`.
+fn format_range(source_text: &str, start: &str, end: &str) -> String {
+    let start = u32::try_from(source_text.find(start).unwrap()).unwrap();
+    let end = source_text.find(end).unwrap() + end.len();
+    let edits = edits(source_text, Span::new(start, u32::try_from(end).unwrap()));
+    apply(source_text, &edits)
+}
+
+fn apply(source_text: &str, edits: &[TextEdit]) -> String {
+    let mut code = source_text.to_string();
+    for edit in edits.iter().rev() {
+        code.replace_range(edit.span.start as usize..edit.span.end as usize, &edit.text);
+    }
+    code
+}
+
+#[test]
+fn statements() {
+    assert_eq!(
+        format_range("const  a=1;\nconst  b=2;\nconst  c=3;\n", "b", "b"),
+        "const  a=1;\nconst b = 2;\nconst  c=3;\n"
+    );
+    // Statements on the same line are formatted together.
+    assert_eq!(format_range("a;b  ;\nc  ;\n", "b", "b"), "a;\nb;\nc  ;\n");
+    // Comments are formatted with their statements.
+    assert_eq!(
+        format_range("function foo() {\n  // c\n  a  ;  // d\n  b  ;\n}\n", "a", "a"),
+        "function foo() {\n  // c\n  a; // d\n  b  ;\n}\n"
+    );
+}
+
+#[test]
+fn indentation() {
+    assert_eq!(
+        format_range(
+            "function foo() {\n    if (x) {\n        a=1;\n        b=2;\n    }\n}\n",
+            "a=",
+            "b="
+        ),
+        "function foo() {\n    if (x) {\n        a = 1;\n        b = 2;\n    }\n}\n"
+    );
+    assert_eq!(
+        format_range("function foo() {\n  if(x){y()}\n  z()\n}\n", "y", "y"),
+        "function foo() {\n  if (x) {\n    y();\n  }\n  z()\n}\n"
+    );
+}
+
+#[test]
+fn enclosing_statement() {
+    // The block cannot be formatted on its own lines, so the function is formatted.
+    assert_eq!(
+        format_range("function foo() { a  ; b  ; }\nc  ;\n", "b", "b"),
+        "function foo() {\n  a;\n  b;\n}\nc  ;\n"
+    );
+    // The range includes the closing `}` of the block.
+    assert_eq!(format_range("if (x) {\n  a  ;\n}\nb  ;\n", "a", "}"), "if (x) {\n  a;\n}\nb  ;\n");
+}
+
+#[test]
+fn minimal_edits() {
+    let source_text = "function foo() {\n  let  x=1;\n}\n";
+    let offset = u32::try_from(source_text.find(';').unwrap()).unwrap() + 1;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let on_type =
+        Formatter::new(&allocator, FormatOptions::default()).format_on_type(&ret.program, offset);
+    assert_eq!(on_type, vec![TextEdit { span: Span::new(23, 26), text: "x = ".to_string() }]);
+
+    assert!(edits("a;\n\nb;\n", Span::new(2, 3)).is_empty());
+    assert!(edits("a;\nb;\n", Span::new(0, 6)).is_empty());
+}
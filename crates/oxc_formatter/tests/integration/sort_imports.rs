@@ -0,0 +1,120 @@
+use oxc_allocator::Allocator;
+use oxc_formatter::{FormatOptions, Formatter, ImportGroup, SortImports};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn format_with(source_text: &str, sort_imports: Option<SortImports>) -> String {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+    assert!(ret.errors.is_empty());
+    let options = FormatOptions { sort_imports, ..FormatOptions::default() };
+    Formatter::new(&allocator, options).build(&ret.program)
+}
+
+fn format(source_text: &str) -> String {
+    format_with(source_text, Some(SortImports::default()))
+}
+
+#[test]
+fn groups() {
+    assert_eq!(
+        format(
+            r#"
+import { b, a as c } from "./b";
+import React from "react";
+import type { Props } from "react";
+import { join } from "node:path";
+import { utils } from "~/utils";
+import fs from "fs/promises";
+import * as lodash from "Lodash";
+import { x } from "../x";
+"#
+        ),
+        r#"import fs from "fs/promises";
+import { join } from "node:path";
+
+import * as lodash from "Lodash";
+import React from "react";
+import type { Props } from "react";
+
+import { utils } from "~/utils";
+
+import { x } from "../x";
+import { a as c, b } from "./b";
+"#
+    );
+}
+
+#[test]
+fn side_effect_imports() {
+    assert_eq!(
+        format(
+            r#"import b from "b";
+import a from "a";
+import "./polyfill";
+import d from "d";
+import c from "c";
+
+const x = 1;
+"#
+        ),
+        r#"import a from "a";
+import b from "b";
+import "./polyfill";
+import c from "c";
+import d from "d";
+
+const x = 1;
+"#
+    );
+}
+
+#[test]
+fn comments() {
+    assert_eq!(
+        format(
+            r#"// b
+import b from "b";
+import a from "a"; // a
+"#
+        ),
+        r#"import a from "a"; // a
+// b
+import b from "b";
+"#
+    );
+}
+
+#[test]
+fn options() {
+    let sort_imports = SortImports {
+        groups: vec![ImportGroup::Relative, ImportGroup::External],
+        internal_prefixes: vec!["@app/".to_string()],
+        newlines_between: false,
+    };
+    assert_eq!(
+        format_with(
+            r#"import { a } from "a";
+import { app } from "@app/app";
+
+import { b } from "./b";
+import { join } from "path";
+"#,
+            Some(sort_imports)
+        ),
+        r#"import { b } from "./b";
+import { a } from "a";
+import { app } from "@app/app";
+import { join } from "path";
+"#
+    );
+}
+
+#[test]
+fn disabled() {
+    let source_text = r#"import { b, a } from "b";
+
+import a from "a";
+"#;
+    assert_eq!(format_with(source_text, None), source_text);
+}
@@ -1,6 +1,8 @@
 use std::{
     cell::{Ref, RefCell},
     fmt::{self, Debug, Display},
+    path::Path,
+    sync::Arc,
 };
 
 use itertools::Itertools;
@@ -16,6 +18,7 @@ use crate::{
         ConfigStore, ESLintRule, LintPlugins, OxlintOverrides, OxlintRules,
         overrides::OxlintOverride,
     },
+    js_plugins::{JsPluginHost, JsPluginSpecifier},
     rules::RULES,
 };
 
@@ -25,6 +28,11 @@ pub struct ConfigStoreBuilder {
     config: LintConfig,
     overrides: OxlintOverrides,
     cache: RulesCache,
+    /// JS plugins to load when building.
+    js_plugins: Vec<JsPluginSpecifier>,
+    /// All rule configurations, in the order they apply. Those for rules of a JS plugin are
+    /// resolved once the plugins are loaded.
+    js_rule_configs: Vec<ESLintRule>,
 }
 
 impl Default for ConfigStoreBuilder {
@@ -44,7 +52,7 @@ impl ConfigStoreBuilder {
         let overrides = OxlintOverrides::default();
         let cache = RulesCache::new(config.plugins);
 
-        Self { rules, config, overrides, cache }
+        Self { rules, config, overrides, cache, js_plugins: vec![], js_rule_configs: vec![] }
     }
 
     /// Warn on all rules in all plugins and categories, including those in `nursery`.
@@ -63,6 +71,8 @@ impl ConfigStoreBuilder {
             config,
             overrides,
             cache,
+            js_plugins: vec![],
+            js_rule_configs: vec![],
         }
     }
 
@@ -101,13 +111,28 @@ impl ConfigStoreBuilder {
             path,
             ignore_patterns: _,
             extends,
+            js_plugins,
         } = oxlintrc;
 
-        let config = LintConfig { plugins, settings, env, globals, path: Some(path) };
+        let base = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        let js_plugins = js_plugins
+            .into_iter()
+            .map(|specifier| JsPluginSpecifier { specifier, base: base.clone() })
+            .collect();
+        let config = LintConfig {
+            plugins,
+            settings,
+            env,
+            globals,
+            path: Some(path),
+            js_plugins: None,
+            js_rules: vec![],
+        };
         let rules =
             if start_empty { FxHashSet::default() } else { Self::warn_correctness(plugins) };
         let cache = RulesCache::new(config.plugins);
-        let mut builder = Self { rules, config, overrides, cache };
+        let mut builder =
+            Self { rules, config, overrides, cache, js_plugins, js_rule_configs: vec![] };
 
         for filter in categories.filters() {
             builder = builder.with_filter(&filter);
@@ -167,6 +192,15 @@ impl ConfigStoreBuilder {
                                     std::mem::take(&mut extended_config_store.overrides);
                                 builder = builder.with_overrides(overrides);
                             }
+
+                            for js_plugin in extended_config_store.js_plugins.drain(..) {
+                                if !builder.js_plugins.contains(&js_plugin) {
+                                    builder.js_plugins.push(js_plugin);
+                                }
+                            }
+                            builder
+                                .js_rule_configs
+                                .append(&mut extended_config_store.js_rule_configs);
                         }
                         Err(err) => {
                             return Err(ConfigBuilderError::InvalidConfigFile {
@@ -182,6 +216,7 @@ impl ConfigStoreBuilder {
 
             oxlintrc_rules.override_rules(&mut builder.rules, all_rules.as_slice());
         }
+        builder.js_rule_configs.extend(oxlintrc_rules.rules);
 
         Ok(builder)
     }
@@ -301,7 +336,15 @@ impl ConfigStoreBuilder {
     }
 
     /// # Errors
-    pub fn build(self) -> Result<ConfigStore, OxcDiagnostic> {
+    ///
+    /// Returns an error if JS plugins are configured but fail to load.
+    pub fn build(mut self) -> Result<ConfigStore, OxcDiagnostic> {
+        if !self.js_plugins.is_empty() {
+            let host = JsPluginHost::new(&self.js_plugins)?;
+            host.configure_rules(&mut self.config.js_rules, &self.js_rule_configs);
+            self.config.js_plugins = Some(Arc::new(host));
+        }
+
         // When a plugin gets disabled before build(), rules for that plugin aren't removed until
         // with_filters() gets called. If the user never calls it, those now-undesired rules need
         // to be taken out.
@@ -1,6 +1,11 @@
-use std::{path::Path, sync::Arc};
+use std::{
+    fmt::{self, Write},
+    hash::Hasher,
+    path::Path,
+    sync::{Arc, OnceLock},
+};
 
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashSet, FxHasher};
 
 use super::{LintConfig, LintPlugins, overrides::OxlintOverrides};
 use crate::{RuleWithSeverity, rules::RULES};
@@ -32,6 +37,8 @@ struct Config {
 #[derive(Debug, Clone)]
 pub struct ConfigStore {
     base: Config,
+    /// Lazily computed by [`ConfigStore::hash`].
+    hash: OnceLock<u64>,
 }
 
 impl ConfigStore {
@@ -44,7 +51,7 @@ impl ConfigStore {
             rules: Arc::from(base_rules.into_boxed_slice()),
             config: Arc::new(base_config),
         };
-        Self { base: Config { base, overrides } }
+        Self { base: Config { base, overrides }, hash: OnceLock::new() }
     }
 
     pub fn number_of_rules(&self) -> usize {
@@ -59,6 +66,29 @@ impl ConfigStore {
         self.base.base.config.plugins
     }
 
+    /// A hash of the rules, their options and the rest of the configuration in this store,
+    /// overrides and the source texts of JS plugins included. Cached lint results are only reused
+    /// while it stays the same.
+    pub(crate) fn hash(&self) -> u64 {
+        *self.hash.get_or_init(|| {
+            let mut hasher = FxHasher::default();
+            let mut rules = self.base.base.rules.iter().collect::<Vec<_>>();
+            rules.sort_unstable_by_key(|rule| (rule.plugin_name(), rule.name()));
+            // Rule options are only reachable through `Debug`.
+            let mut writer = HashWriter(&mut hasher);
+            for rule in rules {
+                write!(writer, "{rule:?}").unwrap();
+            }
+            write!(writer, "{:?}", self.base.base.config).unwrap();
+            let overrides = serde_json::to_string(&self.base.overrides).unwrap_or_default();
+            hasher.write(overrides.as_bytes());
+            if let Some(js_plugins) = &self.base.base.config.js_plugins {
+                hasher.write(js_plugins.sources_hash());
+            }
+            hasher.finish()
+        })
+    }
+
     pub(crate) fn resolve(&self, path: &Path) -> ResolvedLinterState {
         // TODO: based on the `path` provided, resolve the configuration file to use.
         let resolved_config = &self.base;
@@ -92,6 +122,7 @@ impl ConfigStore {
         let mut env = config.base.config.env.clone();
         let mut globals = config.base.config.globals.clone();
         let mut plugins = config.base.config.plugins;
+        let mut js_rules = config.base.config.js_rules.clone();
         let mut rules = config
             .base
             .rules
@@ -109,6 +140,9 @@ impl ConfigStore {
         for override_config in overrides_to_apply {
             if !override_config.rules.is_empty() {
                 override_config.rules.override_rules(&mut rules, &all_rules);
+                if let Some(js_plugins) = &config.base.config.js_plugins {
+                    js_plugins.configure_rules(&mut js_rules, &override_config.rules.rules);
+                }
             }
 
             if let Some(override_plugins) = override_config.plugins {
@@ -127,6 +161,7 @@ impl ConfigStore {
         let config = if plugins == config.base.config.plugins
             && env == config.base.config.env
             && globals == config.base.config.globals
+            && js_rules == config.base.config.js_rules
         {
             Arc::clone(&config.base.config)
         } else {
@@ -135,6 +170,7 @@ impl ConfigStore {
             config.plugins = plugins;
             config.env = env;
             config.globals = globals;
+            config.js_rules = js_rules;
             Arc::new(config)
         };
 
@@ -143,6 +179,16 @@ impl ConfigStore {
     }
 }
 
+/// Feeds formatted output into a hasher.
+struct HashWriter<'a>(&'a mut FxHasher);
+
+impl Write for HashWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.write(s.as_bytes());
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{ConfigStore, OxlintOverrides};
@@ -278,6 +324,8 @@ mod test {
             settings: OxlintSettings::default(),
             globals: OxlintGlobals::default(),
             path: None,
+            js_plugins: None,
+            js_rules: vec![],
         };
         let overrides = from_json!([{
             "files": ["*.jsx", "*.tsx"],
@@ -311,6 +359,8 @@ mod test {
             settings: OxlintSettings::default(),
             globals: OxlintGlobals::default(),
             path: None,
+            js_plugins: None,
+            js_rules: vec![],
         };
 
         let overrides = from_json!([{
@@ -335,6 +385,8 @@ mod test {
             settings: OxlintSettings::default(),
             globals: OxlintGlobals::default(),
             path: None,
+            js_plugins: None,
+            js_rules: vec![],
         };
 
         let overrides = from_json!([{
@@ -359,6 +411,8 @@ mod test {
             settings: OxlintSettings::default(),
             globals: OxlintGlobals::default(),
             path: None,
+            js_plugins: None,
+            js_rules: vec![],
         };
 
         let overrides = from_json!([{
@@ -389,6 +443,8 @@ mod test {
                 "Secret": "writeable"
             }),
             path: None,
+            js_plugins: None,
+            js_rules: vec![],
         };
 
         let overrides = from_json!([{
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::Arc};
 
 mod categories;
 mod config_builder;
@@ -21,6 +21,8 @@ pub use plugins::LintPlugins;
 pub use rules::{ESLintRule, OxlintRules};
 pub use settings::{OxlintSettings, jsdoc::JSDocPluginSettings};
 
+use crate::js_plugins::{JsPluginHost, JsRule};
+
 #[derive(Debug, Default, Clone)]
 pub struct LintConfig {
     pub(crate) plugins: LintPlugins,
@@ -31,6 +33,10 @@ pub struct LintConfig {
     pub(crate) globals: OxlintGlobals,
     /// Absolute path to the configuration file (may be `None` if there is no file).
     pub(crate) path: Option<PathBuf>,
+    /// Host for the JS plugins listed in `jsPlugins`, if there are any.
+    pub(crate) js_plugins: Option<Arc<JsPluginHost>>,
+    /// Enabled rules from JS plugins.
+    pub(crate) js_rules: Vec<JsRule>,
 }
 
 impl From<Oxlintrc> for LintConfig {
@@ -41,6 +47,8 @@ impl From<Oxlintrc> for LintConfig {
             env: config.env,
             globals: config.globals,
             path: Some(config.path),
+            js_plugins: None,
+            js_rules: vec![],
         }
     }
 }
@@ -102,6 +102,33 @@ pub struct Oxlintrc {
     /// overriding the previous ones.
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub extends: Vec<PathBuf>,
+    /// ESLint plugins written in JavaScript, to load custom rules from. Requires Node.js.
+    ///
+    /// Each entry is a path relative to the configuration file, or the name of an installed
+    /// package. A plugin's rules are configured in `rules` like any other, using the plugin's
+    /// `meta.name` (with the `eslint-plugin-` prefix removed) or, failing that, its file or package
+    /// name as the prefix.
+    ///
+    /// Rules receive the ESTree AST and a subset of ESLint's rule context: `context.options`,
+    /// `context.filename`, `context.sourceCode` and `context.report`, including fixes and
+    /// suggestions. Visitors may only select nodes by type, e.g. `Identifier` or
+    /// `CallExpression:exit`.
+    ///
+    /// Example
+    ///
+    /// `.oxlintrc.json`
+    ///
+    /// ```json
+    /// {
+    ///   "jsPlugins": ["./plugins/my-rules.js", "eslint-plugin-foo"],
+    ///   "rules": {
+    ///     "my-rules/no-legacy-api": "error",
+    ///     "foo/bar": ["warn", { "option": true }]
+    ///   }
+    /// }
+    /// ```
+    #[serde(rename = "jsPlugins", skip_serializing_if = "Vec::is_empty")]
+    pub js_plugins: Vec<String>,
 }
 
 impl Oxlintrc {
@@ -163,6 +190,7 @@ mod test {
         assert_eq!(config.env, OxlintEnv::default());
         assert_eq!(config.path, PathBuf::default());
         assert_eq!(config.extends, Vec::<PathBuf>::default());
+        assert!(config.js_plugins.is_empty());
     }
 
     #[test]
@@ -211,4 +239,11 @@ mod test {
         let config: Oxlintrc = serde_json::from_str(r#"{"extends": []}"#).unwrap();
         assert_eq!(0, config.extends.len());
     }
+
+    #[test]
+    fn test_oxlintrc_js_plugins() {
+        let config: Oxlintrc =
+            serde_json::from_str(r#"{"jsPlugins": ["./plugin.js", "eslint-plugin-foo"]}"#).unwrap();
+        assert_eq!(config.js_plugins, vec!["./plugin.js", "eslint-plugin-foo"]);
+    }
 }
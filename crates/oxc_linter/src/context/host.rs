@@ -2,7 +2,7 @@ use std::{borrow::Cow, cell::RefCell, path::Path, rc::Rc, sync::Arc};
 
 use oxc_diagnostics::{OxcDiagnostic, Severity};
 use oxc_semantic::Semantic;
-use oxc_span::{SourceType, Span};
+use oxc_span::{GetSpan, SourceType, Span};
 
 use crate::{
     FrameworkFlags, RuleWithSeverity,
@@ -64,6 +64,13 @@ pub struct ContextHost<'a> {
     pub(super) frameworks: FrameworkFlags,
     /// A list of all available linter plugins.
     pub(super) plugins: LintPlugins,
+    /// Whether type-aware rules are enabled.
+    ///
+    /// Set via the `--type-aware` CLI flag.
+    pub(super) type_aware: bool,
+    /// Span of the code generated for the expressions of a Vue, Svelte or Astro template, which
+    /// is linted together with the script whose scope it shares. See [`crate::loader::Template`].
+    pub(super) template_span: Option<Span>,
 }
 
 impl<'a> ContextHost<'a> {
@@ -101,10 +108,19 @@ impl<'a> ContextHost<'a> {
             config,
             frameworks: options.framework_hints,
             plugins,
+            type_aware: options.type_aware,
+            template_span: None,
         }
         .sniff_for_frameworks()
     }
 
+    /// Set the span of the code generated for a template, see [`ContextHost::template_span`].
+    #[inline]
+    pub fn with_template_span(mut self, template_span: Option<Span>) -> Self {
+        self.template_span = template_span;
+        self
+    }
+
     /// Set the linter configuration for this context.
     #[inline]
     pub fn with_config(mut self, config: &Arc<LintConfig>) -> Self {
@@ -147,11 +163,48 @@ impl<'a> ContextHost<'a> {
         self.semantic.source_type()
     }
 
+    /// Span of the code generated for the expressions of a Vue, Svelte or Astro template, if the
+    /// file has one and it is linted together with this script.
+    ///
+    /// Rules can use this to tell template expressions apart from the script, or to skip
+    /// components whose template is unknown, since script bindings may be used there.
+    #[inline]
+    pub fn template_span(&self) -> Option<Span> {
+        self.template_span
+    }
+
     #[inline]
     pub fn plugins(&self) -> LintPlugins {
         self.plugins
     }
 
+    /// Whether rules that need type information should be run.
+    #[inline]
+    pub fn is_type_aware(&self) -> bool {
+        self.type_aware
+    }
+
+    /// Run the rules of JS plugins on the file, if any are enabled.
+    pub fn run_js_plugins(&self) {
+        let Some(js_plugins) = &self.config.js_plugins else { return };
+        if self.config.js_rules.is_empty() {
+            return;
+        }
+        let messages =
+            js_plugins.lint(&self.file_path, self.semantic(), &self.config.js_rules, self.fix);
+        for message in messages {
+            // Failures of the plugins themselves have no error code, and can't be disabled.
+            if let (Some(plugin), Some(rule)) =
+                (&message.error.code.scope, &message.error.code.number)
+            {
+                if self.disable_directives.contains(&format!("{plugin}/{rule}"), message.span()) {
+                    continue;
+                }
+            }
+            self.push_diagnostic(message);
+        }
+    }
+
     /// Add a diagnostic message to the end of the list of diagnostics. Can be used
     /// by any rule to report issues.
     #[inline]
@@ -17,6 +17,7 @@ use crate::{
     config::GlobalValue,
     disable_directives::DisableDirectives,
     fixer::{FixKind, Message, RuleFix, RuleFixer},
+    type_checker::TypeChecker,
 };
 
 mod host;
@@ -140,6 +141,21 @@ impl<'a> LintContext<'a> {
         &self.parent.file_path
     }
 
+    /// Span of the code generated for the expressions of a Vue, Svelte or Astro template, see
+    /// [`ContextHost::template_span`].
+    #[inline]
+    pub fn template_span(&self) -> Option<Span> {
+        self.parent.template_span
+    }
+
+    /// Type information for the file being linted.
+    ///
+    /// Returns [`None`] unless type-aware linting is enabled.
+    #[inline]
+    pub(crate) fn type_checker(&self) -> Option<TypeChecker<'_, 'a>> {
+        self.parent.type_aware.then(|| TypeChecker::new(&self.parent.semantic))
+    }
+
     /// Plugin settings
     #[inline]
     pub fn settings(&self) -> &OxlintSettings {
@@ -386,7 +402,7 @@ impl<'a> LintContext<'a> {
 /// assert_eq!(plugin_name_to_prefix("react"), "eslint-plugin-react");
 /// ```
 #[inline]
-fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
+pub fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
     PLUGIN_PREFIXES.get(plugin_name).copied().unwrap_or(plugin_name)
 }
 
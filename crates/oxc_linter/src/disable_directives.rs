@@ -38,7 +38,7 @@ impl<'a> DisableDirectives<'a> {
         self.used_disable_comments.borrow_mut().push(disable_directive);
     }
 
-    pub fn contains(&self, rule_name: &'static str, span: Span) -> bool {
+    pub fn contains(&self, rule_name: &str, span: Span) -> bool {
         let matched_intervals = self
             .intervals
             .find(span.start, span.end)
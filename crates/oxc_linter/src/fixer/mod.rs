@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use oxc_codegen::{CodeGenerator, CodegenOptions};
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{DiagnosticFix, OxcDiagnostic};
 use oxc_span::{GetSpan, Span};
 
 use crate::LintContext;
@@ -289,6 +289,20 @@ impl<'a> Message<'a> {
         };
         Self { error, span: Span::new(start, end), fix, fixed: false }
     }
+
+    /// Split into the diagnostic and its fix, if the fix changes the source text.
+    pub fn into_diagnostic_with_fix(self) -> (OxcDiagnostic, Option<DiagnosticFix>) {
+        let fix =
+            self.fix.filter(|fix| !(fix.span.is_empty() && fix.content.is_empty())).map(|fix| {
+                DiagnosticFix {
+                    start: fix.span.start,
+                    end: fix.span.end,
+                    content: Cow::Owned(fix.content.into_owned()),
+                    message: fix.message.map(|message| Cow::Owned(message.into_owned())),
+                }
+            });
+        (self.error, fix)
+    }
 }
 
 impl From<Message<'_>> for OxcDiagnostic {
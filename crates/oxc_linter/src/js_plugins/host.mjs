@@ -0,0 +1,338 @@
+// Host for oxlint JavaScript plugins.
+//
+// Reads newline-delimited JSON requests from stdin and writes one JSON response line per request
+// to stdout. Offsets exchanged with oxlint are UTF-8 byte offsets; rules see UTF-16 offsets, the
+// same as in ESLint.
+
+import { createRequire } from 'node:module';
+import { basename, extname, join } from 'node:path';
+import { createInterface } from 'node:readline';
+import { pathToFileURL } from 'node:url';
+
+// stdout is reserved for responses, so route plugin logging to stderr.
+const writeResponse = process.stdout.write.bind(process.stdout);
+console.log = console.info = console.debug = (...args) => console.error(...args);
+
+/** @type {{ name: string, rules: Map<string, object> }[]} */
+const plugins = [];
+
+function pluginName(plugin, specifier) {
+  let name = plugin.meta?.name ?? specifier;
+  if (name === specifier && !name.startsWith('@') && /[\\/]/.test(name)) {
+    name = basename(name, extname(name));
+  }
+  if (name.startsWith('@')) {
+    // `@scope/eslint-plugin` -> `@scope`, `@scope/eslint-plugin-foo` -> `@scope/foo`
+    return name.replace(/\/eslint-plugin(-|$)/, (_, dash) => (dash ? '/' : ''));
+  }
+  return name.replace(/^eslint-plugin-/, '');
+}
+
+async function load({ specifier, base }) {
+  const require = createRequire(join(base, 'noop.js'));
+  const path = require.resolve(specifier);
+  const module = await import(pathToFileURL(path).href);
+  const plugin = module.default ?? module;
+  if (!plugin || typeof plugin.rules !== 'object') {
+    throw new Error(`JS plugin \`${specifier}\` does not export any rules`);
+  }
+  const name = pluginName(plugin, specifier);
+  plugins.push({ name, rules: new Map(Object.entries(plugin.rules)) });
+  return {
+    name,
+    path,
+    rules: Object.entries(plugin.rules).map(([rule, definition]) => ({
+      name: rule,
+      fixable: Boolean(definition.meta?.fixable),
+      hasSuggestions: Boolean(definition.meta?.hasSuggestions),
+      url: definition.meta?.docs?.url ?? null,
+    })),
+  };
+}
+
+/** Maps between UTF-8 byte offsets and UTF-16 offsets for non-ASCII source text. */
+class Offsets {
+  constructor(text) {
+    const byteLength = Buffer.byteLength(text);
+    if (byteLength === text.length) {
+      this.toUtf16 = this.toUtf8 = (offset) => offset;
+      return;
+    }
+    const utf16 = new Uint32Array(byteLength + 1);
+    const utf8 = new Uint32Array(text.length + 1);
+    let byte = 0;
+    for (let i = 0; i < text.length; i++) {
+      utf8[i] = byte;
+      const code = text.charCodeAt(i);
+      const size = code < 0x80 ? 1 : code < 0x800 ? 2 : 3;
+      if (code >= 0xd800 && code <= 0xdbff && i + 1 < text.length) {
+        // Surrogate pair: 4 bytes for 2 code units.
+        utf16.fill(i, byte, byte + 4);
+        utf8[++i] = byte;
+        byte += 4;
+        continue;
+      }
+      utf16.fill(i, byte, byte + size);
+      byte += size;
+    }
+    utf16[byteLength] = text.length;
+    utf8[text.length] = byteLength;
+    this.toUtf16 = (offset) => utf16[offset];
+    this.toUtf8 = (offset) => utf8[offset];
+  }
+}
+
+class SourceCode {
+  constructor(text, ast, comments) {
+    this.text = text;
+    this.ast = ast;
+    this.lines = text.split(/\r\n|[\r\n\u2028\u2029]/u);
+    this.lineStarts = [0];
+    for (const match of text.matchAll(/\r\n|[\r\n\u2028\u2029]/gu)) {
+      this.lineStarts.push(match.index + match[0].length);
+    }
+    this.comments = comments;
+  }
+
+  getText(node, beforeCount = 0, afterCount = 0) {
+    if (!node) return this.text;
+    return this.text.slice(Math.max(node.range[0] - beforeCount, 0), node.range[1] + afterCount);
+  }
+
+  getAllComments() {
+    return this.comments;
+  }
+
+  getAncestors(node) {
+    const ancestors = [];
+    for (let parent = node.parent; parent; parent = parent.parent) ancestors.unshift(parent);
+    return ancestors;
+  }
+
+  getLocFromIndex(index) {
+    let low = 0;
+    let high = this.lineStarts.length - 1;
+    while (low < high) {
+      const mid = (low + high + 1) >> 1;
+      if (this.lineStarts[mid] <= index) low = mid;
+      else high = mid - 1;
+    }
+    return { line: low + 1, column: index - this.lineStarts[low] };
+  }
+
+  getIndexFromLoc({ line, column }) {
+    return (this.lineStarts[line - 1] ?? this.text.length) + column;
+  }
+}
+
+/** Converts offsets to UTF-16 and adds `range`, `loc` and `parent` like ESLint's parser. */
+function prepare(node, parent, offsets, sourceCode) {
+  node.start = offsets.toUtf16(node.start);
+  node.end = offsets.toUtf16(node.end);
+  node.range = [node.start, node.end];
+  Object.defineProperty(node, 'parent', { value: parent, writable: true });
+  Object.defineProperty(node, 'loc', {
+    get() {
+      return { start: sourceCode.getLocFromIndex(node.start), end: sourceCode.getLocFromIndex(node.end) };
+    },
+  });
+  for (const key in node) {
+    const value = node[key];
+    if (Array.isArray(value)) {
+      for (const child of value) {
+        if (isNode(child)) prepare(child, node, offsets, sourceCode);
+      }
+    } else if (isNode(value)) {
+      prepare(value, node, offsets, sourceCode);
+    }
+  }
+}
+
+function isNode(value) {
+  return value !== null && typeof value === 'object' && typeof value.type === 'string';
+}
+
+/** Calls visitor functions for `node` and its descendants, in source order. */
+function traverse(node, listeners) {
+  listeners.enter(node);
+  for (const key in node) {
+    const value = node[key];
+    if (Array.isArray(value)) {
+      for (const child of value) {
+        if (isNode(child)) traverse(child, listeners);
+      }
+    } else if (isNode(value)) {
+      traverse(value, listeners);
+    }
+  }
+  listeners.exit(node);
+}
+
+/** Splits a visitor into `enter` and `exit` handlers keyed by node type (`*` for any node). */
+function parseVisitor(visitor) {
+  const enter = new Map();
+  const exit = new Map();
+  for (const [key, handler] of Object.entries(visitor)) {
+    if (typeof handler !== 'function' || key.startsWith('onCodePath')) continue;
+    for (let selector of key.split(',')) {
+      selector = selector.trim();
+      let handlers = enter;
+      if (selector.endsWith(':exit')) {
+        selector = selector.slice(0, -':exit'.length);
+        handlers = exit;
+      }
+      if (selector !== '*' && !/^[A-Za-z]+$/.test(selector)) {
+        throw new Error(`Unsupported selector \`${key}\`, only node types are supported`);
+      }
+      if (!handlers.has(selector)) handlers.set(selector, []);
+      handlers.get(selector).push(handler);
+    }
+  }
+  return { enter, exit };
+}
+
+function interpolate(message, data) {
+  if (!data) return message;
+  return message.replace(/\{\{([^{}]+?)\}\}/g, (match, key) => {
+    key = key.trim();
+    return key in data ? String(data[key]) : match;
+  });
+}
+
+const fixer = {
+  insertTextAfter: (nodeOrToken, text) => fixer.insertTextAfterRange(nodeOrToken.range, text),
+  insertTextAfterRange: (range, text) => ({ range: [range[1], range[1]], text }),
+  insertTextBefore: (nodeOrToken, text) => fixer.insertTextBeforeRange(nodeOrToken.range, text),
+  insertTextBeforeRange: (range, text) => ({ range: [range[0], range[0]], text }),
+  remove: (nodeOrToken) => fixer.removeRange(nodeOrToken.range),
+  removeRange: (range) => ({ range, text: '' }),
+  replaceText: (nodeOrToken, text) => fixer.replaceTextRange(nodeOrToken.range, text),
+  replaceTextRange: (range, text) => ({ range, text }),
+};
+
+/** Runs a fix function and returns its edits as UTF-8 byte offsets. */
+function collectFixes(fix, offsets) {
+  const result = fix(fixer);
+  if (!result) return null;
+  const fixes = typeof result[Symbol.iterator] === 'function' ? [...result] : [result];
+  return fixes.filter(Boolean).map(({ range, text }) => ({
+    start: offsets.toUtf8(range[0]),
+    end: offsets.toUtf8(range[1]),
+    text,
+  }));
+}
+
+function lint({ filename, sourceText, ast, comments, rules }) {
+  const offsets = new Offsets(sourceText);
+  const sourceCode = new SourceCode(sourceText, ast, comments);
+  prepare(ast, null, offsets, sourceCode);
+  for (const comment of comments) {
+    comment.start = offsets.toUtf16(comment.start);
+    comment.end = offsets.toUtf16(comment.end);
+    comment.range = [comment.start, comment.end];
+    // Strip the `//` or `/* */` delimiters.
+    comment.value = sourceText.slice(comment.start + 2, comment.type === 'Line' ? comment.end : comment.end - 2);
+  }
+
+  const reports = [];
+  const errors = [];
+  const visitors = [];
+  rules.forEach(({ plugin, rule: ruleName, options }, index) => {
+    const rule = plugins[plugin].rules.get(ruleName);
+    const meta = rule.meta ?? {};
+    const context = {
+      id: `${plugins[plugin].name}/${ruleName}`,
+      options,
+      settings: {},
+      filename,
+      physicalFilename: filename,
+      cwd: process.cwd(),
+      sourceCode,
+      getFilename: () => filename,
+      getPhysicalFilename: () => filename,
+      getCwd: () => process.cwd(),
+      getSourceCode: () => sourceCode,
+      report(descriptor) {
+        let message = descriptor.message;
+        if (descriptor.messageId !== undefined) {
+          message = meta.messages?.[descriptor.messageId];
+          if (message === undefined) {
+            throw new TypeError(`context.report() called with unknown messageId '${descriptor.messageId}'`);
+          }
+        }
+        let start;
+        let end;
+        if (descriptor.loc) {
+          const loc = descriptor.loc.start ? descriptor.loc : { start: descriptor.loc, end: descriptor.loc };
+          start = sourceCode.getIndexFromLoc(loc.start);
+          end = sourceCode.getIndexFromLoc(loc.end);
+        } else {
+          [start, end] = descriptor.node.range;
+        }
+        const report = {
+          rule: index,
+          message: interpolate(message, descriptor.data),
+          start: offsets.toUtf8(start),
+          end: offsets.toUtf8(end),
+          fix: null,
+          suggestion: null,
+        };
+        if (typeof descriptor.fix === 'function' && meta.fixable) {
+          report.fix = collectFixes(descriptor.fix, offsets);
+        }
+        const suggestion = descriptor.suggest?.[0];
+        if (suggestion && meta.hasSuggestions) {
+          const description = suggestion.messageId === undefined
+            ? suggestion.desc
+            : meta.messages?.[suggestion.messageId];
+          report.suggestion = {
+            message: interpolate(description ?? '', suggestion.data ?? descriptor.data),
+            fixes: collectFixes(suggestion.fix, offsets),
+          };
+        }
+        reports.push(report);
+      },
+    };
+    try {
+      const create = typeof rule === 'function' ? rule : rule.create;
+      visitors.push({ index, ...parseVisitor(create(context)) });
+    } catch (error) {
+      errors.push({ rule: index, message: String(error?.stack ?? error) });
+    }
+  });
+
+  const failed = new Set();
+  const call = (phase, node) => {
+    for (const visitor of visitors) {
+      if (failed.has(visitor.index)) continue;
+      const handlers = visitor[phase];
+      try {
+        for (const listener of handlers.get(node.type) ?? []) listener(node);
+        for (const listener of handlers.get('*') ?? []) listener(node);
+      } catch (error) {
+        failed.add(visitor.index);
+        errors.push({ rule: visitor.index, message: String(error?.stack ?? error) });
+      }
+    }
+  };
+  traverse(ast, { enter: (node) => call('enter', node), exit: (node) => call('exit', node) });
+
+  return { reports, errors };
+}
+
+for await (const line of createInterface({ input: process.stdin, crlfDelay: Infinity })) {
+  let response;
+  try {
+    const request = JSON.parse(line);
+    if (request.load) {
+      const loaded = [];
+      for (const entry of request.load) loaded.push(await load(entry));
+      response = { plugins: loaded };
+    } else {
+      response = lint(request.lint);
+    }
+  } catch (error) {
+    response = { error: String(error?.stack ?? error) };
+  }
+  writeResponse(`${JSON.stringify(response)}\n`);
+}
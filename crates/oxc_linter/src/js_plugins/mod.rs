@@ -0,0 +1,622 @@
+//! Custom lint rules written in JavaScript.
+//!
+//! Plugins listed under `jsPlugins` in a configuration file are ESLint plugins: modules that
+//! export a `rules` object. They are loaded into Node.js processes running `host.mjs`, which
+//! receive each file's ESTree AST (as serialized by `oxc_ast`), run the configured rules on it,
+//! and send their reports back. Communication happens over the child's stdin and stdout, one JSON
+//! message per line.
+//!
+//! Each process lints one file at a time, so files are linted in parallel by a pool of processes.
+//! Processes are started on demand, up to one per rayon thread and at most
+//! [`MAX_HOST_PROCESSES`], and are reused for the following files.
+
+use std::{
+    fmt, fs,
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+    sync::{Condvar, Mutex, MutexGuard},
+};
+
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use sha1::{Digest, Sha1};
+
+use oxc_ast::{AstKind, ast::CommentKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{AstNode, Semantic};
+use oxc_span::Span;
+
+use crate::{
+    AllowWarnDeny,
+    config::ESLintRule,
+    fixer::{CompositeFix, Fix, FixKind, Message},
+};
+
+const HOST_SCRIPT: &str = include_str!("host.mjs");
+
+const HOST_UNAVAILABLE: &str = "JS plugin host is unavailable";
+
+/// Maximum number of Node.js processes running JS plugins. Each process loads all plugins, which
+/// takes time and memory, so there are fewer processes than threads on machines with many cores.
+const MAX_HOST_PROCESSES: usize = 8;
+
+/// A JavaScript plugin module, and the directory its specifier is resolved from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct JsPluginSpecifier {
+    /// A path (`./plugins/my-rules.js`) or package name (`eslint-plugin-foo`).
+    pub specifier: String,
+    /// Directory of the configuration file that listed the plugin.
+    pub base: PathBuf,
+}
+
+/// A JS plugin rule enabled by the configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsRule {
+    /// Index into [`JsPluginHost::plugins`].
+    plugin: usize,
+    /// Index into the plugin's rules.
+    rule: usize,
+    severity: AllowWarnDeny,
+    /// Options passed to the rule as `context.options`.
+    options: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsPlugin {
+    name: String,
+    /// Resolved path of the plugin module.
+    path: PathBuf,
+    rules: Vec<JsRuleMeta>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsRuleMeta {
+    name: String,
+    fixable: bool,
+    has_suggestions: bool,
+    url: Option<String>,
+}
+
+/// A pool of Node.js processes with all JS plugins of a configuration loaded.
+pub struct JsPluginHost {
+    specifiers: Vec<JsPluginSpecifier>,
+    plugins: Vec<JsPlugin>,
+    /// SHA-1 hash of the plugin modules' paths and source texts.
+    sources_hash: [u8; 20],
+    pool: Mutex<HostPool>,
+    /// Notified when a process is returned to the pool, or a process fails to start.
+    released: Condvar,
+    max_processes: usize,
+}
+
+struct HostPool {
+    idle: Vec<HostProcess>,
+    /// Number of running processes, including those which are linting a file.
+    running: usize,
+}
+
+struct HostProcess {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Response<T> {
+    Error { error: String },
+    Ok(T),
+}
+
+#[derive(Deserialize)]
+struct LoadResponse {
+    plugins: Vec<JsPlugin>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LintRequest<'r> {
+    filename: &'r Path,
+    source_text: &'r str,
+    comments: Vec<CommentRequest>,
+    rules: Vec<RuleRequest<'r>>,
+}
+
+#[derive(Serialize)]
+struct CommentRequest {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    start: u32,
+    end: u32,
+}
+
+#[derive(Serialize)]
+struct RuleRequest<'r> {
+    plugin: usize,
+    rule: &'r str,
+    options: &'r serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct LintResponse {
+    reports: Vec<Report>,
+    errors: Vec<RuleError>,
+}
+
+#[derive(Deserialize)]
+struct Report {
+    /// Index into the request's rules.
+    rule: usize,
+    message: String,
+    start: u32,
+    end: u32,
+    fix: Option<Vec<Edit>>,
+    suggestion: Option<Suggestion>,
+}
+
+#[derive(Deserialize)]
+struct Suggestion {
+    message: String,
+    fixes: Option<Vec<Edit>>,
+}
+
+#[derive(Deserialize)]
+struct Edit {
+    start: u32,
+    end: u32,
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct RuleError {
+    rule: usize,
+    message: String,
+}
+
+impl JsPluginHost {
+    /// Start a Node.js process and load `specifiers` into it. More processes are started when
+    /// files are linted in parallel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `node` cannot be started, or if a plugin fails to load.
+    pub fn new(specifiers: &[JsPluginSpecifier]) -> Result<Self, OxcDiagnostic> {
+        let (process, plugins) = HostProcess::start(specifiers)?;
+        let mut hasher = Sha1::new();
+        for plugin in &plugins {
+            hasher.update(plugin.path.as_os_str().as_encoded_bytes());
+            hasher.update([0]);
+            hasher.update(fs::read(&plugin.path).unwrap_or_default());
+            hasher.update([0]);
+        }
+        Ok(Self {
+            specifiers: specifiers.to_vec(),
+            plugins,
+            sources_hash: hasher.finalize().into(),
+            pool: Mutex::new(HostPool { idle: vec![process], running: 1 }),
+            released: Condvar::new(),
+            max_processes: rayon::current_num_threads().clamp(1, MAX_HOST_PROCESSES),
+        })
+    }
+
+    /// Hash of the plugin modules, so cached lint results are discarded when a plugin changes.
+    ///
+    /// Only the module each plugin specifier resolves to is hashed, not the modules it imports.
+    pub(crate) fn sources_hash(&self) -> &[u8] {
+        &self.sources_hash
+    }
+
+    /// Apply rule configurations to `js_rules`, in order. Rules that don't belong to a JS plugin
+    /// are ignored.
+    pub(crate) fn configure_rules<'r>(
+        &self,
+        js_rules: &mut Vec<JsRule>,
+        configs: impl IntoIterator<Item = &'r ESLintRule>,
+    ) {
+        for config in configs {
+            let Some((plugin, rule)) = self.find_rule(&config.full_name()) else { continue };
+            js_rules.retain(|r| r.plugin != plugin || r.rule != rule);
+            if config.severity.is_warn_deny() {
+                js_rules.push(JsRule {
+                    plugin,
+                    rule,
+                    severity: config.severity,
+                    options: config.config.clone().unwrap_or_else(|| serde_json::json!([])),
+                });
+            }
+        }
+    }
+
+    fn find_rule(&self, full_name: &str) -> Option<(usize, usize)> {
+        self.plugins.iter().enumerate().find_map(|(plugin_index, plugin)| {
+            let rule_name = full_name.strip_prefix(plugin.name.as_str())?.strip_prefix('/')?;
+            let rule_index = plugin.rules.iter().position(|rule| rule.name == rule_name)?;
+            Some((plugin_index, rule_index))
+        })
+    }
+
+    /// Run `rules` on a file.
+    ///
+    /// Diagnostics of reported problems have an error code of `plugin(rule)`. Failures of the
+    /// plugins themselves are reported as diagnostics without an error code.
+    pub(crate) fn lint<'a>(
+        &self,
+        path: &Path,
+        semantic: &Semantic<'a>,
+        rules: &[JsRule],
+        fix: FixKind,
+    ) -> Vec<Message<'a>> {
+        let Some(AstKind::Program(program)) = semantic.nodes().root_node().map(AstNode::kind)
+        else {
+            return vec![];
+        };
+        let ast = if semantic.source_type().is_typescript() {
+            program.to_estree_ts_json()
+        } else {
+            program.to_estree_js_json()
+        };
+        let source_text = semantic.source_text();
+        let request = LintRequest {
+            filename: path,
+            source_text,
+            comments: semantic
+                .comments()
+                .iter()
+                .map(|comment| CommentRequest {
+                    kind: match comment.kind {
+                        CommentKind::Line => "Line",
+                        CommentKind::Block => "Block",
+                    },
+                    start: comment.span.start,
+                    end: comment.span.end,
+                })
+                .collect(),
+            rules: rules
+                .iter()
+                .map(|rule| RuleRequest {
+                    plugin: rule.plugin,
+                    rule: &self.plugins[rule.plugin].rules[rule.rule].name,
+                    options: &rule.options,
+                })
+                .collect(),
+        };
+        // The AST is already JSON, so splice it in rather than parsing it into a `Value` first.
+        let request = serde_json::to_string(&request).unwrap();
+        let request = format!(r#"{{"lint":{{"ast":{ast},{}}}"#, &request[1..]);
+
+        let response = self.acquire().and_then(|mut process| {
+            let response = process.request::<LintResponse>(&request);
+            // A process which failed may be in an unknown state, so it's not reused.
+            if response.is_ok() {
+                self.release(process);
+            } else {
+                self.discard(process);
+            }
+            response
+        });
+        let LintResponse { reports, errors } = match response {
+            Ok(response) => response,
+            Err(err) => {
+                return vec![Message::new(
+                    OxcDiagnostic::error(format!(
+                        "Failed to run JS plugins on {}: {err}",
+                        path.display()
+                    )),
+                    None,
+                )];
+            }
+        };
+
+        let mut messages = Vec::with_capacity(reports.len() + errors.len());
+        for error in errors {
+            let (plugin, rule) = self.rule_meta(&rules[error.rule]);
+            messages.push(Message::new(
+                OxcDiagnostic::error(format!(
+                    "JS plugin rule `{}/{}` failed on {}: {}",
+                    plugin.name,
+                    rule.name,
+                    path.display(),
+                    error.message
+                )),
+                None,
+            ));
+        }
+        for report in reports {
+            let js_rule = &rules[report.rule];
+            let (plugin, rule) = self.rule_meta(js_rule);
+            let (start, end) = clamp_span(report.start, report.end, source_text);
+            let mut diagnostic = OxcDiagnostic::warn(report.message)
+                .with_label(Span::new(start, end))
+                .with_error_code(plugin.name.clone(), rule.name.clone())
+                .with_severity(js_rule.severity.into());
+            if let Some(url) = &rule.url {
+                diagnostic = diagnostic.with_url(url.clone());
+            }
+
+            let mut message_fix = None;
+            if let Some(suggestion) = &report.suggestion {
+                if diagnostic.help.is_none() && !suggestion.message.is_empty() {
+                    diagnostic = diagnostic.with_help(suggestion.message.clone());
+                }
+            }
+            if let Some(edits) =
+                report.fix.filter(|_| rule.fixable && fix.can_apply(FixKind::SafeFix))
+            {
+                message_fix = merge_edits(edits, source_text);
+            }
+            if message_fix.is_none() && rule.has_suggestions && fix.can_apply(FixKind::Suggestion) {
+                if let Some(Suggestion { message, fixes: Some(edits) }) = report.suggestion {
+                    message_fix =
+                        merge_edits(edits, source_text).map(|fix| fix.with_message(message));
+                }
+            }
+            messages.push(Message::new(diagnostic, message_fix));
+        }
+        messages
+    }
+
+    /// Take an idle process from the pool, start a new one if there are none and the pool is not
+    /// full, or wait for another thread to release one.
+    fn acquire(&self) -> Result<HostProcess, String> {
+        let mut pool = self.lock_pool()?;
+        loop {
+            if let Some(process) = pool.idle.pop() {
+                return Ok(process);
+            }
+            if pool.running < self.max_processes {
+                break;
+            }
+            pool = self.released.wait(pool).map_err(|_| HOST_UNAVAILABLE.to_string())?;
+        }
+        pool.running += 1;
+        drop(pool);
+
+        // Start the process without holding the lock, so other threads can use idle processes.
+        HostProcess::start(&self.specifiers).map(|(process, _)| process).map_err(|err| {
+            if let Ok(mut pool) = self.pool.lock() {
+                pool.running -= 1;
+            }
+            self.released.notify_one();
+            err.to_string()
+        })
+    }
+
+    fn release(&self, process: HostProcess) {
+        if let Ok(mut pool) = self.pool.lock() {
+            pool.idle.push(process);
+        }
+        self.released.notify_one();
+    }
+
+    fn discard(&self, mut process: HostProcess) {
+        process.kill();
+        if let Ok(mut pool) = self.pool.lock() {
+            pool.running -= 1;
+        }
+        self.released.notify_one();
+    }
+
+    fn lock_pool(&self) -> Result<MutexGuard<'_, HostPool>, String> {
+        self.pool.lock().map_err(|_| HOST_UNAVAILABLE.to_string())
+    }
+
+    fn rule_meta(&self, rule: &JsRule) -> (&JsPlugin, &JsRuleMeta) {
+        let plugin = &self.plugins[rule.plugin];
+        (plugin, &plugin.rules[rule.rule])
+    }
+}
+
+impl HostProcess {
+    /// Start a Node.js process and load `specifiers` into it.
+    fn start(specifiers: &[JsPluginSpecifier]) -> Result<(Self, Vec<JsPlugin>), OxcDiagnostic> {
+        let mut child = Command::new("node")
+            .args(["--input-type=module", "--eval", HOST_SCRIPT])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .map_err(|err| {
+                OxcDiagnostic::error(format!("Failed to start Node.js to load JS plugins: {err}"))
+                    .with_help("JS plugins require `node` to be installed and available in PATH.")
+            })?;
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            unreachable!("stdin and stdout are piped");
+        };
+        let mut process = Self { child, stdin, stdout: BufReader::new(stdout) };
+
+        let request = serde_json::json!({ "load": specifiers }).to_string();
+        match process.request(&request) {
+            Ok(LoadResponse { plugins }) => Ok((process, plugins)),
+            Err(err) => {
+                process.kill();
+                Err(OxcDiagnostic::error(format!("Failed to load JS plugins: {err}")))
+            }
+        }
+    }
+
+    fn kill(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+
+    fn request<T: DeserializeOwned>(&mut self, request: &str) -> Result<T, String> {
+        writeln!(self.stdin, "{request}")
+            .and_then(|()| self.stdin.flush())
+            .map_err(|err| err.to_string())?;
+        let mut line = String::new();
+        match self.stdout.read_line(&mut line) {
+            Ok(0) => return Err("JS plugin host exited unexpectedly".to_string()),
+            Ok(_) => {}
+            Err(err) => return Err(err.to_string()),
+        }
+        match serde_json::from_str(&line).map_err(|err| err.to_string())? {
+            Response::Ok(response) => Ok(response),
+            Response::Error { error } => Err(error),
+        }
+    }
+}
+
+impl Drop for JsPluginHost {
+    fn drop(&mut self) {
+        if let Ok(pool) = self.pool.get_mut() {
+            for process in &mut pool.idle {
+                process.kill();
+            }
+        }
+    }
+}
+
+impl fmt::Debug for JsPluginHost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JsPluginHost").field("plugins", &self.plugins).finish_non_exhaustive()
+    }
+}
+
+/// Keep a reported span within the source text, so a misbehaving rule can't cause a panic when
+/// the diagnostic is rendered.
+#[expect(clippy::cast_possible_truncation)]
+fn clamp_span(start: u32, end: u32, source_text: &str) -> (u32, u32) {
+    let len = source_text.len() as u32;
+    let end = end.min(len);
+    (start.min(end), end)
+}
+
+/// Merge the edits of a fix into one [`Fix`], or `None` if they are out of bounds or overlap.
+fn merge_edits<'a>(mut edits: Vec<Edit>, source_text: &str) -> Option<Fix<'a>> {
+    edits.sort_unstable_by_key(|edit| (edit.start, edit.end));
+    let mut last_end = 0;
+    for edit in &edits {
+        if edit.start < last_end
+            || edit.start > edit.end
+            || source_text.get(edit.start as usize..edit.end as usize).is_none()
+        {
+            return None;
+        }
+        last_end = edit.end;
+    }
+    let fixes = edits
+        .into_iter()
+        .map(|edit| Fix::new(edit.text, Span::new(edit.start, edit.end)))
+        .collect::<Vec<_>>();
+    if fixes.is_empty() {
+        return None;
+    }
+    Some(CompositeFix::Multiple(fixes).normalize_fixes(source_text))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        env,
+        ffi::OsStr,
+        sync::{Arc, mpsc},
+        thread,
+        time::Duration,
+    };
+
+    use oxc_allocator::Allocator;
+
+    use super::{JsPluginHost, JsPluginSpecifier};
+    use crate::{
+        ConfigStoreBuilder, FixKind, LintOptions, LintService, LintServiceOptions, Linter,
+        Oxlintrc, fixer::Fixer,
+    };
+
+    const SOURCE: &str = "// TODO: rename
+const ñame = 'ü'; foo(ñame);
+
+function add(a, b) {
+  return a + b;
+}
+
+// eslint-disable-next-line local/no-foo
+foo();
+";
+
+    /// Lint `source_text` as `file_name` in `fixtures/js_plugins`, returning the reported
+    /// messages and the fixed code.
+    fn lint(file_name: &str, source_text: &str, fix: FixKind) -> (Vec<String>, String) {
+        let cwd = env::current_dir().unwrap().join("fixtures/js_plugins");
+        let oxlintrc = Oxlintrc::from_file(&cwd.join(".oxlintrc.json")).unwrap();
+        let config = ConfigStoreBuilder::from_oxlintrc(true, oxlintrc).unwrap().build().unwrap();
+        let linter = Linter::new(LintOptions::default(), config).with_fix(fix);
+        let paths = vec![Arc::<OsStr>::from(cwd.join(file_name).as_os_str())];
+        let mut service = LintService::from_linter(linter, LintServiceOptions::new(cwd, paths));
+
+        let allocator = Allocator::default();
+        let (sender, _receiver) = mpsc::channel();
+        let messages = service.run_test_source(&allocator, source_text, false, &sender);
+        let reports = messages
+            .iter()
+            .map(|message| match (&message.error.code.scope, &message.error.code.number) {
+                (Some(plugin), Some(rule)) => format!("{plugin}/{rule}: {}", message.error),
+                _ => message.error.to_string(),
+            })
+            .collect();
+        let fixed_code = Fixer::new(source_text, messages).fix().fixed_code.to_string();
+        (reports, fixed_code)
+    }
+
+    #[test]
+    fn test_reports() {
+        let (reports, fixed_code) = lint("test.js", SOURCE, FixKind::None);
+        assert_eq!(
+            reports,
+            vec![
+                "local/no-foo: Unexpected identifier `foo`.",
+                "local/max-params: Function has too many parameters (2). Maximum allowed is 1.",
+                "local/no-todo-comments: Unexpected TODO comment: TODO: rename",
+            ]
+        );
+        assert_eq!(fixed_code, SOURCE);
+    }
+
+    #[test]
+    fn test_fix() {
+        let (_, fixed_code) = lint("test.js", SOURCE, FixKind::SafeFix);
+        // Offsets are converted between UTF-16 and UTF-8, so the fix lands after `ñame`.
+        assert!(fixed_code.contains("const ñame = 'ü'; bar(ñame);"));
+        assert!(fixed_code.contains("function add(a, b)"));
+        // Disable directives apply to JS plugin rules.
+        assert!(fixed_code.ends_with("foo();\n"));
+    }
+
+    #[test]
+    fn test_suggestion() {
+        let (_, fixed_code) = lint("test.js", SOURCE, FixKind::Suggestion);
+        assert!(fixed_code.contains("function add(a) {"));
+        assert!(fixed_code.contains("foo(ñame);"));
+    }
+
+    #[test]
+    fn test_overrides() {
+        let (reports, _) = lint("test.test.js", "foo();", FixKind::None);
+        assert_eq!(reports.len(), 1);
+        assert!(reports[0].starts_with("JS plugin rule `local/no-throw` failed on"));
+        assert!(reports[0].contains("Rule failed to start."));
+    }
+
+    #[test]
+    fn test_process_pool() {
+        let base = env::current_dir().unwrap().join("fixtures/js_plugins");
+        let specifier =
+            JsPluginSpecifier { specifier: "./eslint-plugin-local.mjs".to_string(), base };
+        let mut host = JsPluginHost::new(&[specifier]).unwrap();
+        host.max_processes = 2;
+
+        thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    let process = host.acquire().unwrap();
+                    thread::sleep(Duration::from_millis(50));
+                    host.release(process);
+                });
+            }
+        });
+
+        let pool = host.pool.lock().unwrap();
+        assert!(pool.running <= 2);
+        assert_eq!(pool.idle.len(), pool.running);
+    }
+}
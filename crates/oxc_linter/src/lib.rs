@@ -11,11 +11,13 @@ mod disable_directives;
 mod fixer;
 mod frameworks;
 mod globals;
+mod js_plugins;
 mod module_graph_visitor;
 mod module_record;
 mod options;
 mod rule;
 mod service;
+mod type_checker;
 mod utils;
 
 pub mod loader;
@@ -29,13 +31,14 @@ use std::{
 };
 
 use oxc_semantic::{AstNode, Semantic};
+use oxc_span::Span;
 use rustc_hash::FxHashMap;
 
 pub use crate::{
     config::{
         ConfigBuilderError, ConfigStore, ConfigStoreBuilder, ESLintRule, LintPlugins, Oxlintrc,
     },
-    context::LintContext,
+    context::{LintContext, plugin_name_to_prefix},
     fixer::FixKind,
     frameworks::FrameworkFlags,
     loader::LINTABLE_EXTENSIONS,
@@ -43,7 +46,7 @@ pub use crate::{
     options::LintOptions,
     options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind},
     rule::{RuleCategory, RuleFixMeta, RuleMeta, RuleWithSeverity},
-    service::{LintService, LintServiceOptions},
+    service::{Baseline, BaselineMode, LintService, LintServiceOptions},
 };
 use crate::{
     config::{LintConfig, OxlintEnv, OxlintGlobals, OxlintSettings, ResolvedLinterState},
@@ -103,6 +106,13 @@ impl Linter {
         self
     }
 
+    /// Enable rules that need type information.
+    #[must_use]
+    pub fn with_type_aware(mut self, yes: bool) -> Self {
+        self.options.type_aware = yes;
+        self
+    }
+
     pub(crate) fn options(&self) -> &LintOptions {
         &self.options
     }
@@ -120,17 +130,24 @@ impl Linter {
         semantic: Rc<Semantic<'a>>,
         module_record: Arc<ModuleRecord>,
     ) -> Vec<Message<'a>> {
-        // TODO(refactor): remove branch when nested config is standardized
-        let ResolvedLinterState { rules, config } = if self.nested_configs.is_empty() {
-            // Get config + rules for this file. Takes base rules and applies glob-based overrides.
-            self.config.resolve(path)
-        } else if let Some(nearest_config) = self.get_nearest_config(path) {
-            nearest_config.resolve(path)
-        } else {
-            self.config.resolve(path)
-        };
-        let ctx_host =
-            Rc::new(ContextHost::new(path, semantic, module_record, self.options, config));
+        self.run_with_template(path, semantic, module_record, None)
+    }
+
+    /// Like [`Linter::run`], for a script followed by the code generated for the expressions of a
+    /// template at `template_span`. See [`loader::Template`].
+    pub fn run_with_template<'a>(
+        &self,
+        path: &Path,
+        semantic: Rc<Semantic<'a>>,
+        module_record: Arc<ModuleRecord>,
+        template_span: Option<Span>,
+    ) -> Vec<Message<'a>> {
+        // Get config + rules for this file. Takes base rules and applies glob-based overrides.
+        let ResolvedLinterState { rules, config } = self.config_for(path).resolve(path);
+        let ctx_host = Rc::new(
+            ContextHost::new(path, semantic, module_record, self.options, config)
+                .with_template_span(template_span),
+        );
 
         let rules = rules
             .iter()
@@ -207,6 +224,8 @@ impl Linter {
             }
         }
 
+        ctx_host.run_js_plugins();
+
         if let Some(severity) = self.options.report_unused_directive {
             if severity.is_warn_deny() {
                 ctx_host.report_unused_directives(severity.into());
@@ -216,6 +235,20 @@ impl Linter {
         ctx_host.take_diagnostics()
     }
 
+    /// Hash of the configuration used to lint `path`, see [`ConfigStore::hash`].
+    pub(crate) fn config_hash(&self, path: &Path) -> u64 {
+        self.config_for(path).hash()
+    }
+
+    fn config_for(&self, path: &Path) -> &ConfigStore {
+        // TODO(refactor): remove branch when nested config is standardized
+        if self.nested_configs.is_empty() {
+            &self.config
+        } else {
+            self.get_nearest_config(path).unwrap_or(&self.config)
+        }
+    }
+
     /// Get the nearest config for the given path, in the following priority order:
     /// 1. config file in the same directory as the path
     /// 2. config file in the closest parent directory
@@ -1,7 +1,10 @@
 use memchr::memmem::Finder;
 use oxc_span::{SourceType, Span};
 
-use super::{SCRIPT_END, SCRIPT_START};
+use super::{
+    SCRIPT_END, SCRIPT_START,
+    template::{MarkupParser, Syntax, Template, TemplateBuilder},
+};
 use crate::loader::JavaScriptSource;
 
 const ASTRO_SPLIT: &str = "---";
@@ -24,6 +27,18 @@ impl<'a> AstroPartialLoader<'a> {
         results
     }
 
+    /// Generate code for the expressions of the template after the frontmatter, whose scope is the
+    /// one of the frontmatter. Returns `None` without a frontmatter.
+    pub fn parse_template(&self, sources: &[JavaScriptSource<'a>]) -> Option<Template> {
+        let frontmatter = self.parse_frontmatter()?;
+        let section = sources.iter().position(|source| source.start == frontmatter.start)?;
+        let start = frontmatter.start as usize + frontmatter.source_text.len() + ASTRO_SPLIT.len();
+        let mut builder = TemplateBuilder::new(self.source_text, frontmatter.source_text);
+        MarkupParser::new(Syntax::Astro, self.source_text, &mut builder)
+            .parse(start, self.source_text.len());
+        Some(builder.build(section, true))
+    }
+
     /// Parse `---` frontmatter block
     #[expect(clippy::cast_possible_truncation)]
     fn parse_frontmatter(&self) -> Option<JavaScriptSource<'a>> {
@@ -187,4 +202,26 @@ mod test {
         assert_eq!(sources[1].source_text.trim(), r#"console.log("Hi");"#);
         assert_eq!(sources[1].start, 122);
     }
+
+    #[test]
+    fn test_parse_template() {
+        let source_text = r#"---
+import Card from './Card.astro';
+const items = [1, 2];
+---
+<ul class:list={["a", { b: true }]}>
+  {items.map((item) => <Card title={`#${item}`} />)}
+</ul>
+<script>
+  console.log("Hi");
+</script>
+"#;
+        let sources = parse_astro(source_text);
+        let template = AstroPartialLoader::new(source_text).parse_template(&sources).unwrap();
+        assert_eq!(template.section, 0);
+        assert_eq!(
+            template.source_text(""),
+            "\n{\nvoid ([\"a\", { b: true }]);\nvoid (items.map((item) => <Card title={`#${item}`} />));\n}\n"
+        );
+    }
 }
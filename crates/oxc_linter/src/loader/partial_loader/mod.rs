@@ -1,10 +1,14 @@
 mod astro;
 mod svelte;
+mod template;
 mod vue;
 
 use oxc_span::VALID_EXTENSIONS;
 
-pub use self::{astro::AstroPartialLoader, svelte::SveltePartialLoader, vue::VuePartialLoader};
+pub use self::{
+    astro::AstroPartialLoader, svelte::SveltePartialLoader, template::Template,
+    vue::VuePartialLoader,
+};
 use crate::loader::JavaScriptSource;
 
 const SCRIPT_START: &str = "<script";
@@ -32,6 +36,22 @@ impl PartialLoader {
             _ => None,
         }
     }
+
+    /// Generate code for the expressions of the template of special files, to be linted together
+    /// with the section of `sources` whose scope they share.
+    /// Returns `None` if the file has no template, or no such section.
+    pub fn parse_template<'a>(
+        ext: &str,
+        source_text: &'a str,
+        sources: &[JavaScriptSource<'a>],
+    ) -> Option<Template> {
+        match ext {
+            "vue" => VuePartialLoader::new(source_text).parse_template(sources),
+            "astro" => AstroPartialLoader::new(source_text).parse_template(sources),
+            "svelte" => SveltePartialLoader::new(source_text).parse_template(sources),
+            _ => None,
+        }
+    }
 }
 
 /// Find closing angle for situations where there is another `>` in between.
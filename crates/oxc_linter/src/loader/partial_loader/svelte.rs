@@ -1,7 +1,10 @@
 use memchr::memmem::Finder;
 use oxc_span::SourceType;
 
-use super::{SCRIPT_END, SCRIPT_START, find_script_closing_angle};
+use super::{
+    SCRIPT_END, SCRIPT_START, find_script_closing_angle,
+    template::{MarkupParser, Syntax, Template, TemplateBuilder, store_references},
+};
 use crate::loader::JavaScriptSource;
 
 pub struct SveltePartialLoader<'a> {
@@ -17,6 +20,18 @@ impl<'a> SveltePartialLoader<'a> {
         self.parse_script().map_or_else(Vec::new, |source| vec![source])
     }
 
+    /// Generate code for the expressions of the markup, whose scope is the one of the script.
+    /// Returns `None` without a script.
+    pub fn parse_template(&self, sources: &[JavaScriptSource<'a>]) -> Option<Template> {
+        let script = sources.first()?;
+        let end = self.source_text.len();
+        let mut builder = TemplateBuilder::new(self.source_text, script.source_text);
+        MarkupParser::new(Syntax::Svelte, self.source_text, &mut builder).parse(0, end);
+        // Stores are subscribed to with `$store`, in the markup and in the script.
+        store_references(&mut builder, 0, end);
+        Some(builder.build(0, false))
+    }
+
     fn parse_script(&self) -> Option<JavaScriptSource<'a>> {
         let script_start_finder = Finder::new(SCRIPT_START);
         let script_end_finder = Finder::new(SCRIPT_END);
@@ -84,4 +99,30 @@ mod test {
         let result = parse_svelte(source_text);
         assert_eq!(result.source_text.trim(), r#"console.log("hi");"#);
     }
+
+    #[test]
+    fn test_parse_template() {
+        let source_text = r"<script>
+  import Row from './Row.svelte';
+  import { items } from './stores';
+</script>
+
+{#each $items as { id, label }, i (id)}
+  <Row on:click={() => select(id)} {label} />
+{:else}
+  <p>none</p>
+{/each}
+{#if a > b}{@html html}{/if}
+<style>
+  p { color: red; }
+</style>";
+        let loader = SveltePartialLoader::new(source_text);
+        let sources = loader.parse();
+        let template = SveltePartialLoader::new(source_text).parse_template(&sources).unwrap();
+        assert_eq!(template.section, 0);
+        assert_eq!(
+            template.source_text(""),
+            "\n{\nfor (const [i, { id, label }] of ( $items ).entries()) {\nvoid (id);\nvoid (Row);\nvoid (() => select(id));\nvoid (label);\n}\n{\n}\nvoid ( a > b);\n{\nvoid ( html);\n}\nvoid (items);\n}\n"
+        );
+    }
 }
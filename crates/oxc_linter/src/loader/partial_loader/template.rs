@@ -0,0 +1,1099 @@
+//! Code generated for the expressions of component templates, so they are linted together with
+//! the script whose bindings they use.
+//!
+//! The generated code is appended to the script in a block statement. For example, the Vue template
+//!
+//! ```vue
+//! <li v-for="item in items" :key="item.id" @click="select(item)">{{ item.name }}</li>
+//! ```
+//!
+//! becomes
+//!
+//! ```js
+//! {
+//! for (const item of items) {
+//! void (item.id);
+//! void (() => {
+//! select(item)
+//! });
+//! void (item.name);
+//! }
+//! }
+//! ```
+//!
+//! Expressions are operands of `void`, so their values count as used, e.g. by
+//! `no-unused-expressions` and `no-unused-vars`.
+//!
+//! Expressions are copied verbatim, so [`Template::original_span`] can map the spans of
+//! diagnostics back to the template.
+
+use memchr::memmem::Finder;
+use oxc_span::{SourceType, Span};
+use oxc_syntax::keyword::is_reserved_keyword;
+
+use crate::loader::JavaScriptSource;
+
+const PREFIX: &str = "\n{\n";
+const SUFFIX: &str = "}\n";
+
+/// Code generated for the expressions of a component template.
+pub struct Template {
+    /// Index of the source section whose scope the template shares.
+    pub section: usize,
+    code: String,
+    /// Sorted by generated offset.
+    mappings: Vec<Mapping>,
+    /// Whether expressions can contain JSX, e.g. in Astro.
+    jsx: bool,
+}
+
+/// Code in [`Template::code`] copied or generated from a span of the original source text.
+struct Mapping {
+    generated: Span,
+    original: Span,
+}
+
+impl Template {
+    /// The script followed by the code generated for the template, to be parsed as one program.
+    pub fn source_text(&self, script: &str) -> String {
+        [script, PREFIX, &self.code, SUFFIX].concat()
+    }
+
+    /// Source type of [`Template::source_text`] for a script of `source_type`.
+    pub fn source_type(&self, source_type: SourceType) -> SourceType {
+        if self.jsx { source_type.with_jsx(true) } else { source_type }
+    }
+
+    /// Span of the generated code in [`Template::source_text`].
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn span(&self, script: &str) -> Span {
+        Span::sized((script.len() + PREFIX.len()) as u32, self.code.len() as u32)
+    }
+
+    /// Map `span` of [`Template::source_text`] to a span of the original file.
+    /// Returns `None` if it only covers generated code.
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn original_span(&self, script: &JavaScriptSource<'_>, span: Span) -> Option<Span> {
+        let script_end = script.source_text.len() as u32;
+        let code_start = self.span(script.source_text).start;
+        let mut result: Option<Span> = None;
+        let mut add = |span: Span| result = Some(result.map_or(span, |result| result.merge(span)));
+
+        if span.start < script_end || (span.is_empty() && span.start == script_end) {
+            let end = span.end.min(script_end);
+            add(Span::new(span.start + script.start, end + script.start));
+        }
+        if span.end > code_start {
+            let start = span.start.max(code_start) - code_start;
+            let end = span.end - code_start;
+            // Treat empty spans as the position they point at.
+            let point_end = end.max(start + 1);
+            for mapping in &self.mappings {
+                let (generated, original) = (mapping.generated, mapping.original);
+                if generated.start >= point_end {
+                    break;
+                }
+                if generated.end <= start {
+                    continue;
+                }
+                // Generated names, e.g. of components, are not mapped character by character.
+                let copied = generated.size() == original.size();
+                let mapped_start = if copied && start > generated.start {
+                    original.start + start - generated.start
+                } else {
+                    original.start
+                };
+                let mapped_end = if copied && end < generated.end {
+                    original.start + end - generated.start
+                } else {
+                    original.end
+                };
+                add(Span::new(mapped_start, mapped_end.max(mapped_start)));
+            }
+        }
+        result
+    }
+}
+
+/// Builds the code of a [`Template`].
+pub(super) struct TemplateBuilder<'a> {
+    source_text: &'a str,
+    /// The script the template shares its scope with, to look up bindings.
+    script: &'a str,
+    code: String,
+    mappings: Vec<Mapping>,
+}
+
+impl<'a> TemplateBuilder<'a> {
+    pub fn new(source_text: &'a str, script: &'a str) -> Self {
+        Self { source_text, script, code: String::new(), mappings: vec![] }
+    }
+
+    pub fn build(self, section: usize, jsx: bool) -> Template {
+        Template { section, code: self.code, mappings: self.mappings, jsx }
+    }
+
+    /// Generated code which does not correspond to the source text.
+    pub fn code(&mut self, code: &str) {
+        self.code.push_str(code);
+    }
+
+    /// Copy `start..end` of the source text.
+    pub fn source(&mut self, start: usize, end: usize) {
+        let source_text = self.source_text;
+        self.name(&source_text[start..end], start, end);
+    }
+
+    /// Generated `name` which stands for `start..end` of the source text.
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn name(&mut self, name: &str, start: usize, end: usize) {
+        if name.is_empty() {
+            return;
+        }
+        let generated = Span::sized(self.code.len() as u32, name.len() as u32);
+        self.mappings.push(Mapping { generated, original: Span::new(start as u32, end as u32) });
+        self.code.push_str(name);
+    }
+
+    /// `void (expression);`
+    pub fn expression(&mut self, start: usize, end: usize) {
+        if is_blank(&self.source_text[start..end]) {
+            return;
+        }
+        self.code("void (");
+        self.source(start, end);
+        self.code(");\n");
+    }
+
+    /// `void (() => { statements });`, e.g. for event handlers.
+    pub fn statements(&mut self, start: usize, end: usize) {
+        if is_blank(&self.source_text[start..end]) {
+            return;
+        }
+        self.code("void (() => {\n");
+        self.source(start, end);
+        self.code("\n});\n");
+    }
+
+    /// `void (name);` if the script declares `name`, e.g. for components.
+    pub fn reference(&mut self, name: &str, start: usize, end: usize) {
+        if is_identifier(name) && contains_identifier(self.script, name) {
+            self.code("void (");
+            self.name(name, start, end);
+            self.code(");\n");
+        }
+    }
+
+    /// Open a scope with `params` for the following code: `void ((params) => {`.
+    /// Returns the code which closes it.
+    pub fn open_function(&mut self, start: usize, end: usize) -> &'static str {
+        if is_blank(&self.source_text[start..end]) {
+            self.code("{\n");
+            return "}\n";
+        }
+        self.code("void ((");
+        self.source(start, end);
+        self.code(") => {\n");
+        "});\n"
+    }
+}
+
+/// Flavor of the markup of a template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Syntax {
+    Vue,
+    Svelte,
+    Astro,
+}
+
+/// Elements whose content is not markup.
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style"];
+
+/// Elements without an end tag.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Vue directives whose names are not custom directives.
+const VUE_BUILTIN_DIRECTIVES: &[&str] = &[
+    "bind", "cloak", "else", "else-if", "for", "html", "if", "is", "memo", "model", "on", "once",
+    "pre", "show", "slot", "text",
+];
+
+pub(super) struct StartTag<'a> {
+    pub name: &'a str,
+    pub start: usize,
+    pub attributes: Vec<Attribute<'a>>,
+    pub self_closing: bool,
+    /// Offset after the closing `>`.
+    pub end: usize,
+}
+
+impl StartTag<'_> {
+    pub fn attribute(&self, name: &str) -> Option<&Attribute<'_>> {
+        self.attributes.iter().find(|attribute| attribute.name == name)
+    }
+}
+
+pub(super) struct Attribute<'a> {
+    /// Empty for `{expression}` attributes of Svelte and Astro.
+    pub name: &'a str,
+    pub name_start: usize,
+    pub value: Option<AttributeValue>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ValueKind {
+    /// `"value"` or `'value'`
+    Quoted,
+    /// `{value}`
+    Braced,
+    /// `` `value` ``, the span includes the backticks.
+    TemplateLiteral,
+    Unquoted,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(super) struct AttributeValue {
+    pub kind: ValueKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Element or block whose scope is closed by `closer`.
+struct Frame<'a> {
+    /// `None` for Svelte blocks such as `{#if}`.
+    element: Option<&'a str>,
+    closer: String,
+}
+
+/// Walks the markup of a template and generates code for its expressions.
+pub(super) struct MarkupParser<'a, 'b> {
+    syntax: Syntax,
+    source_text: &'a str,
+    builder: &'b mut TemplateBuilder<'a>,
+    frames: Vec<Frame<'a>>,
+}
+
+impl<'a, 'b> MarkupParser<'a, 'b> {
+    pub fn new(syntax: Syntax, source_text: &'a str, builder: &'b mut TemplateBuilder<'a>) -> Self {
+        Self { syntax, source_text, builder, frames: vec![] }
+    }
+
+    /// Generate code for the markup in `start..end`.
+    pub fn parse(&mut self, start: usize, end: usize) {
+        let bytes = self.source_text.as_bytes();
+        let mut pointer = start;
+        while pointer < end {
+            match bytes[pointer] {
+                b'<' if self.source_text[pointer..end].starts_with("<!--") => {
+                    pointer = Finder::new("-->")
+                        .find(&bytes[pointer..end])
+                        .map_or(end, |offset| pointer + offset + 3);
+                }
+                b'<' if bytes.get(pointer + 1) == Some(&b'/') => {
+                    let name_start = pointer + 2;
+                    let name_end = tag_name_end(bytes, name_start, end);
+                    self.close_element(&self.source_text[name_start..name_end]);
+                    pointer = self.source_text[name_end..end]
+                        .find('>')
+                        .map_or(end, |offset| name_end + offset + 1);
+                }
+                b'<' if bytes.get(pointer + 1).is_some_and(u8::is_ascii_alphabetic) => {
+                    let Some(tag) = parse_start_tag(self.syntax, self.source_text, pointer, end)
+                    else {
+                        break;
+                    };
+                    pointer = self.start_tag(&tag, end);
+                }
+                b'{' if self.syntax == Syntax::Vue => {
+                    if bytes.get(pointer + 1) != Some(&b'{') {
+                        pointer += 1;
+                        continue;
+                    }
+                    let Some(offset) = Finder::new("}}").find(&bytes[pointer + 2..end]) else {
+                        break;
+                    };
+                    self.builder.expression(pointer + 2, pointer + 2 + offset);
+                    pointer += offset + 4;
+                }
+                b'{' => {
+                    let Some(close) = find_closing_brace(&self.source_text[..end], pointer) else {
+                        break;
+                    };
+                    self.interpolation(pointer + 1, close);
+                    pointer = close + 1;
+                }
+                _ => pointer += 1,
+            }
+        }
+        while let Some(frame) = self.frames.pop() {
+            self.builder.code(&frame.closer);
+        }
+    }
+
+    /// Handle a start tag, and return the offset where parsing continues.
+    fn start_tag(&mut self, tag: &StartTag<'a>, end: usize) -> usize {
+        let is_raw_text = RAW_TEXT_ELEMENTS.iter().any(|raw| tag.name.eq_ignore_ascii_case(raw));
+        // Vue does not compile elements with `v-pre`, nor their content.
+        let is_pre = self.syntax == Syntax::Vue && tag.attribute("v-pre").is_some();
+
+        let mut closer = String::new();
+        if !is_pre {
+            if self.syntax == Syntax::Vue {
+                if let Some(value) = tag.attribute("v-for").and_then(|attribute| attribute.value) {
+                    closer.push_str(self.vue_for(value));
+                }
+            }
+            self.component(tag.name, tag.start + 1);
+            for attribute in &tag.attributes {
+                match self.syntax {
+                    Syntax::Vue => self.vue_attribute(attribute),
+                    Syntax::Svelte => self.svelte_attribute(attribute),
+                    Syntax::Astro => self.astro_attribute(attribute),
+                }
+            }
+            // Scopes of slots only apply to the content.
+            for attribute in &tag.attributes {
+                closer.push_str(self.content_scope(attribute));
+            }
+        }
+
+        if is_raw_text || is_pre {
+            self.builder.code(&closer);
+            return skip_element(self.source_text, tag, is_raw_text, end);
+        }
+        let is_void = VOID_ELEMENTS.iter().any(|void| tag.name.eq_ignore_ascii_case(void));
+        if tag.self_closing || is_void {
+            self.builder.code(&closer);
+        } else {
+            self.frames.push(Frame { element: Some(tag.name), closer });
+        }
+        tag.end
+    }
+
+    fn close_element(&mut self, name: &str) {
+        let Some(index) = self.frames.iter().rposition(|frame| frame.element == Some(name)) else {
+            return;
+        };
+        for frame in self.frames.drain(index..).rev() {
+            self.builder.code(&frame.closer);
+        }
+    }
+
+    /// Reference to the binding of a component, e.g. `<MyButton>` or `<my-button>` in Vue.
+    fn component(&mut self, name: &'a str, start: usize) {
+        let end = start + name.len();
+        let name = name.split('.').next().unwrap_or(name);
+        if name.contains(':') {
+            return;
+        }
+        if self.syntax == Syntax::Vue && name.contains('-') {
+            let camel = camelize(name);
+            let pascal = capitalize(&camel);
+            if contains_identifier(self.builder.script, &pascal) {
+                self.builder.reference(&pascal, start, end);
+            } else {
+                self.builder.reference(&camel, start, end);
+            }
+        } else if name.starts_with(|c: char| c.is_ascii_uppercase()) {
+            self.builder.reference(name, start, end);
+        }
+    }
+
+    /// `v-for="(item, index) in items"`: `for (const [item, index] of items) {`
+    fn vue_for(&mut self, value: AttributeValue) -> &'static str {
+        let text = &self.source_text[value.start..value.end];
+        let Some((alias_end, source_start)) = find_for_separator(text) else {
+            self.builder.expression(value.start, value.end);
+            return "";
+        };
+        let (alias_start, alias_end) = trim(text, 0, alias_end);
+        let alias = &text[alias_start..alias_end];
+        self.builder.code("for (const ");
+        if alias.starts_with('(') && alias.ends_with(')') {
+            self.builder.code("[");
+            self.builder.source(value.start + alias_start + 1, value.start + alias_end - 1);
+            self.builder.code("]");
+        } else {
+            self.builder.source(value.start + alias_start, value.start + alias_end);
+        }
+        self.builder.code(" of ");
+        let (source_start, source_end) =
+            trim(self.source_text, value.start + source_start, value.end);
+        self.builder.source(source_start, source_end);
+        self.builder.code(") {\n");
+        "}\n"
+    }
+
+    fn vue_attribute(&mut self, attribute: &Attribute<'a>) {
+        let name = attribute.name;
+        let value = attribute.value.filter(|value| value.kind != ValueKind::TemplateLiteral);
+        let (directive, argument_offset) = if let Some(rest) = name.strip_prefix("v-") {
+            let directive_end = rest.find([':', '.']).unwrap_or(rest.len());
+            (&rest[..directive_end], 2 + directive_end + 1)
+        } else if name.starts_with(':') || name.starts_with('.') {
+            ("bind", 1)
+        } else if name.starts_with('@') {
+            ("on", 1)
+        } else if name.starts_with('#') {
+            ("slot", 1)
+        } else {
+            if let Some(value) = value.filter(|_| name == "ref") {
+                let text = &self.source_text[value.start..value.end];
+                self.builder.reference(text, value.start, value.end);
+            }
+            return;
+        };
+
+        // Dynamic arguments, e.g. `:[key]="value"`.
+        if let Some(argument) = name.get(argument_offset..) {
+            if let (Some(open), Some(close)) = (argument.find('['), argument.rfind(']')) {
+                let start = attribute.name_start + argument_offset;
+                self.builder.expression(start + open + 1, start + close);
+            }
+        }
+
+        match (directive, value) {
+            ("for" | "slot" | "pre" | "cloak" | "once" | "else", _) => {}
+            ("on", Some(value)) if is_vue_handler(&self.source_text[value.start..value.end]) => {
+                self.builder.expression(value.start, value.end);
+            }
+            ("on", Some(value)) => self.builder.statements(value.start, value.end),
+            // Same-name shorthand, e.g. `:id` for `:id="id"`.
+            ("bind", None) => {
+                if let Some(argument) = name.get(argument_offset..) {
+                    let argument = argument.split('.').next().unwrap_or(argument);
+                    let start = attribute.name_start + argument_offset;
+                    self.builder.reference(&camelize(argument), start, start + argument.len());
+                }
+            }
+            (directive, value) => {
+                if let Some(value) = value {
+                    self.builder.expression(value.start, value.end);
+                }
+                if !VUE_BUILTIN_DIRECTIVES.contains(&directive) {
+                    let start = attribute.name_start;
+                    let end = start + 2 + directive.len();
+                    self.builder.reference(&camelize(&format!("v-{directive}")), start, end);
+                }
+            }
+        }
+    }
+
+    fn svelte_attribute(&mut self, attribute: &Attribute<'a>) {
+        let name = attribute.name;
+        let (directive, argument) = name.split_once(':').unwrap_or(("", name));
+        let argument = argument.split('|').next().unwrap_or(argument);
+        let argument_start =
+            attribute.name_start + directive.len() + usize::from(!directive.is_empty());
+        match attribute.value {
+            Some(value) if value.kind == ValueKind::Braced => {
+                if directive == "let" {
+                    return;
+                }
+                if matches!(directive, "use" | "transition" | "in" | "out" | "animate") {
+                    self.builder.reference(
+                        argument,
+                        argument_start,
+                        argument_start + argument.len(),
+                    );
+                }
+                self.braced_expression(value.start, value.end);
+            }
+            Some(value) if value.kind == ValueKind::Quoted => {
+                self.quoted_interpolations(value.start, value.end);
+            }
+            Some(_) => {}
+            None => match directive {
+                "bind" | "class" | "style" | "use" | "transition" | "in" | "out" | "animate" => {
+                    self.builder.reference(
+                        argument,
+                        argument_start,
+                        argument_start + argument.len(),
+                    );
+                }
+                _ => {}
+            },
+        }
+    }
+
+    fn astro_attribute(&mut self, attribute: &Attribute<'a>) {
+        match attribute.value {
+            Some(value) if value.kind == ValueKind::Braced => {
+                self.braced_expression(value.start, value.end);
+            }
+            Some(value) if value.kind == ValueKind::TemplateLiteral => {
+                self.builder.expression(value.start, value.end);
+            }
+            _ => {}
+        }
+    }
+
+    /// Scope of slot props for the content of an element, e.g. `v-slot="{ item }"` in Vue or
+    /// `let:item` in Svelte. Returns the code which closes it.
+    fn content_scope(&mut self, attribute: &Attribute<'a>) -> &'static str {
+        let name = attribute.name;
+        match self.syntax {
+            Syntax::Vue => {
+                let is_slot =
+                    name == "v-slot" || name.starts_with("v-slot:") || name.starts_with('#');
+                match attribute.value {
+                    Some(value) if is_slot || name == "slot-scope" => {
+                        self.builder.open_function(value.start, value.end)
+                    }
+                    _ => "",
+                }
+            }
+            Syntax::Svelte => {
+                let Some(argument) = name.strip_prefix("let:") else {
+                    return "";
+                };
+                match attribute.value {
+                    Some(value) if value.kind == ValueKind::Braced => {
+                        self.builder.open_function(value.start, value.end)
+                    }
+                    _ => {
+                        let start = attribute.name_start + 4;
+                        self.builder.open_function(start, start + argument.len())
+                    }
+                }
+            }
+            Syntax::Astro => "",
+        }
+    }
+
+    /// `{expression}` or `{...spread}` of Svelte or Astro.
+    fn braced_expression(&mut self, start: usize, end: usize) {
+        let (trimmed_start, trimmed_end) = trim(self.source_text, start, end);
+        if self.source_text[trimmed_start..trimmed_end].starts_with("...") {
+            self.builder.expression(trimmed_start + 3, trimmed_end);
+        } else {
+            self.builder.expression(start, end);
+        }
+    }
+
+    /// `{expression}` in quoted attribute values of Svelte, e.g. `class="item {active}"`.
+    fn quoted_interpolations(&mut self, start: usize, end: usize) {
+        let mut pointer = start;
+        while let Some(offset) = self.source_text[pointer..end].find('{') {
+            let open = pointer + offset;
+            let Some(close) = find_closing_brace(&self.source_text[..end], open) else {
+                return;
+            };
+            self.builder.expression(open + 1, close);
+            pointer = close + 1;
+        }
+    }
+
+    /// `{...}` in the content of Svelte or Astro, including Svelte blocks such as `{#if}`.
+    fn interpolation(&mut self, start: usize, end: usize) {
+        if self.syntax == Syntax::Astro {
+            self.braced_expression(start, end);
+            return;
+        }
+        let (start, end) = trim(self.source_text, start, end);
+        let text = &self.source_text[start..end];
+        let keyword_end = text.find(char::is_whitespace).unwrap_or(text.len());
+        let (keyword, rest_start) = (&text[..keyword_end], start + keyword_end);
+        match keyword {
+            "#if" | "#key" => {
+                self.builder.expression(rest_start, end);
+                self.open_block("{\n", "}\n");
+            }
+            "#each" => self.svelte_each(rest_start, end),
+            "#await" => {
+                let rest = &self.source_text[rest_start..end];
+                let split = find_keyword(rest, "then").or_else(|| find_keyword(rest, "catch"));
+                if let Some((keyword_start, keyword_len)) = split {
+                    self.builder.expression(rest_start, rest_start + keyword_start);
+                    let params = rest_start + keyword_start + keyword_len;
+                    let closer = self.builder.open_function(params, end);
+                    self.frames.push(Frame { element: None, closer: closer.to_string() });
+                } else {
+                    self.builder.expression(rest_start, end);
+                    self.open_block("{\n", "}\n");
+                }
+            }
+            // Snippets can be passed to components implicitly, so they are not declarations.
+            "#snippet" => {
+                self.builder.code("void (function ");
+                let (rest_start, end) = trim(self.source_text, rest_start, end);
+                self.builder.source(rest_start, end);
+                self.open_block(" {\n", "});\n");
+            }
+            ":else" => {
+                self.close_block_part();
+                let rest = &self.source_text[rest_start..end];
+                if let Some(condition) = rest.trim_start().strip_prefix("if") {
+                    self.builder.expression(end - condition.len(), end);
+                }
+                self.open_block("{\n", "}\n");
+            }
+            ":then" | ":catch" => {
+                self.close_block_part();
+                let closer = self.builder.open_function(rest_start, end);
+                self.frames.push(Frame { element: None, closer: closer.to_string() });
+            }
+            "@const" => {
+                self.builder.code("const ");
+                let (rest_start, end) = trim(self.source_text, rest_start, end);
+                self.builder.source(rest_start, end);
+                self.builder.code(";\n");
+            }
+            "@html" | "@render" | "@debug" => self.builder.expression(rest_start, end),
+            _ if keyword.starts_with('/') => {
+                if let Some(index) = self.frames.iter().rposition(|frame| frame.element.is_none()) {
+                    for frame in self.frames.drain(index..).rev() {
+                        self.builder.code(&frame.closer);
+                    }
+                }
+            }
+            _ if keyword.starts_with(['#', ':', '@']) => {}
+            _ => self.builder.expression(start, end),
+        }
+    }
+
+    fn open_block(&mut self, opener: &str, closer: &str) {
+        self.builder.code(opener);
+        self.frames.push(Frame { element: None, closer: closer.to_string() });
+    }
+
+    /// Close the scope of the current part of a Svelte block, e.g. before `{:else}`.
+    fn close_block_part(&mut self) {
+        if let Some(index) = self.frames.iter().rposition(|frame| frame.element.is_none()) {
+            for frame in self.frames.drain(index..).rev() {
+                self.builder.code(&frame.closer);
+            }
+        }
+    }
+
+    /// `{#each items as item, index (key)}`: `for (const [index, item] of (items).entries()) {`
+    fn svelte_each(&mut self, start: usize, end: usize) {
+        let text = &self.source_text[start..end];
+        let Some((as_start, as_len)) = find_keyword(text, "as") else {
+            self.builder.expression(start, end);
+            self.open_block("{\n", "}\n");
+            return;
+        };
+        let (expression_start, expression_end) = (start, start + as_start);
+        let pattern_start = start + as_start + as_len;
+
+        // `(key)` at the end.
+        let mut pattern_end = end;
+        let mut key = None;
+        if let Some(open) = find_top_level(&self.source_text[pattern_start..end], b'(', true) {
+            pattern_end = pattern_start + open;
+            key = Some((pattern_end + 1, end.saturating_sub(1).max(pattern_end + 1)));
+        }
+        let pattern = &self.source_text[pattern_start..pattern_end];
+        let index = find_top_level(pattern, b',', false).map(|comma| pattern_start + comma);
+
+        self.builder.code("for (const ");
+        if let Some(comma) = index {
+            self.builder.code("[");
+            let (index_start, index_end) = trim(self.source_text, comma + 1, pattern_end);
+            self.builder.source(index_start, index_end);
+            self.builder.code(", ");
+            let (pattern_start, pattern_end) = trim(self.source_text, pattern_start, comma);
+            self.builder.source(pattern_start, pattern_end);
+            self.builder.code("] of (");
+            self.builder.source(expression_start, expression_end);
+            self.builder.code(").entries()) {\n");
+        } else {
+            let (pattern_start, pattern_end) = trim(self.source_text, pattern_start, pattern_end);
+            self.builder.source(pattern_start, pattern_end);
+            self.builder.code(" of ");
+            self.builder.source(expression_start, expression_end);
+            self.builder.code(") {\n");
+        }
+        self.frames.push(Frame { element: None, closer: "}\n".to_string() });
+        if let Some((key_start, key_end)) = key {
+            self.builder.expression(key_start, key_end);
+        }
+    }
+}
+
+/// Parse the start tag at `start`, which points at `<`.
+/// Returns `None` if it is not closed before `end`.
+pub(super) fn parse_start_tag(
+    syntax: Syntax,
+    source_text: &str,
+    start: usize,
+    end: usize,
+) -> Option<StartTag<'_>> {
+    let bytes = source_text.as_bytes();
+    let name_end = tag_name_end(bytes, start + 1, end);
+    let mut tag = StartTag {
+        name: &source_text[start + 1..name_end],
+        start,
+        attributes: vec![],
+        self_closing: false,
+        end,
+    };
+    let mut pointer = name_end;
+    loop {
+        while pointer < end && bytes[pointer].is_ascii_whitespace() {
+            pointer += 1;
+        }
+        match bytes.get(pointer).filter(|_| pointer < end)? {
+            b'>' => {
+                tag.end = pointer + 1;
+                return Some(tag);
+            }
+            b'/' if bytes.get(pointer + 1) == Some(&b'>') => {
+                tag.self_closing = true;
+                tag.end = pointer + 2;
+                return Some(tag);
+            }
+            b'/' => pointer += 1,
+            b'{' if syntax != Syntax::Vue => {
+                let close = find_closing_brace(&source_text[..end], pointer)?;
+                tag.attributes.push(Attribute {
+                    name: "",
+                    name_start: pointer,
+                    value: Some(AttributeValue {
+                        kind: ValueKind::Braced,
+                        start: pointer + 1,
+                        end: close,
+                    }),
+                });
+                pointer = close + 1;
+            }
+            _ => {
+                let name_start = pointer;
+                while pointer < end
+                    && !matches!(bytes[pointer], b'=' | b'>')
+                    && !bytes[pointer].is_ascii_whitespace()
+                    && !source_text[pointer..].starts_with("/>")
+                {
+                    pointer += 1;
+                }
+                let name = &source_text[name_start..pointer];
+                let mut value_start = pointer;
+                while value_start < end && bytes[value_start].is_ascii_whitespace() {
+                    value_start += 1;
+                }
+                let mut value = None;
+                if bytes.get(value_start) == Some(&b'=') {
+                    value_start += 1;
+                    while value_start < end && bytes[value_start].is_ascii_whitespace() {
+                        value_start += 1;
+                    }
+                    let (parsed, next) =
+                        parse_attribute_value(syntax, source_text, value_start, end)?;
+                    value = Some(parsed);
+                    pointer = next;
+                }
+                tag.attributes.push(Attribute { name, name_start, value });
+            }
+        }
+    }
+}
+
+/// Returns the value and the offset after it.
+fn parse_attribute_value(
+    syntax: Syntax,
+    source_text: &str,
+    start: usize,
+    end: usize,
+) -> Option<(AttributeValue, usize)> {
+    let bytes = source_text.as_bytes();
+    match *bytes.get(start).filter(|_| start < end)? {
+        quote @ (b'"' | b'\'') => {
+            let close = start + 1 + source_text[start + 1..end].find(char::from(quote))?;
+            let value = AttributeValue { kind: ValueKind::Quoted, start: start + 1, end: close };
+            Some((value, close + 1))
+        }
+        b'{' if syntax != Syntax::Vue => {
+            let close = find_closing_brace(&source_text[..end], start)?;
+            let value = AttributeValue { kind: ValueKind::Braced, start: start + 1, end: close };
+            Some((value, close + 1))
+        }
+        b'`' if syntax == Syntax::Astro => {
+            let close = template_literal_end(&source_text[..end], start + 1)?;
+            let value = AttributeValue { kind: ValueKind::TemplateLiteral, start, end: close + 1 };
+            Some((value, close + 1))
+        }
+        _ => {
+            let mut pointer = start;
+            while pointer < end && !bytes[pointer].is_ascii_whitespace() && bytes[pointer] != b'>' {
+                pointer += 1;
+            }
+            Some((AttributeValue { kind: ValueKind::Unquoted, start, end: pointer }, pointer))
+        }
+    }
+}
+
+fn tag_name_end(bytes: &[u8], start: usize, end: usize) -> usize {
+    let mut pointer = start;
+    while pointer < end
+        && !bytes[pointer].is_ascii_whitespace()
+        && !matches!(bytes[pointer], b'/' | b'>')
+    {
+        pointer += 1;
+    }
+    pointer
+}
+
+/// Offset after the end tag of the element with start `tag`.
+/// Nested elements with the same name are skipped, unless the content is raw text.
+pub(super) fn skip_element(
+    source_text: &str,
+    tag: &StartTag<'_>,
+    is_raw_text: bool,
+    end: usize,
+) -> usize {
+    if tag.self_closing {
+        return tag.end;
+    }
+    let bytes = source_text.as_bytes();
+    let mut depth = 0;
+    let mut pointer = tag.end;
+    while let Some(offset) = source_text[pointer..end].find('<') {
+        pointer += offset;
+        let name_start = pointer + 1 + usize::from(bytes.get(pointer + 1) == Some(&b'/'));
+        let name_end = tag_name_end(bytes, name_start, end);
+        let name = &source_text[name_start..name_end];
+        if !name.eq_ignore_ascii_case(tag.name) {
+            pointer += 1;
+            continue;
+        }
+        if name_start == pointer + 1 {
+            if !is_raw_text {
+                depth += 1;
+            }
+        } else if depth == 0 {
+            return source_text[name_end..end]
+                .find('>')
+                .map_or(end, |offset| name_end + offset + 1);
+        } else {
+            depth -= 1;
+        }
+        pointer = name_end;
+    }
+    end
+}
+
+enum TemplatePart {
+    /// Offset of the closing backtick.
+    End(usize),
+    /// Offset of the `{` of a `${` substitution.
+    Substitution(usize),
+}
+
+/// Scan a template literal from `start`, which is after the opening backtick or a substitution.
+fn template_part(bytes: &[u8], start: usize) -> Option<TemplatePart> {
+    let mut pointer = start;
+    while pointer < bytes.len() {
+        match bytes[pointer] {
+            b'\\' => pointer += 1,
+            b'`' => return Some(TemplatePart::End(pointer)),
+            b'$' if bytes.get(pointer + 1) == Some(&b'{') => {
+                return Some(TemplatePart::Substitution(pointer + 1));
+            }
+            _ => {}
+        }
+        pointer += 1;
+    }
+    None
+}
+
+/// Offset of the closing backtick of the template literal whose content starts at `start`.
+fn template_literal_end(text: &str, start: usize) -> Option<usize> {
+    let mut pointer = start;
+    loop {
+        match template_part(text.as_bytes(), pointer)? {
+            TemplatePart::End(end) => return Some(end),
+            TemplatePart::Substitution(open) => pointer = find_closing_brace(text, open)? + 1,
+        }
+    }
+}
+
+/// Offset of the `}` matching the `{` at `open`, skipping strings, template literals and comments.
+pub(super) fn find_closing_brace(text: &str, open: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut depth = 0usize;
+    let mut pointer = open;
+    while pointer < bytes.len() {
+        match bytes[pointer] {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(pointer);
+                }
+            }
+            quote @ (b'\'' | b'"') => {
+                pointer += 1;
+                while pointer < bytes.len() && bytes[pointer] != quote && bytes[pointer] != b'\n' {
+                    if bytes[pointer] == b'\\' {
+                        pointer += 1;
+                    }
+                    pointer += 1;
+                }
+            }
+            b'`' => pointer = template_literal_end(text, pointer + 1)?,
+            b'/' if bytes.get(pointer + 1) == Some(&b'/') => {
+                pointer = text[pointer..].find('\n').map_or(bytes.len(), |offset| pointer + offset);
+            }
+            b'/' if bytes.get(pointer + 1) == Some(&b'*') => {
+                pointer = pointer + 2 + text[pointer + 2..].find("*/")? + 1;
+            }
+            _ => {}
+        }
+        pointer += 1;
+    }
+    None
+}
+
+/// Offsets of the end of the alias and the start of the source of a Vue `v-for` expression,
+/// around `in` or `of`.
+fn find_for_separator(text: &str) -> Option<(usize, usize)> {
+    let (start, len) =
+        find_keyword(text, "in").into_iter().chain(find_keyword(text, "of")).min()?;
+    Some((start, start + len))
+}
+
+/// Offset and length of the first `keyword` surrounded by whitespace, outside of brackets.
+fn find_keyword(text: &str, keyword: &str) -> Option<(usize, usize)> {
+    let bytes = text.as_bytes();
+    let mut depth = 0i32;
+    for (offset, byte) in bytes.iter().enumerate() {
+        match byte {
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth -= 1,
+            _ if depth == 0
+                && offset > 0
+                && bytes[offset - 1].is_ascii_whitespace()
+                && text[offset..].starts_with(keyword)
+                && bytes.get(offset + keyword.len()).is_some_and(u8::is_ascii_whitespace) =>
+            {
+                return Some((offset, keyword.len()));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Offset of `byte` outside of brackets. With `after_whitespace`, it must follow whitespace.
+fn find_top_level(text: &str, target: u8, after_whitespace: bool) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut depth = 0i32;
+    for (offset, &byte) in bytes.iter().enumerate() {
+        if depth == 0
+            && byte == target
+            && (!after_whitespace || offset > 0 && bytes[offset - 1].is_ascii_whitespace())
+        {
+            return Some(offset);
+        }
+        match byte {
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Offsets of `text[start..end]` without surrounding whitespace.
+fn trim(text: &str, start: usize, end: usize) -> (usize, usize) {
+    let slice = &text[start..end];
+    let trimmed_start = slice.trim_start();
+    let start = start + slice.len() - trimmed_start.len();
+    (start, start + trimmed_start.trim_end().len())
+}
+
+/// Whether `text` only contains whitespace and comments.
+fn is_blank(text: &str) -> bool {
+    let mut text = text.trim_start();
+    loop {
+        if let Some(rest) = text.strip_prefix("/*") {
+            let Some(end) = rest.find("*/") else {
+                return false;
+            };
+            text = rest[end + 2..].trim_start();
+        } else if let Some(rest) = text.strip_prefix("//") {
+            text = rest.find('\n').map_or("", |end| rest[end..].trim_start());
+        } else {
+            return text.is_empty();
+        }
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| !c.is_ascii_digit()) && name.chars().all(is_identifier_char)
+}
+
+/// Whether `name` occurs in `text` as a whole word, i.e. the script may declare it.
+pub(super) fn contains_identifier(text: &str, name: &str) -> bool {
+    text.match_indices(name).any(|(start, _)| {
+        !text[..start].ends_with(is_identifier_char)
+            && !text[start + name.len()..].starts_with(is_identifier_char)
+    })
+}
+
+/// Whether the value of a Vue `v-on` directive is a handler, i.e. a member path or a function,
+/// instead of inline statements, e.g. `select` or `(item) => select(item)` but not `count++`.
+fn is_vue_handler(text: &str) -> bool {
+    let text = text.trim();
+    if text.split('.').all(is_identifier) && !is_reserved_keyword(text) {
+        return true;
+    }
+    let text = text.strip_prefix("async").map_or(text, str::trim_start);
+    if text.strip_prefix("function").is_some_and(|rest| rest.starts_with(['(', ' '])) {
+        return true;
+    }
+    text.split_once("=>").is_some_and(|(params, _)| {
+        let params = params.trim();
+        is_identifier(params)
+            || params.starts_with('(') && params.ends_with(')') && !params[1..].contains('(')
+    })
+}
+
+/// `my-button` to `myButton`
+fn camelize(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '-' {
+            upper = true;
+        } else if upper {
+            result.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map_or_else(String::new, |first| first.to_ascii_uppercase().to_string() + chars.as_str())
+}
+
+/// Reference `store` for each `$store` of Svelte in `start..end`, if the script declares it.
+pub(super) fn store_references(builder: &mut TemplateBuilder<'_>, start: usize, end: usize) {
+    let source_text = builder.source_text;
+    let text = &source_text[start..end];
+    for (offset, _) in text.match_indices('$') {
+        if text[..offset].ends_with(is_identifier_char) {
+            continue;
+        }
+        let name = &text[offset + 1..];
+        let name_len = name.find(|c: char| !is_identifier_char(c)).unwrap_or(name.len());
+        let name = &name[..name_len];
+        if name.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+            builder.reference(name, start + offset, start + offset + 1 + name_len);
+        }
+    }
+}
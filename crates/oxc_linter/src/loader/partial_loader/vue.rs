@@ -1,7 +1,10 @@
 use memchr::memmem::Finder;
 use oxc_span::SourceType;
 
-use super::{JavaScriptSource, SCRIPT_END, SCRIPT_START, find_script_closing_angle};
+use super::{
+    JavaScriptSource, SCRIPT_END, SCRIPT_START, find_script_closing_angle,
+    template::{MarkupParser, Syntax, Template, TemplateBuilder, parse_start_tag, skip_element},
+};
 
 pub struct VuePartialLoader<'a> {
     source_text: &'a str,
@@ -31,6 +34,51 @@ impl<'a> VuePartialLoader<'a> {
         vec![result1, result2]
     }
 
+    /// Generate code for the expressions of the `<template>` block, whose scope is the one of
+    /// `<script setup>`. Returns `None` without either of them.
+    /// <https://vuejs.org/api/sfc-script-setup.html#using-components>
+    pub fn parse_template(&self, sources: &[JavaScriptSource<'a>]) -> Option<Template> {
+        let end = self.source_text.len();
+        let mut template = None;
+        let mut setup = None;
+        let mut pointer = 0;
+        while let Some(offset) = self.source_text[pointer..].find('<') {
+            let start = pointer + offset;
+            if self.source_text[start..].starts_with("<!--") {
+                pointer =
+                    self.source_text[start..].find("-->").map_or(end, |offset| start + offset);
+                continue;
+            }
+            if !self.source_text[start + 1..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+                pointer = start + 1;
+                continue;
+            }
+            let tag = parse_start_tag(Syntax::Vue, self.source_text, start, end)?;
+            let is_template = tag.name == "template";
+            pointer = skip_element(self.source_text, &tag, !is_template, end);
+            if is_template && template.is_none() {
+                // Only HTML templates, not e.g. `<template lang="pug">`.
+                if tag.attribute("lang").is_some_and(|lang| {
+                    lang.value
+                        .is_some_and(|value| &self.source_text[value.start..value.end] != "html")
+                }) {
+                    return None;
+                }
+                let content_end = self.source_text[..pointer].rfind("</").unwrap_or(pointer);
+                template = Some((tag.end, content_end.max(tag.end)));
+            } else if tag.name == "script" && tag.attribute("setup").is_some() {
+                setup = Some(tag.end);
+            }
+        }
+
+        let (start, end) = template?;
+        let setup = setup?;
+        let section = sources.iter().position(|source| source.start as usize == setup)?;
+        let mut builder = TemplateBuilder::new(self.source_text, sources[section].source_text);
+        MarkupParser::new(Syntax::Vue, self.source_text, &mut builder).parse(start, end);
+        Some(builder.build(section, false))
+    }
+
     fn parse_script(&self, pointer: &mut usize) -> Option<JavaScriptSource<'a>> {
         let script_start_finder = Finder::new(SCRIPT_START);
 
@@ -70,6 +118,8 @@ impl<'a> VuePartialLoader<'a> {
 
 #[cfg(test)]
 mod test {
+    use oxc_span::Span;
+
     use super::{JavaScriptSource, VuePartialLoader};
 
     fn parse_vue(source_text: &str) -> JavaScriptSource<'_> {
@@ -252,4 +302,53 @@ mod test {
         assert_eq!(sources.len(), 1);
         assert_eq!(sources[0].source_text, "a");
     }
+
+    #[test]
+    #[expect(clippy::cast_possible_truncation)]
+    fn test_parse_template() {
+        let source_text = r#"<template>
+  <MyButton v-for="(item, index) in items" :key="item.id" @click="select(item)">
+    {{ item.name }}
+  </MyButton>
+  <p v-if="show" v-focus>{{ count }}</p>
+  <div v-pre>{{ raw }}</div>
+  <!-- {{ commented }} -->
+</template>
+<script setup>
+import MyButton from './MyButton.vue'
+import vFocus from './focus'
+</script>"#;
+        let loader = VuePartialLoader::new(source_text);
+        let sources = loader.parse_scripts();
+        let template = loader.parse_template(&sources).unwrap();
+        assert_eq!(template.section, 0);
+        assert_eq!(
+            template.source_text(""),
+            "\n{\nfor (const [item, index] of items) {\nvoid (MyButton);\nvoid (item.id);\nvoid (() => {\nselect(item)\n});\nvoid ( item.name );\n}\nvoid (show);\nvoid (vFocus);\nvoid ( count );\n}\n"
+        );
+
+        let script = sources[0].source_text;
+        let generated = template.source_text(script);
+        let start = generated.find("select").unwrap() as u32;
+        let span = template.original_span(&sources[0], Span::sized(start, 6)).unwrap();
+        assert_eq!(span.source_text(source_text), "select");
+        let start = generated.rfind("MyButton);").unwrap() as u32;
+        let span = template.original_span(&sources[0], Span::sized(start, 8)).unwrap();
+        assert_eq!(span.source_text(source_text), "MyButton");
+        let start = generated.rfind("vFocus").unwrap() as u32;
+        let span = template.original_span(&sources[0], Span::sized(start, 6)).unwrap();
+        assert_eq!(span.source_text(source_text), "v-focus");
+    }
+
+    #[test]
+    fn test_parse_template_without_script_setup() {
+        let source_text = r"<template>{{ a }}</template><script>export default {}</script>";
+        let loader = VuePartialLoader::new(source_text);
+        assert!(loader.parse_template(&loader.parse_scripts()).is_none());
+
+        let source_text =
+            r#"<template lang="pug">p {{ a }}</template><script setup>const a = 1</script>"#;
+        let loader = VuePartialLoader::new(source_text);
+        assert!(loader.parse_template(&loader.parse_scripts()).is_none());
+    }
 }
@@ -13,4 +13,6 @@ pub struct LintOptions {
     pub fix: FixKind,
     pub framework_hints: FrameworkFlags,
     pub report_unused_directive: Option<AllowWarnDeny>,
+    /// Run rules that need type information, see [`TypeChecker`](crate::type_checker::TypeChecker).
+    pub type_aware: bool,
 }
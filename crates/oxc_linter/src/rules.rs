@@ -183,6 +183,7 @@ mod eslint {
 mod typescript {
     pub mod adjacent_overload_signatures;
     pub mod array_type;
+    pub mod await_thenable;
     pub mod ban_ts_comment;
     pub mod ban_tslint_comment;
     pub mod ban_types;
@@ -199,9 +200,11 @@ mod typescript {
     pub mod no_explicit_any;
     pub mod no_extra_non_null_assertion;
     pub mod no_extraneous_class;
+    pub mod no_floating_promises;
     pub mod no_import_type_side_effects;
     pub mod no_inferrable_types;
     pub mod no_misused_new;
+    pub mod no_misused_promises;
     pub mod no_namespace;
     pub mod no_non_null_asserted_nullish_coalescing;
     pub mod no_non_null_asserted_optional_chain;
@@ -209,6 +212,7 @@ mod typescript {
     pub mod no_require_imports;
     pub mod no_this_alias;
     pub mod no_unnecessary_parameter_property_assignment;
+    pub mod no_unnecessary_type_assertion;
     pub mod no_unnecessary_type_constraint;
     pub mod no_unsafe_declaration_merging;
     pub mod no_unsafe_function_type;
@@ -922,6 +926,7 @@ oxc_macros::declare_all_lint_rules! {
     react_perf::jsx_no_new_object_as_prop,
     typescript::adjacent_overload_signatures,
     typescript::array_type,
+    typescript::await_thenable,
     typescript::ban_ts_comment,
     typescript::ban_tslint_comment,
     typescript::ban_types,
@@ -939,8 +944,10 @@ oxc_macros::declare_all_lint_rules! {
     typescript::no_explicit_any,
     typescript::no_extra_non_null_assertion,
     typescript::no_extraneous_class,
+    typescript::no_floating_promises,
     typescript::no_import_type_side_effects,
     typescript::no_misused_new,
+    typescript::no_misused_promises,
     typescript::no_namespace,
     typescript::no_non_null_asserted_nullish_coalescing,
     typescript::no_non_null_asserted_optional_chain,
@@ -948,6 +955,7 @@ oxc_macros::declare_all_lint_rules! {
     typescript::no_require_imports,
     typescript::no_this_alias,
     typescript::no_unnecessary_parameter_property_assignment,
+    typescript::no_unnecessary_type_assertion,
     typescript::no_unnecessary_type_constraint,
     typescript::no_unsafe_declaration_merging,
     typescript::no_unsafe_function_type,
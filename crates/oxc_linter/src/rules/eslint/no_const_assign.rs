@@ -54,12 +54,20 @@ impl Rule for NoConstAssign {
     fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
         let symbol_table = ctx.scoping();
         if symbol_table.symbol_flags(symbol_id).is_const_variable() {
+            // Refs are unwrapped in Vue templates, so `count++` assigns to `count.value`.
+            let vue_template = ctx
+                .template_span()
+                .filter(|_| ctx.file_path().extension().is_some_and(|ext| ext == "vue"));
             for reference in symbol_table.get_resolved_references(symbol_id) {
                 if reference.is_write() {
+                    let span = ctx.semantic().reference_span(reference);
+                    if vue_template.is_some_and(|template| template.contains_inclusive(span)) {
+                        continue;
+                    }
                     ctx.diagnostic(no_const_assign_diagnostic(
                         symbol_table.symbol_name(symbol_id),
                         symbol_table.symbol_span(symbol_id),
-                        ctx.semantic().reference_span(reference),
+                        span,
                     ));
                 }
             }
@@ -539,3 +539,71 @@ fn test() {
     Tester::new(NoUnusedExpressions::NAME, NoUnusedExpressions::PLUGIN, pass, fail)
         .test_and_snapshot();
 }
+
+#[test]
+fn test_component_templates() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            r#"<script setup>
+const items = []
+const count = 0
+</script>
+<template>
+  <li v-for="item in items" :key="item.id" @click="count++">{{ item.name }}</li>
+</template>"#,
+            None,
+            None,
+            Some(PathBuf::from("component.vue")),
+        ),
+        (
+            r#"<script setup>
+function select() {}
+</script>
+<template>
+  <button @click="select" @focus="(event) => select(event)">Select</button>
+</template>"#,
+            None,
+            None,
+            Some(PathBuf::from("component.vue")),
+        ),
+        (
+            r"<script>
+  let n = 0;
+</script>
+<button on:click={() => n++}>{n}</button>",
+            None,
+            None,
+            Some(PathBuf::from("component.svelte")),
+        ),
+    ];
+    let fail = vec![
+        (
+            r#"<script setup>
+const count = 0
+</script>
+<template>
+  <button @click="count; count++">{{ count }}</button>
+</template>"#,
+            None,
+            None,
+            Some(PathBuf::from("component.vue")),
+        ),
+        (
+            r"<script>
+  let n = 0;
+</script>
+<button on:click={() => { n }}>{n}</button>",
+            None,
+            None,
+            Some(PathBuf::from("component.svelte")),
+        ),
+    ];
+
+    Tester::new(NoUnusedExpressions::NAME, NoUnusedExpressions::PLUGIN, pass, fail)
+        .with_snapshot_suffix("component-templates")
+        .test_and_snapshot();
+}
@@ -78,11 +78,14 @@ declare_oxc_lint!(
     /// functions, etc.
     ///
     /// #### Ignored Files
-    /// This rule ignores `.d.ts` files and `.vue` files entirely. Variables,
-    /// classes, interfaces, and types declared in `.d.ts` files are generally
-    /// used by other files, which are not checked by Oxlint. Since Oxlint does
-    /// not support parsing Vue templates, this rule cannot tell if a variable
-    /// is used or unused in a Vue file.
+    /// This rule ignores `.d.ts` files entirely. Variables, classes,
+    /// interfaces, and types declared in `.d.ts` files are generally used by
+    /// other files, which are not checked by Oxlint.
+    ///
+    /// Scripts of `.vue` and `.svelte` files are only checked together with
+    /// the expressions of their template, i.e. `<script setup>` in Vue and the
+    /// instance script in Svelte. Otherwise, this rule cannot tell if a
+    /// variable is used or unused in the template.
     ///
     /// #### Exported
     ///
@@ -215,12 +218,13 @@ impl Rule for NoUnusedVars {
     }
 
     fn should_run(&self, ctx: &ContextHost) -> bool {
-        // ignore .d.ts and vue/svelte files.
+        // ignore .d.ts files, and vue/svelte scripts which are not linted with their template.
         // 1. declarations have side effects (they get merged together)
         // 2. vue/svelte scripts declare variables that get used in the template, which
-        //    we can't detect
+        //    we can only detect when the template expressions are part of the program
         !ctx.source_type().is_typescript_definition()
-            && !ctx.file_path().extension().is_some_and(|ext| ext == "vue" || ext == "svelte")
+            && (ctx.template_span().is_some()
+                || !ctx.file_path().extension().is_some_and(|ext| ext == "vue" || ext == "svelte"))
     }
 }
 
@@ -233,7 +237,9 @@ impl NoUnusedVars {
         }
 
         // Order matters. We want to call cheap/high "yield" functions first.
-        let is_used = symbol.is_exported() || symbol.has_usages(self);
+        let is_used = symbol.is_exported()
+            || symbol.is_used_in_template(ctx.template_span())
+            || symbol.has_usages(self);
 
         match (is_used, is_ignored) {
             (true, true) => {
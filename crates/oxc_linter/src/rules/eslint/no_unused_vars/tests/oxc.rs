@@ -1,5 +1,7 @@
 //! Test cases created by oxc maintainers
 
+use std::path::PathBuf;
+
 use serde_json::json;
 
 use super::NoUnusedVars;
@@ -1202,6 +1204,107 @@ fn test_type_references() {
         .test_and_snapshot();
 }
 
+#[test]
+fn test_component_templates() {
+    let pass = vec![
+        (
+            r#"<script setup>
+import MyButton from './MyButton.vue'
+import { format } from './format'
+const items = []
+</script>
+<template>
+  <MyButton v-for="item in items" :key="item.id">{{ format(item) }}</MyButton>
+</template>"#,
+            None,
+            None,
+            Some(PathBuf::from("component.vue")),
+        ),
+        (
+            r"<script>
+  import Row from './Row.svelte';
+  let rows = [];
+</script>
+{#each rows as row (row.id)}<Row {row} />{/each}",
+            None,
+            None,
+            Some(PathBuf::from("component.svelte")),
+        ),
+        (
+            r"---
+import Card from './Card.astro';
+const { title } = Astro.props;
+---
+<Card title={title} />",
+            None,
+            None,
+            Some(PathBuf::from("component.astro")),
+        ),
+        (
+            r#"<script setup>
+import { ref } from 'vue'
+const count = ref(0)
+</script>
+<template>
+  <button @click="count++">Increment</button>
+</template>"#,
+            None,
+            None,
+            Some(PathBuf::from("component.vue")),
+        ),
+        (
+            r"<script>
+  let n = 0;
+</script>
+<button on:click={() => n++}>Increment</button>",
+            None,
+            None,
+            Some(PathBuf::from("component.svelte")),
+        ),
+        (
+            r"<script setup>
+const a = 1
+let b = 2
+</script>
+<template>
+  <p>{{ a, b }}</p>
+</template>",
+            None,
+            None,
+            Some(PathBuf::from("component.vue")),
+        ),
+    ];
+    let fail = vec![
+        (
+            r#"<script setup>
+import { ref } from 'vue'
+const count = ref(0)
+const unused = ref(0)
+</script>
+<template>
+  <button @click="count++">Increment</button>
+</template>"#,
+            None,
+            None,
+            Some(PathBuf::from("component.vue")),
+        ),
+        (
+            r"<script>
+  let n = 0;
+  let unused = 0;
+</script>
+<button on:click={() => n++}>Increment</button>",
+            None,
+            None,
+            Some(PathBuf::from("component.svelte")),
+        ),
+    ];
+
+    Tester::new(NoUnusedVars::NAME, NoUnusedVars::PLUGIN, pass, fail)
+        .intentionally_allow_no_fix_tests()
+        .test();
+}
+
 // #[test]
 // fn test_template() {
 //     let pass = vec![];
@@ -162,6 +162,28 @@ impl<'a> Symbol<'_, 'a> {
         false
     }
 
+    /// Checks for references within the code generated for a component
+    /// template at `template_span`, which are always usages. Writes update
+    /// what the template renders, and reads in the template are not treated
+    /// as discarded, e.g. the operands of the generated sequence `(a, b)`.
+    ///
+    /// ## Examples
+    /// ```vue
+    /// <script setup>
+    /// const count = ref(0)
+    /// </script>
+    /// <!-- assigns `count.value`, because refs are unwrapped in templates -->
+    /// <button @click="count++" />
+    /// <!-- generated as `void (a, b)` -->
+    /// <p>{{ a, b }}</p>
+    /// ```
+    pub fn is_used_in_template(&self, template_span: Option<Span>) -> bool {
+        let Some(template_span) = template_span else { return false };
+        self.references().any(|reference| {
+            template_span.contains_inclusive(self.nodes().get_node(reference.node_id()).span())
+        })
+    }
+
     /// Checks for references within for..in and for..of conditions (not
     /// bodies). These are always considered usages since their removal would
     /// introduce syntax and/or semantic errors.
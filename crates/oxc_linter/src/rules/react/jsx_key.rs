@@ -80,7 +80,8 @@ impl Rule for JsxKey {
     }
 
     fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
+        // Lists in Astro templates are not rendered by React, so they need no keys.
+        ctx.source_type().is_jsx() && ctx.file_path().extension().is_none_or(|ext| ext != "astro")
     }
 }
 
@@ -0,0 +1,130 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn await_thenable_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected `await` of a non-Promise (non-\"Thenable\") value.")
+        .with_help("Remove unnecessary `await`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AwaitThenable;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow awaiting a value that is not a Thenable.
+    ///
+    /// This rule requires type-aware linting to be enabled.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// While it is valid JavaScript to `await` a non-Promise-like value (it will resolve
+    /// immediately), this pattern is often a programmer error, such as forgetting to add
+    /// parenthesis to call a function that returns a Promise.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// await 'value';
+    ///
+    /// const createValue = () => 'value';
+    /// await createValue();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// await Promise.resolve('value');
+    ///
+    /// const createValue = async () => 'value';
+    /// await createValue();
+    /// ```
+    AwaitThenable,
+    typescript,
+    pedantic,
+    suggestion
+);
+
+impl Rule for AwaitThenable {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::AwaitExpression(expr) = node.kind() else { return };
+        let Some(checker) = ctx.type_checker() else { return };
+        let ty = checker.expression_type(&expr.argument);
+        if !ty.is_known() || ty.is_thenable() {
+            return;
+        }
+        ctx.diagnostic_with_suggestion(await_thenable_diagnostic(expr.span), |fixer| {
+            fixer.replace_with(expr, &expr.argument)
+        });
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.is_type_aware() && ctx.source_type().is_typescript()
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{options::LintOptions, tester::Tester};
+
+    let pass = vec![
+        "async function test() { await Promise.resolve('value'); }",
+        "async function test() { await new Promise((resolve) => resolve('value')); }",
+        "async function test() { const promise = Promise.resolve('value'); await promise; }",
+        "async function returnsPromise() { return 'value'; } async function test() { await returnsPromise(); }",
+        "const createValue = async () => 'value'; async function test() { await createValue(); }",
+        "declare function returnsPromise(): Promise<string>; async function test() { await returnsPromise(); }",
+        "declare const value: string | Promise<string>; async function test() { await value; }",
+        "declare const value: any; async function test() { await value; }",
+        "declare const value: unknown; async function test() { await value; }",
+        "interface Thenable { then(): void; } declare const thenable: Thenable; async function test() { await thenable; }",
+        "class Thenable { then() {} } async function test() { await new Thenable(); }",
+        "async function test() { await { then() {} }; }",
+        "declare const thenable: PromiseLike<void>; async function test() { await { ...thenable }; }",
+        "const key = 'then'; async function test() { await { [key]() {} }; }",
+        "import { value } from './module'; async function test() { await value; }",
+        "async function test() { await foo(); }",
+        "async function test() { await foo.bar; }",
+        "let value = 'value'; value = getPromise(); async function test() { await value; }",
+        "async function test() { await import('./module'); }",
+    ];
+
+    let fail = vec![
+        "async function test() { await 'value'; }",
+        "async function test() { await 1; }",
+        "async function test() { await null; }",
+        "async function test() { await undefined; }",
+        "async function test() { await [Promise.resolve(1)]; }",
+        "async function test() { await { key: 'value' }; }",
+        "async function test() { await (() => Promise.resolve()); }",
+        "const createValue = () => 'value'; async function test() { await createValue(); }",
+        "function createValue(): string { return 'value'; } async function test() { await createValue(); }",
+        "declare const value: string | number | null; async function test() { await value; }",
+        "class Foo {} async function test() { await new Foo(); }",
+        "async function test(value: number) { await value; }",
+        "async function test() { const value = 'value'; await value; }",
+        "async function test() { await (1 + 2); }",
+    ];
+
+    let fix = vec![
+        ("async function test() { await 'value'; }", "async function test() { 'value'; }"),
+        (
+            "async function test(value: number) { return await value; }",
+            "async function test(value: number) { return value; }",
+        ),
+    ];
+
+    Tester::new(AwaitThenable::NAME, AwaitThenable::PLUGIN, pass, fail)
+        .with_lint_options(LintOptions { type_aware: true, ..LintOptions::default() })
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
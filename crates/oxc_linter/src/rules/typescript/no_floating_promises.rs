@@ -0,0 +1,295 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, UnaryOperator},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    type_checker::TypeChecker,
+};
+
+fn no_floating_promises_diagnostic(span: Span, ignore_void: bool) -> OxcDiagnostic {
+    let help = if ignore_void {
+        "Promises must be awaited, end with a call to .catch, end with a call to .then with a rejection handler or be explicitly marked as ignored with the `void` operator."
+    } else {
+        "Promises must be awaited, end with a call to .catch, or end with a call to .then with a rejection handler."
+    };
+    OxcDiagnostic::warn("Promise is not handled").with_help(help).with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoFloatingPromises {
+    ignore_void: bool,
+    ignore_iife: bool,
+}
+
+impl Default for NoFloatingPromises {
+    fn default() -> Self {
+        Self { ignore_void: true, ignore_iife: false }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require Promise-like statements to be handled appropriately.
+    ///
+    /// This rule requires type-aware linting to be enabled.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A "floating" Promise is one that is created without any code set up to handle any errors
+    /// it might throw. Floating Promises can cause several issues, such as improperly sequenced
+    /// operations, ignored Promise rejections, and more.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// const promise = new Promise((resolve, reject) => resolve('value'));
+    /// promise;
+    ///
+    /// async function returnsPromise() {
+    ///   return 'value';
+    /// }
+    /// returnsPromise().then(() => {});
+    ///
+    /// Promise.reject('value').catch();
+    ///
+    /// Promise.reject('value').finally();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// const promise = new Promise((resolve, reject) => resolve('value'));
+    /// await promise;
+    ///
+    /// async function returnsPromise() {
+    ///   return 'value';
+    /// }
+    /// void returnsPromise();
+    /// returnsPromise().then(
+    ///   () => {},
+    ///   () => {},
+    /// );
+    ///
+    /// await Promise.reject('value').catch(() => {});
+    ///
+    /// await Promise.reject('value').finally(() => {});
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### ignoreVoid
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Whether to ignore `void` expressions.
+    ///
+    /// #### ignoreIIFE
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Whether to ignore async IIFEs (Immediately Invoked Function Expressions).
+    NoFloatingPromises,
+    typescript,
+    suspicious,
+    suggestion
+);
+
+impl Rule for NoFloatingPromises {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        use serde_json::Value;
+        let Some(config) = value.get(0).and_then(Value::as_object) else {
+            return Self::default();
+        };
+        Self {
+            ignore_void: config.get("ignoreVoid").and_then(Value::as_bool).unwrap_or(true),
+            ignore_iife: config.get("ignoreIIFE").and_then(Value::as_bool).unwrap_or(false),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ExpressionStatement(stmt) = node.kind() else { return };
+        let Some(checker) = ctx.type_checker() else { return };
+        let expr = &stmt.expression;
+        if self.ignore_iife && is_async_iife(expr) {
+            return;
+        }
+        if !self.is_unhandled_promise(checker, expr) {
+            return;
+        }
+        let diagnostic = no_floating_promises_diagnostic(expr.span(), self.ignore_void);
+        if self.ignore_void {
+            ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                if matches!(expr.without_parentheses(), Expression::SequenceExpression(_)) {
+                    fixer
+                        .replace(expr.span(), format!("void ({})", fixer.source_range(expr.span())))
+                } else {
+                    fixer.insert_text_before(expr, "void ")
+                }
+            });
+        } else {
+            ctx.diagnostic(diagnostic);
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.is_type_aware() && ctx.source_type().is_typescript()
+    }
+}
+
+impl NoFloatingPromises {
+    fn is_unhandled_promise<'a>(
+        &self,
+        checker: TypeChecker<'_, 'a>,
+        expr: &Expression<'a>,
+    ) -> bool {
+        match expr.without_parentheses() {
+            Expression::SequenceExpression(sequence) => {
+                sequence.expressions.iter().any(|expr| self.is_unhandled_promise(checker, expr))
+            }
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::Void => {
+                !self.ignore_void && self.is_unhandled_promise(checker, &unary.argument)
+            }
+            Expression::ConditionalExpression(cond) => {
+                self.is_unhandled_promise(checker, &cond.consequent)
+                    || self.is_unhandled_promise(checker, &cond.alternate)
+            }
+            Expression::LogicalExpression(logical) => {
+                self.is_unhandled_promise(checker, &logical.left)
+                    || self.is_unhandled_promise(checker, &logical.right)
+            }
+            // Assigned promises are handled wherever they are used.
+            Expression::AssignmentExpression(_) => false,
+            Expression::CallExpression(call) => {
+                if let Some(member) = call.callee.without_parentheses().get_member_expr() {
+                    match member.static_property_name() {
+                        // `promise.catch(onRejected)`
+                        Some("catch") if !call.arguments.is_empty() => return false,
+                        // `promise.then(onFulfilled, onRejected)`
+                        Some("then") if call.arguments.len() >= 2 => return false,
+                        // `promise.finally()` rejects if `promise` does
+                        Some("finally") => {
+                            return self.is_unhandled_promise(checker, member.object());
+                        }
+                        _ => {}
+                    }
+                }
+                checker.expression_type(expr).is_thenable()
+            }
+            expr => checker.expression_type(expr).is_thenable(),
+        }
+    }
+}
+
+/// `(async () => {})()` or `(async function() {})()`
+fn is_async_iife(expr: &Expression) -> bool {
+    let Expression::CallExpression(call) = expr.without_parentheses() else { return false };
+    match call.callee.without_parentheses() {
+        Expression::ArrowFunctionExpression(arrow) => arrow.r#async,
+        Expression::FunctionExpression(func) => func.r#async,
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::{options::LintOptions, tester::Tester};
+
+    let pass = vec![
+        ("async function test() { await Promise.resolve('value'); }", None),
+        ("async function test() { Promise.resolve('value').then(() => {}, () => {}); }", None),
+        ("async function test() { Promise.resolve('value').catch(() => {}); }", None),
+        (
+            "async function test() { Promise.resolve('value').then(() => {}).catch(() => {}); }",
+            None,
+        ),
+        (
+            "async function test() { Promise.resolve('value').finally(() => {}).catch(() => {}); }",
+            None,
+        ),
+        ("async function test() { void Promise.resolve('value'); }", None),
+        ("async function test() { return Promise.resolve('value'); }", None),
+        (
+            "async function test() { const promise = new Promise((resolve) => resolve('value')); await promise; }",
+            None,
+        ),
+        (
+            "async function returnsPromise() { return 'value'; } async function test() { await returnsPromise(); }",
+            None,
+        ),
+        ("async function returnsPromise() { return 'value'; } void returnsPromise();", None),
+        (
+            "async function returnsPromise() { return 'value'; } let promise; promise = returnsPromise();",
+            None,
+        ),
+        ("function returnsValue(): string { return 'value'; } returnsValue();", None),
+        ("declare const maybePromise: any; maybePromise;", None),
+        ("declare const maybePromise: unknown; maybePromise;", None),
+        ("import { returnsPromise } from './module'; returnsPromise();", None),
+        (
+            "declare function returnsPromise(): Promise<void>; returnsPromise().catch(console.error);",
+            None,
+        ),
+        ("declare const thenable: { then(): void; }; thenable.then(() => {}, () => {});", None),
+        ("foo();", None),
+        ("(async () => { await foo(); })();", Some(json!([{ "ignoreIIFE": true }]))),
+        ("(async function () { await foo(); })();", Some(json!([{ "ignoreIIFE": true }]))),
+    ];
+
+    let fail = vec![
+        ("async function test() { Promise.resolve('value'); }", None),
+        ("async function test() { Promise.resolve('value').then(() => {}); }", None),
+        ("async function test() { Promise.resolve('value').catch(); }", None),
+        ("async function test() { Promise.resolve('value').finally(() => {}); }", None),
+        ("async function test() { new Promise((resolve) => resolve('value')); }", None),
+        ("const promise = new Promise((resolve) => resolve('value')); promise;", None),
+        ("async function returnsPromise() { return 'value'; } returnsPromise();", None),
+        ("const returnsPromise = async () => 'value'; returnsPromise().then(() => {});", None),
+        ("declare function returnsPromise(): Promise<void>; returnsPromise();", None),
+        ("declare const promise: PromiseLike<number>; promise;", None),
+        ("type Task = () => Promise<void>; declare const task: Task; task();", None),
+        ("interface Thenable { then(): void; } declare const thenable: Thenable; thenable;", None),
+        (
+            "declare const promise: Promise<void>; declare const cond: boolean; cond ? promise : null;",
+            None,
+        ),
+        (
+            "declare const promise: Promise<void>; declare const cond: boolean; cond && promise;",
+            None,
+        ),
+        ("declare const promise: Promise<void>; foo(), promise;", None),
+        (
+            "declare const promise: Promise<void>; void promise;",
+            Some(json!([{ "ignoreVoid": false }])),
+        ),
+        ("(async () => { await foo(); })();", None),
+        ("import('./module');", None),
+    ];
+
+    let fix = vec![
+        (
+            "declare const promise: Promise<void>; promise;",
+            "declare const promise: Promise<void>; void promise;",
+            None,
+        ),
+        (
+            "declare const promise: Promise<void>; foo(), promise;",
+            "declare const promise: Promise<void>; void (foo(), promise);",
+            None,
+        ),
+    ];
+
+    Tester::new(NoFloatingPromises::NAME, NoFloatingPromises::PLUGIN, pass, fail)
+        .with_lint_options(LintOptions { type_aware: true, ..LintOptions::default() })
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,345 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, Expression, LogicalOperator, ObjectPropertyKind, UnaryOperator},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    type_checker::TypeChecker,
+};
+
+fn conditional_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected non-Promise value in a boolean conditional.")
+        .with_help("A Promise is always truthy, did you forget to `await` it?")
+        .with_label(span)
+}
+
+fn void_return_argument_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Promise returned in function argument where a void return was expected.")
+        .with_help("The caller does not handle the returned Promise, so its rejections are lost.")
+        .with_label(span)
+}
+
+fn void_return_variable_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Promise-returning function provided to variable where a void return was expected.",
+    )
+    .with_help("The caller does not handle the returned Promise, so its rejections are lost.")
+    .with_label(span)
+}
+
+fn spread_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected a non-Promise value to be spreaded in an object.")
+        .with_help(
+            "Spreading a Promise copies none of its resolved value, did you forget to `await` it?",
+        )
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoMisusedPromises {
+    checks_conditionals: bool,
+    checks_void_return: bool,
+    checks_spreads: bool,
+}
+
+impl Default for NoMisusedPromises {
+    fn default() -> Self {
+        Self { checks_conditionals: true, checks_void_return: true, checks_spreads: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow Promises in places not designed to handle them.
+    ///
+    /// This rule requires type-aware linting to be enabled.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A Promise is always truthy, so using one as a condition is almost certainly a missing
+    /// `await`. Passing a Promise-returning function where a `void`-returning function is
+    /// expected means nothing handles its rejections.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// const promise = Promise.resolve('value');
+    ///
+    /// if (promise) {
+    ///   // Do something
+    /// }
+    ///
+    /// const val = promise ? 123 : 456;
+    ///
+    /// [1, 2, 3].forEach(async value => {
+    ///   await fetch(`/${value}`);
+    /// });
+    ///
+    /// const getData = () => Promise.resolve({ key: 42 });
+    /// const object = { ...getData() };
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// const promise = Promise.resolve('value');
+    ///
+    /// if (await promise) {
+    ///   // Do something
+    /// }
+    ///
+    /// const val = (await promise) ? 123 : 456;
+    ///
+    /// for (const value of [1, 2, 3]) {
+    ///   await fetch(`/${value}`);
+    /// }
+    ///
+    /// const getData = () => Promise.resolve({ key: 42 });
+    /// const object = { ...(await getData()) };
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### checksConditionals
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Whether to check for Promises in boolean conditionals.
+    ///
+    /// #### checksVoidReturn
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Whether to check for Promise-returning functions passed where a `void`-returning function
+    /// is expected.
+    ///
+    /// #### checksSpreads
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Whether to check for Promises spread in object literals.
+    NoMisusedPromises,
+    typescript,
+    pedantic
+);
+
+impl Rule for NoMisusedPromises {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        use serde_json::Value;
+        let Some(config) = value.get(0).and_then(Value::as_object) else {
+            return Self::default();
+        };
+        let is_enabled = |key: &str| {
+            config.get(key).is_none_or(|value| {
+                // `checksVoidReturn` may also be an object of more specific checks.
+                value.as_bool().unwrap_or_else(|| value.is_object())
+            })
+        };
+        Self {
+            checks_conditionals: is_enabled("checksConditionals"),
+            checks_void_return: is_enabled("checksVoidReturn"),
+            checks_spreads: is_enabled("checksSpreads"),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let Some(checker) = ctx.type_checker() else { return };
+        match node.kind() {
+            AstKind::IfStatement(stmt) if self.checks_conditionals => {
+                check_conditional(checker, &stmt.test, ctx);
+            }
+            AstKind::WhileStatement(stmt) if self.checks_conditionals => {
+                check_conditional(checker, &stmt.test, ctx);
+            }
+            AstKind::DoWhileStatement(stmt) if self.checks_conditionals => {
+                check_conditional(checker, &stmt.test, ctx);
+            }
+            AstKind::ForStatement(stmt) if self.checks_conditionals => {
+                if let Some(test) = &stmt.test {
+                    check_conditional(checker, test, ctx);
+                }
+            }
+            AstKind::ConditionalExpression(expr) if self.checks_conditionals => {
+                check_conditional(checker, &expr.test, ctx);
+            }
+            AstKind::UnaryExpression(expr)
+                if self.checks_conditionals && expr.operator == UnaryOperator::LogicalNot =>
+            {
+                check_conditional(checker, &expr.argument, ctx);
+            }
+            AstKind::LogicalExpression(expr)
+                if self.checks_conditionals && expr.operator != LogicalOperator::Coalesce =>
+            {
+                check_conditional(checker, &expr.left, ctx);
+            }
+            AstKind::CallExpression(call) if self.checks_void_return => {
+                check_arguments(checker, &call.callee, &call.arguments, ctx);
+            }
+            AstKind::NewExpression(new_expr) if self.checks_void_return => {
+                check_arguments(checker, &new_expr.callee, &new_expr.arguments, ctx);
+            }
+            AstKind::VariableDeclarator(decl) if self.checks_void_return => {
+                let (Some(annotation), Some(init)) = (&decl.id.type_annotation, &decl.init) else {
+                    return;
+                };
+                if checker.annotation_type(&annotation.type_annotation).returns_void()
+                    && checker.expression_type(init).returns_thenable()
+                {
+                    ctx.diagnostic(void_return_variable_diagnostic(init.span()));
+                }
+            }
+            AstKind::ObjectExpression(object) if self.checks_spreads => {
+                for prop in &object.properties {
+                    if let ObjectPropertyKind::SpreadProperty(spread) = prop {
+                        if checker.expression_type(&spread.argument).is_thenable() {
+                            ctx.diagnostic(spread_diagnostic(spread.argument.span()));
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.is_type_aware() && ctx.source_type().is_typescript()
+    }
+}
+
+fn check_conditional<'a>(
+    checker: TypeChecker<'_, 'a>,
+    expr: &Expression<'a>,
+    ctx: &LintContext<'a>,
+) {
+    match expr.without_parentheses() {
+        // The left operand is checked when visiting the logical expression.
+        Expression::LogicalExpression(logical) if logical.operator != LogicalOperator::Coalesce => {
+            check_conditional(checker, &logical.right, ctx);
+        }
+        expr => {
+            if checker.expression_type(expr).is_thenable() {
+                ctx.diagnostic(conditional_diagnostic(expr.span()));
+            }
+        }
+    }
+}
+
+fn check_arguments<'a>(
+    checker: TypeChecker<'_, 'a>,
+    callee: &Expression<'a>,
+    arguments: &[Argument<'a>],
+    ctx: &LintContext<'a>,
+) {
+    for (index, argument) in arguments.iter().enumerate() {
+        let Some(argument) = argument.as_expression() else { break };
+        if !expects_void_callback(checker, callee, index) {
+            continue;
+        }
+        if checker.expression_type(argument).returns_thenable() {
+            ctx.diagnostic(void_return_argument_diagnostic(argument.span()));
+        }
+    }
+}
+
+/// Whether the parameter at `index` of `callee` is a function with a `void` return type.
+fn expects_void_callback<'a>(
+    checker: TypeChecker<'_, 'a>,
+    callee: &Expression<'a>,
+    index: usize,
+) -> bool {
+    if let Some(member) = callee.without_parentheses().get_member_expr() {
+        // `Array.prototype.forEach`, `Map.prototype.forEach`, `Set.prototype.forEach`,
+        // and `EventTarget.prototype.addEventListener`
+        return matches!(
+            (member.static_property_name(), index),
+            (Some("forEach"), 0) | (Some("addEventListener"), 1)
+        );
+    }
+    let Some(params) = checker.callee_parameters(callee) else { return false };
+    params.items.get(index).and_then(|param| param.pattern.type_annotation.as_ref()).is_some_and(
+        |annotation| checker.annotation_type(&annotation.type_annotation).returns_void(),
+    )
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::{options::LintOptions, tester::Tester};
+
+    let pass = vec![
+        ("async function test() { if (await Promise.resolve(true)) {} }", None),
+        (
+            "declare const promise: Promise<boolean>; async function test() { while (await promise) {} }",
+            None,
+        ),
+        ("declare const value: boolean; if (value) {}", None),
+        ("declare const value: any; if (value) {}", None),
+        (
+            "declare const promise: Promise<boolean> | undefined; const value = promise ?? true;",
+            None,
+        ),
+        (
+            "declare const promise: Promise<void>; if (promise) {}",
+            Some(json!([{ "checksConditionals": false }])),
+        ),
+        ("[1, 2, 3].forEach((value) => { console.log(value); });", None),
+        ("[1, 2, 3].map(async (value) => { await fetch(`/${value}`); });", None),
+        ("function run(callback: () => Promise<void>) {} run(async () => {});", None),
+        ("function run(callback: () => void) {} run(() => {});", None),
+        ("const callback: () => Promise<void> = async () => {};", None),
+        (
+            "[1, 2, 3].forEach(async (value) => { await fetch(`/${value}`); });",
+            Some(json!([{ "checksVoidReturn": false }])),
+        ),
+        (
+            "const getData = () => Promise.resolve({ key: 42 }); async function test() { return { ...(await getData()) }; }",
+            None,
+        ),
+        ("declare const object: { key: number }; const copy = { ...object };", None),
+        (
+            "declare const promise: Promise<{}>; const copy = { ...promise };",
+            Some(json!([{ "checksSpreads": false }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("declare const promise: Promise<boolean>; if (promise) {}", None),
+        ("declare const promise: Promise<boolean>; while (promise) {}", None),
+        ("declare const promise: Promise<boolean>; do {} while (promise);", None),
+        ("declare const promise: Promise<boolean>; for (; promise;) {}", None),
+        ("declare const promise: Promise<boolean>; const value = promise ? 1 : 2;", None),
+        ("declare const promise: Promise<boolean>; const value = !promise;", None),
+        ("declare const promise: Promise<boolean>; const value = promise && 1;", None),
+        (
+            "declare const promise: Promise<boolean>; declare const value: boolean; if (value && promise) {}",
+            None,
+        ),
+        ("async function isValid() { return true; } if (isValid()) {}", None),
+        ("[1, 2, 3].forEach(async (value) => { await fetch(`/${value}`); });", None),
+        ("document.addEventListener('click', async () => { await fetch('/'); });", None),
+        ("function run(callback: () => void) {} run(async () => {});", None),
+        (
+            "type Callback = () => void; const run = (callback: Callback) => {}; run(async function () {});",
+            None,
+        ),
+        ("async function handler() {} function run(callback: () => void) {} run(handler);", None),
+        ("const callback: () => void = async () => {};", None),
+        (
+            "const getData = () => Promise.resolve({ key: 42 }); const object = { ...getData() };",
+            None,
+        ),
+        ("declare const promise: Promise<{}>; const copy = { ...promise };", None),
+    ];
+
+    Tester::new(NoMisusedPromises::NAME, NoMisusedPromises::PLUGIN, pass, fail)
+        .with_lint_options(LintOptions { type_aware: true, ..LintOptions::default() })
+        .test_and_snapshot();
+}
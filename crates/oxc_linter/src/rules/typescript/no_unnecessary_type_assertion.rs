@@ -0,0 +1,198 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, TSType},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    type_checker::{TypeChecker, TypeFlags},
+};
+
+fn no_unnecessary_type_assertion_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "This assertion is unnecessary since it does not change the type of the expression.",
+    )
+    .with_help("Remove the type assertion.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnnecessaryTypeAssertion;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow type assertions that do not change the type of an expression.
+    ///
+    /// This rule requires type-aware linting to be enabled.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// TypeScript can be told an expression is a different type than expected using type
+    /// assertions. Leaving an assertion in code that does not change the type of the expression
+    /// is unnecessary noise, and hides the assertions that actually matter.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// const foo = 3;
+    /// const bar = foo!;
+    ///
+    /// function getName(): string {
+    ///   return 'name';
+    /// }
+    /// const name = getName() as string;
+    ///
+    /// function greet(name: string) {
+    ///   return <string>name;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// declare const foo: number | undefined;
+    /// const bar = foo!;
+    ///
+    /// declare const value: unknown;
+    /// const name = value as string;
+    ///
+    /// const literal = 'name' as const;
+    /// ```
+    NoUnnecessaryTypeAssertion,
+    typescript,
+    suspicious,
+    fix
+);
+
+impl Rule for NoUnnecessaryTypeAssertion {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let Some(checker) = ctx.type_checker() else { return };
+        let (span, expression) = match node.kind() {
+            AstKind::TSNonNullExpression(expr) => {
+                let ty = checker.expression_type(&expr.expression);
+                if !ty.is_known() || ty.flags.intersects(TypeFlags::NULLISH) {
+                    return;
+                }
+                (expr.span, &expr.expression)
+            }
+            AstKind::TSAsExpression(expr) => {
+                if !is_same_as_declared_type(checker, &expr.expression, &expr.type_annotation, ctx)
+                {
+                    return;
+                }
+                (expr.span, &expr.expression)
+            }
+            AstKind::TSTypeAssertion(expr) => {
+                if !is_same_as_declared_type(checker, &expr.expression, &expr.type_annotation, ctx)
+                {
+                    return;
+                }
+                (expr.span, &expr.expression)
+            }
+            _ => return,
+        };
+        ctx.diagnostic_with_fix(no_unnecessary_type_assertion_diagnostic(span), |fixer| {
+            fixer.replace(span, fixer.source_range(expression.span()))
+        });
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.is_type_aware() && ctx.source_type().is_typescript()
+    }
+}
+
+/// `value as T` where `value` was declared with type `T`.
+fn is_same_as_declared_type<'a>(
+    checker: TypeChecker<'_, 'a>,
+    expr: &Expression<'a>,
+    ty: &TSType<'a>,
+    ctx: &LintContext<'a>,
+) -> bool {
+    let Some(declared) = checker.declared_type_annotation(expr) else { return false };
+    ctx.source_range(declared.span())
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .eq(ctx.source_range(ty.span()).chars().filter(|c| !c.is_whitespace()))
+}
+
+#[test]
+fn test() {
+    use crate::{options::LintOptions, tester::Tester};
+
+    let pass = vec![
+        "declare const foo: number | undefined; const bar = foo!;",
+        "declare const foo: string | null; const bar = foo!;",
+        "function test(foo?: string) { return foo!; }",
+        "declare const foo: any; const bar = foo!;",
+        "declare const foo: unknown; const bar = foo!;",
+        "const bar = foo!;",
+        "import { foo } from './module'; const bar = foo!;",
+        "declare const foo: { bar?: string }; const baz = foo.bar!;",
+        "declare const value: unknown; const name = value as string;",
+        "declare const value: string | number; const name = value as string;",
+        "const literal = 'name' as const;",
+        "const value = 'name' as string;",
+        "function test(value?: string) { return value as string; }",
+        "function getName<T>(): T { return null as T; } const name = getName<string>() as string;",
+        "declare const value: Foo; const name = value as Bar;",
+    ];
+
+    let fail = vec![
+        "const foo = 3; const bar = foo!;",
+        "const foo = 'name'; const bar = foo!.length;",
+        "declare const foo: string; const bar = foo!;",
+        "function test(foo: number) { return foo!; }",
+        "function getName(): string { return 'name'; } const name = getName()!;",
+        "declare const foo: { bar: string } | Array<string>; const baz = foo!;",
+        "const foo = [1, 2]!;",
+        "function getName(): string { return 'name'; } const name = getName() as string;",
+        "function greet(name: string) { return name as string; }",
+        "declare const value: Array<string>; const names = value as Array < string >;",
+        "declare const value: string | number; const name = value as string | number;",
+    ];
+
+    let fix = vec![
+        ("const foo = 3; const bar = foo!;", "const foo = 3; const bar = foo;"),
+        (
+            "const foo = 'name'; const bar = foo!.length;",
+            "const foo = 'name'; const bar = foo.length;",
+        ),
+        (
+            "function greet(name: string) { return name as string; }",
+            "function greet(name: string) { return name; }",
+        ),
+    ];
+
+    Tester::new(NoUnnecessaryTypeAssertion::NAME, NoUnnecessaryTypeAssertion::PLUGIN, pass, fail)
+        .with_lint_options(LintOptions { type_aware: true, ..LintOptions::default() })
+        .expect_fix(fix)
+        .test_and_snapshot();
+
+    let pass = vec![
+        "const literal = <const>['name'];",
+        "function greet(name: string | null) { return <string>name; }",
+    ];
+    let fail = vec!["function greet(name: string) { return <string>name; }"];
+    let fix = vec![(
+        "function greet(name: string) { return <string>name; }",
+        "function greet(name: string) { return name; }",
+    )];
+    Tester::new(NoUnnecessaryTypeAssertion::NAME, NoUnnecessaryTypeAssertion::PLUGIN, pass, fail)
+        .with_lint_options(LintOptions { type_aware: true, ..LintOptions::default() })
+        .change_rule_path_extension("ts")
+        .expect_fix(fix)
+        .test();
+
+    // Type-aware rules don't run unless type-aware linting is enabled.
+    let pass = vec!["const foo = 3; const bar = foo!;"];
+    let fail = vec![];
+    Tester::new(NoUnnecessaryTypeAssertion::NAME, NoUnnecessaryTypeAssertion::PLUGIN, pass, fail)
+        .intentionally_allow_no_fix_tests()
+        .test();
+}
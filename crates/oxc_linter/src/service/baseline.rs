@@ -0,0 +1,171 @@
+//! Diagnostics recorded in a baseline file are not reported, so that rules can be enabled without
+//! fixing or disabling every existing violation first.
+
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use cow_utils::CowUtils;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+
+use oxc_diagnostics::{Error, LabeledSpan, OxcDiagnostic};
+
+/// How the baseline file is used, see [`Baseline::load`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BaselineMode {
+    /// Do not report the diagnostics recorded in the baseline file.
+    /// The file is created with the current diagnostics if it does not exist.
+    Check,
+    /// Record all current diagnostics of the linted files, replacing their previous entries.
+    /// Nothing is reported.
+    Update,
+    /// Like [`BaselineMode::Check`], and remove the entries of the linted files which no longer
+    /// occur.
+    Prune,
+}
+
+/// Number of occurrences of each fingerprint, by rule, by path relative to the working directory.
+type Entries = BTreeMap<String, BTreeMap<String, BTreeMap<String, usize>>>;
+
+/// Contents of the baseline file.
+#[derive(Default, Serialize, Deserialize)]
+struct BaselineFile {
+    files: Entries,
+}
+
+/// Diagnostics which existed when the baseline file was written.
+///
+/// A diagnostic is identified by its path, its rule and a fingerprint of the source text it
+/// points at, so it stays in the baseline when code around it moves.
+pub struct Baseline {
+    location: PathBuf,
+    mode: BaselineMode,
+    previous: Entries,
+    /// Occurrences of the baseline which were not seen yet in the current run.
+    remaining: Mutex<Entries>,
+    /// Occurrences to write to the baseline file.
+    current: Mutex<Entries>,
+}
+
+impl Baseline {
+    /// Load the baseline file at `location`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read or parsed.
+    pub fn load(location: PathBuf, mode: BaselineMode) -> Result<Self, OxcDiagnostic> {
+        let (previous, mode) = match fs::read(&location) {
+            Ok(bytes) => {
+                let file = serde_json::from_slice::<BaselineFile>(&bytes).map_err(|err| {
+                    OxcDiagnostic::error(format!(
+                        "Failed to parse baseline file {location:?} with error \"{err}\""
+                    ))
+                })?;
+                (file.files, mode)
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                let mode = if mode == BaselineMode::Check { BaselineMode::Update } else { mode };
+                (Entries::default(), mode)
+            }
+            Err(err) => {
+                return Err(OxcDiagnostic::error(format!(
+                    "Failed to read baseline file {location:?} with error \"{err}\""
+                )));
+            }
+        };
+        Ok(Self {
+            location,
+            mode,
+            remaining: Mutex::new(previous.clone()),
+            previous,
+            current: Mutex::default(),
+        })
+    }
+
+    pub fn location(&self) -> &Path {
+        &self.location
+    }
+
+    /// Remove the `diagnostics` of `path` which are in the baseline, or all diagnostics of lint
+    /// rules when updating it.
+    pub(super) fn filter(&self, path: &Path, diagnostics: &mut Vec<Error>) {
+        let path = path_key(path);
+        let mut remaining = self.remaining.lock().unwrap();
+        let mut current = self.current.lock().unwrap();
+        diagnostics.retain(|error| {
+            let Some(rule) = error.code().map(|code| code.to_string()) else {
+                return true;
+            };
+            let fingerprint = fingerprint(&rule, error);
+            let count = remaining
+                .get_mut(&path)
+                .and_then(|rules| rules.get_mut(&rule))
+                .and_then(|fingerprints| fingerprints.get_mut(&fingerprint))
+                .filter(|count| **count > 0);
+            match count {
+                Some(count) => *count -= 1,
+                None if self.mode == BaselineMode::Update => {}
+                None => return true,
+            }
+            *current
+                .entry(path.clone())
+                .or_default()
+                .entry(rule)
+                .or_default()
+                .entry(fingerprint)
+                .or_default() += 1;
+            false
+        });
+    }
+
+    /// Write the baseline file, unless it is only checked against. Entries of paths which were
+    /// not linted are kept.
+    pub(super) fn save<'a>(&self, linted: impl IntoIterator<Item = &'a Path>) -> io::Result<()> {
+        if self.mode == BaselineMode::Check {
+            return Ok(());
+        }
+        let mut files = self.previous.clone();
+        for path in linted {
+            files.remove(&path_key(path));
+        }
+        files.extend(std::mem::take(&mut *self.current.lock().unwrap()));
+        let mut contents = serde_json::to_string_pretty(&BaselineFile { files })?;
+        contents.push('\n');
+        if let Some(parent) = self.location.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.location, contents)
+    }
+}
+
+/// The primary label of `error`, or its first label if none is primary.
+pub(super) fn primary_label(error: &Error) -> Option<LabeledSpan> {
+    error
+        .labels()?
+        .reduce(|first, label| if label.primary() && !first.primary() { label } else { first })
+}
+
+fn path_key(path: &Path) -> String {
+    path.to_string_lossy().cow_replace('\\', "/").into_owned()
+}
+
+/// Hash of the rule and the source text of the diagnostic's primary label, ignoring whitespace.
+fn fingerprint(rule: &str, error: &Error) -> String {
+    let text = error
+        .source_code()
+        .zip(primary_label(error))
+        .and_then(|(source, label)| source.read_span(label.inner(), 0, 0).ok())
+        .map(|contents| String::from_utf8_lossy(contents.data()).split_whitespace().join(" "))
+        .unwrap_or_default();
+    let mut hasher = Sha1::new();
+    hasher.update(rule);
+    hasher.update([0]);
+    hasher.update(text);
+    let digest = hasher.finalize();
+    format!("{:016x}", u64::from_be_bytes(digest[..8].try_into().unwrap()))
+}
@@ -0,0 +1,244 @@
+//! Lint results persisted between runs, so files which did not change are not linted again.
+
+use std::{
+    borrow::Cow,
+    fmt::Write,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+
+use oxc_diagnostics::{DiagnosticFix, LabeledSpan, OxcCode, OxcDiagnostic, Severity};
+
+/// Contents of the cache file.
+#[derive(Default, Serialize, Deserialize)]
+struct CacheFile {
+    /// Versions of the program and `oxc_linter`, and the lint options. Entries written with a
+    /// different key are discarded.
+    key: String,
+    entries: FxHashMap<String, CacheEntry>,
+}
+
+/// A module, keyed by its path in [`CacheFile::entries`].
+#[derive(Clone, Serialize, Deserialize)]
+pub(super) struct CacheEntry {
+    /// SHA-1 hash of the source text, as hex.
+    pub source_hash: String,
+    /// Resolved paths of imported modules. Only recorded when cross-module linting is enabled.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<String>,
+    /// `None` if the module was only loaded as a dependency of a linted module.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<LintResult>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub(super) struct LintResult {
+    /// [`ConfigStore::hash`](crate::ConfigStore::hash) of the configuration used for the module.
+    pub config_hash: u64,
+    pub sections: Vec<CachedSection>,
+}
+
+/// Diagnostics of one source section, see [`crate::loader::PartialLoader`].
+#[derive(Clone, Serialize, Deserialize)]
+pub(super) struct CachedSection {
+    /// Offset of the section in the source text.
+    pub start: u32,
+    pub diagnostics: Vec<CachedDiagnostic>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub(super) struct CachedDiagnostic {
+    message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    labels: Option<Vec<CachedLabel>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    help: Option<String>,
+    severity: CachedSeverity,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    scope: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fix: Option<CachedFix>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedFix {
+    start: u32,
+    end: u32,
+    content: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedLabel {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    offset: usize,
+    len: usize,
+    primary: bool,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum CachedSeverity {
+    Advice,
+    Warning,
+    Error,
+}
+
+impl CachedDiagnostic {
+    pub fn new(diagnostic: &OxcDiagnostic, fix: Option<&DiagnosticFix>) -> Self {
+        Self {
+            message: diagnostic.message.to_string(),
+            labels: diagnostic.labels.as_ref().map(|labels| {
+                labels
+                    .iter()
+                    .map(|label| CachedLabel {
+                        label: label.label().map(ToString::to_string),
+                        offset: label.offset(),
+                        len: label.len(),
+                        primary: label.primary(),
+                    })
+                    .collect()
+            }),
+            help: diagnostic.help.as_ref().map(ToString::to_string),
+            severity: match diagnostic.severity {
+                Severity::Advice => CachedSeverity::Advice,
+                Severity::Warning => CachedSeverity::Warning,
+                Severity::Error => CachedSeverity::Error,
+            },
+            scope: diagnostic.code.scope.as_ref().map(ToString::to_string),
+            number: diagnostic.code.number.as_ref().map(ToString::to_string),
+            url: diagnostic.url.as_ref().map(ToString::to_string),
+            fix: fix.map(|fix| CachedFix {
+                start: fix.start,
+                end: fix.end,
+                content: fix.content.to_string(),
+                message: fix.message.as_ref().map(ToString::to_string),
+            }),
+        }
+    }
+
+    pub fn into_diagnostic_with_fix(self) -> (OxcDiagnostic, Option<DiagnosticFix>) {
+        let mut diagnostic = OxcDiagnostic::error(self.message);
+        diagnostic.labels = self.labels.map(|labels| {
+            labels
+                .into_iter()
+                .map(|label| {
+                    let span = (label.offset, label.len);
+                    if label.primary {
+                        LabeledSpan::new_primary_with_span(label.label, span)
+                    } else {
+                        LabeledSpan::new_with_span(label.label, span)
+                    }
+                })
+                .collect()
+        });
+        diagnostic.help = self.help.map(Cow::Owned);
+        diagnostic.severity = match self.severity {
+            CachedSeverity::Advice => Severity::Advice,
+            CachedSeverity::Warning => Severity::Warning,
+            CachedSeverity::Error => Severity::Error,
+        };
+        diagnostic.code =
+            OxcCode { scope: self.scope.map(Cow::Owned), number: self.number.map(Cow::Owned) };
+        diagnostic.url = self.url.map(Cow::Owned);
+        let fix = self.fix.map(|fix| DiagnosticFix {
+            start: fix.start,
+            end: fix.end,
+            content: Cow::Owned(fix.content),
+            message: fix.message.map(Cow::Owned),
+        });
+        (diagnostic, fix)
+    }
+}
+
+/// Lint results of the previous run, and the results of the current run to be saved.
+pub(super) struct LintCache {
+    location: PathBuf,
+    key: String,
+    previous: FxHashMap<String, CacheEntry>,
+    current: Mutex<FxHashMap<String, CacheEntry>>,
+}
+
+impl LintCache {
+    /// Load the cache file at `location`. A missing or unreadable cache file, or one written with
+    /// a different `key`, is treated as empty.
+    pub fn load(location: PathBuf, key: String) -> Self {
+        let previous = fs::read(&location)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<CacheFile>(&bytes).ok())
+            .filter(|file| file.key == key)
+            .map(|file| file.entries)
+            .unwrap_or_default();
+        Self { location, key, previous, current: Mutex::default() }
+    }
+
+    pub fn location(&self) -> &Path {
+        &self.location
+    }
+
+    /// Entry of `path` written by the previous run.
+    pub fn previous(&self, path: &str) -> Option<&CacheEntry> {
+        self.previous.get(path)
+    }
+
+    /// Keep the entry of `path` from the previous run.
+    pub fn retain(&self, path: &str) {
+        if let Some(entry) = self.previous.get(path) {
+            self.current.lock().unwrap().insert(path.to_string(), entry.clone());
+        }
+    }
+
+    /// Record a module which was just read. A lint result kept by [`LintCache::retain`] stays
+    /// if the source text is the same.
+    pub fn insert(&self, path: &str, source_hash: String, dependencies: Vec<String>) {
+        let mut current = self.current.lock().unwrap();
+        let result = current
+            .remove(path)
+            .filter(|entry| entry.source_hash == source_hash)
+            .and_then(|entry| entry.result);
+        current.insert(path.to_string(), CacheEntry { source_hash, dependencies, result });
+    }
+
+    /// Record the lint result of a module previously passed to [`LintCache::insert`].
+    pub fn set_result(&self, path: &str, result: LintResult) {
+        if let Some(entry) = self.current.lock().unwrap().get_mut(path) {
+            entry.result = Some(result);
+        }
+    }
+
+    /// Forget `path`, e.g. because fixes were written to it.
+    pub fn remove(&self, path: &str) {
+        self.current.lock().unwrap().remove(path);
+    }
+
+    /// Write the results of the current run to the cache file.
+    pub fn save(&self) -> io::Result<()> {
+        let file = CacheFile {
+            key: self.key.clone(),
+            entries: std::mem::take(&mut *self.current.lock().unwrap()),
+        };
+        if let Some(parent) = self.location.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.location, serde_json::to_vec(&file)?)
+    }
+}
+
+/// A file is not linted again if its hash is the same, so the hash must not collide.
+pub(super) fn hash_source(source_text: &str) -> String {
+    Sha1::digest(source_text).iter().fold(String::with_capacity(40), |mut hash, byte| {
+        let _ = write!(hash, "{byte:02x}");
+        hash
+    })
+}
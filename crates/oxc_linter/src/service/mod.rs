@@ -1,14 +1,21 @@
 use std::{
     ffi::OsStr,
+    ops::Range,
     path::{Path, PathBuf},
     sync::Arc,
 };
 
+use rustc_hash::FxHashMap;
+
 use oxc_diagnostics::DiagnosticSender;
 use runtime::Runtime;
 
+pub use baseline::{Baseline, BaselineMode};
+
 use crate::Linter;
 
+mod baseline;
+mod cache;
 mod runtime;
 
 #[cfg(feature = "language_server")]
@@ -25,6 +32,21 @@ pub struct LintServiceOptions {
     tsconfig: Option<PathBuf>,
 
     cross_module: bool,
+
+    /// Report fixes with the diagnostics instead of applying them
+    report_fixes: bool,
+
+    /// Location of the cache file, and the version of the program it is written by
+    cache: Option<(PathBuf, String)>,
+
+    /// Diagnostics not to report
+    baseline: Option<Baseline>,
+
+    /// Lines to report diagnostics on, by path relative to `cwd`
+    changed_lines: Option<FxHashMap<PathBuf, Vec<Range<usize>>>>,
+
+    /// Source texts of paths which are not read from the file system
+    source_texts: FxHashMap<Arc<OsStr>, String>,
 }
 
 impl LintServiceOptions {
@@ -33,7 +55,17 @@ impl LintServiceOptions {
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), paths, tsconfig: None, cross_module: false }
+        Self {
+            cwd: cwd.into(),
+            paths,
+            tsconfig: None,
+            cross_module: false,
+            report_fixes: false,
+            cache: None,
+            baseline: None,
+            changed_lines: None,
+            source_texts: FxHashMap::default(),
+        }
     }
 
     #[inline]
@@ -58,6 +90,60 @@ impl LintServiceOptions {
         self
     }
 
+    /// Report the fixes of the linter's [fix kind](crate::Linter::with_fix) with the diagnostics,
+    /// as [`FixableDiagnostic`](oxc_diagnostics::FixableDiagnostic)s, instead of writing them to
+    /// the files.
+    #[inline]
+    #[must_use]
+    pub fn with_report_fixes(mut self, yes: bool) -> Self {
+        self.report_fixes = yes;
+        self
+    }
+
+    /// Reuse the results of previous runs stored in the cache file at `location`, and update it.
+    ///
+    /// Results are discarded when `version` changes.
+    #[must_use]
+    pub fn with_cache<T>(mut self, location: T, version: &str) -> Self
+    where
+        T: Into<PathBuf>,
+    {
+        self.cache = Some((location.into(), version.to_string()));
+        self
+    }
+
+    /// Do not report the diagnostics in `baseline`, and update its file according to its
+    /// [mode](BaselineMode).
+    #[must_use]
+    pub fn with_baseline(mut self, baseline: Baseline) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+
+    /// Only report diagnostics starting on the given lines (counted from 1) of their file, e.g.
+    /// the lines changed since a git revision. Files not in `changed_lines` are reported in full.
+    #[must_use]
+    pub fn with_changed_lines(
+        mut self,
+        changed_lines: FxHashMap<PathBuf, Vec<Range<usize>>>,
+    ) -> Self {
+        self.changed_lines = Some(changed_lines);
+        self
+    }
+
+    /// Lint `source_text` as the contents of `path`, which does not need to exist. `path` must
+    /// also be one of the paths to lint.
+    ///
+    /// Fixes are not written to the file, see [`LintService::take_fixed_source_text`].
+    #[must_use]
+    pub fn with_source_text<T>(mut self, path: T, source_text: String) -> Self
+    where
+        T: Into<PathBuf>,
+    {
+        self.source_texts.insert(path.into().into_os_string().into(), source_text);
+        self
+    }
+
     #[inline]
     pub fn cwd(&self) -> &Path {
         &self.cwd
@@ -90,6 +176,12 @@ impl LintService {
         tx_error.send(None).unwrap();
     }
 
+    /// The fixed source text of a path given to [`LintServiceOptions::with_source_text`], if
+    /// [`LintService::run`] fixed it.
+    pub fn take_fixed_source_text(&self, path: &Path) -> Option<String> {
+        self.runtime.take_fixed_source_text(path)
+    }
+
     #[cfg(feature = "language_server")]
     pub fn run_source<'a>(
         &mut self,
@@ -3,9 +3,10 @@ use std::{
     ffi::OsStr,
     fs,
     mem::take,
+    ops::Range,
     path::{Path, PathBuf},
     rc::Rc,
-    sync::{Arc, mpsc},
+    sync::{Arc, Mutex, mpsc},
 };
 
 use indexmap::IndexSet;
@@ -16,19 +17,25 @@ use self_cell::self_cell;
 use smallvec::SmallVec;
 
 use oxc_allocator::Allocator;
-use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic};
+use oxc_diagnostics::{
+    DiagnosticSender, DiagnosticService, DiagnosticTuple, Error, LabeledSpan, OxcDiagnostic,
+};
 use oxc_parser::{ParseOptions, Parser};
 use oxc_resolver::Resolver;
 use oxc_semantic::{Semantic, SemanticBuilder};
-use oxc_span::{CompactStr, SourceType, VALID_EXTENSIONS};
+use oxc_span::{CompactStr, GetSpan, SourceType, Span, VALID_EXTENSIONS};
 
 #[cfg(feature = "language_server")]
 use oxc_allocator::CloneIn;
 
-use super::LintServiceOptions;
+use super::{
+    Baseline, LintServiceOptions,
+    baseline::primary_label,
+    cache::{CachedDiagnostic, CachedSection, LintCache, LintResult, hash_source},
+};
 use crate::{
     Fixer, Linter, Message,
-    loader::{JavaScriptSource, LINT_PARTIAL_LOADER_EXTENSIONS, PartialLoader},
+    loader::{JavaScriptSource, LINT_PARTIAL_LOADER_EXTENSIONS, PartialLoader, Template},
     module_record::ModuleRecord,
     utils::read_to_string,
 };
@@ -44,6 +51,18 @@ pub struct Runtime {
     paths: IndexSet<Arc<OsStr>, FxBuildHasher>,
     pub(super) linter: Linter,
     resolver: Option<Resolver>,
+    /// See [`LintServiceOptions::with_report_fixes`].
+    report_fixes: bool,
+    /// Results of previous runs, see [`LintServiceOptions::with_cache`].
+    cache: Option<LintCache>,
+    /// See [`LintServiceOptions::with_baseline`].
+    baseline: Option<Baseline>,
+    /// See [`LintServiceOptions::with_changed_lines`].
+    changed_lines: Option<FxHashMap<PathBuf, Vec<Range<usize>>>>,
+    /// See [`LintServiceOptions::with_source_text`].
+    source_texts: FxHashMap<Arc<OsStr>, String>,
+    /// Fixed source texts of the paths in `source_texts`.
+    fixed_source_texts: Mutex<FxHashMap<Arc<OsStr>, String>>,
 
     // The language server uses more up to date source_text provided by `workspace/didChange` request.
     // This is required to support `run: "onType"` configuration
@@ -115,6 +134,9 @@ struct SectionContent<'a> {
     /// None if section parsing failed. The corresponding item with the same index in
     /// `ProcessedModule.section_module_records` would be `Err(Vec<OxcDiagnostic>)`.
     semantic: Option<Semantic<'a>>,
+    /// Expressions of the template which were parsed together with the section, so `semantic`
+    /// is for [`Template::source_text`].
+    template: Option<Template>,
 }
 
 /// A module with its source text and semantic, ready to be linted.
@@ -145,11 +167,27 @@ impl Runtime {
         let resolver = options.cross_module.then(|| {
             Self::get_resolver(options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))))
         });
+        let cache = options.cache.map(|(location, version)| {
+            let key = format!(
+                "{version} {} {:?} cross_module: {} report_fixes: {}",
+                env!("CARGO_PKG_VERSION"),
+                linter.options(),
+                options.cross_module,
+                options.report_fixes
+            );
+            LintCache::load(location, key)
+        });
         Self {
             cwd: options.cwd,
             paths: options.paths.iter().cloned().collect(),
             linter,
             resolver,
+            report_fixes: options.report_fixes,
+            cache,
+            baseline: options.baseline,
+            changed_lines: options.changed_lines,
+            source_texts: options.source_texts,
+            fixed_source_texts: Mutex::default(),
             #[cfg(feature = "language_server")]
             source_text_cache: FxHashMap::default(),
             #[cfg(test)]
@@ -209,6 +247,10 @@ impl Runtime {
             return Some(Ok((source_type, source_text.clone())));
         }
 
+        if let Some(source_text) = self.source_texts.get(path.as_os_str()) {
+            return Some(Ok((source_type, source_text.clone())));
+        }
+
         let file_result = read_to_string(path).map_err(|e| {
             Error::new(OxcDiagnostic::error(format!(
                 "Failed to open file {path:?} with error \"{e}\""
@@ -455,6 +497,17 @@ impl Runtime {
     // we assume that the fix offset will not exceed 2GB in either direction
     #[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
     pub(super) fn run(&mut self, tx_error: &DiagnosticSender) {
+        // Cached paths are removed from `self.paths`, but they are linted too.
+        let linted = self.baseline.is_some().then(|| {
+            self.paths
+                .iter()
+                .map(|path| {
+                    let path = Path::new(path);
+                    path.strip_prefix(&self.cwd).unwrap_or(path).to_path_buf()
+                })
+                .collect::<Vec<_>>()
+        });
+        self.report_cached_results(tx_error);
         rayon::scope(|scope| {
             self.resolve_modules(scope, true, tx_error, |me, mut module_to_lint| {
                 module_to_lint.content.with_dependent_mut(|owner, sections| {
@@ -469,23 +522,24 @@ impl Runtime {
 
                     let path = Path::new(&module_to_lint.path);
 
+                    let mut cached_sections = vec![];
+
                     assert_eq!(module_to_lint.section_module_records.len(), sections.len());
-                    for (record_result, section) in
+                    for (record_result, mut section) in
                         module_to_lint.section_module_records.into_iter().zip(sections.drain(..))
                     {
-                        let mut messages = match record_result {
-                            Ok(module_record) => me.linter.run(
-                                path,
-                                Rc::new(section.semantic.unwrap()),
-                                Arc::clone(&module_record),
-                            ),
-                            Err(errors) => {
-                                errors.into_iter().map(|err| Message::new(err, None)).collect()
+                        let (mut messages, template_messages) = match record_result {
+                            Ok(module_record) => {
+                                me.lint_section(path, &module_record, &mut section)
                             }
+                            Err(errors) => (
+                                errors.into_iter().map(|err| Message::new(err, None)).collect(),
+                                vec![],
+                            ),
                         };
 
                         let source_text = section.source.source_text;
-                        if me.linter.options().fix.is_some() {
+                        if me.linter.options().fix.is_some() && !me.report_fixes {
                             let fix_result = Fixer::new(source_text, messages).fix();
                             if fix_result.fixed {
                                 // write to file, replacing only the changed part
@@ -503,26 +557,228 @@ impl Runtime {
                             messages = fix_result.messages;
                         }
 
-                        if !messages.is_empty() {
-                            let errors = messages.into_iter().map(Into::into).collect();
-                            let path = path.strip_prefix(&me.cwd).unwrap_or(path);
-                            let diagnostics = DiagnosticService::wrap_diagnostics(
-                                path,
-                                &owner.source_text,
-                                section.source.start,
-                                errors,
+                        // Labels of template messages are at offsets in the whole file.
+                        for (start, messages) in
+                            [(section.source.start, messages), (0, template_messages)]
+                        {
+                            let errors = messages
+                                .into_iter()
+                                .map(Message::into_diagnostic_with_fix)
+                                .collect::<Vec<_>>();
+                            if me.cache.is_some() {
+                                cached_sections.push(CachedSection {
+                                    start,
+                                    diagnostics: errors
+                                        .iter()
+                                        .map(|(diagnostic, fix)| {
+                                            CachedDiagnostic::new(diagnostic, fix.as_ref())
+                                        })
+                                        .collect(),
+                                });
+                            }
+
+                            if !errors.is_empty() {
+                                let path = path.strip_prefix(&me.cwd).unwrap_or(path);
+                                let diagnostics = DiagnosticService::wrap_diagnostics_with_fixes(
+                                    path,
+                                    &owner.source_text,
+                                    start,
+                                    errors,
+                                );
+                                me.send_diagnostics(tx_error, diagnostics);
+                            }
+                        }
+                    }
+                    if let (Some(cache), Some(key)) = (&me.cache, path.to_str()) {
+                        if matches!(new_source_text, Cow::Owned(_)) {
+                            cache.remove(key);
+                        } else {
+                            cache.set_result(
+                                key,
+                                LintResult {
+                                    config_hash: me.linter.config_hash(path),
+                                    sections: cached_sections,
+                                },
                             );
-                            tx_error.send(Some(diagnostics)).unwrap();
                         }
                     }
+
                     // If the new source text is owned, that means it was modified,
                     // so we write the new source text to the file.
                     if let Cow::Owned(new_source_text) = new_source_text {
-                        fs::write(path, new_source_text).unwrap();
+                        if me.source_texts.contains_key(&module_to_lint.path) {
+                            me.fixed_source_texts
+                                .lock()
+                                .unwrap()
+                                .insert(Arc::clone(&module_to_lint.path), new_source_text);
+                        } else {
+                            fs::write(path, new_source_text).unwrap();
+                        }
                     }
                 });
             });
         });
+
+        if let Some(cache) = &self.cache {
+            if let Err(err) = cache.save() {
+                let diagnostic = OxcDiagnostic::warn(format!(
+                    "Failed to write cache file {:?} with error \"{err}\"",
+                    cache.location()
+                ));
+                tx_error
+                    .send(Some((cache.location().to_path_buf(), vec![Error::new(diagnostic)])))
+                    .unwrap();
+            }
+        }
+
+        if let (Some(baseline), Some(linted)) = (&self.baseline, linted) {
+            if let Err(err) = baseline.save(linted.iter().map(PathBuf::as_path)) {
+                let diagnostic = OxcDiagnostic::warn(format!(
+                    "Failed to write baseline file {:?} with error \"{err}\"",
+                    baseline.location()
+                ));
+                tx_error
+                    .send(Some((baseline.location().to_path_buf(), vec![Error::new(diagnostic)])))
+                    .unwrap();
+            }
+        }
+    }
+
+    pub(super) fn take_fixed_source_text(&self, path: &Path) -> Option<String> {
+        self.fixed_source_texts.lock().unwrap().remove(path.as_os_str())
+    }
+
+    /// Send `diagnostics` which are not in the baseline, and are on the lines to report.
+    fn send_diagnostics(&self, tx_error: &DiagnosticSender, diagnostics: DiagnosticTuple) {
+        let (path, mut diagnostics) = diagnostics;
+        if let Some(baseline) = &self.baseline {
+            baseline.filter(&path, &mut diagnostics);
+        }
+        if let Some(lines) = self.changed_lines.as_ref().and_then(|lines| lines.get(&path)) {
+            diagnostics.retain(|error| {
+                let line =
+                    error.source_code().zip(primary_label(error)).and_then(|(source, label)| {
+                        let contents = source.read_span(&(label.offset(), 0).into(), 0, 0).ok()?;
+                        Some(contents.line() + 1)
+                    });
+                line.is_none_or(|line| lines.iter().any(|lines| lines.contains(&line)))
+            });
+        }
+        if !diagnostics.is_empty() {
+            tx_error.send(Some((path, diagnostics))).unwrap();
+        }
+    }
+
+    /// Report the cached diagnostics of paths which are up to date with the cache, and remove
+    /// them from the paths to lint.
+    ///
+    /// A path is up to date if neither its source text nor its configuration changed since it was
+    /// cached. With cross-module linting, the modules it imports, directly or indirectly, must
+    /// not have changed either.
+    fn report_cached_results(&mut self, tx_error: &DiagnosticSender) {
+        let Some(cache) = &self.cache else {
+            return;
+        };
+
+        // Modules to compare with the cache: the paths to lint, and the modules they imported.
+        let mut modules = self
+            .paths
+            .iter()
+            .filter_map(|path| path.to_str())
+            .collect::<IndexSet<&str, FxBuildHasher>>();
+        if self.resolver.is_some() {
+            let mut index = 0;
+            while let Some(path) = modules.get_index(index) {
+                if let Some(entry) = cache.previous(path) {
+                    modules.extend(entry.dependencies.iter().map(String::as_str));
+                }
+                index += 1;
+            }
+        }
+
+        let changed = modules
+            .par_iter()
+            .map(|path| {
+                cache.previous(path).is_none_or(|entry| {
+                    !read_to_string(Path::new(path))
+                        .is_ok_and(|source_text| hash_source(&source_text) == entry.source_hash)
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut stale = modules
+            .iter()
+            .zip(changed)
+            .filter_map(|(path, changed)| changed.then_some(*path))
+            .collect::<FxHashSet<_>>();
+
+        // Modules importing a changed module are stale too.
+        if self.resolver.is_some() {
+            let mut dependents = FxHashMap::<&str, Vec<&str>>::default();
+            for path in &modules {
+                for dependency in cache.previous(path).iter().flat_map(|entry| &entry.dependencies)
+                {
+                    dependents.entry(dependency.as_str()).or_default().push(path);
+                }
+            }
+            let mut queue = stale.iter().copied().collect::<Vec<_>>();
+            while let Some(path) = queue.pop() {
+                for dependent in dependents.get(path).into_iter().flatten() {
+                    if stale.insert(dependent) {
+                        queue.push(dependent);
+                    }
+                }
+            }
+        }
+
+        for path in modules.iter().filter(|path| !stale.contains(*path)) {
+            cache.retain(path);
+        }
+
+        let up_to_date = self
+            .paths
+            .iter()
+            .filter_map(|path| {
+                let key = path.to_str()?;
+                if stale.contains(key) {
+                    return None;
+                }
+                let result = cache.previous(key)?.result.as_ref()?;
+                (result.config_hash == self.linter.config_hash(Path::new(path)))
+                    .then_some((path, result))
+            })
+            .collect::<Vec<_>>();
+
+        up_to_date.par_iter().for_each(|(path, result)| {
+            if result.sections.iter().all(|section| section.diagnostics.is_empty()) {
+                return;
+            }
+            let path = Path::new(path);
+            let Ok(source_text) = read_to_string(path) else {
+                return;
+            };
+            let path = path.strip_prefix(&self.cwd).unwrap_or(path);
+            for section in &result.sections {
+                if section.diagnostics.is_empty() {
+                    continue;
+                }
+                let diagnostics = DiagnosticService::wrap_diagnostics_with_fixes(
+                    path,
+                    &source_text,
+                    section.start,
+                    section
+                        .diagnostics
+                        .iter()
+                        .cloned()
+                        .map(CachedDiagnostic::into_diagnostic_with_fix)
+                        .collect(),
+                );
+                self.send_diagnostics(tx_error, diagnostics);
+            }
+        });
+
+        let up_to_date =
+            up_to_date.into_iter().map(|(path, _)| Arc::clone(path)).collect::<FxHashSet<_>>();
+        self.paths.retain(|path| !up_to_date.contains(path));
     }
 
     // clippy: the source field is checked and assumed to be less than 4GB, and
@@ -553,7 +809,7 @@ impl Runtime {
                 module.content.with_dependent_mut(|_owner, dependent| {
                     assert_eq!(module.section_module_records.len(), dependent.len());
 
-                    for (record_result, section) in
+                    for (record_result, mut section) in
                         module.section_module_records.into_iter().zip(dependent.drain(..))
                     {
                         match record_result {
@@ -564,66 +820,80 @@ impl Runtime {
                                     .extend(diagnostics.into_iter().map(std::convert::Into::into));
                             }
                             Ok(module_record) => {
-                                let section_message = me.linter.run(
+                                let (section_message, template_message) = me.lint_section(
                                     Path::new(&module.path),
-                                    Rc::new(section.semantic.unwrap()),
-                                    Arc::clone(&module_record),
+                                    &module_record,
+                                    &mut section,
                                 );
 
-                                messages.lock().unwrap().extend(section_message.iter().map(
-                                    |message| {
-                                        let message = message.clone_in(allocator);
-
-                                        let labels = &message.error.labels.clone().map(|labels| {
-                                            labels
-                                                .into_iter()
-                                                .map(|labeled_span| {
-                                                    let offset = labeled_span.offset() as u32;
-                                                    let start_position = offset_to_position(
-                                                        offset + section.source.start,
-                                                        source_text,
-                                                    );
-                                                    let end_position = offset_to_position(
-                                                        offset
-                                                            + section.source.start
-                                                            + labeled_span.len() as u32,
-                                                        source_text,
-                                                    );
-                                                    let message = labeled_span
-                                                        .label()
-                                                        .map(|label| Cow::Owned(label.to_string()));
-
-                                                    SpanPositionMessage::new(
-                                                        start_position,
-                                                        end_position,
+                                // Labels of template messages are at offsets in the whole file.
+                                for (start, section_message) in
+                                    [(section.source.start, section_message), (0, template_message)]
+                                {
+                                    messages.lock().unwrap().extend(section_message.iter().map(
+                                        |message| {
+                                            let message = message.clone_in(allocator);
+
+                                            let labels =
+                                                &message.error.labels.clone().map(|labels| {
+                                                    labels
+                                                        .into_iter()
+                                                        .map(|labeled_span| {
+                                                            let offset =
+                                                                labeled_span.offset() as u32;
+                                                            let start_position = offset_to_position(
+                                                                offset + start,
+                                                                source_text,
+                                                            );
+                                                            let end_position = offset_to_position(
+                                                                offset
+                                                                    + start
+                                                                    + labeled_span.len() as u32,
+                                                                source_text,
+                                                            );
+                                                            let message =
+                                                                labeled_span.label().map(|label| {
+                                                                    Cow::Owned(label.to_string())
+                                                                });
+
+                                                            SpanPositionMessage::new(
+                                                                start_position,
+                                                                end_position,
+                                                            )
+                                                            .with_message(message)
+                                                        })
+                                                        .collect::<Vec<_>>()
+                                                });
+
+                                            MessageWithPosition {
+                                                message: message.error.message.clone(),
+                                                severity: message.error.severity,
+                                                help: message.error.help.clone(),
+                                                url: message.error.url.clone(),
+                                                code: message.error.code.clone(),
+                                                labels: labels.clone(),
+                                                fix: message.fix.map(|fix| FixWithPosition {
+                                                    content: fix.content,
+                                                    span: SpanPositionMessage::new(
+                                                        offset_to_position(
+                                                            fix.span.start,
+                                                            source_text,
+                                                        ),
+                                                        offset_to_position(
+                                                            fix.span.end,
+                                                            source_text,
+                                                        ),
                                                     )
-                                                    .with_message(message)
-                                                })
-                                                .collect::<Vec<_>>()
-                                        });
-
-                                        MessageWithPosition {
-                                            message: message.error.message.clone(),
-                                            severity: message.error.severity,
-                                            help: message.error.help.clone(),
-                                            url: message.error.url.clone(),
-                                            code: message.error.code.clone(),
-                                            labels: labels.clone(),
-                                            fix: message.fix.map(|fix| FixWithPosition {
-                                                content: fix.content,
-                                                span: SpanPositionMessage::new(
-                                                    offset_to_position(fix.span.start, source_text),
-                                                    offset_to_position(fix.span.end, source_text),
-                                                )
-                                                .with_message(
-                                                    fix.message
-                                                        .as_ref()
-                                                        .map(|label| Cow::Owned(label.to_string())),
-                                                ),
-                                            }),
-                                        }
-                                    },
-                                ));
+                                                    .with_message(
+                                                        fix.message.as_ref().map(|label| {
+                                                            Cow::Owned(label.to_string())
+                                                        }),
+                                                    ),
+                                                }),
+                                            }
+                                        },
+                                    ));
+                                }
                             }
                         }
                     }
@@ -664,22 +934,25 @@ impl Runtime {
             self.resolve_modules(scope, check_syntax_errors, tx_error, |me, mut module| {
                 module.content.with_dependent_mut(|_owner, dependent| {
                     assert_eq!(module.section_module_records.len(), dependent.len());
-                    for (record_result, section) in
+                    for (record_result, mut section) in
                         module.section_module_records.into_iter().zip(dependent.drain(..))
                     {
-                        messages.lock().unwrap().extend(
-                            match record_result {
-                                Ok(module_record) => me.linter.run(
+                        let section_messages = match record_result {
+                            Ok(module_record) => {
+                                let (mut section_messages, template_messages) = me.lint_section(
                                     Path::new(&module.path),
-                                    Rc::new(section.semantic.unwrap()),
-                                    Arc::clone(&module_record),
-                                ),
-                                Err(errors) => {
-                                    errors.into_iter().map(|err| Message::new(err, None)).collect()
-                                }
+                                    &module_record,
+                                    &mut section,
+                                );
+                                section_messages.extend(template_messages);
+                                section_messages
                             }
-                            .into_iter()
-                            .map(|message| message.clone_in(allocator)),
+                            Err(errors) => {
+                                errors.into_iter().map(|err| Message::new(err, None)).collect()
+                            }
+                        };
+                        messages.lock().unwrap().extend(
+                            section_messages.iter().map(|message| message.clone_in(allocator)),
                         );
                     }
                 });
@@ -688,6 +961,65 @@ impl Runtime {
         messages.into_inner().unwrap()
     }
 
+    /// Lint a section which was parsed successfully. Returns the messages of the section, and the
+    /// messages of the expressions of its template, with labels at offsets in the whole file.
+    fn lint_section<'a>(
+        &self,
+        path: &Path,
+        module_record: &Arc<ModuleRecord>,
+        section: &mut SectionContent<'a>,
+    ) -> (Vec<Message<'a>>, Vec<Message<'a>>) {
+        let semantic = Rc::new(section.semantic.take().unwrap());
+        let Some(template) = &section.template else {
+            return (self.linter.run(path, semantic, Arc::clone(module_record)), vec![]);
+        };
+        let template_span = template.span(section.source.source_text);
+        let messages = self.linter.run_with_template(
+            path,
+            semantic,
+            Arc::clone(module_record),
+            Some(template_span),
+        );
+
+        // Fixes are only applied to the section, whose text is a slice of the file.
+        #[expect(clippy::cast_possible_truncation)]
+        let section_end = section.source.source_text.len() as u32;
+        let (mut section_messages, template_messages): (Vec<_>, Vec<_>) =
+            messages.into_iter().partition(|message| message.span().end <= section_end);
+        for message in &mut section_messages {
+            message.fix = message.fix.take().filter(|fix| fix.span.end <= section_end);
+        }
+        let template_messages = template_messages
+            .into_iter()
+            .filter_map(|message| {
+                let mut error = message.error;
+                let labels = error
+                    .labels
+                    .take()?
+                    .into_iter()
+                    .filter_map(|label| {
+                        #[expect(clippy::cast_possible_truncation)]
+                        let span = Span::sized(label.offset() as u32, label.len() as u32);
+                        let span = template.original_span(&section.source, span)?;
+                        let text = label.label().map(ToString::to_string);
+                        let span = (span.start as usize, span.size() as usize);
+                        Some(if label.primary() {
+                            LabeledSpan::new_primary_with_span(text, span)
+                        } else {
+                            LabeledSpan::new_with_span(text, span)
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                if labels.is_empty() {
+                    return None;
+                }
+                error.labels = Some(labels);
+                Some(Message::new(error, None))
+            })
+            .collect();
+        (section_messages, template_messages)
+    }
+
     fn process_path(
         &self,
         path: Arc<OsStr>,
@@ -709,6 +1041,7 @@ impl Runtime {
                 return ModuleProcessOutput { path, processed_module: ProcessedModule::default() };
             }
         };
+        let source_hash = self.cache.as_ref().map(|_| hash_source(&source_text));
         let mut records = SmallVec::<[Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1]>::new();
         let mut module_content: Option<ModuleContent> = None;
         let allocator = Allocator::default();
@@ -739,6 +1072,19 @@ impl Runtime {
             );
         }
 
+        if let (Some(cache), Some(source_hash), Some(key)) =
+            (&self.cache, source_hash, path.to_str())
+        {
+            let dependencies = records
+                .iter()
+                .filter_map(|record| record.as_ref().ok())
+                .flat_map(|record| &record.resolved_module_requests)
+                .filter_map(|request| request.resolved_requested_path.to_str())
+                .map(ToString::to_string)
+                .collect();
+            cache.insert(key, source_hash, dependencies);
+        }
+
         ModuleProcessOutput {
             path,
             processed_module: ProcessedModule {
@@ -761,31 +1107,65 @@ impl Runtime {
     ) -> SmallVec<[Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1]> {
         let section_sources = PartialLoader::parse(ext, source_text)
             .unwrap_or_else(|| vec![JavaScriptSource::partial(source_text, source_type, 0)]);
+        // Templates are only needed to lint, not for the module records of dependencies.
+        let mut template = out_sections
+            .is_some()
+            .then(|| PartialLoader::parse_template(ext, source_text, &section_sources))
+            .flatten();
 
         let mut section_module_records = SmallVec::<
             [Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1],
         >::with_capacity(section_sources.len());
-        for section_source in section_sources {
-            match self.process_source_section(
-                path,
-                allocator,
-                section_source.source_text,
-                section_source.source_type,
-                check_syntax_errors,
-            ) {
+        for (index, section_source) in section_sources.into_iter().enumerate() {
+            // Parse the section together with the template whose scope it shares. If they cannot
+            // be parsed together, e.g. because of unsupported template syntax, only the section
+            // is linted.
+            let with_template =
+                template.take_if(|template| template.section == index).and_then(|template| {
+                    let source_text =
+                        allocator.alloc_str(&template.source_text(section_source.source_text));
+                    self.process_source_section(
+                        path,
+                        allocator,
+                        source_text,
+                        template.source_type(section_source.source_type),
+                        check_syntax_errors,
+                    )
+                    .ok()
+                    .map(|result| (result, template))
+                });
+            let (result, template) = match with_template {
+                Some((result, template)) => (Ok(result), Some(template)),
+                None => (
+                    self.process_source_section(
+                        path,
+                        allocator,
+                        section_source.source_text,
+                        section_source.source_type,
+                        check_syntax_errors,
+                    ),
+                    None,
+                ),
+            };
+            match result {
                 Ok((record, semantic)) => {
                     section_module_records.push(Ok(record));
                     if let Some(sections) = &mut out_sections {
                         sections.push(SectionContent {
                             source: section_source,
                             semantic: Some(semantic),
+                            template,
                         });
                     }
                 }
                 Err(err) => {
                     section_module_records.push(Err(err));
                     if let Some(sections) = &mut out_sections {
-                        sections.push(SectionContent { source: section_source, semantic: None });
+                        sections.push(SectionContent {
+                            source: section_source,
+                            semantic: None,
+                            template: None,
+                        });
                     }
                 }
             }
@@ -0,0 +1,584 @@
+//! Type information for type-aware lint rules.
+//!
+//! Types are inferred from a single file: literals, declared type annotations, function
+//! signatures, and a handful of well-known globals such as `Promise`. There is no module
+//! resolution and no control flow narrowing, so anything that can't be determined from the file
+//! alone is [`TypeFlags::UNKNOWN`]. Rules must treat an unknown type as "could be anything" and
+//! only report on types that are known for certain.
+//!
+//! Type information is only available when type-aware linting is enabled, see
+//! [`LintContext::type_checker`](crate::LintContext::type_checker).
+
+use bitflags::bitflags;
+
+use oxc_ast::{AstKind, ast::*};
+use oxc_semantic::{NodeId, Semantic, SymbolId};
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UnaryOperator};
+
+bitflags! {
+    /// The set of types a value may have at runtime.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct TypeFlags: u16 {
+        const STRING = 1 << 0;
+        const NUMBER = 1 << 1;
+        const BOOLEAN = 1 << 2;
+        const BIGINT = 1 << 3;
+        const SYMBOL = 1 << 4;
+        const NULL = 1 << 5;
+        /// `undefined` or `void`
+        const UNDEFINED = 1 << 6;
+        /// An object which is known not to be a thenable.
+        const OBJECT = 1 << 7;
+        /// A function, see [`Type::returns`] for its return type.
+        const FUNCTION = 1 << 8;
+        /// A `Promise` or any other thenable.
+        const PROMISE = 1 << 9;
+        /// `any`
+        const ANY = 1 << 10;
+        /// `unknown`, or a type that could not be inferred.
+        const UNKNOWN = 1 << 11;
+
+        const NULLISH = Self::NULL.bits() | Self::UNDEFINED.bits();
+    }
+}
+
+/// Inferred type of an expression or a declaration.
+///
+/// Empty flags are the `never` type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Type {
+    pub flags: TypeFlags,
+    /// Return type of the function, if [`TypeFlags::FUNCTION`] is set.
+    pub returns: TypeFlags,
+}
+
+impl Type {
+    pub const UNKNOWN: Self = Self::new(TypeFlags::UNKNOWN);
+
+    pub const fn new(flags: TypeFlags) -> Self {
+        Self { flags, returns: TypeFlags::UNKNOWN }
+    }
+
+    pub const fn function(returns: TypeFlags) -> Self {
+        Self { flags: TypeFlags::FUNCTION, returns }
+    }
+
+    /// The type is neither `any` nor `unknown`.
+    pub fn is_known(self) -> bool {
+        !self.flags.intersects(TypeFlags::ANY | TypeFlags::UNKNOWN)
+    }
+
+    /// The value is a thenable, or may be one.
+    pub fn is_thenable(self) -> bool {
+        self.flags.contains(TypeFlags::PROMISE)
+    }
+
+    /// The value is a function which returns a thenable.
+    pub fn returns_thenable(self) -> bool {
+        self.flags.contains(TypeFlags::FUNCTION) && self.returns.contains(TypeFlags::PROMISE)
+    }
+
+    /// The value is a function which is known to return `void`.
+    pub fn returns_void(self) -> bool {
+        self.flags == TypeFlags::FUNCTION && self.returns == TypeFlags::UNDEFINED
+    }
+
+    #[must_use]
+    fn union(self, other: Self) -> Self {
+        Self { flags: self.flags | other.flags, returns: self.returns | other.returns }
+    }
+}
+
+/// Declarations are followed up to this depth, e.g. `const a = b; const b = c;`.
+const MAX_DEPTH: u8 = 8;
+
+/// Infers types from the [`Semantic`] of a file.
+#[derive(Clone, Copy)]
+pub struct TypeChecker<'s, 'a> {
+    semantic: &'s Semantic<'a>,
+}
+
+impl<'s, 'a> TypeChecker<'s, 'a> {
+    pub fn new(semantic: &'s Semantic<'a>) -> Self {
+        Self { semantic }
+    }
+
+    /// Infer the type of an expression.
+    pub fn expression_type(self, expr: &Expression<'a>) -> Type {
+        self.infer_expression(expr, 0)
+    }
+
+    /// Get the type annotation an expression was declared with.
+    ///
+    /// Returns the annotation of a variable or a parameter for an identifier, and the return type
+    /// annotation for a call to a function declared in this file. Returns [`None`] if the
+    /// expression's type is not exactly the annotated type.
+    pub fn declared_type_annotation(self, expr: &Expression<'a>) -> Option<&'a TSType<'a>> {
+        match expr.without_parentheses() {
+            Expression::Identifier(ident) => {
+                match self.semantic.nodes().kind(self.symbol_declaration(ident)?) {
+                    AstKind::VariableDeclarator(decl) if decl.id.kind.is_binding_identifier() => {
+                        decl.id.type_annotation.as_ref().map(|ann| &ann.type_annotation)
+                    }
+                    AstKind::FormalParameter(param) if !param.pattern.optional => {
+                        param.pattern.type_annotation.as_ref().map(|ann| &ann.type_annotation)
+                    }
+                    _ => None,
+                }
+            }
+            Expression::CallExpression(call) if !call.optional => {
+                let Expression::Identifier(ident) = call.callee.without_parentheses() else {
+                    return None;
+                };
+                let AstKind::Function(func) =
+                    self.semantic.nodes().kind(self.symbol_declaration(ident)?)
+                else {
+                    return None;
+                };
+                // Generic functions return a different type for every call.
+                if func.type_parameters.is_some() {
+                    return None;
+                }
+                func.return_type.as_ref().map(|ann| &ann.type_annotation)
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the parameters of the function called by `callee`, if it is declared in this file.
+    pub fn callee_parameters(self, callee: &Expression<'a>) -> Option<&'a FormalParameters<'a>> {
+        let Expression::Identifier(ident) = callee.without_parentheses() else { return None };
+        match self.semantic.nodes().kind(self.symbol_declaration(ident)?) {
+            AstKind::Function(func) => Some(&func.params),
+            AstKind::VariableDeclarator(decl) if decl.kind.is_const() => {
+                match decl.init.as_ref()?.without_parentheses() {
+                    Expression::ArrowFunctionExpression(arrow) => Some(&arrow.params),
+                    Expression::FunctionExpression(func) => Some(&func.params),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Infer the type of a type annotation.
+    pub fn annotation_type(self, ty: &TSType<'a>) -> Type {
+        self.infer_annotation(ty, 0)
+    }
+
+    /// Resolve the declaration of the symbol referenced by an identifier.
+    ///
+    /// Returns [`None`] for globals, and for symbols with multiple declarations
+    /// (e.g. function overloads or declaration merging).
+    fn symbol_declaration(self, ident: &IdentifierReference<'a>) -> Option<NodeId> {
+        let symbol_id = self.resolve(ident)?;
+        let scoping = self.semantic.scoping();
+        if !scoping.symbol_redeclarations(symbol_id).is_empty() {
+            return None;
+        }
+        Some(scoping.symbol_declaration(symbol_id))
+    }
+
+    fn resolve(self, ident: &IdentifierReference<'a>) -> Option<SymbolId> {
+        let reference_id = ident.reference_id.get()?;
+        self.semantic.scoping().get_reference(reference_id).symbol_id()
+    }
+
+    fn is_global(self, ident: &IdentifierReference<'a>, name: &str) -> bool {
+        ident.name == name && ident.reference_id.get().is_some() && self.resolve(ident).is_none()
+    }
+
+    fn infer_expression(self, expr: &Expression<'a>, depth: u8) -> Type {
+        if depth > MAX_DEPTH {
+            return Type::UNKNOWN;
+        }
+        let depth = depth + 1;
+        match expr {
+            Expression::BooleanLiteral(_) => Type::new(TypeFlags::BOOLEAN),
+            Expression::NullLiteral(_) => Type::new(TypeFlags::NULL),
+            Expression::NumericLiteral(_) => Type::new(TypeFlags::NUMBER),
+            Expression::BigIntLiteral(_) => Type::new(TypeFlags::BIGINT),
+            Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => {
+                Type::new(TypeFlags::STRING)
+            }
+            Expression::RegExpLiteral(_)
+            | Expression::ArrayExpression(_)
+            | Expression::JSXElement(_)
+            | Expression::JSXFragment(_) => Type::new(TypeFlags::OBJECT),
+            Expression::ObjectExpression(object) => {
+                let mut is_thenable = false;
+                for prop in &object.properties {
+                    match prop {
+                        // Properties of spread objects and computed keys are not known
+                        ObjectPropertyKind::SpreadProperty(_) => return Type::UNKNOWN,
+                        ObjectPropertyKind::ObjectProperty(prop) if prop.computed => {
+                            return Type::UNKNOWN;
+                        }
+                        ObjectPropertyKind::ObjectProperty(prop) => {
+                            is_thenable |= prop.key.is_specific_static_name("then");
+                        }
+                    }
+                }
+                Self::object_type(is_thenable)
+            }
+            Expression::Identifier(ident) => self.identifier_type(ident, depth),
+            Expression::FunctionExpression(func) => self.function_type(func, depth),
+            Expression::ArrowFunctionExpression(arrow) => self.arrow_function_type(arrow, depth),
+            Expression::ClassExpression(class) => Type::function(Self::instance_type(class)),
+            Expression::ImportExpression(_) => Type::new(TypeFlags::PROMISE),
+            Expression::NewExpression(new_expr) => {
+                let callee = new_expr.callee.without_parentheses();
+                if let Expression::Identifier(ident) = callee {
+                    if self.is_global(ident, "Promise") {
+                        return Type::new(TypeFlags::PROMISE);
+                    }
+                }
+                let callee = self.infer_expression(callee, depth);
+                if callee.flags == TypeFlags::FUNCTION {
+                    Type::new(callee.returns)
+                } else {
+                    Type::UNKNOWN
+                }
+            }
+            Expression::CallExpression(call) => {
+                let ty = self.call_type(call, depth);
+                if call.optional { ty.union(Type::new(TypeFlags::UNDEFINED)) } else { ty }
+            }
+            Expression::ChainExpression(chain) => match &chain.expression {
+                ChainElement::CallExpression(call) => {
+                    self.call_type(call, depth).union(Type::new(TypeFlags::UNDEFINED))
+                }
+                ChainElement::TSNonNullExpression(non_null) => {
+                    self.non_null_type(&non_null.expression, depth)
+                }
+                _ => Type::UNKNOWN,
+            },
+            Expression::AwaitExpression(await_expr) => {
+                let argument = self.infer_expression(&await_expr.argument, depth);
+                if argument.is_known() && !argument.is_thenable() {
+                    argument
+                } else {
+                    Type::UNKNOWN
+                }
+            }
+            Expression::ParenthesizedExpression(paren) => {
+                self.infer_expression(&paren.expression, depth)
+            }
+            Expression::TSAsExpression(as_expr) => {
+                if is_const_assertion(&as_expr.type_annotation) {
+                    self.infer_expression(&as_expr.expression, depth)
+                } else {
+                    self.infer_annotation(&as_expr.type_annotation, depth)
+                }
+            }
+            Expression::TSTypeAssertion(assertion) => {
+                if is_const_assertion(&assertion.type_annotation) {
+                    self.infer_expression(&assertion.expression, depth)
+                } else {
+                    self.infer_annotation(&assertion.type_annotation, depth)
+                }
+            }
+            Expression::TSSatisfiesExpression(satisfies) => {
+                self.infer_expression(&satisfies.expression, depth)
+            }
+            Expression::TSNonNullExpression(non_null) => {
+                self.non_null_type(&non_null.expression, depth)
+            }
+            Expression::SequenceExpression(sequence) => sequence
+                .expressions
+                .last()
+                .map_or(Type::UNKNOWN, |expr| self.infer_expression(expr, depth)),
+            Expression::AssignmentExpression(assign) if assign.operator.is_assign() => {
+                self.infer_expression(&assign.right, depth)
+            }
+            Expression::ConditionalExpression(cond) => self
+                .infer_expression(&cond.consequent, depth)
+                .union(self.infer_expression(&cond.alternate, depth)),
+            Expression::LogicalExpression(logical) => {
+                let left = self.infer_expression(&logical.left, depth);
+                let right = self.infer_expression(&logical.right, depth);
+                if logical.operator == LogicalOperator::Coalesce {
+                    Type { flags: left.flags - TypeFlags::NULLISH, returns: left.returns }
+                        .union(right)
+                } else {
+                    left.union(right)
+                }
+            }
+            Expression::UnaryExpression(unary) => match unary.operator {
+                UnaryOperator::Void => Type::new(TypeFlags::UNDEFINED),
+                UnaryOperator::Typeof => Type::new(TypeFlags::STRING),
+                UnaryOperator::LogicalNot | UnaryOperator::Delete => Type::new(TypeFlags::BOOLEAN),
+                UnaryOperator::UnaryPlus => Type::new(TypeFlags::NUMBER),
+                UnaryOperator::UnaryNegation | UnaryOperator::BitwiseNot => {
+                    Type::new(TypeFlags::NUMBER | TypeFlags::BIGINT)
+                }
+            },
+            Expression::UpdateExpression(_) => Type::new(TypeFlags::NUMBER | TypeFlags::BIGINT),
+            Expression::BinaryExpression(binary) => {
+                if binary.operator.is_equality()
+                    || binary.operator.is_compare()
+                    || matches!(binary.operator, BinaryOperator::In | BinaryOperator::Instanceof)
+                {
+                    Type::new(TypeFlags::BOOLEAN)
+                } else if binary.operator == BinaryOperator::Addition {
+                    Type::new(TypeFlags::STRING | TypeFlags::NUMBER | TypeFlags::BIGINT)
+                } else {
+                    Type::new(TypeFlags::NUMBER | TypeFlags::BIGINT)
+                }
+            }
+            _ => Type::UNKNOWN,
+        }
+    }
+
+    fn non_null_type(self, expr: &Expression<'a>, depth: u8) -> Type {
+        let ty = self.infer_expression(expr, depth);
+        Type { flags: ty.flags - TypeFlags::NULLISH, returns: ty.returns }
+    }
+
+    fn call_type(self, call: &CallExpression<'a>, depth: u8) -> Type {
+        let callee = call.callee.without_parentheses();
+        if let Some(member) = callee.get_member_expr() {
+            let object = member.object().without_parentheses();
+            match member.static_property_name() {
+                // `Promise.resolve()`, `Promise.all()`, ...
+                Some("resolve" | "reject" | "all" | "allSettled" | "any" | "race" | "try") if matches!(object, Expression::Identifier(ident) if self.is_global(ident, "Promise")) =>
+                {
+                    return Type::new(TypeFlags::PROMISE);
+                }
+                // `promise.then()`, `promise.catch()`, `promise.finally()`
+                Some("then" | "catch" | "finally")
+                    if self.infer_expression(object, depth).is_thenable() =>
+                {
+                    return Type::new(TypeFlags::PROMISE);
+                }
+                _ => return Type::UNKNOWN,
+            }
+        }
+        let callee = self.infer_expression(callee, depth);
+        if callee.flags == TypeFlags::FUNCTION { Type::new(callee.returns) } else { Type::UNKNOWN }
+    }
+
+    fn identifier_type(self, ident: &IdentifierReference<'a>, depth: u8) -> Type {
+        let Some(symbol_id) = self.resolve(ident) else {
+            return if ident.name == "undefined" {
+                Type::new(TypeFlags::UNDEFINED)
+            } else {
+                Type::UNKNOWN
+            };
+        };
+        let scoping = self.semantic.scoping();
+        if !scoping.symbol_redeclarations(symbol_id).is_empty() {
+            return Type::UNKNOWN;
+        }
+        match self.semantic.nodes().kind(scoping.symbol_declaration(symbol_id)) {
+            AstKind::VariableDeclarator(decl) => {
+                if !decl.id.kind.is_binding_identifier() {
+                    return Type::UNKNOWN;
+                }
+                if let Some(annotation) = &decl.id.type_annotation {
+                    return self.infer_annotation(&annotation.type_annotation, depth);
+                }
+                match &decl.init {
+                    // Without an annotation, the type of a reassigned variable depends on control flow.
+                    Some(init) if decl.kind.is_const() || !scoping.symbol_is_mutated(symbol_id) => {
+                        self.infer_expression(init, depth)
+                    }
+                    _ => Type::UNKNOWN,
+                }
+            }
+            AstKind::FormalParameter(param) => {
+                let Some(annotation) = &param.pattern.type_annotation else {
+                    return Type::UNKNOWN;
+                };
+                let ty = self.infer_annotation(&annotation.type_annotation, depth);
+                if param.pattern.optional { ty.union(Type::new(TypeFlags::UNDEFINED)) } else { ty }
+            }
+            AstKind::Function(func) => self.function_type(func, depth),
+            AstKind::Class(class) => Type::function(Self::instance_type(class)),
+            AstKind::TSEnumDeclaration(_) => Type::new(TypeFlags::OBJECT),
+            _ => Type::UNKNOWN,
+        }
+    }
+
+    fn function_type(self, func: &Function<'a>, depth: u8) -> Type {
+        let returns = if let Some(annotation) = &func.return_type {
+            self.infer_annotation(&annotation.type_annotation, depth).flags
+        } else if func.generator {
+            TypeFlags::OBJECT
+        } else if func.r#async {
+            TypeFlags::PROMISE
+        } else {
+            TypeFlags::UNKNOWN
+        };
+        Type::function(returns)
+    }
+
+    fn arrow_function_type(self, arrow: &ArrowFunctionExpression<'a>, depth: u8) -> Type {
+        let returns = if let Some(annotation) = &arrow.return_type {
+            self.infer_annotation(&annotation.type_annotation, depth).flags
+        } else if arrow.r#async {
+            TypeFlags::PROMISE
+        } else if let Some(expr) = arrow.get_expression() {
+            self.infer_expression(expr, depth).flags
+        } else {
+            TypeFlags::UNKNOWN
+        };
+        Type::function(returns)
+    }
+
+    fn infer_annotation(self, ty: &TSType<'a>, depth: u8) -> Type {
+        if depth > MAX_DEPTH {
+            return Type::UNKNOWN;
+        }
+        let depth = depth + 1;
+        match ty {
+            TSType::TSAnyKeyword(_) => Type::new(TypeFlags::ANY),
+            TSType::TSStringKeyword(_) | TSType::TSTemplateLiteralType(_) => {
+                Type::new(TypeFlags::STRING)
+            }
+            TSType::TSNumberKeyword(_) => Type::new(TypeFlags::NUMBER),
+            TSType::TSBooleanKeyword(_) => Type::new(TypeFlags::BOOLEAN),
+            TSType::TSBigIntKeyword(_) => Type::new(TypeFlags::BIGINT),
+            TSType::TSSymbolKeyword(_) => Type::new(TypeFlags::SYMBOL),
+            TSType::TSNullKeyword(_) => Type::new(TypeFlags::NULL),
+            TSType::TSUndefinedKeyword(_) | TSType::TSVoidKeyword(_) => {
+                Type::new(TypeFlags::UNDEFINED)
+            }
+            TSType::TSNeverKeyword(_) => Type::new(TypeFlags::empty()),
+            TSType::TSLiteralType(literal) => Type::new(match &literal.literal {
+                TSLiteral::BooleanLiteral(_) => TypeFlags::BOOLEAN,
+                TSLiteral::NumericLiteral(_) | TSLiteral::UnaryExpression(_) => TypeFlags::NUMBER,
+                TSLiteral::BigIntLiteral(_) => TypeFlags::BIGINT,
+                TSLiteral::StringLiteral(_) | TSLiteral::TemplateLiteral(_) => TypeFlags::STRING,
+            }),
+            TSType::TSArrayType(_) | TSType::TSTupleType(_) => Type::new(TypeFlags::OBJECT),
+            TSType::TSFunctionType(func) => Type::function(
+                self.infer_annotation(&func.return_type.type_annotation, depth).flags,
+            ),
+            TSType::TSConstructorType(ctor) => Type::function(
+                self.infer_annotation(&ctor.return_type.type_annotation, depth).flags,
+            ),
+            TSType::TSTypePredicate(predicate) => {
+                Type::new(if predicate.asserts { TypeFlags::UNDEFINED } else { TypeFlags::BOOLEAN })
+            }
+            TSType::TSUnionType(union) => union
+                .types
+                .iter()
+                .map(|ty| self.infer_annotation(ty, depth))
+                .reduce(Type::union)
+                .unwrap_or(Type::UNKNOWN),
+            TSType::TSParenthesizedType(paren) => {
+                self.infer_annotation(&paren.type_annotation, depth)
+            }
+            TSType::TSTypeOperatorType(operator) => match operator.operator {
+                TSTypeOperatorOperator::Readonly => {
+                    self.infer_annotation(&operator.type_annotation, depth)
+                }
+                TSTypeOperatorOperator::Keyof => {
+                    Type::new(TypeFlags::STRING | TypeFlags::NUMBER | TypeFlags::SYMBOL)
+                }
+                TSTypeOperatorOperator::Unique => Type::new(TypeFlags::SYMBOL),
+            },
+            TSType::TSTypeLiteral(literal) => {
+                if literal.members.iter().any(|member| {
+                    matches!(
+                        member,
+                        TSSignature::TSCallSignatureDeclaration(_)
+                            | TSSignature::TSConstructSignatureDeclaration(_)
+                    )
+                }) {
+                    Type::UNKNOWN
+                } else {
+                    Self::object_type(has_then_signature(&literal.members))
+                }
+            }
+            TSType::TSTypeQuery(query) => match &query.expr_name {
+                TSTypeQueryExprName::IdentifierReference(ident) => {
+                    self.identifier_type(ident, depth)
+                }
+                _ => Type::UNKNOWN,
+            },
+            TSType::TSTypeReference(reference) => self.type_reference_type(reference, depth),
+            _ => Type::UNKNOWN,
+        }
+    }
+
+    fn type_reference_type(self, reference: &TSTypeReference<'a>, depth: u8) -> Type {
+        let TSTypeName::IdentifierReference(ident) = &reference.type_name else {
+            return Type::UNKNOWN;
+        };
+        let Some(symbol_id) = self.resolve(ident) else {
+            return match ident.name.as_str() {
+                "Promise" | "PromiseLike" => Type::new(TypeFlags::PROMISE),
+                "Array" | "ReadonlyArray" | "Map" | "ReadonlyMap" | "Set" | "ReadonlySet"
+                | "WeakMap" | "WeakSet" | "Date" | "RegExp" | "Error" | "Record" => {
+                    Type::new(TypeFlags::OBJECT)
+                }
+                _ => Type::UNKNOWN,
+            };
+        };
+        let scoping = self.semantic.scoping();
+        if !scoping.symbol_redeclarations(symbol_id).is_empty() {
+            return Type::UNKNOWN;
+        }
+        match self.semantic.nodes().kind(scoping.symbol_declaration(symbol_id)) {
+            AstKind::TSTypeAliasDeclaration(alias) => {
+                self.infer_annotation(&alias.type_annotation, depth)
+            }
+            AstKind::TSInterfaceDeclaration(interface) => {
+                if has_then_signature(&interface.body.body) {
+                    Type::new(TypeFlags::PROMISE)
+                } else if interface.extends.as_ref().is_some_and(|extends| !extends.is_empty())
+                    || interface
+                        .body
+                        .body
+                        .iter()
+                        .any(|member| matches!(member, TSSignature::TSCallSignatureDeclaration(_)))
+                {
+                    Type::UNKNOWN
+                } else {
+                    Type::new(TypeFlags::OBJECT)
+                }
+            }
+            AstKind::Class(class) => Type::new(Self::instance_type(class)),
+            AstKind::TSEnumDeclaration(_) => Type::new(TypeFlags::NUMBER | TypeFlags::STRING),
+            _ => Type::UNKNOWN,
+        }
+    }
+
+    /// Type of an instance of `class`.
+    fn instance_type(class: &Class<'a>) -> TypeFlags {
+        if class.super_class.is_some() {
+            return TypeFlags::UNKNOWN;
+        }
+        let is_thenable = class.body.body.iter().any(|element| match element {
+            ClassElement::MethodDefinition(method) => {
+                !method.r#static && method.key.is_specific_static_name("then")
+            }
+            ClassElement::PropertyDefinition(prop) => {
+                !prop.r#static && prop.key.is_specific_static_name("then")
+            }
+            ClassElement::AccessorProperty(prop) => {
+                !prop.r#static && prop.key.is_specific_static_name("then")
+            }
+            _ => false,
+        });
+        Self::object_type(is_thenable).flags
+    }
+
+    fn object_type(is_thenable: bool) -> Type {
+        Type::new(if is_thenable { TypeFlags::PROMISE } else { TypeFlags::OBJECT })
+    }
+}
+
+fn is_const_assertion(ty: &TSType) -> bool {
+    matches!(ty, TSType::TSTypeReference(reference) if reference.type_name.is_const())
+}
+
+fn has_then_signature(members: &[TSSignature]) -> bool {
+    members.iter().any(|member| match member {
+        TSSignature::TSPropertySignature(prop) => prop.key.is_specific_static_name("then"),
+        TSSignature::TSMethodSignature(method) => method.key.is_specific_static_name("then"),
+        _ => false,
+    })
+}
@@ -15,8 +15,10 @@ use oxc_span::Atom;
 
 pub(crate) mod base54;
 mod keep_names;
+mod properties;
 
 pub use keep_names::MangleOptionsKeepNames;
+pub use properties::{MangleOptionsProperties, PropertyMangler, PropertyNameCache};
 
 #[derive(Default, Debug, Clone, Copy)]
 pub struct MangleOptions {
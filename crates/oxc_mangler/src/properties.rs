@@ -0,0 +1,352 @@
+use lazy_regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{Allocator, CloneIn};
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_span::{Atom, CompactStr};
+
+use crate::base54::base54;
+
+#[derive(Debug, Clone, Default)]
+pub struct MangleOptionsProperties {
+    /// Mangle property names matching this pattern, e.g. `^_` or `\$$`.
+    ///
+    /// Private class members (`#name`) are always mangled.
+    ///
+    /// Default: `None`, which only mangles private class members.
+    pub regex: Option<Regex>,
+
+    /// Property names which are never mangled.
+    pub reserved: Vec<String>,
+}
+
+/// Mangled property names.
+///
+/// Property names are shared between bundles which use the same objects. Export the cache
+/// returned by [PropertyMangler::build] and pass it to [PropertyMangler::with_name_cache]
+/// when mangling the next bundle, so the same properties get the same names.
+#[derive(Debug, Clone, Default)]
+pub struct PropertyNameCache {
+    names: FxHashMap<CompactStr, CompactStr>,
+}
+
+impl PropertyNameCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the mangled name of property `name`.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.names.get(name).map(CompactStr::as_str)
+    }
+
+    pub fn insert(&mut self, name: &str, mangled: &str) {
+        self.names.insert(CompactStr::new(name), CompactStr::new(mangled));
+    }
+
+    /// Iterate over original and mangled names.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> + '_ {
+        self.names.iter().map(|(name, mangled)| (name.as_str(), mangled.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+impl<K: AsRef<str>, V: AsRef<str>> FromIterator<(K, V)> for PropertyNameCache {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        Self {
+            names: iter
+                .into_iter()
+                .map(|(name, mangled)| {
+                    (CompactStr::new(name.as_ref()), CompactStr::new(mangled.as_ref()))
+                })
+                .collect(),
+        }
+    }
+}
+
+/// # Property Name Mangler
+///
+/// Renames property names matched by [MangleOptionsProperties::regex] and private class members.
+/// Unlike [crate::Mangler], properties are not bindings in [oxc_semantic::Scoping],
+/// so `program` is modified.
+///
+/// Quoted property names are never mangled: `a._foo` and `{ _foo: 1 }` are mangled, unless
+/// `_foo` is also used quoted anywhere in the program, e.g. in `a["_foo"]` or `{ "_foo": 1 }`,
+/// so that all uses of a property keep the same name.
+/// Mangled names never collide with other property names in the program.
+///
+/// ## Example
+///
+/// ```rust
+/// use lazy_regex::Regex;
+/// use oxc_allocator::Allocator;
+/// use oxc_codegen::Codegen;
+/// use oxc_mangler::{MangleOptionsProperties, PropertyMangler};
+/// use oxc_parser::Parser;
+/// use oxc_span::SourceType;
+///
+/// let allocator = Allocator::default();
+/// let source = "x._foo = 1; x.bar = x._foo;";
+/// let mut program = Parser::new(&allocator, source, SourceType::mjs()).parse().program;
+///
+/// let options =
+///     MangleOptionsProperties { regex: Some(Regex::new("^_").unwrap()), ..Default::default() };
+/// let cache = PropertyMangler::new(options).build(&allocator, &mut program);
+///
+/// assert_eq!(Codegen::new().build(&program).code, "x.e = 1;\nx.bar = x.e;\n");
+/// assert_eq!(cache.get("_foo"), Some("e"));
+/// ```
+#[derive(Default)]
+pub struct PropertyMangler {
+    options: MangleOptionsProperties,
+    cache: PropertyNameCache,
+}
+
+impl PropertyMangler {
+    #[must_use]
+    pub fn new(options: MangleOptionsProperties) -> Self {
+        Self { options, cache: PropertyNameCache::default() }
+    }
+
+    /// Reuse the names of properties mangled in other bundles.
+    #[must_use]
+    pub fn with_name_cache(mut self, cache: PropertyNameCache) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Mangles property names in `program`.
+    ///
+    /// Returns the name cache with the names of all mangled public properties.
+    pub fn build<'a>(
+        mut self,
+        allocator: &'a Allocator,
+        program: &mut Program<'a>,
+    ) -> PropertyNameCache {
+        let reserved = self.options.reserved.iter().map(String::as_str).collect::<FxHashSet<_>>();
+        let mut collector = PropertyCollector {
+            regex: self.options.regex.as_ref(),
+            reserved: &reserved,
+            properties: NameFrequencies::default(),
+            private_names: NameFrequencies::default(),
+            unmangled: FxHashSet::default(),
+        };
+        collector.visit_program(program);
+
+        let ast = AstBuilder::new(allocator);
+
+        // Names which cannot be used because other properties or other bundles use them.
+        let mut used_names = collector.unmangled;
+        used_names.extend(reserved.iter().copied());
+        let cached_names = self.cache.names.values().cloned().collect::<FxHashSet<_>>();
+        let mut count = 0;
+        let mut properties = FxHashMap::default();
+        // Skip names which are also used quoted, e.g. `a["_foo"]`, as those uses can't be renamed.
+        let names = collector.properties.into_sorted();
+        for name in names.into_iter().filter(|name| !used_names.contains(name.as_str())) {
+            let mangled = if let Some(mangled) = self.cache.get(name.as_str()) {
+                ast.atom(mangled)
+            } else {
+                let mangled = loop {
+                    let mangled = base54(count);
+                    count += 1;
+                    let n = mangled.as_str();
+                    if !used_names.contains(n) && !cached_names.contains(n) {
+                        break ast.atom(n);
+                    }
+                };
+                self.cache.insert(name.as_str(), mangled.as_str());
+                mangled
+            };
+            properties.insert(name, mangled);
+        }
+
+        // Private names are only visible in their classes, and never collide with public properties.
+        let private_names = collector
+            .private_names
+            .into_sorted()
+            .into_iter()
+            .zip(0..)
+            .map(|(name, count)| (name, ast.atom(base54(count).as_str())))
+            .collect();
+
+        PropertyRenamer { ast, properties, private_names }.visit_program(program);
+        self.cache
+    }
+}
+
+/// Names are counted in order of their first occurrence, and sorted by frequency,
+/// so that the most frequent names get the shortest mangled names.
+#[derive(Default)]
+struct NameFrequencies<'a> {
+    indexes: FxHashMap<Atom<'a>, usize>,
+    names: Vec<(Atom<'a>, usize)>,
+}
+
+impl<'a> NameFrequencies<'a> {
+    fn add(&mut self, name: Atom<'a>) {
+        let index = *self.indexes.entry(name).or_insert_with(|| {
+            self.names.push((name, 0));
+            self.names.len() - 1
+        });
+        self.names[index].1 += 1;
+    }
+
+    fn into_sorted(mut self) -> Vec<Atom<'a>> {
+        self.names.sort_by_key(|(_, frequency)| std::cmp::Reverse(*frequency));
+        self.names.into_iter().map(|(name, _)| name).collect()
+    }
+}
+
+struct PropertyCollector<'a, 'b> {
+    regex: Option<&'b Regex>,
+    reserved: &'b FxHashSet<&'b str>,
+    properties: NameFrequencies<'a>,
+    private_names: NameFrequencies<'a>,
+    /// Names which are not mangled, including quoted names.
+    unmangled: FxHashSet<&'a str>,
+}
+
+impl<'a> PropertyCollector<'a, '_> {
+    fn add_property(&mut self, name: Atom<'a>) {
+        let name_str = name.as_str();
+        let is_mangled = !is_special_property(name_str)
+            && !self.reserved.contains(name_str)
+            && self.regex.is_some_and(|regex| regex.is_match(name_str));
+        if is_mangled {
+            self.properties.add(name);
+        } else {
+            self.unmangled.insert(name_str);
+        }
+    }
+}
+
+impl<'a> Visit<'a> for PropertyCollector<'a, '_> {
+    fn visit_static_member_expression(&mut self, it: &StaticMemberExpression<'a>) {
+        self.add_property(it.property.name);
+        walk::walk_static_member_expression(self, it);
+    }
+
+    fn visit_computed_member_expression(&mut self, it: &ComputedMemberExpression<'a>) {
+        if let Expression::StringLiteral(lit) = &it.expression {
+            self.unmangled.insert(lit.value.as_str());
+        }
+        walk::walk_computed_member_expression(self, it);
+    }
+
+    fn visit_property_key(&mut self, it: &PropertyKey<'a>) {
+        match it {
+            PropertyKey::StaticIdentifier(ident) => self.add_property(ident.name),
+            PropertyKey::StringLiteral(lit) => {
+                self.unmangled.insert(lit.value.as_str());
+            }
+            _ => {}
+        }
+        walk::walk_property_key(self, it);
+    }
+
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        it: &AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        self.add_property(it.binding.name);
+        walk::walk_assignment_target_property_identifier(self, it);
+    }
+
+    fn visit_private_identifier(&mut self, it: &PrivateIdentifier<'a>) {
+        self.private_names.add(it.name);
+    }
+}
+
+struct PropertyRenamer<'a> {
+    ast: AstBuilder<'a>,
+    properties: FxHashMap<Atom<'a>, Atom<'a>>,
+    private_names: FxHashMap<Atom<'a>, Atom<'a>>,
+}
+
+impl<'a> PropertyRenamer<'a> {
+    fn rename(&self, name: &mut Atom<'a>) {
+        if let Some(mangled) = self.properties.get(name) {
+            *name = *mangled;
+        }
+    }
+
+    fn is_mangled_key(&self, key: &PropertyKey<'a>) -> bool {
+        matches!(key, PropertyKey::StaticIdentifier(ident) if self.properties.contains_key(&ident.name))
+    }
+}
+
+impl<'a> VisitMut<'a> for PropertyRenamer<'a> {
+    fn visit_static_member_expression(&mut self, it: &mut StaticMemberExpression<'a>) {
+        self.rename(&mut it.property.name);
+        walk_mut::walk_static_member_expression(self, it);
+    }
+
+    fn visit_property_key(&mut self, it: &mut PropertyKey<'a>) {
+        if let PropertyKey::StaticIdentifier(ident) = it {
+            self.rename(&mut ident.name);
+        }
+        walk_mut::walk_property_key(self, it);
+    }
+
+    fn visit_object_property(&mut self, it: &mut ObjectProperty<'a>) {
+        // `{ _foo }` => `{ a: _foo }`
+        if it.shorthand && self.is_mangled_key(&it.key) {
+            it.shorthand = false;
+        }
+        walk_mut::walk_object_property(self, it);
+    }
+
+    fn visit_binding_property(&mut self, it: &mut BindingProperty<'a>) {
+        // `let { _foo } = x` => `let { a: _foo } = x`
+        if it.shorthand && self.is_mangled_key(&it.key) {
+            it.shorthand = false;
+        }
+        walk_mut::walk_binding_property(self, it);
+    }
+
+    fn visit_assignment_target_property(&mut self, it: &mut AssignmentTargetProperty<'a>) {
+        // `({ _foo = 1 } = x)` => `({ a: _foo = 1 } = x)`
+        if let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(prop) = it {
+            if let Some(mangled) = self.properties.get(&prop.binding.name) {
+                let span = prop.span;
+                let key = self.ast.property_key_static_identifier(prop.binding.span, *mangled);
+                // Keep the `ReferenceId` for symbols mangled by `Mangler`.
+                let target = AssignmentTarget::AssignmentTargetIdentifier(
+                    self.ast.alloc(prop.binding.clone_in_with_semantic_ids(self.ast.allocator)),
+                );
+                let binding = match prop.init.take() {
+                    Some(init) => {
+                        self.ast.assignment_target_maybe_default_assignment_target_with_default(
+                            span, target, init,
+                        )
+                    }
+                    None => AssignmentTargetMaybeDefault::from(target),
+                };
+                *it = self.ast.assignment_target_property_assignment_target_property_property(
+                    span, key, binding, false,
+                );
+            }
+        }
+        walk_mut::walk_assignment_target_property(self, it);
+    }
+
+    fn visit_private_identifier(&mut self, it: &mut PrivateIdentifier<'a>) {
+        if let Some(mangled) = self.private_names.get(&it.name) {
+            it.name = *mangled;
+        }
+    }
+}
+
+/// Properties with special meanings in object literals and classes.
+fn is_special_property(name: &str) -> bool {
+    matches!(name, "constructor" | "prototype" | "__proto__")
+}
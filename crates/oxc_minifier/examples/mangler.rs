@@ -1,9 +1,12 @@
 #![expect(clippy::print_stdout)]
 use std::path::Path;
 
+use lazy_regex::Regex;
 use oxc_allocator::Allocator;
 use oxc_codegen::CodeGenerator;
-use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, Mangler};
+use oxc_mangler::{
+    MangleOptions, MangleOptionsKeepNames, MangleOptionsProperties, Mangler, PropertyMangler,
+};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 use pico_args::Arguments;
@@ -11,6 +14,7 @@ use pico_args::Arguments;
 // Instruction:
 // create a `test.js`,
 // run `cargo run -p oxc_minifier --example mangler`
+// or `cargo run -p oxc_minifier --example mangler -- --mangle-props '^_'` to also mangle properties
 
 fn main() -> std::io::Result<()> {
     let mut args = Arguments::from_env();
@@ -18,6 +22,7 @@ fn main() -> std::io::Result<()> {
     let keep_names = args.contains("--keep-names");
     let debug = args.contains("--debug");
     let twice = args.contains("--twice");
+    let mangle_props: Option<String> = args.opt_value_from_str("--mangle-props").unwrap();
     let name = args.free_from_str().unwrap_or_else(|_| "test.js".to_string());
 
     let path = Path::new(&name);
@@ -29,11 +34,15 @@ fn main() -> std::io::Result<()> {
         keep_names: MangleOptionsKeepNames { function: keep_names, class: keep_names },
         debug,
     };
-    let printed = mangler(&source_text, source_type, options);
+    let properties = mangle_props.map(|pattern| MangleOptionsProperties {
+        regex: Some(Regex::new(&pattern).unwrap()),
+        ..MangleOptionsProperties::default()
+    });
+    let printed = mangler(&source_text, source_type, options, properties.as_ref());
     println!("{printed}");
 
     if twice {
-        let printed2 = mangler(&printed, source_type, options);
+        let printed2 = mangler(&printed, source_type, options, properties.as_ref());
         println!("{printed2}");
         println!("same = {}", printed == printed2);
     }
@@ -41,9 +50,17 @@ fn main() -> std::io::Result<()> {
     Ok(())
 }
 
-fn mangler(source_text: &str, source_type: SourceType, options: MangleOptions) -> String {
+fn mangler(
+    source_text: &str,
+    source_type: SourceType,
+    options: MangleOptions,
+    properties: Option<&MangleOptionsProperties>,
+) -> String {
     let allocator = Allocator::default();
-    let ret = Parser::new(&allocator, source_text, source_type).parse();
-    let symbol_table = Mangler::new().with_options(options).build(&ret.program);
-    CodeGenerator::new().with_scoping(Some(symbol_table)).build(&ret.program).code
+    let mut program = Parser::new(&allocator, source_text, source_type).parse().program;
+    if let Some(properties) = properties {
+        PropertyMangler::new(properties.clone()).build(&allocator, &mut program);
+    }
+    let symbol_table = Mangler::new().with_options(options).build(&program);
+    CodeGenerator::new().with_scoping(Some(symbol_table)).build(&program).code
 }
@@ -6,8 +6,8 @@ use oxc_traverse::ReusableTraverseCtx;
 use crate::{
     CompressOptions,
     peephole::{
-        DeadCodeElimination, LatePeepholeOptimizations, Normalize, NormalizeOptions,
-        PeepholeOptimizations,
+        DeadCodeElimination, Inline, LatePeepholeOptimizations, Normalize, NormalizeOptions,
+        PeepholeOptimizations, RemoveUnusedDeclarations,
     },
 };
 
@@ -33,6 +33,25 @@ impl<'a> Compressor<'a> {
         Normalize::new(normalize_options, self.options).build(program, &mut ctx);
         PeepholeOptimizations::new(self.options.target, self.options.keep_names)
             .run_in_loop(program, &mut ctx);
+        // Inlining and removing declarations leave code to be folded by the peephole optimizations,
+        // which in turn leave more declarations unused.
+        loop {
+            let mut changed = false;
+            if self.options.inline.is_enabled() {
+                changed |= Inline::new(self.options.inline, self.options.top_level)
+                    .build(program, &mut ctx);
+            }
+            if self.options.unused {
+                changed |=
+                    RemoveUnusedDeclarations::new(self.options.top_level, self.options.keep_fargs)
+                        .build(program, &mut ctx);
+            }
+            if !changed {
+                break;
+            }
+            PeepholeOptimizations::new(self.options.target, self.options.keep_names)
+                .run_in_loop(program, &mut ctx);
+        }
         LatePeepholeOptimizations::new(self.options.target).build(program, &mut ctx);
     }
 
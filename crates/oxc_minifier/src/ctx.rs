@@ -6,6 +6,7 @@ use oxc_ecmascript::constant_evaluation::{
 };
 use oxc_ecmascript::side_effects::{MayHaveSideEffects, PropertyReadSideEffects};
 use oxc_semantic::{IsGlobalReference, Scoping};
+use oxc_syntax::scope::ScopeId;
 use oxc_traverse::TraverseCtx;
 
 #[derive(Clone, Copy)]
@@ -58,6 +59,14 @@ impl<'a> Ctx<'a, '_> {
         self.0.scoping()
     }
 
+    /// Whether the current scope is in [`Scoping`].
+    ///
+    /// Block statements created by peephole optimizations have placeholder scope ids.
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn is_current_scope_known(self) -> bool {
+        self.current_scope_id() < ScopeId::new(self.scoping().scopes_len() as u32)
+    }
+
     pub fn is_global_reference(self, ident: &IdentifierReference<'a>) -> bool {
         ident.is_global_reference(self.0.scoping())
     }
@@ -16,10 +16,14 @@ use oxc_ast::ast::Program;
 use oxc_mangler::Mangler;
 use oxc_semantic::{Scoping, SemanticBuilder, Stats};
 
-pub use oxc_mangler::{MangleOptions, MangleOptionsKeepNames};
+pub use oxc_mangler::{
+    MangleOptions, MangleOptionsKeepNames, MangleOptionsProperties, PropertyMangler,
+    PropertyNameCache,
+};
 
 pub use crate::{
-    compressor::Compressor, options::CompressOptions, options::CompressOptionsKeepNames,
+    compressor::Compressor,
+    options::{CompressOptions, CompressOptionsInline, CompressOptionsKeepNames},
 };
 
 #[derive(Debug, Clone, Copy)]
@@ -24,12 +24,37 @@ pub struct CompressOptions {
     ///
     /// Default `false`
     pub drop_console: bool,
+
+    /// Inline constants and functions that are only called once.
+    ///
+    /// Default [`CompressOptionsInline::all_true`]
+    pub inline: CompressOptionsInline,
+
+    /// Remove unreferenced variables, functions and classes.
+    ///
+    /// Initializers with side effects are kept as expression statements.
+    ///
+    /// Default `true`
+    pub unused: bool,
+
+    /// Keep unused parameters at the end of parameter lists, so that `Function.prototype.length`
+    /// is preserved. Only has an effect with [`CompressOptions::unused`].
+    ///
+    /// Default `true`
+    pub keep_fargs: bool,
+
+    /// Allow dropping and inlining bindings in the top level scope of scripts,
+    /// which are global variables visible to other scripts.
+    ///
+    /// Top level bindings of modules are always local to the module.
+    ///
+    /// Default `false`
+    pub top_level: bool,
 }
 
-#[expect(clippy::derivable_impls)]
 impl Default for CompressOptions {
     fn default() -> Self {
-        Self { drop_console: false, ..Self::smallest() }
+        Self { drop_console: false, keep_fargs: true, ..Self::smallest() }
     }
 }
 
@@ -40,6 +65,10 @@ impl CompressOptions {
             keep_names: CompressOptionsKeepNames::all_false(),
             drop_debugger: true,
             drop_console: true,
+            inline: CompressOptionsInline::all_true(),
+            unused: true,
+            keep_fargs: false,
+            top_level: false,
         }
     }
 
@@ -49,6 +78,10 @@ impl CompressOptions {
             keep_names: CompressOptionsKeepNames::all_true(),
             drop_debugger: false,
             drop_console: false,
+            inline: CompressOptionsInline::all_false(),
+            unused: false,
+            keep_fargs: true,
+            top_level: false,
         }
     }
 }
@@ -87,3 +120,40 @@ impl CompressOptionsKeepNames {
         Self { function: false, class: true }
     }
 }
+
+#[derive(Debug, Clone, Copy)]
+pub struct CompressOptionsInline {
+    /// Inline variables that are never reassigned and initialized with a small literal,
+    /// e.g. `const DEBUG = false`, or with a side effect free primitive value used only once.
+    ///
+    /// Top level variables of scripts are only inlined with [`CompressOptions::top_level`].
+    ///
+    /// Default `true`
+    pub constants: bool,
+
+    /// Inline function declarations that are called once and only return an expression,
+    /// e.g. `function add(a, b) { return a + b }`.
+    ///
+    /// Default `true`
+    pub functions: bool,
+}
+
+impl Default for CompressOptionsInline {
+    fn default() -> Self {
+        Self::all_true()
+    }
+}
+
+impl CompressOptionsInline {
+    pub fn all_false() -> Self {
+        Self { constants: false, functions: false }
+    }
+
+    pub fn all_true() -> Self {
+        Self { constants: true, functions: true }
+    }
+
+    pub fn is_enabled(self) -> bool {
+        self.constants || self.functions
+    }
+}
@@ -0,0 +1,650 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{CloneIn, TakeIn, Vec};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_ecmascript::side_effects::MayHaveSideEffects;
+use oxc_span::Atom;
+use oxc_syntax::{
+    reference::{ReferenceFlags, ReferenceId},
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolId,
+};
+use oxc_traverse::{Ancestor, ReusableTraverseCtx, Traverse, TraverseCtx, traverse_mut_with_ctx};
+
+use crate::{ctx::Ctx, options::CompressOptionsInline};
+
+/// Inline constants and functions
+///
+/// * `const DEBUG = false; if (DEBUG) foo()` => `if (false) foo()`
+/// * `const type = typeof a; return type` => `return typeof a`
+/// * `function add(a, b) { return a + b } add(x, 1)` => `x + 1`
+///
+/// Candidates are found by the references of their symbols, and their declarations are removed
+/// once all references are inlined. Inlined values are folded by [`super::PeepholeOptimizations`],
+/// so the two passes are run in turn until nothing is inlined.
+///
+/// Variables are only inlined into references visited after their declaration.
+///
+/// <https://github.com/terser/terser/blob/v5.39.0/lib/compress/inline.js>
+pub struct Inline<'a> {
+    options: CompressOptionsInline,
+    top_level: bool,
+    is_module: bool,
+    changed: bool,
+    constants: FxHashMap<SymbolId, Constant<'a>>,
+    functions: FxHashMap<SymbolId, InlineFunction<'a>>,
+    /// Symbols with inlined references, whose declarations are removed once they are unused.
+    inlined: FxHashSet<SymbolId>,
+    /// Names of references created by other passes without a `ReferenceId`, which may refer to
+    /// the declarations of inlined symbols.
+    unresolved_names: FxHashSet<Atom<'a>>,
+}
+
+struct Constant<'a> {
+    value: Expression<'a>,
+    /// Moved into its only reference instead of being copied.
+    single_use: bool,
+    /// Scope of the statements containing the declaration.
+    scope_id: ScopeId,
+    /// `var` declarations can be skipped by control flow or read before they are initialized
+    /// by hoisted functions, so they are only inlined in the same block or nested blocks.
+    is_var: bool,
+    references: std::vec::Vec<(Atom<'a>, Option<SymbolId>)>,
+}
+
+struct InlineFunction<'a> {
+    params: std::vec::Vec<SymbolId>,
+    /// The returned expression, `None` for `return;` and empty functions.
+    body: Option<Expression<'a>>,
+    /// References in `body` other than to `params`.
+    references: std::vec::Vec<(Atom<'a>, Option<SymbolId>)>,
+}
+
+impl<'a> Inline<'a> {
+    pub fn new(options: CompressOptionsInline, top_level: bool) -> Self {
+        Self {
+            options,
+            top_level,
+            is_module: false,
+            changed: false,
+            constants: FxHashMap::default(),
+            functions: FxHashMap::default(),
+            inlined: FxHashSet::default(),
+            unresolved_names: FxHashSet::default(),
+        }
+    }
+
+    /// Returns whether anything is inlined.
+    pub fn build(mut self, program: &mut Program<'a>, ctx: &mut ReusableTraverseCtx<'a>) -> bool {
+        traverse_mut_with_ctx(&mut self, program, ctx);
+        self.changed
+    }
+}
+
+impl<'a> Traverse<'a> for Inline<'a> {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.is_module = program.source_type.is_module();
+    }
+
+    fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        if !self.options.functions {
+            return;
+        }
+        // Function declarations are hoisted, so they can be called before they are visited.
+        for stmt in stmts.iter() {
+            if let Statement::FunctionDeclaration(func) = stmt {
+                self.add_function(func, ctx);
+            }
+        }
+    }
+
+    fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        if !self.inlined.is_empty() {
+            self.remove_inlined_declarations(stmts, ctx);
+        }
+    }
+
+    fn exit_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // Skip declarations in `for` statements, `export` and `switch` cases.
+        if self.options.constants
+            && matches!(
+                ctx.parent(),
+                Ancestor::ProgramBody(_)
+                    | Ancestor::BlockStatementBody(_)
+                    | Ancestor::FunctionBodyStatements(_)
+                    | Ancestor::StaticBlockBody(_)
+            )
+        {
+            self.add_constants(decl, ctx);
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::Identifier(_) => self.inline_constant(expr, ctx),
+            Expression::CallExpression(_) => self.inline_call(expr, ctx),
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Inline<'a> {
+    /// Bindings at the top level of scripts are global and can be used by other scripts.
+    fn is_global_scope(&self, scope_id: ScopeId, ctx: &TraverseCtx<'a>) -> bool {
+        !self.top_level && !self.is_module && scope_id == ctx.scoping().root_scope_id()
+    }
+
+    fn add_constants(&mut self, decl: &VariableDeclaration<'a>, ctx: &TraverseCtx<'a>) {
+        let scope_id = ctx.current_scope_id();
+        if !Ctx(ctx).is_current_scope_known() || self.is_global_scope(scope_id, ctx) {
+            return;
+        }
+        for declarator in &decl.declarations {
+            let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind else { continue };
+            let Some(init) = &declarator.init else { continue };
+            let symbol_id = id.symbol_id();
+            let scoping = ctx.scoping();
+            if !scoping.symbol_redeclarations(symbol_id).is_empty()
+                || scoping.symbol_is_mutated(symbol_id)
+            {
+                continue;
+            }
+            let constant = match scoping.get_resolved_reference_ids(symbol_id).len() {
+                0 => continue,
+                1 => {
+                    let Some(references) = Self::movable_references(init, ctx) else { continue };
+                    Constant {
+                        value: init.clone_in_with_semantic_ids(ctx.ast.allocator),
+                        single_use: true,
+                        scope_id,
+                        is_var: decl.kind.is_var(),
+                        references,
+                    }
+                }
+                _ if Self::is_small_literal(init) => Constant {
+                    value: init.clone_in(ctx.ast.allocator),
+                    single_use: false,
+                    scope_id,
+                    is_var: decl.kind.is_var(),
+                    references: vec![],
+                },
+                _ => continue,
+            };
+            self.constants.insert(symbol_id, constant);
+        }
+    }
+
+    /// Literals which are not much longer than a mangled name, so they can be copied to every reference.
+    fn is_small_literal(expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::BooleanLiteral(_) | Expression::NullLiteral(_) => true,
+            Expression::NumericLiteral(lit) => {
+                lit.value.fract() == 0.0 && (0.0..1000.0).contains(&lit.value)
+            }
+            Expression::StringLiteral(lit) => lit.value.len() <= 3,
+            Expression::UnaryExpression(e) => match e.operator {
+                UnaryOperator::Void => e.argument.is_number_0(),
+                UnaryOperator::UnaryNegation | UnaryOperator::LogicalNot => {
+                    matches!(e.argument, Expression::NumericLiteral(_))
+                        && Self::is_small_literal(&e.argument)
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// References of a side effect free expression, which evaluates to the same value wherever
+    /// its references resolve to the same symbols.
+    fn movable_references(
+        expr: &Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<std::vec::Vec<(Atom<'a>, Option<SymbolId>)>> {
+        if expr.may_have_side_effects(&Ctx(ctx)) {
+            return None;
+        }
+        let mut collector = ReferenceCollector::default();
+        collector.visit_expression(expr);
+        // A reference in a loop or a function would create a new object for each evaluation.
+        if collector.unmovable || collector.creates_objects {
+            return None;
+        }
+        let scoping = ctx.scoping();
+        collector
+            .references
+            .into_iter()
+            .map(|(name, reference_id)| {
+                let symbol_id = scoping.get_reference(reference_id).symbol_id()?;
+                (!scoping.symbol_is_mutated(symbol_id)).then_some((name, Some(symbol_id)))
+            })
+            .collect()
+    }
+
+    fn add_function(&mut self, func: &Function<'a>, ctx: &TraverseCtx<'a>) {
+        if !Ctx(ctx).is_current_scope_known() {
+            return;
+        }
+        let scope_flags = ctx.current_scope_flags();
+        // Functions in blocks have different semantics in sloppy mode.
+        if !scope_flags.intersects(ScopeFlags::Top | ScopeFlags::Function)
+            || self.is_global_scope(ctx.current_scope_id(), ctx)
+        {
+            return;
+        }
+        let Some(id) = &func.id else { return };
+        let Some(body) = &func.body else { return };
+        if func.r#async
+            || func.generator
+            || func.this_param.is_some()
+            || func.params.rest.is_some()
+            || !body.directives.is_empty()
+        {
+            return;
+        }
+        let symbol_id = id.symbol_id();
+        let scoping = ctx.scoping();
+        if scoping.get_resolved_reference_ids(symbol_id).len() != 1
+            || !scoping.symbol_redeclarations(symbol_id).is_empty()
+        {
+            return;
+        }
+        let expression = match body.statements.as_slice() {
+            [] => None,
+            [Statement::ReturnStatement(ret)] => ret.argument.as_ref(),
+            _ => return,
+        };
+        let mut params = vec![];
+        for param in &func.params.items {
+            let BindingPatternKind::BindingIdentifier(id) = &param.pattern.kind else { return };
+            if scoping.symbol_is_mutated(id.symbol_id()) {
+                return;
+            }
+            params.push(id.symbol_id());
+        }
+        let mut collector = ReferenceCollector::default();
+        if let Some(expression) = expression {
+            collector.visit_expression(expression);
+        }
+        if collector.unmovable {
+            return;
+        }
+        let mut references = vec![];
+        for (name, reference_id) in collector.references {
+            let reference_symbol_id = scoping.get_reference(reference_id).symbol_id();
+            if reference_symbol_id == Some(symbol_id) {
+                return;
+            }
+            if !reference_symbol_id.is_some_and(|symbol_id| params.contains(&symbol_id)) {
+                references.push((name, reference_symbol_id));
+            }
+        }
+        let body = expression.map(|e| e.clone_in_with_semantic_ids(ctx.ast.allocator));
+        self.functions.insert(symbol_id, InlineFunction { params, body, references });
+    }
+
+    /// Whether all `references` resolve to the same symbols in `scope_id`.
+    fn resolves_same(
+        references: &[(Atom<'a>, Option<SymbolId>)],
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        references
+            .iter()
+            .all(|(name, symbol_id)| ctx.scoping().find_binding(scope_id, name) == *symbol_id)
+    }
+
+    fn inline_constant(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::Identifier(ident) = expr else { return };
+        let Some(reference_id) = ident.reference_id.get() else {
+            self.unresolved_names.insert(ident.name);
+            return;
+        };
+        let Some(symbol_id) = ctx.scoping().get_reference(reference_id).symbol_id() else {
+            return;
+        };
+        let Some(constant) = self.constants.get(&symbol_id) else { return };
+        if !Ctx(ctx).is_current_scope_known() {
+            return;
+        }
+        // `delete x` returns `false`
+        if matches!(ctx.parent(), Ancestor::UnaryExpressionArgument(e) if e.operator().is_delete())
+        {
+            return;
+        }
+        if constant.is_var && !Self::is_in_same_function_block(constant.scope_id, ctx) {
+            return;
+        }
+        if !Self::resolves_same(&constant.references, ctx.current_scope_id(), ctx) {
+            return;
+        }
+        let value = if constant.single_use {
+            self.constants.remove(&symbol_id).unwrap().value
+        } else {
+            constant.value.clone_in(ctx.ast.allocator)
+        };
+        ctx.delete_reference_for_identifier(ident);
+        *expr = value;
+        self.inlined.insert(symbol_id);
+        self.changed = true;
+    }
+
+    /// Whether the current scope is `scope_id` or a block nested in it, without crossing functions.
+    fn is_in_same_function_block(scope_id: ScopeId, ctx: &TraverseCtx<'a>) -> bool {
+        for ancestor_scope_id in ctx.ancestor_scopes() {
+            if ancestor_scope_id == scope_id {
+                return true;
+            }
+            if ctx.scoping().scope_flags(ancestor_scope_id).is_function() {
+                return false;
+            }
+        }
+        false
+    }
+
+    fn inline_call(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::CallExpression(call) = expr else { return };
+        let Expression::Identifier(callee) = &call.callee else { return };
+        let Some(reference_id) = callee.reference_id.get() else { return };
+        let Some(symbol_id) = ctx.scoping().get_reference(reference_id).symbol_id() else {
+            return;
+        };
+        let Some(function) = self.functions.get(&symbol_id) else { return };
+        if !Ctx(ctx).is_current_scope_known()
+            || call.optional
+            || call.type_arguments.is_some()
+            || !call.arguments.iter().all(|arg| {
+                arg.as_expression().is_some_and(|arg| Self::is_constant_argument(arg, ctx))
+            })
+            || !Self::resolves_same(&function.references, ctx.current_scope_id(), ctx)
+        {
+            return;
+        }
+        let function = self.functions.remove(&symbol_id).unwrap();
+        let Expression::CallExpression(call) = expr.take_in(ctx.ast.allocator) else {
+            unreachable!()
+        };
+        let call = call.unbox();
+        if let Expression::Identifier(callee) = &call.callee {
+            ctx.delete_reference_for_identifier(callee);
+        }
+        // Arguments are copied for each reference to their parameters, or dropped if unused.
+        let mut arguments =
+            call.arguments.into_iter().map(Argument::into_expression).collect::<std::vec::Vec<_>>();
+        for argument in &arguments {
+            if let Expression::Identifier(ident) = argument {
+                ctx.delete_reference_for_identifier(ident);
+            }
+        }
+        arguments.resize_with(function.params.len(), || ctx.ast.void_0(call.span));
+        let arguments = function.params.into_iter().zip(arguments).collect::<std::vec::Vec<_>>();
+        let mut body = function.body.unwrap_or_else(|| ctx.ast.void_0(call.span));
+        ParamSubstitution { arguments: &arguments, ctx }.visit_expression(&mut body);
+        *expr = body;
+        self.inlined.insert(symbol_id);
+        self.changed = true;
+    }
+
+    /// Arguments which can be evaluated in any order, any number of times.
+    fn is_constant_argument(expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        match expr {
+            Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::BigIntLiteral(_)
+            | Expression::StringLiteral(_) => true,
+            Expression::UnaryExpression(e) => {
+                e.operator.is_void() && e.argument.is_number_0()
+                    || e.operator == UnaryOperator::UnaryNegation
+                        && matches!(e.argument, Expression::NumericLiteral(_))
+            }
+            Expression::Identifier(ident) => {
+                let scoping = ctx.scoping();
+                ident
+                    .reference_id
+                    .get()
+                    .and_then(|reference_id| scoping.get_reference(reference_id).symbol_id())
+                    .is_some_and(|symbol_id| !scoping.symbol_is_mutated(symbol_id))
+            }
+            _ => false,
+        }
+    }
+
+    fn remove_inlined_declarations(
+        &self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        let scoping = ctx.scoping();
+        // Declarations can still be used by `eval`.
+        let is_removable = |id: &BindingIdentifier<'a>| {
+            self.inlined.contains(&id.symbol_id())
+                && !scoping.symbol_is_used(id.symbol_id())
+                && !scoping
+                    .scope_flags(scoping.symbol_scope_id(id.symbol_id()))
+                    .contains_direct_eval()
+                && !self.unresolved_names.contains(&id.name)
+        };
+        stmts.retain_mut(|stmt| match stmt {
+            Statement::VariableDeclaration(decl) => {
+                decl.declarations.retain(|declarator| {
+                    !declarator.id.get_binding_identifier().is_some_and(is_removable)
+                });
+                !decl.declarations.is_empty()
+            }
+            Statement::FunctionDeclaration(func) => !func.id.as_ref().is_some_and(is_removable),
+            _ => true,
+        });
+    }
+}
+
+/// References of an expression, and whether it can be moved to another scope.
+#[derive(Default)]
+struct ReferenceCollector<'a> {
+    references: std::vec::Vec<(Atom<'a>, ReferenceId)>,
+    /// Contains scopes, or values which depend on the function it is in.
+    unmovable: bool,
+    creates_objects: bool,
+}
+
+impl<'a> Visit<'a> for ReferenceCollector<'a> {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        match it.reference_id.get() {
+            Some(reference_id) if it.name != "arguments" => {
+                self.references.push((it.name, reference_id));
+            }
+            _ => self.unmovable = true,
+        }
+    }
+
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        self.unmovable = true;
+    }
+
+    fn visit_super(&mut self, _it: &Super) {
+        self.unmovable = true;
+    }
+
+    fn visit_meta_property(&mut self, _it: &MetaProperty<'a>) {
+        self.unmovable = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {
+        self.unmovable = true;
+    }
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {
+        self.unmovable = true;
+    }
+
+    fn visit_class(&mut self, _it: &Class<'a>) {
+        self.unmovable = true;
+    }
+
+    fn visit_object_expression(&mut self, it: &ObjectExpression<'a>) {
+        self.creates_objects = true;
+        walk::walk_object_expression(self, it);
+    }
+
+    fn visit_array_expression(&mut self, it: &ArrayExpression<'a>) {
+        self.creates_objects = true;
+        walk::walk_array_expression(self, it);
+    }
+
+    fn visit_reg_exp_literal(&mut self, _it: &RegExpLiteral<'a>) {
+        self.creates_objects = true;
+    }
+
+    fn visit_object_property(&mut self, it: &ObjectProperty<'a>) {
+        // `{ a }` cannot be substituted with `{ 1 }`
+        if it.shorthand {
+            self.unmovable = true;
+        }
+        walk::walk_object_property(self, it);
+    }
+}
+
+/// Replace references to parameters with their arguments.
+struct ParamSubstitution<'a, 'b> {
+    arguments: &'b [(SymbolId, Expression<'a>)],
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for ParamSubstitution<'a, '_> {
+    fn visit_expression(&mut self, it: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = it {
+            // References in function bodies and arguments are resolved when they are collected.
+            let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+            if let Some((_, argument)) =
+                self.arguments.iter().find(|(param, _)| Some(*param) == symbol_id)
+            {
+                *it = match argument {
+                    Expression::Identifier(ident) => {
+                        let symbol_id =
+                            self.ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+                        self.ctx.create_ident_expr(
+                            ident.span,
+                            ident.name,
+                            symbol_id,
+                            ReferenceFlags::Read,
+                        )
+                    }
+                    argument => argument.clone_in(self.ctx.ast.allocator),
+                };
+                return;
+            }
+        }
+        walk_mut::walk_expression(self, it);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CompressOptions, CompressOptionsInline, tester::run};
+
+    #[track_caller]
+    fn test(source_text: &str, expected: &str) {
+        let options = CompressOptions { unused: false, ..CompressOptions::smallest() };
+        let result = run(source_text, Some(options));
+        let expected = run(expected, None);
+        assert_eq!(
+            result, expected,
+            "\nfor source\n{source_text}\nexpect\n{expected}\ngot\n{result}"
+        );
+    }
+
+    #[track_caller]
+    fn test_same(source_text: &str) {
+        test(source_text, source_text);
+    }
+
+    #[test]
+    fn inline_constants() {
+        test("const DEBUG = false; if (DEBUG) foo()", "");
+        test("const a = 1; foo(a, a)", "foo(1, 1)");
+        test("let a = 'ab'; foo(a, a)", "foo('ab', 'ab')");
+        test("var a = void 0; foo(a, a)", "foo(void 0, void 0)");
+        test_same("const a = 'long string'; foo(a, a)");
+        test("const a = 'long string'; foo(a)", "foo('long string')");
+        test("const a = 1, b = x; foo(a, b)", "const b = x; foo(1, b)");
+        test(
+            "function f(a) { const type = typeof a; return type }",
+            "function f(a) { return typeof a }",
+        );
+        test(
+            "function f(a) { const s = typeof a; return () => s }",
+            "function f(a) { return () => typeof a }",
+        );
+    }
+
+    #[test]
+    fn skip_constants() {
+        // mutated
+        test("let a = 1; a = 2; foo(a, a)", "let a = 1; a = 2, foo(a, a)");
+        // objects have identity
+        test_same("const a = {}; foo(a)");
+        test(
+            "function f(b) { const a = b ? [] : b; return () => a }",
+            "function f(b) { let a = b && []; return () => a }",
+        );
+        // side effects
+        test_same("const a = foo(); bar(a)");
+        // exported
+        test_same("export const a = 1; foo(a)");
+        // shadowed
+        test(
+            "function f(b) { const a = typeof b; return (b) => a }",
+            "function f(b) { let a = typeof b; return (b) => a }",
+        );
+        // `var` in a block which may not run
+        test(
+            "function f(c) { if (c) { var a = 1; } return a }",
+            "function f(c) { if (c) var a = 1; return a }",
+        );
+        // `var` read by hoisted functions
+        test_same("function f() { var a = 1; g(), g(); function g() { return a } }");
+    }
+
+    #[test]
+    fn inline_functions() {
+        test(
+            "function add(a, b) { return a + b } let x = foo(); bar(add(x, 1))",
+            "let x = foo(); bar(x + 1)",
+        );
+        test("function f(a) { return [a, a] } foo(f())", "foo([void 0, void 0])");
+        test("function f() { return 1 } foo(f(1, 2))", "foo(1)");
+        test("function noop() {} noop()", "");
+        test("foo(f()); function f() { return bar }", "foo(bar)");
+    }
+
+    #[test]
+    fn skip_functions() {
+        // called twice
+        test_same("function f() { return bar } foo(f(), f())");
+        // `this` and `arguments`
+        test_same("function f() { return this } foo(f())");
+        test_same("function f() { return arguments } foo(f())");
+        // more than a return statement
+        test_same("function f() { for (;;) bar(); } foo(f())");
+        // arguments with side effects
+        test_same("function f(a) { return a } foo(f(bar()))");
+        // shadowed
+        test_same("function f() { return b } function g(b) { return f() } foo(g)");
+        // nested scopes
+        test_same("function f() { return () => 1 } foo(f())");
+    }
+
+    #[test]
+    fn options() {
+        let source_text = "const a = 1; function f() { return 2 } foo(a, f())";
+        let options = CompressOptions {
+            inline: CompressOptionsInline::all_false(),
+            ..CompressOptions::smallest()
+        };
+        assert_eq!(run(source_text, Some(options)), run(source_text, None));
+    }
+}
@@ -3,6 +3,7 @@
 mod collapse_variable_declarations;
 mod convert_to_dotted_properties;
 mod fold_constants;
+mod inline;
 mod minimize_conditional_expression;
 mod minimize_conditions;
 mod minimize_exit_points;
@@ -14,6 +15,7 @@ mod minimize_not_expression;
 mod minimize_statements;
 mod normalize;
 mod remove_dead_code;
+mod remove_unused_declarations;
 mod remove_unused_expression;
 mod replace_known_methods;
 mod statement_fusion;
@@ -29,7 +31,11 @@ use oxc_traverse::{ReusableTraverseCtx, Traverse, TraverseCtx, traverse_mut_with
 
 use crate::{ctx::Ctx, options::CompressOptionsKeepNames};
 
-pub use self::normalize::{Normalize, NormalizeOptions};
+pub use self::{
+    inline::Inline,
+    normalize::{Normalize, NormalizeOptions},
+    remove_unused_declarations::RemoveUnusedDeclarations,
+};
 
 #[derive(Debug, Default, Clone, Copy)]
 pub struct State {
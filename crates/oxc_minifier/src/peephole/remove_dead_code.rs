@@ -217,6 +217,12 @@ impl<'a, 'b> PeepholeOptimizations {
                 return None;
             }
             if test_has_side_effects {
+                // The branch which is never taken is already removed.
+                if boolean && if_stmt.alternate.is_none()
+                    || !boolean && matches!(if_stmt.consequent, Statement::EmptyStatement(_))
+                {
+                    return None;
+                }
                 if !has_var_stmt {
                     if boolean {
                         if_stmt.alternate = None;
@@ -678,6 +684,8 @@ mod test {
         test("if (foo) {} else {}", "foo");
         test("if (false) {}", "");
         test("if (true) {}", "");
+        test("if (foo(), true) { var a = 1; bar(a) }", "if (foo(), !0) { var a = 1; bar(a) }");
+        test("if (foo(), false) { var a = 1; bar(a) }", "if (foo(), !1) var a;");
     }
 
     #[test]
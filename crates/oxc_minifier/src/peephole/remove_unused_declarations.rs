@@ -0,0 +1,435 @@
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::{TakeIn, Vec};
+use oxc_ast::ast::*;
+use oxc_ast_visit::Visit;
+use oxc_ecmascript::side_effects::MayHaveSideEffects;
+use oxc_span::{Atom, GetSpan};
+use oxc_syntax::{reference::ReferenceId, scope::ScopeFlags};
+use oxc_traverse::{Ancestor, ReusableTraverseCtx, Traverse, TraverseCtx, traverse_mut_with_ctx};
+
+use crate::ctx::Ctx;
+
+/// Remove unused declarations
+///
+/// * `function f() {} let a = 1, b = foo()` => `foo()`
+/// * `class A {}` => ``
+/// * `function f(a, b) { return a }` => `function f(a) { return a }` without `keep_fargs`
+///
+/// Bindings are unused when their symbols have no references. Side effects of initializers are
+/// kept, which respects `/*#__PURE__*/` annotations and calls to `/*#__NO_SIDE_EFFECTS__*/`
+/// functions marked by [`super::Normalize`].
+///
+/// References in removed declarations are deleted, so declarations only used by other unused
+/// declarations are removed in the next run.
+///
+/// <https://github.com/terser/terser/blob/v5.39.0/lib/compress/drop-unused.js>
+pub struct RemoveUnusedDeclarations<'a> {
+    top_level: bool,
+    keep_fargs: bool,
+    is_module: bool,
+    changed: bool,
+    /// Names of references created by other passes without a `ReferenceId`, which may refer to
+    /// any declaration with the same name.
+    unresolved_names: FxHashSet<Atom<'a>>,
+}
+
+impl<'a> RemoveUnusedDeclarations<'a> {
+    pub fn new(top_level: bool, keep_fargs: bool) -> Self {
+        Self {
+            top_level,
+            keep_fargs,
+            is_module: false,
+            changed: false,
+            unresolved_names: FxHashSet::default(),
+        }
+    }
+
+    /// Returns whether anything is removed.
+    pub fn build(mut self, program: &mut Program<'a>, ctx: &mut ReusableTraverseCtx<'a>) -> bool {
+        let mut collector = UnresolvedNameCollector::default();
+        collector.visit_program(program);
+        self.unresolved_names = collector.names;
+        traverse_mut_with_ctx(&mut self, program, ctx);
+        self.changed
+    }
+}
+
+impl<'a> Traverse<'a> for RemoveUnusedDeclarations<'a> {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.is_module = program.source_type.is_module();
+    }
+
+    fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        if stmts.iter().any(|stmt| self.has_unused_declaration(stmt, ctx)) {
+            self.remove_unused_declarations(stmts, ctx);
+        }
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Setters must have exactly one parameter.
+        let is_setter = match ctx.parent() {
+            Ancestor::MethodDefinitionValue(method) => *method.kind() == MethodDefinitionKind::Set,
+            Ancestor::ObjectPropertyValue(prop) => *prop.kind() == PropertyKind::Set,
+            _ => false,
+        };
+        let Some(body) = &func.body else { return };
+        if is_setter || ArgumentsFinder::find(body) {
+            return;
+        }
+        self.remove_unused_params(&mut func.params, ctx);
+    }
+
+    fn exit_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.remove_unused_params(&mut arrow.params, ctx);
+    }
+}
+
+impl<'a> RemoveUnusedDeclarations<'a> {
+    fn is_unused(&self, id: &BindingIdentifier<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let Some(symbol_id) = id.symbol_id.get() else { return false };
+        let scoping = ctx.scoping();
+        let scope_id = scoping.symbol_scope_id(symbol_id);
+        // Bindings at the top level of scripts are global and can be used by other scripts.
+        if !self.top_level && !self.is_module && scope_id == scoping.root_scope_id() {
+            return false;
+        }
+        // Declarations can still be used by `eval`.
+        !scoping.symbol_is_used(symbol_id)
+            && scoping.symbol_redeclarations(symbol_id).is_empty()
+            && !scoping.scope_flags(scope_id).contains_direct_eval()
+            && !self.unresolved_names.contains(&id.name)
+    }
+
+    fn is_unused_declarator(
+        &self,
+        declarator: &VariableDeclarator<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        declarator.id.get_binding_identifier().is_some_and(|id| self.is_unused(id, ctx))
+    }
+
+    fn is_unused_function(&self, func: &Function<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        if !Ctx(ctx).is_current_scope_known() {
+            return false;
+        }
+        // Functions in blocks have different semantics in sloppy mode.
+        let scope_flags = ctx.current_scope_flags();
+        if !scope_flags.intersects(ScopeFlags::Top | ScopeFlags::Function)
+            && !scope_flags.is_strict_mode()
+        {
+            return false;
+        }
+        !func.declare && func.id.as_ref().is_some_and(|id| self.is_unused(id, ctx))
+    }
+
+    fn is_unused_class(&self, class: &Class<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        !class.declare
+            && class.id.as_ref().is_some_and(|id| self.is_unused(id, ctx))
+            && !class.may_have_side_effects(&Ctx(ctx))
+    }
+
+    fn has_unused_declaration(&self, stmt: &Statement<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        match stmt {
+            Statement::VariableDeclaration(decl) => {
+                !decl.declare
+                    && matches!(
+                        decl.kind,
+                        VariableDeclarationKind::Var
+                            | VariableDeclarationKind::Let
+                            | VariableDeclarationKind::Const
+                    )
+                    && decl
+                        .declarations
+                        .iter()
+                        .any(|declarator| self.is_unused_declarator(declarator, ctx))
+            }
+            Statement::FunctionDeclaration(func) => self.is_unused_function(func, ctx),
+            Statement::ClassDeclaration(class) => self.is_unused_class(class, ctx),
+            _ => false,
+        }
+    }
+
+    fn remove_unused_declarations(
+        &mut self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut new_stmts = ctx.ast.vec_with_capacity(stmts.len());
+        for stmt in stmts.drain(..) {
+            if !self.has_unused_declaration(&stmt, ctx) {
+                new_stmts.push(stmt);
+                continue;
+            }
+            self.changed = true;
+            match stmt {
+                Statement::VariableDeclaration(decl) => {
+                    self.remove_unused_declarators(decl.unbox(), &mut new_stmts, ctx);
+                }
+                Statement::FunctionDeclaration(func) => {
+                    ReferenceCollector::delete_references(
+                        |c| c.visit_function(&func, ScopeFlags::Function),
+                        ctx,
+                    );
+                }
+                Statement::ClassDeclaration(class) => {
+                    ReferenceCollector::delete_references(|c| c.visit_class(&class), ctx);
+                }
+                _ => unreachable!(),
+            }
+        }
+        *stmts = new_stmts;
+    }
+
+    /// Split a declaration at unused declarators, keeping initializers with side effects as
+    /// expression statements.
+    ///
+    /// `let a = foo(), b = 1, c = bar()` => `foo(); let c = bar()` if `a` and `b` are unused.
+    fn remove_unused_declarators(
+        &self,
+        mut decl: VariableDeclaration<'a>,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut kept = ctx.ast.vec();
+        for mut declarator in decl.declarations.take_in(ctx.ast.allocator) {
+            if !self.is_unused_declarator(&declarator, ctx) {
+                kept.push(declarator);
+                continue;
+            }
+            let Some(init) = declarator.init.take() else { continue };
+            if init.may_have_side_effects(&Ctx(ctx)) {
+                if !kept.is_empty() {
+                    let declarations = kept.take_in(ctx.ast.allocator);
+                    stmts.push(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                        decl.span,
+                        decl.kind,
+                        declarations,
+                        false,
+                    )));
+                }
+                stmts.push(ctx.ast.statement_expression(init.span(), init));
+            } else {
+                ReferenceCollector::delete_references(|c| c.visit_expression(&init), ctx);
+            }
+        }
+        if !kept.is_empty() {
+            decl.declarations = kept;
+            stmts.push(Statement::VariableDeclaration(ctx.ast.alloc(decl)));
+        }
+    }
+
+    /// Remove unused parameters at the end of the parameter list.
+    ///
+    /// Only runs without `keep_fargs`, as `Function.prototype.length` is not preserved.
+    fn remove_unused_params(&mut self, params: &mut FormalParameters<'a>, ctx: &TraverseCtx<'a>) {
+        if self.keep_fargs || params.rest.is_some() {
+            return;
+        }
+        while let Some(param) = params.items.last() {
+            let is_unused = param.decorators.is_empty()
+                && !param.has_modifier()
+                && matches!(&param.pattern.kind, BindingPatternKind::BindingIdentifier(id) if self.is_unused(id, ctx));
+            if !is_unused {
+                break;
+            }
+            params.items.pop();
+            self.changed = true;
+        }
+    }
+}
+
+/// Names of references without a `ReferenceId`.
+#[derive(Default)]
+struct UnresolvedNameCollector<'a> {
+    names: FxHashSet<Atom<'a>>,
+}
+
+impl<'a> Visit<'a> for UnresolvedNameCollector<'a> {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        if it.reference_id.get().is_none() {
+            self.names.insert(it.name);
+        }
+    }
+}
+
+/// References in removed code.
+#[derive(Default)]
+struct ReferenceCollector {
+    references: std::vec::Vec<ReferenceId>,
+}
+
+impl ReferenceCollector {
+    fn delete_references(visit: impl FnOnce(&mut Self), ctx: &mut TraverseCtx<'_>) {
+        let mut collector = Self::default();
+        visit(&mut collector);
+        let scoping = ctx.scoping_mut();
+        for reference_id in collector.references {
+            let Some(symbol_id) = scoping.get_reference(reference_id).symbol_id() else { continue };
+            // Copied references may already be deleted.
+            if scoping.get_resolved_reference_ids(symbol_id).contains(&reference_id) {
+                scoping.delete_resolved_reference(symbol_id, reference_id);
+            }
+        }
+    }
+}
+
+impl<'a> Visit<'a> for ReferenceCollector {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        if let Some(reference_id) = it.reference_id.get() {
+            self.references.push(reference_id);
+        }
+    }
+}
+
+/// Whether a function body uses `arguments`, which can read unused parameters.
+#[derive(Default)]
+struct ArgumentsFinder {
+    found: bool,
+}
+
+impl ArgumentsFinder {
+    fn find(body: &FunctionBody<'_>) -> bool {
+        let mut finder = Self::default();
+        finder.visit_function_body(body);
+        finder.found
+    }
+}
+
+impl<'a> Visit<'a> for ArgumentsFinder {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        if it.name == "arguments" {
+            self.found = true;
+        }
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {
+        // Nested functions have their own `arguments`.
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CompressOptions, CompressOptionsInline, tester::run};
+
+    #[track_caller]
+    fn test_with_options(source_text: &str, expected: &str, options: CompressOptions) {
+        let result = run(source_text, Some(options));
+        let expected = run(expected, None);
+        assert_eq!(
+            result, expected,
+            "\nfor source\n{source_text}\nexpect\n{expected}\ngot\n{result}"
+        );
+    }
+
+    #[track_caller]
+    fn test(source_text: &str, expected: &str) {
+        let options = CompressOptions {
+            inline: CompressOptionsInline::all_false(),
+            ..CompressOptions::smallest()
+        };
+        test_with_options(source_text, expected, options);
+    }
+
+    #[track_caller]
+    fn test_same(source_text: &str) {
+        test(source_text, source_text);
+    }
+
+    #[test]
+    fn remove_unused_variables() {
+        test("let a = 1", "");
+        test("var a, b = 1; foo(b)", "var b = 1; foo(b)");
+        test("const a = foo()", "foo()");
+        test("let a = 1, b = foo(), c = bar(); foo(c)", "foo(); let c = bar(); foo(c)");
+        test("let a = /*#__PURE__*/ foo()", "");
+        test(
+            "/*#__NO_SIDE_EFFECTS__*/ function f() {} let a = f(); foo(f)",
+            "/*#__NO_SIDE_EFFECTS__*/ function f() {} foo(f)",
+        );
+        test("export function f() { let a = foo(); var b = 1 }", "export function f() { foo() }");
+        test("{ let a = 1 } foo()", "foo()");
+    }
+
+    #[test]
+    fn remove_unused_functions_and_classes() {
+        test("function f() {}", "");
+        test("function f() { g() } function g() {}", "");
+        test("class A {}", "");
+        test("class A { static a = 1; foo() {} }", "");
+        test_same("class A { static { foo() } }");
+        test_same("class A extends foo() {}");
+        test("function f() { function g() {} } f()", "function f() {} f()");
+    }
+
+    #[test]
+    fn remove_unused_params() {
+        test("function f(a, b) { return a } f()", "function f(a) { return a } f()");
+        test("foo((a, b) => b)", "foo((a, b) => b)");
+        test("foo((a, b) => 1)", "foo(() => 1)");
+        test_same("function f(a, b) { return arguments } f()");
+        test_same("function f(a, ...b) {} f()");
+        test_same("foo({ set a(b) {} })");
+        test_same("class A { set a(b) {} } foo(A)");
+        test(
+            "function f(a) { return () => arguments } f()",
+            "function f(a) { return () => arguments } f()",
+        );
+    }
+
+    #[test]
+    fn keep_fargs() {
+        let options = CompressOptions {
+            inline: CompressOptionsInline::all_false(),
+            ..CompressOptions::default()
+        };
+        let test_same = |source_text| test_with_options(source_text, source_text, options);
+        test_same("function b(p) { return 1 } console.log(b.length)");
+        test_same("export function a(x, y) { return x }");
+        test_same("foo((a, b) => 1)");
+        test_with_options("function f(a) { let b = 1 } f()", "function f(a) {} f()", options);
+    }
+
+    #[test]
+    fn keep_used_declarations() {
+        test_same("let a = 1; foo(a)");
+        test_same("let a = 1; a = 2");
+        test_same("function f() { let a = 1; eval('a') } f()");
+        test_same("export let a = 1");
+        test_same("let a = 1; export { a }");
+        // `var` redeclarations
+        test_same("var a = foo(), a = bar()");
+    }
+
+    #[test]
+    fn top_level() {
+        let source_text = "var a = 1; function f() {} class A {}";
+        let options = CompressOptions {
+            inline: CompressOptionsInline::all_false(),
+            ..CompressOptions::smallest()
+        };
+        let script = |options| {
+            let allocator = oxc_allocator::Allocator::default();
+            let ret = oxc_parser::Parser::new(&allocator, source_text, oxc_span::SourceType::cjs())
+                .parse();
+            let mut program = ret.program;
+            crate::Compressor::new(&allocator, options).build(&mut program);
+            oxc_codegen::CodeGenerator::new().build(&program).code
+        };
+        assert_eq!(script(options), "var a = 1;\nfunction f() {}\nclass A {}\n");
+        assert_eq!(script(CompressOptions { top_level: true, ..options }), "");
+        // Top level bindings of modules are always local.
+        test("var a = 1; function f() {} class A {}", "");
+    }
+
+    #[test]
+    fn options() {
+        let source_text = "let a = 1; function f(b) {}";
+        let options = CompressOptions { unused: false, ..CompressOptions::smallest() };
+        assert_eq!(run(source_text, Some(options)), run(source_text, None));
+    }
+}
@@ -1266,7 +1266,10 @@ mod test {
     };
 
     fn test_same_keep_names(keep_names: CompressOptionsKeepNames, code: &str) {
-        let result = run(code, Some(CompressOptions { keep_names, ..CompressOptions::smallest() }));
+        let result = run(
+            code,
+            Some(CompressOptions { keep_names, unused: false, ..CompressOptions::smallest() }),
+        );
         let expected = run(code, None);
         assert_eq!(result, expected, "\nfor source\n{code}\ngot\n{result}");
     }
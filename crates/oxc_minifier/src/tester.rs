@@ -3,7 +3,7 @@ use oxc_codegen::{CodeGenerator, CodegenOptions};
 use oxc_parser::{ParseOptions, Parser};
 use oxc_span::SourceType;
 
-use crate::{CompressOptions, Compressor};
+use crate::{CompressOptions, CompressOptionsInline, Compressor};
 
 #[track_caller]
 pub fn test_same(source_text: &str) {
@@ -12,7 +12,13 @@ pub fn test_same(source_text: &str) {
 
 #[track_caller]
 pub fn test(source_text: &str, expected: &str) {
-    let result = run(source_text, Some(CompressOptions::smallest()));
+    // Peephole tests declare variables for their inputs, which should not be inlined or removed.
+    let options = CompressOptions {
+        inline: CompressOptionsInline::all_false(),
+        unused: false,
+        ..CompressOptions::smallest()
+    };
+    let result = run(source_text, Some(options));
     let expected = run(expected, None);
     assert_eq!(result, expected, "\nfor source\n{source_text}\nexpect\n{expected}\ngot\n{result}");
 }
@@ -1,8 +1,12 @@
 use std::fmt::Write;
 
+use lazy_regex::Regex;
 use oxc_allocator::Allocator;
 use oxc_codegen::CodeGenerator;
-use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, Mangler};
+use oxc_mangler::{
+    MangleOptions, MangleOptionsKeepNames, MangleOptionsProperties, Mangler, PropertyMangler,
+    PropertyNameCache,
+};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
@@ -15,6 +19,25 @@ fn mangle(source_text: &str, options: MangleOptions) -> String {
     CodeGenerator::new().with_scoping(Some(symbol_table)).build(&program).code
 }
 
+fn mangle_properties(
+    source_text: &str,
+    options: MangleOptionsProperties,
+    cache: PropertyNameCache,
+) -> (String, PropertyNameCache) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::mjs();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let cache =
+        PropertyMangler::new(options).with_name_cache(cache).build(&allocator, &mut program);
+    (CodeGenerator::new().build(&program).code, cache)
+}
+
+#[expect(clippy::trivial_regex)]
+fn underscore_options() -> MangleOptionsProperties {
+    MangleOptionsProperties { regex: Some(Regex::new("^_").unwrap()), ..Default::default() }
+}
+
 #[test]
 fn direct_eval() {
     let source_text = "function foo() { let NO_MANGLE; eval('') }";
@@ -93,3 +116,65 @@ fn mangler() {
         insta::assert_snapshot!("mangler", snapshot);
     });
 }
+
+#[test]
+fn property_mangler() {
+    let cases = [
+        "x._foo = x._bar + x._foo + x.baz",
+        "x = { _foo: 1, _bar() {}, get _baz() {}, baz: 2 }",
+        "x = { _foo }",
+        "let { _foo, _bar: bar } = x",
+        "({ _foo, _bar = 1 } = x)",
+        "class A { _foo = 1; static _bar() {} accessor _baz; constructor() {} }",
+        "class A { #foo; #bar() {} static #baz = 1; has(a) { return #foo in a } bar() { return this.#bar() } }",
+        "x['_foo'] = x._foo; x = { '_bar': 1, _bar: 2 }", // quoted names are not mangled
+        "x._foo = 1; x['_foo']",
+        "z = { '_baz': 1 }; z._baz",
+        "x._foo = x.e; x.t = 1", // existing names are not reused
+        "x = { __proto__: null, _foo: 1 }; x._foo.constructor", // special names
+    ];
+    let reserved_cases = ["x._foo = x._keep"];
+    let no_regex_cases = ["class A { #foo; _foo() { return this.#foo } }"];
+
+    let mut snapshot = String::new();
+    cases.into_iter().fold(&mut snapshot, |w, case| {
+        let (code, _) = mangle_properties(case, underscore_options(), PropertyNameCache::new());
+        write!(w, "{case}\n{code}\n").unwrap();
+        w
+    });
+    reserved_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options =
+            MangleOptionsProperties { reserved: vec!["_keep".into()], ..underscore_options() };
+        let (code, _) = mangle_properties(case, options, PropertyNameCache::new());
+        write!(w, "{case}\n{code}\n").unwrap();
+        w
+    });
+    no_regex_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = MangleOptionsProperties::default();
+        let (code, _) = mangle_properties(case, options, PropertyNameCache::new());
+        write!(w, "{case}\n{code}\n").unwrap();
+        w
+    });
+
+    insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
+        insta::assert_snapshot!("property_mangler", snapshot);
+    });
+}
+
+#[test]
+fn property_name_cache() {
+    let (code, cache) = mangle_properties(
+        "x._foo = x._bar + x._bar",
+        underscore_options(),
+        PropertyNameCache::new(),
+    );
+    assert_eq!(code, "x.t = x.e + x.e;\n");
+    assert_eq!(cache.get("_bar"), Some("e"));
+    assert_eq!(cache.get("_foo"), Some("t"));
+
+    // Names from the cache are reused, and not assigned to other properties.
+    let cache = cache.iter().collect::<PropertyNameCache>();
+    let (code, cache) = mangle_properties("x._baz = x._foo", underscore_options(), cache);
+    assert_eq!(code, "x.n = x.t;\n");
+    assert_eq!(cache.len(), 3);
+}
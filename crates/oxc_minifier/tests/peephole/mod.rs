@@ -5,7 +5,9 @@ use oxc_minifier::CompressOptions;
 
 #[track_caller]
 fn test(source_text: &str, expected: &str) {
-    let options = CompressOptions { drop_debugger: false, ..CompressOptions::default() };
+    // Test cases declare functions and variables for their inputs, which should not be removed.
+    let options =
+        CompressOptions { drop_debugger: false, unused: false, ..CompressOptions::default() };
     crate::test(source_text, expected, options);
 }
 
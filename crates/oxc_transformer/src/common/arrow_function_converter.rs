@@ -93,7 +93,10 @@ use rustc_hash::{FxBuildHasher, FxHashSet};
 
 use oxc_allocator::{Box as ArenaBox, TakeIn, Vec as ArenaVec};
 use oxc_ast::{NONE, ast::*};
-use oxc_ast_visit::{VisitMut, walk_mut::walk_expression};
+use oxc_ast_visit::{
+    VisitMut,
+    walk_mut::{walk_expression, walk_function},
+};
 use oxc_data_structures::stack::{NonEmptyStack, SparseStack};
 use oxc_semantic::{ReferenceFlags, SymbolId};
 use oxc_span::{CompactStr, GetSpan, SPAN};
@@ -147,6 +150,8 @@ pub struct ArrowFunctionConverter<'a> {
     // Only using `FxIndexMap` for predictable iteration order to match Babel's output.
     super_methods_stack: NonEmptyStack<FxIndexMap<SuperMethodKey<'a>, SuperMethodInfo<'a>>>,
     super_needs_transform_stack: NonEmptyStack<bool>,
+    /// Scopes of arrow functions which have been converted to function expressions
+    converted_arrow_scope_ids: FxHashSet<ScopeId>,
 }
 
 impl ArrowFunctionConverter<'_> {
@@ -168,6 +173,7 @@ impl ArrowFunctionConverter<'_> {
             renamed_arguments_symbol_ids: FxHashSet::default(),
             super_methods_stack: NonEmptyStack::new(FxIndexMap::default()),
             super_needs_transform_stack: NonEmptyStack::new(false),
+            converted_arrow_scope_ids: FxHashSet::default(),
         }
     }
 }
@@ -454,6 +460,7 @@ impl<'a> Traverse<'a> for ArrowFunctionConverter<'a> {
                 unreachable!()
             };
 
+            self.converted_arrow_scope_ids.insert(arrow_function_expr.scope_id());
             *expr = Self::transform_arrow_function_expression(arrow_function_expr, ctx);
         }
     }
@@ -1177,8 +1184,12 @@ impl<'a> ArrowFunctionConverter<'a> {
             let init = if is_constructor && *self.constructor_super_stack.last() {
                 // `super()` is called in the constructor body, so we need to insert `_this = this;`
                 // after `super()` call. Because `this` is not available before `super()` call.
-                ConstructorBodyThisAfterSuperInserter::new(&this_var, ctx)
-                    .visit_statements(statements);
+                ConstructorBodyThisAfterSuperInserter::new(
+                    &this_var,
+                    &self.converted_arrow_scope_ids,
+                    ctx,
+                )
+                .visit_statements(statements);
                 None
             } else {
                 Some(ctx.ast.expression_this(SPAN))
@@ -1212,12 +1223,19 @@ impl<'a> ArrowFunctionConverter<'a> {
 /// Visitor for inserting `this` after `super` in constructor body.
 struct ConstructorBodyThisAfterSuperInserter<'a, 'v> {
     this_var_binding: &'v BoundIdentifier<'a>,
+    /// Arrow functions which have already been converted to function expressions,
+    /// which may contain `super()`
+    converted_arrow_scope_ids: &'v FxHashSet<ScopeId>,
     ctx: &'v mut TraverseCtx<'a>,
 }
 
 impl<'a, 'v> ConstructorBodyThisAfterSuperInserter<'a, 'v> {
-    fn new(this_var_binding: &'v BoundIdentifier<'a>, ctx: &'v mut TraverseCtx<'a>) -> Self {
-        Self { this_var_binding, ctx }
+    fn new(
+        this_var_binding: &'v BoundIdentifier<'a>,
+        converted_arrow_scope_ids: &'v FxHashSet<ScopeId>,
+        ctx: &'v mut TraverseCtx<'a>,
+    ) -> Self {
+        Self { this_var_binding, converted_arrow_scope_ids, ctx }
     }
 }
 
@@ -1268,9 +1286,12 @@ impl<'a> VisitMut<'a> for ConstructorBodyThisAfterSuperInserter<'a, '_> {
         }
     }
 
-    #[inline] // `#[inline]` because is a no-op
-    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {
-        // `super()` can't appear in a nested function
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        // `super()` can't appear in a nested function, but can in an arrow function
+        // which has been converted to a function expression
+        if self.converted_arrow_scope_ids.contains(&func.scope_id()) {
+            walk_function(self, func, flags);
+        }
     }
 
     /// `super();` -> `super(); _this = this;`
@@ -1,7 +1,7 @@
 //! Utility to load helper functions.
 //!
 //! This module provides functionality to load helper functions in different modes.
-//! It supports runtime, external, and inline (not yet implemented) modes for loading helper functions.
+//! It supports runtime, external, and inline modes for loading helper functions.
 //!
 //! ## Usage
 //!
@@ -47,7 +47,9 @@
 //!
 //! ### Inline ([`HelperLoaderMode::Inline`])
 //!
-//! > Note: This mode is not currently implemented.
+//! > Note: Only helpers which have an inlinable source ([`Helper::RegeneratorRuntime`],
+//! > [`Helper::InteropRequireDefault`] and [`Helper::InteropRequireWildcard`]) are supported.
+//! > Loading any other helper in this mode produces an error.
 //!
 //! Inline helper functions are inserted directly into the top of program.
 //!
@@ -63,7 +65,10 @@
 //! ## Implementation
 //!
 //! Unlike other "common" utilities, this one has no transformer. It adds imports to the program
-//! via `ModuleImports` transform.
+//! via `ModuleImports` transform, and inlined helpers via `TopLevelStatements` transform.
+//!
+//! Inlined helpers are parsed from source embedded in this crate, and have no semantic data
+//! except for the helper's own binding.
 
 use std::{borrow::Cow, cell::RefCell};
 
@@ -73,10 +78,16 @@ use serde::Deserialize;
 use oxc_allocator::Vec as ArenaVec;
 use oxc_ast::{
     NONE,
-    ast::{Argument, CallExpression, Expression},
+    ast::{
+        Argument, BindingIdentifier, CallExpression, Expression, IdentifierReference,
+        SimpleAssignmentTarget, Statement,
+    },
 };
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
 use oxc_semantic::{ReferenceFlags, SymbolFlags};
-use oxc_span::{Atom, SPAN, Span};
+use oxc_span::{Atom, SPAN, SourceType, Span};
 use oxc_traverse::{BoundIdentifier, TraverseCtx};
 
 use crate::TransformCtx;
@@ -86,7 +97,7 @@ use crate::TransformCtx;
 pub enum HelperLoaderMode {
     /// Inline mode: Helper functions are directly inserted into the program.
     ///
-    /// Note: This mode is not currently implemented.
+    /// Note: Only helpers with an inlinable source are supported. See [`Helper::inline_source`].
     ///
     /// Example output:
     /// ```js
@@ -146,6 +157,7 @@ pub enum Helper {
     AsyncToGenerator,
     ObjectSpread2,
     WrapAsyncGenerator,
+    WrapRegExp,
     Extends,
     ObjectDestructuringEmpty,
     ObjectWithoutProperties,
@@ -160,6 +172,9 @@ pub enum Helper {
     ClassPrivateFieldLooseKey,
     ClassPrivateFieldLooseBase,
     SuperPropGet,
+    Get,
+    Set,
+    GetPrototypeOf,
     SuperPropSet,
     ReadOnlyError,
     WriteOnlyError,
@@ -167,7 +182,29 @@ pub enum Helper {
     Decorate,
     DecorateParam,
     DecorateMetadata,
+    ApplyDecs2305,
     UsingCtx,
+    ClassCallCheck,
+    CreateClass,
+    Inherits,
+    CallSuper,
+    PossibleConstructorReturn,
+    ToConsumableArray,
+    ToArray,
+    SlicedToArray,
+    MaybeArrayLike,
+    Construct,
+    Tdz,
+    TemporalRef,
+    TemporalUndefined,
+    TaggedTemplateLiteral,
+    TaggedTemplateLiteralLoose,
+    DefineAccessor,
+    CreateForOfIteratorHelper,
+    CreateForOfIteratorHelperLoose,
+    RegeneratorRuntime,
+    InteropRequireDefault,
+    InteropRequireWildcard,
 }
 
 impl Helper {
@@ -179,6 +216,7 @@ impl Helper {
             Self::AsyncToGenerator => "asyncToGenerator",
             Self::ObjectSpread2 => "objectSpread2",
             Self::WrapAsyncGenerator => "wrapAsyncGenerator",
+            Self::WrapRegExp => "wrapRegExp",
             Self::Extends => "extends",
             Self::ObjectDestructuringEmpty => "objectDestructuringEmpty",
             Self::ObjectWithoutProperties => "objectWithoutProperties",
@@ -193,6 +231,9 @@ impl Helper {
             Self::ClassPrivateFieldLooseKey => "classPrivateFieldLooseKey",
             Self::ClassPrivateFieldLooseBase => "classPrivateFieldLooseBase",
             Self::SuperPropGet => "superPropGet",
+            Self::Get => "get",
+            Self::Set => "set",
+            Self::GetPrototypeOf => "getPrototypeOf",
             Self::SuperPropSet => "superPropSet",
             Self::ReadOnlyError => "readOnlyError",
             Self::WriteOnlyError => "writeOnlyError",
@@ -200,13 +241,48 @@ impl Helper {
             Self::Decorate => "decorate",
             Self::DecorateParam => "decorateParam",
             Self::DecorateMetadata => "decorateMetadata",
+            Self::ApplyDecs2305 => "applyDecs2305",
             Self::UsingCtx => "usingCtx",
+            Self::ClassCallCheck => "classCallCheck",
+            Self::CreateClass => "createClass",
+            Self::Inherits => "inherits",
+            Self::CallSuper => "callSuper",
+            Self::PossibleConstructorReturn => "possibleConstructorReturn",
+            Self::ToConsumableArray => "toConsumableArray",
+            Self::ToArray => "toArray",
+            Self::SlicedToArray => "slicedToArray",
+            Self::MaybeArrayLike => "maybeArrayLike",
+            Self::Construct => "construct",
+            Self::Tdz => "tdz",
+            Self::TemporalRef => "temporalRef",
+            Self::TemporalUndefined => "temporalUndefined",
+            Self::TaggedTemplateLiteral => "taggedTemplateLiteral",
+            Self::TaggedTemplateLiteralLoose => "taggedTemplateLiteralLoose",
+            Self::DefineAccessor => "defineAccessor",
+            Self::CreateForOfIteratorHelper => "createForOfIteratorHelper",
+            Self::CreateForOfIteratorHelperLoose => "createForOfIteratorHelperLoose",
+            Self::RegeneratorRuntime => "regeneratorRuntime",
+            Self::InteropRequireDefault => "interopRequireDefault",
+            Self::InteropRequireWildcard => "interopRequireWildcard",
         }
     }
 
     pub const fn pure(self) -> bool {
         matches!(self, Self::ClassPrivateFieldLooseKey)
     }
+
+    /// Source of the helper, used in [`HelperLoaderMode::Inline`].
+    ///
+    /// Source is a single function declaration named after [`Helper::name`].
+    /// Returns `None` if the helper cannot be inlined.
+    pub const fn inline_source(self) -> Option<&'static str> {
+        match self {
+            Self::RegeneratorRuntime => Some(include_str!("helpers/regeneratorRuntime.js")),
+            Self::InteropRequireDefault => Some(include_str!("helpers/interopRequireDefault.js")),
+            Self::InteropRequireWildcard => Some(include_str!("helpers/interopRequireWildcard.js")),
+            _ => None,
+        }
+    }
 }
 
 /// Stores the state of the helper loader in [`TransformCtx`].
@@ -260,6 +336,16 @@ impl<'a> TransformCtx<'a> {
     /// Load a helper function and return a callee expression.
     pub fn helper_load(&self, helper: Helper, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
         let helper_loader = &self.helper_loader;
+        if matches!(helper_loader.mode, HelperLoaderMode::Inline) {
+            if let Some(source) = helper.inline_source() {
+                return helper_loader.transform_for_inline_helper(helper, source, self, ctx);
+            }
+            self.error(OxcDiagnostic::error(format!(
+                "Helper `{}` cannot be inlined. Use `External` or `Runtime` helper mode.",
+                helper.name()
+            )));
+        }
+
         let source = helper_loader.get_runtime_source(helper, ctx);
         helper_loader.used_helpers.borrow_mut().entry(helper).or_insert_with(|| source.to_string());
 
@@ -267,12 +353,10 @@ impl<'a> TransformCtx<'a> {
             HelperLoaderMode::Runtime => {
                 helper_loader.transform_for_runtime_helper(helper, source, self, ctx)
             }
-            HelperLoaderMode::External => {
+            // Helpers which cannot be inlined fall back to external helpers, after reporting an error
+            HelperLoaderMode::External | HelperLoaderMode::Inline => {
                 HelperLoaderStore::transform_for_external_helper(helper, ctx)
             }
-            HelperLoaderMode::Inline => {
-                unreachable!("Inline helpers are not supported yet");
-            }
         }
     }
 }
@@ -301,10 +385,10 @@ impl<'a> HelperLoaderStore<'a> {
     ) -> BoundIdentifier<'a> {
         let helper_name = helper.name();
 
-        let flag = if transform_ctx.source_type.is_module() {
-            SymbolFlags::Import
-        } else {
+        let flag = if transform_ctx.use_require() {
             SymbolFlags::FunctionScopedVariable
+        } else {
+            SymbolFlags::Import
         };
         let binding = ctx.generate_uid_in_root_scope(helper_name, flag);
 
@@ -313,6 +397,41 @@ impl<'a> HelperLoaderStore<'a> {
         binding
     }
 
+    fn transform_for_inline_helper(
+        &self,
+        helper: Helper,
+        source: &'static str,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut loaded_helpers = self.loaded_helpers.borrow_mut();
+        let binding = loaded_helpers
+            .entry(helper)
+            .or_insert_with(|| Self::get_inline_helper(helper, source, transform_ctx, ctx));
+        binding.create_read_expression(ctx)
+    }
+
+    /// Parse helper's source, and insert it at top of program.
+    ///
+    /// The helper function is renamed to a UID bound in root scope.
+    fn get_inline_helper(
+        helper: Helper,
+        source: &'static str,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let binding = ctx.generate_uid_in_root_scope(helper.name(), SymbolFlags::Function);
+
+        let ret = Parser::new(ctx.ast.allocator, source, SourceType::cjs()).parse();
+        debug_assert!(ret.errors.is_empty(), "Failed to parse `{}` helper", helper.name());
+        let mut stmts = ret.program.body;
+        InlineHelperRenamer { name: helper.name(), binding: &binding, ctx }
+            .visit_statements(&mut stmts);
+        transform_ctx.top_level_statements.insert_statements(stmts);
+
+        binding
+    }
+
     // Construct string directly in arena without an intermediate temp allocation
     fn get_runtime_source(&self, helper: Helper, ctx: &TraverseCtx<'a>) -> Atom<'a> {
         ctx.ast.atom_from_strs_array([&self.module_name, "/helpers/", helper.name()])
@@ -328,3 +447,63 @@ impl<'a> HelperLoaderStore<'a> {
         Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
     }
 }
+
+/// Visitor which removes spans from an inlined helper, and binds references to the helper's own name
+/// to the helper's UID.
+struct InlineHelperRenamer<'a, 'b, 'c> {
+    name: &'static str,
+    binding: &'b BoundIdentifier<'a>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for InlineHelperRenamer<'a, '_, '_> {
+    fn visit_span(&mut self, span: &mut Span) {
+        *span = SPAN;
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        // Helper's function declaration
+        if let Statement::FunctionDeclaration(func) = stmt {
+            if let Some(id) = &mut func.id {
+                if id.name == self.name {
+                    *id = self.binding.create_binding_identifier(self.ctx);
+                }
+            }
+        }
+        walk_mut::walk_statement(self, stmt);
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        // Name of function expression which replaces the helper on first call
+        if ident.name == self.name {
+            ident.name = self.binding.name;
+        }
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = target {
+            if ident.name == self.name {
+                **ident = self.create_reference(ReferenceFlags::Write);
+            }
+            return;
+        }
+        walk_mut::walk_simple_assignment_target(self, target);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if ident.name == self.name {
+            *ident = self.create_reference(ReferenceFlags::Read);
+        }
+    }
+}
+
+impl<'a> InlineHelperRenamer<'a, '_, '_> {
+    fn create_reference(&mut self, flags: ReferenceFlags) -> IdentifierReference<'a> {
+        self.ctx.create_bound_ident_reference(
+            SPAN,
+            self.binding.name,
+            self.binding.symbol_id,
+            flags,
+        )
+    }
+}
@@ -0,0 +1,20 @@
+function interopRequireWildcard(e, t) {
+  if ("function" == typeof WeakMap) var r = new WeakMap(), n = new WeakMap();
+  return (interopRequireWildcard = function (e, t) {
+    if (!t && e && e.__esModule) return e;
+    var o, i, f = { __proto__: null, default: e };
+    if (null === e || ("object" != typeof e && "function" != typeof e)) return f;
+    if ((o = t ? n : r)) {
+      if (o.has(e)) return o.get(e);
+      o.set(e, f);
+    }
+    for (var k in e) {
+      if ("default" !== k && {}.hasOwnProperty.call(e, k)) {
+        (i = Object.getOwnPropertyDescriptor(e, k)) && (i.get || i.set)
+          ? Object.defineProperty(f, k, i)
+          : (f[k] = e[k]);
+      }
+    }
+    return f;
+  })(e, t);
+}
@@ -27,7 +27,7 @@
 //! );
 //! ```
 //!
-//! > NOTE: Using `import` or `require` is determined by [`TransformCtx::source_type`].
+//! > NOTE: Using `import` or `require` is determined by [`TransformCtx::use_require`].
 //!
 //! Based on `@babel/helper-module-imports`
 //! <https://github.com/nicolo-ribaudo/babel/tree/v7.25.8/packages/babel-helper-module-imports>
@@ -88,7 +88,7 @@ impl<'a> ModuleImportsStore<'a> {
 
     /// Add default `import` or `require` to top of program.
     ///
-    /// Which it will be depends on the source type.
+    /// Which it will be depends on the source type and the module format.
     ///
     /// * `import named_import from 'source';` or
     /// * `var named_import = require('source');`
@@ -125,7 +125,7 @@ impl<'a> ModuleImportsStore<'a> {
 impl<'a> ModuleImportsStore<'a> {
     /// Add `import` or `require` to top of program.
     ///
-    /// Which it will be depends on the source type.
+    /// Which it will be depends on the source type and the module format.
     ///
     /// * `import { named_import } from 'source';` or
     /// * `var named_import = require('source');`
@@ -156,7 +156,7 @@ impl<'a> ModuleImportsStore<'a> {
 
     /// Insert `import` / `require` statements at top of program.
     fn insert_into_program(&self, transform_ctx: &TransformCtx<'a>, ctx: &mut TraverseCtx<'a>) {
-        if transform_ctx.source_type.is_script() {
+        if transform_ctx.use_require() {
             self.insert_require_statements(transform_ctx, ctx);
         } else {
             self.insert_import_statements(transform_ctx, ctx);
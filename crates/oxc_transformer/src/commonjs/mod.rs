@@ -0,0 +1,1588 @@
+//! Modules: CommonJS
+//!
+//! This plugin transforms ECMAScript modules to CommonJS modules.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import foo, { bar } from "foo";
+//! export { baz } from "baz";
+//! export const qux = foo(bar);
+//! ```
+//!
+//! Output:
+//! ```js
+//! "use strict";
+//! Object.defineProperty(exports, "__esModule", { value: true });
+//! Object.defineProperty(exports, "baz", {
+//!   enumerable: true,
+//!   get: function() {
+//!     return _baz.baz;
+//!   }
+//! });
+//! exports.qux = void 0;
+//! var _foo = babelHelpers.interopRequireWildcard(require("foo"));
+//! var _baz = require("baz");
+//! const qux = exports.qux = (0, _foo.default)(_foo.bar);
+//! ```
+//!
+//! ## Options
+//!
+//! ### `importInterop`
+//!
+//! How imports are interoperated with CommonJS modules. `"babel"` (default), `"node"` or `"none"`.
+//! See [`ImportInterop`].
+//!
+//! ### `strict`
+//!
+//! Do not mark the module with `exports.__esModule`. Defaults to `false`.
+//!
+//! ### `strictMode`
+//!
+//! Insert `"use strict"` directive. Defaults to `true`.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-modules-commonjs](https://babel.dev/docs/babel-plugin-transform-modules-commonjs)
+//! and [@babel/helper-module-transforms](https://github.com/babel/babel/tree/v7.26.2/packages/babel-helper-module-transforms).
+//!
+//! The transform runs on exit of program, after all other transforms, so imports which have been
+//! removed by other transforms (e.g. type-only imports in TypeScript) are not `require`d.
+//!
+//! 1. `import` and `export` declarations are removed. Each module source is `require`d once,
+//!    at top of program, after initialization of `exports`.
+//! 2. Re-exports are defined as getters on `exports`, so they are live bindings.
+//! 3. References to imported bindings are replaced with member expressions on the required module
+//!    (`foo` -> `_foo.default`), so they are live bindings too.
+//! 4. Declarations of and assignments to exported bindings also assign to `exports`
+//!    (`x = 1` -> `exports.x = x = 1`). Exported bindings assigned in `for-in` / `for-of` heads
+//!    are assigned in the loop body (`for (x of xs)` -> `for (let _x of xs) { exports.x = x = _x; }`).
+//! 5. Assignments to imported bindings throw (`foo = 1` -> `_foo.foo = (1, _readOnlyError("foo"))`).
+//! 6. Top level `this` is `undefined` in modules, but `exports` in CommonJS, so it is replaced
+//!    with `void 0`, outside of non-arrow functions and class bodies.
+//!
+//! Unlike Babel, `export default expression` is assigned to `exports.default` directly,
+//! without a `_default` variable. Dynamic `import()` is not transformed.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-modules-commonjs>
+//! * Babel helper implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-helper-module-transforms>
+
+use std::{collections::hash_map::Entry, ffi::OsStr, path::Path};
+
+use indexmap::IndexMap;
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::{Box, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk_mut};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ReferenceId, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN, Span};
+use oxc_syntax::identifier::is_identifier_name;
+use oxc_traverse::{BoundIdentifier, Traverse, TraverseCtx, ast_operations::to_identifier};
+
+use crate::{
+    common::helper_loader::Helper, context::TransformCtx, utils::ast_builder::create_member_callee,
+};
+
+mod options;
+
+pub use options::{CommonJsOptions, ImportInterop};
+
+const DEFAULT: Atom<'static> = Atom::new_const("default");
+const EXPORTS: Atom<'static> = Atom::new_const("exports");
+
+/// Number of exports initialized to `void 0` in one statement.
+/// Babel uses chunks, to avoid deeply nested assignment expressions.
+const UNINITIALIZED_CHUNK_SIZE: usize = 100;
+
+pub struct CommonJs<'a, 'ctx> {
+    options: CommonJsOptions,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> CommonJs<'a, 'ctx> {
+    pub fn new(options: CommonJsOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx }
+    }
+}
+
+impl<'a> Traverse<'a> for CommonJs<'a, '_> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !program.source_type.is_module() {
+            return;
+        }
+
+        if self.options.strict_mode && !program.directives.iter().any(Directive::is_use_strict) {
+            program.directives.insert(0, ctx.ast.use_strict_directive());
+        }
+
+        TopLevelThisRewriter { ctx }.visit_statements(&mut program.body);
+
+        if !program.body.iter().any(|stmt| matches!(stmt, match_module_declaration!(Statement))) {
+            return;
+        }
+
+        let mut metadata = self.collect_sources(&program.body, ctx);
+        let body =
+            Self::transform_statements(&mut metadata, program.body.take_in(ctx.ast.allocator), ctx);
+
+        let mut stmts = ctx.ast.vec_with_capacity(body.len() + metadata.sources.len() + 2);
+        if metadata.has_exports && !self.options.strict {
+            stmts.push(Self::create_es_module_marker(ctx));
+        }
+        let export_names = Self::create_export_names(&metadata, &mut stmts, ctx);
+        Self::create_export_initializers(&mut metadata, &mut stmts, ctx);
+        self.create_requires(&metadata, export_names.as_ref(), &mut stmts, ctx);
+        stmts.extend(body);
+        program.body = stmts;
+
+        LiveReferencesRewriter::new(&metadata, ctx, self.ctx).visit_statements(&mut program.body);
+    }
+}
+
+/// How a module source is `require`d.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+enum Interop {
+    /// `require("source")`
+    #[default]
+    None,
+    /// `_interopRequireDefault(require("source"))`
+    Default,
+    /// `_interopRequireWildcard(require("source"))`
+    Namespace,
+    /// `_interopRequireWildcard(require("source"), true)`
+    NodeNamespace,
+}
+
+/// A module source which is imported or re-exported from.
+#[derive(Default)]
+struct Source<'a> {
+    /// Binding which the required module is assigned to.
+    /// `None` if the source is only imported for side effects.
+    binding: Option<BoundIdentifier<'a>>,
+    /// Span of the binding, if it is reused from `import * as ns from "source"`.
+    binding_span: Span,
+    interop: Interop,
+    /// `true` if the default export of the source is the required module itself, rather than its
+    /// `default` property.
+    default_is_module: bool,
+    has_default: bool,
+    has_named: bool,
+    has_namespace: bool,
+    /// `export * from "source"`
+    reexport_all: bool,
+}
+
+#[derive(Default)]
+struct ModuleMetadata<'a> {
+    sources: IndexMap<Atom<'a>, Source<'a>>,
+    /// Imported bindings, and the property of the required module which they refer to.
+    /// `None` if they refer to the required module itself.
+    imports: FxHashMap<SymbolId, (BoundIdentifier<'a>, Option<Atom<'a>>)>,
+    /// Exported local bindings, and their exported names.
+    exports: IndexMap<SymbolId, (BoundIdentifier<'a>, Vec<Atom<'a>>)>,
+    /// Re-exported names, the binding of the required module, and the property which they refer to.
+    reexports: Vec<(Atom<'a>, BoundIdentifier<'a>, Option<Atom<'a>>)>,
+    /// All exported names, in order of appearance.
+    export_names: Vec<Atom<'a>>,
+    /// `export default expression`
+    has_default_expression: bool,
+    has_exports: bool,
+}
+
+impl<'a> ModuleMetadata<'a> {
+    fn add_export(&mut self, binding: BoundIdentifier<'a>, exported: Atom<'a>) {
+        self.exports.entry(binding.symbol_id).or_insert_with(|| (binding, vec![])).1.push(exported);
+        self.export_names.push(exported);
+    }
+
+    fn add_reexport(
+        &mut self,
+        exported: Atom<'a>,
+        binding: BoundIdentifier<'a>,
+        property: Option<Atom<'a>>,
+    ) {
+        self.reexports.push((exported, binding, property));
+        self.export_names.push(exported);
+    }
+
+    /// Get binding and property for the imported name of a source.
+    fn get_imported(
+        &self,
+        source: &str,
+        imported: Atom<'a>,
+    ) -> (BoundIdentifier<'a>, Option<Atom<'a>>) {
+        let source = &self.sources[source];
+        let binding = source.binding.clone().unwrap();
+        let property =
+            if imported == DEFAULT && source.default_is_module { None } else { Some(imported) };
+        (binding, property)
+    }
+}
+
+impl<'a> CommonJs<'a, '_> {
+    /// Collect module sources from `import` and `export ... from` declarations,
+    /// and decide how they are `require`d.
+    fn collect_sources(
+        &self,
+        stmts: &[Statement<'a>],
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ModuleMetadata<'a> {
+        let mut metadata = ModuleMetadata::default();
+        // Imported bindings, with their source and imported name. `None` for namespace imports.
+        let mut imported = vec![];
+        // Bindings of `import * as ns from "source"`, which are reused for the required modules
+        let mut namespaces = FxHashMap::default();
+
+        for stmt in stmts {
+            match stmt {
+                Statement::ImportDeclaration(decl) => {
+                    let source_name = decl.source.value;
+                    let source = metadata.sources.entry(source_name).or_default();
+                    for specifier in decl.specifiers.iter().flatten() {
+                        match specifier {
+                            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                                let name = specifier.imported.name();
+                                if name == DEFAULT {
+                                    source.has_default = true;
+                                } else {
+                                    source.has_named = true;
+                                }
+                                let binding = BoundIdentifier::from_binding_ident(&specifier.local);
+                                imported.push((binding, source_name, Some(name)));
+                            }
+                            ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                                source.has_default = true;
+                                let binding = BoundIdentifier::from_binding_ident(&specifier.local);
+                                imported.push((binding, source_name, Some(DEFAULT)));
+                            }
+                            ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                                source.has_namespace = true;
+                                let binding = BoundIdentifier::from_binding_ident(&specifier.local);
+                                match namespaces.entry(source_name) {
+                                    Entry::Occupied(_) => {
+                                        imported.push((binding, source_name, None));
+                                    }
+                                    Entry::Vacant(entry) => {
+                                        entry.insert((binding, specifier.local.span));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                Statement::ExportNamedDeclaration(decl) => {
+                    if let Some(source) = &decl.source {
+                        let source = metadata.sources.entry(source.value).or_default();
+                        for specifier in &decl.specifiers {
+                            if specifier.local.name() == DEFAULT {
+                                source.has_default = true;
+                            } else {
+                                source.has_named = true;
+                            }
+                        }
+                    }
+                }
+                Statement::ExportAllDeclaration(decl) => {
+                    let source = metadata.sources.entry(decl.source.value).or_default();
+                    if decl.exported.is_some() {
+                        source.has_namespace = true;
+                    } else {
+                        source.reexport_all = true;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let root_scope_id = ctx.scoping().root_scope_id();
+        for (&source_name, source) in &mut metadata.sources {
+            let needs_namespace = source.has_namespace || (source.has_default && source.has_named);
+            source.interop = match self.options.import_interop {
+                ImportInterop::Babel if needs_namespace => Interop::Namespace,
+                ImportInterop::Babel if source.has_default => Interop::Default,
+                ImportInterop::Node if needs_namespace => Interop::NodeNamespace,
+                _ => Interop::None,
+            };
+            source.default_is_module = self.options.import_interop == ImportInterop::Node
+                && source.interop != Interop::NodeNamespace;
+
+            source.binding = if let Some((binding, span)) = namespaces.remove(&source_name) {
+                // `import * as ns from "source"` -> `var ns = require("source")`
+                *ctx.scoping_mut().symbol_flags_mut(binding.symbol_id) =
+                    SymbolFlags::FunctionScopedVariable;
+                source.binding_span = span;
+                Some(binding)
+            } else if source.has_default
+                || source.has_named
+                || source.has_namespace
+                || source.reexport_all
+            {
+                let name = Path::new(source_name.as_str())
+                    .file_stem()
+                    .and_then(OsStr::to_str)
+                    .unwrap_or(source_name.as_str());
+                let name = to_identifier(name.to_string());
+                Some(ctx.generate_uid_in_root_scope(&name, SymbolFlags::FunctionScopedVariable))
+            } else {
+                None
+            };
+        }
+
+        for (binding, source_name, name) in imported {
+            ctx.scoping_mut().remove_binding(root_scope_id, &binding.name);
+            let imported = match name {
+                Some(name) => metadata.get_imported(&source_name, name),
+                None => (metadata.sources[&source_name].binding.clone().unwrap(), None),
+            };
+            metadata.imports.insert(binding.symbol_id, imported);
+        }
+
+        metadata
+    }
+
+    /// Remove `import` and `export` declarations, and collect exports.
+    fn transform_statements(
+        metadata: &mut ModuleMetadata<'a>,
+        stmts: ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaVec<'a, Statement<'a>> {
+        let mut new_stmts = ctx.ast.vec_with_capacity(stmts.len());
+        for stmt in stmts {
+            match stmt {
+                Statement::ImportDeclaration(_) => {}
+                Statement::ExportAllDeclaration(decl) => {
+                    metadata.has_exports = true;
+                    if let Some(exported) = &decl.exported {
+                        // `export * as ns from "source"`
+                        let binding = metadata.sources[&decl.source.value].binding.clone().unwrap();
+                        metadata.add_reexport(exported.name(), binding, None);
+                    }
+                }
+                Statement::ExportNamedDeclaration(decl) => {
+                    metadata.has_exports = true;
+                    let ExportNamedDeclaration { declaration, specifiers, source, .. } =
+                        decl.unbox();
+                    if let Some(declaration) = declaration {
+                        // `export const x = 1;`
+                        match &declaration {
+                            Declaration::VariableDeclaration(decl) => {
+                                decl.bound_names(&mut |ident| {
+                                    let binding = BoundIdentifier::from_binding_ident(ident);
+                                    metadata.add_export(binding, ident.name);
+                                });
+                            }
+                            Declaration::FunctionDeclaration(func) => {
+                                let ident = func.id.as_ref().unwrap();
+                                metadata.add_export(
+                                    BoundIdentifier::from_binding_ident(ident),
+                                    ident.name,
+                                );
+                            }
+                            Declaration::ClassDeclaration(class) => {
+                                let ident = class.id.as_ref().unwrap();
+                                metadata.add_export(
+                                    BoundIdentifier::from_binding_ident(ident),
+                                    ident.name,
+                                );
+                            }
+                            _ => {}
+                        }
+                        new_stmts.push(Statement::from(declaration));
+                    } else if let Some(source) = source {
+                        // `export { x as y } from "source";`
+                        for specifier in specifiers {
+                            let (binding, property) =
+                                metadata.get_imported(&source.value, specifier.local.name());
+                            metadata.add_reexport(specifier.exported.name(), binding, property);
+                        }
+                    } else {
+                        // `export { x as y };`
+                        for specifier in specifiers {
+                            let ModuleExportName::IdentifierReference(ident) = &specifier.local
+                            else {
+                                unreachable!()
+                            };
+                            let symbol_id =
+                                ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+                            ctx.delete_reference_for_identifier(ident);
+                            let Some(symbol_id) = symbol_id else { continue };
+                            let exported = specifier.exported.name();
+                            if let Some((binding, property)) = metadata.imports.get(&symbol_id) {
+                                // Re-export of an imported binding
+                                let (binding, property) = (binding.clone(), *property);
+                                metadata.add_reexport(exported, binding, property);
+                            } else {
+                                metadata.add_export(
+                                    BoundIdentifier::new(ident.name, symbol_id),
+                                    exported,
+                                );
+                            }
+                        }
+                    }
+                }
+                Statement::ExportDefaultDeclaration(decl) => {
+                    metadata.has_exports = true;
+                    let ExportDefaultDeclaration { span, declaration, .. } = decl.unbox();
+                    match declaration {
+                        ExportDefaultDeclarationKind::FunctionDeclaration(mut func) => {
+                            // `export default function() {}` -> `function _default() {}`
+                            let binding = if let Some(id) = &func.id {
+                                BoundIdentifier::from_binding_ident(id)
+                            } else {
+                                let binding = ctx
+                                    .generate_uid_in_root_scope("default", SymbolFlags::Function);
+                                func.id = Some(binding.create_binding_identifier(ctx));
+                                binding
+                            };
+                            metadata.add_export(binding, DEFAULT);
+                            new_stmts.push(Statement::FunctionDeclaration(func));
+                        }
+                        ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                            // `export default class {}` -> `class _default {}`
+                            let binding = if let Some(id) = &class.id {
+                                BoundIdentifier::from_binding_ident(id)
+                            } else {
+                                let binding =
+                                    ctx.generate_uid_in_root_scope("default", SymbolFlags::Class);
+                                class.id = Some(binding.create_binding_identifier(ctx));
+                                binding
+                            };
+                            metadata.add_export(binding, DEFAULT);
+                            new_stmts.push(Statement::ClassDeclaration(class));
+                        }
+                        ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => {}
+                        expr @ match_expression!(ExportDefaultDeclarationKind) => {
+                            // `export default 1` -> `exports.default = 1`
+                            metadata.has_default_expression = true;
+                            metadata.export_names.push(DEFAULT);
+                            let expr =
+                                create_exports_assignment(&[DEFAULT], expr.into_expression(), ctx);
+                            new_stmts.push(ctx.ast.statement_expression(span, expr));
+                        }
+                    }
+                }
+                stmt => new_stmts.push(stmt),
+            }
+        }
+        new_stmts
+    }
+
+    /// `Object.defineProperty(exports, "__esModule", { value: true });`
+    fn create_es_module_marker(ctx: &mut TraverseCtx<'a>) -> Statement<'a> {
+        let value = ctx.ast.expression_boolean_literal(SPAN, true);
+        let descriptor = ctx.ast.vec1(create_object_property("value", value, ctx));
+        let name = ctx.ast.expression_string_literal(SPAN, "__esModule", None);
+        create_define_property(name, descriptor, ctx)
+    }
+
+    /// `var _exportNames = { foo: true, bar: true };`
+    ///
+    /// Only created if there is `export * from "source"`, so that the names exported by this module
+    /// are not overwritten by `export *`.
+    fn create_export_names(
+        metadata: &ModuleMetadata<'a>,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<BoundIdentifier<'a>> {
+        if metadata.export_names.is_empty()
+            || !metadata.sources.values().any(|source| source.reexport_all)
+        {
+            return None;
+        }
+
+        let properties = ctx.ast.vec_from_iter(
+            metadata.export_names.iter().filter(|&&name| name != DEFAULT).map(|&name| {
+                let key = if is_identifier_name(&name) {
+                    ctx.ast.property_key_static_identifier(SPAN, name)
+                } else {
+                    PropertyKey::StringLiteral(ctx.ast.alloc_string_literal(SPAN, name, None))
+                };
+                let value = ctx.ast.expression_boolean_literal(SPAN, true);
+                ctx.ast.object_property_kind_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    key,
+                    value,
+                    false,
+                    false,
+                    false,
+                )
+            }),
+        );
+        let binding =
+            ctx.generate_uid_in_root_scope("exportNames", SymbolFlags::FunctionScopedVariable);
+        let init = ctx.ast.expression_object(SPAN, properties);
+        stmts.push(create_var_declaration(&binding, SPAN, init, ctx));
+        Some(binding)
+    }
+
+    /// Initialize exports at top of program.
+    ///
+    /// * Hoisted functions: `exports.foo = foo;`
+    /// * Re-exports: `Object.defineProperty(exports, "foo", { enumerable: true, get: ... });`
+    /// * Other exports: `exports.foo = exports.bar = void 0;`
+    ///
+    /// Statements are sorted by exported name, as Babel does.
+    fn create_export_initializers(
+        metadata: &mut ModuleMetadata<'a>,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let root_scope_id = ctx.scoping().root_scope_id();
+        let mut initializers = vec![];
+        for (binding, names) in metadata.exports.values() {
+            let symbol_id = binding.symbol_id;
+            let is_hoisted = ctx.scoping().symbol_flags(symbol_id).is_function()
+                && ctx.scoping().symbol_scope_id(symbol_id) == root_scope_id;
+            if is_hoisted {
+                let value = binding.create_read_expression(ctx);
+                let expr = create_exports_assignment(names, value, ctx);
+                initializers.push((names[0], Some(ctx.ast.statement_expression(SPAN, expr))));
+            } else {
+                initializers.extend(names.iter().map(|&name| (name, None)));
+            }
+        }
+        if metadata.has_default_expression {
+            initializers.push((DEFAULT, None));
+        }
+        for (exported, binding, property) in metadata.reexports.drain(..) {
+            let object = binding.create_read_expression(ctx);
+            let value = match property {
+                Some(property) => create_member(object, property, ctx),
+                None => object,
+            };
+            let stmt = create_export_getter(exported, value, root_scope_id, ctx);
+            initializers.push((exported, Some(stmt)));
+        }
+
+        initializers.sort_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+
+        let mut uninitialized = vec![];
+        for (index, (name, stmt)) in initializers.into_iter().enumerate() {
+            if index % UNINITIALIZED_CHUNK_SIZE == 0 {
+                Self::flush_uninitialized(&mut uninitialized, stmts, ctx);
+            }
+            if let Some(stmt) = stmt {
+                Self::flush_uninitialized(&mut uninitialized, stmts, ctx);
+                stmts.push(stmt);
+            } else {
+                uninitialized.push(name);
+            }
+        }
+        Self::flush_uninitialized(&mut uninitialized, stmts, ctx);
+    }
+
+    /// `exports.foo = exports.bar = void 0;`
+    fn flush_uninitialized(
+        names: &mut Vec<Atom<'a>>,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if names.is_empty() {
+            return;
+        }
+        let expr = create_exports_assignment(names, ctx.ast.void_0(SPAN), ctx);
+        stmts.push(ctx.ast.statement_expression(SPAN, expr));
+        names.clear();
+    }
+
+    /// `var _foo = require("foo");` for each source, followed by re-exports of `export * from "foo"`.
+    fn create_requires(
+        &self,
+        metadata: &ModuleMetadata<'a>,
+        export_names: Option<&BoundIdentifier<'a>>,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let require_symbol_id = ctx.scoping().get_root_binding("require");
+        for (&source_name, source) in &metadata.sources {
+            let callee = ctx.create_ident_expr(
+                SPAN,
+                Atom::from("require"),
+                require_symbol_id,
+                ReferenceFlags::Read,
+            );
+            let argument = ctx.ast.expression_string_literal(SPAN, source_name, None);
+            let require = ctx.ast.expression_call(
+                SPAN,
+                callee,
+                NONE,
+                ctx.ast.vec1(Argument::from(argument)),
+                false,
+            );
+
+            let Some(binding) = &source.binding else {
+                // `import "foo"` -> `require("foo");`
+                stmts.push(ctx.ast.statement_expression(SPAN, require));
+                continue;
+            };
+
+            let init = match source.interop {
+                Interop::None => require,
+                Interop::Default => {
+                    let arguments = ctx.ast.vec1(Argument::from(require));
+                    self.ctx.helper_call_expr(Helper::InteropRequireDefault, SPAN, arguments, ctx)
+                }
+                Interop::Namespace | Interop::NodeNamespace => {
+                    let mut arguments = ctx.ast.vec1(Argument::from(require));
+                    if source.interop == Interop::NodeNamespace {
+                        arguments
+                            .push(Argument::from(ctx.ast.expression_boolean_literal(SPAN, true)));
+                    }
+                    self.ctx.helper_call_expr(Helper::InteropRequireWildcard, SPAN, arguments, ctx)
+                }
+            };
+            stmts.push(create_var_declaration(binding, source.binding_span, init, ctx));
+
+            if source.reexport_all {
+                stmts.push(Self::create_reexport_all(binding, export_names, ctx));
+            }
+        }
+    }
+
+    /// Re-export all names from a module, except those exported by this module.
+    ///
+    /// ```js
+    /// Object.keys(_foo).forEach(function (key) {
+    ///   if (key === "default" || key === "__esModule") return;
+    ///   if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+    ///   if (key in exports && exports[key] === _foo[key]) return;
+    ///   Object.defineProperty(exports, key, {
+    ///     enumerable: true,
+    ///     get: function () {
+    ///       return _foo[key];
+    ///     }
+    ///   });
+    /// });
+    /// ```
+    fn create_reexport_all(
+        binding: &BoundIdentifier<'a>,
+        export_names: Option<&BoundIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let scope_id = ctx.create_child_scope(ctx.scoping().root_scope_id(), ScopeFlags::Function);
+        let key =
+            ctx.generate_binding(Atom::from("key"), scope_id, SymbolFlags::FunctionScopedVariable);
+
+        let mut body = ctx.ast.vec_with_capacity(4);
+
+        // `if (key === "default" || key === "__esModule") return;`
+        let test = {
+            let is_default = {
+                let right = ctx.ast.expression_string_literal(SPAN, DEFAULT, None);
+                let left = key.create_read_expression(ctx);
+                ctx.ast.expression_binary(SPAN, left, BinaryOperator::StrictEquality, right)
+            };
+            let is_es_module = {
+                let right = ctx.ast.expression_string_literal(SPAN, "__esModule", None);
+                let left = key.create_read_expression(ctx);
+                ctx.ast.expression_binary(SPAN, left, BinaryOperator::StrictEquality, right)
+            };
+            ctx.ast.expression_logical(SPAN, is_default, LogicalOperator::Or, is_es_module)
+        };
+        body.push(create_if_return(test, ctx));
+
+        // `if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;`
+        if let Some(export_names) = export_names {
+            let callee = {
+                let object =
+                    ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+                let object = create_member_callee(object, "prototype", ctx);
+                let object = create_member_callee(object, "hasOwnProperty", ctx);
+                create_member_callee(object, "call", ctx)
+            };
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(export_names.create_read_expression(ctx)),
+                Argument::from(key.create_read_expression(ctx)),
+            ]);
+            let test = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+            body.push(create_if_return(test, ctx));
+        }
+
+        // `if (key in exports && exports[key] === _foo[key]) return;`
+        let test = {
+            let key_in_exports = {
+                let left = key.create_read_expression(ctx);
+                let right = create_exports(ctx);
+                ctx.ast.expression_binary(SPAN, left, BinaryOperator::In, right)
+            };
+            let is_same = {
+                let exports = create_exports(ctx);
+                let left = Expression::from(ctx.ast.member_expression_computed(
+                    SPAN,
+                    exports,
+                    key.create_read_expression(ctx),
+                    false,
+                ));
+                let object = binding.create_read_expression(ctx);
+                let right = Expression::from(ctx.ast.member_expression_computed(
+                    SPAN,
+                    object,
+                    key.create_read_expression(ctx),
+                    false,
+                ));
+                ctx.ast.expression_binary(SPAN, left, BinaryOperator::StrictEquality, right)
+            };
+            ctx.ast.expression_logical(SPAN, key_in_exports, LogicalOperator::And, is_same)
+        };
+        body.push(create_if_return(test, ctx));
+
+        // `Object.defineProperty(exports, key, { enumerable: true, get: function () { return _foo[key]; } });`
+        let value = {
+            let object = binding.create_read_expression(ctx);
+            let property = key.create_read_expression(ctx);
+            Expression::from(ctx.ast.member_expression_computed(SPAN, object, property, false))
+        };
+        let name = key.create_read_expression(ctx);
+        body.push(create_define_property(
+            name,
+            create_getter_descriptor(value, scope_id, ctx),
+            ctx,
+        ));
+
+        // `function (key) { ... }`
+        let callback = {
+            let params = ctx.ast.formal_parameters(
+                SPAN,
+                FormalParameterKind::FormalParameter,
+                ctx.ast.vec1(ctx.ast.plain_formal_parameter(SPAN, key.create_binding_pattern(ctx))),
+                NONE,
+            );
+            let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), body);
+            create_function_expression(params, body, scope_id, ctx)
+        };
+
+        // `Object.keys(_foo).forEach(callback);`
+        let keys = {
+            let object =
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+            let callee = create_member_callee(object, "keys", ctx);
+            let arguments = ctx.ast.vec1(Argument::from(binding.create_read_expression(ctx)));
+            ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+        };
+        let callee = create_member_callee(keys, "forEach", ctx);
+        let arguments = ctx.ast.vec1(Argument::from(callback));
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        ctx.ast.statement_expression(SPAN, call)
+    }
+}
+
+/// Visitor which makes references to imported and exported bindings live.
+///
+/// * `foo` -> `_foo.foo`, where `foo` is imported.
+/// * `x = 1` -> `exports.x = x = 1`, where `x` is exported.
+struct LiveReferencesRewriter<'a, 'ctx, 'm> {
+    imports: &'m FxHashMap<SymbolId, (BoundIdentifier<'a>, Option<Atom<'a>>)>,
+    exports: &'m IndexMap<SymbolId, (BoundIdentifier<'a>, Vec<Atom<'a>>)>,
+    ctx: &'ctx mut TraverseCtx<'a>,
+    transform_ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx, 'm> LiveReferencesRewriter<'a, 'ctx, 'm> {
+    fn new(
+        metadata: &'m ModuleMetadata<'a>,
+        ctx: &'ctx mut TraverseCtx<'a>,
+        transform_ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        Self { imports: &metadata.imports, exports: &metadata.exports, ctx, transform_ctx }
+    }
+}
+
+impl<'a> VisitMut<'a> for LiveReferencesRewriter<'a, '_, '_> {
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        // Exports of bindings declared by classes and destructuring declarations are assigned
+        // after the declaration
+        let mut insertions = vec![];
+        for (index, stmt) in stmts.iter_mut().enumerate() {
+            self.visit_statement(stmt);
+            let assignments = self.create_exports_after_declaration(stmt);
+            if !assignments.is_empty() {
+                insertions.push((index, assignments));
+            }
+        }
+        if insertions.is_empty() {
+            return;
+        }
+
+        let count = insertions.iter().map(|(_, assignments)| assignments.len()).sum::<usize>();
+        let old_stmts = stmts.take_in(self.ctx.ast.allocator);
+        let mut new_stmts = self.ctx.ast.vec_with_capacity(old_stmts.len() + count);
+        let mut insertions = insertions.into_iter().peekable();
+        for (index, stmt) in old_stmts.into_iter().enumerate() {
+            new_stmts.push(stmt);
+            if let Some((_, assignments)) = insertions.next_if(|(i, _)| *i == index) {
+                new_stmts.extend(assignments);
+            }
+        }
+        *stmts = new_stmts;
+    }
+
+    fn visit_expression_statement(&mut self, stmt: &mut ExpressionStatement<'a>) {
+        match stmt.expression.without_parentheses_mut() {
+            // Value of `x++` is unused, so it can be transformed to `exports.x = ++x` without a temp var
+            Expression::UpdateExpression(update) => {
+                if self.get_exported_names(&update.argument).is_some() {
+                    update.prefix = true;
+                }
+            }
+            // Value of `[x] = arr` is unused, so it can be transformed to `[x] = arr, exports.x = x`
+            // without a temp var
+            Expression::AssignmentExpression(assign)
+                if assign.left.is_assignment_target_pattern() =>
+            {
+                let imported_name = self.get_imported_name_in_target(&assign.left);
+                walk_mut::walk_assignment_expression(self, assign);
+                if let Some(name) = imported_name {
+                    self.add_read_only_error(assign, name);
+                    return;
+                }
+                let assignments = self.create_exports_after_destructuring(&assign.left);
+                if !assignments.is_empty() {
+                    let expr = stmt.expression.take_in(self.ctx.ast.allocator);
+                    let mut exprs = self.ctx.ast.vec_with_capacity(assignments.len() + 1);
+                    exprs.push(expr);
+                    exprs.extend(assignments);
+                    stmt.expression = self.ctx.ast.expression_sequence(SPAN, exprs);
+                }
+                return;
+            }
+            _ => {}
+        }
+        walk_mut::walk_expression_statement(self, stmt);
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr {
+            if let Some(new_expr) = self.transform_imported_reference(ident) {
+                *expr = new_expr;
+            }
+            return;
+        }
+
+        // Imported bindings in assignment targets are replaced while walking, so find them first
+        let imported_name = match expr {
+            Expression::AssignmentExpression(assign) => {
+                self.get_imported_name_in_target(&assign.left)
+            }
+            Expression::UpdateExpression(update) => match &update.argument {
+                SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                    self.get_imported(ident).map(|_| ident.name)
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+
+        walk_mut::walk_expression(self, expr);
+
+        if let Some(name) = imported_name {
+            self.transform_imported_assignment(expr, name);
+            return;
+        }
+        match expr {
+            Expression::AssignmentExpression(_) => self.transform_assignment_expression(expr),
+            Expression::UpdateExpression(_) => self.transform_update_expression(expr),
+            _ => {}
+        }
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        let scope_id = stmt.scope_id();
+        let stmts = self.transform_for_statement_left(&mut stmt.left, scope_id);
+        walk_mut::walk_for_in_statement(self, stmt);
+        if let Some(stmts) = stmts {
+            self.insert_into_for_statement_body(&mut stmt.body, stmts, scope_id);
+        }
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        let scope_id = stmt.scope_id();
+        let stmts = self.transform_for_statement_left(&mut stmt.left, scope_id);
+        walk_mut::walk_for_of_statement(self, stmt);
+        if let Some(stmts) = stmts {
+            self.insert_into_for_statement_body(&mut stmt.body, stmts, scope_id);
+        }
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        // `foo = 1` -> `_foo.foo = 1`
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = target {
+            if let Some(new_target) = self.transform_imported_target(ident) {
+                *target = new_target;
+            }
+            return;
+        }
+        walk_mut::walk_simple_assignment_target(self, target);
+    }
+
+    fn visit_assignment_target_property(&mut self, property: &mut AssignmentTargetProperty<'a>) {
+        // `({ foo } = obj)` -> `({ foo: _foo.foo } = obj)`
+        if let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(prop) = property {
+            if let Some(target) = self.transform_imported_target(&prop.binding) {
+                let name = self.ctx.ast.property_key_static_identifier(SPAN, prop.binding.name);
+                let target = AssignmentTarget::from(target);
+                let binding = if let Some(init) = prop.init.take() {
+                    self.ctx.ast.assignment_target_maybe_default_assignment_target_with_default(
+                        SPAN, target, init,
+                    )
+                } else {
+                    AssignmentTargetMaybeDefault::from(target)
+                };
+                *property =
+                    self.ctx.ast.assignment_target_property_assignment_target_property_property(
+                        prop.span, name, binding, false,
+                    );
+            }
+        }
+        walk_mut::walk_assignment_target_property(self, property);
+    }
+
+    fn visit_call_expression(&mut self, call: &mut CallExpression<'a>) {
+        let is_imported_member = self.is_imported_member(&call.callee);
+        walk_mut::walk_call_expression(self, call);
+        if is_imported_member {
+            // `foo()` -> `(0, _foo.foo)()`, so `this` is not the required module
+            call.callee = self.wrap_in_zero_sequence(call.callee.take_in(self.ctx.ast.allocator));
+        }
+    }
+
+    fn visit_tagged_template_expression(&mut self, expr: &mut TaggedTemplateExpression<'a>) {
+        let is_imported_member = self.is_imported_member(&expr.tag);
+        walk_mut::walk_tagged_template_expression(self, expr);
+        if is_imported_member {
+            expr.tag = self.wrap_in_zero_sequence(expr.tag.take_in(self.ctx.ast.allocator));
+        }
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        // `{ foo }` -> `{ foo: _foo.foo }`
+        if prop.shorthand {
+            if let Expression::Identifier(ident) = &prop.value {
+                if self.get_imported(ident).is_some() {
+                    prop.shorthand = false;
+                }
+            }
+        }
+        walk_mut::walk_object_property(self, prop);
+    }
+
+    fn visit_variable_declarator(&mut self, declarator: &mut VariableDeclarator<'a>) {
+        walk_mut::walk_variable_declarator(self, declarator);
+
+        // `let x = 1` -> `let x = exports.x = 1`
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else { return };
+        let Some(init) = &mut declarator.init else { return };
+        let Some((_, names)) = self.exports.get(&ident.symbol_id()) else { return };
+        let value = init.take_in(self.ctx.ast.allocator);
+        *init = create_exports_assignment(names, value, self.ctx);
+    }
+
+    fn visit_jsx_element_name(&mut self, name: &mut JSXElementName<'a>) {
+        // `<Foo />` -> `<_foo.Foo />`
+        if let JSXElementName::IdentifierReference(ident) = name {
+            if let Some((object, property)) = self.transform_imported_jsx_reference(ident) {
+                *name = if let Some(property) = property {
+                    let object = JSXMemberExpressionObject::IdentifierReference(object);
+                    self.ctx.ast.jsx_element_name_member_expression(SPAN, object, property)
+                } else {
+                    JSXElementName::IdentifierReference(object)
+                };
+            }
+            return;
+        }
+        walk_mut::walk_jsx_element_name(self, name);
+    }
+
+    fn visit_jsx_member_expression_object(&mut self, object: &mut JSXMemberExpressionObject<'a>) {
+        // `<Foo.Bar />` -> `<_foo.Foo.Bar />`
+        if let JSXMemberExpressionObject::IdentifierReference(ident) = object {
+            if let Some((new_object, property)) = self.transform_imported_jsx_reference(ident) {
+                let new_object = JSXMemberExpressionObject::IdentifierReference(new_object);
+                *object = if let Some(property) = property {
+                    self.ctx
+                        .ast
+                        .jsx_member_expression_object_member_expression(SPAN, new_object, property)
+                } else {
+                    new_object
+                };
+            }
+            return;
+        }
+        walk_mut::walk_jsx_member_expression_object(self, object);
+    }
+}
+
+impl<'a, 'm> LiveReferencesRewriter<'a, '_, 'm> {
+    fn get_imported(
+        &self,
+        ident: &IdentifierReference<'a>,
+    ) -> Option<&'m (BoundIdentifier<'a>, Option<Atom<'a>>)> {
+        let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        self.imports.get(&symbol_id)
+    }
+
+    /// Get binding and exported names of an exported binding which is assigned to.
+    fn get_exported_names(
+        &self,
+        target: &SimpleAssignmentTarget<'a>,
+    ) -> Option<&'m (BoundIdentifier<'a>, Vec<Atom<'a>>)> {
+        let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = target else {
+            return None;
+        };
+        let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        self.exports.get(&symbol_id)
+    }
+
+    /// Whether `expr` is a reference to an imported binding, which is replaced with a member expression.
+    fn is_imported_member(&self, expr: &Expression<'a>) -> bool {
+        let Expression::Identifier(ident) = expr else { return false };
+        self.get_imported(ident).is_some_and(|(_, property)| property.is_some())
+    }
+
+    /// `foo` -> `_foo.foo`
+    fn transform_imported_reference(
+        &mut self,
+        ident: &IdentifierReference<'a>,
+    ) -> Option<Expression<'a>> {
+        let imports = self.imports;
+        let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        let (binding, property) = imports.get(&symbol_id)?;
+        self.ctx.delete_reference_for_identifier(ident);
+        let object = binding.create_spanned_read_expression(ident.span, self.ctx);
+        Some(match property {
+            Some(property) => create_member(object, *property, self.ctx),
+            None => object,
+        })
+    }
+
+    /// `Foo` -> `_foo.Foo` in JSX element names.
+    ///
+    /// Returns the object, and the property if the imported binding is a property of the module.
+    fn transform_imported_jsx_reference(
+        &mut self,
+        ident: &IdentifierReference<'a>,
+    ) -> Option<(Box<'a, IdentifierReference<'a>>, Option<JSXIdentifier<'a>>)> {
+        let imports = self.imports;
+        let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        let (binding, property) = imports.get(&symbol_id)?;
+        // Properties which are not valid identifiers cannot be accessed in JSX element names
+        if property.is_some_and(|property| !is_identifier_name(&property)) {
+            return None;
+        }
+        self.ctx.delete_reference_for_identifier(ident);
+        let reference = binding.create_spanned_read_reference(ident.span, self.ctx);
+        let object = self.ctx.alloc(reference);
+        let property = property.map(|property| self.ctx.ast.jsx_identifier(SPAN, property));
+        Some((object, property))
+    }
+
+    /// `(0, expr)`
+    fn wrap_in_zero_sequence(&self, expr: Expression<'a>) -> Expression<'a> {
+        let zero = self.ctx.ast.number_0();
+        self.ctx.ast.expression_sequence(SPAN, self.ctx.ast.vec_from_array([zero, expr]))
+    }
+
+    /// `foo` -> `_foo.foo` in assignment targets.
+    fn transform_imported_target(
+        &mut self,
+        ident: &IdentifierReference<'a>,
+    ) -> Option<SimpleAssignmentTarget<'a>> {
+        let imports = self.imports;
+        let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        let (binding, property) = imports.get(&symbol_id)?;
+        self.ctx.delete_reference_for_identifier(ident);
+        Some(match property {
+            Some(property) => {
+                let object = binding.create_spanned_read_expression(ident.span, self.ctx);
+                SimpleAssignmentTarget::from(create_member_expression(object, *property, self.ctx))
+            }
+            None => binding.create_spanned_write_simple_target(ident.span, self.ctx),
+        })
+    }
+
+    /// * `foo = 1` -> `_foo.foo = (1, _readOnlyError("foo"))`
+    /// * `foo++` -> `_foo.foo += _readOnlyError("foo")`
+    ///
+    /// where `foo` is imported, and has been replaced in the assignment target already.
+    fn transform_imported_assignment(&mut self, expr: &mut Expression<'a>, name: Atom<'a>) {
+        match expr {
+            Expression::AssignmentExpression(assign) => self.add_read_only_error(assign, name),
+            Expression::UpdateExpression(update) => {
+                let operator = match update.operator {
+                    UpdateOperator::Increment => AssignmentOperator::Addition,
+                    UpdateOperator::Decrement => AssignmentOperator::Subtraction,
+                };
+                let target =
+                    AssignmentTarget::from(update.argument.take_in(self.ctx.ast.allocator));
+                let error = self.create_read_only_error(name);
+                *expr = self.ctx.ast.expression_assignment(update.span, operator, target, error);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// `[_foo.foo] = arr` -> `[_foo.foo] = (arr, _readOnlyError("foo"))`
+    fn add_read_only_error(&mut self, assign: &mut AssignmentExpression<'a>, name: Atom<'a>) {
+        let value = assign.right.take_in(self.ctx.ast.allocator);
+        let error = self.create_read_only_error(name);
+        assign.right =
+            self.ctx.ast.expression_sequence(SPAN, self.ctx.ast.vec_from_array([value, error]));
+    }
+
+    /// Name of the first imported binding which is assigned to in `target`.
+    fn get_imported_name_in_target(&self, target: &AssignmentTarget<'a>) -> Option<Atom<'a>> {
+        let mut collector = AssignedIdentifiersCollector::default();
+        collector.visit_assignment_target(target);
+        collector.references.into_iter().find_map(|(reference_id, name)| {
+            let symbol_id = self.ctx.scoping().get_reference(reference_id).symbol_id()?;
+            self.imports.contains_key(&symbol_id).then_some(name)
+        })
+    }
+
+    /// `_readOnlyError("foo")`
+    fn create_read_only_error(&mut self, name: Atom<'a>) -> Expression<'a> {
+        let message = self.ctx.ast.expression_string_literal(SPAN, name, None);
+        let arguments = self.ctx.ast.vec1(Argument::from(message));
+        self.transform_ctx.helper_call_expr(Helper::ReadOnlyError, SPAN, arguments, self.ctx)
+    }
+
+    /// * `for (x of xs) {}` -> `for (let _x of xs) { exports.x = x = _x; }`, where `x` is exported
+    /// * `for (foo of xs) {}` -> `for (let _foo of xs) { _readOnlyError("foo"); }`, where `foo` is imported
+    ///
+    /// Returns the statements to insert at start of the loop body, if the loop head is replaced.
+    fn transform_for_statement_left(
+        &mut self,
+        left: &mut ForStatementLeft<'a>,
+        scope_id: ScopeId,
+    ) -> Option<Vec<Statement<'a>>> {
+        let target = left.as_assignment_target()?;
+
+        let mut collector = AssignedIdentifiersCollector::default();
+        collector.visit_assignment_target(target);
+        let mut has_exports = false;
+        for (reference_id, _) in &collector.references {
+            let Some(symbol_id) = self.ctx.scoping().get_reference(*reference_id).symbol_id()
+            else {
+                continue;
+            };
+            has_exports |= self.exports.contains_key(&symbol_id);
+        }
+        let imported_name = self.get_imported_name_in_target(target);
+        if !has_exports && imported_name.is_none() {
+            return None;
+        }
+
+        // `for (let _x of xs)`
+        let binding =
+            self.ctx.generate_uid_based_on_node(target, scope_id, SymbolFlags::BlockScopedVariable);
+        let id = binding.create_binding_pattern(self.ctx);
+        let kind = VariableDeclarationKind::Let;
+        let declarator = self.ctx.ast.variable_declarator(SPAN, kind, id, None, false);
+        let declaration = self.ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            self.ctx.ast.vec1(declarator),
+            false,
+        );
+        let target = std::mem::replace(left, ForStatementLeft::VariableDeclaration(declaration))
+            .into_assignment_target();
+
+        let mut stmts = vec![];
+        if let Some(name) = imported_name {
+            let error = self.create_read_only_error(name);
+            stmts.push(self.ctx.ast.statement_expression(SPAN, error));
+        }
+        if has_exports {
+            // `x = _x;`, which is transformed to `exports.x = x = _x;`
+            let value = binding.create_read_expression(self.ctx);
+            let expr =
+                self.ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+            let mut stmt = self.ctx.ast.expression_statement(SPAN, expr);
+            self.visit_expression_statement(&mut stmt);
+            stmts.push(Statement::ExpressionStatement(self.ctx.ast.alloc(stmt)));
+        } else {
+            // Only imported bindings are assigned, and the loop body throws before the assignment
+            for (reference_id, name) in collector.references {
+                self.ctx.delete_reference(reference_id, &name);
+            }
+        }
+        Some(stmts)
+    }
+
+    /// Insert `stmts` at start of the body of a `for-in` / `for-of` statement.
+    fn insert_into_for_statement_body(
+        &mut self,
+        body: &mut Statement<'a>,
+        stmts: Vec<Statement<'a>>,
+        scope_id: ScopeId,
+    ) {
+        if !matches!(body, Statement::BlockStatement(_)) {
+            let block_scope_id = self.ctx.insert_scope_below_statement_from_scope_id(
+                body,
+                scope_id,
+                ScopeFlags::empty(),
+            );
+            let span = body.span();
+            let block_body = if matches!(body, Statement::EmptyStatement(_)) {
+                self.ctx.ast.vec()
+            } else {
+                self.ctx.ast.vec1(body.take_in(self.ctx.ast.allocator))
+            };
+            *body = self.ctx.ast.statement_block_with_scope_id(span, block_body, block_scope_id);
+        }
+        let Statement::BlockStatement(block) = body else { unreachable!() };
+        block.body.splice(0..0, stmts);
+    }
+
+    /// * `x = 1` -> `exports.x = x = 1`
+    /// * `[x] = arr` -> `(_temp = [x] = arr, exports.x = x, _temp)`
+    fn transform_assignment_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::AssignmentExpression(assign) = expr else { unreachable!() };
+        match &assign.left {
+            AssignmentTarget::AssignmentTargetIdentifier(_) => {
+                let Some(target) = assign.left.as_simple_assignment_target() else { return };
+                let Some((_, names)) = self.get_exported_names(target) else { return };
+                let value = expr.take_in(self.ctx.ast.allocator);
+                *expr = create_exports_assignment(names, value, self.ctx);
+            }
+            match_assignment_target_pattern!(AssignmentTarget) => {
+                let assignments = self.create_exports_after_destructuring(&assign.left);
+                if assignments.is_empty() {
+                    return;
+                }
+                let temp = self.transform_ctx.var_declarations.create_uid_var("temp", self.ctx);
+                let value = expr.take_in(self.ctx.ast.allocator);
+                let mut exprs = self.ctx.ast.vec_with_capacity(assignments.len() + 2);
+                exprs.push(self.ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    temp.create_write_target(self.ctx),
+                    value,
+                ));
+                exprs.extend(assignments);
+                exprs.push(temp.create_read_expression(self.ctx));
+                *expr = self.ctx.ast.expression_sequence(SPAN, exprs);
+            }
+            _ => {}
+        }
+    }
+
+    /// * `++x` -> `exports.x = ++x`
+    /// * `x++` -> `(_x = x++, exports.x = x, _x)`
+    fn transform_update_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::UpdateExpression(update) = expr else { unreachable!() };
+        let Some((binding, names)) = self.get_exported_names(&update.argument) else { return };
+        let prefix = update.prefix;
+        let value = expr.take_in(self.ctx.ast.allocator);
+        if prefix {
+            *expr = create_exports_assignment(names, value, self.ctx);
+        } else {
+            let temp = self.transform_ctx.var_declarations.create_uid_var(&binding.name, self.ctx);
+            let old_value = self.ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                temp.create_write_target(self.ctx),
+                value,
+            );
+            let new_value = binding.create_read_expression(self.ctx);
+            let export = create_exports_assignment(names, new_value, self.ctx);
+            let result = temp.create_read_expression(self.ctx);
+            *expr = self.ctx.ast.expression_sequence(
+                SPAN,
+                self.ctx.ast.vec_from_array([old_value, export, result]),
+            );
+        }
+    }
+
+    /// `exports.x = x` for each exported binding assigned in a destructuring assignment.
+    fn create_exports_after_destructuring(
+        &mut self,
+        target: &AssignmentTarget<'a>,
+    ) -> Vec<Expression<'a>> {
+        let mut collector = AssignedIdentifiersCollector::default();
+        collector.visit_assignment_target(target);
+
+        let mut symbol_ids = vec![];
+        for (reference_id, _) in collector.references {
+            let Some(symbol_id) = self.ctx.scoping().get_reference(reference_id).symbol_id() else {
+                continue;
+            };
+            if self.exports.contains_key(&symbol_id) && !symbol_ids.contains(&symbol_id) {
+                symbol_ids.push(symbol_id);
+            }
+        }
+        self.create_exports_assignments(symbol_ids)
+    }
+
+    /// `exports.x = x;` after class declarations and destructuring variable declarations
+    /// which declare exported bindings.
+    fn create_exports_after_declaration(&mut self, stmt: &Statement<'a>) -> Vec<Statement<'a>> {
+        let mut symbol_ids = vec![];
+        match stmt {
+            Statement::ClassDeclaration(class) => {
+                if let Some(id) = &class.id {
+                    symbol_ids.push(id.symbol_id());
+                }
+            }
+            Statement::VariableDeclaration(decl) => {
+                for declarator in &decl.declarations {
+                    if !declarator.id.kind.is_binding_identifier() {
+                        declarator.id.bound_names(&mut |ident| symbol_ids.push(ident.symbol_id()));
+                    }
+                }
+            }
+            _ => {}
+        }
+        symbol_ids.retain(|symbol_id| self.exports.contains_key(symbol_id));
+        self.create_exports_assignments(symbol_ids)
+            .into_iter()
+            .map(|expr| self.ctx.ast.statement_expression(SPAN, expr))
+            .collect()
+    }
+
+    fn create_exports_assignments(&mut self, symbol_ids: Vec<SymbolId>) -> Vec<Expression<'a>> {
+        let exports = self.exports;
+        symbol_ids
+            .into_iter()
+            .map(|symbol_id| {
+                let (binding, names) = &exports[&symbol_id];
+                let value = binding.create_read_expression(self.ctx);
+                create_exports_assignment(names, value, self.ctx)
+            })
+            .collect()
+    }
+}
+
+/// Visitor which replaces top level `this` with `void 0`.
+struct TopLevelThisRewriter<'a, 'ctx> {
+    ctx: &'ctx TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for TopLevelThisRewriter<'a, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::ThisExpression(this) = expr {
+            *expr = self.ctx.ast.void_0(this.span);
+            return;
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    // `this` in non-arrow functions is not the top level `this`
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    // `this` in class bodies refers to the class or its instances, except in computed keys
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        self.visit_decorators(&mut class.decorators);
+        if let Some(super_class) = &mut class.super_class {
+            self.visit_expression(super_class);
+        }
+        for element in &mut class.body.body {
+            match element {
+                ClassElement::MethodDefinition(method) => {
+                    self.visit_decorators(&mut method.decorators);
+                    self.visit_property_key(&mut method.key);
+                }
+                ClassElement::PropertyDefinition(prop) => {
+                    self.visit_decorators(&mut prop.decorators);
+                    self.visit_property_key(&mut prop.key);
+                }
+                ClassElement::AccessorProperty(prop) => {
+                    self.visit_decorators(&mut prop.decorators);
+                    self.visit_property_key(&mut prop.key);
+                }
+                ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => {}
+            }
+        }
+    }
+}
+
+/// Collects references to identifiers which are assigned to in an assignment target.
+#[derive(Default)]
+struct AssignedIdentifiersCollector<'a> {
+    references: Vec<(ReferenceId, Atom<'a>)>,
+}
+
+impl<'a> Visit<'a> for AssignedIdentifiersCollector<'a> {
+    fn visit_simple_assignment_target(&mut self, target: &SimpleAssignmentTarget<'a>) {
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = target {
+            self.references.push((ident.reference_id(), ident.name));
+        }
+    }
+
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        property: &AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        self.references.push((property.binding.reference_id(), property.binding.name));
+    }
+
+    // Default values and computed keys are not assigned to
+    fn visit_expression(&mut self, _expr: &Expression<'a>) {}
+}
+
+/// `exports`
+fn create_exports<'a>(ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    ctx.create_unbound_ident_expr(SPAN, EXPORTS, ReferenceFlags::Read)
+}
+
+/// `object.property` or `object["property"]`
+fn create_member_expression<'a>(
+    object: Expression<'a>,
+    property: Atom<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> MemberExpression<'a> {
+    if is_identifier_name(&property) {
+        let property = ctx.ast.identifier_name(SPAN, property);
+        ctx.ast.member_expression_static(SPAN, object, property, false)
+    } else {
+        let property = ctx.ast.expression_string_literal(SPAN, property, None);
+        ctx.ast.member_expression_computed(SPAN, object, property, false)
+    }
+}
+
+/// `object.property` or `object["property"]`
+fn create_member<'a>(
+    object: Expression<'a>,
+    property: Atom<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    Expression::from(create_member_expression(object, property, ctx))
+}
+
+/// `exports.bar = exports.foo = value`
+fn create_exports_assignment<'a>(
+    names: &[Atom<'a>],
+    value: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    names.iter().fold(value, |value, &name| {
+        let member = create_member_expression(create_exports(ctx), name, ctx);
+        let target = AssignmentTarget::from(SimpleAssignmentTarget::from(member));
+        ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+    })
+}
+
+/// `Object.defineProperty(exports, name, { ...descriptor });`
+fn create_define_property<'a>(
+    name: Expression<'a>,
+    descriptor: ArenaVec<'a, ObjectPropertyKind<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let object = ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+    let callee = create_member_callee(object, "defineProperty", ctx);
+    let arguments = ctx.ast.vec_from_array([
+        Argument::from(create_exports(ctx)),
+        Argument::from(name),
+        Argument::from(ctx.ast.expression_object(SPAN, descriptor)),
+    ]);
+    let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+    ctx.ast.statement_expression(SPAN, call)
+}
+
+/// `Object.defineProperty(exports, "name", { enumerable: true, get: function () { return value; } });`
+fn create_export_getter<'a>(
+    name: Atom<'a>,
+    value: Expression<'a>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let descriptor = create_getter_descriptor(value, parent_scope_id, ctx);
+    let name = ctx.ast.expression_string_literal(SPAN, name, None);
+    create_define_property(name, descriptor, ctx)
+}
+
+/// `{ enumerable: true, get: function () { return value; } }`
+///
+/// A new scope is created for the getter function, as a child of `parent_scope_id`.
+fn create_getter_descriptor<'a>(
+    value: Expression<'a>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> ArenaVec<'a, ObjectPropertyKind<'a>> {
+    let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::Function);
+    let params =
+        ctx.ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, ctx.ast.vec(), NONE);
+    let body = ctx.ast.function_body(
+        SPAN,
+        ctx.ast.vec(),
+        ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(value))),
+    );
+    let getter = create_function_expression(params, body, scope_id, ctx);
+    let enumerable = ctx.ast.expression_boolean_literal(SPAN, true);
+    ctx.ast.vec_from_array([
+        create_object_property("enumerable", enumerable, ctx),
+        create_object_property("get", getter, ctx),
+    ])
+}
+
+/// `function (params) { body }`
+fn create_function_expression<'a>(
+    params: FormalParameters<'a>,
+    body: FunctionBody<'a>,
+    scope_id: ScopeId,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    Expression::FunctionExpression(ctx.ast.alloc_plain_function_with_scope_id(
+        FunctionType::FunctionExpression,
+        SPAN,
+        None,
+        params,
+        body,
+        scope_id,
+    ))
+}
+
+/// `key: value`
+fn create_object_property<'a>(
+    key: &'static str,
+    value: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> ObjectPropertyKind<'a> {
+    let key = ctx.ast.property_key_static_identifier(SPAN, key);
+    ctx.ast.object_property_kind_object_property(
+        SPAN,
+        PropertyKind::Init,
+        key,
+        value,
+        false,
+        false,
+        false,
+    )
+}
+
+/// `if (test) return;`
+fn create_if_return<'a>(test: Expression<'a>, ctx: &TraverseCtx<'a>) -> Statement<'a> {
+    ctx.ast.statement_if(SPAN, test, ctx.ast.statement_return(SPAN, None), None)
+}
+
+/// `var binding = init;`
+///
+/// `span` is the span of the binding identifier.
+fn create_var_declaration<'a>(
+    binding: &BoundIdentifier<'a>,
+    span: Span,
+    init: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    let kind = VariableDeclarationKind::Var;
+    let id = ctx.ast.binding_pattern(
+        ctx.ast.binding_pattern_kind_binding_identifier_with_symbol_id(
+            span,
+            binding.name,
+            binding.symbol_id,
+        ),
+        NONE,
+        false,
+    );
+    let declarator = ctx.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        ctx.ast.vec1(declarator),
+        false,
+    ))
+}
@@ -0,0 +1,43 @@
+use serde::Deserialize;
+
+/// Options for transforming ES modules to CommonJS.
+///
+/// <https://babel.dev/docs/babel-plugin-transform-modules-commonjs#options>
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CommonJsOptions {
+    /// How imports are interoperated with CommonJS modules.
+    ///
+    /// <https://babel.dev/docs/babel-plugin-transform-modules-commonjs#importinterop>
+    pub import_interop: ImportInterop,
+
+    /// Do not mark the module with `exports.__esModule`.
+    ///
+    /// <https://babel.dev/docs/babel-plugin-transform-modules-commonjs#strict>
+    pub strict: bool,
+
+    /// Insert `"use strict"` directive. Defaults to `true`.
+    ///
+    /// <https://babel.dev/docs/babel-plugin-transform-modules-commonjs#strictmode>
+    pub strict_mode: bool,
+}
+
+impl Default for CommonJsOptions {
+    fn default() -> Self {
+        Self { import_interop: ImportInterop::default(), strict: false, strict_mode: true }
+    }
+}
+
+/// <https://babel.dev/docs/babel-plugin-transform-modules-commonjs#importinterop>
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportInterop {
+    /// Treat modules with `exports.__esModule` as ES modules, and others as CommonJS modules
+    /// whose `module.exports` is the default export.
+    #[default]
+    Babel,
+    /// Behave like Node.js importing CommonJS modules: `module.exports` is always the default export.
+    Node,
+    /// Do not wrap `require` calls with any interop helper.
+    None,
+}
@@ -68,6 +68,13 @@ impl TransformCtx<'_> {
         }
     }
 
+    /// Whether modules are imported with `require` calls instead of `import` statements.
+    ///
+    /// This is the case when source is a script, or when ES modules are transformed to CommonJS.
+    pub fn use_require(&self) -> bool {
+        self.source_type.is_script() || self.module.is_commonjs()
+    }
+
     pub fn take_errors(&self) -> Vec<OxcDiagnostic> {
         mem::take(&mut self.errors.borrow_mut())
     }
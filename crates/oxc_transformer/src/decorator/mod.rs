@@ -1,5 +1,6 @@
 mod legacy;
 mod options;
+mod standard;
 
 use oxc_ast::ast::*;
 use oxc_traverse::{Traverse, TraverseCtx};
@@ -8,41 +9,65 @@ use crate::TransformCtx;
 
 use legacy::LegacyDecorator;
 pub use options::DecoratorOptions;
+use standard::StandardDecorator;
 
 pub struct Decorator<'a, 'ctx> {
     options: DecoratorOptions,
 
     // Plugins
-    legacy_decorator: LegacyDecorator<'a, 'ctx>,
+    legacy: LegacyDecorator<'a, 'ctx>,
+    standard: StandardDecorator<'a, 'ctx>,
 }
 
 impl<'a, 'ctx> Decorator<'a, 'ctx> {
     pub fn new(options: DecoratorOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
         Self {
-            legacy_decorator: LegacyDecorator::new(options.emit_decorator_metadata, ctx),
+            legacy: LegacyDecorator::new(options.emit_decorator_metadata, ctx),
+            standard: StandardDecorator::new(ctx),
             options,
         }
     }
 }
 
+impl Decorator<'_, '_> {
+    #[inline]
+    fn is_standard(&self) -> bool {
+        !self.options.legacy && self.options.standard
+    }
+}
+
 impl<'a> Traverse<'a> for Decorator<'a, '_> {
+    #[inline]
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.is_standard() {
+            self.standard.enter_statement(stmt, ctx);
+        }
+    }
+
+    #[inline]
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.is_standard() {
+            self.standard.enter_expression(expr, ctx);
+        }
+    }
+
     fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.legacy {
-            self.legacy_decorator.exit_statement(stmt, ctx);
+            self.legacy.exit_statement(stmt, ctx);
         }
     }
 
     #[inline]
     fn enter_class(&mut self, node: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.legacy {
-            self.legacy_decorator.enter_class(node, ctx);
+            self.legacy.enter_class(node, ctx);
         }
     }
 
     #[inline]
     fn exit_class(&mut self, node: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.legacy {
-            self.legacy_decorator.exit_class(node, ctx);
+            self.legacy.exit_class(node, ctx);
         }
     }
 
@@ -53,7 +78,7 @@ impl<'a> Traverse<'a> for Decorator<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.enter_method_definition(node, ctx);
+            self.legacy.enter_method_definition(node, ctx);
         }
     }
 
@@ -64,7 +89,7 @@ impl<'a> Traverse<'a> for Decorator<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.enter_accessor_property(node, ctx);
+            self.legacy.enter_accessor_property(node, ctx);
         }
     }
 
@@ -75,7 +100,7 @@ impl<'a> Traverse<'a> for Decorator<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.enter_property_definition(node, ctx);
+            self.legacy.enter_property_definition(node, ctx);
         }
     }
 }
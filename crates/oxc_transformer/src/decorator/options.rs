@@ -12,6 +12,15 @@ pub struct DecoratorOptions {
     #[serde(skip)]
     pub legacy: bool,
 
+    /// Enables the transform of TC39 standard decorators, following the 2023-05 version of the proposal.
+    ///
+    /// This is the version of decorators which TypeScript 5 implements when `experimentalDecorators`
+    /// is not enabled. It is ignored when `legacy` is true.
+    ///
+    /// <https://babeljs.io/docs/babel-plugin-proposal-decorators#version>
+    #[serde(skip)]
+    pub standard: bool,
+
     /// Enables emitting decorator metadata.
     ///
     /// This option the same as [emitDecoratorMetadata](https://www.typescriptlang.org/tsconfig/#emitDecoratorMetadata)
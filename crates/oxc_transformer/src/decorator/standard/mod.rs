@@ -0,0 +1,1446 @@
+//! Standard decorators (2023-05)
+//!
+//! This plugin transforms TC39 standard decorators by calling the `_applyDecs2305` helper
+//! to apply decorators.
+//!
+//! ## Examples
+//!
+//! Input:
+//! ```js
+//! @dec
+//! class Class {
+//!   @dec
+//!   prop = 0;
+//!
+//!   @dec
+//!   accessor value = 1;
+//!
+//!   @dec
+//!   method() {}
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _Class, _initClass, _init_prop, _init_value, _initProto;
+//! let Class = (class Class {
+//!   static {
+//!     ({
+//!       e: [_init_value, _init_prop, _initProto],
+//!       c: [_Class, _initClass],
+//!     } = _applyDecs2305(this, [[dec, 1, "value"], [dec, 2, "method"], [dec, 0, "prop"]], [dec]));
+//!   }
+//!   prop = (_initProto(this), _init_prop(this, 0));
+//!   #A = _init_value(this, 1);
+//!   get value() { return this.#A; }
+//!   set value(v) { this.#A = v; }
+//!   method() {}
+//!   static {
+//!     _initClass();
+//!   }
+//! }, _Class);
+//! ```
+//!
+//! Decorator expressions and computed keys are evaluated before the class, in the order they
+//! appear in. All decorators are then applied by a static block at the start of the class body,
+//! which stores the functions returned by `_applyDecs2305` in the bindings used by class elements:
+//!
+//! * `_init_x(this, value)` runs initializers returned by field and accessor decorators.
+//! * `_initProto(this)` and `_initStatic(this)` run initializers added with
+//!   `context.addInitializer`.
+//! * `_initClass()` runs initializers added by class decorators, once the class is defined.
+//!
+//! Private elements are exposed to the helper by functions defined inside the class body.
+//! A private method is moved into the static block, and replaced with a getter returning
+//! the decorated method.
+//!
+//! `accessor` fields are always transformed, whether decorated or not, into a private field
+//! plus a getter and setter.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-proposal-decorators](https://github.com/babel/babel/blob/v7.26.2/packages/babel-helper-create-class-features-plugin/src/decorators.ts),
+//! with `version: "2023-05"`.
+//!
+//! Known limitations:
+//! * `super` inside a decorated private method is not supported, as the method is moved out of
+//!   the class body.
+//! * Decorator expressions are evaluated before the class, so they cannot reference private
+//!   names of the class they decorate.
+//!
+//! ## References:
+//! * TC39 proposal: <https://github.com/tc39/proposal-decorators>
+//! * Babel docs: <https://babeljs.io/docs/babel-plugin-proposal-decorators>
+
+use oxc_allocator::{Box as ArenaBox, CloneIn, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_syntax::{
+    identifier::is_identifier_name, node::NodeId, number::NumberBase, operator::AssignmentOperator,
+    reference::ReferenceFlags,
+};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+use rustc_hash::FxHashSet;
+
+use crate::{Helper, TransformCtx};
+
+/// Kind of a decorated class element, as expected by the `_applyDecs2305` helper.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ElementKind {
+    Field = 0,
+    Accessor = 1,
+    Method = 2,
+    Getter = 3,
+    Setter = 4,
+}
+
+/// Flag added to [`ElementKind`] for static elements.
+const STATIC_FLAG: u8 = 8;
+/// Flag added to [`ElementKind`] when decorators are passed along with their `this` values.
+const DECORATORS_HAVE_THIS_FLAG: u8 = 16;
+
+/// A memoized decorator, and the `this` value it is called with.
+///
+/// `@obj.dec` is called with `obj` as `this`, same as `obj.dec()`.
+struct DecoratorValue<'a> {
+    this: Option<Expression<'a>>,
+    decorator: Expression<'a>,
+}
+
+/// A decorated class element, which becomes an entry of the array of element decorators
+/// passed to `_applyDecs2305`.
+struct DecoratedElement<'a> {
+    kind: ElementKind,
+    is_static: bool,
+    decorators: Vec<DecoratorValue<'a>>,
+    /// Name of element. Private names are passed without `#`.
+    name: Expression<'a>,
+    /// Private elements only.
+    /// Functions getting and setting the value of a field or accessor, or the method itself.
+    functions: Vec<Expression<'a>>,
+    /// Bindings receiving the functions which `_applyDecs2305` returns for this element.
+    locals: Vec<BoundIdentifier<'a>>,
+}
+
+impl DecoratedElement<'_> {
+    /// Elements are applied in this order:
+    /// static methods and accessors, instance methods and accessors, static fields, instance fields.
+    fn order(&self) -> u8 {
+        let is_field = self.kind == ElementKind::Field;
+        (u8::from(is_field) << 1) | u8::from(!self.is_static)
+    }
+}
+
+pub struct StandardDecorator<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> StandardDecorator<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a> for StandardDecorator<'a, '_> {
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            Statement::ClassDeclaration(class) => {
+                if !Self::class_needs_transform(class) {
+                    return;
+                }
+                let mut memos = vec![];
+                let class_binding = self.transform_class(class, &mut memos, ctx);
+                if let Some(class_binding) = class_binding {
+                    let (declaration, _) =
+                        Self::create_class_let_declaration(class, &class_binding, ctx);
+                    let new_stmt = Statement::VariableDeclaration(declaration);
+                    self.ctx.statement_injector.move_insertions(stmt, &new_stmt);
+                    *stmt = new_stmt;
+                }
+                self.insert_memos_before(stmt, memos, ctx);
+            }
+            Statement::ExportNamedDeclaration(export) => {
+                let Some(Declaration::ClassDeclaration(class)) = &mut export.declaration else {
+                    return;
+                };
+                if !Self::class_needs_transform(class) {
+                    return;
+                }
+                let mut memos = vec![];
+                let class_binding = self.transform_class(class, &mut memos, ctx);
+                if let Some(class_binding) = class_binding {
+                    // `export class C {}` -> `export let C = (class C {}, _C);`
+                    let (declaration, _) =
+                        Self::create_class_let_declaration(class, &class_binding, ctx);
+                    export.declaration = Some(Declaration::VariableDeclaration(declaration));
+                }
+                self.insert_memos_before(stmt, memos, ctx);
+            }
+            Statement::ExportDefaultDeclaration(export) => {
+                let ExportDefaultDeclarationKind::ClassDeclaration(class) = &mut export.declaration
+                else {
+                    return;
+                };
+                if !Self::class_needs_transform(class) {
+                    return;
+                }
+                let mut memos = vec![];
+                let class_binding = self.transform_class(class, &mut memos, ctx);
+                if let Some(class_binding) = class_binding {
+                    if class.id.is_some() {
+                        // `export default class C {}` -> `let C = (class C {}, _C); export default C;`
+                        let (declaration, binding) =
+                            Self::create_class_let_declaration(class, &class_binding, ctx);
+                        let export_default = Self::create_export_default_reference(&binding, ctx);
+                        let new_stmt = Statement::VariableDeclaration(declaration);
+                        self.ctx.statement_injector.move_insertions(stmt, &new_stmt);
+                        self.ctx.statement_injector.insert_after(&new_stmt, export_default);
+                        *stmt = new_stmt;
+                    } else {
+                        // `export default class {}` -> `export default (class {}, _Class);`
+                        class.r#type = ClassType::ClassExpression;
+                        let class =
+                            Expression::ClassExpression(class.take_in_box(ctx.ast.allocator));
+                        let expr = Self::create_class_sequence(vec![], class, &class_binding, ctx);
+                        export.declaration = ExportDefaultDeclarationKind::from(expr);
+                    }
+                }
+                self.insert_memos_before(stmt, memos, ctx);
+            }
+            _ => {}
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ClassExpression(class) = expr else { return };
+        if !Self::class_needs_transform(class) {
+            return;
+        }
+
+        // Anonymous class loses the name of the variable it is assigned to, once wrapped in
+        // a sequence, so name it explicitly.
+        // `let C = @dec class {}` -> `let C = (class C {}, _C)`
+        if class.id.is_none() {
+            if let Ancestor::VariableDeclaratorInit(declarator) = ctx.parent() {
+                if let BindingPatternKind::BindingIdentifier(ident) = &declarator.id().kind {
+                    let binding =
+                        ctx.generate_binding(ident.name, class.scope_id(), SymbolFlags::Class);
+                    class.id = Some(binding.create_binding_identifier(ctx));
+                }
+            }
+        }
+
+        let mut memos = vec![];
+        let class_binding = self.transform_class(class, &mut memos, ctx);
+        if class_binding.is_none() && memos.is_empty() {
+            return;
+        }
+
+        // `@dec class {}` -> `(_dec = dec, class {}, _Class)`
+        let class = expr.take_in(ctx.ast.allocator);
+        *expr = if let Some(class_binding) = class_binding {
+            Self::create_class_sequence(memos, class, &class_binding, ctx)
+        } else {
+            memos.push(class);
+            ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter(memos))
+        };
+    }
+}
+
+impl<'a> StandardDecorator<'a, '_> {
+    /// Returns `true` if class has any decorators, or any `accessor` fields.
+    fn class_needs_transform(class: &Class<'a>) -> bool {
+        if class.declare {
+            return false;
+        }
+        !class.decorators.is_empty()
+            || class.body.body.iter().any(|element| match element {
+                ClassElement::AccessorProperty(accessor) => !accessor.r#type.is_abstract(),
+                element => element.has_decorator(),
+            })
+    }
+
+    /// Transform decorators and `accessor` fields of a class.
+    ///
+    /// Assignments which must be evaluated before the class are pushed to `memos`.
+    ///
+    /// If class has class decorators, returns the binding which the decorated class is assigned to.
+    /// References to the class inside the class body are replaced with references to that binding.
+    fn transform_class(
+        &self,
+        class: &mut Class<'a>,
+        memos: &mut Vec<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<BoundIdentifier<'a>> {
+        let class_scope_id = class.scope_id();
+        let has_decorators =
+            !class.decorators.is_empty() || class.body.body.iter().any(ClassElement::has_decorator);
+
+        let class_decorators = class
+            .decorators
+            .take_in(ctx.ast.allocator)
+            .into_iter()
+            .map(|decorator| self.memoize_decorator(decorator.expression, memos, ctx))
+            .collect::<Vec<_>>();
+
+        // Static block applying decorators. Functions defined for private elements are inside it.
+        let static_block_scope_id = has_decorators
+            .then(|| ctx.create_child_scope(class_scope_id, ScopeFlags::ClassStaticBlock));
+
+        let mut private_names = class
+            .body
+            .body
+            .iter()
+            .filter_map(|element| element.property_key().and_then(PropertyKey::private_name))
+            .collect::<FxHashSet<_>>();
+
+        let body = class.body.body.take_in(ctx.ast.allocator);
+        let mut elements = ctx.ast.vec_with_capacity(body.len() + 2);
+        let mut decorated_elements = vec![];
+        // Private name of a decorated instance element, used as the brand check of instances
+        let mut instance_private_name = None;
+
+        for element in body {
+            match element {
+                ClassElement::MethodDefinition(mut method)
+                    if !method.decorators.is_empty() && !method.r#type.is_abstract() =>
+                {
+                    let decorators = self.memoize_decorators(&mut method.decorators, memos, ctx);
+                    if method.computed {
+                        self.memoize_computed_key(&mut method.key, memos, ctx);
+                    }
+                    let kind = match method.kind {
+                        MethodDefinitionKind::Get => ElementKind::Getter,
+                        MethodDefinitionKind::Set => ElementKind::Setter,
+                        _ => ElementKind::Method,
+                    };
+                    let mut element = DecoratedElement {
+                        kind,
+                        is_static: method.r#static,
+                        decorators,
+                        name: Self::create_key_name(&method.key, ctx),
+                        functions: vec![],
+                        locals: vec![],
+                    };
+                    if let PropertyKey::PrivateIdentifier(ident) = &method.key {
+                        if !method.r#static {
+                            instance_private_name.get_or_insert(ident.name);
+                        }
+                        let function = self.transform_private_method(
+                            &mut method,
+                            kind,
+                            static_block_scope_id.unwrap(),
+                            ctx,
+                        );
+                        element.functions.push(function.0);
+                        element.locals.push(function.1);
+                    }
+                    elements.push(ClassElement::MethodDefinition(method));
+                    decorated_elements.push(element);
+                }
+                ClassElement::PropertyDefinition(mut prop)
+                    if !prop.decorators.is_empty()
+                        && !prop.declare
+                        && !prop.r#type.is_abstract() =>
+                {
+                    let decorators = self.memoize_decorators(&mut prop.decorators, memos, ctx);
+                    if prop.computed {
+                        self.memoize_computed_key(&mut prop.key, memos, ctx);
+                    }
+                    let mut element = DecoratedElement {
+                        kind: ElementKind::Field,
+                        is_static: prop.r#static,
+                        decorators,
+                        name: Self::create_key_name(&prop.key, ctx),
+                        functions: vec![],
+                        locals: vec![],
+                    };
+
+                    // `x = 1` -> `x = _init_x(this, 1)`
+                    let init_binding = self.create_local("init", &prop.key, ctx);
+                    prop.value =
+                        Some(Self::create_init_call(&init_binding, prop.value.take(), ctx));
+                    element.locals.push(init_binding);
+
+                    if let PropertyKey::PrivateIdentifier(ident) = &prop.key {
+                        if !prop.r#static {
+                            instance_private_name.get_or_insert(ident.name);
+                        }
+                        let scope_id = static_block_scope_id.unwrap();
+                        element
+                            .functions
+                            .push(Self::create_private_getter(ident.name, scope_id, ctx));
+                        element
+                            .functions
+                            .push(Self::create_private_setter(ident.name, scope_id, ctx));
+                    }
+                    elements.push(ClassElement::PropertyDefinition(prop));
+                    decorated_elements.push(element);
+                }
+                ClassElement::AccessorProperty(mut accessor) if !accessor.r#type.is_abstract() => {
+                    let decorators = self.memoize_decorators(&mut accessor.decorators, memos, ctx);
+                    if accessor.computed {
+                        self.memoize_computed_key(&mut accessor.key, memos, ctx);
+                    }
+                    let storage_name = Self::create_storage_name(&mut private_names, ctx);
+                    let element = self.transform_accessor(
+                        &mut accessor,
+                        storage_name,
+                        decorators,
+                        class_scope_id,
+                        static_block_scope_id,
+                        &mut elements,
+                        ctx,
+                    );
+                    if let Some(element) = element {
+                        if let PropertyKey::PrivateIdentifier(ident) = &accessor.key {
+                            if !accessor.r#static {
+                                instance_private_name.get_or_insert(ident.name);
+                            }
+                        }
+                        decorated_elements.push(element);
+                    }
+                }
+                mut element => {
+                    // Computed keys are evaluated after the decorators before them
+                    if has_decorators {
+                        if let ClassElement::MethodDefinition(method) = &mut element {
+                            if method.computed {
+                                self.memoize_computed_key(&mut method.key, memos, ctx);
+                            }
+                        } else if let ClassElement::PropertyDefinition(prop) = &mut element {
+                            if prop.computed {
+                                self.memoize_computed_key(&mut prop.key, memos, ctx);
+                            }
+                        }
+                    }
+                    elements.push(element);
+                }
+            }
+        }
+
+        let Some(static_block_scope_id) = static_block_scope_id else {
+            // Only `accessor` fields to transform
+            class.body.body = elements;
+            return None;
+        };
+
+        // Bindings for the functions returned by `_applyDecs2305`
+        decorated_elements.sort_by_key(DecoratedElement::order);
+        let mut element_locals = decorated_elements
+            .iter_mut()
+            .flat_map(|element| element.locals.drain(..))
+            .collect::<Vec<_>>();
+        let init_proto = decorated_elements
+            .iter()
+            .any(|element| !element.is_static)
+            .then(|| self.ctx.var_declarations.create_uid_var("initProto", ctx));
+        let init_static = decorated_elements
+            .iter()
+            .any(|element| element.is_static)
+            .then(|| self.ctx.var_declarations.create_uid_var("initStatic", ctx));
+        element_locals.extend(init_proto.clone());
+        element_locals.extend(init_static.clone());
+
+        let class_locals = (!class_decorators.is_empty()).then(|| {
+            let name = class.id.as_ref().map_or("Class", |id| id.name.as_str());
+            let class_binding = self.ctx.var_declarations.create_uid_var(name, ctx);
+            let init_class = self.ctx.var_declarations.create_uid_var("initClass", ctx);
+            (class_binding, init_class)
+        });
+
+        // `_applyDecs2305(this, memberDecs, classDecs, classDecsHaveThis, instanceBrand, parentClass)`
+        let parent_class = class
+            .super_class
+            .as_mut()
+            .map(|super_class| self.memoize_super_class(super_class, ctx));
+        let instance_brand = instance_private_name
+            .map(|name| Self::create_instance_brand(name, static_block_scope_id, ctx));
+        let apply_decs = self.create_apply_decs_call(
+            decorated_elements,
+            class_decorators,
+            instance_brand,
+            parent_class,
+            ctx,
+        );
+
+        // `[_init_x, _initProto] = _applyDecs2305(...).e;`
+        // `[_Class, _initClass] = _applyDecs2305(...).c;`
+        // `({ e: [_init_x, _initProto], c: [_Class, _initClass] } = _applyDecs2305(...));`
+        let mut targets = vec![];
+        if !element_locals.is_empty() {
+            targets.push(("e", Self::create_array_target(element_locals.iter(), ctx)));
+        }
+        if let Some((class_binding, init_class)) = &class_locals {
+            targets.push(("c", Self::create_array_target([class_binding, init_class], ctx)));
+        }
+        let assignment = if let [(property, target)] = targets.as_mut_slice() {
+            let property = ctx.ast.identifier_name(SPAN, *property);
+            let object = Expression::from(
+                ctx.ast.member_expression_static(SPAN, apply_decs, property, false),
+            );
+            Self::create_assignment(target.take_in(ctx.ast.allocator), object, ctx)
+        } else {
+            let properties =
+                ctx.ast.vec_from_iter(targets.into_iter().map(|(property, target)| {
+                    ctx.ast.assignment_target_property_assignment_target_property_property(
+                        SPAN,
+                        ctx.ast.property_key_static_identifier(SPAN, property),
+                        AssignmentTargetMaybeDefault::from(target),
+                        false,
+                    )
+                }));
+            let target = AssignmentTarget::from(
+                ctx.ast.assignment_target_pattern_object_assignment_target(SPAN, properties, None),
+            );
+            Self::create_assignment(target, apply_decs, ctx)
+        };
+        let mut stmts = ctx.ast.vec_with_capacity(2);
+        stmts.push(ctx.ast.statement_expression(SPAN, assignment));
+        // `_initStatic(this);`
+        if let Some(init_static) = &init_static {
+            let call = Self::create_init_call(init_static, None, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, call));
+        }
+
+        if let Some(init_proto) = &init_proto {
+            Self::insert_init_proto(
+                &mut elements,
+                init_proto,
+                class_scope_id,
+                class.super_class.is_some(),
+                ctx,
+            );
+        }
+
+        elements.insert(
+            0,
+            ctx.ast.class_element_static_block_with_scope_id(SPAN, stmts, static_block_scope_id),
+        );
+
+        let class_binding = class_locals.map(|(class_binding, init_class)| {
+            // `static { _initClass(); }`
+            let scope_id = ctx.create_child_scope(class_scope_id, ScopeFlags::ClassStaticBlock);
+            let callee = init_class.create_read_expression(ctx);
+            let call = ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec(), false);
+            let stmts = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call));
+            elements.push(ctx.ast.class_element_static_block_with_scope_id(SPAN, stmts, scope_id));
+            class_binding
+        });
+
+        class.body.body = elements;
+
+        // References to the class inside class body refer to the decorated class
+        if let (Some(class_binding), Some(id)) = (&class_binding, &class.id) {
+            let mut replacer =
+                ClassReferenceReplacer { symbol_id: id.symbol_id(), class_binding, ctx };
+            replacer.visit_class_body(&mut class.body);
+        }
+
+        class_binding
+    }
+
+    /// Transform an `accessor` field into a private field, plus a getter and setter.
+    ///
+    /// Pushes the new class elements to `elements`, and returns the [`DecoratedElement`]
+    /// if the accessor is decorated.
+    ///
+    /// ```js
+    /// class C {
+    ///   accessor x = 1;
+    ///   @dec accessor #y = 2;
+    /// }
+    /// // ->
+    /// class C {
+    ///   #A = 1;
+    ///   get x() { return this.#A; }
+    ///   set x(v) { this.#A = v; }
+    ///   #B = _init_y(this, 2);
+    ///   get #y() { return _get_y(this); }
+    ///   set #y(v) { _set_y(this, v); }
+    /// }
+    /// ```
+    fn transform_accessor(
+        &self,
+        accessor: &mut AccessorProperty<'a>,
+        storage_name: Atom<'a>,
+        decorators: Vec<DecoratorValue<'a>>,
+        class_scope_id: ScopeId,
+        static_block_scope_id: Option<ScopeId>,
+        elements: &mut ArenaVec<'a, ClassElement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<DecoratedElement<'a>> {
+        let is_static = accessor.r#static;
+        let mut value = accessor.value.take();
+
+        let mut element = (!decorators.is_empty()).then(|| {
+            // `_init_x(this, value)`
+            let init_binding = self.create_local("init", &accessor.key, ctx);
+            value = Some(Self::create_init_call(&init_binding, value.take(), ctx));
+            DecoratedElement {
+                kind: ElementKind::Accessor,
+                is_static,
+                decorators,
+                name: Self::create_key_name(&accessor.key, ctx),
+                functions: vec![],
+                locals: vec![init_binding],
+            }
+        });
+
+        // `#A = value;`
+        elements.push(ctx.ast.class_element_property_definition(
+            accessor.span,
+            PropertyDefinitionType::PropertyDefinition,
+            ctx.ast.vec(),
+            PropertyKey::PrivateIdentifier(ctx.ast.alloc_private_identifier(SPAN, storage_name)),
+            value,
+            false,
+            is_static,
+            false,
+            false,
+            false,
+            false,
+            false,
+            NONE,
+            None,
+        ));
+
+        let (getter_body, setter_body);
+        if let (Some(element), PropertyKey::PrivateIdentifier(_)) =
+            (element.as_mut(), &accessor.key)
+        {
+            // Private accessor is accessed via functions returned by `_applyDecs2305`.
+            // `get #x() { return _get_x(this); }`, `set #x(v) { _set_x(this, v); }`
+            let scope_id = static_block_scope_id.unwrap();
+            element.functions.push(Self::create_private_getter(storage_name, scope_id, ctx));
+            element.functions.push(Self::create_private_setter(storage_name, scope_id, ctx));
+            let get_binding = self.create_local("get", &accessor.key, ctx);
+            let set_binding = self.create_local("set", &accessor.key, ctx);
+            getter_body = AccessorBody::Call(get_binding.clone());
+            setter_body = AccessorBody::Call(set_binding.clone());
+            element.locals.push(get_binding);
+            element.locals.push(set_binding);
+        } else {
+            getter_body = AccessorBody::Storage(storage_name);
+            setter_body = AccessorBody::Storage(storage_name);
+        }
+
+        let getter_key = Self::copy_key(&accessor.key, ctx);
+        let getter = Self::create_accessor_method(
+            MethodDefinitionKind::Get,
+            getter_key,
+            accessor.computed,
+            is_static,
+            getter_body,
+            class_scope_id,
+            ctx,
+        );
+        let setter = Self::create_accessor_method(
+            MethodDefinitionKind::Set,
+            accessor.key.take_in(ctx.ast.allocator),
+            accessor.computed,
+            is_static,
+            setter_body,
+            class_scope_id,
+            ctx,
+        );
+        elements.push(getter);
+        elements.push(setter);
+
+        element
+    }
+
+    /// Memoize decorators, in order.
+    fn memoize_decorators(
+        &self,
+        decorators: &mut ArenaVec<'a, Decorator<'a>>,
+        memos: &mut Vec<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<DecoratorValue<'a>> {
+        decorators
+            .take_in(ctx.ast.allocator)
+            .into_iter()
+            .map(|decorator| self.memoize_decorator(decorator.expression, memos, ctx))
+            .collect()
+    }
+
+    /// Memoize a decorator expression, so it is evaluated before the class.
+    ///
+    /// * `@dec` -> `dec`, which is not memoized.
+    /// * `@obj.dec` -> `_dec = (_obj = obj).dec`, called with `_obj` as `this`.
+    /// * `@dec()` -> `_dec = dec()`.
+    fn memoize_decorator(
+        &self,
+        expression: Expression<'a>,
+        memos: &mut Vec<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> DecoratorValue<'a> {
+        // `@(obj.dec)` is called with `obj` as `this`, same as `@obj.dec`
+        let mut expression = expression;
+        while let Expression::ParenthesizedExpression(paren) = expression {
+            expression = paren.unbox().expression;
+        }
+
+        match expression {
+            Expression::Identifier(_) => DecoratorValue { this: None, decorator: expression },
+            match_member_expression!(Expression)
+                if !expression.to_member_expression().optional()
+                    && !expression.to_member_expression().object().is_super() =>
+            {
+                let mut member = expression.into_member_expression();
+                let object = member.object_mut();
+                let object_binding =
+                    self.ctx.var_declarations.create_uid_var_based_on_node(object, ctx);
+                let object_expr = object.take_in(ctx.ast.allocator);
+                *object = Self::create_assignment(
+                    object_binding.create_write_target(ctx),
+                    object_expr,
+                    ctx,
+                );
+                let decorator_binding = self.ctx.var_declarations.create_uid_var("dec", ctx);
+                memos.push(Self::create_assignment(
+                    decorator_binding.create_write_target(ctx),
+                    Expression::from(member),
+                    ctx,
+                ));
+                DecoratorValue {
+                    this: Some(object_binding.create_read_expression(ctx)),
+                    decorator: decorator_binding.create_read_expression(ctx),
+                }
+            }
+            expression => {
+                let binding = self.ctx.var_declarations.create_uid_var("dec", ctx);
+                memos.push(Self::create_assignment(
+                    binding.create_write_target(ctx),
+                    expression,
+                    ctx,
+                ));
+                DecoratorValue { this: None, decorator: binding.create_read_expression(ctx) }
+            }
+        }
+    }
+
+    /// Memoize a computed key which is not a literal, so it is evaluated in order with decorators.
+    ///
+    /// `[a()]` -> `[_computedKey]`, with `_computedKey = _toPropertyKey(a())` pushed to `memos`.
+    fn memoize_computed_key(
+        &self,
+        key: &mut PropertyKey<'a>,
+        memos: &mut Vec<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let is_literal = match key {
+            PropertyKey::StaticIdentifier(_)
+            | PropertyKey::PrivateIdentifier(_)
+            | PropertyKey::StringLiteral(_)
+            | PropertyKey::NumericLiteral(_)
+            | PropertyKey::BigIntLiteral(_)
+            | PropertyKey::BooleanLiteral(_)
+            | PropertyKey::NullLiteral(_) => true,
+            PropertyKey::TemplateLiteral(literal) => literal.expressions.is_empty(),
+            _ => false,
+        };
+        if is_literal {
+            return;
+        }
+
+        let binding = self.ctx.var_declarations.create_uid_var("computedKey", ctx);
+        let expr = key.to_expression_mut().take_in(ctx.ast.allocator);
+        let arguments = ctx.ast.vec1(Argument::from(expr));
+        let value = self.ctx.helper_call_expr(Helper::ToPropertyKey, SPAN, arguments, ctx);
+        memos.push(Self::create_assignment(binding.create_write_target(ctx), value, ctx));
+        *key = PropertyKey::from(binding.create_read_expression(ctx));
+    }
+
+    /// Memoize super class, so it can be passed to `_applyDecs2305`.
+    ///
+    /// `class C extends Base {}` -> `Base`
+    /// `class C extends mixin(Base) {}` -> `class C extends (_super = mixin(Base)) {}`, `_super`
+    fn memoize_super_class(
+        &self,
+        super_class: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if let Expression::Identifier(ident) = super_class {
+            return Self::copy_identifier(ident, ctx);
+        }
+        let binding = self.ctx.var_declarations.create_uid_var("super", ctx);
+        let expr = super_class.take_in(ctx.ast.allocator);
+        *super_class = Self::create_assignment(binding.create_write_target(ctx), expr, ctx);
+        binding.create_read_expression(ctx)
+    }
+
+    /// Create binding for a function returned by `_applyDecs2305`, named after the element.
+    /// e.g. `_init_x`, `_get_x`, `_call_x`.
+    fn create_local(
+        &self,
+        prefix: &str,
+        key: &PropertyKey<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let name = match key {
+            PropertyKey::StaticIdentifier(ident) => Some(ident.name.as_str()),
+            PropertyKey::PrivateIdentifier(ident) => Some(ident.name.as_str()),
+            PropertyKey::StringLiteral(literal) if is_identifier_name(&literal.value) => {
+                Some(literal.value.as_str())
+            }
+            _ => None,
+        };
+        let name = format!("{prefix}_{}", name.unwrap_or("computedKey"));
+        self.ctx.var_declarations.create_uid_var(&name, ctx)
+    }
+
+    /// Replace a decorated private method with a getter returning the decorated method,
+    /// and return the original method, and the binding for the decorated method.
+    ///
+    /// * `#m() {}` -> `get #m() { return _call_m; }`
+    /// * `get #g() {}` -> `get #g() { return _call_g(this); }`
+    /// * `set #s(v) {}` -> `set #s(v) { _call_s(this, v); }`
+    fn transform_private_method(
+        &self,
+        method: &mut MethodDefinition<'a>,
+        kind: ElementKind,
+        static_block_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, BoundIdentifier<'a>) {
+        let binding = self.create_local("call", &method.key, ctx);
+        let (new_kind, body) = match kind {
+            ElementKind::Getter => (MethodDefinitionKind::Get, AccessorBody::Call(binding.clone())),
+            ElementKind::Setter => (MethodDefinitionKind::Set, AccessorBody::Call(binding.clone())),
+            _ => (MethodDefinitionKind::Get, AccessorBody::Value(binding.clone())),
+        };
+
+        let scope_id = method.value.scope_id();
+        let class_scope_id = ctx.scoping().scope_parent_id(scope_id).unwrap();
+        let accessor = Self::create_accessor_function(new_kind, body, class_scope_id, ctx);
+        let function = std::mem::replace(&mut method.value, accessor);
+        method.kind = new_kind;
+
+        // Method is moved into the static block applying decorators
+        ctx.scoping_mut().change_scope_parent_id(scope_id, Some(static_block_scope_id));
+        ctx.scoping_mut()
+            .scope_flags_mut(scope_id)
+            .remove(ScopeFlags::GetAccessor | ScopeFlags::SetAccessor);
+
+        (Expression::FunctionExpression(function), binding)
+    }
+
+    /// Create a getter or setter of an `accessor` field, or replacing a private method.
+    fn create_accessor_method(
+        kind: MethodDefinitionKind,
+        key: PropertyKey<'a>,
+        computed: bool,
+        is_static: bool,
+        body: AccessorBody<'a>,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        let function = Self::create_accessor_function(kind, body, class_scope_id, ctx);
+        ctx.ast.class_element_method_definition(
+            SPAN,
+            MethodDefinitionType::MethodDefinition,
+            ctx.ast.vec(),
+            key,
+            function,
+            kind,
+            computed,
+            is_static,
+            false,
+            false,
+            None,
+        )
+    }
+
+    /// Create function of a getter or setter.
+    ///
+    /// * Getter: `return this.#A;`, `return _get_x(this);` or `return _call_x;`
+    /// * Setter: `this.#A = v;` or `_set_x(this, v);`
+    fn create_accessor_function(
+        kind: MethodDefinitionKind,
+        body: AccessorBody<'a>,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, Function<'a>> {
+        let scope_id = ctx.create_child_scope(class_scope_id, kind.scope_flags());
+
+        let mut params = ctx.ast.vec();
+        let value = kind.is_set().then(|| {
+            let binding = ctx.generate_binding(
+                Atom::from("v"),
+                scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            params.push(ctx.ast.plain_formal_parameter(SPAN, binding.create_binding_pattern(ctx)));
+            binding.create_read_expression(ctx)
+        });
+
+        let expr = match body {
+            AccessorBody::Storage(name) => {
+                let field = ctx.ast.member_expression_private_field_expression(
+                    SPAN,
+                    ctx.ast.expression_this(SPAN),
+                    ctx.ast.private_identifier(SPAN, name),
+                    false,
+                );
+                match value {
+                    Some(value) => Self::create_assignment(
+                        AssignmentTarget::from(SimpleAssignmentTarget::from(field)),
+                        value,
+                        ctx,
+                    ),
+                    None => Expression::from(field),
+                }
+            }
+            AccessorBody::Call(binding) => Self::create_init_call(&binding, value, ctx),
+            AccessorBody::Value(binding) => binding.create_read_expression(ctx),
+        };
+        let stmt = if kind.is_set() {
+            ctx.ast.statement_expression(SPAN, expr)
+        } else {
+            ctx.ast.statement_return(SPAN, Some(expr))
+        };
+
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            params,
+            NONE,
+        );
+        let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), ctx.ast.vec1(stmt));
+        ctx.ast.alloc_plain_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            params,
+            body,
+            scope_id,
+        )
+    }
+
+    /// `o => o.#x`
+    fn create_private_getter(
+        name: Atom<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        Self::create_arrow_function(&["o"], parent_scope_id, ctx, |params, ctx| {
+            let object = params[0].create_read_expression(ctx);
+            let field = ctx.ast.member_expression_private_field_expression(
+                SPAN,
+                object,
+                ctx.ast.private_identifier(SPAN, name),
+                false,
+            );
+            Expression::from(field)
+        })
+    }
+
+    /// `(o, v) => o.#x = v`
+    fn create_private_setter(
+        name: Atom<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        Self::create_arrow_function(&["o", "v"], parent_scope_id, ctx, |params, ctx| {
+            let object = params[0].create_read_expression(ctx);
+            let field = ctx.ast.member_expression_private_field_expression(
+                SPAN,
+                object,
+                ctx.ast.private_identifier(SPAN, name),
+                false,
+            );
+            let target = AssignmentTarget::from(SimpleAssignmentTarget::from(field));
+            let value = params[1].create_read_expression(ctx);
+            Self::create_assignment(target, value, ctx)
+        })
+    }
+
+    /// `o => #x in o`
+    fn create_instance_brand(
+        name: Atom<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        Self::create_arrow_function(&["o"], parent_scope_id, ctx, |params, ctx| {
+            let object = params[0].create_read_expression(ctx);
+            ctx.ast.expression_private_in(SPAN, ctx.ast.private_identifier(SPAN, name), object)
+        })
+    }
+
+    /// Create an arrow function with an expression body, with parameters named `param_names`.
+    fn create_arrow_function(
+        param_names: &[&'static str],
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+        create_body: impl FnOnce(&[BoundIdentifier<'a>], &mut TraverseCtx<'a>) -> Expression<'a>,
+    ) -> Expression<'a> {
+        let scope_id =
+            ctx.create_child_scope(parent_scope_id, ScopeFlags::Function | ScopeFlags::Arrow);
+        let bindings = param_names
+            .iter()
+            .map(|&name| {
+                ctx.generate_binding(
+                    Atom::from(name),
+                    scope_id,
+                    SymbolFlags::FunctionScopedVariable,
+                )
+            })
+            .collect::<Vec<_>>();
+        let params = ctx.ast.vec_from_iter(bindings.iter().map(|binding| {
+            ctx.ast.plain_formal_parameter(SPAN, binding.create_binding_pattern(ctx))
+        }));
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            params,
+            NONE,
+        );
+        let body = create_body(&bindings, ctx);
+        let body = ctx.ast.function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, body)),
+        );
+        ctx.ast.expression_arrow_function_with_scope_id_and_pure(
+            SPAN, true, false, NONE, params, NONE, body, scope_id, false,
+        )
+    }
+
+    /// Generate a private name for the storage of an `accessor` field: `#A`, `#B`, ... `#Z`, `#A1`, ...
+    fn create_storage_name(
+        private_names: &mut FxHashSet<Atom<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Atom<'a> {
+        for i in 0usize.. {
+            let letter = char::from(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ"[i % 26]);
+            let name = match i / 26 {
+                0 => ctx.ast.atom(letter.encode_utf8(&mut [0; 4])),
+                suffix => ctx.ast.atom(&format!("{letter}{suffix}")),
+            };
+            if private_names.insert(name) {
+                return name;
+            }
+        }
+        unreachable!()
+    }
+
+    /// `_applyDecs2305(this, memberDecs, classDecs, classDecsHaveThis, instanceBrand, parentClass)`
+    ///
+    /// Trailing arguments are omitted where possible.
+    fn create_apply_decs_call(
+        &self,
+        decorated_elements: Vec<DecoratedElement<'a>>,
+        class_decorators: Vec<DecoratorValue<'a>>,
+        instance_brand: Option<Expression<'a>>,
+        parent_class: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        // `[decs, flags, name, ...functions]`
+        let member_decs = decorated_elements
+            .into_iter()
+            .map(|element| {
+                let has_this = element.decorators.iter().any(|decorator| decorator.this.is_some());
+                let mut flags = element.kind as u8;
+                if element.is_static {
+                    flags |= STATIC_FLAG;
+                }
+                if has_this {
+                    flags |= DECORATORS_HAVE_THIS_FLAG;
+                }
+                let decorators = if !has_this && element.decorators.len() == 1 {
+                    element.decorators.into_iter().next().unwrap().decorator
+                } else {
+                    Self::create_decorators_array(element.decorators, has_this, ctx)
+                };
+                let flags = ctx.ast.expression_numeric_literal(
+                    SPAN,
+                    f64::from(flags),
+                    None,
+                    NumberBase::Decimal,
+                );
+                let items = ctx.ast.vec_from_iter(
+                    [decorators, flags, element.name]
+                        .into_iter()
+                        .chain(element.functions)
+                        .map(ArrayExpressionElement::from),
+                );
+                ArrayExpressionElement::from(ctx.ast.expression_array(SPAN, items))
+            })
+            .collect::<Vec<_>>();
+        let member_decs = ctx.ast.expression_array(SPAN, ctx.ast.vec_from_iter(member_decs));
+
+        let class_decs_have_this =
+            class_decorators.iter().any(|decorator| decorator.this.is_some());
+        let class_decs = Self::create_decorators_array(class_decorators, class_decs_have_this, ctx);
+
+        let mut arguments = ctx.ast.vec_with_capacity(6);
+        arguments.push(Argument::from(ctx.ast.expression_this(SPAN)));
+        arguments.push(Argument::from(member_decs));
+        arguments.push(Argument::from(class_decs));
+        let has_trailing_arguments = instance_brand.is_some() || parent_class.is_some();
+        if class_decs_have_this || has_trailing_arguments {
+            let value = f64::from(u8::from(class_decs_have_this));
+            let value = ctx.ast.expression_numeric_literal(SPAN, value, None, NumberBase::Decimal);
+            arguments.push(Argument::from(value));
+        }
+        if has_trailing_arguments {
+            let instance_brand = instance_brand.unwrap_or_else(|| ctx.ast.void_0(SPAN));
+            arguments.push(Argument::from(instance_brand));
+        }
+        if let Some(parent_class) = parent_class {
+            arguments.push(Argument::from(parent_class));
+        }
+        self.ctx.helper_call_expr(Helper::ApplyDecs2305, SPAN, arguments, ctx)
+    }
+
+    /// `[dec1, dec2]`, or `[this1, dec1, this2, dec2]` if any decorator has a `this` value.
+    fn create_decorators_array(
+        decorators: Vec<DecoratorValue<'a>>,
+        has_this: bool,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut elements =
+            ctx.ast.vec_with_capacity(decorators.len() * (1 + usize::from(has_this)));
+        for DecoratorValue { this, decorator } in decorators {
+            if has_this {
+                let this = this.unwrap_or_else(|| ctx.ast.void_0(SPAN));
+                elements.push(ArrayExpressionElement::from(this));
+            }
+            elements.push(ArrayExpressionElement::from(decorator));
+        }
+        ctx.ast.expression_array(SPAN, elements)
+    }
+
+    /// Call `_initProto(this)` before instance fields are initialized.
+    ///
+    /// * `x = 1;` -> `x = (_initProto(this), 1);` in first instance field, or otherwise
+    /// * `constructor() { foo(); }` -> `constructor() { _initProto(this); foo(); }`
+    /// * `constructor() { super(); }` -> `constructor() { (super(), _initProto(this)); }`
+    /// * A constructor is created, if class does not have one.
+    fn insert_init_proto(
+        elements: &mut ArenaVec<'a, ClassElement<'a>>,
+        init_proto: &BoundIdentifier<'a>,
+        class_scope_id: ScopeId,
+        has_super_class: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let field = elements.iter_mut().find_map(|element| match element {
+            ClassElement::PropertyDefinition(prop)
+                if !prop.r#static && !prop.declare && !prop.r#type.is_abstract() =>
+            {
+                Some(prop)
+            }
+            _ => None,
+        });
+        if let Some(prop) = field {
+            let call = Self::create_init_call(init_proto, None, ctx);
+            let value = prop.value.take().unwrap_or_else(|| ctx.ast.void_0(SPAN));
+            prop.value =
+                Some(ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_array([call, value])));
+            return;
+        }
+
+        let constructor = elements.iter_mut().find_map(|element| match element {
+            ClassElement::MethodDefinition(method) if method.kind.is_constructor() => {
+                method.value.body.as_mut()
+            }
+            _ => None,
+        });
+        if let Some(body) = constructor {
+            if has_super_class {
+                SuperCallInitProtoInserter { init_proto, ctx }.visit_function_body(body);
+            } else {
+                let call = Self::create_init_call(init_proto, None, ctx);
+                body.statements.insert(0, ctx.ast.statement_expression(SPAN, call));
+            }
+            return;
+        }
+
+        // `constructor() { _initProto(this); }`
+        // `constructor(..._args) { super(..._args); _initProto(this); }`
+        let scope_id =
+            ctx.create_child_scope(class_scope_id, ScopeFlags::Function | ScopeFlags::Constructor);
+        let mut stmts = ctx.ast.vec_with_capacity(2);
+        let mut params_rest = None;
+        if has_super_class {
+            let args_binding =
+                ctx.generate_uid("args", scope_id, SymbolFlags::FunctionScopedVariable);
+            params_rest = Some(
+                ctx.ast.alloc_binding_rest_element(SPAN, args_binding.create_binding_pattern(ctx)),
+            );
+            let arguments = ctx.ast.vec1(
+                ctx.ast.argument_spread_element(SPAN, args_binding.create_read_expression(ctx)),
+            );
+            let super_call = ctx.ast.expression_call(
+                SPAN,
+                ctx.ast.expression_super(SPAN),
+                NONE,
+                arguments,
+                false,
+            );
+            stmts.push(ctx.ast.statement_expression(SPAN, super_call));
+        }
+        let call = Self::create_init_call(init_proto, None, ctx);
+        stmts.push(ctx.ast.statement_expression(SPAN, call));
+
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec(),
+            params_rest,
+        );
+        let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), stmts);
+        let function = ctx.ast.alloc_plain_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            params,
+            body,
+            scope_id,
+        );
+        let constructor = ctx.ast.class_element_method_definition(
+            SPAN,
+            MethodDefinitionType::MethodDefinition,
+            ctx.ast.vec(),
+            ctx.ast.property_key_static_identifier(SPAN, "constructor"),
+            function,
+            MethodDefinitionKind::Constructor,
+            false,
+            false,
+            false,
+            false,
+            None,
+        );
+        elements.insert(0, constructor);
+    }
+
+    /// `binding(this)` or `binding(this, value)`
+    fn create_init_call(
+        binding: &BoundIdentifier<'a>,
+        value: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = binding.create_read_expression(ctx);
+        let mut arguments = ctx.ast.vec_with_capacity(2);
+        arguments.push(Argument::from(ctx.ast.expression_this(SPAN)));
+        if let Some(value) = value {
+            arguments.push(Argument::from(value));
+        }
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// `[a, b]` assignment target
+    fn create_array_target<'b>(
+        bindings: impl IntoIterator<Item = &'b BoundIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> AssignmentTarget<'a>
+    where
+        'a: 'b,
+    {
+        let elements = bindings
+            .into_iter()
+            .map(|binding| {
+                Some(AssignmentTargetMaybeDefault::from(binding.create_write_target(ctx)))
+            })
+            .collect::<Vec<_>>();
+        let elements = ctx.ast.vec_from_iter(elements);
+        AssignmentTarget::from(
+            ctx.ast.assignment_target_pattern_array_assignment_target(SPAN, elements, None),
+        )
+    }
+
+    /// `target = value`
+    fn create_assignment(
+        target: AssignmentTarget<'a>,
+        value: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+    }
+
+    /// Create a new read reference to the same binding as `ident`.
+    fn copy_identifier(
+        ident: &IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+        ctx.create_ident_expr(SPAN, ident.name, symbol_id, ReferenceFlags::Read)
+    }
+
+    /// Copy a property key, which is either a literal, or a memoized computed key.
+    fn copy_key(key: &PropertyKey<'a>, ctx: &mut TraverseCtx<'a>) -> PropertyKey<'a> {
+        match key {
+            PropertyKey::Identifier(ident) => PropertyKey::from(Self::copy_identifier(ident, ctx)),
+            key => key.clone_in(ctx.ast.allocator),
+        }
+    }
+
+    /// Name of an element passed to `_applyDecs2305`.
+    ///
+    /// * `x` / `#x` -> `"x"`
+    /// * `["x"]` -> `"x"`
+    /// * `[_computedKey]` -> `_computedKey`
+    fn create_key_name(key: &PropertyKey<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ctx.ast.expression_string_literal(SPAN, ident.name, None)
+            }
+            PropertyKey::PrivateIdentifier(ident) => {
+                ctx.ast.expression_string_literal(SPAN, ident.name, None)
+            }
+            PropertyKey::Identifier(ident) => Self::copy_identifier(ident, ctx),
+            key => key.to_expression().clone_in(ctx.ast.allocator),
+        }
+    }
+
+    /// Convert a class declaration with class decorators to a `let` declaration.
+    ///
+    /// `class C {}` -> `let C = (class C {}, _C);`
+    ///
+    /// Returns the declaration, and the binding it declares.
+    fn create_class_let_declaration(
+        class: &mut ArenaBox<'a, Class<'a>>,
+        class_binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (ArenaBox<'a, VariableDeclaration<'a>>, BoundIdentifier<'a>) {
+        let span = class.span;
+        let class_scope_id = class.scope_id();
+        let ident = class.id.as_ref().unwrap();
+
+        // Class name binding inside the class becomes a separate symbol,
+        // and the outer binding becomes a `let` binding
+        // Both symbols keep the class name's span, as both are declared by an identifier in the output
+        let id_span = ident.span;
+        let scoping = ctx.scoping_mut();
+        let inner_symbol_id = scoping.create_symbol(
+            id_span,
+            &ident.name,
+            SymbolFlags::Class,
+            class_scope_id,
+            NodeId::DUMMY,
+        );
+        scoping.add_binding(class_scope_id, &ident.name, inner_symbol_id);
+        let symbol_id = ident.symbol_id.replace(Some(inner_symbol_id)).unwrap();
+        *scoping.symbol_flags_mut(symbol_id) = SymbolFlags::BlockScopedVariable;
+        let binding = BoundIdentifier::new(ident.name, symbol_id);
+
+        class.r#type = ClassType::ClassExpression;
+        let class = Expression::ClassExpression(class.take_in_box(ctx.ast.allocator));
+        let init = Self::create_class_sequence(vec![], class, class_binding, ctx);
+        let kind = VariableDeclarationKind::Let;
+        let ident = ctx.ast.binding_identifier_with_symbol_id(id_span, binding.name, symbol_id);
+        let pattern = ctx.ast.binding_pattern(
+            BindingPatternKind::BindingIdentifier(ctx.alloc(ident)),
+            NONE,
+            false,
+        );
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, Some(init), false);
+        let declaration =
+            ctx.ast.alloc_variable_declaration(span, kind, ctx.ast.vec1(declarator), false);
+        (declaration, binding)
+    }
+
+    /// `(...memos, class C {}, _C)`
+    fn create_class_sequence(
+        mut memos: Vec<Expression<'a>>,
+        class: Expression<'a>,
+        class_binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        memos.push(class);
+        memos.push(class_binding.create_read_expression(ctx));
+        ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter(memos))
+    }
+
+    /// `export default C;`
+    fn create_export_default_reference(
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let export_default = ctx.ast.module_declaration_export_default_declaration(
+            SPAN,
+            ctx.ast.module_export_name_identifier_name(SPAN, "default"),
+            ExportDefaultDeclarationKind::Identifier(
+                ctx.ast.alloc(binding.create_read_reference(ctx)),
+            ),
+        );
+        Statement::from(export_default)
+    }
+
+    /// Insert memoized decorators and computed keys before the statement containing the class.
+    fn insert_memos_before(
+        &self,
+        stmt: &Statement<'a>,
+        memos: Vec<Expression<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        if memos.is_empty() {
+            return;
+        }
+        self.ctx.statement_injector.insert_many_before(
+            stmt,
+            memos.into_iter().map(|expr| ctx.ast.statement_expression(SPAN, expr)),
+        );
+    }
+}
+
+/// Body of a getter or setter created by this transform.
+enum AccessorBody<'a> {
+    /// `return this.#A;` / `this.#A = v;`
+    Storage(Atom<'a>),
+    /// `return _get_x(this);` / `_set_x(this, v);`
+    Call(BoundIdentifier<'a>),
+    /// `return _call_x;`
+    Value(BoundIdentifier<'a>),
+}
+
+/// Visitor to replace references to a class with references to the decorated class.
+struct ClassReferenceReplacer<'a, 'b> {
+    symbol_id: SymbolId,
+    class_binding: &'b BoundIdentifier<'a>,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for ClassReferenceReplacer<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let reference = self.ctx.scoping().get_reference(reference_id);
+        if reference.symbol_id() != Some(self.symbol_id) {
+            return;
+        }
+        let flags = reference.flags();
+        self.ctx.scoping_mut().delete_resolved_reference(self.symbol_id, reference_id);
+        *ident = self.class_binding.create_spanned_reference(ident.span, flags, self.ctx);
+    }
+}
+
+/// Visitor to call `_initProto(this)` after `super()` calls in constructor of a derived class.
+///
+/// `super()` -> `(super(), _initProto(this))`
+struct SuperCallInitProtoInserter<'a, 'b> {
+    init_proto: &'b BoundIdentifier<'a>,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for SuperCallInitProtoInserter<'a, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        walk_mut::walk_expression(self, expr);
+        if matches!(expr, Expression::CallExpression(call) if call.callee.is_super()) {
+            let super_call = expr.take_in(self.ctx.ast.allocator);
+            let call = StandardDecorator::create_init_call(self.init_proto, None, self.ctx);
+            *expr = self
+                .ctx
+                .ast
+                .expression_sequence(SPAN, self.ctx.ast.vec_from_array([super_call, call]));
+        }
+    }
+
+    // `super()` in nested functions and classes does not refer to this constructor
+    #[inline]
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    #[inline]
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
@@ -0,0 +1,953 @@
+//! Wrap loop bodies in closures.
+//!
+//! Each iteration of a loop gets fresh copies of the `let` / `const` bindings declared in the loop
+//! head or body. Once those bindings are converted to `var`s, all iterations share the same binding,
+//! which is observable if a closure created in the loop body captures one of them.
+//!
+//! In that case, the loop body is moved into a function which is called once per iteration,
+//! so each iteration still gets its own bindings.
+//!
+//! ```js
+//! for (let i = 0; i < 3; i++) {
+//!   if (i === 1) continue;
+//!   if (i === 2) break;
+//!   fns.push(() => i);
+//! }
+//! ```
+//!
+//! ->
+//!
+//! ```js
+//! var _loop = function(i) {
+//!     if (i === 1) return;
+//!     if (i === 2) return 0;
+//!     fns.push(() => i);
+//!   },
+//!   _ret;
+//! for (var i = 0; i < 3; i++) {
+//!   _ret = _loop(i);
+//!   if (_ret === 0) break;
+//! }
+//! ```
+//!
+//! * `break` and `continue` statements which jump out of the loop body return a number from the
+//!   function, which the loop checks for after each call. `continue` which targets the loop itself
+//!   becomes a plain `return`.
+//! * `return x` becomes `return { v: x }`, and the loop returns `_ret.v` once the call returns an object.
+//! * `var` declarations in the loop body are hoisted outside the function, and replaced with
+//!   assignments.
+//! * `this` and `arguments` are replaced with references to bindings outside the function.
+//! * If the loop body contains `yield` or `await`, the function is a generator or async function,
+//!   and is called with `yield*` or `await`.
+//! * If a binding from the loop head is reassigned in the loop body, its new value is copied back
+//!   to the outer binding at the end of each iteration, so the loop's update expression sees it.
+
+use std::{cell::Cell, mem};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, Scoping, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN, Span};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator};
+use oxc_traverse::{Ancestor, BoundIdentifier, TraverseCtx};
+
+use crate::utils::ast_builder::create_assignment_target_from_pattern;
+
+use super::{BlockScoping, is_let_or_const};
+
+impl<'a> BlockScoping<'a, '_> {
+    /// Wrap bodies of loops in `stmts` which contain closures capturing block-scoped bindings,
+    /// inserting the declaration of the function before the loop.
+    pub(super) fn wrap_loops_in_statements(
+        &self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut index = 0;
+        while index < stmts.len() {
+            if let Some(declaration) = self.wrap_loop(&mut stmts[index], ctx) {
+                stmts.insert(index, declaration);
+                index += 1;
+            }
+            index += 1;
+        }
+    }
+
+    /// Wrap a loop which is not in a statement list (e.g. `if (x) for (let i of y) {}`) in a block,
+    /// if its body needs to be wrapped in a function. The loop is then transformed when entering
+    /// the statements of the new block.
+    pub(super) fn wrap_loop_in_block(stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if matches!(
+            ctx.parent(),
+            Ancestor::ProgramBody(_)
+                | Ancestor::BlockStatementBody(_)
+                | Ancestor::FunctionBodyStatements(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::SwitchCaseConsequent(_)
+                | Ancestor::TSModuleBlockBody(_)
+                | Ancestor::LabeledStatementBody(_)
+        ) {
+            return;
+        }
+
+        let mut labels = vec![];
+        let Some(loop_stmt) = find_loop(stmt, &mut labels) else { return };
+        let (body, head_symbols) = split_loop(loop_stmt);
+        let info = LoopBodyInfo::analyze(body, &head_symbols, ctx.scoping());
+        if !info.has_captured_binding {
+            return;
+        }
+
+        let scope_id = ctx.insert_scope_below_statement(stmt, ScopeFlags::empty());
+        let span = stmt.span();
+        let body = ctx.ast.vec1(stmt.take_in(ctx.ast.allocator));
+        *stmt = ctx.ast.statement_block_with_scope_id(span, body, scope_id);
+    }
+
+    /// Wrap body of loop `stmt` in a function, if required.
+    ///
+    /// Returns the declaration of the function, to be inserted before `stmt`.
+    fn wrap_loop(
+        &self,
+        stmt: &mut Statement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        let mut own_labels = vec![];
+        let loop_stmt = find_loop(stmt, &mut own_labels)?;
+        let loop_span = loop_stmt.span();
+        let loop_scope_id = match loop_stmt {
+            Statement::ForStatement(for_stmt) => Some(for_stmt.scope_id()),
+            Statement::ForInStatement(for_stmt) => Some(for_stmt.scope_id()),
+            Statement::ForOfStatement(for_stmt) => Some(for_stmt.scope_id()),
+            _ => None,
+        };
+        let is_for_statement = matches!(loop_stmt, Statement::ForStatement(_));
+        let (body, head_symbols) = split_loop(loop_stmt);
+
+        let info = LoopBodyInfo::analyze(body, &head_symbols, ctx.scoping());
+        if !info.has_captured_binding {
+            return None;
+        }
+        if self.options.throw_if_closure_required {
+            self.ctx.error(
+                OxcDiagnostic::error(
+                    "Compiling let/const in this block would add a closure (throwIfClosureRequired).",
+                )
+                .with_label(loop_span),
+            );
+            return None;
+        }
+        if let Some(span) = info.super_span {
+            self.ctx.error(
+                OxcDiagnostic::error(
+                    "Cannot wrap a loop body containing `super` or `new.target` in a closure.",
+                )
+                .with_label(span),
+            );
+            return None;
+        }
+
+        let outer_scope_id = ctx.current_scope_id();
+        let hoist_scope_id = ctx.current_hoist_scope_id();
+        let strict_mode_flag = if ctx.current_scope_flags().is_strict_mode() {
+            ScopeFlags::StrictMode
+        } else {
+            ScopeFlags::empty()
+        };
+
+        // Body's scope becomes the scope of the function
+        let body_scope_id = if let Statement::BlockStatement(block) = body {
+            block.scope_id()
+        } else {
+            let parent_scope_id = loop_scope_id.unwrap_or(outer_scope_id);
+            ctx.insert_scope_below_statement_from_scope_id(
+                body,
+                parent_scope_id,
+                ScopeFlags::empty(),
+            )
+        };
+        *ctx.scoping_mut().scope_flags_mut(body_scope_id) = ScopeFlags::Function | strict_mode_flag;
+        ctx.scoping_mut().change_scope_parent_id(body_scope_id, Some(outer_scope_id));
+
+        // Create a parameter for each binding from loop head which is referenced in the body
+        let mut params = FxHashMap::default();
+        let mut param_bindings = vec![];
+        let mut write_backs = vec![];
+        for &symbol_id in &head_symbols {
+            if !info.referenced.contains(&symbol_id) {
+                continue;
+            }
+            let name = ctx.ast.atom(ctx.scoping().symbol_name(symbol_id));
+            let is_mutated = is_for_statement && info.mutated.contains(&symbol_id);
+            let flags = SymbolFlags::FunctionScopedVariable;
+            let param = if is_mutated || ctx.scoping().scope_has_binding(body_scope_id, &name) {
+                ctx.generate_uid(&name, body_scope_id, flags)
+            } else {
+                ctx.generate_binding(name, body_scope_id, flags)
+            };
+            let outer = BoundIdentifier::new(name, symbol_id);
+            if is_mutated {
+                write_backs.push((outer.clone(), param.clone()));
+            }
+            params.insert(symbol_id, param.clone());
+            param_bindings.push((outer, param));
+        }
+
+        let this_binding = info
+            .has_this
+            .then(|| ctx.generate_uid("this", hoist_scope_id, SymbolFlags::FunctionScopedVariable));
+        let arguments_binding = info.has_arguments.then(|| {
+            ctx.generate_uid("arguments", hoist_scope_id, SymbolFlags::FunctionScopedVariable)
+        });
+
+        let mut transformer = LoopBodyTransformer {
+            params,
+            write_backs: &write_backs,
+            own_labels: &own_labels,
+            this_binding: this_binding.as_ref(),
+            arguments_binding: arguments_binding.as_ref(),
+            completions: vec![],
+            has_return: false,
+            hoisted_vars: vec![],
+            scope_ids: vec![body_scope_id],
+            labels: vec![],
+            function_depth: 0,
+            this_depth: 0,
+            loop_depth: 0,
+            switch_depth: 0,
+            removed: false,
+            ctx,
+        };
+        transformer.visit_statement(body);
+        let LoopBodyTransformer { completions, has_return, hoisted_vars, .. } = transformer;
+
+        // Hoist `var` declarations from the loop body
+        for (span, name, symbol_id) in hoisted_vars {
+            let ident = ctx.ast.binding_identifier_with_symbol_id(span, name, symbol_id);
+            let pattern = ctx.ast.binding_pattern(
+                BindingPatternKind::BindingIdentifier(ctx.alloc(ident)),
+                NONE,
+                false,
+            );
+            self.ctx.var_declarations.insert_var_binding_pattern(pattern, None, ctx);
+        }
+
+        // `function(i) { ...body; i = _i; }`
+        let body_span = body.span();
+        let mut stmts = match body.take_in(ctx.ast.allocator) {
+            Statement::BlockStatement(block) => block.unbox().body,
+            stmt => ctx.ast.vec1(stmt),
+        };
+        for (outer, param) in &write_backs {
+            let assignment = create_assignment(outer, param, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, assignment));
+        }
+        let formal_params = ctx.ast.vec_from_iter(param_bindings.iter().map(|(_, param)| {
+            ctx.ast.formal_parameter(
+                SPAN,
+                ctx.ast.vec(),
+                param.create_binding_pattern(ctx),
+                None,
+                false,
+                false,
+            )
+        }));
+        let formal_params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            formal_params,
+            NONE,
+        );
+        let function_body = ctx.ast.alloc_function_body(body_span, ctx.ast.vec(), stmts);
+        let function = ctx.ast.expression_function_with_scope_id_and_pure(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            info.has_yield,
+            info.has_await,
+            false,
+            NONE,
+            NONE,
+            formal_params,
+            NONE,
+            Some(function_body),
+            body_scope_id,
+            false,
+        );
+
+        // `var _this = this, _loop = function(i) { ... }, _ret;`
+        let kind = VariableDeclarationKind::Var;
+        let mut declarators = ctx.ast.vec();
+        if let Some(this_binding) = &this_binding {
+            let init = ctx.ast.expression_this(SPAN);
+            let pattern = this_binding.create_binding_pattern(ctx);
+            declarators.push(ctx.ast.variable_declarator(SPAN, kind, pattern, Some(init), false));
+        }
+        if let Some(arguments_binding) = &arguments_binding {
+            let init =
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("arguments"), ReferenceFlags::Read);
+            let pattern = arguments_binding.create_binding_pattern(ctx);
+            declarators.push(ctx.ast.variable_declarator(SPAN, kind, pattern, Some(init), false));
+        }
+        let loop_binding =
+            ctx.generate_uid("loop", hoist_scope_id, SymbolFlags::FunctionScopedVariable);
+        let pattern = loop_binding.create_binding_pattern(ctx);
+        declarators.push(ctx.ast.variable_declarator(SPAN, kind, pattern, Some(function), false));
+        let ret_binding = (!completions.is_empty() || has_return).then(|| {
+            let binding =
+                ctx.generate_uid("ret", hoist_scope_id, SymbolFlags::FunctionScopedVariable);
+            let pattern = binding.create_binding_pattern(ctx);
+            declarators.push(ctx.ast.variable_declarator(SPAN, kind, pattern, None, false));
+            binding
+        });
+        let declaration = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            declarators,
+            false,
+        ));
+
+        // `_loop(i)`, `yield* _loop(i)` or `await _loop(i)`
+        let arguments = ctx.ast.vec_from_iter(
+            param_bindings
+                .iter()
+                .map(|(outer, _)| Argument::from(outer.create_read_expression(ctx))),
+        );
+        let callee = loop_binding.create_read_expression(ctx);
+        let mut call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        if info.has_yield {
+            call = ctx.ast.expression_yield(SPAN, true, Some(call));
+        } else if info.has_await {
+            call = ctx.ast.expression_await(SPAN, call);
+        }
+
+        // New loop body
+        let block_scope_id =
+            ctx.create_child_scope(loop_scope_id.unwrap_or(outer_scope_id), ScopeFlags::empty());
+        let mut new_body = ctx.ast.vec();
+        if let Some(ret_binding) = ret_binding {
+            // `_ret = _loop(i);`
+            let assignment = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                ret_binding.create_write_target(ctx),
+                call,
+            );
+            new_body.push(ctx.ast.statement_expression(SPAN, assignment));
+            // `if (_ret === 0) break;`
+            for (index, completion) in completions.iter().enumerate() {
+                let test = ctx.ast.expression_binary(
+                    SPAN,
+                    ret_binding.create_read_expression(ctx),
+                    BinaryOperator::StrictEquality,
+                    create_number_literal(index, ctx),
+                );
+                let label = completion.label.map(|name| ctx.ast.label_identifier(SPAN, name));
+                let consequent = match completion.kind {
+                    JumpKind::Break => ctx.ast.statement_break(SPAN, label),
+                    JumpKind::Continue => ctx.ast.statement_continue(SPAN, label),
+                };
+                new_body.push(ctx.ast.statement_if(SPAN, test, consequent, None));
+            }
+            // `if (_ret) return _ret.v;`
+            if has_return {
+                let test = ret_binding.create_read_expression(ctx);
+                let object = ret_binding.create_read_expression(ctx);
+                let value = Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    object,
+                    ctx.ast.identifier_name(SPAN, "v"),
+                    false,
+                ));
+                let consequent = ctx.ast.statement_return(SPAN, Some(value));
+                new_body.push(ctx.ast.statement_if(SPAN, test, consequent, None));
+            }
+        } else {
+            new_body.push(ctx.ast.statement_expression(SPAN, call));
+        }
+        *body = ctx.ast.statement_block_with_scope_id(body_span, new_body, block_scope_id);
+
+        Some(declaration)
+    }
+}
+
+/// Find loop statement, skipping over any labels, and collect the labels.
+fn find_loop<'a, 's>(
+    stmt: &'s mut Statement<'a>,
+    labels: &mut Vec<Atom<'a>>,
+) -> Option<&'s mut Statement<'a>> {
+    if stmt.is_iteration_statement() {
+        return Some(stmt);
+    }
+    if let Statement::LabeledStatement(labeled) = stmt {
+        labels.push(labeled.label.name);
+        return find_loop(&mut labeled.body, labels);
+    }
+    None
+}
+
+/// Get body of a loop, and symbols of `let` / `const` bindings declared in the loop head.
+fn split_loop<'a, 's>(stmt: &'s mut Statement<'a>) -> (&'s mut Statement<'a>, Vec<SymbolId>) {
+    let mut head_symbols = vec![];
+    let mut collect = |decl: &VariableDeclaration<'a>| {
+        if is_let_or_const(decl.kind) {
+            decl.bound_names(&mut |ident| head_symbols.push(ident.symbol_id()));
+        }
+    };
+    let body = match stmt {
+        Statement::ForStatement(for_stmt) => {
+            if let Some(ForStatementInit::VariableDeclaration(decl)) = &for_stmt.init {
+                collect(decl);
+            }
+            &mut for_stmt.body
+        }
+        Statement::ForInStatement(for_stmt) => {
+            if let ForStatementLeft::VariableDeclaration(decl) = &for_stmt.left {
+                collect(decl);
+            }
+            &mut for_stmt.body
+        }
+        Statement::ForOfStatement(for_stmt) => {
+            if let ForStatementLeft::VariableDeclaration(decl) = &for_stmt.left {
+                collect(decl);
+            }
+            &mut for_stmt.body
+        }
+        Statement::WhileStatement(while_stmt) => &mut while_stmt.body,
+        Statement::DoWhileStatement(do_while_stmt) => &mut do_while_stmt.body,
+        _ => unreachable!(),
+    };
+    (body, head_symbols)
+}
+
+/// `i = _i`
+fn create_assignment<'a>(
+    target: &BoundIdentifier<'a>,
+    value: &BoundIdentifier<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    ctx.ast.expression_assignment(
+        SPAN,
+        AssignmentOperator::Assign,
+        target.create_write_target(ctx),
+        value.create_read_expression(ctx),
+    )
+}
+
+#[expect(clippy::cast_precision_loss)]
+fn create_number_literal<'a>(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    ctx.ast.expression_numeric_literal(SPAN, value as f64, None, NumberBase::Decimal)
+}
+
+/// Information about a loop body, collected before deciding whether to wrap it in a function.
+#[derive(Default)]
+struct LoopBodyInfo {
+    /// `true` if a closure in the loop body references a binding from the loop head,
+    /// or a `let` / `const` binding declared in the loop body.
+    has_captured_binding: bool,
+    /// Bindings from the loop head which are referenced in the loop body.
+    referenced: FxHashSet<SymbolId>,
+    /// Bindings from the loop head which are written to in the loop body.
+    mutated: FxHashSet<SymbolId>,
+    has_yield: bool,
+    has_await: bool,
+    has_this: bool,
+    has_arguments: bool,
+    /// Span of `super` or `new.target` in the loop body, which can't be moved into a function.
+    super_span: Option<Span>,
+}
+
+impl LoopBodyInfo {
+    fn analyze(body: &Statement<'_>, head_symbols: &[SymbolId], scoping: &Scoping) -> Self {
+        let mut analyzer = LoopBodyAnalyzer {
+            scoping,
+            head_symbols,
+            info: Self::default(),
+            body_symbols: FxHashSet::default(),
+            captured_symbols: vec![],
+            function_depth: 0,
+            this_depth: 0,
+            loop_depth: 0,
+        };
+        analyzer.visit_statement(body);
+
+        let LoopBodyAnalyzer { mut info, body_symbols, captured_symbols, .. } = analyzer;
+        info.has_captured_binding = captured_symbols
+            .iter()
+            .any(|symbol_id| head_symbols.contains(symbol_id) || body_symbols.contains(symbol_id));
+        info
+    }
+}
+
+/// Visitor which collects [`LoopBodyInfo`].
+struct LoopBodyAnalyzer<'s> {
+    scoping: &'s Scoping,
+    head_symbols: &'s [SymbolId],
+    info: LoopBodyInfo,
+    /// `let` / `const` bindings declared in the loop body, outside of nested loops and functions
+    body_symbols: FxHashSet<SymbolId>,
+    /// Bindings referenced inside closures
+    captured_symbols: Vec<SymbolId>,
+    /// Depth of functions, arrow functions and classes
+    function_depth: u32,
+    /// Depth of functions and classes, which have their own `this`
+    this_depth: u32,
+    /// Depth of nested loops
+    loop_depth: u32,
+}
+
+impl<'a> Visit<'a> for LoopBodyAnalyzer<'_> {
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        self.this_depth += 1;
+        walk::walk_function(self, func, flags);
+        self.function_depth -= 1;
+        self.this_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        self.function_depth += 1;
+        walk::walk_arrow_function_expression(self, arrow);
+        self.function_depth -= 1;
+    }
+
+    fn visit_class_body(&mut self, body: &ClassBody<'a>) {
+        self.function_depth += 1;
+        self.this_depth += 1;
+        walk::walk_class_body(self, body);
+        self.function_depth -= 1;
+        self.this_depth -= 1;
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        if is_let_or_const(decl.kind) && self.function_depth == 0 && self.loop_depth == 0 {
+            decl.bound_names(&mut |ident| {
+                self.body_symbols.insert(ident.symbol_id());
+            });
+        }
+        walk::walk_variable_declaration(self, decl);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference = self.scoping.get_reference(ident.reference_id());
+        if let Some(symbol_id) = reference.symbol_id() {
+            if self.head_symbols.contains(&symbol_id) {
+                self.info.referenced.insert(symbol_id);
+                if reference.is_write() {
+                    self.info.mutated.insert(symbol_id);
+                }
+            }
+            if self.function_depth > 0 {
+                self.captured_symbols.push(symbol_id);
+            }
+        } else if self.this_depth == 0 && ident.name == "arguments" {
+            self.info.has_arguments = true;
+        }
+    }
+
+    fn visit_this_expression(&mut self, _: &ThisExpression) {
+        if self.this_depth == 0 {
+            self.info.has_this = true;
+        }
+    }
+
+    fn visit_super(&mut self, sup: &Super) {
+        if self.this_depth == 0 {
+            self.info.super_span = Some(sup.span);
+        }
+    }
+
+    fn visit_meta_property(&mut self, meta: &MetaProperty<'a>) {
+        if self.this_depth == 0 && meta.meta.name == "new" {
+            self.info.super_span = Some(meta.span);
+        }
+    }
+
+    fn visit_yield_expression(&mut self, expr: &YieldExpression<'a>) {
+        if self.function_depth == 0 {
+            self.info.has_yield = true;
+        }
+        walk::walk_yield_expression(self, expr);
+    }
+
+    fn visit_await_expression(&mut self, expr: &AwaitExpression<'a>) {
+        if self.function_depth == 0 {
+            self.info.has_await = true;
+        }
+        walk::walk_await_expression(self, expr);
+    }
+
+    fn visit_for_statement(&mut self, stmt: &ForStatement<'a>) {
+        self.loop_depth += 1;
+        walk::walk_for_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &ForInStatement<'a>) {
+        self.loop_depth += 1;
+        walk::walk_for_in_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ForOfStatement<'a>) {
+        if stmt.r#await && self.function_depth == 0 {
+            self.info.has_await = true;
+        }
+        self.loop_depth += 1;
+        walk::walk_for_of_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_while_statement(&mut self, stmt: &WhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk::walk_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_do_while_statement(&mut self, stmt: &DoWhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk::walk_do_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum JumpKind {
+    Break,
+    Continue,
+}
+
+/// A `break` or `continue` which jumps out of the loop body.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Completion<'a> {
+    kind: JumpKind,
+    /// `None` if the jump targets the loop itself
+    label: Option<Atom<'a>>,
+}
+
+/// Visitor which transforms loop body into body of the function.
+struct LoopBodyTransformer<'a, 'v> {
+    /// Map from loop head bindings to function parameters
+    params: FxHashMap<SymbolId, BoundIdentifier<'a>>,
+    /// Pairs of (outer binding, parameter) for loop head bindings which are written to in the body
+    write_backs: &'v [(BoundIdentifier<'a>, BoundIdentifier<'a>)],
+    /// Labels of the loop
+    own_labels: &'v [Atom<'a>],
+    this_binding: Option<&'v BoundIdentifier<'a>>,
+    arguments_binding: Option<&'v BoundIdentifier<'a>>,
+    /// Jumps out of the loop body. Index is the value returned from the function.
+    completions: Vec<Completion<'a>>,
+    has_return: bool,
+    /// `var` bindings declared in the loop body, to be hoisted out of the function
+    hoisted_vars: Vec<(Span, Atom<'a>, SymbolId)>,
+    /// Stack of scopes, used as parent for new block statements
+    scope_ids: Vec<ScopeId>,
+    /// Labels defined within the loop body
+    labels: Vec<Atom<'a>>,
+    function_depth: u32,
+    this_depth: u32,
+    loop_depth: u32,
+    switch_depth: u32,
+    /// Set when a statement has been removed, to remove it from the enclosing statement list
+    removed: bool,
+    ctx: &'v mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for LoopBodyTransformer<'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        self.scope_ids.push(scope_id.get().unwrap());
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_ids.pop();
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        self.this_depth += 1;
+        walk_mut::walk_function(self, func, flags);
+        self.function_depth -= 1;
+        self.this_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.function_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.function_depth -= 1;
+    }
+
+    fn visit_class_body(&mut self, body: &mut ClassBody<'a>) {
+        self.function_depth += 1;
+        self.this_depth += 1;
+        walk_mut::walk_class_body(self, body);
+        self.function_depth -= 1;
+        self.this_depth -= 1;
+    }
+
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        stmts.retain_mut(|stmt| {
+            self.visit_statement(stmt);
+            !mem::take(&mut self.removed)
+        });
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        if self.function_depth > 0 {
+            walk_mut::walk_statement(self, stmt);
+            return;
+        }
+
+        match stmt {
+            // `var a = 1;` -> `a = 1;`
+            Statement::VariableDeclaration(decl) if decl.kind.is_var() => {
+                let span = decl.span;
+                let expr = self.convert_var_declaration(decl);
+                *stmt = if let Some(expr) = expr {
+                    self.ctx.ast.statement_expression(span, expr)
+                } else {
+                    self.removed = true;
+                    self.ctx.ast.statement_empty(span)
+                };
+            }
+            Statement::LabeledStatement(labeled) => {
+                self.labels.push(labeled.label.name);
+                walk_mut::walk_labeled_statement(self, labeled);
+                self.labels.pop();
+                return;
+            }
+            Statement::SwitchStatement(switch) => {
+                self.switch_depth += 1;
+                walk_mut::walk_switch_statement(self, switch);
+                self.switch_depth -= 1;
+                return;
+            }
+            _ => {}
+        }
+
+        walk_mut::walk_statement(self, stmt);
+
+        match stmt {
+            Statement::BreakStatement(break_stmt) => {
+                let label = break_stmt.label.as_ref().map(|label| label.name);
+                if let Some(new_stmt) = self.transform_jump(JumpKind::Break, label, break_stmt.span)
+                {
+                    *stmt = new_stmt;
+                }
+            }
+            Statement::ContinueStatement(continue_stmt) => {
+                let label = continue_stmt.label.as_ref().map(|label| label.name);
+                if let Some(new_stmt) =
+                    self.transform_jump(JumpKind::Continue, label, continue_stmt.span)
+                {
+                    *stmt = new_stmt;
+                }
+            }
+            // `return x;` -> `return { v: x };`
+            Statement::ReturnStatement(return_stmt) => {
+                self.has_return = true;
+                let value = return_stmt
+                    .argument
+                    .take()
+                    .unwrap_or_else(|| self.ctx.ast.void_0(return_stmt.span));
+                let property = self.ctx.ast.object_property_kind_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    self.ctx.ast.property_key_static_identifier(SPAN, "v"),
+                    value,
+                    false,
+                    false,
+                    false,
+                );
+                let object = self.ctx.ast.expression_object(SPAN, self.ctx.ast.vec1(property));
+                return_stmt.argument = Some(object);
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        // `for (var i = 0;;)` -> `for (i = 0;;)`
+        if self.function_depth == 0 {
+            if let Some(ForStatementInit::VariableDeclaration(decl)) = &mut stmt.init {
+                if decl.kind.is_var() {
+                    stmt.init = self.convert_var_declaration(decl).map(ForStatementInit::from);
+                }
+            }
+        }
+        self.loop_depth += 1;
+        walk_mut::walk_for_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        self.convert_for_statement_left(&mut stmt.left);
+        self.loop_depth += 1;
+        walk_mut::walk_for_in_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        self.convert_for_statement_left(&mut stmt.left);
+        self.loop_depth += 1;
+        walk_mut::walk_for_of_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_while_statement(&mut self, stmt: &mut WhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk_mut::walk_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_do_while_statement(&mut self, stmt: &mut DoWhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk_mut::walk_do_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if self.this_depth == 0 {
+            match expr {
+                // `this` -> `_this`
+                Expression::ThisExpression(this) => {
+                    if let Some(binding) = self.this_binding {
+                        *expr = binding.create_spanned_read_expression(this.span, self.ctx);
+                    }
+                    return;
+                }
+                // `arguments` -> `_arguments`
+                Expression::Identifier(ident) if ident.name == "arguments" => {
+                    if let Some(binding) = self.arguments_binding {
+                        if !self.ctx.scoping().has_binding(ident.reference_id()) {
+                            self.ctx.delete_reference_for_identifier(ident);
+                            *expr = binding.create_spanned_read_expression(ident.span, self.ctx);
+                            return;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let Some(symbol_id) = self.ctx.scoping().get_reference(reference_id).symbol_id() else {
+            return;
+        };
+        let Some(param) = self.params.get(&symbol_id) else { return };
+        ident.name = param.name;
+        let scoping = self.ctx.scoping_mut();
+        scoping.get_reference_mut(reference_id).set_symbol_id(param.symbol_id);
+        scoping.delete_resolved_reference(symbol_id, reference_id);
+        scoping.add_resolved_reference(param.symbol_id, reference_id);
+    }
+}
+
+impl<'a> LoopBodyTransformer<'a, '_> {
+    /// Transform `break` or `continue` statement, if it jumps out of the loop body.
+    fn transform_jump(
+        &mut self,
+        kind: JumpKind,
+        label: Option<Atom<'a>>,
+        span: Span,
+    ) -> Option<Statement<'a>> {
+        let label = match label {
+            Some(label) if self.labels.contains(&label) => return None,
+            Some(label) if self.own_labels.contains(&label) => None,
+            Some(label) => Some(label),
+            None => {
+                let is_internal = match kind {
+                    JumpKind::Break => self.loop_depth > 0 || self.switch_depth > 0,
+                    JumpKind::Continue => self.loop_depth > 0,
+                };
+                if is_internal {
+                    return None;
+                }
+                None
+            }
+        };
+
+        let ctx = &mut *self.ctx;
+        if kind == JumpKind::Continue && label.is_none() {
+            // `continue` -> `return`, or `{ i = _i; return; }` if loop head bindings are mutated
+            let return_stmt = ctx.ast.statement_return(span, None);
+            if self.write_backs.is_empty() {
+                return Some(return_stmt);
+            }
+            let scope_id =
+                ctx.create_child_scope(*self.scope_ids.last().unwrap(), ScopeFlags::empty());
+            let mut stmts = ctx.ast.vec_with_capacity(self.write_backs.len() + 1);
+            for (outer, param) in self.write_backs {
+                let assignment = create_assignment(outer, param, ctx);
+                stmts.push(ctx.ast.statement_expression(SPAN, assignment));
+            }
+            stmts.push(return_stmt);
+            return Some(ctx.ast.statement_block_with_scope_id(span, stmts, scope_id));
+        }
+
+        // `break` -> `return 0`
+        let completion = Completion { kind, label };
+        let index = self.completions.iter().position(|&c| c == completion).unwrap_or_else(|| {
+            self.completions.push(completion);
+            self.completions.len() - 1
+        });
+        let value = create_number_literal(index, ctx);
+        Some(ctx.ast.statement_return(span, Some(value)))
+    }
+
+    /// `var a = 1, b, [c] = d` -> `a = 1, [c] = d`
+    ///
+    /// Returns `None` if none of the declarators have an initializer.
+    /// Bindings are recorded in `hoisted_vars`.
+    fn convert_var_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+    ) -> Option<Expression<'a>> {
+        let mut expressions = self.ctx.ast.vec();
+        for declarator in decl.declarations.take_in(self.ctx.ast.allocator) {
+            self.record_hoisted_vars(&declarator.id);
+            if let Some(init) = declarator.init {
+                let target = create_assignment_target_from_pattern(declarator.id, self.ctx);
+                expressions.push(self.ctx.ast.expression_assignment(
+                    declarator.span,
+                    AssignmentOperator::Assign,
+                    target,
+                    init,
+                ));
+            }
+        }
+        match expressions.len() {
+            0 => None,
+            1 => expressions.pop(),
+            _ => Some(self.ctx.ast.expression_sequence(decl.span, expressions)),
+        }
+    }
+
+    /// `for (var a in b)` -> `for (a in b)`
+    fn convert_for_statement_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        if self.function_depth > 0 {
+            return;
+        }
+        let ForStatementLeft::VariableDeclaration(decl) = left else { return };
+        if !decl.kind.is_var() {
+            return;
+        }
+        let declarator = decl.declarations.pop().unwrap();
+        self.record_hoisted_vars(&declarator.id);
+        *left =
+            ForStatementLeft::from(create_assignment_target_from_pattern(declarator.id, self.ctx));
+    }
+
+    fn record_hoisted_vars(&mut self, pattern: &BindingPattern<'a>) {
+        pattern.bound_names(&mut |ident| {
+            self.hoisted_vars.push((ident.span, ident.name, ident.symbol_id()));
+        });
+    }
+}
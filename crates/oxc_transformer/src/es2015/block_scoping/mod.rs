@@ -0,0 +1,365 @@
+//! ES2015: Block Scoping
+//!
+//! This plugin transforms `let` and `const` declarations into `var` declarations.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! let a = 1;
+//! {
+//!   let a = 2;
+//!   console.log(a);
+//! }
+//! for (let i = 0; i < 3; i++) {
+//!   fns.push(() => i);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var a = 1;
+//! {
+//!   var _a = 2;
+//!   console.log(_a);
+//! }
+//! var _loop = function(i) {
+//!   fns.push(() => i);
+//! };
+//! for (var i = 0; i < 3; i++) {
+//!   _loop(i);
+//! }
+//! ```
+//!
+//! ## Options
+//!
+//! ### `tdz`
+//!
+//! Insert checks which throw when a binding is read before its declaration has been evaluated.
+//! See [`tdz`] module.
+//!
+//! ### `throwIfClosureRequired`
+//!
+//! Report an error instead of wrapping a loop body in a function.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-block-scoping](https://babel.dev/docs/babel-plugin-transform-block-scoping).
+//!
+//! On entering a block, `for` statement or `switch` statement, `let` and `const` bindings
+//! declared in it are moved to the enclosing function's scope, which is where the `var` they
+//! become is bound. If the name of a binding is already bound in an enclosing scope, or is a global
+//! referenced anywhere in the program, the binding is renamed first so it doesn't shadow
+//! the outer binding once hoisted. All references to a binding are inside the block,
+//! so are renamed as they are visited.
+//!
+//! Declarations are converted to `var` on exit. `let x;` inside a loop body becomes
+//! `var x = void 0;`, because the `var` would otherwise retain its value from the previous iteration.
+//!
+//! Loop bodies containing closures which capture block-scoped bindings are wrapped in a function.
+//! See [`loops`] module.
+//!
+//! Classes converted to `let` declarations by classes plugin are converted to `var` after the
+//! statement list containing them has been transformed. This also catches `let` declarations
+//! inserted by other transforms at the top of statement blocks.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-block-scoping>
+//! * Block specification: <https://tc39.es/ecma262/#sec-block>
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::ast::*;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceId, ScopeId, SymbolFlags, SymbolId};
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
+
+use crate::context::TransformCtx;
+
+mod loops;
+mod read_only;
+mod tdz;
+
+use tdz::TdzStatus;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct BlockScopingOptions {
+    pub tdz: bool,
+    pub throw_if_closure_required: bool,
+}
+
+pub struct BlockScoping<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    options: BlockScopingOptions,
+    /// If `true`, class declarations are converted to `let` declarations by classes plugin.
+    classes_enabled: bool,
+    /// Symbols which have been renamed, and their new names
+    renamed_symbols: FxHashMap<SymbolId, Atom<'a>>,
+    /// References which need TDZ checks
+    tdz_references: FxHashMap<ReferenceId, TdzStatus>,
+    /// Symbols which are set to `temporalUndefined` before their declaration
+    tdz_symbols: FxHashSet<SymbolId>,
+    /// `const` bindings which have been converted to `var`s
+    const_symbols: FxHashSet<SymbolId>,
+}
+
+impl<'a, 'ctx> BlockScoping<'a, 'ctx> {
+    pub fn new(
+        options: BlockScopingOptions,
+        classes_enabled: bool,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        Self {
+            ctx,
+            options,
+            classes_enabled,
+            renamed_symbols: FxHashMap::default(),
+            tdz_references: FxHashMap::default(),
+            tdz_symbols: FxHashSet::default(),
+            const_symbols: FxHashSet::default(),
+        }
+    }
+}
+
+impl<'a> Traverse<'a> for BlockScoping<'a, '_> {
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.tdz {
+            self.insert_tdz_checks(stmts, ctx);
+        }
+        self.wrap_loops_in_statements(stmts, ctx);
+    }
+
+    /// Must run after `VarDeclarations` and `StatementInjector`, to convert `let` declarations
+    /// which they insert.
+    fn exit_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        for stmt in stmts.iter_mut() {
+            let decl = match stmt {
+                Statement::VariableDeclaration(decl) => decl,
+                Statement::ExportNamedDeclaration(export) => match &mut export.declaration {
+                    Some(Declaration::VariableDeclaration(decl)) => decl,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            if is_let_or_const(decl.kind) {
+                self.convert_declaration(decl, false, ctx);
+            }
+        }
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        Self::wrap_loop_in_block(stmt, ctx);
+    }
+
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_bindings(block.scope_id(), ctx);
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_bindings(stmt.scope_id(), ctx);
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_bindings(stmt.scope_id(), ctx);
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_bindings(stmt.scope_id(), ctx);
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.hoist_bindings(stmt.scope_id(), ctx);
+    }
+
+    fn exit_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if is_let_or_const(decl.kind) {
+            let is_in_loop_body = Self::is_in_loop_body(ctx);
+            self.convert_declaration(decl, is_in_loop_body, ctx);
+        }
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.renamed_symbols.is_empty() {
+            return;
+        }
+        if let Some(symbol_id) = ident.symbol_id.get() {
+            if let Some(&name) = self.renamed_symbols.get(&symbol_id) {
+                ident.name = name;
+            }
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.renamed_symbols.is_empty() {
+            return;
+        }
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        if let Some(symbol_id) = ctx.scoping().get_reference(reference_id).symbol_id() {
+            if let Some(&name) = self.renamed_symbols.get(&symbol_id) {
+                ident.name = name;
+            }
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.tdz_references.is_empty() {
+            self.transform_tdz_reference(expr, ctx);
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if matches!(expr, Expression::AssignmentExpression(_) | Expression::UpdateExpression(_)) {
+            self.transform_read_only_assignment(expr, ctx);
+        }
+    }
+}
+
+impl<'a> BlockScoping<'a, '_> {
+    /// Move `let` / `const` bindings declared in `scope_id` to the enclosing function's scope,
+    /// renaming them if they would shadow a binding in an outer scope.
+    fn hoist_bindings(&mut self, scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) {
+        let hoist_scope_id = ctx.current_hoist_scope_id();
+        let symbol_ids = ctx
+            .scoping()
+            .iter_bindings_in(scope_id)
+            .filter(|&symbol_id| {
+                let flags = ctx.scoping().symbol_flags(symbol_id);
+                (flags.contains(SymbolFlags::BlockScopedVariable) && !flags.is_catch_variable())
+                    || (self.classes_enabled && flags.is_class())
+            })
+            .collect::<Vec<_>>();
+
+        for symbol_id in symbol_ids {
+            let mut name = ctx.ast.atom(ctx.scoping().symbol_name(symbol_id));
+            let is_shadowing = ctx
+                .scoping()
+                .scope_ancestors(scope_id)
+                .skip(1)
+                .any(|scope_id| ctx.scoping().scope_has_binding(scope_id, &name))
+                || ctx.scoping().root_unresolved_references().contains_key(name.as_str());
+            if is_shadowing {
+                let new_name = ctx.generate_uid_name(&name);
+                ctx.rename_symbol(symbol_id, scope_id, new_name.clone());
+                name = ctx.ast.atom(&new_name);
+                self.renamed_symbols.insert(symbol_id, name);
+            }
+
+            let flags = ctx.scoping().symbol_flags(symbol_id);
+            if flags.is_const_variable() {
+                self.const_symbols.insert(symbol_id);
+            }
+            // Classes are hoisted once they've been converted to `let` declarations
+            if !flags.is_class() {
+                Self::hoist_binding(symbol_id, name, hoist_scope_id, ctx);
+            }
+        }
+    }
+
+    /// Move binding to `hoist_scope_id`, and mark it as a `var`.
+    fn hoist_binding(
+        symbol_id: SymbolId,
+        name: Atom<'a>,
+        hoist_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let scoping = ctx.scoping_mut();
+        *scoping.symbol_flags_mut(symbol_id) = SymbolFlags::FunctionScopedVariable;
+        let scope_id = scoping.symbol_scope_id(symbol_id);
+        if scope_id != hoist_scope_id {
+            scoping.move_binding(scope_id, hoist_scope_id, &name);
+            scoping.set_symbol_scope_id(symbol_id, hoist_scope_id);
+        }
+    }
+
+    /// `let a = 1, b;` -> `var a = 1, b;`
+    ///
+    /// If `is_in_loop_body` is `true`, declarators without an initializer are initialized
+    /// to `void 0`.
+    fn convert_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        is_in_loop_body: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let hoist_scope_id = ctx.current_hoist_scope_id();
+        if decl.kind == VariableDeclarationKind::Const {
+            decl.bound_names(&mut |ident| {
+                self.const_symbols.insert(ident.symbol_id());
+            });
+        }
+        decl.kind = VariableDeclarationKind::Var;
+        for declarator in &mut decl.declarations {
+            declarator.kind = VariableDeclarationKind::Var;
+            declarator.id.bound_names(&mut |ident| {
+                Self::hoist_binding(ident.symbol_id(), ident.name, hoist_scope_id, ctx);
+            });
+            if declarator.init.is_none() {
+                if let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind {
+                    if is_in_loop_body || self.tdz_symbols.contains(&ident.symbol_id()) {
+                        declarator.init = Some(ctx.ast.void_0(declarator.span));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Check if variable declaration is a statement in the body of a loop,
+    /// not in a nested function.
+    fn is_in_loop_body(ctx: &TraverseCtx<'a>) -> bool {
+        if matches!(
+            ctx.parent(),
+            Ancestor::ForStatementInit(_)
+                | Ancestor::ForInStatementLeft(_)
+                | Ancestor::ForOfStatementLeft(_)
+        ) {
+            return false;
+        }
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::ForStatementBody(_)
+                | Ancestor::ForInStatementBody(_)
+                | Ancestor::ForOfStatementBody(_)
+                | Ancestor::WhileStatementBody(_)
+                | Ancestor::DoWhileStatementBody(_) => return true,
+                Ancestor::FunctionBodyStatements(_) | Ancestor::StaticBlockBody(_) => {
+                    return false;
+                }
+                _ => {}
+            }
+        }
+        false
+    }
+}
+
+fn is_let_or_const(kind: VariableDeclarationKind) -> bool {
+    matches!(kind, VariableDeclarationKind::Let | VariableDeclarationKind::Const)
+}
@@ -0,0 +1,150 @@
+//! Assignments to `const` bindings.
+//!
+//! Assigning to a `const` binding throws a `TypeError`. Once the binding is converted to a `var`,
+//! the assignment would succeed instead, so assignments and updates are replaced with
+//! `babelHelpers.readOnlyError("x")`, which throws. The assigned value is still evaluated.
+//!
+//! * `x = 1` -> `(1, babelHelpers.readOnlyError("x"))`
+//! * `x += 1` -> `(x + 1, babelHelpers.readOnlyError("x"))`
+//! * `x ||= 1` -> `x || (1, babelHelpers.readOnlyError("x"))`
+//! * `x++` -> `(+x, babelHelpers.readOnlyError("x"))`
+//! * `[x] = arr` -> `(arr, babelHelpers.readOnlyError("x"))`
+
+use oxc_allocator::TakeIn;
+use oxc_ast::ast::*;
+use oxc_ast_visit::Visit;
+use oxc_semantic::{ReferenceFlags, ReferenceId, SymbolId};
+use oxc_span::SPAN;
+use oxc_traverse::TraverseCtx;
+
+use crate::Helper;
+
+use super::BlockScoping;
+
+impl<'a> BlockScoping<'a, '_> {
+    /// Replace assignments and updates of `const` bindings with `babelHelpers.readOnlyError`.
+    pub(super) fn transform_read_only_assignment(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let name = match expr {
+            Expression::AssignmentExpression(assign) => {
+                self.find_const_binding_in_target(&assign.left, ctx)
+            }
+            Expression::UpdateExpression(update) => match &update.argument {
+                SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                    self.is_const_reference(ident, ctx).then_some(ident.name)
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+        let Some(name) = name else { return };
+
+        let message = ctx.ast.expression_string_literal(SPAN, name, None);
+        let arguments = ctx.ast.vec1(Argument::from(message));
+        let error = self.ctx.helper_call_expr(Helper::ReadOnlyError, SPAN, arguments, ctx);
+
+        *expr = match expr.take_in(ctx.ast.allocator) {
+            Expression::AssignmentExpression(assign) => {
+                let AssignmentExpression { span, operator, left, right } = assign.unbox();
+                if operator == AssignmentOperator::Assign {
+                    // The assigned targets are removed
+                    let mut collector = ReferenceCollector::default();
+                    collector.visit_assignment_target(&left);
+                    for (reference_id, name) in collector.references {
+                        ctx.delete_reference(reference_id, &name);
+                    }
+                    ctx.ast.expression_sequence(span, ctx.ast.vec_from_array([right, error]))
+                } else {
+                    let AssignmentTarget::AssignmentTargetIdentifier(ident) = left else {
+                        unreachable!()
+                    };
+                    let left = Self::read_only_reference(ident.unbox(), ctx);
+                    if let Some(operator) = operator.to_logical_operator() {
+                        let right = ctx
+                            .ast
+                            .expression_sequence(SPAN, ctx.ast.vec_from_array([right, error]));
+                        ctx.ast.expression_logical(span, left, operator, right)
+                    } else {
+                        let operator = operator.to_binary_operator().unwrap();
+                        let value = ctx.ast.expression_binary(span, left, operator, right);
+                        ctx.ast.expression_sequence(span, ctx.ast.vec_from_array([value, error]))
+                    }
+                }
+            }
+            Expression::UpdateExpression(update) => {
+                let UpdateExpression { span, argument, .. } = update.unbox();
+                let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = argument else {
+                    unreachable!()
+                };
+                let argument = Self::read_only_reference(ident.unbox(), ctx);
+                let value = ctx.ast.expression_unary(span, UnaryOperator::UnaryPlus, argument);
+                ctx.ast.expression_sequence(span, ctx.ast.vec_from_array([value, error]))
+            }
+            _ => unreachable!(),
+        };
+    }
+
+    /// Name of the first `const` binding assigned to by `target`.
+    fn find_const_binding_in_target(
+        &self,
+        target: &AssignmentTarget<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<Atom<'a>> {
+        if let AssignmentTarget::AssignmentTargetIdentifier(ident) = target {
+            return self.is_const_reference(ident, ctx).then_some(ident.name);
+        }
+        let mut collector = ReferenceCollector { only_assigned: true, ..Default::default() };
+        collector.visit_assignment_target(target);
+        collector.references.into_iter().find_map(|(reference_id, name)| {
+            let symbol_id = ctx.scoping().get_reference(reference_id).symbol_id()?;
+            self.is_const_symbol(symbol_id, ctx).then_some(name)
+        })
+    }
+
+    fn is_const_reference(&self, ident: &IdentifierReference<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        ctx.scoping()
+            .get_reference(ident.reference_id())
+            .symbol_id()
+            .is_some_and(|symbol_id| self.is_const_symbol(symbol_id, ctx))
+    }
+
+    /// `const` bindings are marked as `var` once they are hoisted or their declaration is converted.
+    pub(super) fn is_const_symbol(&self, symbol_id: SymbolId, ctx: &TraverseCtx<'a>) -> bool {
+        self.const_symbols.contains(&symbol_id)
+            || ctx.scoping().symbol_flags(symbol_id).is_const_variable()
+    }
+
+    /// The assigned reference `x` of `x += 1` or `x++`, which is only read once transformed.
+    fn read_only_reference(
+        ident: IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        *ctx.scoping_mut().get_reference_mut(ident.reference_id()).flags_mut() =
+            ReferenceFlags::Read;
+        Expression::Identifier(ctx.ast.alloc(ident))
+    }
+}
+
+/// Collects references in an assignment target.
+#[derive(Default)]
+struct ReferenceCollector<'a> {
+    /// Only collect references which are assigned to, not those in default values
+    /// and computed keys.
+    only_assigned: bool,
+    references: Vec<(ReferenceId, Atom<'a>)>,
+}
+
+impl<'a> Visit<'a> for ReferenceCollector<'a> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.references.push((ident.reference_id(), ident.name));
+    }
+
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if !self.only_assigned {
+            oxc_ast_visit::walk::walk_expression(self, expr);
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! Temporal dead zone checks.
+//!
+//! Reading a `let` / `const` binding before its declaration has been evaluated throws
+//! a `ReferenceError`. Once the binding is converted to a `var`, reading it returns `undefined`
+//! instead. With the `tdz` option enabled, reads which may happen before the declaration are
+//! replaced with checks which throw.
+//!
+//! * A read which is before the declaration, in the same function, always throws.
+//!   It's replaced with `babelHelpers.tdz("a")`.
+//! * A read in a closure which may be called before the declaration is evaluated is replaced with
+//!   `babelHelpers.temporalRef(a, "a")`, which throws if `a` still holds the
+//!   `babelHelpers.temporalUndefined` sentinel value. The binding is set to that value at the start
+//!   of the block.
+//!
+//! ```js
+//! f();
+//! function f() { return a; }
+//! a;
+//! let a = 1;
+//! ```
+//!
+//! ->
+//!
+//! ```js
+//! a = babelHelpers.temporalUndefined;
+//! f();
+//! function f() { return babelHelpers.temporalRef(a, "a"); }
+//! babelHelpers.tdz("a");
+//! var a = 1;
+//! ```
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, walk};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceId, ScopeFlags, Scoping, SymbolId};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::operator::AssignmentOperator;
+use oxc_traverse::{BoundIdentifier, TraverseCtx};
+
+use crate::Helper;
+
+use super::{BlockScoping, is_let_or_const};
+
+/// Whether a read of a binding may happen before its declaration is evaluated.
+#[derive(Clone, Copy)]
+pub(super) enum TdzStatus {
+    /// Read always happens before the declaration.
+    Before,
+    /// Read is in a closure which may be called before the declaration.
+    Maybe,
+}
+
+impl<'a> BlockScoping<'a, '_> {
+    /// Find reads of `let` / `const` bindings declared in `stmts` which may happen before their
+    /// declarations. Those reads are replaced in [`BlockScoping::transform_tdz_reference`].
+    pub(super) fn insert_tdz_checks(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // Map from symbol to end of its declarator
+        let mut declarations = FxHashMap::default();
+        let mut symbols = vec![];
+        for stmt in stmts.iter() {
+            let decl = match stmt {
+                Statement::VariableDeclaration(decl) => decl,
+                Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                    Some(Declaration::VariableDeclaration(decl)) => decl,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            if !is_let_or_const(decl.kind) {
+                continue;
+            }
+            for declarator in &decl.declarations {
+                declarator.id.bound_names(&mut |ident| {
+                    declarations.insert(ident.symbol_id(), declarator.span.end);
+                    symbols.push(ident.symbol_id());
+                });
+            }
+        }
+        if declarations.is_empty() {
+            return;
+        }
+
+        let mut analyzer = TdzAnalyzer {
+            scoping: ctx.scoping(),
+            declarations: &declarations,
+            references: &mut self.tdz_references,
+            closure: None,
+        };
+        for stmt in stmts.iter() {
+            analyzer.visit_statement(stmt);
+        }
+
+        // `a = babelHelpers.temporalUndefined;`
+        let mut index = 0;
+        for symbol_id in symbols {
+            let is_maybe = ctx
+                .scoping()
+                .get_resolved_reference_ids(symbol_id)
+                .iter()
+                .any(|id| matches!(self.tdz_references.get(id), Some(TdzStatus::Maybe)));
+            if !is_maybe {
+                continue;
+            }
+            self.tdz_symbols.insert(symbol_id);
+            let name = ctx.ast.atom(ctx.scoping().symbol_name(symbol_id));
+            let binding = BoundIdentifier::new(name, symbol_id);
+            let value = self.ctx.helper_load(Helper::TemporalUndefined, ctx);
+            let assignment = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                binding.create_write_target(ctx),
+                value,
+            );
+            stmts.insert(index, ctx.ast.statement_expression(SPAN, assignment));
+            index += 1;
+        }
+    }
+
+    /// `a` -> `babelHelpers.tdz("a")` or `babelHelpers.temporalRef(a, "a")`
+    pub(super) fn transform_tdz_reference(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::Identifier(ident) = expr else { return };
+        let Some(status) = self.tdz_references.remove(&ident.reference_id()) else { return };
+        let span = ident.span;
+        let name = ctx.ast.expression_string_literal(SPAN, ident.name, None);
+        *expr = match status {
+            TdzStatus::Before => {
+                ctx.delete_reference_for_identifier(ident);
+                let arguments = ctx.ast.vec1(Argument::from(name));
+                self.ctx.helper_call_expr(Helper::Tdz, span, arguments, ctx)
+            }
+            TdzStatus::Maybe => {
+                let ident = expr.take_in(ctx.ast.allocator);
+                let arguments =
+                    ctx.ast.vec_from_array([Argument::from(ident), Argument::from(name)]);
+                self.ctx.helper_call_expr(Helper::TemporalRef, span, arguments, ctx)
+            }
+        };
+    }
+}
+
+/// Closure containing a reference.
+#[derive(Clone, Copy)]
+struct Closure {
+    start: u32,
+    /// Function declarations are hoisted, so may be called anywhere in the block.
+    is_hoisted: bool,
+}
+
+/// Visitor which finds reads of bindings which may happen before their declaration.
+struct TdzAnalyzer<'v> {
+    scoping: &'v Scoping,
+    declarations: &'v FxHashMap<SymbolId, u32>,
+    references: &'v mut FxHashMap<ReferenceId, TdzStatus>,
+    /// Outermost closure the visitor is currently in
+    closure: Option<Closure>,
+}
+
+impl TdzAnalyzer<'_> {
+    fn enter_closure(&mut self, start: u32, is_hoisted: bool) -> Option<Closure> {
+        let previous = self.closure;
+        if previous.is_none() {
+            self.closure = Some(Closure { start, is_hoisted });
+        }
+        previous
+    }
+}
+
+impl<'a> Visit<'a> for TdzAnalyzer<'_> {
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        let previous = self.enter_closure(func.span.start, func.is_declaration());
+        walk::walk_function(self, func, flags);
+        self.closure = previous;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        let previous = self.enter_closure(arrow.span.start, false);
+        walk::walk_arrow_function_expression(self, arrow);
+        self.closure = previous;
+    }
+
+    fn visit_class_body(&mut self, body: &ClassBody<'a>) {
+        let previous = self.enter_closure(body.span.start, false);
+        walk::walk_class_body(self, body);
+        self.closure = previous;
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let reference = self.scoping.get_reference(reference_id);
+        if reference.is_write() {
+            return;
+        }
+        let Some(symbol_id) = reference.symbol_id() else { return };
+        let Some(&end) = self.declarations.get(&symbol_id) else { return };
+        let status = match self.closure {
+            None if ident.span().start < end => TdzStatus::Before,
+            Some(closure) if closure.is_hoisted || closure.start < end => TdzStatus::Maybe,
+            _ => return,
+        };
+        self.references.insert(reference_id, status);
+    }
+}
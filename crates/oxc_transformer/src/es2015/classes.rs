@@ -0,0 +1,1461 @@
+//! ES2015: Classes
+//!
+//! This plugin transforms classes into ES5 constructor functions.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! class A extends B {
+//!   constructor(x) {
+//!     super(x);
+//!     this.x = x;
+//!   }
+//!   method() {
+//!     return super.method();
+//!   }
+//!   get prop() {
+//!     return this.x;
+//!   }
+//!   static create() {
+//!     return new A(1);
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! let A = function(_B) {
+//!   function A(x) {
+//!     var _this;
+//!     babelHelpers.classCallCheck(this, A);
+//!     _this = babelHelpers.callSuper(this, A, [x]);
+//!     _this.x = x;
+//!     return _this;
+//!   }
+//!   babelHelpers.inherits(A, _B);
+//!   return babelHelpers.createClass(A, [{
+//!     key: "method",
+//!     value: function method() {
+//!       return babelHelpers.superPropGet(A, "method", this, 3)([]);
+//!     }
+//!   }, {
+//!     key: "prop",
+//!     get: function() {
+//!       return this.x;
+//!     }
+//!   }], [{
+//!     key: "create",
+//!     value: function create() {
+//!       return new A(1);
+//!     }
+//!   }]);
+//! }(B);
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! Enables both the `setClassMethods` and `noClassCalls` assumptions.
+//!
+//! ### `setClassMethods` assumption
+//!
+//! Methods are assigned to the prototype (or to the class for static methods) instead of being
+//! defined with `createClass` helper. Getters and setters are still defined via `createClass`,
+//! as they cannot be created with a plain assignment.
+//!
+//! ```js
+//! let A = function() {
+//!   function A() {
+//!     babelHelpers.classCallCheck(this, A);
+//!   }
+//!   A.prototype.method = function method() {};
+//!   return A;
+//! }();
+//! ```
+//!
+//! ### `noClassCalls` assumption
+//!
+//! Omits the `classCallCheck` call which throws when a class is called without `new`.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-classes](https://babel.dev/docs/babel-plugin-transform-classes).
+//!
+//! Classes are transformed on exit, so any nested classes and other class features (e.g. class
+//! properties, which run in an earlier phase) have already been transformed by the time we get
+//! to a class.
+//!
+//! The scope of the class is reused as the scope of the wrapping IIFE, and the class's
+//! constructor and method functions keep their own scopes.
+//!
+//! Classes which still contain features which cannot be represented in ES5 (properties,
+//! static blocks, private members, `accessor` properties, decorators) are left as is.
+//! Those features are handled by other plugins, which must be enabled too.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-classes>
+//! * Class definitions specification: <https://tc39.es/ecma262/#sec-class-definitions>
+
+use std::cell::Cell;
+
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+
+use oxc_allocator::{Box as ArenaBox, CloneIn, TakeIn};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{Atom, GetSpan, SPAN, Span};
+use oxc_syntax::{identifier::is_identifier_name, keyword::is_reserved_keyword};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{Helper, context::TransformCtx, utils::ast_builder::create_prototype_member};
+
+use super::{Spread, SpreadOptions};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ClassesOptions {
+    pub loose: bool,
+}
+
+pub struct Classes<'a, 'ctx> {
+    /// If `true`, assign methods to prototype instead of using `createClass` helper.
+    set_class_methods: bool,
+    /// If `true`, omit `classCallCheck` call in constructor.
+    no_class_calls: bool,
+    /// `Some` if spread plugin is enabled, to transform spread arguments of `super()` calls.
+    spread: Option<SpreadOptions>,
+    /// `true` if arrow functions plugin is enabled
+    arrow_functions: bool,
+    /// Scopes of arrow functions, which are converted to function expressions by the time
+    /// the class is transformed. `this` and `super` in them still refer to the method's.
+    arrow_scope_ids: FxHashSet<ScopeId>,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Classes<'a, 'ctx> {
+    pub fn new(
+        options: ClassesOptions,
+        spread: Option<SpreadOptions>,
+        arrow_functions: bool,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        Self {
+            set_class_methods: options.loose || ctx.assumptions.set_class_methods,
+            no_class_calls: options.loose || ctx.assumptions.no_class_calls,
+            spread,
+            arrow_functions,
+            arrow_scope_ids: FxHashSet::default(),
+            ctx,
+        }
+    }
+}
+
+impl<'a> Traverse<'a> for Classes<'a, '_> {
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        // Async arrow functions are not converted to function expressions
+        if self.arrow_functions && !arrow.r#async {
+            self.arrow_scope_ids.insert(arrow.scope_id());
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            Statement::ClassDeclaration(class) => {
+                if !Self::can_transform(class) {
+                    return;
+                }
+                let new_stmt = self.transform_class_declaration(class, ctx);
+                self.ctx.statement_injector.move_insertions(stmt, &new_stmt);
+                *stmt = new_stmt;
+            }
+            Statement::ExportNamedDeclaration(export) => {
+                let Some(Declaration::ClassDeclaration(class)) = &mut export.declaration else {
+                    return;
+                };
+                if !Self::can_transform(class) {
+                    return;
+                }
+                let Statement::VariableDeclaration(decl) =
+                    self.transform_class_declaration(class, ctx)
+                else {
+                    unreachable!()
+                };
+                export.declaration = Some(Declaration::VariableDeclaration(decl));
+            }
+            Statement::ExportDefaultDeclaration(export) => {
+                let ExportDefaultDeclarationKind::ClassDeclaration(class) = &mut export.declaration
+                else {
+                    return;
+                };
+                if !Self::can_transform(class) {
+                    return;
+                }
+                if let Some(id) = &class.id {
+                    // `export default class A {}` -> `let A = ...; export default A;`
+                    let binding = BoundIdentifier::from_binding_ident(id);
+                    let new_stmt = self.transform_class_declaration(class, ctx);
+                    let export_stmt =
+                        Statement::from(ctx.ast.module_declaration_export_default_declaration(
+                            SPAN,
+                            ctx.ast.module_export_name_identifier_name(SPAN, "default"),
+                            ExportDefaultDeclarationKind::Identifier(
+                                ctx.ast.alloc(binding.create_read_reference(ctx)),
+                            ),
+                        ));
+                    self.ctx.statement_injector.move_insertions(stmt, &new_stmt);
+                    self.ctx.statement_injector.insert_after(&new_stmt, export_stmt);
+                    *stmt = new_stmt;
+                } else {
+                    // `export default class {}` -> `export default function() { ... }()`
+                    let expr = self.transform_class(class, Some("default"), None, ctx);
+                    export.declaration = ExportDefaultDeclarationKind::from(expr);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Class expression may have been wrapped by class properties transform.
+        // e.g. `(_A = class A {}, babelHelpers.defineProperty(_A, "x", 1), _A)`.
+        let (class_expr, name_hint) = match expr {
+            Expression::ClassExpression(_) => {
+                let name_hint = Self::get_name_hint_from_parent(ctx);
+                (expr, name_hint)
+            }
+            Expression::SequenceExpression(_) | Expression::AssignmentExpression(_) => {
+                let Some(class_expr) = Self::find_wrapped_class_expression(expr) else {
+                    return;
+                };
+                (class_expr, None)
+            }
+            _ => return,
+        };
+
+        let Expression::ClassExpression(class) = class_expr else { unreachable!() };
+        if !Self::can_transform(class) {
+            return;
+        }
+        *class_expr = self.transform_class(class, None, name_hint, ctx);
+    }
+}
+
+impl<'a> Classes<'a, '_> {
+    /// Check if class can be transformed.
+    ///
+    /// Class properties, static blocks, private members and decorators must be transformed
+    /// by other plugins first. If any remain, leave the class unchanged.
+    fn can_transform(class: &Class<'a>) -> bool {
+        !class.declare
+            && class.decorators.is_empty()
+            && class.body.body.iter().all(|element| match element {
+                ClassElement::MethodDefinition(method) => {
+                    method.decorators.is_empty()
+                        && !method.key.is_private_identifier()
+                        && method.value.body.is_some()
+                }
+                ClassElement::TSIndexSignature(_) => true,
+                ClassElement::PropertyDefinition(_)
+                | ClassElement::AccessorProperty(_)
+                | ClassElement::StaticBlock(_) => false,
+            })
+    }
+
+    /// Find class expression wrapped in a sequence or assignment expression,
+    /// as output by class properties transform.
+    fn find_wrapped_class_expression<'e>(
+        expr: &'e mut Expression<'a>,
+    ) -> Option<&'e mut Expression<'a>> {
+        match expr {
+            Expression::ClassExpression(_) => Some(expr),
+            Expression::AssignmentExpression(assign) => {
+                Self::find_wrapped_class_expression(&mut assign.right)
+            }
+            Expression::SequenceExpression(seq) => {
+                seq.expressions.first_mut().and_then(Self::find_wrapped_class_expression)
+            }
+            Expression::ParenthesizedExpression(paren) => {
+                Self::find_wrapped_class_expression(&mut paren.expression)
+            }
+            _ => None,
+        }
+    }
+
+    /// Get binding which anonymous class expression is assigned to.
+    ///
+    /// `let Foo = class {}` -> `Foo`
+    fn get_name_hint_from_parent(ctx: &TraverseCtx<'a>) -> Option<BoundIdentifier<'a>> {
+        let Ancestor::VariableDeclaratorInit(decl) = ctx.parent() else { return None };
+        let BindingPatternKind::BindingIdentifier(ident) = &decl.id().kind else { return None };
+        let binding = BoundIdentifier::from_binding_ident(ident);
+        // If variable is reassigned, references within class body can't be redirected to
+        // the constructor function's binding
+        (!ctx.scoping().symbol_is_mutated(binding.symbol_id)).then_some(binding)
+    }
+
+    /// `class A {}` -> `let A = function() { ... }();`
+    fn transform_class_declaration(
+        &self,
+        class: &mut Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let id = class.id.as_ref().unwrap();
+        let id_span = id.span;
+        let binding = BoundIdentifier::from_binding_ident(id);
+        *ctx.scoping_mut().symbol_flags_mut(binding.symbol_id) = SymbolFlags::BlockScopedVariable;
+
+        let init = self.transform_class(class, None, Some(binding.clone()), ctx);
+
+        let kind = VariableDeclarationKind::Let;
+        // Keep the class name's span, as the symbol is now declared by this binding
+        let ident =
+            ctx.ast.binding_identifier_with_symbol_id(id_span, binding.name, binding.symbol_id);
+        let pattern = ctx.ast.binding_pattern(
+            BindingPatternKind::BindingIdentifier(ctx.alloc(ident)),
+            NONE,
+            false,
+        );
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, Some(init), false);
+        Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            class.span,
+            kind,
+            ctx.ast.vec1(declarator),
+            false,
+        ))
+    }
+
+    /// Transform class into an IIFE which returns the constructor function.
+    ///
+    /// * `class_name_hint` is used as the name of the constructor if class is anonymous.
+    /// * `outer_binding` is the binding outside the class which the class is assigned to.
+    ///   References to it within the class body are redirected to the constructor function.
+    fn transform_class(
+        &self,
+        class: &mut Class<'a>,
+        class_name_hint: Option<&str>,
+        outer_binding: Option<BoundIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let class_scope_id = class.scope_id();
+        let outer_scope_id = ctx.current_scope_id();
+        let is_strict_mode = ctx.current_scope_flags().is_strict_mode();
+        let strict_mode_flag =
+            if is_strict_mode { ScopeFlags::StrictMode } else { ScopeFlags::empty() };
+
+        // Class scope becomes the scope of the IIFE
+        *ctx.scoping_mut().scope_flags_mut(class_scope_id) =
+            ScopeFlags::Function | strict_mode_flag;
+
+        // Create binding for constructor function.
+        // Class expression's name is already bound in class scope, so reuse it.
+        let mut redirect_symbol_id = None;
+        let class_binding = match (class.id.as_ref(), class.is_expression()) {
+            (Some(id), true) => {
+                let binding = BoundIdentifier::from_binding_ident(id);
+                *ctx.scoping_mut().symbol_flags_mut(binding.symbol_id) = SymbolFlags::Function;
+                binding
+            }
+            _ => {
+                if let Some(outer_binding) = outer_binding {
+                    redirect_symbol_id = Some(outer_binding.symbol_id);
+                    ctx.generate_binding(outer_binding.name, class_scope_id, SymbolFlags::Function)
+                } else {
+                    ctx.generate_uid(
+                        class_name_hint.unwrap_or("Class"),
+                        class_scope_id,
+                        SymbolFlags::Function,
+                    )
+                }
+            }
+        };
+
+        // `extends B` -> IIFE param `_B`
+        let super_class = class.super_class.take().map(|mut super_class| {
+            ScopeReparenter::reparent(&mut super_class, outer_scope_id, ctx);
+            let binding = ctx.generate_uid_based_on_node(
+                &super_class,
+                class_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            (super_class, binding)
+        });
+        let is_derived = super_class.is_some();
+
+        // Split class elements into constructor and methods
+        let mut constructor = None;
+        let mut methods = vec![];
+        let mut has_computed_keys = false;
+        for element in class.body.body.take_in(ctx.ast.allocator) {
+            let ClassElement::MethodDefinition(method) = element else { continue };
+            let method = method.unbox();
+            if method.kind == MethodDefinitionKind::Constructor {
+                constructor = Some(method.value);
+                continue;
+            }
+            has_computed_keys |= method.computed;
+            methods.push(method);
+        }
+
+        let mut stmts = ctx.ast.vec();
+
+        // `function A() { babelHelpers.classCallCheck(this, A); }`
+        let constructor = self.transform_constructor(
+            constructor,
+            &class_binding,
+            redirect_symbol_id,
+            is_derived,
+            is_strict_mode,
+            class_scope_id,
+            ctx,
+        );
+        stmts.push(Statement::FunctionDeclaration(constructor));
+
+        // `babelHelpers.inherits(A, _B);`
+        if let Some((_, super_binding)) = &super_class {
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(class_binding.create_read_expression(ctx)),
+                Argument::from(super_binding.create_read_expression(ctx)),
+            ]);
+            let call = self.ctx.helper_call_expr(Helper::Inherits, SPAN, arguments, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, call));
+        }
+
+        // Methods
+        let mut proto_descriptors = vec![];
+        let mut static_descriptors = vec![];
+        for method in methods {
+            let MethodDefinition { span, mut key, mut value, kind, computed, r#static, .. } =
+                method;
+
+            let mut visitor = ClassBodyVisitor::new(
+                self,
+                &class_binding,
+                redirect_symbol_id,
+                None,
+                r#static,
+                !is_strict_mode,
+                ctx,
+            );
+            if computed {
+                visitor.visit_property_key_in_class_scope(&mut key);
+            }
+            visitor.visit_function_contents(&mut value);
+
+            let key_name = if computed { None } else { key.static_name() };
+            let key_name = key_name.map(|name| ctx.ast.atom(&name));
+            let key = match key {
+                PropertyKey::StaticIdentifier(ident) => {
+                    ctx.ast.expression_string_literal(ident.span, ident.name, None)
+                }
+                key => key.into_expression(),
+            };
+
+            let function = Self::transform_method_function(
+                value,
+                kind,
+                key_name,
+                is_strict_mode,
+                class_scope_id,
+                ctx,
+            );
+
+            if self.set_class_methods && kind == MethodDefinitionKind::Method {
+                // `A.prototype.method = function method() {};`
+                let object = class_binding.create_read_expression(ctx);
+                let object = if r#static { object } else { create_prototype_member(object, ctx) };
+                let static_property = match &key {
+                    Expression::StringLiteral(lit)
+                        if !computed && is_identifier_name(&lit.value) =>
+                    {
+                        Some(ctx.ast.identifier_name(lit.span, lit.value))
+                    }
+                    _ => None,
+                };
+                let target = if let Some(property) = static_property {
+                    AssignmentTarget::from(
+                        ctx.ast.member_expression_static(SPAN, object, property, false),
+                    )
+                } else {
+                    AssignmentTarget::from(
+                        ctx.ast.member_expression_computed(SPAN, object, key, false),
+                    )
+                };
+                let assignment = ctx.ast.expression_assignment(
+                    span,
+                    AssignmentOperator::Assign,
+                    target,
+                    function,
+                );
+                stmts.push(ctx.ast.statement_expression(span, assignment));
+                continue;
+            }
+
+            let descriptors =
+                if r#static { &mut static_descriptors } else { &mut proto_descriptors };
+            Descriptor::add(descriptors, key, key_name, kind, function);
+        }
+
+        // `return babelHelpers.createClass(A, [...], [...]);` or `return A;`
+        let return_value = if self.set_class_methods
+            && proto_descriptors.is_empty()
+            && static_descriptors.is_empty()
+        {
+            class_binding.create_read_expression(ctx)
+        } else {
+            let mut arguments =
+                ctx.ast.vec1(Argument::from(class_binding.create_read_expression(ctx)));
+            if !proto_descriptors.is_empty() || !static_descriptors.is_empty() {
+                arguments.push(Argument::from(Descriptor::create_array(proto_descriptors, ctx)));
+            }
+            if !static_descriptors.is_empty() {
+                arguments.push(Argument::from(Descriptor::create_array(static_descriptors, ctx)));
+            }
+            self.ctx.helper_call_expr(Helper::CreateClass, SPAN, arguments, ctx)
+        };
+        stmts.push(ctx.ast.statement_return(SPAN, Some(return_value)));
+
+        // `function(_B) { ... }(B)`
+        let (params, arguments) = if let Some((super_class, super_binding)) = super_class {
+            let param = ctx.ast.formal_parameter(
+                SPAN,
+                ctx.ast.vec(),
+                super_binding.create_binding_pattern(ctx),
+                None,
+                false,
+                false,
+            );
+            (ctx.ast.vec1(param), ctx.ast.vec1(Argument::from(super_class)))
+        } else {
+            (ctx.ast.vec(), ctx.ast.vec())
+        };
+        let is_pure = !has_computed_keys
+            && arguments.iter().all(|arg| matches!(arg, Argument::Identifier(_)));
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            params,
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), stmts);
+        let callee = ctx.ast.expression_function_with_scope_id_and_pure(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            class_scope_id,
+            false,
+        );
+        ctx.ast.expression_call_with_pure(class.span, callee, NONE, arguments, false, is_pure)
+    }
+
+    /// Transform class constructor into a function declaration.
+    ///
+    /// * Base class:
+    ///   `constructor() { foo(); }` -> `function A() { babelHelpers.classCallCheck(this, A); foo(); }`
+    /// * Derived class:
+    ///   `constructor() { super(1); this.x = 1; }` ->
+    ///   ```js
+    ///   function A() {
+    ///     var _this;
+    ///     babelHelpers.classCallCheck(this, A);
+    ///     _this = babelHelpers.callSuper(this, A, [1]);
+    ///     _this.x = 1;
+    ///     return _this;
+    ///   }
+    ///   ```
+    #[expect(clippy::too_many_arguments)]
+    fn transform_constructor(
+        &self,
+        constructor: Option<ArenaBox<'a, Function<'a>>>,
+        class_binding: &BoundIdentifier<'a>,
+        redirect_symbol_id: Option<SymbolId>,
+        is_derived: bool,
+        is_strict_mode: bool,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, Function<'a>> {
+        let mut leading_stmts = ctx.ast.vec();
+
+        let mut func = if let Some(mut func) = constructor {
+            let scope_id = func.scope_id();
+            let this_binding = is_derived
+                .then(|| ctx.generate_uid("this", scope_id, SymbolFlags::FunctionScopedVariable));
+
+            let mut visitor = ClassBodyVisitor::new(
+                self,
+                class_binding,
+                redirect_symbol_id,
+                this_binding.as_ref(),
+                false,
+                !is_strict_mode,
+                ctx,
+            );
+            visitor.visit_function_contents(&mut func);
+
+            if let Some(this_binding) = &this_binding {
+                // `var _this;`
+                let kind = VariableDeclarationKind::Var;
+                let declarator = ctx.ast.variable_declarator(
+                    SPAN,
+                    kind,
+                    this_binding.create_binding_pattern(ctx),
+                    None,
+                    false,
+                );
+                leading_stmts.push(Statement::from(ctx.ast.declaration_variable(
+                    SPAN,
+                    kind,
+                    ctx.ast.vec1(declarator),
+                    false,
+                )));
+
+                // `return _this;`
+                let body = func.body.as_mut().unwrap();
+                if !matches!(body.statements.last(), Some(Statement::ReturnStatement(_))) {
+                    let argument = this_binding.create_read_expression(ctx);
+                    body.statements.push(ctx.ast.statement_return(SPAN, Some(argument)));
+                }
+            }
+
+            Self::set_function_scope_flags(&func, is_strict_mode, ctx);
+            func
+        } else {
+            let strict_mode_flag =
+                if is_strict_mode { ScopeFlags::StrictMode } else { ScopeFlags::empty() };
+            let scope_id =
+                ctx.create_child_scope(class_scope_id, ScopeFlags::Function | strict_mode_flag);
+            let mut stmts = ctx.ast.vec();
+            if is_derived {
+                // `return babelHelpers.callSuper(this, A, arguments);`
+                let arguments = ctx.create_unbound_ident_expr(
+                    SPAN,
+                    Atom::from("arguments"),
+                    ReferenceFlags::Read,
+                );
+                let this = ctx.ast.expression_this(SPAN);
+                let call =
+                    self.create_call_super(this, class_binding, Argument::from(arguments), ctx);
+                stmts.push(ctx.ast.statement_return(SPAN, Some(call)));
+            }
+            let params = ctx.ast.alloc_formal_parameters(
+                SPAN,
+                FormalParameterKind::FormalParameter,
+                ctx.ast.vec(),
+                NONE,
+            );
+            let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), stmts);
+            ctx.ast.alloc_function_with_scope_id(
+                SPAN,
+                FunctionType::FunctionDeclaration,
+                None,
+                false,
+                false,
+                false,
+                NONE,
+                NONE,
+                params,
+                NONE,
+                Some(body),
+                scope_id,
+            )
+        };
+
+        // `babelHelpers.classCallCheck(this, A);`
+        if !self.no_class_calls {
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(ctx.ast.expression_this(SPAN)),
+                Argument::from(class_binding.create_read_expression(ctx)),
+            ]);
+            let call = self.ctx.helper_call_expr(Helper::ClassCallCheck, SPAN, arguments, ctx);
+            leading_stmts.push(ctx.ast.statement_expression(SPAN, call));
+        }
+
+        func.r#type = FunctionType::FunctionDeclaration;
+        // Class expression's name is reused, so keep its span
+        let span = ctx.scoping().symbol_span(class_binding.symbol_id);
+        func.id = Some(ctx.ast.binding_identifier_with_symbol_id(
+            span,
+            class_binding.name,
+            class_binding.symbol_id,
+        ));
+        let body = func.body.as_mut().unwrap();
+        body.statements.splice(0..0, leading_stmts);
+        func
+    }
+
+    /// Convert method into a function expression.
+    ///
+    /// Method is named after its key, if that doesn't shadow any other binding.
+    /// `foo() {}` -> `function foo() {}`
+    fn transform_method_function(
+        mut func: ArenaBox<'a, Function<'a>>,
+        kind: MethodDefinitionKind,
+        key_name: Option<Atom<'a>>,
+        is_strict_mode: bool,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        Self::set_function_scope_flags(&func, is_strict_mode, ctx);
+        func.r#type = FunctionType::FunctionExpression;
+
+        let name = key_name.filter(|name| {
+            kind == MethodDefinitionKind::Method
+                && is_identifier_name(name)
+                && !is_reserved_keyword(name)
+        });
+        if let Some(name) = name {
+            let scope_id = func.scope_id();
+            let scoping = ctx.scoping();
+            if scoping.find_binding(class_scope_id, &name).is_none()
+                && scoping.get_binding(scope_id, &name).is_none()
+                && !scoping.root_unresolved_references().contains_key(name.as_str())
+            {
+                let binding = ctx.generate_binding(name, scope_id, SymbolFlags::Function);
+                func.id = Some(binding.create_binding_identifier(ctx));
+            }
+        }
+
+        Expression::FunctionExpression(func)
+    }
+
+    /// Remove method-specific flags from scope of a function which was a class method,
+    /// and set strict mode flag according to the scope class is in.
+    fn set_function_scope_flags(
+        func: &Function<'a>,
+        is_strict_mode: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut flags = ScopeFlags::Function;
+        if is_strict_mode || func.has_use_strict_directive() {
+            flags |= ScopeFlags::StrictMode;
+        }
+        *ctx.scoping_mut().scope_flags_mut(func.scope_id()) = flags;
+    }
+
+    /// `babelHelpers.callSuper(this, A, arguments)`
+    fn create_call_super(
+        &self,
+        this: Expression<'a>,
+        class_binding: &BoundIdentifier<'a>,
+        arguments: Argument<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(this),
+            Argument::from(class_binding.create_read_expression(ctx)),
+            arguments,
+        ]);
+        self.ctx.helper_call_expr(Helper::CallSuper, SPAN, arguments, ctx)
+    }
+}
+
+/// Property descriptor passed to `createClass` helper.
+///
+/// `{ key: "foo", value: function foo() {} }` or `{ key: "foo", get: function() {}, set: function(v) {} }`
+struct Descriptor<'a> {
+    key: Expression<'a>,
+    key_name: Option<Atom<'a>>,
+    value: Option<Expression<'a>>,
+    get: Option<Expression<'a>>,
+    set: Option<Expression<'a>>,
+}
+
+impl<'a> Descriptor<'a> {
+    /// Add method to list of descriptors.
+    /// Getter and setter with same (non-computed) key are merged into a single descriptor.
+    fn add(
+        descriptors: &mut Vec<Self>,
+        key: Expression<'a>,
+        key_name: Option<Atom<'a>>,
+        kind: MethodDefinitionKind,
+        function: Expression<'a>,
+    ) {
+        let is_accessor = matches!(kind, MethodDefinitionKind::Get | MethodDefinitionKind::Set);
+        let existing = if is_accessor && key_name.is_some() {
+            descriptors
+                .iter_mut()
+                .rev()
+                .find(|descriptor| descriptor.value.is_none() && descriptor.key_name == key_name)
+        } else {
+            None
+        };
+
+        let descriptor = if let Some(existing) = existing {
+            existing
+        } else {
+            descriptors.push(Self { key, key_name, value: None, get: None, set: None });
+            descriptors.last_mut().unwrap()
+        };
+
+        match kind {
+            MethodDefinitionKind::Get => descriptor.get = Some(function),
+            MethodDefinitionKind::Set => descriptor.set = Some(function),
+            _ => descriptor.value = Some(function),
+        }
+    }
+
+    /// `[{ key: "foo", value: function foo() {} }, ...]`
+    fn create_array(descriptors: Vec<Self>, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        if descriptors.is_empty() {
+            return ctx.ast.expression_null_literal(SPAN);
+        }
+
+        let elements = ctx.ast.vec_from_iter(descriptors.into_iter().map(|descriptor| {
+            let mut properties = ctx.ast.vec_with_capacity(3);
+            let fields = [
+                ("key", Some(descriptor.key)),
+                ("value", descriptor.value),
+                ("get", descriptor.get),
+                ("set", descriptor.set),
+            ];
+            for (name, value) in fields {
+                let Some(value) = value else { continue };
+                properties.push(ctx.ast.object_property_kind_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    ctx.ast.property_key_static_identifier(SPAN, name),
+                    value,
+                    false,
+                    false,
+                    false,
+                ));
+            }
+            ArrayExpressionElement::from(ctx.ast.expression_object(SPAN, properties))
+        }));
+        ctx.ast.expression_array(SPAN, elements)
+    }
+}
+
+/// Visitor for the contents of class methods and constructor.
+///
+/// * Converts `super.prop`, `super.prop()`, `super.prop = value` into calls to helpers.
+/// * In derived class constructor, converts `super()` into `_this = callSuper(...)`,
+///   `this` into `_this`, and `return` statements to return `_this`.
+/// * Redirects references to class name to the constructor function's binding.
+/// * Removes strict mode flag from scopes if class is not in strict mode code.
+/// * In arrow functions which have been converted to function expressions, refers to `this` and
+///   `new.target` of the method or constructor via `_this2` and `_newtarget` vars.
+struct ClassBodyVisitor<'a, 'ctx, 'v> {
+    classes: &'v Classes<'a, 'ctx>,
+    class_binding: &'v BoundIdentifier<'a>,
+    /// Symbol for references which should be redirected to `class_binding`
+    redirect_symbol_id: Option<SymbolId>,
+    /// `_this` binding, if visiting derived class constructor
+    this_binding: Option<&'v BoundIdentifier<'a>>,
+    /// `true` if visiting a static method
+    is_static: bool,
+    /// `false` when visiting computed key, where `this` and `super` belong to outer scope
+    convert_this_and_super: bool,
+    /// Incremented when entering a non-arrow function or class, where `this` and `super` are different
+    this_depth: u32,
+    /// Incremented when entering any function, where `return` belongs to another function
+    function_depth: u32,
+    /// Incremented when entering an arrow function which has been converted to a function expression
+    arrow_depth: u32,
+    make_sloppy_mode: bool,
+    /// Scope of method or constructor being visited, where temp vars are declared
+    function_scope_id: Option<ScopeId>,
+    /// Temp vars to declare at top of method or constructor
+    temp_bindings: Vec<BoundIdentifier<'a>>,
+    /// `_this2` var holding `this` of method or constructor, for use in converted arrow functions
+    this_alias: Option<BoundIdentifier<'a>>,
+    /// `_newtarget` var holding `new.target` of method or constructor, for use in converted arrow functions
+    new_target_alias: Option<BoundIdentifier<'a>>,
+    ctx: &'v mut TraverseCtx<'a>,
+}
+
+impl<'a, 'ctx, 'v> ClassBodyVisitor<'a, 'ctx, 'v> {
+    fn new(
+        classes: &'v Classes<'a, 'ctx>,
+        class_binding: &'v BoundIdentifier<'a>,
+        redirect_symbol_id: Option<SymbolId>,
+        this_binding: Option<&'v BoundIdentifier<'a>>,
+        is_static: bool,
+        make_sloppy_mode: bool,
+        ctx: &'v mut TraverseCtx<'a>,
+    ) -> Self {
+        Self {
+            classes,
+            class_binding,
+            redirect_symbol_id,
+            this_binding,
+            is_static,
+            convert_this_and_super: true,
+            this_depth: 0,
+            function_depth: 0,
+            arrow_depth: 0,
+            make_sloppy_mode,
+            function_scope_id: None,
+            temp_bindings: vec![],
+            this_alias: None,
+            new_target_alias: None,
+            ctx,
+        }
+    }
+
+    /// Visit params and body of method, without visiting the function itself.
+    ///
+    /// Any temp vars created are declared at top of function body.
+    fn visit_function_contents(&mut self, func: &mut Function<'a>) {
+        let parent_sloppy_mode = self.make_sloppy_mode;
+        if func.has_use_strict_directive() {
+            self.make_sloppy_mode = false;
+        }
+        self.function_scope_id = Some(func.scope_id());
+        self.visit_formal_parameters(&mut func.params);
+        let body = func.body.as_mut().unwrap();
+        self.visit_function_body(body);
+        self.make_sloppy_mode = parent_sloppy_mode;
+
+        // `var _this2 = this, _newtarget = new.target, _super$prop, _super$prop2;`
+        let kind = VariableDeclarationKind::Var;
+        let mut declarators = self.ctx.ast.vec();
+        if let Some(this_alias) = self.this_alias.take() {
+            let init = self.ctx.ast.expression_this(SPAN);
+            declarators.push(self.ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                this_alias.create_binding_pattern(self.ctx),
+                Some(init),
+                false,
+            ));
+        }
+        if let Some(new_target_alias) = self.new_target_alias.take() {
+            let init = self.ctx.ast.expression_meta_property(
+                SPAN,
+                self.ctx.ast.identifier_name(SPAN, "new"),
+                self.ctx.ast.identifier_name(SPAN, "target"),
+            );
+            declarators.push(self.ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                new_target_alias.create_binding_pattern(self.ctx),
+                Some(init),
+                false,
+            ));
+        }
+        declarators.extend(self.temp_bindings.drain(..).map(|binding| {
+            self.ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                binding.create_binding_pattern(self.ctx),
+                None,
+                false,
+            )
+        }));
+        if !declarators.is_empty() {
+            let decl = self.ctx.ast.declaration_variable(SPAN, kind, declarators, false);
+            body.statements.insert(0, Statement::from(decl));
+        }
+    }
+
+    /// Create temp var in method or constructor.
+    fn create_temp_var(&mut self, name: &str) -> BoundIdentifier<'a> {
+        let scope_id = self.function_scope_id.unwrap();
+        let binding = self.ctx.generate_uid(name, scope_id, SymbolFlags::FunctionScopedVariable);
+        self.temp_bindings.push(binding.clone());
+        binding
+    }
+
+    /// Duplicate property key, so it can be used for both get and set.
+    ///
+    /// Literals are cloned, other expressions are stored in a temp var.
+    /// Returns 2 `Expression`s. The first must be evaluated first.
+    fn duplicate_property(&mut self, property: Expression<'a>) -> (Expression<'a>, Expression<'a>) {
+        if matches!(property, Expression::StringLiteral(_) | Expression::NumericLiteral(_)) {
+            let clone = property.clone_in(self.ctx.ast.allocator);
+            return (property, clone);
+        }
+        let scope_id = self.function_scope_id.unwrap();
+        let binding = self.ctx.generate_uid_based_on_node(
+            &property,
+            scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        self.temp_bindings.push(binding.clone());
+        let assignment = self.ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(self.ctx),
+            property,
+        );
+        (assignment, binding.create_read_expression(self.ctx))
+    }
+
+    /// Visit computed key. `this` and `super` in key refer to those outside the class.
+    fn visit_property_key_in_class_scope(&mut self, key: &mut PropertyKey<'a>) {
+        self.convert_this_and_super = false;
+        self.visit_property_key(key);
+        self.convert_this_and_super = true;
+    }
+
+    fn should_convert(&self) -> bool {
+        self.convert_this_and_super && self.this_depth == 0
+    }
+
+    /// `super(a, b)` -> `_this = babelHelpers.callSuper(this, A, [a, b])`
+    fn transform_super_call(&mut self, expr: &mut Expression<'a>) {
+        let Some(this_binding) = self.this_binding else { return };
+        let Expression::CallExpression(call) = expr.take_in(self.ctx.ast.allocator) else {
+            unreachable!()
+        };
+        let CallExpression { span, mut arguments, .. } = call.unbox();
+
+        // `super(...arguments)` -> `callSuper(this, A, arguments)`
+        let is_spread_arguments = arguments.len() == 1
+            && matches!(
+                &arguments[0],
+                Argument::SpreadElement(spread)
+                    if matches!(&spread.argument, Expression::Identifier(ident) if ident.name == "arguments")
+            );
+        let arguments = if is_spread_arguments {
+            let Some(Argument::SpreadElement(spread)) = arguments.pop() else { unreachable!() };
+            Argument::from(spread.unbox().argument)
+        } else {
+            let elements =
+                self.ctx.ast.vec_from_iter(arguments.drain(..).map(ArrayExpressionElement::from));
+            let mut array = self.ctx.ast.expression_array(SPAN, elements);
+            // `super(...a)` -> `callSuper(this, A, _toConsumableArray(a))`
+            if let Some(options) = self.classes.spread {
+                Spread::transform_array_expression(options, &mut array, self.classes.ctx, self.ctx);
+            }
+            Argument::from(array)
+        };
+
+        let this = self.create_this();
+        let call = self.classes.create_call_super(this, self.class_binding, arguments, self.ctx);
+        *expr = self.ctx.ast.expression_assignment(
+            span,
+            AssignmentOperator::Assign,
+            this_binding.create_write_target(self.ctx),
+            call,
+        );
+    }
+
+    /// `super.prop` -> `babelHelpers.superPropGet(A, "prop", this, 1)`
+    fn transform_super_member(&mut self, expr: &mut Expression<'a>) {
+        let (span, property) = match expr.take_in(self.ctx.ast.allocator) {
+            Expression::StaticMemberExpression(member) => {
+                (member.span, self.create_static_property(&member.property))
+            }
+            Expression::ComputedMemberExpression(member) => {
+                let member = member.unbox();
+                (member.span, member.expression)
+            }
+            _ => unreachable!(),
+        };
+        *expr = self.create_super_prop_get(span, property, false);
+    }
+
+    /// `super.prop(a, b)` -> `babelHelpers.superPropGet(A, "prop", this, 3)([a, b])`
+    fn transform_super_member_call(&mut self, call: &mut CallExpression<'a>) {
+        let property = match call.callee.take_in(self.ctx.ast.allocator) {
+            Expression::StaticMemberExpression(member) => {
+                self.create_static_property(&member.property)
+            }
+            Expression::ComputedMemberExpression(member) => member.unbox().expression,
+            _ => unreachable!(),
+        };
+        call.callee = self.create_super_prop_get(SPAN, property, true);
+        let elements =
+            self.ctx.ast.vec_from_iter(call.arguments.drain(..).map(ArrayExpressionElement::from));
+        let array = self.ctx.ast.expression_array(SPAN, elements);
+        call.arguments.push(Argument::from(array));
+    }
+
+    /// * `super.prop = value` -> `babelHelpers.superPropSet(A, "prop", value, this, 1, 1)`
+    /// * `super.prop += value` ->
+    ///   `babelHelpers.superPropSet(A, "prop", babelHelpers.superPropGet(A, "prop", this, 1) + value, this, 1, 1)`
+    /// * `super.prop &&= value` ->
+    ///   `babelHelpers.superPropGet(A, "prop", this, 1) && babelHelpers.superPropSet(A, "prop", value, this, 1, 1)`
+    fn transform_super_assignment(&mut self, expr: &mut Expression<'a>) {
+        let Expression::AssignmentExpression(assign) = expr.take_in(self.ctx.ast.allocator) else {
+            unreachable!()
+        };
+        let AssignmentExpression { span, operator, left, right: value } = assign.unbox();
+        let property = match left {
+            AssignmentTarget::StaticMemberExpression(member) => {
+                self.create_static_property(&member.property)
+            }
+            AssignmentTarget::ComputedMemberExpression(member) => member.unbox().expression,
+            _ => unreachable!(),
+        };
+
+        *expr = if operator == AssignmentOperator::Assign {
+            self.create_super_prop_set(span, property, value)
+        } else {
+            let (property1, property2) = self.duplicate_property(property);
+            if let Some(operator) = operator.to_binary_operator() {
+                let get_call = self.create_super_prop_get(SPAN, property2, false);
+                let value = self.ctx.ast.expression_binary(SPAN, get_call, operator, value);
+                self.create_super_prop_set(span, property1, value)
+            } else if let Some(operator) = operator.to_logical_operator() {
+                let get_call = self.create_super_prop_get(SPAN, property1, false);
+                let set_call = self.create_super_prop_set(SPAN, property2, value);
+                self.ctx.ast.expression_logical(span, get_call, operator, set_call)
+            } else {
+                unreachable!()
+            }
+        };
+    }
+
+    /// * `++super.prop` ->
+    ///   `babelHelpers.superPropSet(A, "prop", (_super$prop = babelHelpers.superPropGet(A, "prop", this, 1), ++_super$prop), this, 1, 1)`
+    /// * `super.prop++` ->
+    ///   `(babelHelpers.superPropSet(A, "prop", (_super$prop = babelHelpers.superPropGet(A, "prop", this, 1), _super$prop2 = _super$prop++, _super$prop), this, 1, 1), _super$prop2)`
+    fn transform_super_update(&mut self, expr: &mut Expression<'a>) {
+        let Expression::UpdateExpression(mut update) = expr.take_in(self.ctx.ast.allocator) else {
+            unreachable!()
+        };
+        let property = match update.argument.take_in(self.ctx.ast.allocator) {
+            SimpleAssignmentTarget::StaticMemberExpression(member) => {
+                self.create_static_property(&member.property)
+            }
+            SimpleAssignmentTarget::ComputedMemberExpression(member) => member.unbox().expression,
+            _ => unreachable!(),
+        };
+
+        let (property1, property2) = self.duplicate_property(property);
+        let get_call = self.create_super_prop_get(SPAN, property2, false);
+
+        let temp_binding = self.create_temp_var("super$prop");
+        let assignment = self.ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            temp_binding.create_write_target(self.ctx),
+            get_call,
+        );
+
+        let span = update.span;
+        update.span = SPAN;
+        update.argument = temp_binding.create_read_write_simple_target(self.ctx);
+        let prefix = update.prefix;
+        let update = Expression::UpdateExpression(update);
+
+        *expr = if prefix {
+            let value = self
+                .ctx
+                .ast
+                .expression_sequence(SPAN, self.ctx.ast.vec_from_array([assignment, update]));
+            self.create_super_prop_set(span, property1, value)
+        } else {
+            let temp_binding2 = self.create_temp_var("super$prop");
+            let assignment2 = self.ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                temp_binding2.create_write_target(self.ctx),
+                update,
+            );
+            let value = self.ctx.ast.expression_sequence(
+                SPAN,
+                self.ctx.ast.vec_from_array([
+                    assignment,
+                    assignment2,
+                    temp_binding.create_read_expression(self.ctx),
+                ]),
+            );
+            let set_call = self.create_super_prop_set(SPAN, property1, value);
+            self.ctx.ast.expression_sequence(
+                span,
+                self.ctx
+                    .ast
+                    .vec_from_array([set_call, temp_binding2.create_read_expression(self.ctx)]),
+            )
+        };
+    }
+
+    /// * `return` -> `return babelHelpers.possibleConstructorReturn(_this)`
+    /// * `return value` -> `return babelHelpers.possibleConstructorReturn(_this, value)`
+    fn transform_return(&mut self, stmt: &mut ReturnStatement<'a>) {
+        let Some(this_binding) = self.this_binding else { return };
+        let mut arguments =
+            self.ctx.ast.vec1(Argument::from(this_binding.create_read_expression(self.ctx)));
+        if let Some(argument) = stmt.argument.take() {
+            arguments.push(Argument::from(argument));
+        }
+        stmt.argument = Some(self.classes.ctx.helper_call_expr(
+            Helper::PossibleConstructorReturn,
+            SPAN,
+            arguments,
+            self.ctx,
+        ));
+    }
+
+    fn create_static_property(&self, property: &IdentifierName<'a>) -> Expression<'a> {
+        self.ctx.ast.expression_string_literal(property.span, property.name, None)
+    }
+
+    /// `this`, or `_this` in derived constructor
+    fn create_receiver(&mut self) -> Expression<'a> {
+        if let Some(this_binding) = self.this_binding {
+            this_binding.create_read_expression(self.ctx)
+        } else {
+            self.create_this()
+        }
+    }
+
+    /// `this`, or `_this2` in a converted arrow function
+    fn create_this(&mut self) -> Expression<'a> {
+        if self.arrow_depth == 0 {
+            return self.ctx.ast.expression_this(SPAN);
+        }
+        let scope_id = self.function_scope_id.unwrap();
+        let ctx = &mut *self.ctx;
+        let this_alias = self.this_alias.get_or_insert_with(|| {
+            ctx.generate_uid("this", scope_id, SymbolFlags::FunctionScopedVariable)
+        });
+        this_alias.create_read_expression(self.ctx)
+    }
+
+    /// `new.target` in a converted arrow function -> `_newtarget`
+    fn transform_new_target(&mut self, expr: &mut Expression<'a>) {
+        let scope_id = self.function_scope_id.unwrap();
+        let ctx = &mut *self.ctx;
+        let new_target_alias = self.new_target_alias.get_or_insert_with(|| {
+            ctx.generate_uid("newtarget", scope_id, SymbolFlags::FunctionScopedVariable)
+        });
+        *expr = new_target_alias.create_spanned_read_expression(expr.span(), self.ctx);
+    }
+
+    fn create_number(&self, value: f64) -> Argument<'a> {
+        Argument::from(self.ctx.ast.expression_numeric_literal(
+            SPAN,
+            value,
+            None,
+            NumberBase::Decimal,
+        ))
+    }
+
+    /// `babelHelpers.superPropGet(A, prop, this, flags)`
+    ///
+    /// Flags: `1` = look up on prototype (instance methods), `2` = result is called.
+    fn create_super_prop_get(
+        &mut self,
+        span: Span,
+        property: Expression<'a>,
+        is_callee: bool,
+    ) -> Expression<'a> {
+        let mut arguments = self.ctx.ast.vec_from_array([
+            Argument::from(self.class_binding.create_read_expression(self.ctx)),
+            Argument::from(property),
+            Argument::from(self.create_receiver()),
+        ]);
+        let flags = u8::from(!self.is_static) | (u8::from(is_callee) << 1);
+        if flags != 0 {
+            arguments.push(self.create_number(f64::from(flags)));
+        }
+        self.classes.ctx.helper_call_expr(Helper::SuperPropGet, span, arguments, self.ctx)
+    }
+
+    /// `babelHelpers.superPropSet(A, prop, value, this, 1, 1)`
+    ///
+    /// 5th argument is `isStrict`, 6th argument is `1` for instance methods.
+    fn create_super_prop_set(
+        &mut self,
+        span: Span,
+        property: Expression<'a>,
+        value: Expression<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = self.ctx.ast.vec_from_array([
+            Argument::from(self.class_binding.create_read_expression(self.ctx)),
+            Argument::from(property),
+            Argument::from(value),
+            Argument::from(self.create_receiver()),
+            self.create_number(1.0),
+        ]);
+        if !self.is_static {
+            arguments.push(self.create_number(1.0));
+        }
+        self.classes.ctx.helper_call_expr(Helper::SuperPropSet, span, arguments, self.ctx)
+    }
+}
+
+impl<'a> VisitMut<'a> for ClassBodyVisitor<'a, '_, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if !self.should_convert() {
+            walk_mut::walk_expression(self, expr);
+            return;
+        }
+
+        // `super.prop()` - visit callee's computed key and arguments only,
+        // so that callee is not transformed as a property access
+        if let Expression::CallExpression(call) = expr {
+            if !call.optional
+                && call
+                    .callee
+                    .as_member_expression()
+                    .is_some_and(|member| member.object().is_super())
+            {
+                if let Expression::ComputedMemberExpression(member) = &mut call.callee {
+                    self.visit_expression(&mut member.expression);
+                }
+                self.visit_arguments(&mut call.arguments);
+                self.transform_super_member_call(call);
+                return;
+            }
+        }
+
+        walk_mut::walk_expression(self, expr);
+
+        match expr {
+            Expression::ThisExpression(this) => {
+                if let Some(this_binding) = self.this_binding {
+                    *expr = this_binding.create_spanned_read_expression(this.span, self.ctx);
+                } else if self.arrow_depth > 0 {
+                    *expr = self.create_this();
+                }
+            }
+            Expression::MetaProperty(meta)
+                if self.arrow_depth > 0
+                    && meta.meta.name == "new"
+                    && meta.property.name == "target" =>
+            {
+                self.transform_new_target(expr);
+            }
+            Expression::CallExpression(call) if call.callee.is_super() => {
+                self.transform_super_call(expr);
+            }
+            Expression::StaticMemberExpression(member) if member.object.is_super() => {
+                self.transform_super_member(expr);
+            }
+            Expression::ComputedMemberExpression(member) if member.object.is_super() => {
+                self.transform_super_member(expr);
+            }
+            Expression::AssignmentExpression(assign)
+                if assign
+                    .left
+                    .as_member_expression()
+                    .is_some_and(|member| member.object().is_super()) =>
+            {
+                self.transform_super_assignment(expr);
+            }
+            Expression::UpdateExpression(update)
+                if update
+                    .argument
+                    .as_member_expression()
+                    .is_some_and(|member| member.object().is_super()) =>
+            {
+                self.transform_super_update(expr);
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_return_statement(&mut self, stmt: &mut ReturnStatement<'a>) {
+        walk_mut::walk_return_statement(self, stmt);
+        if self.function_depth == 0 {
+            self.transform_return(stmt);
+        }
+    }
+
+    /// Redirect reference to class name to constructor function's binding.
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let Some(redirect_symbol_id) = self.redirect_symbol_id else { return };
+        let reference_id = ident.reference_id();
+        let scoping = self.ctx.scoping_mut();
+        if scoping.get_reference(reference_id).symbol_id() == Some(redirect_symbol_id) {
+            let symbol_id = self.class_binding.symbol_id;
+            scoping.delete_resolved_reference(redirect_symbol_id, reference_id);
+            scoping.get_reference_mut(reference_id).set_symbol_id(symbol_id);
+            scoping.add_resolved_reference(symbol_id, reference_id);
+        }
+    }
+
+    #[inline]
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.make_sloppy_mode {
+            let scope_id = scope_id.get().unwrap();
+            *self.ctx.scoping_mut().scope_flags_mut(scope_id) -= ScopeFlags::StrictMode;
+        }
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        let parent_sloppy_mode = self.make_sloppy_mode;
+        if func.has_use_strict_directive() {
+            self.make_sloppy_mode = false;
+        }
+        // Arrow function converted to a function expression shares `this` and `super`
+        // with the enclosing function
+        if self.classes.arrow_scope_ids.contains(&func.scope_id()) {
+            self.arrow_depth += 1;
+            self.function_depth += 1;
+            walk_mut::walk_function(self, func, flags);
+            self.arrow_depth -= 1;
+            self.function_depth -= 1;
+        } else {
+            self.this_depth += 1;
+            self.function_depth += 1;
+            walk_mut::walk_function(self, func, flags);
+            self.this_depth -= 1;
+            self.function_depth -= 1;
+        }
+        self.make_sloppy_mode = parent_sloppy_mode;
+    }
+
+    fn visit_arrow_function_expression(&mut self, func: &mut ArrowFunctionExpression<'a>) {
+        let parent_sloppy_mode = self.make_sloppy_mode;
+        if func.has_use_strict_directive() {
+            self.make_sloppy_mode = false;
+        }
+        self.function_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, func);
+        self.function_depth -= 1;
+        self.make_sloppy_mode = parent_sloppy_mode;
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        // `extends` clause is outside class's `this` scope, but is within its scope
+        let parent_sloppy_mode = self.make_sloppy_mode;
+        self.make_sloppy_mode = false;
+        self.this_depth += 1;
+        self.function_depth += 1;
+        walk_mut::walk_class(self, class);
+        self.this_depth -= 1;
+        self.function_depth -= 1;
+        self.make_sloppy_mode = parent_sloppy_mode;
+    }
+}
+
+/// Visitor to reparent first-level scopes within an expression which is moved out of class scope.
+struct ScopeReparenter<'a, 'v> {
+    scope_id: ScopeId,
+    scope_depth: u32,
+    ctx: &'v mut TraverseCtx<'a>,
+}
+
+impl<'a, 'v> ScopeReparenter<'a, 'v> {
+    fn reparent(expr: &mut Expression<'a>, scope_id: ScopeId, ctx: &'v mut TraverseCtx<'a>) {
+        Self { scope_id, scope_depth: 0, ctx }.visit_expression(expr);
+    }
+}
+
+impl<'a> VisitMut<'a> for ScopeReparenter<'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.scope_depth == 0 {
+            let scope_id = scope_id.get().unwrap();
+            self.ctx.scoping_mut().change_scope_parent_id(scope_id, Some(self.scope_id));
+        }
+        self.scope_depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_depth -= 1;
+    }
+}
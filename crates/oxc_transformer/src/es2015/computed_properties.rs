@@ -0,0 +1,272 @@
+//! ES2015: Computed Properties
+//!
+//! This plugin transforms object literals with computed keys.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const obj = {
+//!   a: 1,
+//!   [b]: 2,
+//!   c: 3,
+//!   get [d]() {},
+//! };
+//! ```
+//!
+//! Output:
+//! ```js
+//! const obj = babelHelpers.defineAccessor(
+//!   "get",
+//!   babelHelpers.defineProperty(babelHelpers.defineProperty({ a: 1 }, b, 2), "c", 3),
+//!   d,
+//!   function() {},
+//! );
+//! ```
+//!
+//! Properties before the first computed key stay in the object literal. The rest are defined
+//! one by one, in order, so keys and values are evaluated in the same order as before.
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! Enables the `setComputedProperties` assumption.
+//!
+//! ### `setComputedProperties` assumption
+//!
+//! Properties are assumed not to clash with setters on `Object.prototype`,
+//! so they are assigned instead of defined.
+//!
+//! ```js
+//! var _obj;
+//! const obj = (
+//!   _obj = { a: 1 },
+//!   _obj[b] = 2,
+//!   _obj.c = 3,
+//!   babelHelpers.defineAccessor("get", _obj, d, function() {}),
+//!   _obj
+//! );
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-computed-properties](https://babel.dev/docs/babel-plugin-transform-computed-properties).
+//!
+//! Object spread after a computed key (only present if object rest spread is not transformed)
+//! is defined with `babelHelpers.objectSpread2`.
+//!
+//! Objects containing a method using `super` after a computed key are not transformed,
+//! as the method can't be moved out of the object literal.
+//! See [`ShorthandProperties`](super::ShorthandProperties).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-computed-properties>
+//! * Object initializer specification: <https://tc39.es/ecma262/#sec-object-initializer>
+
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::ast::*;
+use oxc_semantic::ScopeFlags;
+use oxc_span::SPAN;
+use oxc_syntax::operator::AssignmentOperator;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::{Helper, context::TransformCtx};
+
+use super::shorthand_properties::property_value_uses_super;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ComputedPropertiesOptions {
+    pub loose: bool,
+}
+
+pub struct ComputedProperties<'a, 'ctx> {
+    set_computed_properties: bool,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ComputedProperties<'a, 'ctx> {
+    pub fn new(options: ComputedPropertiesOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            set_computed_properties: options.loose || ctx.assumptions.set_computed_properties,
+            ctx,
+        }
+    }
+}
+
+impl<'a> Traverse<'a> for ComputedProperties<'a, '_> {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if matches!(expr, Expression::ObjectExpression(_)) {
+            self.transform_object_expression(expr, ctx);
+        }
+    }
+}
+
+impl<'a> ComputedProperties<'a, '_> {
+    fn transform_object_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ObjectExpression(object) = expr else { unreachable!() };
+        let Some(index) = object.properties.iter().position(
+            |prop| matches!(prop, ObjectPropertyKind::ObjectProperty(prop) if prop.computed),
+        ) else {
+            return;
+        };
+        if object.properties[index..].iter().any(|prop| {
+            matches!(prop, ObjectPropertyKind::ObjectProperty(prop) if property_value_uses_super(&prop.value))
+        }) {
+            return;
+        }
+
+        let span = object.span;
+        let properties = ctx.ast.vec_from_iter(object.properties.drain(index..));
+        let init = expr.take_in(ctx.ast.allocator);
+        *expr = if self.set_computed_properties {
+            self.assign_properties(span, init, properties, ctx)
+        } else {
+            self.define_properties(span, init, properties, ctx)
+        };
+    }
+
+    /// `{ a: 1, [b]: 2, c: 3 }` -> `_defineProperty(_defineProperty({ a: 1 }, b, 2), "c", 3)`
+    ///
+    /// All the helpers return the object, so calls are nested rather than using a temp var.
+    fn define_properties(
+        &self,
+        span: Span,
+        init: Expression<'a>,
+        properties: ArenaVec<'a, ObjectPropertyKind<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut object = init;
+        for prop in properties {
+            object = match prop {
+                ObjectPropertyKind::ObjectProperty(prop) => {
+                    let ObjectProperty { kind, key, value, computed, .. } = prop.unbox();
+                    let key = Self::create_key(key, computed, ctx);
+                    self.create_define_call(kind, object, key, value, ctx)
+                }
+                ObjectPropertyKind::SpreadProperty(spread) => {
+                    self.create_spread_call(object, spread.unbox().argument, ctx)
+                }
+            };
+        }
+        if let Expression::CallExpression(call) = &mut object {
+            call.span = span;
+        }
+        object
+    }
+
+    /// `{ a: 1, [b]: 2, c: 3 }` -> `(_obj = { a: 1 }, _obj[b] = 2, _obj.c = 3, _obj)`
+    fn assign_properties(
+        &self,
+        span: Span,
+        init: Expression<'a>,
+        properties: ArenaVec<'a, ObjectPropertyKind<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let binding = self.ctx.var_declarations.create_uid_var("obj", ctx);
+        let mut expressions = ctx.ast.vec_with_capacity(properties.len() + 2);
+        expressions.push(Self::create_assignment(binding.create_write_target(ctx), init, ctx));
+        for prop in properties {
+            let object = binding.create_read_expression(ctx);
+            let expression = match prop {
+                ObjectPropertyKind::ObjectProperty(prop) => {
+                    let ObjectProperty { kind, key, value, computed, .. } = prop.unbox();
+                    if kind == PropertyKind::Init {
+                        let target = Self::create_member_target(object, key, computed, ctx);
+                        Self::create_assignment(target, value, ctx)
+                    } else {
+                        let key = Self::create_key(key, computed, ctx);
+                        self.create_define_call(kind, object, key, value, ctx)
+                    }
+                }
+                ObjectPropertyKind::SpreadProperty(spread) => {
+                    self.create_spread_call(object, spread.unbox().argument, ctx)
+                }
+            };
+            expressions.push(expression);
+        }
+        expressions.push(binding.create_read_expression(ctx));
+        ctx.ast.expression_sequence(span, expressions)
+    }
+
+    /// `_defineProperty(object, key, value)` or `_defineAccessor("get", object, key, value)`
+    fn create_define_call(
+        &self,
+        kind: PropertyKind,
+        object: Expression<'a>,
+        key: Expression<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = ctx.ast.vec_with_capacity(4);
+        let helper = match kind {
+            PropertyKind::Init => Helper::DefineProperty,
+            PropertyKind::Get | PropertyKind::Set => {
+                // Getter / setter is now a plain function expression
+                if let Expression::FunctionExpression(func) = &value {
+                    ctx.scoping_mut()
+                        .scope_flags_mut(func.scope_id())
+                        .remove(ScopeFlags::GetAccessor | ScopeFlags::SetAccessor);
+                }
+                let kind = if kind == PropertyKind::Get { "get" } else { "set" };
+                arguments.push(Argument::from(ctx.ast.expression_string_literal(SPAN, kind, None)));
+                Helper::DefineAccessor
+            }
+        };
+        arguments.push(Argument::from(object));
+        arguments.push(Argument::from(key));
+        arguments.push(Argument::from(value));
+        self.ctx.helper_call_expr(helper, SPAN, arguments, ctx)
+    }
+
+    /// `_objectSpread2(object, argument)`
+    fn create_spread_call(
+        &self,
+        object: Expression<'a>,
+        argument: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let arguments = ctx.ast.vec_from_array([Argument::from(object), Argument::from(argument)]);
+        self.ctx.helper_call_expr(Helper::ObjectSpread2, SPAN, arguments, ctx)
+    }
+
+    /// `[a]` -> `a`, `a` -> `"a"`, `1` -> `1`
+    fn create_key(key: PropertyKey<'a>, computed: bool, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        match key {
+            PropertyKey::StaticIdentifier(ident) if !computed => {
+                ctx.ast.expression_string_literal(ident.span, ident.name, None)
+            }
+            key => key.into_expression(),
+        }
+    }
+
+    /// `object[a]`, `object.a`, `object["a"]`
+    fn create_member_target(
+        object: Expression<'a>,
+        key: PropertyKey<'a>,
+        computed: bool,
+        ctx: &TraverseCtx<'a>,
+    ) -> AssignmentTarget<'a> {
+        let member = match key {
+            PropertyKey::StaticIdentifier(ident) if !computed => {
+                let property = ctx.ast.identifier_name(ident.span, ident.name);
+                ctx.ast.member_expression_static(SPAN, object, property, false)
+            }
+            key => ctx.ast.member_expression_computed(SPAN, object, key.into_expression(), false),
+        };
+        AssignmentTarget::from(member)
+    }
+
+    fn create_assignment(
+        target: AssignmentTarget<'a>,
+        value: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+    }
+}
@@ -0,0 +1,1197 @@
+//! ES2015: Destructuring
+//!
+//! This plugin transforms destructuring patterns in variable declarations, assignments,
+//! function parameters, `catch` clauses and `for-in` / `for-of` loops.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var { a, b: [c, d = 1], ...rest } = obj;
+//! ({ x, y } = foo());
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _foo;
+//! var _obj = obj,
+//!   a = _obj.a,
+//!   _obj$b = babelHelpers.slicedToArray(_obj.b, 2),
+//!   c = _obj$b[0],
+//!   _obj$b$ = _obj$b[1],
+//!   d = _obj$b$ === void 0 ? 1 : _obj$b$,
+//!   rest = babelHelpers.objectWithoutProperties(_obj, ["a", "b"]);
+//! _foo = foo(), x = _foo.x, y = _foo.y;
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! Enables the `iterableIsArray` assumption.
+//!
+//! ### `allowArrayLike`
+//!
+//! Enables the `arrayLikeIsIterable` assumption.
+//!
+//! ### `iterableIsArray` assumption
+//!
+//! Values destructured by array patterns are assumed to be arrays, so they are indexed directly.
+//!
+//! ```js
+//! var _arr = arr, c = _arr[0], d = _arr[1];
+//! ```
+//!
+//! ### `arrayLikeIsIterable` assumption
+//!
+//! Array-like objects which are not iterable can be destructured by array patterns.
+//!
+//! ```js
+//! var _arr = babelHelpers.maybeArrayLike(babelHelpers.slicedToArray, arr, 2);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-destructuring](https://babel.dev/docs/babel-plugin-transform-destructuring).
+//!
+//! Destructuring parameters are moved into the function body with the same logic as
+//! the parameters plugin, and the resulting variable declarations are transformed here.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-destructuring>
+//! * Destructuring assignment specification: <https://tc39.es/ecma262/#sec-destructuring-assignment>
+//! * Destructuring binding patterns specification: <https://tc39.es/ecma262/#sec-destructuring-binding-patterns>
+
+use std::mem;
+
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::Visit;
+use oxc_ecmascript::{
+    BoundNames, ToJsString, is_global_reference::WithoutGlobalReferenceInformation,
+};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::operator::BinaryOperator;
+use oxc_traverse::{Ancestor, BoundIdentifier, MaybeBoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{Helper, context::TransformCtx, utils::ast_builder::create_member_callee};
+
+use super::{
+    parameters::{ConvertParamsOptions, Parameters, ensure_arrow_function_block_body, is_setter},
+    spread::{ToArrayKind, to_array},
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DestructuringOptions {
+    pub loose: bool,
+
+    pub allow_array_like: bool,
+}
+
+pub struct Destructuring<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// `loose` option or `iterableIsArray` assumption.
+    iterable_is_array: bool,
+    /// `allowArrayLike` option or `arrayLikeIsIterable` assumption.
+    array_like_is_iterable: bool,
+    /// `ignoreFunctionLength` assumption, used when moving parameters into the function body.
+    ignore_function_length: bool,
+    /// Excluded keys of object rest, hoisted to the top level.
+    excluded_variable_declarators: Vec<VariableDeclarator<'a>>,
+}
+
+impl<'a, 'ctx> Destructuring<'a, 'ctx> {
+    pub fn new(options: DestructuringOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            iterable_is_array: options.loose || ctx.assumptions.iterable_is_array,
+            array_like_is_iterable: options.allow_array_like
+                || ctx.assumptions.array_like_is_iterable,
+            ignore_function_length: ctx.assumptions.ignore_function_length,
+            excluded_variable_declarators: vec![],
+        }
+    }
+}
+
+impl<'a> Traverse<'a> for Destructuring<'a, '_> {
+    // For excluded keys when destructuring inside a function.
+    // `function foo() { var {a, ...b} = c }` -> `const _excluded = ["a"]; function foo() { ... }`
+    fn exit_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.excluded_variable_declarators.is_empty() {
+            let declarators = ctx.ast.vec_from_iter(self.excluded_variable_declarators.drain(..));
+            let kind = VariableDeclarationKind::Const;
+            let declaration = ctx.ast.alloc_variable_declaration(SPAN, kind, declarators, false);
+            let statement = Statement::VariableDeclaration(declaration);
+            self.ctx.top_level_statements.insert_statement(statement);
+        }
+    }
+
+    // `export const { a } = b;`
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if matches!(stmt, Statement::ExportNamedDeclaration(_)) {
+            self.split_export_declaration(stmt, ctx);
+        }
+    }
+
+    // `var { a } = b;`
+    // Includes `for (var { a } = b;;);`
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.transform_variable_declaration(decl, ctx);
+    }
+
+    // `({ a } = b);`
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if matches!(expr, Expression::AssignmentExpression(_)) {
+            self.transform_assignment_expression(expr, ctx);
+        }
+    }
+
+    // `function foo({ a }) {}`
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = func.scope_id();
+        let Some(body) = func.body.as_mut() else { return };
+        let Some(from) = first_destructuring_param_index(&func.params) else { return };
+        let options = ConvertParamsOptions {
+            from,
+            loose: self.ignore_function_length || is_setter(ctx),
+            lower_rest: false,
+        };
+        Parameters::convert_params(&mut func.params, body, scope_id, options, ctx);
+    }
+
+    // `({ a }) => {}`
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(from) = first_destructuring_param_index(&arrow.params) else { return };
+        ensure_arrow_function_block_body(arrow, ctx);
+        let scope_id = arrow.scope_id();
+        let options = ConvertParamsOptions { from, loose: true, lower_rest: false };
+        Parameters::convert_params(&mut arrow.params, &mut arrow.body, scope_id, options, ctx);
+    }
+
+    // `try {} catch ({ a }) {}`
+    fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        Self::transform_catch_clause(clause, ctx);
+    }
+
+    // `for (var { a } in b);` `for ({ a } in b);`
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = stmt.scope_id();
+        Self::transform_for_statement_left(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+    }
+
+    // `for (var { a } of b);` `for ({ a } of b);`
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = stmt.scope_id();
+        Self::transform_for_statement_left(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+    }
+}
+
+impl<'a, 'ctx> Destructuring<'a, 'ctx> {
+    fn create_transformer(
+        &self,
+        declaration: Option<DeclarationState>,
+    ) -> DestructuringTransformer<'a, 'ctx> {
+        DestructuringTransformer {
+            ctx: self.ctx,
+            declaration,
+            iterable_is_array: self.iterable_is_array,
+            array_like_is_iterable: self.array_like_is_iterable,
+            declarators: vec![],
+            expressions: vec![],
+            temps: vec![],
+            arrays: vec![],
+            excluded_variable_declarators: vec![],
+        }
+    }
+
+    /// Split a destructuring export declaration into a declaration and an export,
+    /// so temporary variables are not exported.
+    ///
+    /// `export const { a, b } = c;` -> `const { a, b } = c; export { a, b };`
+    fn split_export_declaration(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Statement::ExportNamedDeclaration(export) = stmt else { unreachable!() };
+        let Some(Declaration::VariableDeclaration(decl)) = &export.declaration else { return };
+        if !decl.declarations.iter().any(|declarator| declarator.id.kind.is_destructuring_pattern())
+        {
+            return;
+        }
+
+        let Some(Declaration::VariableDeclaration(decl)) = export.declaration.take() else {
+            unreachable!()
+        };
+        let mut specifiers = ctx.ast.vec();
+        decl.bound_names(&mut |ident| {
+            let local = BoundIdentifier::from_binding_ident(ident).create_read_reference(ctx);
+            specifiers.push(ctx.ast.export_specifier(
+                SPAN,
+                ModuleExportName::IdentifierReference(local),
+                ctx.ast.module_export_name_identifier_name(SPAN, ident.name),
+                ImportOrExportKind::Value,
+            ));
+        });
+        *stmt = Statement::VariableDeclaration(decl);
+
+        let export = ctx.ast.alloc_export_named_declaration(
+            SPAN,
+            None,
+            specifiers,
+            None,
+            ImportOrExportKind::Value,
+            NONE,
+        );
+        self.ctx.statement_injector.insert_after(stmt, Statement::ExportNamedDeclaration(export));
+    }
+
+    /// `var { a, b } = c;` -> `var a = c.a, b = c.b;`
+    fn transform_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !decl.declarations.iter().any(|declarator| {
+            declarator.init.is_some() && declarator.id.kind.is_destructuring_pattern()
+        }) {
+            return;
+        }
+
+        let kind = decl.kind;
+        let scope_id =
+            if kind.is_var() { ctx.current_hoist_scope_id() } else { ctx.current_scope_id() };
+        let state = DeclarationState { kind, scope_id, symbol_flags: kind_to_symbol_flags(kind) };
+        let mut transformer = self.create_transformer(Some(state));
+        for declarator in decl.declarations.take_in(ctx.ast.allocator) {
+            if declarator.init.is_some() && declarator.id.kind.is_destructuring_pattern() {
+                let VariableDeclarator { id, init, .. } = declarator;
+                transformer.init(Target::Binding(id), init.unwrap(), ctx);
+            } else {
+                transformer.declarators.push(declarator);
+            }
+        }
+
+        decl.declarations = ctx.ast.vec_from_iter(transformer.declarators);
+        self.excluded_variable_declarators.extend(transformer.excluded_variable_declarators);
+    }
+
+    /// `({ a, b } = c)` -> `(_c = c, a = _c.a, b = _c.b, _c)`
+    ///
+    /// If the value of the assignment is not used, the trailing reference is omitted:
+    /// `({ a, b } = c);` -> `a = c.a, b = c.b;`
+    fn transform_assignment_expression(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::AssignmentExpression(assign) = expr else { unreachable!() };
+        if assign.operator != AssignmentOperator::Assign
+            || !assign.left.is_assignment_target_pattern()
+        {
+            return;
+        }
+
+        let value_used = !is_value_unused(ctx);
+        let Expression::AssignmentExpression(assign) = expr.take_in(ctx.ast.allocator) else {
+            unreachable!()
+        };
+        let AssignmentExpression { span, left, right, .. } = assign.unbox();
+        let target = Target::Assignment(AssignmentTargetMaybeDefault::from(left));
+
+        let mut transformer = self.create_transformer(None);
+        if value_used {
+            let is_array = matches!(right, Expression::ArrayExpression(_));
+            let binding = transformer.create_temp(right, ctx);
+            if is_array {
+                transformer.arrays.push(binding.symbol_id);
+            }
+            transformer.push(target, binding.create_read_expression(ctx), ctx);
+            transformer.expressions.push(binding.create_read_expression(ctx));
+        } else {
+            transformer.init(target, right, ctx);
+        }
+        self.excluded_variable_declarators.extend(transformer.excluded_variable_declarators);
+
+        let mut expressions = transformer.expressions;
+        *expr = if expressions.len() == 1 {
+            expressions.pop().unwrap()
+        } else {
+            ctx.ast.expression_sequence(span, ctx.ast.vec_from_iter(expressions))
+        };
+    }
+
+    /// `catch ({ a }) {}` -> `catch (_ref) { let { a } = _ref; }`
+    fn transform_catch_clause(clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        let body_scope_id = clause.body.scope_id();
+        let Some(param) = &mut clause.param else { return };
+        if !param.pattern.kind.is_destructuring_pattern() {
+            return;
+        }
+
+        // Catch parameter bindings live in the catch body scope.
+        let flags = SymbolFlags::CatchVariable | SymbolFlags::FunctionScopedVariable;
+        let binding = ctx.generate_uid("ref", body_scope_id, flags);
+        let pattern = mem::replace(&mut param.pattern, binding.create_binding_pattern(ctx));
+
+        // The bindings are now declared by `let` in the catch body.
+        pattern.bound_names(&mut |ident| {
+            *ctx.scoping_mut().symbol_flags_mut(ident.symbol_id()) =
+                SymbolFlags::BlockScopedVariable;
+        });
+
+        let init = binding.create_read_expression(ctx);
+        let decl = create_variable_declaration(VariableDeclarationKind::Let, pattern, init, ctx);
+        clause.body.body.insert(0, decl);
+    }
+
+    /// * `for (const { a } of b);` -> `for (const _ref of b) { const { a } = _ref; }`
+    /// * `for ({ a } of b);` -> `var _ref; for (_ref of b) { ({ a } = _ref); }`
+    fn transform_for_statement_left(
+        left: &mut ForStatementLeft<'a>,
+        body: &mut Statement<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let ForStatementLeft::VariableDeclaration(decl) = left {
+            let kind = decl.kind;
+            let declarator = &mut decl.declarations[0];
+            if !declarator.id.kind.is_destructuring_pattern() {
+                return;
+            }
+
+            let binding = if kind.is_var() {
+                let flags = SymbolFlags::FunctionScopedVariable;
+                ctx.generate_uid("ref", ctx.current_hoist_scope_id(), flags)
+            } else {
+                ctx.generate_uid("ref", scope_id, kind_to_symbol_flags(kind))
+            };
+            let pattern = mem::replace(&mut declarator.id, binding.create_binding_pattern(ctx));
+
+            let mut names = vec![];
+            pattern.bound_names(&mut |ident| names.push(ident.name));
+            let block_scope_id = ensure_block_statement(body, scope_id, &names, ctx);
+            if !kind.is_var() {
+                // Move the bindings from the for statement scope to the loop body scope.
+                move_bindings(&pattern, scope_id, block_scope_id, ctx);
+            }
+
+            let init = binding.create_read_expression(ctx);
+            let decl = create_variable_declaration(kind, pattern, init, ctx);
+            let Statement::BlockStatement(block) = body else { unreachable!() };
+            block.body.insert(0, decl);
+        } else {
+            if !left.to_assignment_target().is_assignment_target_pattern() {
+                return;
+            }
+
+            let flags = SymbolFlags::FunctionScopedVariable;
+            let binding = ctx.generate_uid("ref", ctx.current_hoist_scope_id(), flags);
+            let target = left.to_assignment_target_mut().take_in(ctx.ast.allocator);
+            let kind = VariableDeclarationKind::Var;
+            let declarator = ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                binding.create_binding_pattern(ctx),
+                None,
+                false,
+            );
+            *left = ForStatementLeft::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                SPAN,
+                kind,
+                ctx.ast.vec1(declarator),
+                false,
+            ));
+
+            ensure_block_statement(body, scope_id, &[], ctx);
+            let value = binding.create_read_expression(ctx);
+            let assignment =
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+            let Statement::BlockStatement(block) = body else { unreachable!() };
+            block.body.insert(0, ctx.ast.statement_expression(SPAN, assignment));
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DeclarationState {
+    kind: VariableDeclarationKind,
+    /// Scope for temporary variables.
+    scope_id: ScopeId,
+    symbol_flags: SymbolFlags,
+}
+
+/// A destructuring target, either a binding pattern in a declaration,
+/// or an assignment target in an assignment expression.
+enum Target<'a> {
+    Binding(BindingPattern<'a>),
+    Assignment(AssignmentTargetMaybeDefault<'a>),
+}
+
+struct ObjectProperty<'a> {
+    key: PropertyKey<'a>,
+    target: Target<'a>,
+}
+
+/// A value which can be read more than once.
+struct ValueReference<'a> {
+    expr: Option<Expression<'a>>,
+    binding: Option<MaybeBoundIdentifier<'a>>,
+}
+
+impl<'a> ValueReference<'a> {
+    /// A value which is only read once.
+    fn once(expr: Expression<'a>) -> Self {
+        Self { expr: Some(expr), binding: None }
+    }
+
+    fn read(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        self.expr.take().unwrap_or_else(|| {
+            self.binding.as_ref().expect("value can only be read once").create_read_expression(ctx)
+        })
+    }
+}
+
+/// Flattens destructuring patterns into a list of declarators or assignment expressions.
+///
+/// Based on Babel's `DestructuringTransformer`.
+struct DestructuringTransformer<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// `Some` when transforming a variable declaration, `None` for an assignment expression.
+    declaration: Option<DeclarationState>,
+    iterable_is_array: bool,
+    array_like_is_iterable: bool,
+    /// Output of a variable declaration.
+    declarators: Vec<VariableDeclarator<'a>>,
+    /// Output of an assignment expression.
+    expressions: Vec<Expression<'a>>,
+    /// Temporary variables created by this transformer.
+    temps: Vec<SymbolId>,
+    /// Temporary variables which are known to hold an array.
+    arrays: Vec<SymbolId>,
+    excluded_variable_declarators: Vec<VariableDeclarator<'a>>,
+}
+
+impl<'a> DestructuringTransformer<'a, '_> {
+    /// Entry point. Memoise the value if it's not static, and push the target.
+    fn init(&mut self, target: Target<'a>, value: Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let value = if matches!(value, Expression::ArrayExpression(_))
+            || value.is_member_expression()
+            || self.is_static(&value, ctx)
+        {
+            value
+        } else {
+            self.create_temp(value, ctx).create_read_expression(ctx)
+        };
+        self.push(target, value, ctx);
+    }
+
+    fn push(&mut self, target: Target<'a>, value: Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match target {
+            Target::Binding(pattern) => self.push_binding_pattern(pattern, value, ctx),
+            Target::Assignment(target) => self.push_assignment_target(target, value, ctx),
+        }
+    }
+
+    fn push_binding_pattern(
+        &mut self,
+        pattern: BindingPattern<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match pattern.kind {
+            BindingPatternKind::BindingIdentifier(_) => {
+                let Some(state) = self.declaration else { unreachable!() };
+                let declarator =
+                    ctx.ast.variable_declarator(SPAN, state.kind, pattern, Some(value), false);
+                self.declarators.push(declarator);
+            }
+            BindingPatternKind::ObjectPattern(object) => {
+                let ObjectPattern { properties, rest, .. } = object.unbox();
+                let properties = properties
+                    .into_iter()
+                    .map(|property| ObjectProperty {
+                        key: property.key,
+                        target: Target::Binding(property.value),
+                    })
+                    .collect();
+                let rest = rest.map(|rest| Target::Binding(rest.unbox().argument));
+                self.push_object_pattern(properties, rest, value, ctx);
+            }
+            BindingPatternKind::ArrayPattern(array) => {
+                let ArrayPattern { elements, rest, .. } = array.unbox();
+                let elements =
+                    elements.into_iter().map(|element| element.map(Target::Binding)).collect();
+                let rest = rest.map(|rest| Target::Binding(rest.unbox().argument));
+                self.push_array_pattern(elements, rest, value, ctx);
+            }
+            BindingPatternKind::AssignmentPattern(assign) => {
+                let AssignmentPattern { left, right, .. } = assign.unbox();
+                let value = self.build_default(value, right, ctx);
+                self.push_binding_pattern(left, value, ctx);
+            }
+        }
+    }
+
+    fn push_assignment_target(
+        &mut self,
+        target: AssignmentTargetMaybeDefault<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match target {
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                let AssignmentTargetWithDefault { binding, init, .. } = target.unbox();
+                let value = self.build_default(value, init, ctx);
+                self.push_assignment_target(
+                    AssignmentTargetMaybeDefault::from(binding),
+                    value,
+                    ctx,
+                );
+            }
+            AssignmentTargetMaybeDefault::ObjectAssignmentTarget(object) => {
+                let ObjectAssignmentTarget { properties, rest, .. } = object.unbox();
+                let mut object_properties = Vec::with_capacity(properties.len());
+                for property in properties {
+                    let property = match property {
+                        // `{ a = 1 }`
+                        AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+                            let AssignmentTargetPropertyIdentifier { span, binding, init } =
+                                property.unbox();
+                            let key =
+                                ctx.ast.property_key_static_identifier(binding.span, binding.name);
+                            let target = AssignmentTarget::AssignmentTargetIdentifier(
+                                ctx.ast.alloc(binding),
+                            );
+                            let target = match init {
+                                Some(init) => {
+                                    AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(
+                                        ctx.ast.alloc_assignment_target_with_default(
+                                            span, target, init,
+                                        ),
+                                    )
+                                }
+                                None => AssignmentTargetMaybeDefault::from(target),
+                            };
+                            ObjectProperty { key, target: Target::Assignment(target) }
+                        }
+                        // `{ a: b = 1 }`
+                        AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                            let property = property.unbox();
+                            ObjectProperty {
+                                key: property.name,
+                                target: Target::Assignment(property.binding),
+                            }
+                        }
+                    };
+                    object_properties.push(property);
+                }
+                let rest = rest.map(|rest| {
+                    Target::Assignment(AssignmentTargetMaybeDefault::from(rest.target))
+                });
+                self.push_object_pattern(object_properties, rest, value, ctx);
+            }
+            AssignmentTargetMaybeDefault::ArrayAssignmentTarget(array) => {
+                let ArrayAssignmentTarget { elements, rest, .. } = array.unbox();
+                let elements =
+                    elements.into_iter().map(|element| element.map(Target::Assignment)).collect();
+                let rest = rest.map(|rest| {
+                    Target::Assignment(AssignmentTargetMaybeDefault::from(rest.target))
+                });
+                self.push_array_pattern(elements, rest, value, ctx);
+            }
+            target => {
+                let target = target.into_assignment_target();
+                let assignment =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+                self.expressions.push(assignment);
+            }
+        }
+    }
+
+    /// `{ a, b: c, ...d } = value`
+    fn push_object_pattern(
+        &mut self,
+        mut properties: Vec<ObjectProperty<'a>>,
+        rest: Option<Target<'a>>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `{} = value` -> `_objectDestructuringEmpty(value)`
+        if properties.is_empty() && rest.is_none() {
+            let arguments = ctx.ast.vec1(Argument::from(value));
+            let call =
+                self.ctx.helper_call_expr(Helper::ObjectDestructuringEmpty, SPAN, arguments, ctx);
+            self.push_expression(call, ctx);
+            return;
+        }
+
+        // The object is read once for each property, and once more for the rest element.
+        let mut object = if properties.len() > 1 || rest.is_some() {
+            self.memoise(value, ctx)
+        } else {
+            ValueReference::once(value)
+        };
+
+        // Computed keys are read twice if there is a rest element, once for the property access
+        // and once for the excluded keys. So replace impure keys with temporary variables.
+        let excluded_keys = if rest.is_some() {
+            for property in &mut properties {
+                self.memoise_key(&mut property.key, ctx);
+            }
+            Some(Self::create_excluded_keys(&properties, ctx))
+        } else {
+            None
+        };
+
+        for ObjectProperty { key, target } in properties {
+            let object = object.read(ctx);
+            let value = create_property_access(object, key, ctx);
+            self.push(target, value, ctx);
+        }
+
+        if let Some(rest) = rest {
+            let (keys, all_literal) = excluded_keys.unwrap();
+            let value = self.build_object_rest(&mut object, keys, all_literal, ctx);
+            self.push(rest, value, ctx);
+        }
+    }
+
+    /// `[a, b, ...c] = value`
+    fn push_array_pattern(
+        &mut self,
+        elements: Vec<Option<Target<'a>>>,
+        rest: Option<Target<'a>>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Expression::ArrayExpression(array) = &value {
+            if Self::can_unpack_array_pattern(&elements, rest.is_some(), array) {
+                self.push_unpacked_array_pattern(elements, rest, value, ctx);
+                return;
+            }
+        }
+
+        let mut array = if self.iterable_is_array || self.is_array_reference(&value, ctx) {
+            if let Expression::Identifier(ident) = &value {
+                let binding = MaybeBoundIdentifier::from_identifier_reference(ident, ctx);
+                ValueReference { expr: Some(value), binding: Some(binding) }
+            } else {
+                let binding = self.create_temp(value, ctx);
+                self.arrays.push(binding.symbol_id);
+                ValueReference { expr: None, binding: Some(binding.to_maybe_bound_identifier()) }
+            }
+        } else {
+            // `_slicedToArray(value, 2)` or `_toArray(value)`
+            let kind = if rest.is_some() {
+                ToArrayKind::Rest
+            } else {
+                ToArrayKind::Sliced(elements.len())
+            };
+            let binding = self.create_temp_binding(&value, ctx);
+            let (value, _) = to_array(value, kind, self.array_like_is_iterable, self.ctx, ctx);
+            self.assign_temp(&binding, value, ctx);
+            self.arrays.push(binding.symbol_id);
+            ValueReference { expr: None, binding: Some(binding.to_maybe_bound_identifier()) }
+        };
+
+        let rest_index = elements.len();
+        for (index, element) in elements.into_iter().enumerate() {
+            let Some(element) = element else { continue };
+            // `_ref[0]`
+            let value = Expression::from(ctx.ast.member_expression_computed(
+                SPAN,
+                array.read(ctx),
+                create_number_literal(index, ctx),
+                false,
+            ));
+            self.push(element, value, ctx);
+        }
+
+        if let Some(rest) = rest {
+            // `_ref.slice(2)`
+            let callee = create_member_callee(array.read(ctx), "slice", ctx);
+            let arguments = ctx.ast.vec1(Argument::from(create_number_literal(rest_index, ctx)));
+            let value = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+            self.push(rest, value, ctx);
+        }
+    }
+
+    /// An array pattern can be matched with an array literal element by element,
+    /// if the array literal has no spread elements, and doesn't reference any of the bindings.
+    ///
+    /// `[a, b] = [1, 2]` -> `a = 1, b = 2`
+    fn can_unpack_array_pattern(
+        elements: &[Option<Target<'a>>],
+        has_rest: bool,
+        array: &ArrayExpression<'a>,
+    ) -> bool {
+        if elements.len() > array.elements.len()
+            || (elements.len() < array.elements.len() && !has_rest)
+        {
+            return false;
+        }
+
+        let mut names = vec![];
+        for element in elements {
+            match element {
+                None => return false,
+                Some(Target::Assignment(target)) if target.is_member_expression() => return false,
+                Some(target) => collect_target_names(target, &mut names),
+            }
+        }
+
+        if array.elements.iter().any(|element| {
+            matches!(
+                element,
+                ArrayExpressionElement::SpreadElement(_)
+                    | ArrayExpressionElement::CallExpression(_)
+            ) || element.is_member_expression()
+        }) {
+            return false;
+        }
+
+        let mut finder = ReferenceFinder { names: &names, found: false };
+        finder.visit_array_expression(array);
+        !finder.found
+    }
+
+    /// `[a, b, ...c] = [1, 2, 3, 4]` -> `a = 1, b = 2, c = [3, 4]`
+    fn push_unpacked_array_pattern(
+        &mut self,
+        elements: Vec<Option<Target<'a>>>,
+        rest: Option<Target<'a>>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::ArrayExpression(array) = value else { unreachable!() };
+        let mut values = array.unbox().elements.into_iter();
+        for element in elements.into_iter().flatten() {
+            let value = match values.next().unwrap() {
+                ArrayExpressionElement::Elision(_) => ctx.ast.void_0(SPAN),
+                element => element.into_expression(),
+            };
+            self.push(element, value, ctx);
+        }
+
+        if let Some(rest) = rest {
+            let elements = values.map(|element| match element {
+                ArrayExpressionElement::Elision(_) => {
+                    ArrayExpressionElement::from(ctx.ast.void_0(SPAN))
+                }
+                element => element,
+            });
+            let value = ctx.ast.expression_array(SPAN, ctx.ast.vec_from_iter(elements));
+            self.push(rest, value, ctx);
+        }
+    }
+
+    /// `value` -> `_value === void 0 ? default : _value`
+    fn build_default(
+        &mut self,
+        value: Expression<'a>,
+        default: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if value.is_void_0() {
+            return default;
+        }
+        let binding = self.create_temp(value, ctx);
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            binding.create_read_expression(ctx),
+            BinaryOperator::StrictEquality,
+            ctx.ast.void_0(SPAN),
+        );
+        ctx.ast.expression_conditional(SPAN, test, default, binding.create_read_expression(ctx))
+    }
+
+    /// * `{ ...rest } = obj` -> `rest = _extends({}, (_objectDestructuringEmpty(obj), obj))`
+    /// * `{ a, ...rest } = obj` -> `rest = _objectWithoutProperties(obj, ["a"])`
+    fn build_object_rest(
+        &mut self,
+        object: &mut ValueReference<'a>,
+        keys: ArenaVec<'a, ArrayExpressionElement<'a>>,
+        all_literal: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if keys.is_empty() {
+            let arguments = ctx.ast.vec1(Argument::from(object.read(ctx)));
+            let check =
+                self.ctx.helper_call_expr(Helper::ObjectDestructuringEmpty, SPAN, arguments, ctx);
+            let sequence = ctx
+                .ast
+                .expression_sequence(SPAN, ctx.ast.vec_from_array([check, object.read(ctx)]));
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(ctx.ast.expression_object(SPAN, ctx.ast.vec())),
+                Argument::from(sequence),
+            ]);
+            return self.ctx.helper_call_expr(Helper::Extends, SPAN, arguments, ctx);
+        }
+
+        let keys = ctx.ast.expression_array(SPAN, keys);
+        let keys = if !all_literal {
+            // `[a].map(_toPropertyKey)`
+            let callee = create_member_callee(keys, "map", ctx);
+            let arguments =
+                ctx.ast.vec1(Argument::from(self.ctx.helper_load(Helper::ToPropertyKey, ctx)));
+            ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+        } else if ctx.current_scope_id() == ctx.scoping().root_scope_id() {
+            keys
+        } else {
+            // Hoist the keys to the top level, so the array is not created on each call.
+            let binding = ctx.generate_uid_in_root_scope(
+                "excluded",
+                SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable,
+            );
+            self.excluded_variable_declarators.push(ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Const,
+                binding.create_binding_pattern(ctx),
+                Some(keys),
+                false,
+            ));
+            binding.create_read_expression(ctx)
+        };
+        let arguments =
+            ctx.ast.vec_from_array([Argument::from(object.read(ctx)), Argument::from(keys)]);
+        self.ctx.helper_call_expr(Helper::ObjectWithoutProperties, SPAN, arguments, ctx)
+    }
+
+    /// Create the list of keys excluded from an object rest element.
+    ///
+    /// Returns the keys, and `true` if all keys are literals.
+    fn create_excluded_keys(
+        properties: &[ObjectProperty<'a>],
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (ArenaVec<'a, ArrayExpressionElement<'a>>, bool) {
+        let mut all_literal = true;
+        let mut keys = ctx.ast.vec_with_capacity(properties.len());
+        for property in properties {
+            let key = match &property.key {
+                PropertyKey::StaticIdentifier(ident) => {
+                    ctx.ast.expression_string_literal(ident.span, ident.name, None)
+                }
+                PropertyKey::PrivateIdentifier(_) => unreachable!(),
+                key => match key.to_expression() {
+                    expr if expr.is_literal() => {
+                        let value = expr.to_js_string(&WithoutGlobalReferenceInformation {});
+                        let value = ctx.ast.atom_from_cow(&value.unwrap());
+                        ctx.ast.expression_string_literal(expr.span(), value, None)
+                    }
+                    // Impure keys have been replaced with temporary variables by `memoise_key`.
+                    Expression::Identifier(ident) => {
+                        all_literal = false;
+                        MaybeBoundIdentifier::from_identifier_reference(ident, ctx)
+                            .create_read_expression(ctx)
+                    }
+                    _ => unreachable!(),
+                },
+            };
+            keys.push(ArrayExpressionElement::from(key));
+        }
+        (keys, all_literal)
+    }
+
+    /// Replace a computed key which is not a literal or a bound identifier with a temporary variable.
+    ///
+    /// `{ [foo()]: a, ...b }` -> `_foo = foo()`, `{ [_foo]: a, ...b }`
+    fn memoise_key(&mut self, key: &mut PropertyKey<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(expr) = key.as_expression_mut() else { return };
+        if expr.is_literal() || is_bound_identifier(expr, ctx) {
+            return;
+        }
+        let binding = self.create_temp(expr.take_in(ctx.ast.allocator), ctx);
+        *expr = binding.create_read_expression(ctx);
+    }
+
+    /// Store `value` in a temporary variable, unless it's a static identifier.
+    fn memoise(&mut self, value: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> ValueReference<'a> {
+        if self.is_static(&value, ctx) {
+            let Expression::Identifier(ident) = &value else { unreachable!() };
+            let binding = MaybeBoundIdentifier::from_identifier_reference(ident, ctx);
+            return ValueReference { expr: Some(value), binding: Some(binding) };
+        }
+        let binding = self.create_temp(value, ctx);
+        ValueReference { expr: None, binding: Some(binding.to_maybe_bound_identifier()) }
+    }
+
+    /// `true` if `expr` is an identifier which is never reassigned, so it can be read more than once.
+    fn is_static(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let Expression::Identifier(ident) = expr else { return false };
+        let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() else {
+            return false;
+        };
+        self.temps.contains(&symbol_id) || !ctx.scoping().symbol_is_mutated(symbol_id)
+    }
+
+    /// `true` if `expr` is a temporary variable which holds an array.
+    fn is_array_reference(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let Expression::Identifier(ident) = expr else { return false };
+        ctx.scoping()
+            .get_reference(ident.reference_id())
+            .symbol_id()
+            .is_some_and(|symbol_id| self.arrays.contains(&symbol_id))
+    }
+
+    /// Create a temporary variable initialized to `value`.
+    fn create_temp(
+        &mut self,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let binding = self.create_temp_binding(&value, ctx);
+        self.assign_temp(&binding, value, ctx);
+        binding
+    }
+
+    /// Create a temporary variable with a name based on `node`.
+    ///
+    /// In a variable declaration, it's declared by [`Self::assign_temp`] with the same kind.
+    /// Otherwise it's declared as a `var` at the top of the enclosing function.
+    fn create_temp_binding(
+        &mut self,
+        node: &Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let binding = match self.declaration {
+            Some(state) => ctx.generate_uid_based_on_node(node, state.scope_id, state.symbol_flags),
+            None => self.ctx.var_declarations.create_uid_var_based_on_node(node, ctx),
+        };
+        self.temps.push(binding.symbol_id);
+        binding
+    }
+
+    /// `_ref = value`
+    fn assign_temp(
+        &mut self,
+        binding: &BoundIdentifier<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(state) = self.declaration {
+            let pattern = binding.create_binding_pattern(ctx);
+            let declarator =
+                ctx.ast.variable_declarator(SPAN, state.kind, pattern, Some(value), false);
+            self.declarators.push(declarator);
+        } else {
+            let target = binding.create_write_target(ctx);
+            let assignment =
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+            self.expressions.push(assignment);
+        }
+    }
+
+    /// Push an expression which is only evaluated for its side effects.
+    fn push_expression(&mut self, expr: Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match self.declaration {
+            Some(state) => {
+                let binding = ctx.generate_uid("ref", state.scope_id, state.symbol_flags);
+                self.assign_temp(&binding, expr, ctx);
+            }
+            None => self.expressions.push(expr),
+        }
+    }
+}
+
+/// Index of the first parameter which needs to be moved into the function body,
+/// or `None` if there are no destructuring parameters.
+fn first_destructuring_param_index(params: &FormalParameters<'_>) -> Option<usize> {
+    params.items.iter().position(|param| param.pattern.kind.is_destructuring_pattern()).or_else(
+        || {
+            params
+                .rest
+                .as_ref()
+                .is_some_and(|rest| rest.argument.kind.is_destructuring_pattern())
+                .then_some(params.items.len())
+        },
+    )
+}
+
+/// `true` if the value of the current assignment expression is not used.
+fn is_value_unused(ctx: &TraverseCtx<'_>) -> bool {
+    // Skip parentheses: `({ a } = b);`
+    let mut ancestors =
+        ctx.ancestors().skip_while(|ancestor| ancestor.is_parenthesized_expression());
+    match ancestors.next() {
+        // `() => ({ a } = b)` is an expression statement in the body of the arrow function
+        Some(Ancestor::ExpressionStatementExpression(_)) => !matches!(
+            ancestors.next(),
+            Some(Ancestor::ArrowFunctionExpressionBody(arrow)) if *arrow.expression()
+        ),
+        _ => false,
+    }
+}
+
+fn is_bound_identifier(expr: &Expression<'_>, ctx: &TraverseCtx<'_>) -> bool {
+    matches!(expr, Expression::Identifier(ident)
+        if ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_some())
+}
+
+/// `obj.a`, `obj["a"]` or `obj[a]`
+fn create_property_access<'a>(
+    object: Expression<'a>,
+    key: PropertyKey<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let member = match key {
+        PropertyKey::StaticIdentifier(ident) => {
+            ctx.ast.member_expression_static(SPAN, object, ident.unbox(), false)
+        }
+        PropertyKey::PrivateIdentifier(_) => unreachable!(),
+        key => ctx.ast.member_expression_computed(SPAN, object, key.into_expression(), false),
+    };
+    Expression::from(member)
+}
+
+/// Collect names of the bindings or identifiers assigned to by a target.
+fn collect_target_names<'a>(target: &Target<'a>, names: &mut Vec<Atom<'a>>) {
+    match target {
+        Target::Binding(pattern) => pattern.bound_names(&mut |ident| names.push(ident.name)),
+        Target::Assignment(target) => collect_assignment_target_maybe_default_names(target, names),
+    }
+}
+
+fn collect_assignment_target_maybe_default_names<'a>(
+    target: &AssignmentTargetMaybeDefault<'a>,
+    names: &mut Vec<Atom<'a>>,
+) {
+    match target {
+        AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+            collect_assignment_target_names(&target.binding, names);
+        }
+        target => collect_assignment_target_names(target.to_assignment_target(), names),
+    }
+}
+
+fn collect_assignment_target_names<'a>(target: &AssignmentTarget<'a>, names: &mut Vec<Atom<'a>>) {
+    match target {
+        AssignmentTarget::AssignmentTargetIdentifier(ident) => names.push(ident.name),
+        AssignmentTarget::ArrayAssignmentTarget(array) => {
+            for element in array.elements.iter().flatten() {
+                collect_assignment_target_maybe_default_names(element, names);
+            }
+            if let Some(rest) = &array.rest {
+                collect_assignment_target_names(&rest.target, names);
+            }
+        }
+        AssignmentTarget::ObjectAssignmentTarget(object) => {
+            for property in &object.properties {
+                match property {
+                    AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+                        names.push(property.binding.name);
+                    }
+                    AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                        collect_assignment_target_maybe_default_names(&property.binding, names);
+                    }
+                }
+            }
+            if let Some(rest) = &object.rest {
+                collect_assignment_target_names(&rest.target, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Find references to any of the given names.
+struct ReferenceFinder<'n, 'a> {
+    names: &'n [Atom<'a>],
+    found: bool,
+}
+
+impl<'a> Visit<'a> for ReferenceFinder<'_, 'a> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if self.names.contains(&ident.name) {
+            self.found = true;
+        }
+    }
+}
+
+/// Wrap `stmt` in a block statement, unless it's a block statement which doesn't bind
+/// any of `names` already.
+///
+/// Returns the scope of the block statement.
+fn ensure_block_statement<'a>(
+    stmt: &mut Statement<'a>,
+    parent_scope_id: ScopeId,
+    names: &[Atom<'a>],
+    ctx: &mut TraverseCtx<'a>,
+) -> ScopeId {
+    if let Statement::BlockStatement(block) = stmt {
+        let scope_id = block.scope_id();
+        if !names.iter().any(|name| ctx.scoping().scope_has_binding(scope_id, name)) {
+            return scope_id;
+        }
+    }
+
+    let scope_id =
+        ctx.insert_scope_below_statement_from_scope_id(stmt, parent_scope_id, ScopeFlags::empty());
+    let span = stmt.span();
+    let body = if matches!(stmt, Statement::EmptyStatement(_)) {
+        ctx.ast.vec()
+    } else {
+        ctx.ast.vec1(stmt.take_in(ctx.ast.allocator))
+    };
+    *stmt = ctx.ast.statement_block_with_scope_id(span, body, scope_id);
+    scope_id
+}
+
+/// Move bindings of `pattern` from `from` scope to `to` scope, as block scoped variables.
+fn move_bindings(
+    pattern: &BindingPattern<'_>,
+    from: ScopeId,
+    to: ScopeId,
+    ctx: &mut TraverseCtx<'_>,
+) {
+    pattern.bound_names(&mut |ident| {
+        let symbol_id = ident.symbol_id();
+        let scoping = ctx.scoping_mut();
+        scoping.set_symbol_scope_id(symbol_id, to);
+        scoping.symbol_flags_mut(symbol_id).remove(SymbolFlags::CatchVariable);
+        scoping.move_binding(from, to, &ident.name);
+    });
+}
+
+/// `kind pattern = init;`
+fn create_variable_declaration<'a>(
+    kind: VariableDeclarationKind,
+    pattern: BindingPattern<'a>,
+    init: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, Some(init), false);
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        ctx.ast.vec1(declarator),
+        false,
+    ))
+}
+
+fn kind_to_symbol_flags(kind: VariableDeclarationKind) -> SymbolFlags {
+    match kind {
+        VariableDeclarationKind::Var => SymbolFlags::FunctionScopedVariable,
+        VariableDeclarationKind::Let
+        | VariableDeclarationKind::Using
+        | VariableDeclarationKind::AwaitUsing => SymbolFlags::BlockScopedVariable,
+        VariableDeclarationKind::Const => {
+            SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable
+        }
+    }
+}
+
+#[expect(clippy::cast_precision_loss)]
+fn create_number_literal<'a>(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    ctx.ast.expression_numeric_literal(SPAN, value as f64, None, NumberBase::Decimal)
+}
@@ -0,0 +1,612 @@
+//! ES2015: For Of
+//!
+//! This plugin transforms `for...of` loops.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! for (const x of y) {
+//!   foo(x);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _iterator = babelHelpers.createForOfIteratorHelper(y), _step;
+//! try {
+//!   for (_iterator.s(); !(_step = _iterator.n()).done;) {
+//!     const x = _step.value;
+//!     foo(x);
+//!   }
+//! } catch (err) {
+//!   _iterator.e(err);
+//! } finally {
+//!   _iterator.f();
+//! }
+//! ```
+//!
+//! Loops over an array literal are transformed to an index loop, as the array is known to be a
+//! real array, with the default `Array.prototype[Symbol.iterator]`:
+//!
+//! ```js
+//! for (var _i = 0, _arr = [1, 2, 3]; _i < _arr.length; _i++) {
+//!   const x = _arr[_i];
+//!   foo(x);
+//! }
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! Enables the `skipForOfIteratorClosing` assumption.
+//!
+//! ### `assumeArray`
+//!
+//! Enables the `iterableIsArray` assumption.
+//!
+//! ### `allowArrayLike`
+//!
+//! Enables the `arrayLikeIsIterable` assumption.
+//!
+//! ### `skipForOfIteratorClosing` assumption
+//!
+//! The iterator's `return` method is not called when the loop exits early, or throws,
+//! so no `try` statement is needed.
+//!
+//! ```js
+//! for (var _iterator = babelHelpers.createForOfIteratorHelperLoose(y), _step; !(_step = _iterator()).done;) {
+//!   const x = _step.value;
+//!   foo(x);
+//! }
+//! ```
+//!
+//! ### `iterableIsArray` assumption
+//!
+//! All iterated values are assumed to be arrays, so all loops are transformed to index loops.
+//! A constant binding is used as is, rather than being copied to a temp var.
+//!
+//! ```js
+//! for (var _i = 0, _y = y; _i < _y.length; _i++) {
+//!   const x = _y[_i];
+//!   foo(x);
+//! }
+//! ```
+//!
+//! ### `arrayLikeIsIterable` assumption
+//!
+//! Array-like objects which are not iterable (e.g. `NodeList` in old browsers) can be iterated.
+//!
+//! ```js
+//! var _iterator = babelHelpers.createForOfIteratorHelper(y, true), _step;
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-for-of](https://babel.dev/docs/babel-plugin-transform-for-of).
+//!
+//! Loops are transformed on exit, after `let` / `const` declarations in the loop head
+//! have been converted by block scoping plugin. Babel also takes the index loop path for
+//! identifiers with an `Array` type annotation, but types are removed before this plugin runs.
+//!
+//! `for await...of` loops are transformed by ES2018 async generator functions plugin.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-for-of>
+//! * `for...of` specification: <https://tc39.es/ecma262/#sec-for-in-and-for-of-statements>
+
+use serde::Deserialize;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{Atom, GetSpan, SPAN};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, UnaryOperator, UpdateOperator};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{Helper, context::TransformCtx};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ForOfOptions {
+    pub loose: bool,
+
+    pub assume_array: bool,
+
+    pub allow_array_like: bool,
+}
+
+pub struct ForOf<'a, 'ctx> {
+    iterable_is_array: bool,
+    array_like_is_iterable: bool,
+    skip_iterator_closing: bool,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ForOf<'a, 'ctx> {
+    pub fn new(options: ForOfOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            iterable_is_array: options.assume_array || ctx.assumptions.iterable_is_array,
+            array_like_is_iterable: options.allow_array_like
+                || ctx.assumptions.array_like_is_iterable,
+            skip_iterator_closing: options.loose || ctx.assumptions.skip_for_of_iterator_closing,
+            ctx,
+        }
+    }
+}
+
+impl<'a> Traverse<'a> for ForOf<'a, '_> {
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let (for_of, is_labeled) = match stmt {
+            Statement::ForOfStatement(for_of) => (for_of, false),
+            Statement::LabeledStatement(labeled) => match &mut labeled.body {
+                Statement::ForOfStatement(for_of) => (for_of, true),
+                _ => return,
+            },
+            _ => return,
+        };
+        if for_of.r#await {
+            return;
+        }
+
+        if self.iterable_is_array || matches!(for_of.right, Expression::ArrayExpression(_)) {
+            if !is_labeled {
+                *stmt = Self::transform_to_index_loop(for_of, ctx);
+            }
+        } else if self.skip_iterator_closing {
+            if !is_labeled {
+                *stmt = self.transform_to_iterator_loop(for_of, ctx);
+            }
+        } else {
+            // The loop is moved into a `try` block, so label has to be moved with it.
+            // `label: for (x of y) {}` is transformed on exit of the labeled statement.
+            if is_labeled || !matches!(ctx.parent(), Ancestor::LabeledStatementBody(_)) {
+                self.transform_to_iterator_loop_with_closing(stmt, ctx);
+            }
+        }
+    }
+}
+
+impl<'a> ForOf<'a, '_> {
+    /// `for (const x of y) {}` -> `for (var _i = 0, _y = y; _i < _y.length; _i++) { const x = _y[_i]; }`
+    fn transform_to_index_loop(
+        for_of: &mut ForOfStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let hoist_scope_id = ctx.current_hoist_scope_id();
+        let index = ctx.generate_uid("i", hoist_scope_id, SymbolFlags::FunctionScopedVariable);
+
+        // Iterate over a constant binding directly. Otherwise copy it to a temp var,
+        // as reassigning the binding in the loop body must not affect the loop.
+        let right = for_of.right.take_in(ctx.ast.allocator);
+        let (array, array_init) = match right {
+            Expression::Identifier(ident) if Self::is_constant_binding(&ident, for_of, ctx) => {
+                let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+                ctx.delete_reference_for_identifier(&ident);
+                (BoundIdentifier::new(ident.name, symbol_id.unwrap()), None)
+            }
+            right => {
+                let name = if let Expression::Identifier(ident) = &right {
+                    ident.name.as_str()
+                } else {
+                    "arr"
+                };
+                let binding =
+                    ctx.generate_uid(name, hoist_scope_id, SymbolFlags::FunctionScopedVariable);
+                (binding, Some(right))
+            }
+        };
+
+        // `_y[_i]`
+        let value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            array.create_read_expression(ctx),
+            index.create_read_expression(ctx),
+            false,
+        ));
+        let parent_scope_id = ctx.current_scope_id();
+        let (body, scope_id) = Self::create_loop_body(for_of, value, parent_scope_id, ctx);
+
+        // `var _i = 0, _y = y`
+        let mut declarations = ctx.ast.vec_with_capacity(2);
+        declarations.push(Self::create_var_declarator(
+            &index,
+            Some(ctx.ast.expression_numeric_literal(SPAN, 0.0, None, NumberBase::Decimal)),
+            ctx,
+        ));
+        if let Some(array_init) = array_init {
+            declarations.push(Self::create_var_declarator(&array, Some(array_init), ctx));
+        }
+        let init = ctx.ast.for_statement_init_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            false,
+        );
+        // `_i < _y.length`
+        let length = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            array.create_read_expression(ctx),
+            ctx.ast.identifier_name(SPAN, "length"),
+            false,
+        ));
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            index.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            length,
+        );
+        // `_i++`
+        let update = ctx.ast.expression_update(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            index.create_read_write_simple_target(ctx),
+        );
+
+        ctx.ast.statement_for_with_scope_id(
+            for_of.span,
+            Some(init),
+            Some(test),
+            Some(update),
+            body,
+            scope_id,
+        )
+    }
+
+    /// `for (const x of y) {}` ->
+    /// `for (var _iterator = _createForOfIteratorHelperLoose(y), _step; !(_step = _iterator()).done;) { const x = _step.value; }`
+    fn transform_to_iterator_loop(
+        &self,
+        for_of: &mut ForOfStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let (iterator, step) = Self::create_iterator_bindings(ctx);
+        let iterator_init =
+            self.create_iterator_helper_call(Helper::CreateForOfIteratorHelperLoose, for_of, ctx);
+
+        let parent_scope_id = ctx.current_scope_id();
+        let value = Self::create_step_value(&step, ctx);
+        let (body, scope_id) = Self::create_loop_body(for_of, value, parent_scope_id, ctx);
+
+        // `var _iterator = _createForOfIteratorHelperLoose(y), _step`
+        let declarations = ctx.ast.vec_from_array([
+            Self::create_var_declarator(&iterator, Some(iterator_init), ctx),
+            Self::create_var_declarator(&step, None, ctx),
+        ]);
+        let init = ctx.ast.for_statement_init_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            false,
+        );
+        // `_iterator()`
+        let next = ctx.ast.expression_call(
+            SPAN,
+            iterator.create_read_expression(ctx),
+            NONE,
+            ctx.ast.vec(),
+            false,
+        );
+        let test = Self::create_not_done_test(&step, next, ctx);
+
+        ctx.ast.statement_for_with_scope_id(
+            for_of.span,
+            Some(init),
+            Some(test),
+            None,
+            body,
+            scope_id,
+        )
+    }
+
+    /// Transform `for (const x of y) {}` to:
+    ///
+    /// ```js
+    /// var _iterator = _createForOfIteratorHelper(y), _step;
+    /// try {
+    ///   for (_iterator.s(); !(_step = _iterator.n()).done;) {
+    ///     const x = _step.value;
+    ///   }
+    /// } catch (err) {
+    ///   _iterator.e(err);
+    /// } finally {
+    ///   _iterator.f();
+    /// }
+    /// ```
+    ///
+    /// `stmt` is either a `for...of` statement, or a labeled statement with `for...of` as its body.
+    fn transform_to_iterator_loop_with_closing(
+        &self,
+        stmt: &mut Statement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let span = stmt.span();
+        let (for_of, label) = match stmt {
+            Statement::ForOfStatement(for_of) => (for_of, None),
+            Statement::LabeledStatement(labeled) => {
+                let LabeledStatement { label, body, .. } = labeled.as_mut();
+                let Statement::ForOfStatement(for_of) = body else { unreachable!() };
+                (for_of, Some(label.clone()))
+            }
+            _ => unreachable!(),
+        };
+
+        // If statement is not in a statement list (e.g. `if (x) for (y of z) {}`),
+        // wrap the `var` declaration and `try` statement in a block
+        let is_in_statement_list = matches!(
+            ctx.parent(),
+            Ancestor::ProgramBody(_)
+                | Ancestor::FunctionBodyStatements(_)
+                | Ancestor::BlockStatementBody(_)
+                | Ancestor::SwitchCaseConsequent(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::TSModuleBlockBody(_)
+        );
+        let parent_scope_id = if is_in_statement_list {
+            ctx.current_scope_id()
+        } else {
+            ctx.create_child_scope_of_current(ScopeFlags::empty())
+        };
+
+        let (iterator, step) = Self::create_iterator_bindings(ctx);
+        let iterator_init =
+            self.create_iterator_helper_call(Helper::CreateForOfIteratorHelper, for_of, ctx);
+
+        // `for (_iterator.s(); !(_step = _iterator.n()).done;) {}`
+        let try_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let value = Self::create_step_value(&step, ctx);
+        let (body, for_scope_id) = Self::create_loop_body(for_of, value, try_scope_id, ctx);
+        let init =
+            ForStatementInit::from(Self::create_iterator_method_call(&iterator, "s", None, ctx));
+        let next = Self::create_iterator_method_call(&iterator, "n", None, ctx);
+        let test = Self::create_not_done_test(&step, next, ctx);
+        let mut for_stmt = ctx.ast.statement_for_with_scope_id(
+            for_of.span,
+            Some(init),
+            Some(test),
+            None,
+            body,
+            for_scope_id,
+        );
+        if let Some(label) = label {
+            for_stmt = ctx.ast.statement_labeled(span, label, for_stmt);
+        }
+        let block =
+            ctx.ast.block_statement_with_scope_id(SPAN, ctx.ast.vec1(for_stmt), try_scope_id);
+
+        // `catch (err) { _iterator.e(err); }`
+        let catch_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::CatchClause);
+        let catch_block_scope_id = ctx.create_child_scope(catch_scope_id, ScopeFlags::empty());
+        let err = ctx.generate_binding(
+            Atom::from("err"),
+            catch_block_scope_id,
+            SymbolFlags::CatchVariable | SymbolFlags::FunctionScopedVariable,
+        );
+        let call = Self::create_iterator_method_call(
+            &iterator,
+            "e",
+            Some(err.create_read_expression(ctx)),
+            ctx,
+        );
+        let catch_clause = ctx.ast.catch_clause_with_scope_id(
+            SPAN,
+            Some(ctx.ast.catch_parameter(SPAN, err.create_binding_pattern(ctx))),
+            ctx.ast.block_statement_with_scope_id(
+                SPAN,
+                ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call)),
+                catch_block_scope_id,
+            ),
+            catch_scope_id,
+        );
+
+        // `finally { _iterator.f(); }`
+        let finally_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let call = Self::create_iterator_method_call(&iterator, "f", None, ctx);
+        let finalizer = ctx.ast.block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call)),
+            finally_scope_id,
+        );
+
+        let try_stmt = ctx.ast.statement_try(span, block, Some(catch_clause), Some(finalizer));
+
+        // `var _iterator = _createForOfIteratorHelper(y), _step;`
+        let declarations = ctx.ast.vec_from_array([
+            Self::create_var_declarator(&iterator, Some(iterator_init), ctx),
+            Self::create_var_declarator(&step, None, ctx),
+        ]);
+        let var_decl = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            false,
+        ));
+
+        *stmt = if is_in_statement_list {
+            self.ctx.statement_injector.insert_before(&try_stmt, var_decl);
+            try_stmt
+        } else {
+            ctx.ast.statement_block_with_scope_id(
+                span,
+                ctx.ast.vec_from_array([var_decl, try_stmt]),
+                parent_scope_id,
+            )
+        };
+    }
+
+    /// Move left side of `for...of` into the loop body, assigning it `value`.
+    ///
+    /// Returns the new body, and the scope ID for the `for` statement, which is a child of `parent_scope_id`.
+    ///
+    /// If the body is a block, the declaration is inserted at its start, and the scope of
+    /// the `for...of` statement is reused for the `for` statement.
+    /// Otherwise, or if the block declares a binding with the same name as one declared in
+    /// the loop head (`for (const x of y) { let x; }`), the body is wrapped in a new block,
+    /// which takes the scope of the `for...of` statement.
+    fn create_loop_body(
+        for_of: &mut ForOfStatement<'a>,
+        value: Expression<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Statement<'a>, ScopeId) {
+        let for_of_scope_id = for_of.scope_id();
+        let left = match for_of.left.take_in(ctx.ast.allocator) {
+            ForStatementLeft::VariableDeclaration(mut decl) => {
+                decl.declarations[0].init = Some(value);
+                Statement::VariableDeclaration(decl)
+            }
+            left => {
+                let target = left.into_assignment_target();
+                let expr =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+                ctx.ast.statement_expression(SPAN, expr)
+            }
+        };
+
+        let mut body = for_of.body.take_in(ctx.ast.allocator);
+        let head_symbol_ids = ctx.scoping().iter_bindings_in(for_of_scope_id).collect::<Vec<_>>();
+        if let Statement::BlockStatement(block) = &mut body {
+            let block_scope_id = block.scope_id();
+            let has_conflict = head_symbol_ids.iter().any(|&symbol_id| {
+                let name = ctx.scoping().symbol_name(symbol_id);
+                ctx.scoping().scope_has_binding(block_scope_id, name)
+            });
+            if !has_conflict {
+                for symbol_id in head_symbol_ids {
+                    let name = ctx.ast.atom(ctx.scoping().symbol_name(symbol_id));
+                    let scoping = ctx.scoping_mut();
+                    scoping.move_binding(for_of_scope_id, block_scope_id, &name);
+                    scoping.set_symbol_scope_id(symbol_id, block_scope_id);
+                }
+                block.body.insert(0, left);
+                ctx.scoping_mut().change_scope_parent_id(for_of_scope_id, Some(parent_scope_id));
+                return (body, for_of_scope_id);
+            }
+        }
+
+        let for_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        ctx.scoping_mut().change_scope_parent_id(for_of_scope_id, Some(for_scope_id));
+        // `for (x of y);` -> `for (...) { x = _step.value; }`
+        let stmts = if matches!(body, Statement::EmptyStatement(_)) {
+            ctx.ast.vec1(left)
+        } else {
+            ctx.ast.vec_from_array([left, body])
+        };
+        let body = ctx.ast.statement_block_with_scope_id(SPAN, stmts, for_of_scope_id);
+        (body, for_scope_id)
+    }
+
+    /// Returns `true` if `ident` refers to a binding which is never reassigned,
+    /// and is not shadowed in the loop body.
+    fn is_constant_binding(
+        ident: &IdentifierReference<'a>,
+        for_of: &ForOfStatement<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        let scoping = ctx.scoping();
+        let Some(symbol_id) = scoping.get_reference(ident.reference_id()).symbol_id() else {
+            return false;
+        };
+        if scoping.symbol_is_mutated(symbol_id) {
+            return false;
+        }
+        match &for_of.body {
+            Statement::BlockStatement(block) => {
+                !scoping.scope_has_binding(block.scope_id(), &ident.name)
+            }
+            _ => true,
+        }
+    }
+
+    /// `var _iterator`, `var _step`
+    fn create_iterator_bindings(
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (BoundIdentifier<'a>, BoundIdentifier<'a>) {
+        let hoist_scope_id = ctx.current_hoist_scope_id();
+        let iterator =
+            ctx.generate_uid("iterator", hoist_scope_id, SymbolFlags::FunctionScopedVariable);
+        let step = ctx.generate_uid("step", hoist_scope_id, SymbolFlags::FunctionScopedVariable);
+        (iterator, step)
+    }
+
+    /// `_createForOfIteratorHelper(y)` or `_createForOfIteratorHelper(y, true)`
+    fn create_iterator_helper_call(
+        &self,
+        helper: Helper,
+        for_of: &mut ForOfStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let right = for_of.right.take_in(ctx.ast.allocator);
+        let mut arguments = ctx.ast.vec1(Argument::from(right));
+        if self.array_like_is_iterable {
+            arguments.push(Argument::from(ctx.ast.expression_boolean_literal(SPAN, true)));
+        }
+        self.ctx.helper_call_expr(helper, SPAN, arguments, ctx)
+    }
+
+    /// `_iterator.s()`, `_iterator.e(err)`
+    fn create_iterator_method_call(
+        iterator: &BoundIdentifier<'a>,
+        method: &'static str,
+        argument: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            iterator.create_read_expression(ctx),
+            ctx.ast.identifier_name(SPAN, method),
+            false,
+        ));
+        let arguments = ctx.ast.vec_from_iter(argument.map(Argument::from));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// `!(_step = next).done`
+    fn create_not_done_test(
+        step: &BoundIdentifier<'a>,
+        next: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            step.create_write_target(ctx),
+            next,
+        );
+        let done = ctx.ast.member_expression_static(
+            SPAN,
+            ctx.ast.expression_parenthesized(SPAN, assignment),
+            ctx.ast.identifier_name(SPAN, "done"),
+            false,
+        );
+        ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, Expression::from(done))
+    }
+
+    /// `_step.value`
+    fn create_step_value(step: &BoundIdentifier<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            step.create_read_expression(ctx),
+            ctx.ast.identifier_name(SPAN, "value"),
+            false,
+        ))
+    }
+
+    fn create_var_declarator(
+        binding: &BoundIdentifier<'a>,
+        init: Option<Expression<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> VariableDeclarator<'a> {
+        ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            binding.create_binding_pattern(ctx),
+            init,
+            false,
+        )
+    }
+}
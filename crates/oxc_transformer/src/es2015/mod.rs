@@ -1,10 +1,34 @@
-use oxc_traverse::Traverse;
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::ast::*;
+use oxc_traverse::{Traverse, TraverseCtx};
 
 mod arrow_functions;
+mod block_scoping;
+mod classes;
+mod computed_properties;
+mod destructuring;
+mod for_of;
+mod object_super;
 mod options;
+mod parameters;
+mod regenerator;
+mod shorthand_properties;
+mod spread;
+mod template_literals;
 
 pub use arrow_functions::{ArrowFunctions, ArrowFunctionsOptions};
+pub use block_scoping::{BlockScoping, BlockScopingOptions};
+pub use classes::{Classes, ClassesOptions};
+pub use computed_properties::{ComputedProperties, ComputedPropertiesOptions};
+pub use destructuring::{Destructuring, DestructuringOptions};
+pub use for_of::{ForOf, ForOfOptions};
+pub use object_super::ObjectSuper;
 pub use options::ES2015Options;
+pub use parameters::{Parameters, ParametersOptions};
+pub use regenerator::{Regenerator, RegeneratorOptions};
+pub use shorthand_properties::ShorthandProperties;
+pub use spread::{Spread, SpreadOptions};
+pub use template_literals::{TemplateLiterals, TemplateLiteralsOptions};
 
 use crate::context::TransformCtx;
 
@@ -15,15 +39,252 @@ pub struct ES2015<'a, 'ctx> {
     // Plugins
     #[expect(unused)]
     arrow_functions: ArrowFunctions<'a, 'ctx>,
+    block_scoping: Option<BlockScoping<'a, 'ctx>>,
+    classes: Option<Classes<'a, 'ctx>>,
+    computed_properties: Option<ComputedProperties<'a, 'ctx>>,
+    destructuring: Option<Destructuring<'a, 'ctx>>,
+    for_of: Option<ForOf<'a, 'ctx>>,
+    object_super: Option<ObjectSuper<'a, 'ctx>>,
+    parameters: Option<Parameters<'a, 'ctx>>,
+    regenerator: Option<Regenerator<'a, 'ctx>>,
+    shorthand_properties: Option<ShorthandProperties>,
+    spread: Option<Spread<'a, 'ctx>>,
+    template_literals: Option<TemplateLiterals<'a, 'ctx>>,
 }
 
 impl<'a, 'ctx> ES2015<'a, 'ctx> {
     pub fn new(options: ES2015Options, ctx: &'ctx TransformCtx<'a>) -> Self {
         Self {
             arrow_functions: ArrowFunctions::new(options.arrow_function.unwrap_or_default(), ctx),
+            block_scoping: options.block_scoping.map(|block_scoping| {
+                BlockScoping::new(block_scoping, options.classes.is_some(), ctx)
+            }),
+            classes: options.classes.map(|classes| {
+                Classes::new(classes, options.spread, options.arrow_function.is_some(), ctx)
+            }),
+            computed_properties: options
+                .computed_properties
+                .map(|options| ComputedProperties::new(options, ctx)),
+            destructuring: options.destructuring.map(|options| Destructuring::new(options, ctx)),
+            for_of: options.for_of.map(|options| ForOf::new(options, ctx)),
+            object_super: options.object_super.then(|| ObjectSuper::new(ctx)),
+            parameters: options.parameters.map(|parameters| {
+                Parameters::new(parameters, options.arrow_function.is_some(), ctx)
+            }),
+            regenerator: options.regenerator.map(|options| Regenerator::new(options, ctx)),
+            shorthand_properties: options.shorthand_properties.then_some(ShorthandProperties),
+            spread: options
+                .spread
+                .map(|spread| Spread::new(spread, options.classes.is_some(), ctx)),
+            template_literals: options
+                .template_literals
+                .map(|options| TemplateLiterals::new(options, ctx)),
             options,
         }
     }
 }
 
-impl<'a> Traverse<'a> for ES2015<'a, '_> {}
+impl<'a> Traverse<'a> for ES2015<'a, '_> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.exit_program(program, ctx);
+        }
+        if let Some(template_literals) = &mut self.template_literals {
+            template_literals.exit_program(program, ctx);
+        }
+    }
+
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_statements(stmts, ctx);
+        }
+    }
+
+    fn exit_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.exit_statements(stmts, ctx);
+        }
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_statement(stmt, ctx);
+        }
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(for_of) = &mut self.for_of {
+            for_of.exit_statement(stmt, ctx);
+        }
+        if let Some(classes) = &mut self.classes {
+            classes.exit_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_variable_declaration(decl, ctx);
+        }
+    }
+
+    fn exit_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.exit_variable_declaration(decl, ctx);
+        }
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_binding_identifier(ident, ctx);
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_identifier_reference(ident, ctx);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_expression(expr, ctx);
+        }
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_expression(expr, ctx);
+        }
+        if let Some(object_super) = &mut self.object_super {
+            object_super.enter_expression(expr, ctx);
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.exit_expression(expr, ctx);
+        }
+        if let Some(template_literals) = &mut self.template_literals {
+            template_literals.exit_expression(expr, ctx);
+        }
+        if let Some(computed_properties) = &mut self.computed_properties {
+            computed_properties.exit_expression(expr, ctx);
+        }
+        if let Some(spread) = &mut self.spread {
+            spread.exit_expression(expr, ctx);
+        }
+        if let Some(classes) = &mut self.classes {
+            classes.exit_expression(expr, ctx);
+        }
+        if let Some(regenerator) = &mut self.regenerator {
+            regenerator.exit_expression(expr, ctx);
+        }
+    }
+
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(shorthand_properties) = &mut self.shorthand_properties {
+            shorthand_properties.enter_object_property(prop, ctx);
+        }
+    }
+
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(parameters) = &mut self.parameters {
+            parameters.enter_function(func, ctx);
+        }
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_function(func, ctx);
+        }
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(regenerator) = &mut self.regenerator {
+            regenerator.exit_function(func, ctx);
+        }
+    }
+
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(parameters) = &mut self.parameters {
+            parameters.enter_arrow_function_expression(arrow, ctx);
+        }
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_arrow_function_expression(arrow, ctx);
+        }
+        if let Some(classes) = &mut self.classes {
+            classes.enter_arrow_function_expression(arrow, ctx);
+        }
+    }
+
+    fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_catch_clause(clause, ctx);
+        }
+    }
+
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_block_statement(block, ctx);
+        }
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_switch_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_for_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_for_in_statement(stmt, ctx);
+        }
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_for_in_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_for_of_statement(stmt, ctx);
+        }
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_for_of_statement(stmt, ctx);
+        }
+    }
+}
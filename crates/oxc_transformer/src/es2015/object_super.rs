@@ -0,0 +1,443 @@
+//! ES2015: Object Super
+//!
+//! This plugin transforms `super` in object methods.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const obj = {
+//!   foo() {
+//!     return super.foo();
+//!   },
+//!   set bar(v) {
+//!     super.bar = v;
+//!   },
+//! };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _obj;
+//! const obj = _obj = {
+//!   foo() {
+//!     return babelHelpers.get(babelHelpers.getPrototypeOf(_obj), "foo", this).call(this);
+//!   },
+//!   set bar(v) {
+//!     babelHelpers.set(babelHelpers.getPrototypeOf(_obj), "bar", v, this, false);
+//!   },
+//! };
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-object-super](https://babel.dev/docs/babel-plugin-transform-object-super).
+//!
+//! Objects are transformed on entry, before any arrow functions inside their methods are converted
+//! to function expressions, so `this` inserted inside an arrow function is still the method's `this`.
+//! Once `super` is removed, methods can be converted to functions by the shorthand properties transform,
+//! and moved out of the object literal by the computed properties transform.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-object-super>
+//! * `super` keyword specification: <https://tc39.es/ecma262/#sec-super-keyword>
+
+use oxc_allocator::{CloneIn, TakeIn};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::{BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{Helper, context::TransformCtx, utils::ast_builder::create_member_callee};
+
+use super::shorthand_properties::property_value_uses_super;
+
+pub struct ObjectSuper<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ObjectSuper<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a> for ObjectSuper<'a, '_> {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if matches!(expr, Expression::ObjectExpression(_)) {
+            self.transform_object_expression(expr, ctx);
+        }
+    }
+}
+
+impl<'a> ObjectSuper<'a, '_> {
+    /// `{ foo() { super.foo } }` -> `_obj = { foo() { babelHelpers.get(babelHelpers.getPrototypeOf(_obj), "foo", this) } }`
+    fn transform_object_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ObjectExpression(object) = expr else { unreachable!() };
+
+        let mut object_binding = None;
+        for prop in &mut object.properties {
+            let ObjectPropertyKind::ObjectProperty(prop) = prop else { continue };
+            if !(prop.method || prop.kind != PropertyKind::Init)
+                || !property_value_uses_super(&prop.value)
+            {
+                continue;
+            }
+            let Expression::FunctionExpression(func) = &mut prop.value else { unreachable!() };
+            let object_binding = object_binding
+                .get_or_insert_with(|| self.ctx.var_declarations.create_uid_var("obj", ctx));
+            SuperReplacer::new(self, object_binding, func, ctx).visit_function_contents(func);
+        }
+
+        if let Some(object_binding) = object_binding {
+            let object = expr.take_in(ctx.ast.allocator);
+            *expr = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                object_binding.create_write_target(ctx),
+                object,
+            );
+        }
+    }
+}
+
+/// Visitor to replace `super` in an object method with calls to `get` and `set` helpers.
+///
+/// Nested functions and class bodies are not entered, as `super` in them refers to another object.
+/// Arrow functions are entered, as they inherit `super` from the method.
+struct SuperReplacer<'a, 'ctx, 'v> {
+    object_super: &'v ObjectSuper<'a, 'ctx>,
+    object_binding: &'v BoundIdentifier<'a>,
+    is_strict_mode: bool,
+    /// Scope of method being visited, where temp vars are declared
+    function_scope_id: ScopeId,
+    /// Temp vars to declare at top of method
+    temp_bindings: Vec<BoundIdentifier<'a>>,
+    ctx: &'v mut TraverseCtx<'a>,
+}
+
+impl<'a, 'ctx, 'v> SuperReplacer<'a, 'ctx, 'v> {
+    fn new(
+        object_super: &'v ObjectSuper<'a, 'ctx>,
+        object_binding: &'v BoundIdentifier<'a>,
+        func: &Function<'a>,
+        ctx: &'v mut TraverseCtx<'a>,
+    ) -> Self {
+        let function_scope_id = func.scope_id();
+        let is_strict_mode = ctx.scoping().scope_flags(function_scope_id).is_strict_mode();
+        Self {
+            object_super,
+            object_binding,
+            is_strict_mode,
+            function_scope_id,
+            temp_bindings: vec![],
+            ctx,
+        }
+    }
+
+    /// Visit params and body of method, without visiting the function itself.
+    ///
+    /// Any temp vars created are declared at top of function body.
+    fn visit_function_contents(mut self, func: &mut Function<'a>) {
+        self.visit_formal_parameters(&mut func.params);
+        let body = func.body.as_mut().unwrap();
+        self.visit_function_body(body);
+
+        if !self.temp_bindings.is_empty() {
+            // `var _super$prop, _super$prop2;`
+            let kind = VariableDeclarationKind::Var;
+            let declarators =
+                self.ctx.ast.vec_from_iter(self.temp_bindings.drain(..).map(|binding| {
+                    self.ctx.ast.variable_declarator(
+                        SPAN,
+                        kind,
+                        binding.create_binding_pattern(self.ctx),
+                        None,
+                        false,
+                    )
+                }));
+            let decl = self.ctx.ast.declaration_variable(SPAN, kind, declarators, false);
+            body.statements.insert(0, Statement::from(decl));
+        }
+    }
+
+    /// Create temp var in method.
+    fn create_temp_var(&mut self, name: &str) -> BoundIdentifier<'a> {
+        let binding = self.ctx.generate_uid(
+            name,
+            self.function_scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        self.temp_bindings.push(binding.clone());
+        binding
+    }
+
+    /// Duplicate property key, so it can be used for both get and set.
+    ///
+    /// Literals are cloned, other expressions are stored in a temp var.
+    /// Returns 2 `Expression`s. The first must be evaluated first.
+    fn duplicate_property(&mut self, property: Expression<'a>) -> (Expression<'a>, Expression<'a>) {
+        if matches!(property, Expression::StringLiteral(_) | Expression::NumericLiteral(_)) {
+            let clone = property.clone_in(self.ctx.ast.allocator);
+            return (property, clone);
+        }
+        let binding = self.ctx.generate_uid_based_on_node(
+            &property,
+            self.function_scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        self.temp_bindings.push(binding.clone());
+        let assignment = self.ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(self.ctx),
+            property,
+        );
+        (assignment, binding.create_read_expression(self.ctx))
+    }
+
+    /// `super.prop` -> `babelHelpers.get(babelHelpers.getPrototypeOf(_obj), "prop", this)`
+    fn transform_super_member(&mut self, expr: &mut Expression<'a>) {
+        let (span, property) = match expr.take_in(self.ctx.ast.allocator) {
+            Expression::StaticMemberExpression(member) => {
+                (member.span, self.create_static_property(&member.property))
+            }
+            Expression::ComputedMemberExpression(member) => {
+                let member = member.unbox();
+                (member.span, member.expression)
+            }
+            _ => unreachable!(),
+        };
+        *expr = self.create_super_prop_get(span, property);
+    }
+
+    /// `super.prop(a, b)` ->
+    /// `babelHelpers.get(babelHelpers.getPrototypeOf(_obj), "prop", this).call(this, a, b)`
+    fn transform_super_member_call(&mut self, call: &mut CallExpression<'a>) {
+        let property = match call.callee.take_in(self.ctx.ast.allocator) {
+            Expression::StaticMemberExpression(member) => {
+                self.create_static_property(&member.property)
+            }
+            Expression::ComputedMemberExpression(member) => member.unbox().expression,
+            _ => unreachable!(),
+        };
+        let get_call = self.create_super_prop_get(SPAN, property);
+        call.callee = create_member_callee(get_call, "call", self.ctx);
+        call.arguments.insert(0, Argument::from(self.ctx.ast.expression_this(SPAN)));
+    }
+
+    /// * `super.prop = value` -> `babelHelpers.set(babelHelpers.getPrototypeOf(_obj), "prop", value, this, false)`
+    /// * `super.prop += value` ->
+    ///   `babelHelpers.set(babelHelpers.getPrototypeOf(_obj), "prop", babelHelpers.get(babelHelpers.getPrototypeOf(_obj), "prop", this) + value, this, false)`
+    /// * `super.prop &&= value` ->
+    ///   `babelHelpers.get(babelHelpers.getPrototypeOf(_obj), "prop", this) && babelHelpers.set(babelHelpers.getPrototypeOf(_obj), "prop", value, this, false)`
+    fn transform_super_assignment(&mut self, expr: &mut Expression<'a>) {
+        let Expression::AssignmentExpression(assign) = expr.take_in(self.ctx.ast.allocator) else {
+            unreachable!()
+        };
+        let AssignmentExpression { span, operator, left, right: value } = assign.unbox();
+        let property = match left {
+            AssignmentTarget::StaticMemberExpression(member) => {
+                self.create_static_property(&member.property)
+            }
+            AssignmentTarget::ComputedMemberExpression(member) => member.unbox().expression,
+            _ => unreachable!(),
+        };
+
+        *expr = if operator == AssignmentOperator::Assign {
+            self.create_super_prop_set(span, property, value)
+        } else {
+            let (property1, property2) = self.duplicate_property(property);
+            if let Some(operator) = operator.to_binary_operator() {
+                let get_call = self.create_super_prop_get(SPAN, property2);
+                let value = self.ctx.ast.expression_binary(SPAN, get_call, operator, value);
+                self.create_super_prop_set(span, property1, value)
+            } else if let Some(operator) = operator.to_logical_operator() {
+                let get_call = self.create_super_prop_get(SPAN, property1);
+                let set_call = self.create_super_prop_set(SPAN, property2, value);
+                self.ctx.ast.expression_logical(span, get_call, operator, set_call)
+            } else {
+                unreachable!()
+            }
+        };
+    }
+
+    /// * `++super.prop` ->
+    ///   `babelHelpers.set(babelHelpers.getPrototypeOf(_obj), "prop", (_super$prop = babelHelpers.get(babelHelpers.getPrototypeOf(_obj), "prop", this), ++_super$prop), this, false)`
+    /// * `super.prop++` ->
+    ///   `(babelHelpers.set(babelHelpers.getPrototypeOf(_obj), "prop", (_super$prop = babelHelpers.get(babelHelpers.getPrototypeOf(_obj), "prop", this), _super$prop2 = _super$prop++, _super$prop), this, false), _super$prop2)`
+    fn transform_super_update(&mut self, expr: &mut Expression<'a>) {
+        let Expression::UpdateExpression(mut update) = expr.take_in(self.ctx.ast.allocator) else {
+            unreachable!()
+        };
+        let property = match update.argument.take_in(self.ctx.ast.allocator) {
+            SimpleAssignmentTarget::StaticMemberExpression(member) => {
+                self.create_static_property(&member.property)
+            }
+            SimpleAssignmentTarget::ComputedMemberExpression(member) => member.unbox().expression,
+            _ => unreachable!(),
+        };
+
+        let (property1, property2) = self.duplicate_property(property);
+        let get_call = self.create_super_prop_get(SPAN, property2);
+
+        let temp_binding = self.create_temp_var("super$prop");
+        let assignment = self.ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            temp_binding.create_write_target(self.ctx),
+            get_call,
+        );
+
+        let span = update.span;
+        update.span = SPAN;
+        update.argument = temp_binding.create_read_write_simple_target(self.ctx);
+        let prefix = update.prefix;
+        let update = Expression::UpdateExpression(update);
+
+        *expr = if prefix {
+            let value = self
+                .ctx
+                .ast
+                .expression_sequence(SPAN, self.ctx.ast.vec_from_array([assignment, update]));
+            self.create_super_prop_set(span, property1, value)
+        } else {
+            let temp_binding2 = self.create_temp_var("super$prop");
+            let assignment2 = self.ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                temp_binding2.create_write_target(self.ctx),
+                update,
+            );
+            let value = self.ctx.ast.expression_sequence(
+                SPAN,
+                self.ctx.ast.vec_from_array([
+                    assignment,
+                    assignment2,
+                    temp_binding.create_read_expression(self.ctx),
+                ]),
+            );
+            let set_call = self.create_super_prop_set(SPAN, property1, value);
+            self.ctx.ast.expression_sequence(
+                span,
+                self.ctx
+                    .ast
+                    .vec_from_array([set_call, temp_binding2.create_read_expression(self.ctx)]),
+            )
+        };
+    }
+
+    fn create_static_property(&self, property: &IdentifierName<'a>) -> Expression<'a> {
+        self.ctx.ast.expression_string_literal(property.span, property.name, None)
+    }
+
+    /// `babelHelpers.getPrototypeOf(_obj)`
+    fn create_prototype(&mut self) -> Argument<'a> {
+        let arguments =
+            self.ctx.ast.vec1(Argument::from(self.object_binding.create_read_expression(self.ctx)));
+        Argument::from(self.object_super.ctx.helper_call_expr(
+            Helper::GetPrototypeOf,
+            SPAN,
+            arguments,
+            self.ctx,
+        ))
+    }
+
+    /// `babelHelpers.get(babelHelpers.getPrototypeOf(_obj), prop, this)`
+    fn create_super_prop_get(&mut self, span: Span, property: Expression<'a>) -> Expression<'a> {
+        let arguments = self.ctx.ast.vec_from_array([
+            self.create_prototype(),
+            Argument::from(property),
+            Argument::from(self.ctx.ast.expression_this(SPAN)),
+        ]);
+        self.object_super.ctx.helper_call_expr(Helper::Get, span, arguments, self.ctx)
+    }
+
+    /// `babelHelpers.set(babelHelpers.getPrototypeOf(_obj), prop, value, this, isStrict)`
+    fn create_super_prop_set(
+        &mut self,
+        span: Span,
+        property: Expression<'a>,
+        value: Expression<'a>,
+    ) -> Expression<'a> {
+        let arguments = self.ctx.ast.vec_from_array([
+            self.create_prototype(),
+            Argument::from(property),
+            Argument::from(value),
+            Argument::from(self.ctx.ast.expression_this(SPAN)),
+            Argument::from(self.ctx.ast.expression_boolean_literal(SPAN, self.is_strict_mode)),
+        ]);
+        self.object_super.ctx.helper_call_expr(Helper::Set, span, arguments, self.ctx)
+    }
+}
+
+impl<'a> VisitMut<'a> for SuperReplacer<'a, '_, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        // `super.prop()` - visit callee's computed key and arguments only,
+        // so that callee is not transformed as a property access
+        if let Expression::CallExpression(call) = expr {
+            if !call.optional
+                && call
+                    .callee
+                    .as_member_expression()
+                    .is_some_and(|member| member.object().is_super())
+            {
+                if let Expression::ComputedMemberExpression(member) = &mut call.callee {
+                    self.visit_expression(&mut member.expression);
+                }
+                self.visit_arguments(&mut call.arguments);
+                self.transform_super_member_call(call);
+                return;
+            }
+        }
+
+        walk_mut::walk_expression(self, expr);
+
+        match expr {
+            Expression::StaticMemberExpression(member) if member.object.is_super() => {
+                self.transform_super_member(expr);
+            }
+            Expression::ComputedMemberExpression(member) if member.object.is_super() => {
+                self.transform_super_member(expr);
+            }
+            Expression::AssignmentExpression(assign)
+                if assign
+                    .left
+                    .as_member_expression()
+                    .is_some_and(|member| member.object().is_super()) =>
+            {
+                self.transform_super_assignment(expr);
+            }
+            Expression::UpdateExpression(update)
+                if update
+                    .argument
+                    .as_member_expression()
+                    .is_some_and(|member| member.object().is_super()) =>
+            {
+                self.transform_super_update(expr);
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        // `extends` clause and computed keys are outside class's `super` scope
+        if let Some(super_class) = &mut class.super_class {
+            self.visit_expression(super_class);
+        }
+        for element in &mut class.body.body {
+            let key = match element {
+                ClassElement::MethodDefinition(method) if method.computed => &mut method.key,
+                ClassElement::PropertyDefinition(prop) if prop.computed => &mut prop.key,
+                ClassElement::AccessorProperty(prop) if prop.computed => &mut prop.key,
+                _ => continue,
+            };
+            self.visit_property_key(key);
+        }
+    }
+}
@@ -0,0 +1,521 @@
+//! ES2015: Parameters
+//!
+//! This plugin transforms default parameters, rest parameters and destructuring parameters.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function foo(a, b = 1, { c }, ...rest) {}
+//! ```
+//!
+//! Output:
+//! ```js
+//! function foo(a) {
+//!   var b = arguments.length > 1 && arguments[1] !== undefined ? arguments[1] : 1;
+//!   var { c } = arguments.length > 2 ? arguments[2] : undefined;
+//!   for (var _len = arguments.length, rest = new Array(_len > 3 ? _len - 3 : 0), _key = 3; _key < _len; _key++) {
+//!     rest[_key - 3] = arguments[_key];
+//!   }
+//! }
+//! ```
+//!
+//! Destructuring patterns moved into the function body are transformed by the destructuring
+//! plugin, if it's enabled.
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! Enables the `ignoreFunctionLength` assumption.
+//!
+//! ### `ignoreFunctionLength` assumption
+//!
+//! Parameters with default values are kept in the parameter list, which changes `length`
+//! of the function.
+//!
+//! ```js
+//! function foo(a, b) {
+//!   if (b === void 0) b = 1;
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-parameters](https://babel.dev/docs/babel-plugin-transform-parameters).
+//!
+//! Arrow functions and setters always use the `ignoreFunctionLength` form for default values.
+//! Arrow functions don't have their own `arguments`, and setters must keep exactly one parameter.
+//!
+//! Rest parameters of arrow functions are converted to a loop over `arguments`, which is only
+//! correct once the arrow function has been converted to a function expression.
+//! So the arrow functions plugin must be enabled too.
+//!
+//! TODO: Babel wraps the function body in an IIFE when a default value contains a closure
+//! which references a binding shadowed by the body, or when the function is a generator,
+//! so that default values are evaluated in the right scope and at the right time.
+//! We don't do that yet.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-parameters>
+//! * Function definitions specification: <https://tc39.es/ecma262/#sec-function-definitions>
+
+use serde::Deserialize;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UpdateOperator};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{context::TransformCtx, utils::ast_builder::create_member_callee};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ParametersOptions {
+    pub loose: bool,
+}
+
+pub struct Parameters<'a, 'ctx> {
+    /// If `true`, keep parameters with default values in the parameter list.
+    ignore_function_length: bool,
+    /// If `true`, arrow functions are converted to function expressions by arrow functions plugin.
+    arrow_functions_enabled: bool,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Parameters<'a, 'ctx> {
+    pub fn new(
+        options: ParametersOptions,
+        arrow_functions_enabled: bool,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        Self {
+            ignore_function_length: options.loose || ctx.assumptions.ignore_function_length,
+            arrow_functions_enabled,
+            ctx,
+        }
+    }
+}
+
+impl<'a> Traverse<'a> for Parameters<'a, '_> {
+    // `function foo(a = 1, ...b) {}`
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = func.scope_id();
+        let Some(body) = func.body.as_mut() else { return };
+        if is_simple_parameter_list(&func.params) {
+            return;
+        }
+        let options = ConvertParamsOptions {
+            from: 0,
+            loose: self.ignore_function_length || is_setter(ctx),
+            lower_rest: true,
+        };
+        Self::convert_params(&mut func.params, body, scope_id, options, ctx);
+    }
+
+    // `(a = 1, ...b) => {}`
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if is_simple_parameter_list(&arrow.params) {
+            return;
+        }
+        let lower_rest = self.arrow_functions_enabled;
+        if !lower_rest {
+            if let Some(rest) = &arrow.params.rest {
+                self.ctx.error(
+                    OxcDiagnostic::error(
+                        "Rest parameters of arrow functions can only be transformed together with arrow functions.\nPlease enable the `transform-arrow-functions` plugin.",
+                    )
+                    .with_label(rest.span),
+                );
+            }
+        }
+        ensure_arrow_function_block_body(arrow, ctx);
+        let scope_id = arrow.scope_id();
+        let options = ConvertParamsOptions { from: 0, loose: true, lower_rest };
+        Self::convert_params(&mut arrow.params, &mut arrow.body, scope_id, options, ctx);
+    }
+}
+
+/// Options for [`Parameters::convert_params`].
+#[derive(Debug, Clone, Copy)]
+pub struct ConvertParamsOptions {
+    /// Index of the first parameter to move into the function body.
+    pub from: usize,
+    /// If `true`, use the `ignoreFunctionLength` form for default values.
+    pub loose: bool,
+    /// If `true`, convert the rest parameter to a loop over `arguments`.
+    /// Otherwise only a destructuring rest parameter is replaced with a temp var.
+    pub lower_rest: bool,
+}
+
+impl<'a> Parameters<'a, '_> {
+    /// Move parameters into the function body, starting from `options.from`.
+    ///
+    /// Also used by destructuring plugin, to move destructuring parameters
+    /// and all the parameters after them.
+    pub fn convert_params(
+        params: &mut FormalParameters<'a>,
+        body: &mut FunctionBody<'a>,
+        scope_id: ScopeId,
+        options: ConvertParamsOptions,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut stmts = vec![];
+        let mut first_optional_index = None;
+
+        for (index, param) in params.items.iter_mut().enumerate().skip(options.from) {
+            let pattern = &mut param.pattern;
+            match &mut pattern.kind {
+                BindingPatternKind::AssignmentPattern(assign) if options.loose => {
+                    let default = assign.right.take_in(ctx.ast.allocator);
+                    if let BindingPatternKind::BindingIdentifier(ident) = &assign.left.kind {
+                        // `a = 1` -> `a` + `if (a === void 0) a = 1;`
+                        let binding = BoundIdentifier::from_binding_ident(ident);
+                        let test = create_void_0_check(binding.create_read_expression(ctx), ctx);
+                        let assignment = ctx.ast.expression_assignment(
+                            SPAN,
+                            AssignmentOperator::Assign,
+                            binding.create_write_target(ctx),
+                            default,
+                        );
+                        let consequent = ctx.ast.statement_expression(SPAN, assignment);
+                        stmts.push(ctx.ast.statement_if(SPAN, test, consequent, None));
+                        *pattern = assign.left.take_in(ctx.ast.allocator);
+                    } else {
+                        // `{ a } = {}` -> `_ref` + `var { a } = _ref === void 0 ? {} : _ref;`
+                        let binding = create_ref_param(scope_id, ctx);
+                        let test = create_void_0_check(binding.create_read_expression(ctx), ctx);
+                        let init = ctx.ast.expression_conditional(
+                            SPAN,
+                            test,
+                            default,
+                            binding.create_read_expression(ctx),
+                        );
+                        let left = assign.left.take_in(ctx.ast.allocator);
+                        stmts.push(create_var_statement(left, init, ctx));
+                        *pattern = binding.create_binding_pattern(ctx);
+                    }
+                }
+                BindingPatternKind::AssignmentPattern(assign) => {
+                    // `a = 1` -> `var a = arguments.length > 0 && arguments[0] !== undefined ? arguments[0] : 1;`
+                    first_optional_index.get_or_insert(index);
+                    let default = assign.right.take_in(ctx.ast.allocator);
+                    let left = assign.left.take_in(ctx.ast.allocator);
+                    let init = create_default_param_init(index, default, ctx);
+                    stmts.push(create_var_statement(left, init, ctx));
+                }
+                _ if first_optional_index.is_some() => {
+                    // Parameters after the first one with a default value need to be removed,
+                    // as they are not counted in `length` of the function.
+                    // `a` -> `var a = arguments.length > 1 ? arguments[1] : undefined;`
+                    let init = create_arguments_access(index, ctx);
+                    let pattern = pattern.take_in(ctx.ast.allocator);
+                    stmts.push(create_var_statement(pattern, init, ctx));
+                }
+                BindingPatternKind::ObjectPattern(_) | BindingPatternKind::ArrayPattern(_) => {
+                    // `{ a }` -> `_ref` + `var { a } = _ref;`
+                    let binding = create_ref_param(scope_id, ctx);
+                    let pattern = std::mem::replace(pattern, binding.create_binding_pattern(ctx));
+                    let init = binding.create_read_expression(ctx);
+                    stmts.push(create_var_statement(pattern, init, ctx));
+                }
+                BindingPatternKind::BindingIdentifier(_) => {}
+            }
+        }
+
+        let rest_start = params.items.len();
+        if let Some(index) = first_optional_index {
+            params.items.truncate(index);
+        }
+
+        if options.lower_rest {
+            if let Some(rest) = params.rest.take() {
+                let rest = rest.unbox().argument;
+                Self::convert_rest(rest, rest_start, scope_id, &mut stmts, ctx);
+            }
+        } else if let Some(rest) = &mut params.rest {
+            if rest.argument.kind.is_destructuring_pattern() {
+                // `...[a, b]` -> `..._ref` + `var [a, b] = _ref;`
+                let binding = create_ref_param(scope_id, ctx);
+                let pattern =
+                    std::mem::replace(&mut rest.argument, binding.create_binding_pattern(ctx));
+                let init = binding.create_read_expression(ctx);
+                stmts.push(create_var_statement(pattern, init, ctx));
+            }
+        }
+
+        body.statements.splice(0..0, stmts);
+    }
+
+    /// Convert rest parameter to a loop which collects remaining arguments.
+    ///
+    /// `...rest` ->
+    /// ```js
+    /// for (var _len = arguments.length, rest = new Array(_len > 1 ? _len - 1 : 0), _key = 1; _key < _len; _key++) {
+    ///   rest[_key - 1] = arguments[_key];
+    /// }
+    /// ```
+    fn convert_rest(
+        rest: BindingPattern<'a>,
+        start: usize,
+        scope_id: ScopeId,
+        stmts: &mut Vec<Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `...[a, b]` -> collect into `_ref`, then `var [a, b] = _ref;`
+        let rest_binding = match &rest.kind {
+            BindingPatternKind::BindingIdentifier(ident) => {
+                Some(BoundIdentifier::from_binding_ident(ident))
+            }
+            _ => None,
+        };
+        let (array_binding, array_pattern, pattern) = if let Some(binding) = rest_binding {
+            (binding, rest, None)
+        } else {
+            let binding = create_ref_param(scope_id, ctx);
+            let array_pattern = binding.create_binding_pattern(ctx);
+            (binding, array_pattern, Some(rest))
+        };
+
+        let flags = SymbolFlags::FunctionScopedVariable;
+        let len_binding = ctx.generate_uid("len", scope_id, flags);
+        let key_binding = ctx.generate_uid("key", scope_id, flags);
+
+        let start_literal =
+            |ctx: &TraverseCtx<'a>| -> Expression<'a> { create_number_literal(start, ctx) };
+
+        // `_len > 1 ? _len - 1 : 0`, or `_len` if no parameters before rest
+        let array_len = if start == 0 {
+            len_binding.create_read_expression(ctx)
+        } else {
+            let test = ctx.ast.expression_binary(
+                SPAN,
+                len_binding.create_read_expression(ctx),
+                BinaryOperator::GreaterThan,
+                start_literal(ctx),
+            );
+            let consequent = ctx.ast.expression_binary(
+                SPAN,
+                len_binding.create_read_expression(ctx),
+                BinaryOperator::Subtraction,
+                start_literal(ctx),
+            );
+            ctx.ast.expression_conditional(SPAN, test, consequent, create_number_literal(0, ctx))
+        };
+
+        // `var _len = arguments.length, rest = new Array(...), _key = 1`
+        let kind = VariableDeclarationKind::Var;
+        let arguments = create_arguments_ident(ctx);
+        let arguments_length = create_member_callee(arguments, "length", ctx);
+        let array = ctx.create_unbound_ident_expr(SPAN, Atom::from("Array"), ReferenceFlags::Read);
+        let new_array =
+            ctx.ast.expression_new(SPAN, array, ctx.ast.vec1(Argument::from(array_len)), NONE);
+        let declarations = ctx.ast.vec_from_array([
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                len_binding.create_binding_pattern(ctx),
+                Some(arguments_length),
+                false,
+            ),
+            ctx.ast.variable_declarator(SPAN, kind, array_pattern, Some(new_array), false),
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                key_binding.create_binding_pattern(ctx),
+                Some(start_literal(ctx)),
+                false,
+            ),
+        ]);
+        let init = ctx.ast.for_statement_init_variable_declaration(SPAN, kind, declarations, false);
+
+        // `_key < _len`
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            key_binding.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            len_binding.create_read_expression(ctx),
+        );
+
+        // `_key++`
+        let update = ctx.ast.expression_update(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            key_binding.create_read_write_simple_target(ctx),
+        );
+
+        // `rest[_key - 1] = arguments[_key];`
+        let index = if start == 0 {
+            key_binding.create_read_expression(ctx)
+        } else {
+            ctx.ast.expression_binary(
+                SPAN,
+                key_binding.create_read_expression(ctx),
+                BinaryOperator::Subtraction,
+                start_literal(ctx),
+            )
+        };
+        let target = ctx.ast.member_expression_computed(
+            SPAN,
+            array_binding.create_read_expression(ctx),
+            index,
+            false,
+        );
+        let value = ctx.ast.member_expression_computed(
+            SPAN,
+            create_arguments_ident(ctx),
+            key_binding.create_read_expression(ctx),
+            false,
+        );
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(target),
+            Expression::from(value),
+        );
+
+        let for_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::empty());
+        let block_scope_id = ctx.create_child_scope(for_scope_id, ScopeFlags::empty());
+        let body = ctx.ast.statement_block_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment)),
+            block_scope_id,
+        );
+        stmts.push(ctx.ast.statement_for_with_scope_id(
+            SPAN,
+            Some(init),
+            Some(test),
+            Some(update),
+            body,
+            for_scope_id,
+        ));
+
+        if let Some(pattern) = pattern {
+            let init = array_binding.create_read_expression(ctx);
+            stmts.push(create_var_statement(pattern, init, ctx));
+        }
+    }
+}
+
+/// `true` if parameters are all plain identifiers.
+pub fn is_simple_parameter_list(params: &FormalParameters<'_>) -> bool {
+    params.rest.is_none()
+        && params.items.iter().all(|param| param.pattern.kind.is_binding_identifier())
+}
+
+/// Convert `() => x` to `() => { return x; }`.
+pub fn ensure_arrow_function_block_body<'a>(
+    arrow: &mut ArrowFunctionExpression<'a>,
+    ctx: &TraverseCtx<'a>,
+) {
+    if !arrow.expression {
+        return;
+    }
+    arrow.expression = false;
+    let Some(Statement::ExpressionStatement(stmt)) = arrow.body.statements.pop() else {
+        unreachable!()
+    };
+    let stmt = stmt.unbox();
+    arrow.body.statements.push(ctx.ast.statement_return(stmt.span, Some(stmt.expression)));
+}
+
+/// `true` if current function is a setter.
+pub fn is_setter(ctx: &TraverseCtx<'_>) -> bool {
+    match ctx.parent() {
+        Ancestor::MethodDefinitionValue(method) => *method.kind() == MethodDefinitionKind::Set,
+        Ancestor::ObjectPropertyValue(prop) => *prop.kind() == PropertyKind::Set,
+        _ => false,
+    }
+}
+
+/// Create `_ref` binding for a parameter.
+fn create_ref_param<'a>(scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+    ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable)
+}
+
+/// `var pattern = init;`
+fn create_var_statement<'a>(
+    pattern: BindingPattern<'a>,
+    init: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    let kind = VariableDeclarationKind::Var;
+    let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, Some(init), false);
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        ctx.ast.vec1(declarator),
+        false,
+    ))
+}
+
+/// `expr === void 0`
+fn create_void_0_check<'a>(expr: Expression<'a>, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    ctx.ast.expression_binary(SPAN, expr, BinaryOperator::StrictEquality, ctx.ast.void_0(SPAN))
+}
+
+/// `arguments.length > 0 && arguments[0] !== undefined ? arguments[0] : default`
+fn create_default_param_init<'a>(
+    index: usize,
+    default: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let length_check = create_arguments_length_check(index, ctx);
+    let undefined =
+        ctx.create_unbound_ident_expr(SPAN, Atom::from("undefined"), ReferenceFlags::Read);
+    let undefined_check = ctx.ast.expression_binary(
+        SPAN,
+        create_arguments_member(index, ctx),
+        BinaryOperator::StrictInequality,
+        undefined,
+    );
+    let test =
+        ctx.ast.expression_logical(SPAN, length_check, LogicalOperator::And, undefined_check);
+    ctx.ast.expression_conditional(SPAN, test, create_arguments_member(index, ctx), default)
+}
+
+/// `arguments.length > 1 ? arguments[1] : undefined`
+fn create_arguments_access<'a>(index: usize, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let test = create_arguments_length_check(index, ctx);
+    let undefined =
+        ctx.create_unbound_ident_expr(SPAN, Atom::from("undefined"), ReferenceFlags::Read);
+    ctx.ast.expression_conditional(SPAN, test, create_arguments_member(index, ctx), undefined)
+}
+
+/// `arguments.length > 1`
+fn create_arguments_length_check<'a>(index: usize, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let arguments = create_arguments_ident(ctx);
+    let length = create_member_callee(arguments, "length", ctx);
+    ctx.ast.expression_binary(
+        SPAN,
+        length,
+        BinaryOperator::GreaterThan,
+        create_number_literal(index, ctx),
+    )
+}
+
+/// `arguments[1]`
+fn create_arguments_member<'a>(index: usize, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let arguments = create_arguments_ident(ctx);
+    let index = create_number_literal(index, ctx);
+    Expression::from(ctx.ast.member_expression_computed(SPAN, arguments, index, false))
+}
+
+fn create_arguments_ident<'a>(ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    ctx.create_unbound_ident_expr(SPAN, Atom::from("arguments"), ReferenceFlags::Read)
+}
+
+#[expect(clippy::cast_precision_loss)]
+fn create_number_literal<'a>(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    ctx.ast.expression_numeric_literal(SPAN, value as f64, None, NumberBase::Decimal)
+}
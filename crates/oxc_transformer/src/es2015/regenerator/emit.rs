@@ -0,0 +1,1442 @@
+//! Compilation of a generator function's body into a state machine.
+//!
+//! Statements are "exploded" into a flat listing, where control flow is expressed as jumps
+//! to locations in the listing (`_context.next = 5; break;`). Each location which is jumped to
+//! becomes a `case` of the `switch` in the state machine:
+//!
+//! ```js
+//! while (1) switch (_context.prev = _context.next) {
+//!   case 0:
+//!     _context.next = 2;
+//!     return 1;
+//!   case 2:
+//!   case "end":
+//!     return _context.stop();
+//! }
+//! ```
+//!
+//! Statements and expressions which don't contain `yield` (or, for statements, a jump out of them)
+//! are emitted unchanged. Values of sub-expressions which must be evaluated before a `yield`
+//! are stored in temporary properties of the context object (`_context.t0`).
+//!
+//! Locations are resolved once the whole body has been exploded.
+//!
+//! Based on `emit.js` in [regenerator-transform](https://github.com/facebook/regenerator/blob/v0.15.2/packages/transform/src/emit.js).
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::{CloneIn, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolId};
+use oxc_span::{Atom, GetSpan, SPAN, Span};
+use oxc_syntax::number::NumberBase;
+use oxc_traverse::{BoundIdentifier, MaybeBoundIdentifier, TraverseCtx};
+
+use crate::context::TransformCtx;
+
+use super::create_runtime_method;
+
+/// A location in the listing, resolved to an index in the listing when it is marked.
+#[derive(Clone, Copy)]
+struct Loc(usize);
+
+/// An entry in the listing. Each entry becomes a single statement.
+enum Op<'a> {
+    Statement(Statement<'a>),
+    /// `_context.next = loc;`
+    SetNext(Loc),
+    /// `if (test) { _context.next = loc; break; }`
+    JumpIf(Expression<'a>, Loc),
+    /// `_context.next = _context.tN === test1 ? loc1 : _context.tN === test2 ? loc2 : default;`
+    SwitchNext {
+        discriminant: u32,
+        cases: Vec<(Expression<'a>, Loc)>,
+        default: Loc,
+    },
+    /// `return _context.abrupt("break", loc);`
+    Abrupt(&'static str, Loc),
+    /// `return _context.delegateYield(argument, "tN", loc);`
+    DelegateYield(Expression<'a>, u32, Loc),
+}
+
+/// Target of `break` and `continue` statements.
+enum LeapEntry<'a> {
+    Loop { break_loc: Loc, continue_loc: Loc, label: Option<Atom<'a>> },
+    Switch { break_loc: Loc },
+    Labeled { break_loc: Loc, label: Atom<'a> },
+}
+
+/// Locations of parts of a `try` statement.
+struct TryEntry {
+    /// Start of `try` block
+    first: usize,
+    catch: Option<Loc>,
+    finally: Option<Loc>,
+    after: Loc,
+}
+
+/// Output of [`Emitter`].
+pub struct Emitted<'a> {
+    /// `case`s of the `switch` statement
+    pub cases: ArenaVec<'a, SwitchCase<'a>>,
+    /// `[[tryLoc, catchLoc, finallyLoc, afterLoc], ...]`, if there are any `try` statements
+    pub try_locs_list: Option<Expression<'a>>,
+    /// Scopes of statements which were flattened into the listing
+    pub removed_scopes: Vec<ScopeId>,
+    /// Unreachable statements which were removed from the listing
+    pub dead_statements: Vec<Statement<'a>>,
+}
+
+pub struct Emitter<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// Parameter of the inner function
+    context: BoundIdentifier<'a>,
+    /// Scope of `switch` statement
+    switch_scope_id: ScopeId,
+    listing: Vec<Op<'a>>,
+    /// Indexes in `listing` which start a `case`
+    marked: FxHashSet<usize>,
+    /// Values of locations. `None` if not marked yet.
+    locs: Vec<Option<usize>>,
+    leap_entries: Vec<LeapEntry<'a>>,
+    try_entries: Vec<TryEntry>,
+    /// Number of temporary properties on the context object
+    temps: u32,
+    removed_scopes: Vec<ScopeId>,
+}
+
+impl<'a, 'ctx> Emitter<'a, 'ctx> {
+    pub fn new(
+        context: BoundIdentifier<'a>,
+        switch_scope_id: ScopeId,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        let mut marked = FxHashSet::default();
+        marked.insert(0);
+        Self {
+            ctx,
+            context,
+            switch_scope_id,
+            listing: vec![],
+            marked,
+            locs: vec![],
+            leap_entries: vec![],
+            try_entries: vec![],
+            temps: 0,
+            removed_scopes: vec![],
+        }
+    }
+
+    // ----------------------------------------
+    // Listing
+    // ----------------------------------------
+
+    fn loc(&mut self) -> Loc {
+        self.locs.push(None);
+        Loc(self.locs.len() - 1)
+    }
+
+    /// Set location to current position in the listing, and start a new `case` there.
+    fn mark(&mut self, loc: Loc) -> usize {
+        let index = self.listing.len();
+        debug_assert!(self.locs[loc.0].is_none());
+        self.locs[loc.0] = Some(index);
+        self.marked.insert(index);
+        index
+    }
+
+    fn loc_value(&self, loc: Loc) -> usize {
+        self.locs[loc.0].expect("All locations are marked before the listing is finished")
+    }
+
+    fn emit(&mut self, op: Op<'a>) {
+        self.listing.push(op);
+    }
+
+    fn emit_statement(&mut self, stmt: Statement<'a>) {
+        self.emit(Op::Statement(stmt));
+    }
+
+    fn emit_expression(&mut self, expr: Expression<'a>, ctx: &TraverseCtx<'a>) {
+        self.emit_statement(ctx.ast.statement_expression(expr.span(), expr));
+    }
+
+    /// `target = value;`
+    fn emit_assign(
+        &mut self,
+        target: AssignmentTarget<'a>,
+        value: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        let expr = ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+        self.emit_expression(expr, ctx);
+    }
+
+    /// `_context.prev = index;`
+    fn emit_set_prev(&mut self, index: usize, ctx: &mut TraverseCtx<'a>) {
+        let target = self.context_target("prev", ctx);
+        self.emit_assign(target, create_number(index, ctx), ctx);
+    }
+
+    /// `_context.next = loc; break;`
+    fn jump(&mut self, loc: Loc, ctx: &TraverseCtx<'a>) {
+        self.emit(Op::SetNext(loc));
+        self.emit_statement(ctx.ast.statement_break(SPAN, None));
+    }
+
+    fn jump_if(&mut self, test: Expression<'a>, loc: Loc) {
+        self.emit(Op::JumpIf(test, loc));
+    }
+
+    fn jump_if_not(&mut self, test: Expression<'a>, loc: Loc, ctx: &TraverseCtx<'a>) {
+        let test = match test {
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+                unary.unbox().argument
+            }
+            test => ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, test),
+        };
+        self.emit(Op::JumpIf(test, loc));
+    }
+
+    fn break_loc(&self, label: Option<Atom<'a>>) -> Loc {
+        self.leap_entries
+            .iter()
+            .rev()
+            .find_map(|entry| match (entry, label) {
+                (LeapEntry::Loop { break_loc, .. } | LeapEntry::Switch { break_loc }, None) => {
+                    Some(*break_loc)
+                }
+                (
+                    LeapEntry::Loop { break_loc, label: Some(entry_label), .. }
+                    | LeapEntry::Labeled { break_loc, label: entry_label },
+                    Some(label),
+                ) if *entry_label == label => Some(*break_loc),
+                _ => None,
+            })
+            .expect("`break` must have a target")
+    }
+
+    fn continue_loc(&self, label: Option<Atom<'a>>) -> Loc {
+        self.leap_entries
+            .iter()
+            .rev()
+            .find_map(|entry| match entry {
+                LeapEntry::Loop { continue_loc, label: entry_label, .. }
+                    if label.is_none() || *entry_label == label =>
+                {
+                    Some(*continue_loc)
+                }
+                _ => None,
+            })
+            .expect("`continue` must have a target")
+    }
+
+    // ----------------------------------------
+    // Context object
+    // ----------------------------------------
+
+    /// `_context.name`
+    fn context_member(&self, name: &'a str, ctx: &mut TraverseCtx<'a>) -> MemberExpression<'a> {
+        let object = self.context.create_read_expression(ctx);
+        ctx.ast.member_expression_static(SPAN, object, ctx.ast.identifier_name(SPAN, name), false)
+    }
+
+    fn context_property(&self, name: &'a str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        Expression::from(self.context_member(name, ctx))
+    }
+
+    fn context_target(&self, name: &'a str, ctx: &mut TraverseCtx<'a>) -> AssignmentTarget<'a> {
+        AssignmentTarget::from(self.context_member(name, ctx))
+    }
+
+    /// `_context.name(...arguments)`
+    fn context_call(
+        &self,
+        name: &'a str,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = self.context_property(name, ctx);
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    fn make_temp(&mut self) -> u32 {
+        let temp = self.temps;
+        self.temps += 1;
+        temp
+    }
+
+    fn temp_name(temp: u32, ctx: &TraverseCtx<'a>) -> Atom<'a> {
+        ctx.ast.atom(&format!("t{temp}"))
+    }
+
+    /// `_context.tN`
+    fn temp_member(&self, temp: u32, ctx: &mut TraverseCtx<'a>) -> MemberExpression<'a> {
+        let object = self.context.create_read_expression(ctx);
+        let property = ctx.ast.identifier_name(SPAN, Self::temp_name(temp, ctx));
+        ctx.ast.member_expression_static(SPAN, object, property, false)
+    }
+
+    fn temp(&self, temp: u32, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        Expression::from(self.temp_member(temp, ctx))
+    }
+
+    fn temp_target(&self, temp: u32, ctx: &mut TraverseCtx<'a>) -> AssignmentTarget<'a> {
+        AssignmentTarget::from(self.temp_member(temp, ctx))
+    }
+
+    fn error(&self, message: &'static str, span: Span) {
+        self.ctx.error(OxcDiagnostic::error(message).with_label(span));
+    }
+
+    // ----------------------------------------
+    // Statements
+    // ----------------------------------------
+
+    pub fn explode_statement(
+        &mut self,
+        stmt: Statement<'a>,
+        label: Option<Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match stmt {
+            // Blocks are always flattened
+            Statement::BlockStatement(block) => {
+                let block = block.unbox();
+                self.removed_scopes.push(block.scope_id());
+                for stmt in block.body {
+                    self.explode_statement(stmt, None, ctx);
+                }
+                return;
+            }
+            Statement::EmptyStatement(_) => return,
+            _ => {}
+        }
+
+        if !contains_leap(&stmt) {
+            self.emit_statement(stmt);
+            return;
+        }
+
+        match stmt {
+            Statement::ExpressionStatement(stmt) => {
+                self.explode_expression_statement(stmt.unbox().expression, ctx);
+            }
+            Statement::LabeledStatement(stmt) => {
+                let LabeledStatement { label, body, .. } = stmt.unbox();
+                let after = self.loc();
+                self.leap_entries.push(LeapEntry::Labeled { break_loc: after, label: label.name });
+                self.explode_statement(body, Some(label.name), ctx);
+                self.leap_entries.pop();
+                self.mark(after);
+            }
+            Statement::WhileStatement(stmt) => {
+                let WhileStatement { test, body, .. } = stmt.unbox();
+                let before = self.loc();
+                let after = self.loc();
+                self.mark(before);
+                let test = self.explode_expression(test, ctx);
+                self.jump_if_not(test, after, ctx);
+                self.explode_loop_body(body, after, before, label, ctx);
+                self.jump(before, ctx);
+                self.mark(after);
+            }
+            Statement::DoWhileStatement(stmt) => {
+                let DoWhileStatement { body, test, .. } = stmt.unbox();
+                let first = self.loc();
+                let test_loc = self.loc();
+                let after = self.loc();
+                self.mark(first);
+                self.explode_loop_body(body, after, test_loc, label, ctx);
+                self.mark(test_loc);
+                let test = self.explode_expression(test, ctx);
+                self.jump_if(test, first);
+                self.mark(after);
+            }
+            Statement::ForStatement(stmt) => {
+                let ForStatement { init, test, update, body, scope_id, .. } = stmt.unbox();
+                self.removed_scopes.push(scope_id.get().unwrap());
+                let head = self.loc();
+                let update_loc = self.loc();
+                let after = self.loc();
+                match init {
+                    Some(ForStatementInit::VariableDeclaration(decl)) => {
+                        self.emit_statement(Statement::VariableDeclaration(decl));
+                    }
+                    Some(init) => self.explode_expression_statement(init.into_expression(), ctx),
+                    None => {}
+                }
+                self.mark(head);
+                if let Some(test) = test {
+                    let test = self.explode_expression(test, ctx);
+                    self.jump_if_not(test, after, ctx);
+                }
+                self.explode_loop_body(body, after, update_loc, label, ctx);
+                self.mark(update_loc);
+                if let Some(update) = update {
+                    self.explode_expression_statement(update, ctx);
+                }
+                self.jump(head, ctx);
+                self.mark(after);
+            }
+            Statement::ForInStatement(stmt) => {
+                let ForInStatement { span, left, right, body, scope_id } = stmt.unbox();
+                self.removed_scopes.push(scope_id.get().unwrap());
+                let head = self.loc();
+                let after = self.loc();
+
+                // `_context.t0 = regeneratorRuntime().keys(right);`
+                let keys = self.make_temp();
+                let right = self.explode_expression(right, ctx);
+                let callee = create_runtime_method(self.ctx, "keys", ctx);
+                let keys_call = ctx.ast.expression_call(
+                    SPAN,
+                    callee,
+                    NONE,
+                    ctx.ast.vec1(Argument::from(right)),
+                    false,
+                );
+                let target = self.temp_target(keys, ctx);
+                self.emit_assign(target, keys_call, ctx);
+
+                // `if ((_context.t1 = _context.t0()).done) { _context.next = after; break; }`
+                self.mark(head);
+                let key = self.make_temp();
+                let next_call =
+                    ctx.ast.expression_call(SPAN, self.temp(keys, ctx), NONE, ctx.ast.vec(), false);
+                let assign = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    self.temp_target(key, ctx),
+                    next_call,
+                );
+                let done = Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    assign,
+                    ctx.ast.identifier_name(SPAN, "done"),
+                    false,
+                ));
+                self.jump_if(done, after);
+
+                // `left = _context.t1.value;`
+                let value = Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    self.temp(key, ctx),
+                    ctx.ast.identifier_name(SPAN, "value"),
+                    false,
+                ));
+                match left {
+                    ForStatementLeft::VariableDeclaration(_) => {
+                        // Declarations are hoisted before the body is exploded
+                        self.error("Cannot compile `for...in` declaration in a generator.", span);
+                    }
+                    left => self.emit_assign(left.into_assignment_target(), value, ctx),
+                }
+
+                self.explode_loop_body(body, after, head, label, ctx);
+                self.jump(head, ctx);
+                self.mark(after);
+            }
+            Statement::BreakStatement(stmt) => {
+                let loc = self.break_loc(stmt.label.as_ref().map(|label| label.name));
+                self.emit(Op::Abrupt("break", loc));
+            }
+            Statement::ContinueStatement(stmt) => {
+                let loc = self.continue_loc(stmt.label.as_ref().map(|label| label.name));
+                self.emit(Op::Abrupt("continue", loc));
+            }
+            Statement::SwitchStatement(stmt) => self.explode_switch(stmt.unbox(), ctx),
+            Statement::IfStatement(stmt) => {
+                let IfStatement { test, consequent, alternate, .. } = stmt.unbox();
+                let else_loc = alternate.as_ref().map(|_| self.loc());
+                let after = self.loc();
+                let test = self.explode_expression(test, ctx);
+                self.jump_if_not(test, else_loc.unwrap_or(after), ctx);
+                self.explode_statement(consequent, None, ctx);
+                if let Some(alternate) = alternate {
+                    self.jump(after, ctx);
+                    self.mark(else_loc.unwrap());
+                    self.explode_statement(alternate, None, ctx);
+                }
+                self.mark(after);
+            }
+            Statement::ReturnStatement(stmt) => {
+                let ReturnStatement { span, argument } = stmt.unbox();
+                let mut arguments = ctx
+                    .ast
+                    .vec1(Argument::from(ctx.ast.expression_string_literal(SPAN, "return", None)));
+                if let Some(argument) = argument {
+                    arguments.push(Argument::from(self.explode_expression(argument, ctx)));
+                }
+                let abrupt = self.context_call("abrupt", arguments, ctx);
+                self.emit_statement(ctx.ast.statement_return(span, Some(abrupt)));
+            }
+            Statement::ThrowStatement(stmt) => {
+                let ThrowStatement { span, argument } = stmt.unbox();
+                let argument = self.explode_expression(argument, ctx);
+                self.emit_statement(ctx.ast.statement_throw(span, argument));
+            }
+            Statement::TryStatement(stmt) => self.explode_try(stmt.unbox(), ctx),
+            Statement::ForOfStatement(_) => {
+                self.error(
+                    "Cannot compile `for...of` loop containing `yield` or a jump out of the loop in a generator. Enable the `for...of` transform.",
+                    stmt.span(),
+                );
+                self.emit_statement(stmt);
+            }
+            stmt => {
+                self.error("Cannot compile this statement in a generator.", stmt.span());
+                self.emit_statement(stmt);
+            }
+        }
+    }
+
+    fn explode_loop_body(
+        &mut self,
+        body: Statement<'a>,
+        break_loc: Loc,
+        continue_loc: Loc,
+        label: Option<Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.leap_entries.push(LeapEntry::Loop { break_loc, continue_loc, label });
+        self.explode_statement(body, None, ctx);
+        self.leap_entries.pop();
+    }
+
+    fn explode_switch(&mut self, stmt: SwitchStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let SwitchStatement { discriminant, mut cases, scope_id, .. } = stmt;
+        self.removed_scopes.push(scope_id.get().unwrap());
+
+        let discriminant = self.explode_expression(discriminant, ctx);
+        let temp = self.make_temp();
+        let target = self.temp_target(temp, ctx);
+        self.emit_assign(target, discriminant, ctx);
+
+        let after = self.loc();
+        let default_loc = self.loc();
+        let mut case_locs = Vec::with_capacity(cases.len());
+        let mut tests = vec![];
+        for case in &mut cases {
+            if let Some(test) = case.test.take() {
+                if contains_yield(&test) {
+                    self.error("Cannot compile `yield` in a `switch` case test.", test.span());
+                }
+                let loc = self.loc();
+                tests.push((test, loc));
+                case_locs.push(loc);
+            } else {
+                case_locs.push(default_loc);
+            }
+        }
+        self.emit(Op::SwitchNext { discriminant: temp, cases: tests, default: default_loc });
+        self.emit_statement(ctx.ast.statement_break(SPAN, None));
+
+        self.leap_entries.push(LeapEntry::Switch { break_loc: after });
+        for (case, loc) in cases.into_iter().zip(case_locs) {
+            self.mark(loc);
+            for stmt in case.consequent {
+                self.explode_statement(stmt, None, ctx);
+            }
+        }
+        self.leap_entries.pop();
+
+        self.mark(after);
+        if self.locs[default_loc.0].is_none() {
+            self.mark(default_loc);
+        }
+    }
+
+    fn explode_try(&mut self, stmt: TryStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let TryStatement { block, handler, finalizer, .. } = stmt;
+        let after = self.loc();
+        let catch_loc = handler.as_ref().map(|_| self.loc());
+        let finally_loc = finalizer.as_ref().map(|_| self.loc());
+        let first_loc = self.listing.len();
+        self.try_entries.push(TryEntry {
+            first: first_loc,
+            catch: catch_loc,
+            finally: finally_loc,
+            after,
+        });
+
+        self.emit_set_prev(first_loc, ctx);
+        self.explode_statement(Statement::BlockStatement(block), None, ctx);
+
+        if let Some(handler) = handler {
+            self.jump(finally_loc.unwrap_or(after), ctx);
+            let index = self.mark(catch_loc.unwrap());
+            self.emit_set_prev(index, ctx);
+
+            let CatchClause { param, mut body, scope_id, .. } = handler.unbox();
+            self.removed_scopes.push(scope_id.get().unwrap());
+
+            // `_context.t0 = _context["catch"](first_loc);`
+            let catch_callee = Expression::from(ctx.ast.member_expression_computed(
+                SPAN,
+                self.context.create_read_expression(ctx),
+                ctx.ast.expression_string_literal(SPAN, "catch", None),
+                false,
+            ));
+            let catch_call = ctx.ast.expression_call(
+                SPAN,
+                catch_callee,
+                NONE,
+                ctx.ast.vec1(Argument::from(create_number(first_loc, ctx))),
+                false,
+            );
+            match param.map(|param| param.pattern.kind) {
+                Some(BindingPatternKind::BindingIdentifier(ident)) => {
+                    let temp = self.make_temp();
+                    let target = self.temp_target(temp, ctx);
+                    self.emit_assign(target, catch_call, ctx);
+
+                    // Replace references to catch parameter with the temp
+                    let symbol_id = ident.symbol_id();
+                    let binding_scope_id = ctx.scoping().symbol_scope_id(symbol_id);
+                    ctx.scoping_mut().remove_binding(binding_scope_id, &ident.name);
+                    CatchParamReplacer { emitter: self, symbol_id, temp, ctx }
+                        .visit_block_statement(&mut body);
+                }
+                Some(pattern) => {
+                    self.error(
+                        "Cannot compile destructuring `catch` parameter in a generator. Enable the destructuring transform.",
+                        pattern.span(),
+                    );
+                    self.emit_expression(catch_call, ctx);
+                }
+                None => self.emit_expression(catch_call, ctx),
+            }
+
+            self.explode_statement(Statement::BlockStatement(body), None, ctx);
+        }
+
+        if let Some(finalizer) = finalizer {
+            let index = self.mark(finally_loc.unwrap());
+            self.emit_set_prev(index, ctx);
+            self.explode_statement(Statement::BlockStatement(finalizer), None, ctx);
+            // `return _context.finish(finally_loc);`
+            let finish = self.context_call(
+                "finish",
+                ctx.ast.vec1(Argument::from(create_number(index, ctx))),
+                ctx,
+            );
+            self.emit_statement(ctx.ast.statement_return(SPAN, Some(finish)));
+        }
+
+        self.mark(after);
+    }
+
+    // ----------------------------------------
+    // Expressions
+    // ----------------------------------------
+
+    /// Explode an expression whose value is not used.
+    fn explode_expression_statement(&mut self, expr: Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !contains_yield(&expr) {
+            self.emit_expression(expr, ctx);
+            return;
+        }
+
+        match expr {
+            Expression::ParenthesizedExpression(paren) => {
+                self.explode_expression_statement(paren.unbox().expression, ctx);
+            }
+            Expression::SequenceExpression(sequence) => {
+                for expr in sequence.unbox().expressions {
+                    self.explode_expression_statement(expr, ctx);
+                }
+            }
+            Expression::LogicalExpression(logical) => {
+                self.explode_logical(logical.unbox(), true, ctx);
+            }
+            Expression::ConditionalExpression(conditional) => {
+                self.explode_conditional(conditional.unbox(), true, ctx);
+            }
+            Expression::YieldExpression(yield_expr) => {
+                self.explode_yield(yield_expr.unbox(), true, ctx);
+            }
+            expr => {
+                let expr = self.explode_expression(expr, ctx);
+                self.emit_expression(expr, ctx);
+            }
+        }
+    }
+
+    /// Explode an expression, returning an expression which evaluates to its value
+    /// after all the statements it has emitted.
+    fn explode_expression(
+        &mut self,
+        expr: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if !contains_yield(&expr) {
+            return expr;
+        }
+
+        match expr {
+            Expression::ParenthesizedExpression(paren) => {
+                self.explode_expression(paren.unbox().expression, ctx)
+            }
+            Expression::StaticMemberExpression(mut member) => {
+                member.object =
+                    self.explode_expression(member.object.take_in(ctx.ast.allocator), ctx);
+                Expression::StaticMemberExpression(member)
+            }
+            Expression::ComputedMemberExpression(mut member) => {
+                member.object =
+                    self.explode_expression(member.object.take_in(ctx.ast.allocator), ctx);
+                member.expression =
+                    self.explode_expression(member.expression.take_in(ctx.ast.allocator), ctx);
+                Expression::ComputedMemberExpression(member)
+            }
+            Expression::PrivateFieldExpression(mut member) => {
+                member.object =
+                    self.explode_expression(member.object.take_in(ctx.ast.allocator), ctx);
+                Expression::PrivateFieldExpression(member)
+            }
+            Expression::CallExpression(call) => self.explode_call(call.unbox(), ctx),
+            Expression::NewExpression(new_expr) => {
+                let NewExpression { span, callee, arguments, .. } = new_expr.unbox();
+                let callee = self.explode_via_temp(None, callee, true, ctx);
+                let arguments = self.explode_arguments(arguments, ctx);
+                ctx.ast.expression_new(span, callee, arguments, NONE)
+            }
+            Expression::ArrayExpression(mut array) => {
+                for element in &mut array.elements {
+                    match element {
+                        ArrayExpressionElement::SpreadElement(spread) => {
+                            let argument = spread.argument.take_in(ctx.ast.allocator);
+                            spread.argument = self.explode_via_temp(None, argument, true, ctx);
+                        }
+                        ArrayExpressionElement::Elision(_) => {}
+                        element => {
+                            let expr = element.to_expression_mut();
+                            let value = expr.take_in(ctx.ast.allocator);
+                            *expr = self.explode_via_temp(None, value, true, ctx);
+                        }
+                    }
+                }
+                Expression::ArrayExpression(array)
+            }
+            Expression::ObjectExpression(mut object) => {
+                for property in &mut object.properties {
+                    match property {
+                        ObjectPropertyKind::ObjectProperty(property) => {
+                            if property.computed && contains_yield(property.key.to_expression()) {
+                                self.error(
+                                    "Cannot compile `yield` in a computed key in a generator.",
+                                    property.key.span(),
+                                );
+                            }
+                            if property.method || property.kind != PropertyKind::Init {
+                                continue;
+                            }
+                            let value = property.value.take_in(ctx.ast.allocator);
+                            property.value = self.explode_via_temp(None, value, true, ctx);
+                            property.shorthand = false;
+                        }
+                        ObjectPropertyKind::SpreadProperty(spread) => {
+                            let argument = spread.argument.take_in(ctx.ast.allocator);
+                            spread.argument = self.explode_via_temp(None, argument, true, ctx);
+                        }
+                    }
+                }
+                Expression::ObjectExpression(object)
+            }
+            Expression::SequenceExpression(sequence) => {
+                let mut expressions = sequence.unbox().expressions;
+                let last = expressions.pop().unwrap();
+                for expr in expressions {
+                    self.explode_expression_statement(expr, ctx);
+                }
+                self.explode_expression(last, ctx)
+            }
+            Expression::LogicalExpression(logical) => {
+                self.explode_logical(logical.unbox(), false, ctx).unwrap()
+            }
+            Expression::ConditionalExpression(conditional) => {
+                self.explode_conditional(conditional.unbox(), false, ctx).unwrap()
+            }
+            Expression::UnaryExpression(mut unary) => {
+                unary.argument =
+                    self.explode_expression(unary.argument.take_in(ctx.ast.allocator), ctx);
+                Expression::UnaryExpression(unary)
+            }
+            Expression::BinaryExpression(mut binary) => {
+                let left = binary.left.take_in(ctx.ast.allocator);
+                binary.left = self.explode_via_temp(None, left, true, ctx);
+                let right = binary.right.take_in(ctx.ast.allocator);
+                binary.right = self.explode_via_temp(None, right, true, ctx);
+                Expression::BinaryExpression(binary)
+            }
+            Expression::AssignmentExpression(assign) => {
+                self.explode_assignment(assign.unbox(), ctx)
+            }
+            Expression::UpdateExpression(mut update) => {
+                self.explode_simple_assignment_target(&mut update.argument, ctx);
+                Expression::UpdateExpression(update)
+            }
+            Expression::YieldExpression(yield_expr) => {
+                self.explode_yield(yield_expr.unbox(), false, ctx).unwrap()
+            }
+            Expression::TemplateLiteral(mut template) => {
+                for expr in &mut template.expressions {
+                    let value = expr.take_in(ctx.ast.allocator);
+                    *expr = self.explode_via_temp(None, value, true, ctx);
+                }
+                Expression::TemplateLiteral(template)
+            }
+            expr => {
+                self.error(
+                    "Cannot compile `yield` in this expression in a generator.",
+                    expr.span(),
+                );
+                expr
+            }
+        }
+    }
+
+    /// Explode an expression, storing its value in a temp if `temp` is provided,
+    /// or if it is not a literal and `has_leaping_siblings` is `true`.
+    ///
+    /// The value must be stored if another part of the parent expression evaluated after it
+    /// contains `yield`, as that may change it.
+    fn explode_via_temp(
+        &mut self,
+        temp: Option<u32>,
+        expr: Expression<'a>,
+        has_leaping_siblings: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let result = self.explode_expression(expr, ctx);
+        if temp.is_none() && (!has_leaping_siblings || result.is_literal()) {
+            return result;
+        }
+        let temp = temp.unwrap_or_else(|| self.make_temp());
+        let target = self.temp_target(temp, ctx);
+        self.emit_assign(target, result, ctx);
+        self.temp(temp, ctx)
+    }
+
+    /// Explode an expression, and store its value in a temp.
+    fn explode_into_temp(&mut self, temp: u32, expr: Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let value = self.explode_expression(expr, ctx);
+        let target = self.temp_target(temp, ctx);
+        self.emit_assign(target, value, ctx);
+    }
+
+    fn explode_arguments(
+        &mut self,
+        mut arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaVec<'a, Argument<'a>> {
+        for argument in &mut arguments {
+            if let Argument::SpreadElement(spread) = argument {
+                let value = spread.argument.take_in(ctx.ast.allocator);
+                spread.argument = self.explode_via_temp(None, value, true, ctx);
+            } else {
+                let expr = argument.to_expression_mut();
+                let value = expr.take_in(ctx.ast.allocator);
+                *expr = self.explode_via_temp(None, value, true, ctx);
+            }
+        }
+        arguments
+    }
+
+    fn explode_call(
+        &mut self,
+        call: CallExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let CallExpression { span, callee, arguments, pure, .. } = call;
+        let has_leaping_arguments = arguments.iter().any(|argument| match argument {
+            Argument::SpreadElement(spread) => contains_yield(&spread.argument),
+            argument => contains_yield(argument.to_expression()),
+        });
+
+        let mut this_arg = None;
+        let callee = if callee.is_member_expression() {
+            if has_leaping_arguments {
+                // Callee must be evaluated before the arguments, with its object bound to `this`.
+                // `obj.foo(yield x)` -> `_context.t0 = obj; ...; _context.t0.foo.call(_context.t0, _context.sent)`
+                let temp = self.make_temp();
+                let member = match callee.into_member_expression() {
+                    MemberExpression::StaticMemberExpression(mut member) => {
+                        let object = member.object.take_in(ctx.ast.allocator);
+                        member.object = self.explode_via_temp(Some(temp), object, false, ctx);
+                        MemberExpression::StaticMemberExpression(member)
+                    }
+                    MemberExpression::ComputedMemberExpression(mut member) => {
+                        let object = member.object.take_in(ctx.ast.allocator);
+                        member.object = self.explode_via_temp(Some(temp), object, false, ctx);
+                        let property = member.expression.take_in(ctx.ast.allocator);
+                        member.expression = self.explode_via_temp(None, property, true, ctx);
+                        MemberExpression::ComputedMemberExpression(member)
+                    }
+                    MemberExpression::PrivateFieldExpression(mut member) => {
+                        let object = member.object.take_in(ctx.ast.allocator);
+                        member.object = self.explode_via_temp(Some(temp), object, false, ctx);
+                        MemberExpression::PrivateFieldExpression(member)
+                    }
+                };
+                this_arg = Some(self.temp(temp, ctx));
+                Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    Expression::from(member),
+                    ctx.ast.identifier_name(SPAN, "call"),
+                    false,
+                ))
+            } else {
+                self.explode_expression(callee, ctx)
+            }
+        } else {
+            let callee = self.explode_via_temp(None, callee, false, ctx);
+            if callee.is_member_expression() {
+                // Callee has become a temp. Call it without `this`.
+                // `(yield x)()` -> `(0, _context.sent)()`
+                ctx.ast.expression_sequence(
+                    SPAN,
+                    ctx.ast.vec_from_array([create_number(0, ctx), callee]),
+                )
+            } else {
+                callee
+            }
+        };
+
+        let arguments = if has_leaping_arguments {
+            let mut arguments = self.explode_arguments(arguments, ctx);
+            if let Some(this_arg) = this_arg {
+                arguments.insert(0, Argument::from(this_arg));
+            }
+            arguments
+        } else {
+            arguments
+        };
+
+        ctx.ast.expression_call_with_pure(span, callee, NONE, arguments, false, pure)
+    }
+
+    fn explode_logical(
+        &mut self,
+        logical: LogicalExpression<'a>,
+        ignore_result: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let LogicalExpression { left, operator, right, .. } = logical;
+        let after = self.loc();
+        let result = if ignore_result { None } else { Some(self.make_temp()) };
+
+        let left = self.explode_via_temp(result, left, false, ctx);
+        match operator {
+            LogicalOperator::And => self.jump_if_not(left, after, ctx),
+            LogicalOperator::Or => self.jump_if(left, after),
+            LogicalOperator::Coalesce => {
+                let null = ctx.ast.expression_null_literal(SPAN);
+                let test = ctx.ast.expression_binary(SPAN, left, BinaryOperator::Inequality, null);
+                self.jump_if(test, after);
+            }
+        }
+
+        if ignore_result {
+            self.explode_expression_statement(right, ctx);
+        } else {
+            self.explode_into_temp(result.unwrap(), right, ctx);
+        }
+        self.mark(after);
+
+        result.map(|temp| self.temp(temp, ctx))
+    }
+
+    fn explode_conditional(
+        &mut self,
+        conditional: ConditionalExpression<'a>,
+        ignore_result: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let ConditionalExpression { test, consequent, alternate, .. } = conditional;
+        let else_loc = self.loc();
+        let after = self.loc();
+
+        let test = self.explode_expression(test, ctx);
+        self.jump_if_not(test, else_loc, ctx);
+
+        let result = if ignore_result { None } else { Some(self.make_temp()) };
+        for (index, branch) in [consequent, alternate].into_iter().enumerate() {
+            if index == 1 {
+                self.jump(after, ctx);
+                self.mark(else_loc);
+            }
+            if ignore_result {
+                self.explode_expression_statement(branch, ctx);
+            } else {
+                self.explode_into_temp(result.unwrap(), branch, ctx);
+            }
+        }
+        self.mark(after);
+
+        result.map(|temp| self.temp(temp, ctx))
+    }
+
+    /// Explode a `yield` expression.
+    ///
+    /// Returns the expression which evaluates to the value sent into the generator,
+    /// unless `ignore_result` is `true`.
+    fn explode_yield(
+        &mut self,
+        yield_expr: YieldExpression<'a>,
+        ignore_result: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let YieldExpression { span, delegate, argument } = yield_expr;
+        let after = self.loc();
+        let argument = argument.map(|argument| self.explode_expression(argument, ctx));
+
+        if delegate {
+            if let Some(argument) = argument {
+                let result = self.make_temp();
+                self.emit(Op::DelegateYield(argument, result, after));
+                self.mark(after);
+                return (!ignore_result).then(|| self.temp(result, ctx));
+            }
+        }
+
+        self.emit(Op::SetNext(after));
+        self.emit_statement(ctx.ast.statement_return(span, argument));
+        self.mark(after);
+        (!ignore_result).then(|| self.context_property("sent", ctx))
+    }
+
+    fn explode_assignment(
+        &mut self,
+        assign: AssignmentExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let AssignmentExpression { span, operator, mut left, right } = assign;
+
+        if operator == AssignmentOperator::Assign {
+            // Left side doesn't need to be read, so it can be exploded directly
+            if let Some(target) = left.as_simple_assignment_target_mut() {
+                self.explode_simple_assignment_target(target, ctx);
+            } else if contains_yield_in_target(&left) {
+                self.error(
+                    "Cannot compile `yield` in destructuring assignment target.",
+                    left.span(),
+                );
+            }
+            let right = self.explode_expression(right, ctx);
+            return ctx.ast.expression_assignment(span, operator, left, right);
+        }
+
+        // `x += yield y` -> `_context.t0 = x; ...; x = _context.t0 += _context.sent`
+        let Some((target, value)) = self.split_assignment_target(left, ctx) else {
+            unreachable!("Compound assignment target must be a simple assignment target");
+        };
+        let temp = self.make_temp();
+        let temp_target = self.temp_target(temp, ctx);
+        self.emit_assign(temp_target, value, ctx);
+        let right = self.explode_expression(right, ctx);
+        let temp_target = self.temp_target(temp, ctx);
+        let inner = ctx.ast.expression_assignment(SPAN, operator, temp_target, right);
+        ctx.ast.expression_assignment(span, AssignmentOperator::Assign, target, inner)
+    }
+
+    fn explode_simple_assignment_target(
+        &mut self,
+        target: &mut SimpleAssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match target {
+            SimpleAssignmentTarget::StaticMemberExpression(member) => {
+                member.object =
+                    self.explode_expression(member.object.take_in(ctx.ast.allocator), ctx);
+            }
+            SimpleAssignmentTarget::ComputedMemberExpression(member) => {
+                member.object =
+                    self.explode_expression(member.object.take_in(ctx.ast.allocator), ctx);
+                member.expression =
+                    self.explode_expression(member.expression.take_in(ctx.ast.allocator), ctx);
+            }
+            SimpleAssignmentTarget::PrivateFieldExpression(member) => {
+                member.object =
+                    self.explode_expression(member.object.take_in(ctx.ast.allocator), ctx);
+            }
+            _ => {}
+        }
+    }
+
+    /// Split an assignment target into a target and an expression which reads it,
+    /// evaluating the parts of member expressions once.
+    fn split_assignment_target(
+        &mut self,
+        target: AssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<(AssignmentTarget<'a>, Expression<'a>)> {
+        let result = match target {
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+                ctx.delete_reference(ident.reference_id(), &ident.name);
+                let binding = MaybeBoundIdentifier::new(ident.name, symbol_id);
+                (
+                    binding.create_spanned_write_target(ident.span, ctx),
+                    binding.create_spanned_read_expression(ident.span, ctx),
+                )
+            }
+            AssignmentTarget::StaticMemberExpression(member) => {
+                let StaticMemberExpression { span, object, property, .. } = member.unbox();
+                let object = self.reusable(object, ctx);
+                let read = ctx.ast.member_expression_static(
+                    span,
+                    self.reuse(&object, ctx),
+                    property.clone(),
+                    false,
+                );
+                let write = ctx.ast.member_expression_static(
+                    span,
+                    self.reuse(&object, ctx),
+                    property,
+                    false,
+                );
+                (AssignmentTarget::from(write), Expression::from(read))
+            }
+            AssignmentTarget::ComputedMemberExpression(member) => {
+                let ComputedMemberExpression { span, object, expression, .. } = member.unbox();
+                let object = self.reusable(object, ctx);
+                let property = self.reusable(expression, ctx);
+                let read = ctx.ast.member_expression_computed(
+                    span,
+                    self.reuse(&object, ctx),
+                    self.reuse(&property, ctx),
+                    false,
+                );
+                let write = ctx.ast.member_expression_computed(
+                    span,
+                    self.reuse(&object, ctx),
+                    self.reuse(&property, ctx),
+                    false,
+                );
+                (AssignmentTarget::from(write), Expression::from(read))
+            }
+            AssignmentTarget::PrivateFieldExpression(member) => {
+                let PrivateFieldExpression { span, object, field, .. } = member.unbox();
+                let object = self.reusable(object, ctx);
+                let read = ctx.ast.member_expression_private_field_expression(
+                    span,
+                    self.reuse(&object, ctx),
+                    field.clone(),
+                    false,
+                );
+                let write = ctx.ast.member_expression_private_field_expression(
+                    span,
+                    self.reuse(&object, ctx),
+                    field,
+                    false,
+                );
+                (AssignmentTarget::from(write), Expression::from(read))
+            }
+            _ => return None,
+        };
+        Some(result)
+    }
+
+    /// Explode an expression, and convert it into a form which can be evaluated multiple times.
+    fn reusable(&mut self, expr: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Reusable<'a> {
+        match self.explode_expression(expr, ctx) {
+            Expression::Identifier(ident) => {
+                let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+                ctx.delete_reference(ident.reference_id(), &ident.name);
+                Reusable::Identifier(MaybeBoundIdentifier::new(ident.name, symbol_id))
+            }
+            Expression::ThisExpression(_) => Reusable::This,
+            expr if expr.is_literal() => Reusable::Literal(expr),
+            expr => {
+                let temp = self.make_temp();
+                let target = self.temp_target(temp, ctx);
+                self.emit_assign(target, expr, ctx);
+                Reusable::Temp(temp)
+            }
+        }
+    }
+
+    fn reuse(&self, reusable: &Reusable<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match reusable {
+            Reusable::Identifier(binding) => binding.create_read_expression(ctx),
+            Reusable::This => ctx.ast.expression_this(SPAN),
+            Reusable::Literal(expr) => expr.clone_in(ctx.ast.allocator),
+            Reusable::Temp(temp) => self.temp(*temp, ctx),
+        }
+    }
+
+    // ----------------------------------------
+    // Output
+    // ----------------------------------------
+
+    /// Resolve locations, and build the `case`s of the state machine.
+    pub fn finish(mut self, ctx: &mut TraverseCtx<'a>) -> Emitted<'a> {
+        let listing = std::mem::take(&mut self.listing);
+        let final_index = listing.len();
+
+        let mut cases = ctx.ast.vec();
+        let mut case: Option<(usize, ArenaVec<'a, Statement<'a>>)> = None;
+        let mut dead_statements = vec![];
+        let mut already_ended = false;
+        for (index, op) in listing.into_iter().enumerate() {
+            if self.marked.contains(&index) {
+                if let Some((test, consequent)) = case.take() {
+                    cases.push(Self::create_case(test, consequent, ctx));
+                }
+                case = Some((index, ctx.ast.vec()));
+                already_ended = false;
+            }
+            let stmt = self.op_to_statement(op, ctx);
+            if already_ended {
+                dead_statements.push(stmt);
+                continue;
+            }
+            already_ended = matches!(
+                stmt,
+                Statement::BreakStatement(_)
+                    | Statement::ContinueStatement(_)
+                    | Statement::ReturnStatement(_)
+                    | Statement::ThrowStatement(_)
+            );
+            case.as_mut().unwrap().1.push(stmt);
+        }
+        if let Some((test, consequent)) = case.take() {
+            cases.push(Self::create_case(test, consequent, ctx));
+        }
+
+        // `case 5:` - falls through to `case "end":`
+        cases.push(Self::create_case(final_index, ctx.ast.vec(), ctx));
+        // `case "end": return _context.stop();`
+        let stop = self.context_call("stop", ctx.ast.vec(), ctx);
+        cases.push(ctx.ast.switch_case(
+            SPAN,
+            Some(ctx.ast.expression_string_literal(SPAN, "end", None)),
+            ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(stop))),
+        ));
+
+        let try_locs_list = self.create_try_locs_list(ctx);
+
+        Emitted { cases, try_locs_list, removed_scopes: self.removed_scopes, dead_statements }
+    }
+
+    fn create_case(
+        index: usize,
+        consequent: ArenaVec<'a, Statement<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> SwitchCase<'a> {
+        ctx.ast.switch_case(SPAN, Some(create_number(index, ctx)), consequent)
+    }
+
+    fn op_to_statement(&self, op: Op<'a>, ctx: &mut TraverseCtx<'a>) -> Statement<'a> {
+        match op {
+            Op::Statement(stmt) => stmt,
+            Op::SetNext(loc) => {
+                let value = create_number(self.loc_value(loc), ctx);
+                self.create_set_next(value, ctx)
+            }
+            Op::JumpIf(test, loc) => {
+                // `if (test) { _context.next = loc; break; }`
+                let value = create_number(self.loc_value(loc), ctx);
+                let body = ctx.ast.vec_from_array([
+                    self.create_set_next(value, ctx),
+                    ctx.ast.statement_break(SPAN, None),
+                ]);
+                let scope_id = ctx.create_child_scope(self.switch_scope_id, ScopeFlags::empty());
+                let block = ctx.ast.statement_block_with_scope_id(SPAN, body, scope_id);
+                ctx.ast.statement_if(SPAN, test, block, None)
+            }
+            Op::SwitchNext { discriminant, cases, default } => {
+                // `_context.next = _context.t0 === a ? 3 : _context.t0 === b ? 5 : 7;`
+                let mut next = create_number(self.loc_value(default), ctx);
+                for (test, loc) in cases.into_iter().rev() {
+                    let test = ctx.ast.expression_binary(
+                        SPAN,
+                        self.temp(discriminant, ctx),
+                        BinaryOperator::StrictEquality,
+                        test,
+                    );
+                    let consequent = create_number(self.loc_value(loc), ctx);
+                    next = ctx.ast.expression_conditional(SPAN, test, consequent, next);
+                }
+                self.create_set_next(next, ctx)
+            }
+            Op::Abrupt(kind, loc) => {
+                // `return _context.abrupt("break", loc);`
+                let arguments = ctx.ast.vec_from_array([
+                    Argument::from(ctx.ast.expression_string_literal(SPAN, kind, None)),
+                    Argument::from(create_number(self.loc_value(loc), ctx)),
+                ]);
+                let abrupt = self.context_call("abrupt", arguments, ctx);
+                ctx.ast.statement_return(SPAN, Some(abrupt))
+            }
+            Op::DelegateYield(argument, temp, loc) => {
+                // `return _context.delegateYield(argument, "t0", loc);`
+                let arguments = ctx.ast.vec_from_array([
+                    Argument::from(argument),
+                    Argument::from(ctx.ast.expression_string_literal(
+                        SPAN,
+                        Self::temp_name(temp, ctx),
+                        None,
+                    )),
+                    Argument::from(create_number(self.loc_value(loc), ctx)),
+                ]);
+                let delegate_yield = self.context_call("delegateYield", arguments, ctx);
+                ctx.ast.statement_return(SPAN, Some(delegate_yield))
+            }
+        }
+    }
+
+    /// `_context.next = value;`
+    fn create_set_next(&self, value: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Statement<'a> {
+        let target = self.context_target("next", ctx);
+        let expr = ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+        ctx.ast.statement_expression(SPAN, expr)
+    }
+
+    /// `[[tryLoc, catchLoc, finallyLoc, afterLoc], ...]`
+    ///
+    /// `catchLoc` is a hole if there is no `catch` clause.
+    /// `finallyLoc` and `afterLoc` are omitted if there is no `finally` block.
+    fn create_try_locs_list(&self, ctx: &TraverseCtx<'a>) -> Option<Expression<'a>> {
+        if self.try_entries.is_empty() {
+            return None;
+        }
+        let entries = ctx.ast.vec_from_iter(self.try_entries.iter().map(|entry| {
+            let mut locs = ctx.ast.vec_with_capacity(4);
+            locs.push(ArrayExpressionElement::from(create_number(entry.first, ctx)));
+            locs.push(match entry.catch {
+                Some(loc) => ArrayExpressionElement::from(create_number(self.loc_value(loc), ctx)),
+                None => ctx.ast.array_expression_element_elision(SPAN),
+            });
+            if let Some(finally_loc) = entry.finally {
+                for loc in [finally_loc, entry.after] {
+                    locs.push(ArrayExpressionElement::from(create_number(
+                        self.loc_value(loc),
+                        ctx,
+                    )));
+                }
+            }
+            ArrayExpressionElement::from(ctx.ast.expression_array(SPAN, locs))
+        }));
+        Some(ctx.ast.expression_array(SPAN, entries))
+    }
+}
+
+/// An expression which can be evaluated multiple times, without side effects.
+enum Reusable<'a> {
+    Identifier(MaybeBoundIdentifier<'a>),
+    This,
+    Literal(Expression<'a>),
+    Temp(u32),
+}
+
+fn create_number<'a>(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    #[expect(clippy::cast_precision_loss)]
+    let value = value as f64;
+    ctx.ast.expression_numeric_literal(SPAN, value, None, NumberBase::Decimal)
+}
+
+/// Replaces references to a `catch` parameter with the temp holding the caught error.
+struct CatchParamReplacer<'a, 'e, 'ctx, 'v> {
+    emitter: &'e Emitter<'a, 'ctx>,
+    symbol_id: SymbolId,
+    temp: u32,
+    ctx: &'v mut TraverseCtx<'a>,
+}
+
+impl<'a> CatchParamReplacer<'a, '_, '_, '_> {
+    fn is_param(&self, ident: &IdentifierReference<'a>) -> bool {
+        self.ctx.scoping().get_reference(ident.reference_id()).symbol_id() == Some(self.symbol_id)
+    }
+}
+
+impl<'a> VisitMut<'a> for CatchParamReplacer<'a, '_, '_, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr {
+            if self.is_param(ident) {
+                self.ctx.delete_reference(ident.reference_id(), &ident.name);
+                *expr = self.emitter.temp(self.temp, self.ctx);
+            }
+            return;
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = target {
+            if self.is_param(ident) {
+                self.ctx.delete_reference(ident.reference_id(), &ident.name);
+                *target =
+                    SimpleAssignmentTarget::from(self.emitter.temp_member(self.temp, self.ctx));
+            }
+            return;
+        }
+        walk_mut::walk_simple_assignment_target(self, target);
+    }
+
+    fn visit_object_property(&mut self, property: &mut ObjectProperty<'a>) {
+        walk_mut::walk_object_property(self, property);
+        if property.shorthand && !matches!(property.value, Expression::Identifier(_)) {
+            property.shorthand = false;
+        }
+    }
+}
+
+/// Visitor which finds `yield` expressions, and optionally statements which jump.
+/// Does not enter nested functions.
+struct LeapFinder {
+    yields_only: bool,
+    found: bool,
+}
+
+impl<'a> Visit<'a> for LeapFinder {
+    fn visit_yield_expression(&mut self, _it: &YieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_break_statement(&mut self, _it: &BreakStatement<'a>) {
+        self.found |= !self.yields_only;
+    }
+
+    fn visit_continue_statement(&mut self, _it: &ContinueStatement<'a>) {
+        self.found |= !self.yields_only;
+    }
+
+    fn visit_return_statement(&mut self, it: &ReturnStatement<'a>) {
+        self.found |= !self.yields_only;
+        oxc_ast_visit::walk::walk_return_statement(self, it);
+    }
+
+    fn visit_throw_statement(&mut self, it: &ThrowStatement<'a>) {
+        self.found |= !self.yields_only;
+        oxc_ast_visit::walk::walk_throw_statement(self, it);
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+}
+
+/// Returns `true` if statement contains `yield`, or `break`, `continue`, `return` or `throw`.
+fn contains_leap(stmt: &Statement) -> bool {
+    let mut finder = LeapFinder { yields_only: false, found: false };
+    finder.visit_statement(stmt);
+    finder.found
+}
+
+/// Returns `true` if expression contains `yield`.
+pub fn contains_yield(expr: &Expression) -> bool {
+    let mut finder = LeapFinder { yields_only: true, found: false };
+    finder.visit_expression(expr);
+    finder.found
+}
+
+fn contains_yield_in_target(target: &AssignmentTarget) -> bool {
+    let mut finder = LeapFinder { yields_only: true, found: false };
+    finder.visit_assignment_target(target);
+    finder.found
+}
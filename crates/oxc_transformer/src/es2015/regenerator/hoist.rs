@@ -0,0 +1,328 @@
+//! Hoisting of declarations out of a generator function's body.
+//!
+//! The body of a generator is split across `case`s of a `switch` inside a new function,
+//! so variables must be declared in the outer function, to retain their values between calls.
+//!
+//! * `var`, `let` and `const` declarations are converted to assignments,
+//!   and their bindings moved to the scope of the outer function.
+//! * Function declarations are moved into the outer function.
+//! * `arguments` is replaced with a reference to `var _args = arguments` in the outer function.
+//! * Use of `this` is recorded, so it can be passed to `regeneratorRuntime().wrap`.
+//!
+//! Nested functions are not entered, except for arrow functions, which inherit `arguments` and
+//! `this` from the generator (but declare their own variables).
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{Box as ArenaBox, CloneIn, TakeIn, Vec as ArenaVec};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{Atom, Span};
+use oxc_traverse::{BoundIdentifier, TraverseCtx};
+
+use crate::{context::TransformCtx, utils::ast_builder::create_assignment_target_from_pattern};
+
+pub struct Hoister<'a, 'ctx, 'v> {
+    ctx: &'ctx TransformCtx<'a>,
+    traverse_ctx: &'v mut TraverseCtx<'a>,
+    /// Scope of generator function
+    scope_id: ScopeId,
+    /// Number of arrow functions the visitor is currently inside
+    arrow_depth: u32,
+    /// Variables to declare with `var` in outer function
+    pub vars: Vec<BindingIdentifier<'a>>,
+    hoisted_symbols: FxHashSet<SymbolId>,
+    /// Function declarations to move into outer function
+    pub functions: Vec<Statement<'a>>,
+    /// Binding for `arguments` of outer function
+    pub args: Option<BoundIdentifier<'a>>,
+    pub uses_this: bool,
+    /// Bindings which have been renamed to avoid clashing with bindings in generator's scope
+    renamed: FxHashMap<SymbolId, Atom<'a>>,
+}
+
+impl<'a, 'ctx, 'v> Hoister<'a, 'ctx, 'v> {
+    pub fn new(
+        scope_id: ScopeId,
+        ctx: &'ctx TransformCtx<'a>,
+        traverse_ctx: &'v mut TraverseCtx<'a>,
+    ) -> Self {
+        Self {
+            ctx,
+            traverse_ctx,
+            scope_id,
+            arrow_depth: 0,
+            vars: vec![],
+            hoisted_symbols: FxHashSet::default(),
+            functions: vec![],
+            args: None,
+            uses_this: false,
+            renamed: FxHashMap::default(),
+        }
+    }
+
+    /// Hoist declarations in `stmts`, and hoisted function declarations.
+    pub fn hoist(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        self.visit_statements(stmts);
+
+        if !self.renamed.is_empty() {
+            let mut renamer = RenameReferences { renamed: &self.renamed, ctx: self.traverse_ctx };
+            renamer.visit_statements(stmts);
+            for func in &mut self.functions {
+                renamer.visit_statement(func);
+            }
+        }
+    }
+
+    /// Move binding to generator's scope, renaming it if its name is already bound there.
+    fn hoist_binding(&mut self, ident: &mut BindingIdentifier<'a>, flags: SymbolFlags) {
+        let symbol_id = ident.symbol_id();
+        let scoping = self.traverse_ctx.scoping_mut();
+        let old_scope_id = scoping.symbol_scope_id(symbol_id);
+        if old_scope_id != self.scope_id {
+            if scoping.get_binding(self.scope_id, &ident.name).is_some_and(|id| id != symbol_id) {
+                let new_name = self.traverse_ctx.generate_uid_name(&ident.name);
+                self.traverse_ctx.rename_symbol(symbol_id, old_scope_id, new_name.clone());
+                ident.name = self.traverse_ctx.ast.atom(&new_name);
+                self.renamed.insert(symbol_id, ident.name);
+            }
+            let scoping = self.traverse_ctx.scoping_mut();
+            scoping.move_binding(old_scope_id, self.scope_id, &ident.name);
+            scoping.set_symbol_scope_id(symbol_id, self.scope_id);
+        }
+        *self.traverse_ctx.scoping_mut().symbol_flags_mut(symbol_id) = flags;
+
+        if flags == SymbolFlags::FunctionScopedVariable && self.hoisted_symbols.insert(symbol_id) {
+            self.vars.push(BindingIdentifier::clone_in_with_semantic_ids(
+                ident,
+                self.traverse_ctx.ast.allocator,
+            ));
+        }
+    }
+
+    fn hoist_pattern(&mut self, pattern: &mut BindingPattern<'a>) {
+        match &mut pattern.kind {
+            BindingPatternKind::BindingIdentifier(ident) => {
+                self.hoist_binding(ident, SymbolFlags::FunctionScopedVariable);
+            }
+            BindingPatternKind::ObjectPattern(object) => {
+                for property in &mut object.properties {
+                    self.hoist_pattern(&mut property.value);
+                }
+                if let Some(rest) = &mut object.rest {
+                    self.hoist_pattern(&mut rest.argument);
+                }
+            }
+            BindingPatternKind::ArrayPattern(array) => {
+                for element in array.elements.iter_mut().flatten() {
+                    self.hoist_pattern(element);
+                }
+                if let Some(rest) = &mut array.rest {
+                    self.hoist_pattern(&mut rest.argument);
+                }
+            }
+            BindingPatternKind::AssignmentPattern(assign) => self.hoist_pattern(&mut assign.left),
+        }
+    }
+
+    /// Convert variable declaration to an expression assigning the initializers.
+    ///
+    /// `var a = 1, b, c = 2` -> `a = 1, c = 2`
+    ///
+    /// Returns `None` if no declarators have an initializer.
+    fn hoist_declaration(&mut self, decl: &mut VariableDeclaration<'a>) -> Option<Expression<'a>> {
+        walk_mut::walk_variable_declaration(self, decl);
+
+        let declarations = decl.declarations.take_in(self.traverse_ctx.ast.allocator);
+        let mut assignments = self.traverse_ctx.ast.vec();
+        for mut declarator in declarations {
+            self.hoist_pattern(&mut declarator.id);
+            let span = declarator.span;
+            let Some(init) = declarator.init else { continue };
+            let target = create_assignment_target_from_pattern(declarator.id, self.traverse_ctx);
+            assignments.push(self.traverse_ctx.ast.expression_assignment(
+                span,
+                AssignmentOperator::Assign,
+                target,
+                init,
+            ));
+        }
+
+        match assignments.len() {
+            0 => None,
+            1 => assignments.pop(),
+            _ => Some(self.traverse_ctx.ast.expression_sequence(decl.span, assignments)),
+        }
+    }
+
+    fn hoist_function(&mut self, mut func: ArenaBox<'a, Function<'a>>) {
+        if let Some(id) = &mut func.id {
+            self.hoist_binding(id, SymbolFlags::Function);
+        }
+        let scope_id = func.scope_id();
+        let scoping = self.traverse_ctx.scoping_mut();
+        if scoping.scope_parent_id(scope_id) != Some(self.scope_id) {
+            scoping.change_scope_parent_id(scope_id, Some(self.scope_id));
+        }
+        self.functions.push(Statement::FunctionDeclaration(func));
+    }
+
+    fn create_args_reference(&mut self, span: Span) -> Expression<'a> {
+        let args = self.args.get_or_insert_with(|| {
+            self.traverse_ctx.generate_uid(
+                "args",
+                self.scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            )
+        });
+        args.create_spanned_read_expression(span, self.traverse_ctx)
+    }
+}
+
+impl<'a> VisitMut<'a> for Hoister<'a, '_, '_> {
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        let old_stmts = stmts.take_in(self.traverse_ctx.ast.allocator);
+        for mut stmt in old_stmts {
+            match stmt {
+                Statement::FunctionDeclaration(func) if self.arrow_depth == 0 => {
+                    self.hoist_function(func);
+                }
+                Statement::VariableDeclaration(_) if self.arrow_depth == 0 => {
+                    self.visit_statement(&mut stmt);
+                    if !matches!(stmt, Statement::EmptyStatement(_)) {
+                        stmts.push(stmt);
+                    }
+                }
+                _ => {
+                    self.visit_statement(&mut stmt);
+                    stmts.push(stmt);
+                }
+            }
+        }
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        if self.arrow_depth == 0 {
+            if let Statement::VariableDeclaration(decl) = stmt {
+                let span = decl.span;
+                *stmt = match self.hoist_declaration(decl) {
+                    Some(expr) => self.traverse_ctx.ast.statement_expression(span, expr),
+                    None => self.traverse_ctx.ast.statement_empty(span),
+                };
+                return;
+            }
+        }
+        walk_mut::walk_statement(self, stmt);
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        if self.arrow_depth == 0 {
+            if let Some(ForStatementInit::VariableDeclaration(decl)) = &mut stmt.init {
+                stmt.init = self.hoist_declaration(decl).map(ForStatementInit::from);
+            }
+        }
+        walk_mut::walk_for_statement(self, stmt);
+    }
+
+    fn visit_for_statement_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        if self.arrow_depth == 0 {
+            if let ForStatementLeft::VariableDeclaration(decl) = left {
+                walk_mut::walk_variable_declaration(self, decl);
+                let mut declarator = decl.declarations.pop().unwrap();
+                self.hoist_pattern(&mut declarator.id);
+                *left = ForStatementLeft::from(create_assignment_target_from_pattern(
+                    declarator.id,
+                    self.traverse_ctx,
+                ));
+                return;
+            }
+        }
+        walk_mut::walk_for_statement_left(self, left);
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr {
+            if ident.name == "arguments"
+                && self
+                    .traverse_ctx
+                    .scoping()
+                    .get_reference(ident.reference_id())
+                    .symbol_id()
+                    .is_none()
+            {
+                let span = ident.span;
+                self.traverse_ctx
+                    .scoping_mut()
+                    .delete_root_unresolved_reference(&ident.name, ident.reference_id());
+                *expr = self.create_args_reference(span);
+            }
+            return;
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_this_expression(&mut self, _this: &mut ThisExpression) {
+        self.uses_this = true;
+    }
+
+    fn visit_super(&mut self, sup: &mut Super) {
+        self.ctx.error(
+            OxcDiagnostic::error("`super` is not supported in generator functions.")
+                .with_label(sup.span),
+        );
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {
+        // Nested functions have their own `arguments` and `this`, and declare their own variables
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.arrow_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.arrow_depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        // Only `extends` clause and computed keys are evaluated in the generator's scope.
+        // Methods are functions, so are not entered.
+        if let Some(super_class) = &mut class.super_class {
+            self.visit_expression(super_class);
+        }
+        for element in &mut class.body.body {
+            let key = match element {
+                ClassElement::MethodDefinition(method) if method.computed => &mut method.key,
+                ClassElement::PropertyDefinition(prop) if prop.computed => &mut prop.key,
+                ClassElement::AccessorProperty(prop) if prop.computed => &mut prop.key,
+                _ => continue,
+            };
+            if let Some(key) = key.as_expression_mut() {
+                self.visit_expression(key);
+            }
+        }
+    }
+}
+
+/// Visitor which applies renaming of hoisted bindings to references.
+struct RenameReferences<'a, 'r, 'v> {
+    renamed: &'r FxHashMap<SymbolId, Atom<'a>>,
+    ctx: &'v TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for RenameReferences<'a, '_, '_> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference = self.ctx.scoping().get_reference(ident.reference_id());
+        if let Some(symbol_id) = reference.symbol_id() {
+            if let Some(&name) = self.renamed.get(&symbol_id) {
+                ident.name = name;
+            }
+        }
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(&name) = self.renamed.get(&ident.symbol_id()) {
+            ident.name = name;
+        }
+    }
+}
@@ -0,0 +1,537 @@
+//! ES2015: Regenerator
+//!
+//! This plugin compiles generator functions into ES5 functions which drive a state machine,
+//! using the `regeneratorRuntime` helper.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function* gen(x) {
+//!   const y = yield x;
+//!   return y + 1;
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _marked = /*#__PURE__*/ babelHelpers.regeneratorRuntime().mark(gen);
+//! function gen(x) {
+//!   var y;
+//!   return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+//!     while (1) switch (_context.prev = _context.next) {
+//!       case 0:
+//!         _context.next = 2;
+//!         return x;
+//!       case 2:
+//!         y = _context.sent;
+//!         return _context.abrupt("return", y + 1);
+//!       case 4:
+//!       case "end":
+//!         return _context.stop();
+//!     }
+//!   }, _marked);
+//! }
+//! ```
+//!
+//! Async functions and async generator functions are compiled too, after they have been converted
+//! to generators by `async-to-generator` and `async-generator-functions` plugins.
+//!
+//! ## Options
+//!
+//! ### `generators`
+//!
+//! Compile generator functions. Defaults to `true`.
+//!
+//! ### `async`
+//!
+//! Compile the generators produced by `async-to-generator` plugin. Defaults to `true`.
+//!
+//! ### `asyncGenerators`
+//!
+//! Compile the generators produced by `async-generator-functions` plugin. Defaults to `true`.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-regenerator](https://babel.dev/docs/babel-plugin-transform-regenerator)
+//! and [regenerator-transform](https://github.com/facebook/regenerator/tree/v0.15.2/packages/transform).
+//!
+//! Generators are transformed on exit, so nested functions have already been transformed.
+//!
+//! 1. Declarations in the generator's body are hoisted to the generator function ([`hoist`]).
+//! 2. The body is "exploded" into `case`s of a `switch` in a new inner function ([`emit`]).
+//! 3. The generator function's body is replaced with a call to `regeneratorRuntime().wrap`,
+//!    passing it the inner function.
+//!
+//! The generator function keeps its scope. The inner function and the `switch` statement get new
+//! scopes. Scopes of blocks and loops which are flattened into the `switch` are removed,
+//! and scopes of nested functions etc are moved into the scope of the `switch`.
+//!
+//! Unlike Babel, function declarations in the generator are kept in the outer function
+//! as declarations, and generator methods do not pass the method to `wrap`.
+//!
+//! The runtime is loaded via the `regeneratorRuntime` helper, so it can be inlined into the file,
+//! imported from `@oxc-project/runtime`, or read from `babelHelpers` global,
+//! depending on [`HelperLoaderMode`](crate::HelperLoaderMode).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-regenerator>
+//! * regenerator-transform: <https://github.com/facebook/regenerator/tree/v0.15.2/packages/transform>
+//! * Generator function definitions specification: <https://tc39.es/ecma262/#sec-generator-function-definitions>
+
+use std::cell::Cell;
+
+use serde::Deserialize;
+
+use oxc_allocator::{Box as ArenaBox, TakeIn};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::Visit;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::number::NumberBase;
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, context::TransformCtx};
+
+mod emit;
+mod hoist;
+
+use emit::{Emitted, Emitter};
+use hoist::Hoister;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct RegeneratorOptions {
+    pub generators: bool,
+    #[serde(rename = "async")]
+    pub async_functions: bool,
+    pub async_generators: bool,
+}
+
+impl Default for RegeneratorOptions {
+    fn default() -> Self {
+        Self { generators: true, async_functions: true, async_generators: true }
+    }
+}
+
+pub struct Regenerator<'a, 'ctx> {
+    options: RegeneratorOptions,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Regenerator<'a, 'ctx> {
+    pub fn new(options: RegeneratorOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx }
+    }
+}
+
+impl<'a> Traverse<'a> for Regenerator<'a, '_> {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::FunctionExpression(func) = expr else { return };
+        if !self.is_transformable(func) {
+            return;
+        }
+
+        // `{ *foo() {} }` -> `{ foo() { return regeneratorRuntime().wrap(...) } }`
+        if let Ancestor::ObjectPropertyValue(prop) = ctx.parent() {
+            if *prop.method() {
+                self.transform(func, None, ctx);
+                return;
+            }
+        }
+
+        let Expression::FunctionExpression(func) = expr.take_in(ctx.ast.allocator) else {
+            unreachable!()
+        };
+        *expr = self.transform_function_expression(func, ctx);
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.is_transformable(func) {
+            return;
+        }
+
+        if func.is_declaration() {
+            self.transform_function_declaration(func, ctx);
+        } else if matches!(ctx.parent(), Ancestor::MethodDefinitionValue(_)) {
+            // `class A { *foo() {} }` -> `class A { foo() { return regeneratorRuntime().wrap(...) } }`
+            self.transform(func, None, ctx);
+        }
+        // Function expressions are transformed in `exit_expression`, so they can be wrapped in `mark()`
+    }
+}
+
+impl<'a> Regenerator<'a, '_> {
+    fn is_transformable(&self, func: &Function<'a>) -> bool {
+        self.options.generators
+            && func.generator
+            && !func.r#async
+            && func.body.is_some()
+            && !func.is_typescript_syntax()
+    }
+
+    /// Transform a generator function declaration.
+    ///
+    /// ```js
+    /// function* gen() {}
+    /// ```
+    /// ->
+    /// ```js
+    /// var _marked = /*#__PURE__*/ regeneratorRuntime().mark(gen);
+    /// function gen() {
+    ///   return regeneratorRuntime().wrap(function gen$(_context) { /* ... */ }, _marked);
+    /// }
+    /// ```
+    fn transform_function_declaration(&self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        // `export default function* () {}` -> `export default function _callee() {}`
+        let id = func.id.get_or_insert_with(|| {
+            ctx.generate_uid_in_current_scope("callee", SymbolFlags::Function)
+                .create_binding_identifier(ctx)
+        });
+        let func_ref = BoundIdentifier::from_binding_ident(id).create_read_expression(ctx);
+
+        let mark = self.create_mark_call(func_ref, ctx);
+        let marked = self.ctx.var_declarations.create_uid_var_with_init("marked", mark, ctx);
+
+        let outer_fn = marked.create_read_expression(ctx);
+        self.transform(func, Some(outer_fn), ctx);
+    }
+
+    /// Transform a generator function expression.
+    ///
+    /// ```js
+    /// (function* () {})
+    /// ```
+    /// ->
+    /// ```js
+    /// (/*#__PURE__*/ regeneratorRuntime().mark(function _callee() {
+    ///   return regeneratorRuntime().wrap(function _callee$(_context) { /* ... */ }, _callee);
+    /// }))
+    /// ```
+    ///
+    /// Also used by `async-to-generator` and `async-generator-functions` plugins to compile
+    /// the generator functions they create.
+    pub fn transform_function_expression(
+        &self,
+        mut func: ArenaBox<'a, Function<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let scope_id = func.scope_id();
+        let id = func.id.get_or_insert_with(|| {
+            ctx.generate_uid("callee", scope_id, SymbolFlags::Function)
+                .create_binding_identifier(ctx)
+        });
+        let outer_fn = BoundIdentifier::from_binding_ident(id).create_read_expression(ctx);
+
+        self.transform(&mut func, Some(outer_fn), ctx);
+
+        self.create_mark_call(Expression::FunctionExpression(func), ctx)
+    }
+
+    /// Replace body of generator function with a call to `regeneratorRuntime().wrap`,
+    /// and convert it to a normal function.
+    ///
+    /// `outer_fn` is the function passed to `wrap`, whose `prototype` the generator object inherits
+    /// from. `None` for methods.
+    fn transform(
+        &self,
+        func: &mut Function<'a>,
+        outer_fn: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let scope_id = func.scope_id();
+        let inner_name = match &func.id {
+            Some(id) => format!("{}$", id.name),
+            None => format!("{}$", ctx.generate_uid_name("callee")),
+        };
+        let body = func.body.as_mut().unwrap();
+        let mut stmts = body.statements.take_in(ctx.ast.allocator);
+
+        // Hoist declarations to generator function
+        let mut hoister = Hoister::new(scope_id, self.ctx, ctx);
+        hoister.hoist(&mut stmts);
+        let vars = std::mem::take(&mut hoister.vars);
+        let functions = std::mem::take(&mut hoister.functions);
+        let args = hoister.args.take();
+        let uses_this = hoister.uses_this;
+
+        // Explode body into `case`s of `switch` in inner function
+        let inner_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::Function);
+        let switch_scope_id = ctx.create_child_scope(inner_scope_id, ScopeFlags::empty());
+        let inner_binding =
+            ctx.generate_binding(ctx.ast.atom(&inner_name), inner_scope_id, SymbolFlags::Function);
+        let context =
+            ctx.generate_uid("context", inner_scope_id, SymbolFlags::FunctionScopedVariable);
+
+        let mut emitter = Emitter::new(context.clone(), switch_scope_id, self.ctx);
+        for stmt in stmts {
+            emitter.explode_statement(stmt, None, ctx);
+        }
+        let Emitted { cases, try_locs_list, removed_scopes, dead_statements } = emitter.finish(ctx);
+
+        Self::update_scopes(&cases, &removed_scopes, &dead_statements, switch_scope_id, ctx);
+
+        // `function gen$(_context) { while (1) switch (_context.prev = _context.next) { ... } }`
+        let discriminant = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(Self::create_context_member(&context, "prev", ctx)),
+            Expression::from(Self::create_context_member(&context, "next", ctx)),
+        );
+        let switch =
+            ctx.ast.statement_switch_with_scope_id(SPAN, discriminant, cases, switch_scope_id);
+        let one = ctx.ast.expression_numeric_literal(SPAN, 1.0, None, NumberBase::Decimal);
+        let inner_body = ctx.ast.alloc_function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_while(SPAN, one, switch)),
+        );
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec1(ctx.ast.formal_parameter(
+                SPAN,
+                ctx.ast.vec(),
+                context.create_binding_pattern(ctx),
+                None,
+                false,
+                false,
+            )),
+            NONE,
+        );
+        let inner = ctx.ast.expression_function_with_scope_id_and_pure(
+            SPAN,
+            FunctionType::FunctionExpression,
+            Some(inner_binding.create_binding_identifier(ctx)),
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(inner_body),
+            inner_scope_id,
+            false,
+        );
+
+        // `regeneratorRuntime().wrap(gen$, outerFn, this, tryLocsList)`.
+        // Trailing `null`s are omitted.
+        let mut wrap_arguments = ctx.ast.vec1(Argument::from(inner));
+        let this_arg = uses_this.then(|| ctx.ast.expression_this(SPAN));
+        let trailing = [outer_fn, this_arg, try_locs_list];
+        let len = trailing.iter().rposition(Option::is_some).map_or(0, |index| index + 1);
+        for arg in trailing.into_iter().take(len) {
+            let arg = arg.unwrap_or_else(|| ctx.ast.expression_null_literal(SPAN));
+            wrap_arguments.push(Argument::from(arg));
+        }
+        let wrap = create_runtime_method_call("wrap", wrap_arguments, self.ctx, ctx);
+
+        // `var x, y, _args = arguments;`
+        let mut declarations = ctx.ast.vec_from_iter(vars.into_iter().map(|id| {
+            Self::create_var_declarator(
+                ctx.ast.binding_pattern(
+                    BindingPatternKind::BindingIdentifier(ctx.ast.alloc(id)),
+                    NONE,
+                    false,
+                ),
+                None,
+                ctx,
+            )
+        }));
+        if let Some(args) = args {
+            let symbol_id = ctx.scoping().find_binding(scope_id, "arguments");
+            let arguments = ctx.create_ident_expr(
+                SPAN,
+                Atom::from("arguments"),
+                symbol_id,
+                ReferenceFlags::Read,
+            );
+            declarations.push(Self::create_var_declarator(
+                args.create_binding_pattern(ctx),
+                Some(arguments),
+                ctx,
+            ));
+        }
+
+        let body = func.body.as_mut().unwrap();
+        if !declarations.is_empty() {
+            body.statements.push(Statement::VariableDeclaration(
+                ctx.ast.alloc_variable_declaration(
+                    SPAN,
+                    VariableDeclarationKind::Var,
+                    declarations,
+                    false,
+                ),
+            ));
+        }
+        body.statements.extend(functions);
+        body.statements.push(ctx.ast.statement_return(SPAN, Some(wrap)));
+
+        func.generator = false;
+    }
+
+    /// Move scopes in the `switch` statement to be children of the `switch`'s scope,
+    /// and remove scopes of blocks which were flattened, and of unreachable code.
+    fn update_scopes(
+        cases: &[SwitchCase<'a>],
+        removed_scopes: &[ScopeId],
+        dead_statements: &[Statement<'a>],
+        switch_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut mover = ScopeMover { switch_scope_id, depth: 0, ctx };
+        for case in cases {
+            mover.visit_switch_case(case);
+        }
+
+        let mut remover = DeadCodeRemover { depth: 0, ctx };
+        for stmt in dead_statements {
+            remover.visit_statement(stmt);
+        }
+
+        let scoping = ctx.scoping_mut();
+        for &scope_id in removed_scopes {
+            // Any remaining bindings (e.g. `class` declarations in blocks) are now in `switch`
+            let names = scoping
+                .get_bindings(scope_id)
+                .keys()
+                .map(|name| (*name).to_string())
+                .collect::<Vec<_>>();
+            for name in names {
+                let symbol_id = scoping.get_binding(scope_id, &name).unwrap();
+                scoping.move_binding(scope_id, switch_scope_id, &name);
+                scoping.set_symbol_scope_id(symbol_id, switch_scope_id);
+            }
+            scoping.delete_scope(scope_id);
+        }
+    }
+
+    /// `/*#__PURE__*/ regeneratorRuntime().mark(func)`
+    fn create_mark_call(&self, func: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let callee = create_runtime_method(self.ctx, "mark", ctx);
+        ctx.ast.expression_call_with_pure(
+            SPAN,
+            callee,
+            NONE,
+            ctx.ast.vec1(Argument::from(func)),
+            false,
+            true,
+        )
+    }
+
+    /// `_context.name`
+    fn create_context_member(
+        context: &BoundIdentifier<'a>,
+        name: &'static str,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> MemberExpression<'a> {
+        ctx.ast.member_expression_static(
+            SPAN,
+            context.create_read_expression(ctx),
+            ctx.ast.identifier_name(SPAN, name),
+            false,
+        )
+    }
+
+    fn create_var_declarator(
+        id: BindingPattern<'a>,
+        init: Option<Expression<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> VariableDeclarator<'a> {
+        ctx.ast.variable_declarator(SPAN, VariableDeclarationKind::Var, id, init, false)
+    }
+}
+
+/// `regeneratorRuntime().name`
+fn create_runtime_method<'a>(
+    transform_ctx: &TransformCtx<'a>,
+    name: &'static str,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let runtime =
+        transform_ctx.helper_call_expr(Helper::RegeneratorRuntime, SPAN, ctx.ast.vec(), ctx);
+    Expression::from(ctx.ast.member_expression_static(
+        SPAN,
+        runtime,
+        ctx.ast.identifier_name(SPAN, name),
+        false,
+    ))
+}
+
+/// `regeneratorRuntime().name(...arguments)`
+fn create_runtime_method_call<'a>(
+    name: &'static str,
+    arguments: oxc_allocator::Vec<'a, Argument<'a>>,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let callee = create_runtime_method(transform_ctx, name, ctx);
+    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+}
+
+/// Visitor which sets parent of top-level scopes in the `switch` to the `switch`'s scope,
+/// and moves bindings of `class` declarations into the `switch`'s scope.
+struct ScopeMover<'a, 'v> {
+    switch_scope_id: ScopeId,
+    depth: u32,
+    ctx: &'v mut TraverseCtx<'a>,
+}
+
+impl<'a> Visit<'a> for ScopeMover<'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            let scope_id = scope_id.get().unwrap();
+            if self.ctx.scoping().scope_parent_id(scope_id) != Some(self.switch_scope_id) {
+                self.ctx.scoping_mut().change_scope_parent_id(scope_id, Some(self.switch_scope_id));
+            }
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        if self.depth == 0 {
+            if let Statement::ClassDeclaration(class) = stmt {
+                if let Some(id) = &class.id {
+                    let symbol_id = id.symbol_id();
+                    let scoping = self.ctx.scoping_mut();
+                    let scope_id = scoping.symbol_scope_id(symbol_id);
+                    if scope_id != self.switch_scope_id {
+                        scoping.move_binding(scope_id, self.switch_scope_id, &id.name);
+                        scoping.set_symbol_scope_id(symbol_id, self.switch_scope_id);
+                    }
+                }
+            }
+        }
+        oxc_ast_visit::walk::walk_statement(self, stmt);
+    }
+}
+
+/// Visitor which deletes references and top-level scopes in unreachable code which was removed.
+struct DeadCodeRemover<'a, 'v> {
+    depth: u32,
+    ctx: &'v mut TraverseCtx<'a>,
+}
+
+impl<'a> Visit<'a> for DeadCodeRemover<'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            self.ctx.scoping_mut().delete_scope(scope_id.get().unwrap());
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.ctx.delete_reference_for_identifier(ident);
+    }
+}
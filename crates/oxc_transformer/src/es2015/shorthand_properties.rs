@@ -0,0 +1,109 @@
+//! ES2015: Shorthand Properties
+//!
+//! This plugin transforms shorthand properties and methods in object literals.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const obj = {
+//!   a,
+//!   b() {},
+//!   __proto__,
+//! };
+//! ```
+//!
+//! Output:
+//! ```js
+//! const obj = {
+//!   a: a,
+//!   b: function() {},
+//!   ["__proto__"]: __proto__,
+//! };
+//! ```
+//!
+//! `__proto__` keys are made computed, as `__proto__: value` sets the object's prototype,
+//! whereas a shorthand property or method defines an own property named `__proto__`.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-shorthand-properties](https://babel.dev/docs/babel-plugin-transform-shorthand-properties).
+//!
+//! Methods which use `super` are not transformed, as `super` is not valid in a function expression.
+//! `super` is removed first by [`ObjectSuper`](super::ObjectSuper) transform, if it's enabled.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-shorthand-properties>
+//! * Object initializer specification: <https://tc39.es/ecma262/#sec-object-initializer>
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::Visit;
+use oxc_semantic::ScopeFlags;
+use oxc_span::SPAN;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+pub struct ShorthandProperties;
+
+impl<'a> Traverse<'a> for ShorthandProperties {
+    // `{ a }` -> `{ a: a }`
+    // `{ a() {} }` -> `{ a: function() {} }`
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        if prop.shorthand {
+            prop.shorthand = false;
+        } else if prop.method && !property_value_uses_super(&prop.value) {
+            prop.method = false;
+        } else {
+            return;
+        }
+
+        if !prop.computed && prop.key.is_specific_static_name("__proto__") {
+            let key = ctx.ast.expression_string_literal(SPAN, "__proto__", None);
+            prop.key = PropertyKey::from(key);
+            prop.computed = true;
+        }
+    }
+}
+
+/// Returns `true` if the value of an object method uses `super`, so can't be moved out of
+/// the object literal, or converted to a function expression.
+///
+/// `super` inside nested functions, or inside class bodies (except computed keys and
+/// `extends` clause), refers to a different home object, so is ignored.
+pub fn property_value_uses_super(value: &Expression<'_>) -> bool {
+    let Expression::FunctionExpression(func) = value else { return false };
+    let mut finder = SuperFinder { found: false };
+    if let Some(body) = &func.body {
+        finder.visit_function_body(body);
+    }
+    if !finder.found {
+        finder.visit_formal_parameters(&func.params);
+    }
+    finder.found
+}
+
+struct SuperFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for SuperFinder {
+    fn visit_super(&mut self, _it: &Super) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        if let Some(super_class) = &class.super_class {
+            self.visit_expression(super_class);
+        }
+        for element in &class.body.body {
+            if element.computed() {
+                if let Some(key) = element.property_key() {
+                    self.visit_property_key(key);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,369 @@
+//! ES2015: Spread
+//!
+//! This plugin transforms spread elements in array literals, call arguments and `new` arguments.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const arr = [a, ...b, c];
+//! foo(...arr);
+//! obj.foo(x, ...arr);
+//! new Foo(...arr);
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _obj;
+//! const arr = [a].concat(babelHelpers.toConsumableArray(b), [c]);
+//! foo.apply(void 0, babelHelpers.toConsumableArray(arr));
+//! (_obj = obj).foo.apply(_obj, [x].concat(babelHelpers.toConsumableArray(arr)));
+//! babelHelpers.construct(Foo, babelHelpers.toConsumableArray(arr));
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! Enables the `iterableIsArray` assumption.
+//!
+//! ### `allowArrayLike`
+//!
+//! Enables the `arrayLikeIsIterable` assumption.
+//!
+//! ### `iterableIsArray` assumption
+//!
+//! All spread values are assumed to be arrays, so they are passed to `concat` / `apply` as is.
+//!
+//! ```js
+//! const arr = [a].concat(b, [c]);
+//! foo.apply(void 0, arr);
+//! ```
+//!
+//! ### `arrayLikeIsIterable` assumption
+//!
+//! Array-like objects which are not iterable (e.g. `NodeList` in old browsers) can be spread.
+//!
+//! ```js
+//! const arr = [a].concat(babelHelpers.maybeArrayLike(babelHelpers.toConsumableArray, b), [c]);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-spread](https://babel.dev/docs/babel-plugin-transform-spread).
+//!
+//! Spread arguments of `super()` calls are left for the classes plugin, which must be enabled too.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-spread>
+//! * Spread syntax specification: <https://tc39.es/ecma262/#prod-SpreadElement>
+
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::ReferenceFlags;
+use oxc_span::{Atom, SPAN};
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::{Helper, context::TransformCtx, utils::ast_builder::create_member_callee};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SpreadOptions {
+    pub loose: bool,
+
+    pub allow_array_like: bool,
+}
+
+pub struct Spread<'a, 'ctx> {
+    options: SpreadOptions,
+    /// If `true`, `super(...args)` is transformed by classes plugin.
+    classes_enabled: bool,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Spread<'a, 'ctx> {
+    pub fn new(options: SpreadOptions, classes_enabled: bool, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, classes_enabled, ctx }
+    }
+}
+
+impl<'a> Traverse<'a> for Spread<'a, '_> {
+    // `[...a]`, `foo(...a)`, `new Foo(...a)`
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::ArrayExpression(_) => {
+                Self::transform_array_expression(self.options, expr, self.ctx, ctx);
+            }
+            Expression::CallExpression(_) => self.transform_call_expression(expr, ctx),
+            Expression::NewExpression(_) => self.transform_new_expression(expr, ctx),
+            _ => {}
+        }
+    }
+}
+
+impl<'a, 'ctx> Spread<'a, 'ctx> {
+    /// Transform `[a, ...b, c]` to `[a].concat(_toConsumableArray(b), [c])`.
+    ///
+    /// `pub` for `super(...args)` in classes plugin.
+    pub fn transform_array_expression(
+        options: SpreadOptions,
+        expr: &mut Expression<'a>,
+        transform_ctx: &'ctx TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::ArrayExpression(array) = expr else { unreachable!() };
+        if !array.elements.iter().any(ArrayExpressionElement::is_spread) {
+            return;
+        }
+
+        let span = array.span;
+        let elements = array.elements.take_in(ctx.ast.allocator);
+        let mut nodes = Self::build(options, elements, transform_ctx, ctx);
+
+        // If there is only a single spread, and it's been converted to a new array already,
+        // no need to copy it again with `concat`.
+        // `[...a]` -> `_toConsumableArray(a)`
+        if nodes.len() == 1 && nodes[0].is_new_array {
+            *expr = nodes.pop().unwrap().expr;
+            return;
+        }
+
+        // Call `concat` on the first array literal if there is one.
+        // Otherwise call on an empty array, as the first value may not be a real array.
+        // `[a, ...b]` -> `[a].concat(b)`
+        // `[...b, a]` -> `[].concat(b, [a])`
+        let first = if matches!(nodes[0].expr, Expression::ArrayExpression(_)) {
+            nodes.remove(0).expr
+        } else {
+            ctx.ast.expression_array(SPAN, ctx.ast.vec())
+        };
+        let callee = create_member_callee(first, "concat", ctx);
+        let arguments = ctx.ast.vec_from_iter(nodes.into_iter().map(|n| Argument::from(n.expr)));
+        *expr = ctx.ast.expression_call(span, callee, NONE, arguments, false);
+    }
+
+    /// Transform `foo(...a)` to `foo.apply(void 0, _toConsumableArray(a))`,
+    /// and `obj.foo(...a)` to `obj.foo.apply(obj, _toConsumableArray(a))`.
+    fn transform_call_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::CallExpression(call) = expr else { unreachable!() };
+        if !call.arguments.iter().any(Argument::is_spread) {
+            return;
+        }
+
+        if matches!(call.callee.without_parentheses(), Expression::Super(_)) {
+            if !self.classes_enabled {
+                self.ctx.error(OxcDiagnostic::error(
+                    "It's not possible to compile spread arguments in `super()` without compiling classes.\nPlease enable the `transform-classes` plugin.",
+                ).with_label(call.span));
+            }
+            return;
+        }
+
+        let arguments = call.arguments.take_in(ctx.ast.allocator);
+        let arguments = self.build_arguments(arguments, ctx);
+
+        // `obj.foo(...a)` -> `obj.foo.apply(obj, a)`
+        // `obj.foo.bar(...a)` -> `(_obj$foo = obj.foo).bar.apply(_obj$foo, a)`
+        let this_arg = if let Some(member) =
+            call.callee.without_parentheses_mut().as_member_expression_mut()
+        {
+            let object = member.object_mut();
+            if matches!(object, Expression::Super(_)) {
+                ctx.ast.expression_this(SPAN)
+            } else {
+                let (assignment, reference) =
+                    self.ctx.duplicate_expression(object.take_in(ctx.ast.allocator), true, ctx);
+                *object = assignment;
+                reference
+            }
+        } else {
+            ctx.ast.void_0(SPAN)
+        };
+
+        let callee = call.callee.take_in(ctx.ast.allocator);
+        call.callee = create_member_callee(callee, "apply", ctx);
+        call.arguments =
+            ctx.ast.vec_from_array([Argument::from(this_arg), Argument::from(arguments)]);
+    }
+
+    /// Transform `new Foo(...a)` to `_construct(Foo, _toConsumableArray(a))`.
+    fn transform_new_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::NewExpression(new_expr) = expr else { unreachable!() };
+        if !new_expr.arguments.iter().any(Argument::is_spread) {
+            return;
+        }
+
+        let span = new_expr.span;
+        let callee = new_expr.callee.take_in(ctx.ast.allocator);
+        let elements = new_expr.arguments.drain(..).map(ArrayExpressionElement::from);
+        let elements = ctx.ast.vec_from_iter(elements);
+        let arguments = self.concat_nodes(elements, ctx);
+        let arguments = ctx.ast.vec_from_array([Argument::from(callee), Argument::from(arguments)]);
+        *expr = self.ctx.helper_call_expr(Helper::Construct, span, arguments, ctx);
+    }
+
+    /// Build the array of arguments passed to `apply`.
+    ///
+    /// `foo(...arguments)` -> `arguments`
+    /// `foo(a, ...b)` -> `[a].concat(b)`
+    fn build_arguments(
+        &self,
+        mut arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if arguments.len() == 1 {
+            if let Argument::SpreadElement(spread) = &arguments[0] {
+                if is_arguments_identifier(&spread.argument) {
+                    let Some(Argument::SpreadElement(spread)) = arguments.pop() else {
+                        unreachable!()
+                    };
+                    return spread.unbox().argument;
+                }
+            }
+        }
+        let elements =
+            ctx.ast.vec_from_iter(arguments.into_iter().map(ArrayExpressionElement::from));
+        self.concat_nodes(elements, ctx)
+    }
+
+    /// `a, ...b, c` -> `[a].concat(b, [c])`
+    fn concat_nodes(
+        &self,
+        elements: ArenaVec<'a, ArrayExpressionElement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut nodes = Self::build(self.options, elements, self.ctx, ctx).into_iter();
+        let first = nodes.next().unwrap().expr;
+        if nodes.len() == 0 {
+            return first;
+        }
+        let callee = create_member_callee(first, "concat", ctx);
+        let arguments = ctx.ast.vec_from_iter(nodes.map(|n| Argument::from(n.expr)));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// Split elements into groups of array literals and spread values.
+    ///
+    /// `a, b, ...c, d` -> `[a, b]`, `_toConsumableArray(c)`, `[d]`
+    fn build(
+        options: SpreadOptions,
+        elements: ArenaVec<'a, ArrayExpressionElement<'a>>,
+        transform_ctx: &'ctx TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<SpreadNode<'a>> {
+        let iterable_is_array = options.loose || transform_ctx.assumptions.iterable_is_array;
+        let array_like_is_iterable =
+            options.allow_array_like || transform_ctx.assumptions.array_like_is_iterable;
+
+        let mut nodes = vec![];
+        let mut props = ctx.ast.vec();
+        for element in elements {
+            let ArrayExpressionElement::SpreadElement(spread) = element else {
+                props.push(element);
+                continue;
+            };
+            if !props.is_empty() {
+                let array = ctx.ast.expression_array(SPAN, props);
+                nodes.push(SpreadNode { expr: array, is_new_array: false });
+                props = ctx.ast.vec();
+            }
+            let argument = spread.unbox().argument;
+            if iterable_is_array && !is_arguments_identifier(&argument) {
+                nodes.push(SpreadNode { expr: argument, is_new_array: false });
+            } else {
+                let (expr, is_new_array) = to_array(
+                    argument,
+                    ToArrayKind::Spread,
+                    array_like_is_iterable,
+                    transform_ctx,
+                    ctx,
+                );
+                nodes.push(SpreadNode { expr, is_new_array });
+            }
+        }
+        if !props.is_empty() {
+            let array = ctx.ast.expression_array(SPAN, props);
+            nodes.push(SpreadNode { expr: array, is_new_array: false });
+        }
+        nodes
+    }
+}
+
+struct SpreadNode<'a> {
+    expr: Expression<'a>,
+    /// `true` if `expr` always evaluates to a new array, so it's safe to use without copying.
+    is_new_array: bool,
+}
+
+/// How an iterable is converted to an array by [`to_array`].
+#[derive(Debug, Clone, Copy)]
+pub enum ToArrayKind {
+    /// All elements, for a spread: `_toConsumableArray(x)`
+    Spread,
+    /// All elements, for an array pattern with a rest element: `_toArray(x)`
+    Rest,
+    /// The first `n` elements, for an array pattern: `_slicedToArray(x, n)`
+    Sliced(usize),
+}
+
+/// Convert an iterable to an array.
+///
+/// Based on Babel's `scope.toArray`.
+///
+/// * `[a, b]` -> `[a, b]`
+/// * `arguments` -> `Array.prototype.slice.call(arguments)`
+/// * `x` -> `_toConsumableArray(x)` / `_toArray(x)` / `_slicedToArray(x, n)`
+///
+/// If `array_like_is_iterable` is `true`, the helper call is wrapped with `_maybeArrayLike`:
+/// `_maybeArrayLike(_toConsumableArray, x)`.
+///
+/// Returns the new expression, and `true` if the expression evaluates to a new array.
+pub fn to_array<'a>(
+    expr: Expression<'a>,
+    kind: ToArrayKind,
+    array_like_is_iterable: bool,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> (Expression<'a>, bool) {
+    if matches!(expr, Expression::ArrayExpression(_)) {
+        return (expr, false);
+    }
+
+    if is_arguments_identifier(&expr) {
+        let array = ctx.create_unbound_ident_expr(SPAN, Atom::from("Array"), ReferenceFlags::Read);
+        let prototype = create_member_callee(array, "prototype", ctx);
+        let slice = create_member_callee(prototype, "slice", ctx);
+        let callee = create_member_callee(slice, "call", ctx);
+        let arguments = ctx.ast.vec1(Argument::from(expr));
+        return (ctx.ast.expression_call(SPAN, callee, NONE, arguments, false), true);
+    }
+
+    let helper = match kind {
+        ToArrayKind::Spread => Helper::ToConsumableArray,
+        ToArrayKind::Rest => Helper::ToArray,
+        ToArrayKind::Sliced(_) => Helper::SlicedToArray,
+    };
+    let mut arguments = ctx.ast.vec1(Argument::from(expr));
+    if let ToArrayKind::Sliced(count) = kind {
+        #[expect(clippy::cast_precision_loss)]
+        let count =
+            ctx.ast.expression_numeric_literal(SPAN, count as f64, None, NumberBase::Decimal);
+        arguments.push(Argument::from(count));
+    }
+    let expr = if array_like_is_iterable {
+        arguments.insert(0, Argument::from(transform_ctx.helper_load(helper, ctx)));
+        transform_ctx.helper_call_expr(Helper::MaybeArrayLike, SPAN, arguments, ctx)
+    } else {
+        transform_ctx.helper_call_expr(helper, SPAN, arguments, ctx)
+    };
+    (expr, true)
+}
+
+fn is_arguments_identifier(expr: &Expression<'_>) -> bool {
+    matches!(expr, Expression::Identifier(ident) if ident.name == "arguments")
+}
@@ -0,0 +1,262 @@
+//! ES2015: Template Literals
+//!
+//! This plugin transforms template literals and tagged templates.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! `foo${bar}baz${qux}`;
+//! tag`foo${bar}\n`;
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _templateObject;
+//! "foo".concat(bar, "baz").concat(qux);
+//! tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["foo", "\n"], ["foo", "\\n"])), bar);
+//! ```
+//!
+//! A tagged template's strings array is created once and cached in a top level variable,
+//! as the spec requires the same object to be passed to the tag each time the template is evaluated.
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! Enables the `ignoreToPrimitiveHint` and `mutableTemplateObject` assumptions.
+//!
+//! ### `ignoreToPrimitiveHint` assumption
+//!
+//! Values embedded in template literals are assumed not to have a `Symbol.toPrimitive` method
+//! which behaves differently depending on the hint, so they are concatenated with `+`
+//! instead of `String.prototype.concat`.
+//!
+//! ```js
+//! "foo" + bar + "baz" + qux;
+//! ```
+//!
+//! ### `mutableTemplateObject` assumption
+//!
+//! The strings array passed to a tag function is not frozen.
+//!
+//! ```js
+//! tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteralLoose(["foo", "\n"], ["foo", "\\n"])), bar);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-template-literals](https://babel.dev/docs/babel-plugin-transform-template-literals).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-template-literals>
+//! * Template literals specification: <https://tc39.es/ecma262/#sec-template-literals>
+
+use serde::Deserialize;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::SymbolFlags;
+use oxc_span::SPAN;
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator};
+use oxc_traverse::{BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{Helper, context::TransformCtx, utils::ast_builder::create_member_callee};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TemplateLiteralsOptions {
+    pub loose: bool,
+}
+
+pub struct TemplateLiterals<'a, 'ctx> {
+    ignore_to_primitive_hint: bool,
+    mutable_template_object: bool,
+    /// Variables caching tagged templates' strings arrays, declared at top of program
+    template_objects: Vec<BoundIdentifier<'a>>,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> TemplateLiterals<'a, 'ctx> {
+    pub fn new(options: TemplateLiteralsOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ignore_to_primitive_hint: options.loose || ctx.assumptions.ignore_to_primitive_hint,
+            mutable_template_object: options.loose || ctx.assumptions.mutable_template_object,
+            template_objects: vec![],
+            ctx,
+        }
+    }
+}
+
+impl<'a> Traverse<'a> for TemplateLiterals<'a, '_> {
+    // `var _templateObject, _templateObject2;`
+    fn exit_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.template_objects.is_empty() {
+            return;
+        }
+        let kind = VariableDeclarationKind::Var;
+        let declarations = ctx.ast.vec_from_iter(self.template_objects.drain(..).map(|binding| {
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                binding.create_binding_pattern(ctx),
+                None,
+                false,
+            )
+        }));
+        let declaration = ctx.ast.alloc_variable_declaration(SPAN, kind, declarations, false);
+        self.ctx.top_level_statements.insert_statement(Statement::VariableDeclaration(declaration));
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::TemplateLiteral(_) => self.transform_template_literal(expr, ctx),
+            Expression::TaggedTemplateExpression(_) => self.transform_tagged_template(expr, ctx),
+            _ => {}
+        }
+    }
+}
+
+impl<'a> TemplateLiterals<'a, '_> {
+    /// Transform `` `a${b}c${d}` `` to `"a".concat(b, "c").concat(d)`,
+    /// or `"a" + b + "c" + d` with `ignoreToPrimitiveHint` assumption.
+    fn transform_template_literal(&self, expr: &mut Expression<'a>, ctx: &TraverseCtx<'a>) {
+        let Expression::TemplateLiteral(template) = expr else { unreachable!() };
+        let span = template.span;
+
+        // Skip empty strings, they don't affect the result
+        let mut nodes = vec![];
+        let mut expressions = template.expressions.take_in(ctx.ast.allocator).into_iter();
+        for quasi in &template.quasis {
+            if let Some(cooked) = quasi.value.cooked.filter(|cooked| !cooked.is_empty()) {
+                nodes.push(ctx.ast.expression_string_literal_with_lone_surrogates(
+                    SPAN,
+                    cooked,
+                    None,
+                    quasi.lone_surrogates,
+                ));
+            }
+            if let Some(expression) = expressions.next() {
+                if !matches!(&expression, Expression::StringLiteral(lit) if lit.value.is_empty()) {
+                    nodes.push(expression);
+                }
+            }
+        }
+
+        // `+` is left associative, so the first or second value must be a string
+        // to make sure the values are concatenated, rather than added together
+        let is_string =
+            |node: Option<&Expression>| matches!(node, Some(Expression::StringLiteral(_)));
+        let starts_with_string =
+            is_string(nodes.first()) || (self.ignore_to_primitive_hint && is_string(nodes.get(1)));
+        if !starts_with_string {
+            nodes.insert(0, ctx.ast.expression_string_literal(SPAN, "", None));
+        }
+
+        let mut nodes = nodes.into_iter();
+        let mut result = nodes.next().unwrap();
+        if self.ignore_to_primitive_hint {
+            for node in nodes {
+                result = ctx.ast.expression_binary(SPAN, result, BinaryOperator::Addition, node);
+            }
+        } else {
+            // `concat` converts its arguments to strings after evaluating all of them,
+            // whereas a template literal converts each value before evaluating the next.
+            // So only 1 value which isn't a literal can be passed to each `concat` call.
+            let mut is_available = true;
+            for node in nodes {
+                let mut can_be_inserted = node.is_literal();
+                if !can_be_inserted && is_available {
+                    can_be_inserted = true;
+                    is_available = false;
+                }
+                if can_be_inserted {
+                    if let Expression::CallExpression(call) = &mut result {
+                        call.arguments.push(Argument::from(node));
+                        continue;
+                    }
+                }
+                let callee = create_member_callee(result, "concat", ctx);
+                let arguments = ctx.ast.vec1(Argument::from(node));
+                result = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+            }
+        }
+
+        if let Expression::StringLiteral(lit) = &mut result {
+            lit.span = span;
+        }
+        *expr = result;
+    }
+
+    /// Transform `` tag`a${b}` `` to
+    /// `tag(_templateObject || (_templateObject = _taggedTemplateLiteral(["a", ""])), b)`.
+    fn transform_tagged_template(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::TaggedTemplateExpression(tagged) = expr else { unreachable!() };
+        let TaggedTemplateExpression { span, tag, quasi, .. } = &mut **tagged;
+
+        // `["a", ""]`, `["a\n"], ["a\\n"]`
+        let mut strings = ctx.ast.vec_with_capacity(quasi.quasis.len());
+        let mut raws = ctx.ast.vec_with_capacity(quasi.quasis.len());
+        let mut is_strings_raw_equal = true;
+        for element in &quasi.quasis {
+            let TemplateElementValue { raw, cooked } = element.value;
+            let string = if let Some(cooked) = cooked {
+                ctx.ast.expression_string_literal_with_lone_surrogates(
+                    SPAN,
+                    cooked,
+                    None,
+                    element.lone_surrogates,
+                )
+            } else {
+                // Invalid escape sequences in tagged templates have an `undefined` cooked value
+                ctx.ast.void_0(SPAN)
+            };
+            strings.push(ArrayExpressionElement::from(string));
+            raws.push(ArrayExpressionElement::from(
+                ctx.ast.expression_string_literal(SPAN, raw, None),
+            ));
+            if cooked != Some(raw) {
+                is_strings_raw_equal = false;
+            }
+        }
+        let mut arguments = ctx.ast.vec1(Argument::from(ctx.ast.expression_array(SPAN, strings)));
+        if !is_strings_raw_equal {
+            arguments.push(Argument::from(ctx.ast.expression_array(SPAN, raws)));
+        }
+
+        let helper = if self.mutable_template_object {
+            Helper::TaggedTemplateLiteralLoose
+        } else {
+            Helper::TaggedTemplateLiteral
+        };
+        let template_object = self.ctx.helper_call_expr(helper, SPAN, arguments, ctx);
+
+        // `_templateObject || (_templateObject = template_object)`
+        let binding = ctx.generate_uid(
+            "templateObject",
+            ctx.scoping().root_scope_id(),
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(ctx),
+            template_object,
+        );
+        let template_object = ctx.ast.expression_logical(
+            SPAN,
+            binding.create_read_expression(ctx),
+            LogicalOperator::Or,
+            assignment,
+        );
+        self.template_objects.push(binding);
+
+        let mut arguments = ctx.ast.vec_with_capacity(quasi.expressions.len() + 1);
+        arguments.push(Argument::from(template_object));
+        arguments.extend(quasi.expressions.drain(..).map(Argument::from));
+        let tag = tag.take_in(ctx.ast.allocator);
+        *expr = ctx.ast.expression_call(*span, tag, NONE, arguments, false);
+    }
+}
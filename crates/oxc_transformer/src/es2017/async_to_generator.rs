@@ -64,7 +64,11 @@ use oxc_syntax::{
 };
 use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
 
-use crate::{TransformCtx, common::helper_loader::Helper};
+use crate::{
+    TransformCtx,
+    common::helper_loader::Helper,
+    es2015::{Regenerator, RegeneratorOptions},
+};
 
 pub struct AsyncToGenerator<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
@@ -72,8 +76,11 @@ pub struct AsyncToGenerator<'a, 'ctx> {
 }
 
 impl<'a, 'ctx> AsyncToGenerator<'a, 'ctx> {
-    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { ctx, executor: AsyncGeneratorExecutor::new(Helper::AsyncToGenerator, ctx) }
+    pub fn new(regenerator: Option<RegeneratorOptions>, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            executor: AsyncGeneratorExecutor::new(Helper::AsyncToGenerator, regenerator, ctx),
+        }
     }
 }
 
@@ -186,12 +193,19 @@ impl<'a> AsyncToGenerator<'a, '_> {
 
 pub struct AsyncGeneratorExecutor<'a, 'ctx> {
     helper: Helper,
+    /// `Some` if regenerator plugin is enabled, to compile the generator functions created
+    regenerator: Option<Regenerator<'a, 'ctx>>,
     ctx: &'ctx TransformCtx<'a>,
 }
 
 impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
-    pub fn new(helper: Helper, ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { helper, ctx }
+    pub fn new(
+        helper: Helper,
+        regenerator: Option<RegeneratorOptions>,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        let regenerator = regenerator.map(|options| Regenerator::new(options, ctx));
+        Self { helper, regenerator, ctx }
     }
 
     /// Transforms async method definitions to generator functions wrapped in asyncToGenerator.
@@ -692,7 +706,11 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
     ) -> Expression<'a> {
         let mut function = Self::create_function(None, params, body, scope_id, ctx);
         function.generator = true;
-        let arguments = ctx.ast.vec1(Argument::FunctionExpression(function));
+        let function = match &self.regenerator {
+            Some(regenerator) => regenerator.transform_function_expression(function, ctx),
+            None => Expression::FunctionExpression(function),
+        };
+        let arguments = ctx.ast.vec1(Argument::from(function));
         self.ctx.helper_call_expr(self.helper, SPAN, arguments, ctx)
     }
 
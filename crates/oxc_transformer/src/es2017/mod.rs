@@ -1,7 +1,7 @@
 use oxc_ast::ast::{Expression, Function, Statement};
 use oxc_traverse::{Traverse, TraverseCtx};
 
-use crate::TransformCtx;
+use crate::{TransformCtx, es2015::RegeneratorOptions};
 
 mod async_to_generator;
 mod options;
@@ -16,8 +16,13 @@ pub struct ES2017<'a, 'ctx> {
 }
 
 impl<'a, 'ctx> ES2017<'a, 'ctx> {
-    pub fn new(options: ES2017Options, ctx: &'ctx TransformCtx<'a>) -> ES2017<'a, 'ctx> {
-        ES2017 { async_to_generator: AsyncToGenerator::new(ctx), options }
+    pub fn new(
+        options: ES2017Options,
+        regenerator: Option<RegeneratorOptions>,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> ES2017<'a, 'ctx> {
+        let regenerator = regenerator.filter(|regenerator| regenerator.async_functions);
+        ES2017 { async_to_generator: AsyncToGenerator::new(regenerator, ctx), options }
     }
 }
 
@@ -71,7 +71,10 @@ use oxc_ast::ast::*;
 use oxc_span::SPAN;
 use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
 
-use crate::{common::helper_loader::Helper, context::TransformCtx, es2017::AsyncGeneratorExecutor};
+use crate::{
+    common::helper_loader::Helper, context::TransformCtx, es2015::RegeneratorOptions,
+    es2017::AsyncGeneratorExecutor,
+};
 
 pub struct AsyncGeneratorFunctions<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
@@ -79,8 +82,11 @@ pub struct AsyncGeneratorFunctions<'a, 'ctx> {
 }
 
 impl<'a, 'ctx> AsyncGeneratorFunctions<'a, 'ctx> {
-    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { ctx, executor: AsyncGeneratorExecutor::new(Helper::WrapAsyncGenerator, ctx) }
+    pub fn new(regenerator: Option<RegeneratorOptions>, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            executor: AsyncGeneratorExecutor::new(Helper::WrapAsyncGenerator, regenerator, ctx),
+        }
     }
 }
 
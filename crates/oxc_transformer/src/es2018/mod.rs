@@ -1,7 +1,7 @@
 use oxc_ast::ast::*;
 use oxc_traverse::{Traverse, TraverseCtx};
 
-use crate::context::TransformCtx;
+use crate::{context::TransformCtx, es2015::RegeneratorOptions};
 
 mod async_generator_functions;
 mod object_rest_spread;
@@ -20,13 +20,18 @@ pub struct ES2018<'a, 'ctx> {
 }
 
 impl<'a, 'ctx> ES2018<'a, 'ctx> {
-    pub fn new(options: ES2018Options, ctx: &'ctx TransformCtx<'a>) -> Self {
+    pub fn new(
+        options: ES2018Options,
+        regenerator: Option<RegeneratorOptions>,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        let regenerator = regenerator.filter(|regenerator| regenerator.async_generators);
         Self {
             object_rest_spread: ObjectRestSpread::new(
                 options.object_rest_spread.unwrap_or_default(),
                 ctx,
             ),
-            async_generator_functions: AsyncGeneratorFunctions::new(ctx),
+            async_generator_functions: AsyncGeneratorFunctions::new(regenerator, ctx),
             options,
         }
     }
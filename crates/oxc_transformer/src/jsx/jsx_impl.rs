@@ -459,7 +459,7 @@ impl<'a, 'ctx> JsxImpl<'a, 'ctx> {
                     }
                 };
 
-                if ctx.source_type.is_script() {
+                if ctx.use_require() {
                     Bindings::AutomaticScript(AutomaticScriptBindings::new(
                         ctx,
                         jsx_runtime_importer,
@@ -509,7 +509,7 @@ impl<'a> Traverse<'a> for JsxImpl<'a, '_> {
 
 impl<'a> JsxImpl<'a, '_> {
     fn is_script(&self) -> bool {
-        self.ctx.source_type.is_script()
+        self.ctx.use_require()
     }
 
     fn insert_filename_var_statement(&self, ctx: &TraverseCtx<'a>) {
@@ -35,10 +35,12 @@ mod proposals;
 mod regexp;
 mod typescript;
 
+mod commonjs;
 mod decorator;
 mod plugins;
 
 use common::Common;
+use commonjs::CommonJs;
 use context::TransformCtx;
 use decorator::Decorator;
 use es2015::ES2015;
@@ -57,9 +59,14 @@ use typescript::TypeScript;
 
 pub use crate::{
     common::helper_loader::{Helper, HelperLoaderMode, HelperLoaderOptions},
+    commonjs::{CommonJsOptions, ImportInterop},
     compiler_assumptions::CompilerAssumptions,
     decorator::DecoratorOptions,
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ClassesOptions, ComputedPropertiesOptions,
+        DestructuringOptions, ES2015Options, ForOfOptions, ParametersOptions, RegeneratorOptions,
+        SpreadOptions, TemplateLiteralsOptions,
+    },
     es2016::ES2016Options,
     es2017::ES2017Options,
     es2018::ES2018Options,
@@ -152,11 +159,16 @@ impl<'a> Transformer<'a> {
             x2_es2021: ES2021::new(self.env.es2021, &self.ctx),
             x2_es2020: ES2020::new(self.env.es2020, &self.ctx),
             x2_es2019: ES2019::new(self.env.es2019),
-            x2_es2018: ES2018::new(self.env.es2018, &self.ctx),
+            x2_es2018: ES2018::new(self.env.es2018, self.env.es2015.regenerator, &self.ctx),
             x2_es2016: ES2016::new(self.env.es2016, &self.ctx),
-            x2_es2017: ES2017::new(self.env.es2017, &self.ctx),
+            x2_es2017: ES2017::new(self.env.es2017, self.env.es2015.regenerator, &self.ctx),
             x3_es2015: ES2015::new(self.env.es2015, &self.ctx),
             x4_regexp: RegExp::new(self.env.regexp, &self.ctx),
+            x5_commonjs: if let Module::CommonJS(options) = self.env.module {
+                Some(CommonJs::new(options, &self.ctx))
+            } else {
+                None
+            },
         };
 
         let scoping = traverse_mut(&mut transformer, allocator, program, scoping);
@@ -179,9 +191,9 @@ struct TransformerImpl<'a, 'ctx> {
     x2_es2018: ES2018<'a, 'ctx>,
     x2_es2017: ES2017<'a, 'ctx>,
     x2_es2016: ES2016<'a, 'ctx>,
-    #[expect(unused)]
     x3_es2015: ES2015<'a, 'ctx>,
     x4_regexp: RegExp<'a, 'ctx>,
+    x5_commonjs: Option<CommonJs<'a, 'ctx>>,
     common: Common<'a, 'ctx>,
 }
 
@@ -203,7 +215,16 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
         }
         self.x2_es2022.exit_program(program, ctx);
         self.x2_es2018.exit_program(program, ctx);
+        self.x3_es2015.exit_program(program, ctx);
+        // Modules are transformed after all other plugins, so that imports removed by them
+        // (e.g. type-only imports) are not `require`d
+        if let Some(commonjs) = self.x5_commonjs.as_mut() {
+            commonjs.exit_program(program, ctx);
+        }
         self.common.exit_program(program, ctx);
+        // `common` inserts top level statements on exiting program, after the program body's
+        // `exit_statements` has run, so `let` / `const` declarations it inserts must be converted here
+        self.x3_es2015.exit_statements(&mut program.body, ctx);
     }
 
     // ALPHASORT
@@ -217,6 +238,7 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
             typescript.enter_arrow_function_expression(arrow, ctx);
         }
         self.x2_es2018.enter_arrow_function_expression(arrow, ctx);
+        self.x3_es2015.enter_arrow_function_expression(arrow, ctx);
     }
 
     fn enter_variable_declaration(
@@ -225,6 +247,15 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.x2_es2018.enter_variable_declaration(decl, ctx);
+        self.x3_es2015.enter_variable_declaration(decl, ctx);
+    }
+
+    fn exit_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.exit_variable_declaration(decl, ctx);
     }
 
     fn enter_variable_declarator(
@@ -247,6 +278,7 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.common.enter_binding_identifier(node, ctx);
+        self.x3_es2015.enter_binding_identifier(node, ctx);
     }
 
     fn enter_identifier_reference(
@@ -255,6 +287,7 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.common.enter_identifier_reference(node, ctx);
+        self.x3_es2015.enter_identifier_reference(node, ctx);
     }
 
     fn enter_binding_pattern(&mut self, pat: &mut BindingPattern<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -310,6 +343,7 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
 
     #[inline]
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.decorator.enter_expression(expr, ctx);
         self.common.enter_expression(expr, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_expression(expr, ctx);
@@ -319,6 +353,7 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
         self.x2_es2020.enter_expression(expr, ctx);
         self.x2_es2018.enter_expression(expr, ctx);
         self.x2_es2016.enter_expression(expr, ctx);
+        self.x3_es2015.enter_expression(expr, ctx);
         self.x4_regexp.enter_expression(expr, ctx);
     }
 
@@ -328,6 +363,7 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
         self.x2_es2022.exit_expression(expr, ctx);
         self.x2_es2018.exit_expression(expr, ctx);
         self.x2_es2017.exit_expression(expr, ctx);
+        self.x3_es2015.exit_expression(expr, ctx);
     }
 
     fn enter_simple_assignment_target(
@@ -380,6 +416,7 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
     fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
         self.common.enter_function(func, ctx);
         self.x2_es2018.enter_function(func, ctx);
+        self.x3_es2015.enter_function(func, ctx);
     }
 
     fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -389,6 +426,7 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
         self.x1_jsx.exit_function(func, ctx);
         self.x2_es2018.exit_function(func, ctx);
         self.x2_es2017.exit_function(func, ctx);
+        self.x3_es2015.exit_function(func, ctx);
         self.common.exit_function(func, ctx);
     }
 
@@ -465,6 +503,10 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
         }
     }
 
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.enter_object_property(prop, ctx);
+    }
+
     fn enter_property_definition(
         &mut self,
         def: &mut PropertyDefinition<'a>,
@@ -505,6 +547,7 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_statements(stmts, ctx);
         }
+        self.x3_es2015.enter_statements(stmts, ctx);
     }
 
     fn exit_arrow_function_expression(
@@ -554,6 +597,9 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
             typescript.exit_statements(stmts, ctx);
         }
         self.common.exit_statements(stmts, ctx);
+        // Must run after `common`, so `let` / `const` declarations inserted by other plugins
+        // are converted to `var` too
+        self.x3_es2015.exit_statements(stmts, ctx);
     }
 
     fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -563,6 +609,7 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
         self.decorator.exit_statement(stmt, ctx);
         self.x2_es2018.exit_statement(stmt, ctx);
         self.x2_es2017.exit_statement(stmt, ctx);
+        self.x3_es2015.exit_statement(stmt, ctx);
     }
 
     fn enter_tagged_template_expression(
@@ -576,10 +623,12 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
     }
 
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.decorator.enter_statement(stmt, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_statement(stmt, ctx);
         }
         self.x2_es2018.enter_statement(stmt, ctx);
+        self.x3_es2015.enter_statement(stmt, ctx);
         if let Some(explicit_resource_management) = self.explicit_resource_management.as_mut() {
             explicit_resource_management.enter_statement(stmt, ctx);
         }
@@ -617,6 +666,19 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_for_statement(stmt, ctx);
         }
+        self.x3_es2015.enter_for_statement(stmt, ctx);
+    }
+
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.enter_block_statement(block, ctx);
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.enter_switch_statement(stmt, ctx);
     }
 
     fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -627,6 +689,7 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
             explicit_resource_management.enter_for_of_statement(stmt, ctx);
         }
         self.x2_es2018.enter_for_of_statement(stmt, ctx);
+        self.x3_es2015.enter_for_of_statement(stmt, ctx);
     }
 
     fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -634,6 +697,7 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
             typescript.enter_for_in_statement(stmt, ctx);
         }
         self.x2_es2018.enter_for_in_statement(stmt, ctx);
+        self.x3_es2015.enter_for_in_statement(stmt, ctx);
     }
 
     fn enter_try_statement(&mut self, stmt: &mut TryStatement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -645,6 +709,7 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
     fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x2_es2019.enter_catch_clause(clause, ctx);
         self.x2_es2018.enter_catch_clause(clause, ctx);
+        self.x3_es2015.enter_catch_clause(clause, ctx);
     }
 
     fn enter_import_declaration(
@@ -1,8 +1,15 @@
 use serde::Deserialize;
 
 use crate::{
-    DecoratorOptions, TypeScriptOptions, es2015::ArrowFunctionsOptions,
-    es2018::ObjectRestSpreadOptions, es2022::ClassPropertiesOptions, jsx::JsxOptions,
+    CommonJsOptions, DecoratorOptions, TypeScriptOptions,
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ClassesOptions, ComputedPropertiesOptions,
+        DestructuringOptions, ForOfOptions, ParametersOptions, RegeneratorOptions, SpreadOptions,
+        TemplateLiteralsOptions,
+    },
+    es2018::ObjectRestSpreadOptions,
+    es2022::ClassPropertiesOptions,
+    jsx::JsxOptions,
 };
 
 use super::PluginPresetEntries;
@@ -39,7 +46,7 @@ pub struct BabelPlugins {
     pub react_jsx_source: bool,
     pub react_display_name: bool,
     // modules
-    pub modules_commonjs: bool,
+    pub modules_commonjs: Option<CommonJsOptions>,
     // regexp
     pub sticky_flag: bool,
     pub unicode_flag: bool,
@@ -52,6 +59,17 @@ pub struct BabelPlugins {
     pub set_notation: bool,
     // ES2015
     pub arrow_function: Option<ArrowFunctionsOptions>,
+    pub block_scoping: Option<BlockScopingOptions>,
+    pub classes: Option<ClassesOptions>,
+    pub computed_properties: Option<ComputedPropertiesOptions>,
+    pub destructuring: Option<DestructuringOptions>,
+    pub for_of: Option<ForOfOptions>,
+    pub object_super: bool,
+    pub parameters: Option<ParametersOptions>,
+    pub regenerator: Option<RegeneratorOptions>,
+    pub shorthand_properties: bool,
+    pub spread: Option<SpreadOptions>,
+    pub template_literals: Option<TemplateLiteralsOptions>,
     // ES2016
     pub exponentiation_operator: bool,
     // ES2017
@@ -120,7 +138,10 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                 "transform-react-display-name" => p.react_display_name = true,
                 "transform-react-jsx-self" => p.react_jsx_self = true,
                 "transform-react-jsx-source" => p.react_jsx_source = true,
-                "transform-modules-commonjs" => p.modules_commonjs = true,
+                "transform-modules-commonjs" => {
+                    p.modules_commonjs =
+                        entry.value::<CommonJsOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
                 "transform-sticky-regex" => p.sticky_flag = true,
                 "transform-unicode-regex" => p.unicode_flag = true,
                 "transform-dotall-regex" => p.dot_all_flag = true,
@@ -135,6 +156,49 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                         .map_err(|err| p.errors.push(err))
                         .ok();
                 }
+                "transform-block-scoping" => {
+                    p.block_scoping =
+                        entry.value::<BlockScopingOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-classes" => {
+                    p.classes =
+                        entry.value::<ClassesOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-computed-properties" => {
+                    p.computed_properties = entry
+                        .value::<ComputedPropertiesOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "transform-destructuring" => {
+                    p.destructuring = entry
+                        .value::<DestructuringOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "transform-for-of" => {
+                    p.for_of = entry.value::<ForOfOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-parameters" => {
+                    p.parameters =
+                        entry.value::<ParametersOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-regenerator" => {
+                    p.regenerator =
+                        entry.value::<RegeneratorOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-object-super" => p.object_super = true,
+                "transform-shorthand-properties" => p.shorthand_properties = true,
+                "transform-spread" => {
+                    p.spread =
+                        entry.value::<SpreadOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-template-literals" => {
+                    p.template_literals = entry
+                        .value::<TemplateLiteralsOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
                 "transform-exponentiation-operator" => p.exponentiation_operator = true,
                 "transform-async-to-generator" => p.async_to_generator = true,
                 "transform-object-rest-spread" => {
@@ -4,7 +4,11 @@ use serde::Deserialize;
 
 use crate::{
     EngineTargets,
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ClassesOptions, ComputedPropertiesOptions,
+        DestructuringOptions, ES2015Options, ForOfOptions, ParametersOptions, RegeneratorOptions,
+        SpreadOptions, TemplateLiteralsOptions,
+    },
     es2016::ES2016Options,
     es2017::ES2017Options,
     es2018::{ES2018Options, ObjectRestSpreadOptions},
@@ -67,6 +71,53 @@ impl EnvOptions {
                 } else {
                     None
                 },
+                block_scoping: if include_unfinished_plugins {
+                    Some(BlockScopingOptions::default())
+                } else {
+                    None
+                },
+                classes: if include_unfinished_plugins {
+                    Some(ClassesOptions::default())
+                } else {
+                    None
+                },
+                computed_properties: if include_unfinished_plugins {
+                    Some(ComputedPropertiesOptions::default())
+                } else {
+                    None
+                },
+                destructuring: if include_unfinished_plugins {
+                    Some(DestructuringOptions::default())
+                } else {
+                    None
+                },
+                for_of: if include_unfinished_plugins {
+                    Some(ForOfOptions::default())
+                } else {
+                    None
+                },
+                object_super: include_unfinished_plugins,
+                parameters: if include_unfinished_plugins {
+                    Some(ParametersOptions::default())
+                } else {
+                    None
+                },
+                regenerator: if include_unfinished_plugins {
+                    Some(RegeneratorOptions::default())
+                } else {
+                    None
+                },
+                shorthand_properties: include_unfinished_plugins,
+                spread: if include_unfinished_plugins {
+                    Some(SpreadOptions::default())
+                } else {
+                    None
+                },
+                template_literals: if include_unfinished_plugins {
+                    Some(TemplateLiteralsOptions::default())
+                } else {
+                    None
+                },
             },
             es2016: ES2016Options { exponentiation_operator: true },
             es2017: ES2017Options { async_to_generator: true },
@@ -164,6 +215,17 @@ impl From<EngineTargets> for EnvOptions {
             },
             es2015: ES2015Options {
                 arrow_function: o.has_feature(ES2015ArrowFunctions).then(Default::default),
+                block_scoping: o.has_feature(ES2015BlockScoping).then(Default::default),
+                classes: o.has_feature(ES2015Classes).then(Default::default),
+                computed_properties: o.has_feature(ES2015ComputedProperties).then(Default::default),
+                destructuring: o.has_feature(ES2015Destructuring).then(Default::default),
+                for_of: o.has_feature(ES2015ForOf).then(Default::default),
+                object_super: o.has_feature(ES2015ObjectSuper),
+                parameters: o.has_feature(ES2015Parameters).then(Default::default),
+                regenerator: o.has_feature(ES2015Regenerator).then(Default::default),
+                shorthand_properties: o.has_feature(ES2015ShorthandProperties),
+                spread: o.has_feature(ES2015Spread).then(Default::default),
+                template_literals: o.has_feature(ES2015TemplateLiterals).then(Default::default),
             },
             es2016: ES2016Options {
                 exponentiation_operator: o.has_feature(ES2016ExponentiationOperator),
@@ -82,7 +82,11 @@ impl TransformOptions {
             cwd: PathBuf::new(),
             assumptions: CompilerAssumptions::default(),
             typescript: TypeScriptOptions::default(),
-            decorator: DecoratorOptions { legacy: true, emit_decorator_metadata: true },
+            decorator: DecoratorOptions {
+                legacy: true,
+                standard: false,
+                emit_decorator_metadata: true,
+            },
             jsx: JsxOptions {
                 development: true,
                 refresh: Some(ReactRefreshOptions::default()),
@@ -157,6 +161,11 @@ impl TryFrom<&BabelOptions> for TransformOptions {
 
         let decorator = DecoratorOptions {
             legacy: options.plugins.legacy_decorator.is_some(),
+            standard: options
+                .plugins
+                .proposal_decorators
+                .as_ref()
+                .is_some_and(|options| options.version == "2023-05"),
             emit_decorator_metadata: options
                 .plugins
                 .legacy_decorator
@@ -203,6 +212,21 @@ impl TryFrom<&BabelOptions> for TransformOptions {
 
         let es2015 = ES2015Options {
             arrow_function: options.plugins.arrow_function.or(env.es2015.arrow_function),
+            block_scoping: options.plugins.block_scoping.or(env.es2015.block_scoping),
+            classes: options.plugins.classes.or(env.es2015.classes),
+            computed_properties: options
+                .plugins
+                .computed_properties
+                .or(env.es2015.computed_properties),
+            destructuring: options.plugins.destructuring.or(env.es2015.destructuring),
+            for_of: options.plugins.for_of.or(env.es2015.for_of),
+            object_super: options.plugins.object_super || env.es2015.object_super,
+            parameters: options.plugins.parameters.or(env.es2015.parameters),
+            regenerator: options.plugins.regenerator.or(env.es2015.regenerator),
+            shorthand_properties: options.plugins.shorthand_properties
+                || env.es2015.shorthand_properties,
+            spread: options.plugins.spread.or(env.es2015.spread),
+            template_literals: options.plugins.template_literals.or(env.es2015.template_literals),
         };
 
         let es2016 = ES2016Options {
@@ -2,7 +2,7 @@ use oxc_diagnostics::Error;
 use serde::Deserialize;
 
 use super::babel::BabelPlugins;
-use crate::options::babel::BabelModule;
+use crate::{CommonJsOptions, options::babel::BabelModule};
 
 /// Specify what module code is generated.
 ///
@@ -17,7 +17,7 @@ pub enum Module {
     #[default]
     Preserve,
     ESM,
-    CommonJS,
+    CommonJS(CommonJsOptions),
 }
 
 impl Module {
@@ -28,7 +28,7 @@ impl Module {
 
     /// Check if the module is CommonJS.
     pub fn is_commonjs(&self) -> bool {
-        matches!(self, Self::CommonJS)
+        matches!(self, Self::CommonJS(_))
     }
 }
 
@@ -37,7 +37,7 @@ impl TryFrom<BabelModule> for Module {
 
     fn try_from(value: BabelModule) -> Result<Self, Self::Error> {
         match value {
-            BabelModule::Commonjs => Ok(Self::CommonJS),
+            BabelModule::Commonjs => Ok(Self::CommonJS(CommonJsOptions::default())),
             BabelModule::Auto | BabelModule::Boolean(false) => Ok(Self::Preserve),
             _ => Err(Error::msg(format!("{value:?} module is not implemented."))),
         }
@@ -48,8 +48,8 @@ impl TryFrom<&BabelPlugins> for Module {
     type Error = Error;
 
     fn try_from(value: &BabelPlugins) -> Result<Self, Self::Error> {
-        if value.modules_commonjs {
-            Ok(Self::CommonJS)
+        if let Some(options) = value.modules_commonjs {
+            Ok(Self::CommonJS(options))
         } else {
             Err(Error::msg("Doesn't find any transform-modules-* plugin."))
         }
@@ -0,0 +1,227 @@
+//! Sets of code points, used to lower character classes and property escapes.
+//!
+//! Unicode data (property values and simple case folding) comes from `regex-syntax`, which does
+//! not know about surrogate code points, so those are handled here.
+
+use regex_syntax::hir::{Class, ClassUnicode, ClassUnicodeRange, HirKind};
+
+pub const MAX_CODE_POINT: u32 = 0x10_FFFF;
+pub const LEAD_SURROGATES: (u32, u32) = (0xD800, 0xDBFF);
+pub const TRAIL_SURROGATES: (u32, u32) = (0xDC00, 0xDFFF);
+
+/// A set of code points, stored as sorted, non-overlapping and non-adjacent inclusive ranges.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CharSet {
+    ranges: Vec<(u32, u32)>,
+}
+
+impl CharSet {
+    pub fn from_range(min: u32, max: u32) -> Self {
+        let mut set = Self::default();
+        set.add_range(min, max);
+        set
+    }
+
+    pub fn from_code_point(cp: u32) -> Self {
+        Self::from_range(cp, cp)
+    }
+
+    pub fn full() -> Self {
+        Self::from_range(0, MAX_CODE_POINT)
+    }
+
+    pub fn ranges(&self) -> &[(u32, u32)] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The only code point in this set, if it contains exactly one.
+    pub fn single(&self) -> Option<u32> {
+        match self.ranges.as_slice() {
+            [(min, max)] if min == max => Some(*min),
+            _ => None,
+        }
+    }
+
+    pub fn add_range(&mut self, min: u32, max: u32) {
+        if min <= max {
+            self.ranges.push((min, max));
+            self.normalize();
+        }
+    }
+
+    pub fn add(&mut self, cp: u32) {
+        self.add_range(cp, cp);
+    }
+
+    pub fn union(&mut self, other: &Self) {
+        self.ranges.extend_from_slice(&other.ranges);
+        self.normalize();
+    }
+
+    pub fn negate(&self) -> Self {
+        let mut ranges = vec![];
+        let mut next = 0;
+        for &(min, max) in &self.ranges {
+            if min > next {
+                ranges.push((next, min - 1));
+            }
+            next = max + 1;
+        }
+        if next <= MAX_CODE_POINT {
+            ranges.push((next, MAX_CODE_POINT));
+        }
+        Self { ranges }
+    }
+
+    pub fn intersect(&self, other: &Self) -> Self {
+        let mut union = self.negate();
+        union.union(&other.negate());
+        union.negate()
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersect(&other.negate())
+    }
+
+    /// Code points of this set within `min..=max`.
+    pub fn clip(&self, min: u32, max: u32) -> Self {
+        self.intersect(&Self::from_range(min, max))
+    }
+
+    /// Add every code point which is equivalent to a member under simple case folding.
+    pub fn case_fold(&mut self) {
+        let mut class = ClassUnicode::new(self.ranges.iter().flat_map(|&(min, max)| {
+            // `char` ranges cannot contain surrogates, so split around them.
+            [(min, max.min(0xD7FF)), (min.max(0xE000), max)].into_iter().filter_map(|(min, max)| {
+                let min = char::from_u32(min)?;
+                let max = char::from_u32(max)?;
+                (min <= max).then(|| ClassUnicodeRange::new(min, max))
+            })
+        }));
+        class.case_fold_simple();
+        self.union(&Self::from_class(&class));
+    }
+
+    fn from_class(class: &ClassUnicode) -> Self {
+        let mut set = Self {
+            ranges: class
+                .ranges()
+                .iter()
+                .map(|r| (u32::from(r.start()), u32::from(r.end())))
+                .collect(),
+        };
+        set.normalize();
+        set
+    }
+
+    fn normalize(&mut self) {
+        self.ranges.sort_unstable();
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(self.ranges.len());
+        for &(min, max) in &self.ranges {
+            if let Some(last) = merged.last_mut() {
+                if min <= last.1.saturating_add(1) {
+                    last.1 = last.1.max(max);
+                    continue;
+                }
+            }
+            merged.push((min, max));
+        }
+        self.ranges = merged;
+    }
+}
+
+/// `\d`
+pub fn digits() -> CharSet {
+    CharSet::from_range('0' as u32, '9' as u32)
+}
+
+/// `\s`: `WhiteSpace` and `LineTerminator`.
+pub fn white_space() -> CharSet {
+    let mut set = CharSet::default();
+    for (min, max) in [
+        (0x09, 0x0D),
+        (0x20, 0x20),
+        (0xA0, 0xA0),
+        (0x1680, 0x1680),
+        (0x2000, 0x200A),
+        (0x2028, 0x2029),
+        (0x202F, 0x202F),
+        (0x205F, 0x205F),
+        (0x3000, 0x3000),
+        (0xFEFF, 0xFEFF),
+    ] {
+        set.add_range(min, max);
+    }
+    set
+}
+
+/// `\w`. With both `i` and `u` flags, this also includes `ſ` and `K` (Kelvin sign),
+/// which case fold into ASCII word characters.
+pub fn word_characters(unicode_ignore_case: bool) -> CharSet {
+    let mut set = digits();
+    set.add_range('A' as u32, 'Z' as u32);
+    set.add('_' as u32);
+    set.add_range('a' as u32, 'z' as u32);
+    if unicode_ignore_case {
+        set.add(0x017F);
+        set.add(0x212A);
+    }
+    set
+}
+
+/// Code points not matched by `.` without the `s` flag.
+pub fn line_terminators() -> CharSet {
+    let mut set = CharSet::default();
+    set.add(0x0A);
+    set.add(0x0D);
+    set.add_range(0x2028, 0x2029);
+    set
+}
+
+/// Resolve `\p{name}` or `\p{name=value}` to the code points it matches.
+///
+/// Returns `None` if the property is not known to the bundled Unicode data.
+pub fn unicode_property(name: &str, value: Option<&str>) -> Option<CharSet> {
+    let lone = value.map_or(name, |value| if name == "General_Category" { value } else { "" });
+    // Properties which include surrogates, which `regex-syntax` cannot represent.
+    match lone {
+        "Any" => return Some(CharSet::full()),
+        "Cs" | "Surrogate" => return Some(CharSet::from_range(0xD800, 0xDFFF)),
+        _ => {}
+    }
+
+    let source = match value {
+        Some(value) => format!(r"\p{{{name}={value}}}"),
+        None => format!(r"\p{{{name}}}"),
+    };
+    let hir = regex_syntax::Parser::new().parse(&source).ok()?;
+    let mut set = match hir.kind() {
+        HirKind::Class(Class::Unicode(class)) => CharSet::from_class(class),
+        HirKind::Literal(literal) => {
+            let ch = std::str::from_utf8(&literal.0).ok()?.chars().next()?;
+            CharSet::from_code_point(u32::from(ch))
+        }
+        _ => return None,
+    };
+    if matches!(lone, "C" | "Other" | "Assigned") {
+        set.add_range(0xD800, 0xDFFF);
+    }
+    Some(set)
+}
+
+/// `Canonicalize` for the `i` flag without the `u` flag: the code point's upper case mapping,
+/// unless that is more than one code point or maps a non-ASCII code point to ASCII.
+///
+/// <https://tc39.es/ecma262/#sec-runtime-semantics-canonicalize-ch>
+pub fn canonicalize_non_unicode(cp: u32) -> u32 {
+    let Some(ch) = char::from_u32(cp) else { return cp };
+    let mut upper = ch.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(upper), None) if cp < 0x80 || u32::from(upper) >= 0x80 => u32::from(upper),
+        _ => cp,
+    }
+}
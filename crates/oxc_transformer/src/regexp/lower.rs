@@ -0,0 +1,690 @@
+//! Lowering of RegExp pattern syntax.
+//!
+//! Rewrites a parsed pattern into an equivalent pattern which only uses syntax and flags
+//! the target engine supports:
+//!
+//! * `s` flag: `.` becomes `[\s\S]`.
+//! * `\p{...}` and `\P{...}`: expanded into explicit character classes.
+//! * `v` flag: set operations, nested classes and `\q{...}` are computed, and the pattern
+//!   falls back to `u` mode (or no unicode mode at all, if `u` is not supported either).
+//! * `u` flag: astral code points become surrogate pairs, and classes or escapes which can match
+//!   astral code points become alternations of surrogate pair ranges.
+//! * Named capture groups: names are removed, and `\k<name>` becomes an indexed backreference.
+//!   The caller maps group names to indexes with the `wrapRegExp` helper.
+//!
+//! Properties of strings (e.g. `\p{RGI_Emoji}`) and modifiers (`(?i:...)`) combined with
+//! anything which changes how characters are matched are not supported.
+
+use std::fmt::Write;
+
+use oxc_ast::ast::RegExpFlags;
+use oxc_regular_expression::{
+    ast::{
+        Alternative, CapturingGroup, Character, CharacterClass, CharacterClassContents,
+        CharacterClassContentsKind, CharacterClassEscape, CharacterClassEscapeKind, Disjunction,
+        IgnoreGroup, LookAroundAssertionKind, Modifier, NamedReference, Pattern, Quantifier, Term,
+        UnicodePropertyEscape,
+    },
+    visit::{RegExpAstKind, Visit},
+};
+use oxc_span::Atom;
+
+use super::{
+    RegExpOptions,
+    char_set::{
+        self, CharSet, LEAD_SURROGATES, MAX_CODE_POINT, TRAIL_SURROGATES, canonicalize_non_unicode,
+    },
+};
+
+/// Syntax used by a pattern which decides whether it needs transforming.
+#[derive(Default)]
+pub struct PatternFeatures<'a> {
+    /// Capture group indexes for each group name, in order of first appearance.
+    /// A name can have several indexes when duplicated in different alternatives.
+    pub group_names: Vec<(Atom<'a>, Vec<u32>)>,
+    pub unicode_property_escapes: bool,
+    pub look_behind_assertions: bool,
+    pub modifiers: bool,
+    group_count: u32,
+}
+
+impl<'a> PatternFeatures<'a> {
+    pub fn collect(pattern: &Pattern<'a>) -> Self {
+        let mut features = Self::default();
+        features.visit_pattern(pattern);
+        features
+    }
+}
+
+impl<'a> Visit<'a> for PatternFeatures<'a> {
+    fn enter_node(&mut self, kind: RegExpAstKind<'a>) {
+        match kind {
+            RegExpAstKind::CapturingGroup(group) => {
+                self.group_count += 1;
+                if let Some(name) = group.name {
+                    match self.group_names.iter_mut().find(|(n, _)| *n == name) {
+                        Some((_, indexes)) => indexes.push(self.group_count),
+                        None => self.group_names.push((name, vec![self.group_count])),
+                    }
+                }
+            }
+            RegExpAstKind::UnicodePropertyEscape(_) => self.unicode_property_escapes = true,
+            RegExpAstKind::LookAroundAssertion(assertion) => {
+                if matches!(
+                    assertion.kind,
+                    LookAroundAssertionKind::Lookbehind
+                        | LookAroundAssertionKind::NegativeLookbehind
+                ) {
+                    self.look_behind_assertions = true;
+                }
+            }
+            RegExpAstKind::IgnoreGroup(group) => {
+                if group.modifiers.is_some() {
+                    self.modifiers = true;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+pub struct LoweredPattern {
+    pub pattern: String,
+    pub flags: RegExpFlags,
+}
+
+/// Lower unsupported syntax in `pattern`.
+///
+/// Returns `None` if the pattern uses syntax which cannot be lowered.
+pub fn lower_pattern(
+    pattern: &Pattern<'_>,
+    flags: RegExpFlags,
+    features: &PatternFeatures<'_>,
+    options: RegExpOptions,
+) -> Option<LoweredPattern> {
+    let unicode_mode = flags.intersects(RegExpFlags::U | RegExpFlags::V);
+    let lower_sets = options.set_notation && flags.contains(RegExpFlags::V);
+    let lower_unicode = options.unicode_flag && (flags.contains(RegExpFlags::U) || lower_sets);
+    let lower_dot_all = options.dot_all_flag && flags.contains(RegExpFlags::S);
+    let lower_properties = options.unicode_property_escapes && unicode_mode;
+    if features.modifiers && (lower_unicode || lower_sets || lower_dot_all || lower_properties) {
+        return None;
+    }
+
+    let mut new_flags = flags;
+    if lower_dot_all {
+        new_flags.remove(RegExpFlags::S);
+    }
+    if lower_sets {
+        new_flags.remove(RegExpFlags::V);
+        new_flags.insert(RegExpFlags::U);
+    }
+    if lower_unicode {
+        new_flags.remove(RegExpFlags::U);
+    }
+
+    let mut lowering = Lowering {
+        out: String::new(),
+        group_names: options.named_capture_groups.then_some(features.group_names.as_slice()),
+        ignore_case: flags.contains(RegExpFlags::I),
+        dot_all: flags.contains(RegExpFlags::S),
+        unicode_mode,
+        unicode_sets: flags.contains(RegExpFlags::V),
+        lower_unicode,
+        lower_sets,
+        lower_dot_all,
+        lower_properties,
+        target_unicode: new_flags.contains(RegExpFlags::U),
+    };
+    lowering.disjunction(&pattern.body)?;
+    if lowering.out.is_empty() {
+        // `//` would be a comment
+        lowering.out.push_str("(?:)");
+    }
+
+    Some(LoweredPattern { pattern: lowering.out, flags: new_flags })
+}
+
+struct Lowering<'f, 'a> {
+    out: String,
+    /// Set when lowering named capture groups
+    group_names: Option<&'f [(Atom<'a>, Vec<u32>)]>,
+    ignore_case: bool,
+    dot_all: bool,
+    /// The source pattern has `u` or `v` flag
+    unicode_mode: bool,
+    /// The source pattern has `v` flag
+    unicode_sets: bool,
+    lower_unicode: bool,
+    lower_sets: bool,
+    lower_dot_all: bool,
+    lower_properties: bool,
+    /// The lowered pattern has `u` flag
+    target_unicode: bool,
+}
+
+/// Code point sets and strings a character class matches.
+/// Strings only come from `\q{...}` with `v` flag.
+type ClassMatches = (CharSet, Vec<Vec<u32>>);
+
+impl Lowering<'_, '_> {
+    fn disjunction(&mut self, disjunction: &Disjunction<'_>) -> Option<()> {
+        for (index, alternative) in disjunction.body.iter().enumerate() {
+            if index > 0 {
+                self.out.push('|');
+            }
+            self.alternative(alternative)?;
+        }
+        Some(())
+    }
+
+    fn alternative(&mut self, alternative: &Alternative<'_>) -> Option<()> {
+        let mut after_reference = false;
+        for term in &alternative.body {
+            let start = self.out.len();
+            self.term(term)?;
+            // `\1` followed by `0` would be read as `\10`
+            if after_reference && self.out[start..].starts_with(|c: char| c.is_ascii_digit()) {
+                self.out.insert_str(start, "(?:)");
+            }
+            after_reference = matches!(term, Term::IndexedReference(_) | Term::NamedReference(_));
+        }
+        Some(())
+    }
+
+    /// Write `term`, and return whether the output is a single atom which can be quantified.
+    fn term(&mut self, term: &Term<'_>) -> Option<bool> {
+        match term {
+            Term::BoundaryAssertion(assertion) => self.display(&**assertion),
+            Term::LookAroundAssertion(assertion) => {
+                write!(self.out, "({}", assertion.kind).unwrap();
+                self.disjunction(&assertion.body)?;
+                self.out.push(')');
+            }
+            Term::Quantifier(quantifier) => self.quantifier(quantifier)?,
+            Term::Character(character) => return Some(self.character(character)),
+            Term::Dot(_) => return Some(self.dot()),
+            Term::CharacterClassEscape(escape) => return Some(self.character_class_escape(escape)),
+            Term::UnicodePropertyEscape(escape) => return self.unicode_property_escape(escape),
+            Term::CharacterClass(class) => return self.character_class(class),
+            Term::CapturingGroup(group) => self.capturing_group(group)?,
+            Term::IgnoreGroup(group) => self.ignore_group(group)?,
+            Term::IndexedReference(reference) => self.display(&**reference),
+            Term::NamedReference(reference) => self.named_reference(reference),
+        }
+        Some(true)
+    }
+
+    fn display(&mut self, node: &impl std::fmt::Display) {
+        write!(self.out, "{node}").unwrap();
+    }
+
+    fn quantifier(&mut self, quantifier: &Quantifier<'_>) -> Option<()> {
+        let start = self.out.len();
+        if !self.term(&quantifier.body)? {
+            self.out.insert_str(start, "(?:");
+            self.out.push(')');
+        }
+        match (quantifier.min, quantifier.max) {
+            (0, None) => self.out.push('*'),
+            (1, None) => self.out.push('+'),
+            (0, Some(1)) => self.out.push('?'),
+            (min, Some(max)) if min == max => write!(self.out, "{{{min}}}").unwrap(),
+            (min, Some(max)) => write!(self.out, "{{{min},{max}}}").unwrap(),
+            (min, None) => write!(self.out, "{{{min},}}").unwrap(),
+        }
+        if !quantifier.greedy {
+            self.out.push('?');
+        }
+        Some(())
+    }
+
+    fn capturing_group(&mut self, group: &CapturingGroup<'_>) -> Option<()> {
+        self.out.push('(');
+        if let (Some(name), None) = (&group.name, self.group_names) {
+            write!(self.out, "?<{name}>").unwrap();
+        }
+        self.disjunction(&group.body)?;
+        self.out.push(')');
+        Some(())
+    }
+
+    fn ignore_group(&mut self, group: &IgnoreGroup<'_>) -> Option<()> {
+        fn write_modifier(out: &mut String, modifier: &Modifier) {
+            if modifier.ignore_case {
+                out.push('i');
+            }
+            if modifier.multiline {
+                out.push('m');
+            }
+            if modifier.sticky {
+                out.push('s');
+            }
+        }
+
+        self.out.push_str("(?");
+        if let Some(modifiers) = &group.modifiers {
+            if let Some(enabling) = &modifiers.enabling {
+                write_modifier(&mut self.out, enabling);
+            }
+            if let Some(disabling) = &modifiers.disabling {
+                self.out.push('-');
+                write_modifier(&mut self.out, disabling);
+            }
+        }
+        self.out.push(':');
+        self.disjunction(&group.body)?;
+        self.out.push(')');
+        Some(())
+    }
+
+    fn named_reference(&mut self, reference: &NamedReference<'_>) {
+        let indexes = self.group_names.and_then(|group_names| {
+            group_names.iter().find(|(name, _)| *name == reference.name).map(|(_, indexes)| indexes)
+        });
+        match indexes.map(Vec::as_slice) {
+            None => self.display(reference),
+            Some([index]) => write!(self.out, r"\{index}").unwrap(),
+            // Only one of the duplicated groups can participate in a match,
+            // and a backreference to a group which did not participate matches the empty string.
+            Some(indexes) => {
+                self.out.push_str("(?:");
+                for index in indexes {
+                    write!(self.out, r"\{index}").unwrap();
+                }
+                self.out.push(')');
+            }
+        }
+    }
+
+    fn character(&mut self, character: &Character) -> bool {
+        let cp = character.value;
+        if !self.lower_unicode {
+            self.display(character);
+            return true;
+        }
+        if self.ignore_case {
+            // Without `u` flag, case-insensitive matching does not use case folding,
+            // and never matches astral code points case-insensitively.
+            let mut folded = CharSet::from_code_point(cp);
+            folded.case_fold();
+            let same_without_unicode = folded.ranges().iter().all(|&(min, max)| {
+                max <= 0xFFFF
+                    && (min..=max)
+                        .all(|c| canonicalize_non_unicode(c) == canonicalize_non_unicode(cp))
+            });
+            if !same_without_unicode {
+                return self.char_set(folded);
+            }
+        }
+        if is_surrogate(cp) {
+            return self.char_set(CharSet::from_code_point(cp));
+        }
+        push_code_point(&mut self.out, cp, false, false);
+        cp <= 0xFFFF
+    }
+
+    fn dot(&mut self) -> bool {
+        if self.lower_unicode {
+            let mut set = CharSet::full();
+            if !self.dot_all {
+                set = set.difference(&char_set::line_terminators());
+            }
+            return self.char_set(set);
+        }
+        self.out.push_str(if self.lower_dot_all { r"[\s\S]" } else { "." });
+        true
+    }
+
+    fn character_class_escape(&mut self, escape: &CharacterClassEscape) -> bool {
+        // `\d`, `\s` and `\w` match the same code units without `u` flag,
+        // but negated escapes also match astral code points.
+        let same_without_unicode = match escape.kind {
+            CharacterClassEscapeKind::D | CharacterClassEscapeKind::S => true,
+            CharacterClassEscapeKind::W => !self.ignore_case,
+            _ => false,
+        };
+        if !self.lower_unicode || same_without_unicode {
+            self.display(escape);
+            return true;
+        }
+        let set = self.character_class_escape_set(escape.kind);
+        self.char_set(set)
+    }
+
+    fn character_class_escape_set(&self, kind: CharacterClassEscapeKind) -> CharSet {
+        let (set, negative) = match kind {
+            CharacterClassEscapeKind::D => (char_set::digits(), false),
+            CharacterClassEscapeKind::NegativeD => (char_set::digits(), true),
+            CharacterClassEscapeKind::S => (char_set::white_space(), false),
+            CharacterClassEscapeKind::NegativeS => (char_set::white_space(), true),
+            CharacterClassEscapeKind::W => {
+                (char_set::word_characters(self.unicode_mode && self.ignore_case), false)
+            }
+            CharacterClassEscapeKind::NegativeW => {
+                (char_set::word_characters(self.unicode_mode && self.ignore_case), true)
+            }
+        };
+        self.maybe_fold_and_negate(set, negative)
+    }
+
+    fn unicode_property_escape(&mut self, escape: &UnicodePropertyEscape<'_>) -> Option<bool> {
+        if !self.lower_unicode && !self.lower_properties {
+            self.display(escape);
+            return Some(true);
+        }
+        let set = self.unicode_property_escape_set(escape)?;
+        Some(self.char_set(set))
+    }
+
+    fn unicode_property_escape_set(&self, escape: &UnicodePropertyEscape<'_>) -> Option<CharSet> {
+        if escape.strings {
+            return None;
+        }
+        let set = char_set::unicode_property(&escape.name, escape.value.as_deref())?;
+        Some(self.maybe_fold_and_negate(set, escape.negative))
+    }
+
+    /// With `v` and `i` flags, operands are case folded before negation and set operations.
+    fn maybe_fold_and_negate(&self, mut set: CharSet, negative: bool) -> CharSet {
+        if self.unicode_sets && self.ignore_case {
+            set.case_fold();
+        }
+        if negative { set.negate() } else { set }
+    }
+
+    fn character_class(&mut self, class: &CharacterClass<'_>) -> Option<bool> {
+        let needs_lowering = self.lower_unicode
+            || self.lower_sets
+            || (self.lower_properties && has_unicode_property_escape(class));
+        if !needs_lowering {
+            self.display(class);
+            return Some(true);
+        }
+
+        let (set, mut strings) = self.character_class_matches(class)?;
+        if strings.is_empty() {
+            return Some(self.char_set(set));
+        }
+
+        // Longest strings first, as alternatives are tried in order.
+        strings.sort_by_key(|string| std::cmp::Reverse(string.len()));
+        self.out.push_str("(?:");
+        for string in &strings {
+            for &cp in string {
+                push_code_point(&mut self.out, cp, false, self.target_unicode);
+            }
+            self.out.push('|');
+        }
+        if set.is_empty() {
+            self.out.pop();
+        } else {
+            self.char_set(set);
+        }
+        self.out.push(')');
+        Some(true)
+    }
+
+    fn character_class_matches(&self, class: &CharacterClass<'_>) -> Option<ClassMatches> {
+        let mut operands = class.body.iter().map(|contents| self.character_class_operand(contents));
+        let (mut set, mut strings) = match operands.next() {
+            Some(first) => first?,
+            None => ClassMatches::default(),
+        };
+        for operand in operands {
+            let (operand_set, operand_strings) = operand?;
+            match class.kind {
+                CharacterClassContentsKind::Union => {
+                    set.union(&operand_set);
+                    for string in operand_strings {
+                        if !strings.contains(&string) {
+                            strings.push(string);
+                        }
+                    }
+                }
+                CharacterClassContentsKind::Intersection => {
+                    set = set.intersect(&operand_set);
+                    strings.retain(|string| operand_strings.contains(string));
+                }
+                CharacterClassContentsKind::Subtraction => {
+                    set = set.difference(&operand_set);
+                    strings.retain(|string| !operand_strings.contains(string));
+                }
+            }
+        }
+        if class.negative {
+            // Negated classes cannot contain strings
+            if self.ignore_case {
+                set.case_fold();
+            }
+            set = set.negate();
+        }
+        Some((set, strings))
+    }
+
+    fn character_class_operand(
+        &self,
+        contents: &CharacterClassContents<'_>,
+    ) -> Option<ClassMatches> {
+        let set = match contents {
+            CharacterClassContents::CharacterClassRange(range) => self.maybe_fold_and_negate(
+                CharSet::from_range(range.min.value, range.max.value),
+                false,
+            ),
+            CharacterClassContents::CharacterClassEscape(escape) => {
+                self.character_class_escape_set(escape.kind)
+            }
+            CharacterClassContents::UnicodePropertyEscape(escape) => {
+                self.unicode_property_escape_set(escape)?
+            }
+            CharacterClassContents::Character(character) => {
+                self.maybe_fold_and_negate(CharSet::from_code_point(character.value), false)
+            }
+            CharacterClassContents::NestedCharacterClass(class) => {
+                return self.character_class_matches(class);
+            }
+            CharacterClassContents::ClassStringDisjunction(disjunction) => {
+                let mut set = CharSet::default();
+                let mut strings = vec![];
+                for string in &disjunction.body {
+                    match string.body.as_slice() {
+                        [character] => set.add(character.value),
+                        body => strings.push(body.iter().map(|c| c.value).collect()),
+                    }
+                }
+                return Some((self.maybe_fold_and_negate(set, false), strings));
+            }
+        };
+        Some((set, vec![]))
+    }
+
+    /// Write a set of code points, and return whether the output is a single atom.
+    fn char_set(&mut self, mut set: CharSet) -> bool {
+        if self.lower_unicode && self.ignore_case {
+            set.case_fold();
+        }
+        if self.target_unicode {
+            write_class_or_character(&mut self.out, &set, true);
+            return true;
+        }
+
+        let parts = non_unicode_alternatives(&set);
+        match parts.as_slice() {
+            [] => self.out.push_str("[]"),
+            [(part, atomic)] => {
+                self.out.push_str(part);
+                return *atomic;
+            }
+            parts => {
+                self.out.push_str("(?:");
+                for (index, (part, _)) in parts.iter().enumerate() {
+                    if index > 0 {
+                        self.out.push('|');
+                    }
+                    self.out.push_str(part);
+                }
+                self.out.push(')');
+            }
+        }
+        true
+    }
+}
+
+fn has_unicode_property_escape(class: &CharacterClass<'_>) -> bool {
+    class.body.iter().any(|contents| match contents {
+        CharacterClassContents::UnicodePropertyEscape(_) => true,
+        CharacterClassContents::NestedCharacterClass(class) => has_unicode_property_escape(class),
+        _ => false,
+    })
+}
+
+/// Alternatives matching `set` without `u` flag, and whether each is a single atom.
+///
+/// Astral code points are matched as surrogate pairs, and lone surrogates only when they are not
+/// part of a pair, like they are with `u` flag.
+fn non_unicode_alternatives(set: &CharSet) -> Vec<(String, bool)> {
+    let mut alternatives = vec![];
+
+    let mut bmp = set.clip(0, LEAD_SURROGATES.0 - 1);
+    bmp.union(&set.clip(TRAIL_SURROGATES.1 + 1, 0xFFFF));
+    if !bmp.is_empty() {
+        let mut out = String::new();
+        write_class_or_character(&mut out, &bmp, false);
+        alternatives.push((out, true));
+    }
+
+    for (leads, trails) in surrogate_pairs(&set.clip(0x10000, MAX_CODE_POINT)) {
+        let mut out = String::new();
+        write_class_or_character(&mut out, &leads, false);
+        write_class_or_character(&mut out, &trails, false);
+        alternatives.push((out, false));
+    }
+
+    let leads = set.clip(LEAD_SURROGATES.0, LEAD_SURROGATES.1);
+    if !leads.is_empty() {
+        let mut out = String::new();
+        write_class_or_character(&mut out, &leads, false);
+        out.push_str(r"(?![\uDC00-\uDFFF])");
+        alternatives.push((out, false));
+    }
+
+    let trails = set.clip(TRAIL_SURROGATES.0, TRAIL_SURROGATES.1);
+    if !trails.is_empty() {
+        let mut out = String::from(r"(?:[^\uD800-\uDBFF]|^)");
+        write_class_or_character(&mut out, &trails, false);
+        alternatives.push((out, false));
+    }
+
+    alternatives
+}
+
+/// Split astral code points into lead surrogate sets and the trail surrogates which follow them.
+fn surrogate_pairs(astral: &CharSet) -> Vec<(CharSet, CharSet)> {
+    let mut pairs: Vec<(CharSet, CharSet)> = vec![];
+    let mut push = |lead_min: u32, lead_max: u32, trail_min: u32, trail_max: u32| {
+        let leads = CharSet::from_range(lead_min, lead_max);
+        let trails = CharSet::from_range(trail_min, trail_max);
+        if let Some((l, _)) = pairs.iter_mut().find(|(_, t)| *t == trails) {
+            l.union(&leads);
+        } else if let Some((_, t)) = pairs.iter_mut().find(|(l, _)| *l == leads) {
+            t.union(&trails);
+        } else {
+            pairs.push((leads, trails));
+        }
+    };
+
+    let (first_trail, last_trail) = TRAIL_SURROGATES;
+    for &(min, max) in astral.ranges() {
+        let (min_lead, min_trail) = split_surrogates(min);
+        let (max_lead, max_trail) = split_surrogates(max);
+        if min_lead == max_lead {
+            push(min_lead, min_lead, min_trail, max_trail);
+            continue;
+        }
+        let mut full_min = min_lead;
+        let mut full_max = max_lead;
+        if min_trail != first_trail {
+            push(min_lead, min_lead, min_trail, last_trail);
+            full_min += 1;
+        }
+        if max_trail != last_trail {
+            full_max -= 1;
+        }
+        if full_min <= full_max {
+            push(full_min, full_max, first_trail, last_trail);
+        }
+        if max_trail != last_trail {
+            push(max_lead, max_lead, first_trail, max_trail);
+        }
+    }
+    pairs
+}
+
+fn split_surrogates(cp: u32) -> (u32, u32) {
+    let offset = cp - 0x10000;
+    (LEAD_SURROGATES.0 + (offset >> 10), TRAIL_SURROGATES.0 + (offset & 0x3FF))
+}
+
+fn is_surrogate(cp: u32) -> bool {
+    (LEAD_SURROGATES.0..=TRAIL_SURROGATES.1).contains(&cp)
+}
+
+fn write_class_or_character(out: &mut String, set: &CharSet, unicode: bool) {
+    if let Some(cp) = set.single() {
+        push_code_point(out, cp, false, unicode);
+        return;
+    }
+    out.push('[');
+    for &(min, max) in set.ranges() {
+        push_code_point(out, min, true, unicode);
+        if max > min {
+            if max > min + 1 {
+                out.push('-');
+            }
+            push_code_point(out, max, true, unicode);
+        }
+    }
+    out.push(']');
+}
+
+/// Write a code point, escaped if it is not printable ASCII or has a special meaning.
+/// Without `unicode`, astral code points are written as surrogate pairs.
+fn push_code_point(out: &mut String, cp: u32, in_class: bool, unicode: bool) {
+    match cp {
+        0x20..=0x7E => {
+            let ch = char::from_u32(cp).unwrap();
+            let syntax = if in_class {
+                matches!(ch, '\\' | ']' | '[' | '-' | '^' | '/' | '(' | ')' | '{' | '}' | '|')
+            } else {
+                matches!(
+                    ch,
+                    '^' | '$'
+                        | '\\'
+                        | '.'
+                        | '*'
+                        | '+'
+                        | '?'
+                        | '('
+                        | ')'
+                        | '['
+                        | ']'
+                        | '{'
+                        | '}'
+                        | '|'
+                        | '/'
+                )
+            };
+            if syntax {
+                out.push('\\');
+            }
+            out.push(ch);
+        }
+        0..=0xFF => write!(out, r"\x{cp:02X}").unwrap(),
+        _ if unicode && (is_surrogate(cp) || cp > 0xFFFF) => write!(out, r"\u{{{cp:X}}}").unwrap(),
+        0x100..=0xFFFF => write!(out, r"\u{cp:04X}").unwrap(),
+        _ => {
+            let (lead, trail) = split_surrogates(cp);
+            write!(out, r"\u{lead:04X}\u{trail:04X}").unwrap();
+        }
+    }
+}
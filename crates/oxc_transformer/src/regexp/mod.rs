@@ -1,10 +1,16 @@
 //! RegExp Transformer
 //!
 //! This module supports various RegExp plugins to handle unsupported RegExp literal features.
-//! When an unsupported feature is detected, these plugins convert the RegExp literal into
-//! a `new RegExp()` constructor call to avoid syntax errors.
+//! When an unsupported feature is detected, the pattern is rewritten into an equivalent pattern
+//! which the target engine supports (see [`lower`] for details).
 //!
-//! Note: You will need to include a polyfill for the `RegExp` constructor in your code to have the correct runtime behavior.
+//! Named capture groups are turned into numbered groups, and the RegExp is wrapped in
+//! the `wrapRegExp` helper, which adds `groups` to match results.
+//!
+//! Features which cannot be expressed in a pattern (sticky flag, match indices flag, lookbehind
+//! assertions and properties of strings) fall back to a `new RegExp()` constructor call
+//! to avoid syntax errors. You will need to include a polyfill for the `RegExp` constructor
+//! in your code to have the correct runtime behavior for these.
 //!
 //! ### ES2015
 //!
@@ -48,26 +54,26 @@ use std::borrow::Cow;
 
 use oxc_ast::{NONE, ast::*};
 use oxc_diagnostics::Result;
-use oxc_regular_expression::ast::{
-    CharacterClass, CharacterClassContents, LookAroundAssertionKind, Pattern, Term,
-};
+use oxc_regular_expression::ast::Pattern;
 use oxc_semantic::ReferenceFlags;
 use oxc_span::{Atom, SPAN};
+use oxc_syntax::number::NumberBase;
 use oxc_traverse::{Traverse, TraverseCtx};
 
-use crate::TransformCtx;
+use crate::{TransformCtx, common::helper_loader::Helper};
 
+mod char_set;
+mod lower;
 mod options;
 
+use lower::{PatternFeatures, lower_pattern};
 pub use options::RegExpOptions;
 
 pub struct RegExp<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
+    options: RegExpOptions,
     unsupported_flags: RegExpFlags,
     some_unsupported_patterns: bool,
-    look_behind_assertions: bool,
-    named_capture_groups: bool,
-    unicode_property_escapes: bool,
 }
 
 impl<'a, 'ctx> RegExp<'a, 'ctx> {
@@ -91,24 +97,11 @@ impl<'a, 'ctx> RegExp<'a, 'ctx> {
         }
 
         // Get if some unsupported patterns
-        let RegExpOptions {
-            look_behind_assertions,
-            named_capture_groups,
-            unicode_property_escapes,
-            ..
-        } = options;
-
-        let some_unsupported_patterns =
-            look_behind_assertions || named_capture_groups || unicode_property_escapes;
-
-        Self {
-            ctx,
-            unsupported_flags,
-            some_unsupported_patterns,
-            look_behind_assertions,
-            named_capture_groups,
-            unicode_property_escapes,
-        }
+        let some_unsupported_patterns = options.look_behind_assertions
+            || options.named_capture_groups
+            || options.unicode_property_escapes;
+
+        Self { ctx, options, unsupported_flags, some_unsupported_patterns }
     }
 }
 
@@ -125,122 +118,160 @@ impl<'a> Traverse<'a> for RegExp<'a, '_> {
 
         let flags = regexp.regex.flags;
         let has_unsupported_flags = flags.intersects(self.unsupported_flags);
-        if !has_unsupported_flags {
-            if !self.some_unsupported_patterns {
-                // This RegExp has no unsupported flags, and there are no patterns which may need transforming,
-                // so there's nothing to do
-                return;
-            }
+        if !has_unsupported_flags && !self.some_unsupported_patterns {
+            // This RegExp has no unsupported flags, and there are no patterns which may need transforming,
+            // so there's nothing to do
+            return;
+        }
 
-            let literal_span = regexp.span;
-            let pattern = match &mut regexp.regex.pattern {
-                RegExpPattern::Raw(raw) => {
-                    #[expect(clippy::cast_possible_truncation)]
-                    let pattern_len = raw.len() as u32;
-                    let pattern_span_start = literal_span.start + 1; // +1 to skip the opening `/`
-                    let flags_span_start = pattern_span_start + pattern_len + 1; // +1 to skip the closing `/`
-                    let flags_text = Span::new(flags_span_start, literal_span.end)
-                        .source_text(self.ctx.source_text);
-                    // Try to parse pattern
-                    match try_parse_pattern(
-                        raw,
-                        pattern_span_start,
-                        flags_text,
-                        flags_span_start,
-                        ctx,
-                    ) {
-                        Ok(pattern) => {
-                            regexp.regex.pattern = RegExpPattern::Pattern(ctx.alloc(pattern));
-                            let RegExpPattern::Pattern(pattern) = &regexp.regex.pattern else {
-                                unreachable!()
-                            };
-                            pattern
-                        }
-                        Err(error) => {
-                            regexp.regex.pattern = RegExpPattern::Invalid(raw);
-                            self.ctx.error(error);
-                            return;
-                        }
-                    }
-                }
-                RegExpPattern::Invalid(_) => return,
-                RegExpPattern::Pattern(pattern) => &**pattern,
-            };
+        let span = regexp.span;
+        let raw = match regexp.regex.pattern {
+            RegExpPattern::Raw(raw) => Some(raw),
+            _ => None,
+        };
+        let Some(pattern) = self.parse_pattern(regexp, ctx) else {
+            return;
+        };
 
-            if !self.has_unsupported_regular_expression_pattern(pattern) {
-                return;
-            }
+        let features = PatternFeatures::collect(pattern);
+        let named_capture_groups =
+            self.options.named_capture_groups && !features.group_names.is_empty();
+        let look_behind_assertions =
+            self.options.look_behind_assertions && features.look_behind_assertions;
+        let unicode_property_escapes =
+            self.options.unicode_property_escapes && features.unicode_property_escapes;
+        if !has_unsupported_flags
+            && !named_capture_groups
+            && !look_behind_assertions
+            && !unicode_property_escapes
+        {
+            return;
         }
 
-        let pattern_source: Cow<'_, str> = match &regexp.regex.pattern {
-            RegExpPattern::Raw(raw) => Cow::Borrowed(raw),
-            RegExpPattern::Pattern(p) => Cow::Owned(p.to_string()),
-            RegExpPattern::Invalid(_) => return,
+        let Some(lowered) = lower_pattern(pattern, flags, &features, self.options) else {
+            // Cannot lower, so keep the pattern (including group names) as it is
+            let pattern_source = raw.map_or_else(|| Cow::Owned(pattern.to_string()), Cow::Borrowed);
+            *expr = Self::create_regexp_constructor(span, &pattern_source, flags, ctx);
+            return;
         };
 
+        let mut new_expr =
+            if lowered.flags.intersects(self.unsupported_flags) || look_behind_assertions {
+                Self::create_regexp_constructor(span, &lowered.pattern, lowered.flags, ctx)
+            } else {
+                let pattern = RegExpPattern::Raw(ctx.ast.allocator.alloc_str(&lowered.pattern));
+                let regex = oxc_ast::ast::RegExp { pattern, flags: lowered.flags };
+                ctx.ast.expression_reg_exp_literal(span, regex, None)
+            };
+
+        if named_capture_groups {
+            new_expr = self.wrap_named_capture_groups(new_expr, span, &features, ctx);
+        }
+        *expr = new_expr;
+    }
+}
+
+impl<'a> RegExp<'a, '_> {
+    /// Parse the pattern of `regexp` if it has not been parsed yet.
+    ///
+    /// The parsed pattern is not stored in `regexp`, so a literal which is not transformed
+    /// is printed as it was written. Returns `None` if the pattern is invalid.
+    fn parse_pattern<'r>(
+        &self,
+        regexp: &'r mut RegExpLiteral<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<&'r Pattern<'a>> {
+        match regexp.regex.pattern {
+            RegExpPattern::Raw(raw) => {
+                #[expect(clippy::cast_possible_truncation)]
+                let pattern_len = raw.len() as u32;
+                let pattern_span_start = regexp.span.start + 1; // +1 to skip the opening `/`
+                let flags_span_start = pattern_span_start + pattern_len + 1; // +1 to skip the closing `/`
+                // Lowered RegExps are visited again with the span of the original literal,
+                // so take flags from the AST rather than the source text
+                let flags_text =
+                    ctx.ast.allocator.alloc_str(regexp.regex.flags.to_inline_string().as_str());
+                // Try to parse pattern
+                match try_parse_pattern(raw, pattern_span_start, flags_text, flags_span_start, ctx)
+                {
+                    Ok(pattern) => Some(ctx.ast.allocator.alloc(pattern)),
+                    Err(error) => {
+                        regexp.regex.pattern = RegExpPattern::Invalid(raw);
+                        self.ctx.error(error);
+                        None
+                    }
+                }
+            }
+            RegExpPattern::Invalid(_) => None,
+            RegExpPattern::Pattern(ref pattern) => Some(pattern),
+        }
+    }
+
+    /// `new RegExp(pattern, flags)`
+    fn create_regexp_constructor(
+        span: Span,
+        pattern: &str,
+        flags: RegExpFlags,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
         let callee = {
             let symbol_id = ctx.scoping().find_binding(ctx.current_scope_id(), "RegExp");
             ctx.create_ident_expr(SPAN, Atom::from("RegExp"), symbol_id, ReferenceFlags::read())
         };
 
         let arguments = ctx.ast.vec_from_array([
-            Argument::from(ctx.ast.expression_string_literal(SPAN, pattern_source, None)),
+            Argument::from(ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(pattern), None)),
             Argument::from(ctx.ast.expression_string_literal(
                 SPAN,
-                flags.to_inline_string().as_str(),
+                ctx.ast.atom(flags.to_inline_string().as_str()),
                 None,
             )),
         ]);
 
-        *expr = ctx.ast.expression_new(regexp.span, callee, arguments, NONE);
-    }
-}
-
-impl<'a> RegExp<'a, '_> {
-    /// Check if the regular expression contains any unsupported syntax.
-    ///
-    /// Based on parsed regular expression pattern.
-    fn has_unsupported_regular_expression_pattern(&self, pattern: &Pattern<'a>) -> bool {
-        pattern.body.body.iter().any(|alternative| {
-            alternative.body.iter().any(|term| self.term_contains_unsupported(term))
-        })
+        ctx.ast.expression_new(span, callee, arguments, NONE)
     }
 
-    fn term_contains_unsupported(&self, mut term: &Term) -> bool {
-        // Loop because `Term::Quantifier` contains a nested `Term`
-        loop {
-            match term {
-                Term::CapturingGroup(_) => return self.named_capture_groups,
-                Term::UnicodePropertyEscape(_) => return self.unicode_property_escapes,
-                Term::CharacterClass(character_class) => {
-                    return self.unicode_property_escapes
-                        && character_class_has_unicode_property_escape(character_class);
-                }
-                Term::LookAroundAssertion(assertion) => {
-                    return self.look_behind_assertions
-                        && matches!(
-                            assertion.kind,
-                            LookAroundAssertionKind::Lookbehind
-                                | LookAroundAssertionKind::NegativeLookbehind
-                        );
-                }
-                Term::Quantifier(quantifier) => term = &quantifier.body,
-                _ => return false,
-            }
-        }
+    /// `_wrapRegExp(regexp, { name: 1, duplicated: [2, 3] })`
+    fn wrap_named_capture_groups(
+        &self,
+        regexp: Expression<'a>,
+        span: Span,
+        features: &PatternFeatures<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let index_expr = |index: u32, ctx: &TraverseCtx<'a>| {
+            ctx.ast.expression_numeric_literal(SPAN, f64::from(index), None, NumberBase::Decimal)
+        };
+        let properties =
+            ctx.ast.vec_from_iter(features.group_names.iter().map(|(name, indexes)| {
+                let value =
+                    match indexes.as_slice() {
+                        [index] => index_expr(*index, ctx),
+                        indexes => ctx.ast.expression_array(
+                            SPAN,
+                            ctx.ast.vec_from_iter(indexes.iter().map(|index| {
+                                ArrayExpressionElement::from(index_expr(*index, ctx))
+                            })),
+                        ),
+                    };
+                ctx.ast.object_property_kind_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    ctx.ast.property_key_static_identifier(SPAN, *name),
+                    value,
+                    false,
+                    false,
+                    false,
+                )
+            }));
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(regexp),
+            Argument::from(ctx.ast.expression_object(SPAN, properties)),
+        ]);
+        self.ctx.helper_call_expr(Helper::WrapRegExp, span, arguments, ctx)
     }
 }
 
-fn character_class_has_unicode_property_escape(character_class: &CharacterClass) -> bool {
-    character_class.body.iter().any(|element| match element {
-        CharacterClassContents::UnicodePropertyEscape(_) => true,
-        CharacterClassContents::NestedCharacterClass(character_class) => {
-            character_class_has_unicode_property_escape(character_class)
-        }
-        _ => false,
-    })
-}
-
 fn try_parse_pattern<'a>(
     raw: &'a str,
     pattern_span_offset: u32,
@@ -2,7 +2,7 @@ use oxc_allocator::Vec as ArenaVec;
 use oxc_ast::{NONE, ast::*};
 use oxc_semantic::{ScopeFlags, ScopeId};
 use oxc_span::{GetSpan, SPAN};
-use oxc_traverse::TraverseCtx;
+use oxc_traverse::{BoundIdentifier, TraverseCtx};
 
 /// `object` -> `object.call`.
 pub fn create_member_callee<'a>(
@@ -102,3 +102,90 @@ pub fn create_compute_property_access<'a>(
     let expression = ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(property), None);
     Expression::from(ctx.ast.member_expression_computed(span, object, expression, false))
 }
+
+/// Convert a binding pattern to an assignment target.
+///
+/// `[a, { b = 1 }]` -> `[a, { b = 1 }]`
+pub fn create_assignment_target_from_pattern<'a>(
+    pattern: BindingPattern<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTarget<'a> {
+    match pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => {
+            let binding = BoundIdentifier::from_binding_ident(&ident);
+            binding.create_spanned_write_target(ident.span, ctx)
+        }
+        BindingPatternKind::ArrayPattern(array) => {
+            let ArrayPattern { span, elements, rest } = array.unbox();
+            let elements = ctx.ast.vec_from_iter(elements.into_iter().map(|element| {
+                element.map(|element| create_assignment_target_maybe_default(element, ctx))
+            }));
+            let rest = rest.map(|rest| {
+                let rest = rest.unbox();
+                ctx.ast.assignment_target_rest(
+                    rest.span,
+                    create_assignment_target_from_pattern(rest.argument, ctx),
+                )
+            });
+            AssignmentTarget::ArrayAssignmentTarget(
+                ctx.ast.alloc_array_assignment_target(span, elements, rest),
+            )
+        }
+        BindingPatternKind::ObjectPattern(object) => {
+            let ObjectPattern { span, properties, rest } = object.unbox();
+            let properties = ctx.ast.vec_from_iter(properties.into_iter().map(|property| {
+                let BindingProperty { span, key, value, shorthand, computed } = property;
+                if shorthand {
+                    // `{ a }` or `{ a = 1 }`
+                    let (ident, init) = match value.kind {
+                        BindingPatternKind::BindingIdentifier(ident) => (ident, None),
+                        BindingPatternKind::AssignmentPattern(assign) => {
+                            let AssignmentPattern { left, right, .. } = assign.unbox();
+                            let BindingPatternKind::BindingIdentifier(ident) = left.kind else {
+                                unreachable!()
+                            };
+                            (ident, Some(right))
+                        }
+                        _ => unreachable!(),
+                    };
+                    let binding = BoundIdentifier::from_binding_ident(&ident);
+                    let reference = binding.create_spanned_write_reference(ident.span, ctx);
+                    ctx.ast.assignment_target_property_assignment_target_property_identifier(
+                        span, reference, init,
+                    )
+                } else {
+                    // `{ a: b }`
+                    let binding = create_assignment_target_maybe_default(value, ctx);
+                    ctx.ast.assignment_target_property_assignment_target_property_property(
+                        span, key, binding, computed,
+                    )
+                }
+            }));
+            let rest = rest.map(|rest| {
+                let rest = rest.unbox();
+                ctx.ast.assignment_target_rest(
+                    rest.span,
+                    create_assignment_target_from_pattern(rest.argument, ctx),
+                )
+            });
+            AssignmentTarget::ObjectAssignmentTarget(
+                ctx.ast.alloc_object_assignment_target(span, properties, rest),
+            )
+        }
+        BindingPatternKind::AssignmentPattern(_) => unreachable!(),
+    }
+}
+
+/// Convert a binding pattern which may have a default value to an assignment target.
+fn create_assignment_target_maybe_default<'a>(
+    pattern: BindingPattern<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTargetMaybeDefault<'a> {
+    if let BindingPatternKind::AssignmentPattern(assign) = pattern.kind {
+        let AssignmentPattern { span, left, right } = assign.unbox();
+        let binding = create_assignment_target_from_pattern(left, ctx);
+        ctx.ast.assignment_target_maybe_default_assignment_target_with_default(span, binding, right)
+    } else {
+        AssignmentTargetMaybeDefault::from(create_assignment_target_from_pattern(pattern, ctx))
+    }
+}
@@ -22,6 +22,12 @@ fn es_target() {
         ("es2019", "1n ** 2n"), // test target error
         ("es2021", "class foo { static {} }"),
         ("es2021", "class Foo { #a; }"),
+        ("es5", "class A extends B { constructor() { super(); } foo() { super.foo(); } }"),
+        ("es5", "function f([a, b] = c, ...d) { const { e, ...g } = a; return h(...d, ...g); }"),
+        ("es5", "for (let i = 0; i < 3; i++) { const j = i; fns.push(() => j); }"),
+        ("es5", "function* f() { yield 1; } async function g() { await h(); }"),
+        ("es5", "var o = { a, b() {}, [c]: `d${e}` }; f`g${h}`;"),
+        ("es5", "for (const x of y) { if (x) break; } for (const z of [1, 2]) f(z);"),
     ];
 
     // Test no transformation for esnext.
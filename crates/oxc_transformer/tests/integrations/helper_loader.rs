@@ -0,0 +1,44 @@
+use oxc_transformer::{CommonJsOptions, HelperLoaderMode, Module, TransformOptions};
+
+use crate::test;
+
+#[test]
+fn inline_regenerator_runtime() {
+    let mut options = TransformOptions::from_target("es5").unwrap();
+    options.helper_loader.mode = HelperLoaderMode::Inline;
+
+    let code = test("function* f() { yield 1; } function* g() { yield* f(); }", &options).unwrap();
+    // Helper is inlined once at top of file, and called directly
+    assert!(code.starts_with("function _regeneratorRuntime() {"));
+    assert_eq!(code.matches("'use strict'").count(), 1);
+    assert!(code.contains("_regeneratorRuntime().mark(f)"));
+    assert!(code.contains("_regeneratorRuntime().wrap(function f$(_context)"));
+    assert!(!code.contains("babelHelpers"));
+}
+
+#[test]
+fn inline_unsupported_helper() {
+    let mut options = TransformOptions::from_target("es5").unwrap();
+    options.helper_loader.mode = HelperLoaderMode::Inline;
+
+    let errors = test("class A {}", &options).unwrap_err();
+    assert_eq!(
+        errors[0].to_string(),
+        "Helper `classCallCheck` cannot be inlined. Use `External` or `Runtime` helper mode."
+    );
+}
+
+#[test]
+fn inline_interop_require_helpers() {
+    let mut options = TransformOptions::default();
+    options.env.module = Module::CommonJS(CommonJsOptions::default());
+    options.helper_loader.mode = HelperLoaderMode::Inline;
+
+    let code = test("import a from 'a'; import * as b from 'b'; a(b);", &options).unwrap();
+    assert!(code.contains("function _interopRequireDefault("));
+    assert!(code.contains("function _interopRequireWildcard("));
+    assert!(code.contains("var _a = _interopRequireDefault(require('a'));"));
+    assert!(code.contains("var b = _interopRequireWildcard(require('b'));"));
+    assert!(code.contains("(0, _a.default)(b);"));
+    assert!(!code.contains("babelHelpers"));
+}
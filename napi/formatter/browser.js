@@ -0,0 +1 @@
+export * from '@oxc-formatter/binding-wasm32-wasi'
@@ -0,0 +1,164 @@
+/* auto-generated by NAPI-RS */
+/* eslint-disable */
+export interface ErrorLabel {
+  message?: string
+  start: number
+  end: number
+}
+
+/**
+ * Format synchronously.
+ *
+ * The file type is inferred from the extension of `filename`.
+ */
+export declare function format(filename: string, sourceText: string, options?: FormatOptions | undefined | null): FormatResult
+
+export interface FormatOptions {
+  /**
+   * Indent with tabs or spaces.
+   *
+   * @default 'space'
+   */
+  indentStyle?: 'tab' | 'space'
+  /**
+   * The number of spaces per indentation level.
+   *
+   * @default 2
+   */
+  indentWidth?: number
+  /**
+   * The type of line ending.
+   *
+   * @default 'lf'
+   */
+  lineEnding?: 'lf' | 'crlf' | 'cr'
+  /**
+   * The line length that the formatter will try to wrap on.
+   *
+   * @default 80
+   */
+  lineWidth?: number
+  /**
+   * The quotes of string literals.
+   *
+   * @default 'double'
+   */
+  quoteStyle?: 'double' | 'single'
+  /**
+   * The quotes of JSX attributes.
+   *
+   * @default 'double'
+   */
+  jsxQuoteStyle?: 'double' | 'single'
+  /**
+   * When properties in objects are quoted.
+   *
+   * @default 'as-needed'
+   */
+  quoteProperties?: 'as-needed' | 'preserve'
+  /**
+   * Print trailing commas wherever possible in multi-line comma-separated syntactic structures.
+   *
+   * @default 'all'
+   */
+  trailingCommas?: 'all' | 'es5' | 'none'
+  /**
+   * Print semicolons for all statements, or only where necessary.
+   *
+   * @default 'always'
+   */
+  semicolons?: 'always' | 'as-needed'
+  /**
+   * Add parentheses around a sole arrow function parameter always, or only where necessary.
+   *
+   * @default 'always'
+   */
+  arrowParentheses?: 'always' | 'as-needed'
+  /**
+   * Print spaces between brackets in object literals.
+   *
+   * @default true
+   */
+  bracketSpacing?: boolean
+  /**
+   * Put the `>` of a multi-line JSX element at the end of the last line
+   * instead of being alone on the next line.
+   *
+   * @default false
+   */
+  bracketSameLine?: boolean
+  /**
+   * The position of JSX attributes.
+   *
+   * @default 'auto'
+   */
+  attributePosition?: 'auto' | 'multiline'
+  /**
+   * Whether to expand object and array literals to multiple lines.
+   *
+   * @default 'auto'
+   */
+  expand?: 'auto' | 'always' | 'never'
+  /**
+   * Format code embedded in template literals, such as CSS and GraphQL.
+   *
+   * @default 'auto'
+   */
+  embeddedLanguageFormatting?: 'auto' | 'off'
+  /**
+   * Sort and group contiguous import declarations.
+   *
+   * @default false
+   */
+  sortImports?: boolean | SortImportsOptions
+}
+
+export interface FormatResult {
+  /**
+   * The formatted code.
+   *
+   * If parsing failed, this will be the source text as is.
+   */
+  code: string
+  /**
+   * Parse errors.
+   *
+   * The code is only formatted if there are no errors.
+   */
+  errors: Array<OxcError>
+}
+
+export interface OxcError {
+  severity: Severity
+  message: string
+  labels: Array<ErrorLabel>
+  helpMessage?: string
+  codeframe?: string
+}
+
+export declare const enum Severity {
+  Error = 'Error',
+  Warning = 'Warning',
+  Advice = 'Advice'
+}
+
+export interface SortImportsOptions {
+  /**
+   * The order of the groups. Imports of a group which is not listed are put after the others.
+   *
+   * @default ['builtin', 'external', 'internal', 'relative']
+   */
+  groups?: Array<'builtin' | 'external' | 'internal' | 'relative'>
+  /**
+   * The prefixes of the sources of internal imports, such as path aliases.
+   *
+   * @default ['~/', '@/', '#']
+   */
+  internalPrefixes?: Array<string>
+  /**
+   * Separate the groups with a blank line.
+   *
+   * @default true
+   */
+  newlinesBetween?: boolean
+}
@@ -0,0 +1,56 @@
+#![expect(clippy::needless_pass_by_value, clippy::missing_errors_doc)]
+
+#[cfg(all(feature = "allocator", not(target_arch = "arm"), not(target_family = "wasm")))]
+#[global_allocator]
+static ALLOC: mimalloc_safe::MiMalloc = mimalloc_safe::MiMalloc;
+
+mod options;
+
+use napi_derive::napi;
+
+use oxc_allocator::Allocator;
+use oxc_formatter::Formatter;
+use oxc_napi::OxcError;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+use crate::options::{FormatOptions, FormatResult};
+
+/// Format synchronously.
+///
+/// The file type is inferred from the extension of `filename`.
+#[napi]
+pub fn format(
+    filename: String,
+    source_text: String,
+    options: Option<FormatOptions>,
+) -> napi::Result<FormatResult> {
+    let options = options.unwrap_or_default();
+
+    let format_options = match oxc_formatter::FormatOptions::try_from(&options) {
+        Ok(options) => options,
+        Err(error) => return Err(napi::Error::from_reason(&error)),
+    };
+
+    let source_type = match SourceType::from_path(&filename) {
+        Ok(source_type) => source_type,
+        Err(error) => {
+            return Ok(FormatResult {
+                code: source_text,
+                errors: vec![OxcError::new(error.to_string())],
+            });
+        }
+    };
+
+    let allocator = Allocator::default();
+
+    let ret = Parser::new(&allocator, &source_text, source_type).parse();
+    if !ret.errors.is_empty() {
+        let errors = OxcError::from_diagnostics(&filename, &source_text, ret.errors);
+        return Ok(FormatResult { code: source_text, errors });
+    }
+
+    let code = Formatter::new(&allocator, format_options).build(&ret.program);
+
+    Ok(FormatResult { code, errors: vec![] })
+}
@@ -0,0 +1,236 @@
+use std::{fmt::Display, str::FromStr};
+
+use napi::Either;
+use napi_derive::napi;
+
+use oxc_formatter::{
+    ArrowParentheses, AttributePosition, BracketSameLine, BracketSpacing,
+    EmbeddedLanguageFormatting, Expand, ImportGroup, IndentStyle, IndentWidth, LineEnding,
+    LineWidth, QuoteProperties, QuoteStyle, Semicolons, SortImports, TrailingCommas,
+};
+use oxc_napi::OxcError;
+
+#[napi(object)]
+#[derive(Default)]
+pub struct FormatOptions {
+    /// Indent with tabs or spaces.
+    ///
+    /// @default 'space'
+    #[napi(ts_type = "'tab' | 'space'")]
+    pub indent_style: Option<String>,
+
+    /// The number of spaces per indentation level.
+    ///
+    /// @default 2
+    pub indent_width: Option<u32>,
+
+    /// The type of line ending.
+    ///
+    /// @default 'lf'
+    #[napi(ts_type = "'lf' | 'crlf' | 'cr'")]
+    pub line_ending: Option<String>,
+
+    /// The line length that the formatter will try to wrap on.
+    ///
+    /// @default 80
+    pub line_width: Option<u32>,
+
+    /// The quotes of string literals.
+    ///
+    /// @default 'double'
+    #[napi(ts_type = "'double' | 'single'")]
+    pub quote_style: Option<String>,
+
+    /// The quotes of JSX attributes.
+    ///
+    /// @default 'double'
+    #[napi(ts_type = "'double' | 'single'")]
+    pub jsx_quote_style: Option<String>,
+
+    /// When properties in objects are quoted.
+    ///
+    /// @default 'as-needed'
+    #[napi(ts_type = "'as-needed' | 'preserve'")]
+    pub quote_properties: Option<String>,
+
+    /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures.
+    ///
+    /// @default 'all'
+    #[napi(ts_type = "'all' | 'es5' | 'none'")]
+    pub trailing_commas: Option<String>,
+
+    /// Print semicolons for all statements, or only where necessary.
+    ///
+    /// @default 'always'
+    #[napi(ts_type = "'always' | 'as-needed'")]
+    pub semicolons: Option<String>,
+
+    /// Add parentheses around a sole arrow function parameter always, or only where necessary.
+    ///
+    /// @default 'always'
+    #[napi(ts_type = "'always' | 'as-needed'")]
+    pub arrow_parentheses: Option<String>,
+
+    /// Print spaces between brackets in object literals.
+    ///
+    /// @default true
+    pub bracket_spacing: Option<bool>,
+
+    /// Put the `>` of a multi-line JSX element at the end of the last line
+    /// instead of being alone on the next line.
+    ///
+    /// @default false
+    pub bracket_same_line: Option<bool>,
+
+    /// The position of JSX attributes.
+    ///
+    /// @default 'auto'
+    #[napi(ts_type = "'auto' | 'multiline'")]
+    pub attribute_position: Option<String>,
+
+    /// Whether to expand object and array literals to multiple lines.
+    ///
+    /// @default 'auto'
+    #[napi(ts_type = "'auto' | 'always' | 'never'")]
+    pub expand: Option<String>,
+
+    /// Format code embedded in template literals, such as CSS and GraphQL.
+    ///
+    /// @default 'auto'
+    #[napi(ts_type = "'auto' | 'off'")]
+    pub embedded_language_formatting: Option<String>,
+
+    /// Sort and group contiguous import declarations.
+    ///
+    /// @default false
+    pub sort_imports: Option<Either<bool, SortImportsOptions>>,
+}
+
+impl TryFrom<&FormatOptions> for oxc_formatter::FormatOptions {
+    type Error = String;
+
+    fn try_from(o: &FormatOptions) -> Result<Self, Self::Error> {
+        let default = oxc_formatter::FormatOptions::default();
+        Ok(oxc_formatter::FormatOptions {
+            indent_style: parse::<IndentStyle>(o.indent_style.as_ref())?
+                .unwrap_or(default.indent_style),
+            indent_width: match o.indent_width {
+                Some(width) => u8::try_from(width)
+                    .ok()
+                    .and_then(|width| IndentWidth::try_from(width).ok())
+                    .ok_or_else(|| {
+                        format!(
+                            "`indentWidth` must be between {} and {}",
+                            IndentWidth::MIN,
+                            IndentWidth::MAX
+                        )
+                    })?,
+                None => default.indent_width,
+            },
+            line_ending: parse::<LineEnding>(o.line_ending.as_ref())?
+                .unwrap_or(default.line_ending),
+            line_width: match o.line_width {
+                Some(width) => u16::try_from(width)
+                    .ok()
+                    .and_then(|width| LineWidth::try_from(width).ok())
+                    .ok_or_else(|| {
+                        format!(
+                            "`lineWidth` must be between {} and {}",
+                            LineWidth::MIN,
+                            LineWidth::MAX
+                        )
+                    })?,
+                None => default.line_width,
+            },
+            quote_style: parse::<QuoteStyle>(o.quote_style.as_ref())?
+                .unwrap_or(default.quote_style),
+            jsx_quote_style: parse::<QuoteStyle>(o.jsx_quote_style.as_ref())?
+                .unwrap_or(default.jsx_quote_style),
+            quote_properties: parse::<QuoteProperties>(o.quote_properties.as_ref())?
+                .unwrap_or(default.quote_properties),
+            trailing_commas: parse::<TrailingCommas>(o.trailing_commas.as_ref())?
+                .unwrap_or(default.trailing_commas),
+            semicolons: parse::<Semicolons>(o.semicolons.as_ref())?.unwrap_or(default.semicolons),
+            arrow_parentheses: parse::<ArrowParentheses>(o.arrow_parentheses.as_ref())?
+                .unwrap_or(default.arrow_parentheses),
+            bracket_spacing: o
+                .bracket_spacing
+                .map_or(default.bracket_spacing, BracketSpacing::from),
+            bracket_same_line: o
+                .bracket_same_line
+                .map_or(default.bracket_same_line, BracketSameLine::from),
+            attribute_position: parse::<AttributePosition>(o.attribute_position.as_ref())?
+                .unwrap_or(default.attribute_position),
+            expand: parse::<Expand>(o.expand.as_ref())?.unwrap_or(default.expand),
+            embedded_language_formatting: parse::<EmbeddedLanguageFormatting>(
+                o.embedded_language_formatting.as_ref(),
+            )?
+            .unwrap_or(default.embedded_language_formatting),
+            sort_imports: match &o.sort_imports {
+                None | Some(Either::A(false)) => None,
+                Some(Either::A(true)) => Some(SortImports::default()),
+                Some(Either::B(o)) => Some(SortImports::try_from(o)?),
+            },
+        })
+    }
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct SortImportsOptions {
+    /// The order of the groups. Imports of a group which is not listed are put after the others.
+    ///
+    /// @default ['builtin', 'external', 'internal', 'relative']
+    #[napi(ts_type = "Array<'builtin' | 'external' | 'internal' | 'relative'>")]
+    pub groups: Option<Vec<String>>,
+
+    /// The prefixes of the sources of internal imports, such as path aliases.
+    ///
+    /// @default ['~/', '@/', '#']
+    pub internal_prefixes: Option<Vec<String>>,
+
+    /// Separate the groups with a blank line.
+    ///
+    /// @default true
+    pub newlines_between: Option<bool>,
+}
+
+impl TryFrom<&SortImportsOptions> for SortImports {
+    type Error = String;
+
+    fn try_from(o: &SortImportsOptions) -> Result<Self, Self::Error> {
+        let default = SortImports::default();
+        Ok(SortImports {
+            groups: match &o.groups {
+                Some(groups) => groups
+                    .iter()
+                    .map(|group| ImportGroup::from_str(group).map_err(ToString::to_string))
+                    .collect::<Result<_, _>>()?,
+                None => default.groups,
+            },
+            internal_prefixes: o.internal_prefixes.clone().unwrap_or(default.internal_prefixes),
+            newlines_between: o.newlines_between.unwrap_or(default.newlines_between),
+        })
+    }
+}
+
+fn parse<T>(value: Option<&String>) -> Result<Option<T>, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value.map(|value| T::from_str(value).map_err(|err| err.to_string())).transpose()
+}
+
+#[napi(object)]
+pub struct FormatResult {
+    /// The formatted code.
+    ///
+    /// If parsing failed, this will be the source text as is.
+    pub code: String,
+
+    /// Parse errors.
+    ///
+    /// The code is only formatted if there are no errors.
+    pub errors: Vec<OxcError>,
+}
@@ -0,0 +1,68 @@
+import { Worker } from 'node:worker_threads';
+import { describe, expect, it } from 'vitest';
+
+import { format } from '../index';
+
+describe('simple', () => {
+  const code = 'function foo() { return 1 }';
+
+  it('matches output', () => {
+    const ret = format('test.js', code);
+    expect(ret).toStrictEqual({
+      'code': 'function foo() {\n  return 1;\n}\n',
+      'errors': [],
+    });
+  });
+
+  it('applies options', () => {
+    const ret = format('test.js', code, { indentStyle: 'tab', semicolons: 'as-needed' });
+    expect(ret.code).toBe('function foo() {\n\treturn 1\n}\n');
+  });
+
+  it('sorts imports', () => {
+    const code = 'import b from "b";\nimport { join } from "node:path";\nimport a from "./a";\n';
+    expect(format('test.js', code).code).toBe(code);
+    expect(format('test.js', code, { sortImports: true }).code).toBe(
+      'import { join } from "node:path";\n\nimport b from "b";\n\nimport a from "./a";\n',
+    );
+    expect(format('test.js', code, { sortImports: { groups: ['relative'], newlinesBetween: false } }).code).toBe(
+      'import a from "./a";\nimport b from "b";\nimport { join } from "node:path";\n',
+    );
+  });
+
+  it('throws on invalid options', () => {
+    expect(() => format('test.js', code, { lineWidth: 1000 })).toThrow('`lineWidth` must be between 1 and 320');
+    // @ts-expect-error
+    expect(() => format('test.js', code, { semicolons: 'never' })).toThrow();
+  });
+});
+
+describe('errors', () => {
+  it('returns the source text for syntax errors', () => {
+    const code = 'const = 1;';
+    const ret = format('test.js', code);
+    expect(ret.code).toBe(code);
+    expect(ret.errors.length).toBe(1);
+  });
+
+  it('returns an error for unknown file types', () => {
+    const ret = format('test.txt', 'foo');
+    expect(ret.code).toBe('foo');
+    expect(ret.errors.length).toBe(1);
+  });
+});
+
+describe('worker', () => {
+  it('should run', async () => {
+    const code = await new Promise((resolve, reject) => {
+      const worker = new Worker('./test/worker.mjs');
+      worker.on('error', (err) => {
+        reject(err);
+      });
+      worker.on('exit', (code) => {
+        resolve(code);
+      });
+    });
+    expect(code).toBe(0);
+  });
+});
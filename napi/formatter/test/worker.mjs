@@ -0,0 +1,2 @@
+import { format } from '../index.js';
+format('test.js', '');
@@ -37,6 +37,47 @@ export interface CompressOptions {
    * @default true
    */
   dropDebugger?: boolean
+  /**
+   * Inline constants and functions that are only called once.
+   *
+   * @default true
+   */
+  inline?: boolean | CompressOptionsInline
+  /**
+   * Remove unreferenced variables, functions and classes.
+   *
+   * @default true
+   */
+  unused?: boolean
+  /**
+   * Keep unused parameters at the end of parameter lists,
+   * so that `Function.prototype.length` is preserved.
+   *
+   * @default true
+   */
+  keepFargs?: boolean
+  /**
+   * Pass `true` to drop and inline bindings in the top level scope of scripts.
+   *
+   * @default false
+   */
+  toplevel?: boolean
+}
+
+export interface CompressOptionsInline {
+  /**
+   * Inline variables that are never reassigned and initialized with a small literal,
+   * or with a side effect free value used only once.
+   *
+   * @default true
+   */
+  constants: boolean
+  /**
+   * Inline function declarations that are called once and only return an expression.
+   *
+   * @default true
+   */
+  functions: boolean
 }
 
 export interface CompressOptionsKeepNames {
@@ -35,11 +35,41 @@ pub struct CompressOptions {
     ///
     /// @default true
     pub drop_debugger: Option<bool>,
+
+    /// Inline constants and functions that are only called once.
+    ///
+    /// @default true
+    pub inline: Option<Either<bool, CompressOptionsInline>>,
+
+    /// Remove unreferenced variables, functions and classes.
+    ///
+    /// @default true
+    pub unused: Option<bool>,
+
+    /// Keep unused parameters at the end of parameter lists,
+    /// so that `Function.prototype.length` is preserved.
+    ///
+    /// @default true
+    pub keep_fargs: Option<bool>,
+
+    /// Pass `true` to drop and inline bindings in the top level scope of scripts.
+    ///
+    /// @default false
+    pub toplevel: Option<bool>,
 }
 
 impl Default for CompressOptions {
     fn default() -> Self {
-        Self { target: None, keep_names: None, drop_console: None, drop_debugger: Some(true) }
+        Self {
+            target: None,
+            keep_names: None,
+            drop_console: None,
+            drop_debugger: Some(true),
+            inline: None,
+            unused: None,
+            keep_fargs: None,
+            toplevel: None,
+        }
     }
 }
 
@@ -57,6 +87,15 @@ impl TryFrom<&CompressOptions> for oxc_minifier::CompressOptions {
             keep_names: o.keep_names.as_ref().map(Into::into).unwrap_or_default(),
             drop_console: o.drop_console.unwrap_or(default.drop_console),
             drop_debugger: o.drop_debugger.unwrap_or(default.drop_debugger),
+            inline: match &o.inline {
+                Some(Either::A(false)) => oxc_minifier::CompressOptionsInline::all_false(),
+                Some(Either::A(true)) => oxc_minifier::CompressOptionsInline::all_true(),
+                Some(Either::B(o)) => oxc_minifier::CompressOptionsInline::from(o),
+                None => default.inline,
+            },
+            unused: o.unused.unwrap_or(default.unused),
+            keep_fargs: o.keep_fargs.unwrap_or(default.keep_fargs),
+            top_level: o.toplevel.unwrap_or(default.top_level),
         })
     }
 }
@@ -84,6 +123,26 @@ impl From<&CompressOptionsKeepNames> for oxc_minifier::CompressOptionsKeepNames
     }
 }
 
+#[napi(object)]
+pub struct CompressOptionsInline {
+    /// Inline variables that are never reassigned and initialized with a small literal,
+    /// or with a side effect free value used only once.
+    ///
+    /// @default true
+    pub constants: bool,
+
+    /// Inline function declarations that are called once and only return an expression.
+    ///
+    /// @default true
+    pub functions: bool,
+}
+
+impl From<&CompressOptionsInline> for oxc_minifier::CompressOptionsInline {
+    fn from(o: &CompressOptionsInline) -> Self {
+        oxc_minifier::CompressOptionsInline { constants: o.constants, functions: o.functions }
+    }
+}
+
 #[napi(object)]
 #[derive(Default)]
 pub struct MangleOptions {
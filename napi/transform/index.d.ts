@@ -12,6 +12,33 @@ export interface ArrowFunctionsOptions {
   spec?: boolean
 }
 
+export interface BlockScopingOptions {
+  /**
+   * Insert checks which throw when a `let` / `const` binding is read
+   * before it is initialized.
+   *
+   * @default false
+   */
+  tdz?: boolean
+  /**
+   * Throw an error instead of wrapping a loop body in a closure
+   * when it captures a block scoped binding.
+   *
+   * @default false
+   */
+  throwIfClosureRequired?: boolean
+}
+
+export interface ClassesOptions {
+  /**
+   * Assign methods to the prototype instead of defining them with `createClass`,
+   * and omit the check which throws when a class is called without `new`.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export interface Comment {
   type: 'Line' | 'Block'
   value: string
@@ -20,11 +47,26 @@ export interface Comment {
 }
 
 export interface CompilerAssumptions {
+  arrayLikeIsIterable?: boolean
   ignoreFunctionLength?: boolean
+  ignoreToPrimitiveHint?: boolean
+  iterableIsArray?: boolean
+  mutableTemplateObject?: boolean
   noDocumentAll?: boolean
   objectRestNoSymbols?: boolean
   pureGetters?: boolean
+  setComputedProperties?: boolean
   setPublicClassFields?: boolean
+  skipForOfIteratorClosing?: boolean
+}
+
+export interface ComputedPropertiesOptions {
+  /**
+   * Assign computed properties instead of defining them with `defineProperty`.
+   *
+   * @default false
+   */
+  loose?: boolean
 }
 
 export interface DecoratorOptions {
@@ -38,6 +80,16 @@ export interface DecoratorOptions {
    * @default false
    */
   legacy?: boolean
+  /**
+   * Enables the transform of TC39 standard decorators, following the 2023-05 version of the proposal.
+   *
+   * This is the version of decorators which TypeScript 5 implements when `experimentalDecorators`
+   * is not enabled. It is ignored when `legacy` is true.
+   *
+   * @see https://babeljs.io/docs/babel-plugin-proposal-decorators#version
+   * @default false
+   */
+  standard?: boolean
   /**
    * Enables emitting decorator metadata.
    *
@@ -50,6 +102,21 @@ export interface DecoratorOptions {
   emitDecoratorMetadata?: boolean
 }
 
+export interface DestructuringOptions {
+  /**
+   * Assume destructured arrays are arrays, and access their elements by index.
+   *
+   * @default false
+   */
+  loose?: boolean
+  /**
+   * Allow destructuring array-like objects which are not iterable, such as `arguments`.
+   *
+   * @default false
+   */
+  allowArrayLike?: boolean
+}
+
 export interface ErrorLabel {
   message?: string
   start: number
@@ -59,6 +126,49 @@ export interface ErrorLabel {
 export interface Es2015Options {
   /** Transform arrow functions into function expressions. */
   arrowFunction?: ArrowFunctionsOptions
+  /** Transform `let` and `const` declarations into `var`. */
+  blockScoping?: BlockScopingOptions
+  /** Transform classes into constructor functions. */
+  classes?: ClassesOptions
+  /** Transform computed keys in object literals. */
+  computedProperties?: ComputedPropertiesOptions
+  /** Transform destructuring patterns into property and index accesses. */
+  destructuring?: DestructuringOptions
+  /** Transform `for...of` loops into index or iterator loops. */
+  forOf?: ForOfOptions
+  /** Transform `super` in object methods. */
+  objectSuper?: boolean
+  /** Transform default and rest parameters. */
+  parameters?: ParametersOptions
+  /** Transform generator functions into state machines, using `regeneratorRuntime` helper. */
+  regenerator?: RegeneratorOptions
+  /** Transform shorthand properties and methods in object literals. */
+  shorthandProperties?: boolean
+  /** Transform spread in arrays, calls and `new` expressions. */
+  spread?: SpreadOptions
+  /** Transform template literals and tagged templates. */
+  templateLiterals?: TemplateLiteralsOptions
+}
+
+export interface ForOfOptions {
+  /**
+   * Don't call the iterator's `return` method when the loop exits early.
+   *
+   * @default false
+   */
+  loose?: boolean
+  /**
+   * Assume iterated values are arrays, and loop over them by index.
+   *
+   * @default false
+   */
+  assumeArray?: boolean
+  /**
+   * Allow iterating array-like objects which are not iterable, such as `arguments`.
+   *
+   * @default false
+   */
+  allowArrayLike?: boolean
 }
 
 export declare const enum HelperMode {
@@ -82,7 +192,20 @@ export declare const enum HelperMode {
    * babelHelpers.helperName(...arguments);
    * ```
    */
-  External = 'External'
+  External = 'External',
+  /**
+   * Inline mode: Helper functions are inserted into the file.
+   *
+   * Only supported for `regeneratorRuntime` helper. Other helpers produce an error.
+   *
+   * Example:
+   *
+   * ```js
+   * function helperName(...arguments) { ... }
+   * helperName(...arguments);
+   * ```
+   */
+  Inline = 'Inline'
 }
 
 export interface Helpers {
@@ -267,6 +390,16 @@ export interface OxcError {
   codeframe?: string
 }
 
+export interface ParametersOptions {
+  /**
+   * Keep parameters with default values in the parameter list,
+   * changing the `length` of the function.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export interface ReactRefreshOptions {
   /**
    * Specify the identifier of the refresh registration variable.
@@ -283,6 +416,27 @@ export interface ReactRefreshOptions {
   emitFullSignatures?: boolean
 }
 
+export interface RegeneratorOptions {
+  /**
+   * Compile generator functions.
+   *
+   * @default true
+   */
+  generators?: boolean
+  /**
+   * Compile async functions, after they have been converted to generators.
+   *
+   * @default true
+   */
+  async?: boolean
+  /**
+   * Compile async generator functions, after they have been converted to generators.
+   *
+   * @default true
+   */
+  asyncGenerators?: boolean
+}
+
 export declare const enum Severity {
   Error = 'Error',
   Warning = 'Warning',
@@ -300,6 +454,31 @@ export interface SourceMap {
   x_google_ignoreList?: Array<number>
 }
 
+export interface SpreadOptions {
+  /**
+   * Assume spread values are arrays, and concatenate them without converting.
+   *
+   * @default false
+   */
+  loose?: boolean
+  /**
+   * Allow spreading array-like objects which are not iterable, such as `arguments`.
+   *
+   * @default false
+   */
+  allowArrayLike?: boolean
+}
+
+export interface TemplateLiteralsOptions {
+  /**
+   * Concatenate values with `+` instead of `String.prototype.concat`,
+   * and don't freeze the strings array passed to tag functions.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 /**
  * Transpile a JavaScript or TypeScript into a target ECMAScript version.
  *
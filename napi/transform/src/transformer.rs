@@ -185,28 +185,50 @@ impl TryFrom<TransformOptions> for oxc::transformer::TransformOptions {
 #[napi(object)]
 #[derive(Default, Debug)]
 pub struct CompilerAssumptions {
+    pub array_like_is_iterable: Option<bool>,
     pub ignore_function_length: Option<bool>,
+    pub ignore_to_primitive_hint: Option<bool>,
+    pub iterable_is_array: Option<bool>,
+    pub mutable_template_object: Option<bool>,
     pub no_document_all: Option<bool>,
     pub object_rest_no_symbols: Option<bool>,
     pub pure_getters: Option<bool>,
+    pub set_computed_properties: Option<bool>,
     pub set_public_class_fields: Option<bool>,
+    pub skip_for_of_iterator_closing: Option<bool>,
 }
 
 impl From<CompilerAssumptions> for oxc::transformer::CompilerAssumptions {
     fn from(value: CompilerAssumptions) -> Self {
         let ops = oxc::transformer::CompilerAssumptions::default();
         Self {
+            array_like_is_iterable: value
+                .array_like_is_iterable
+                .unwrap_or(ops.array_like_is_iterable),
             ignore_function_length: value
                 .ignore_function_length
                 .unwrap_or(ops.ignore_function_length),
+            ignore_to_primitive_hint: value
+                .ignore_to_primitive_hint
+                .unwrap_or(ops.ignore_to_primitive_hint),
+            iterable_is_array: value.iterable_is_array.unwrap_or(ops.iterable_is_array),
+            mutable_template_object: value
+                .mutable_template_object
+                .unwrap_or(ops.mutable_template_object),
             no_document_all: value.no_document_all.unwrap_or(ops.no_document_all),
             object_rest_no_symbols: value
                 .object_rest_no_symbols
                 .unwrap_or(ops.object_rest_no_symbols),
             pure_getters: value.pure_getters.unwrap_or(ops.pure_getters),
+            set_computed_properties: value
+                .set_computed_properties
+                .unwrap_or(ops.set_computed_properties),
             set_public_class_fields: value
                 .set_public_class_fields
                 .unwrap_or(ops.set_public_class_fields),
+            skip_for_of_iterator_closing: value
+                .skip_for_of_iterator_closing
+                .unwrap_or(ops.skip_for_of_iterator_closing),
             ..ops
         }
     }
@@ -284,6 +306,15 @@ pub struct DecoratorOptions {
     /// @default false
     pub legacy: Option<bool>,
 
+    /// Enables the transform of TC39 standard decorators, following the 2023-05 version of the proposal.
+    ///
+    /// This is the version of decorators which TypeScript 5 implements when `experimentalDecorators`
+    /// is not enabled. It is ignored when `legacy` is true.
+    ///
+    /// @see https://babeljs.io/docs/babel-plugin-proposal-decorators#version
+    /// @default false
+    pub standard: Option<bool>,
+
     /// Enables emitting decorator metadata.
     ///
     /// This option the same as [emitDecoratorMetadata](https://www.typescriptlang.org/tsconfig/#emitDecoratorMetadata)
@@ -298,6 +329,7 @@ impl From<DecoratorOptions> for oxc::transformer::DecoratorOptions {
     fn from(options: DecoratorOptions) -> Self {
         oxc::transformer::DecoratorOptions {
             legacy: options.legacy.unwrap_or_default(),
+            standard: options.standard.unwrap_or_default(),
             emit_decorator_metadata: options.emit_decorator_metadata.unwrap_or_default(),
         }
     }
@@ -455,15 +487,246 @@ impl From<ArrowFunctionsOptions> for oxc::transformer::ArrowFunctionsOptions {
     }
 }
 
+#[napi(object)]
+pub struct BlockScopingOptions {
+    /// Insert checks which throw when a `let` / `const` binding is read
+    /// before it is initialized.
+    ///
+    /// @default false
+    pub tdz: Option<bool>,
+
+    /// Throw an error instead of wrapping a loop body in a closure
+    /// when it captures a block scoped binding.
+    ///
+    /// @default false
+    pub throw_if_closure_required: Option<bool>,
+}
+
+impl From<BlockScopingOptions> for oxc::transformer::BlockScopingOptions {
+    fn from(options: BlockScopingOptions) -> Self {
+        oxc::transformer::BlockScopingOptions {
+            tdz: options.tdz.unwrap_or_default(),
+            throw_if_closure_required: options.throw_if_closure_required.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct ClassesOptions {
+    /// Assign methods to the prototype instead of defining them with `createClass`,
+    /// and omit the check which throws when a class is called without `new`.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ClassesOptions> for oxc::transformer::ClassesOptions {
+    fn from(options: ClassesOptions) -> Self {
+        oxc::transformer::ClassesOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct ComputedPropertiesOptions {
+    /// Assign computed properties instead of defining them with `defineProperty`.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ComputedPropertiesOptions> for oxc::transformer::ComputedPropertiesOptions {
+    fn from(options: ComputedPropertiesOptions) -> Self {
+        oxc::transformer::ComputedPropertiesOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct DestructuringOptions {
+    /// Assume destructured arrays are arrays, and access their elements by index.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+
+    /// Allow destructuring array-like objects which are not iterable, such as `arguments`.
+    ///
+    /// @default false
+    pub allow_array_like: Option<bool>,
+}
+
+impl From<DestructuringOptions> for oxc::transformer::DestructuringOptions {
+    fn from(options: DestructuringOptions) -> Self {
+        oxc::transformer::DestructuringOptions {
+            loose: options.loose.unwrap_or_default(),
+            allow_array_like: options.allow_array_like.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct ForOfOptions {
+    /// Don't call the iterator's `return` method when the loop exits early.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+
+    /// Assume iterated values are arrays, and loop over them by index.
+    ///
+    /// @default false
+    pub assume_array: Option<bool>,
+
+    /// Allow iterating array-like objects which are not iterable, such as `arguments`.
+    ///
+    /// @default false
+    pub allow_array_like: Option<bool>,
+}
+
+impl From<ForOfOptions> for oxc::transformer::ForOfOptions {
+    fn from(options: ForOfOptions) -> Self {
+        oxc::transformer::ForOfOptions {
+            loose: options.loose.unwrap_or_default(),
+            assume_array: options.assume_array.unwrap_or_default(),
+            allow_array_like: options.allow_array_like.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct ParametersOptions {
+    /// Keep parameters with default values in the parameter list,
+    /// changing the `length` of the function.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ParametersOptions> for oxc::transformer::ParametersOptions {
+    fn from(options: ParametersOptions) -> Self {
+        oxc::transformer::ParametersOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct RegeneratorOptions {
+    /// Compile generator functions.
+    ///
+    /// @default true
+    pub generators: Option<bool>,
+
+    /// Compile async functions, after they have been converted to generators.
+    ///
+    /// @default true
+    #[napi(js_name = "async")]
+    pub async_functions: Option<bool>,
+
+    /// Compile async generator functions, after they have been converted to generators.
+    ///
+    /// @default true
+    pub async_generators: Option<bool>,
+}
+
+impl From<RegeneratorOptions> for oxc::transformer::RegeneratorOptions {
+    fn from(options: RegeneratorOptions) -> Self {
+        let default = oxc::transformer::RegeneratorOptions::default();
+        oxc::transformer::RegeneratorOptions {
+            generators: options.generators.unwrap_or(default.generators),
+            async_functions: options.async_functions.unwrap_or(default.async_functions),
+            async_generators: options.async_generators.unwrap_or(default.async_generators),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct SpreadOptions {
+    /// Assume spread values are arrays, and concatenate them without converting.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+
+    /// Allow spreading array-like objects which are not iterable, such as `arguments`.
+    ///
+    /// @default false
+    pub allow_array_like: Option<bool>,
+}
+
+impl From<SpreadOptions> for oxc::transformer::SpreadOptions {
+    fn from(options: SpreadOptions) -> Self {
+        oxc::transformer::SpreadOptions {
+            loose: options.loose.unwrap_or_default(),
+            allow_array_like: options.allow_array_like.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct TemplateLiteralsOptions {
+    /// Concatenate values with `+` instead of `String.prototype.concat`,
+    /// and don't freeze the strings array passed to tag functions.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<TemplateLiteralsOptions> for oxc::transformer::TemplateLiteralsOptions {
+    fn from(options: TemplateLiteralsOptions) -> Self {
+        oxc::transformer::TemplateLiteralsOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
 #[napi(object)]
 pub struct Es2015Options {
     /// Transform arrow functions into function expressions.
     pub arrow_function: Option<ArrowFunctionsOptions>,
+
+    /// Transform `let` and `const` declarations into `var`.
+    pub block_scoping: Option<BlockScopingOptions>,
+
+    /// Transform classes into constructor functions.
+    pub classes: Option<ClassesOptions>,
+
+    /// Transform computed keys in object literals.
+    pub computed_properties: Option<ComputedPropertiesOptions>,
+
+    /// Transform destructuring patterns into property and index accesses.
+    pub destructuring: Option<DestructuringOptions>,
+
+    /// Transform `for...of` loops into index or iterator loops.
+    pub for_of: Option<ForOfOptions>,
+
+    /// Transform `super` in object methods.
+    pub object_super: Option<bool>,
+
+    /// Transform default and rest parameters.
+    pub parameters: Option<ParametersOptions>,
+
+    /// Transform generator functions into state machines, using `regeneratorRuntime` helper.
+    pub regenerator: Option<RegeneratorOptions>,
+
+    /// Transform shorthand properties and methods in object literals.
+    pub shorthand_properties: Option<bool>,
+
+    /// Transform spread in arrays, calls and `new` expressions.
+    pub spread: Option<SpreadOptions>,
+
+    /// Transform template literals and tagged templates.
+    pub template_literals: Option<TemplateLiteralsOptions>,
 }
 
 impl From<Es2015Options> for oxc::transformer::ES2015Options {
     fn from(options: Es2015Options) -> Self {
-        oxc::transformer::ES2015Options { arrow_function: options.arrow_function.map(Into::into) }
+        oxc::transformer::ES2015Options {
+            arrow_function: options.arrow_function.map(Into::into),
+            block_scoping: options.block_scoping.map(Into::into),
+            classes: options.classes.map(Into::into),
+            computed_properties: options.computed_properties.map(Into::into),
+            destructuring: options.destructuring.map(Into::into),
+            for_of: options.for_of.map(Into::into),
+            object_super: options.object_super.unwrap_or_default(),
+            parameters: options.parameters.map(Into::into),
+            regenerator: options.regenerator.map(Into::into),
+            shorthand_properties: options.shorthand_properties.unwrap_or_default(),
+            spread: options.spread.map(Into::into),
+            template_literals: options.template_literals.map(Into::into),
+        }
     }
 }
 
@@ -494,6 +757,17 @@ pub enum HelperMode {
     /// babelHelpers.helperName(...arguments);
     /// ```
     External,
+    /// Inline mode: Helper functions are inserted into the file.
+    ///
+    /// Only supported for `regeneratorRuntime` helper. Other helpers produce an error.
+    ///
+    /// Example:
+    ///
+    /// ```js
+    /// function helperName(...arguments) { ... }
+    /// helperName(...arguments);
+    /// ```
+    Inline,
 }
 
 impl From<Helpers> for HelperLoaderOptions {
@@ -510,6 +784,7 @@ impl From<HelperMode> for HelperLoaderMode {
         match value {
             HelperMode::Runtime => Self::Runtime,
             HelperMode::External => Self::External,
+            HelperMode::Inline => Self::Inline,
         }
     }
 }
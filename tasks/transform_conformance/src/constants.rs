@@ -63,6 +63,27 @@ pub const PLUGINS: &[&str] = &[
     "legacy-decorators",
 ];
 
+/// Plugins which are only tested with our own fixtures in `tests` directory.
+///
+/// Babel's fixtures for these are not run until `babel.snap.md` is regenerated to include them.
+pub const OXC_PLUGINS: &[&str] = &[
+    // ES2015
+    "babel-plugin-transform-block-scoping",
+    "babel-plugin-transform-classes",
+    "babel-plugin-transform-computed-properties",
+    "babel-plugin-transform-destructuring",
+    "babel-plugin-transform-for-of",
+    "babel-plugin-transform-parameters",
+    "babel-plugin-transform-modules-commonjs",
+    "babel-plugin-transform-object-super",
+    "babel-plugin-transform-regenerator",
+    "babel-plugin-transform-spread",
+    "babel-plugin-transform-shorthand-properties",
+    "babel-plugin-transform-template-literals",
+    // Standard decorators (2023-05)
+    "decorators",
+];
+
 pub const PLUGINS_NOT_SUPPORTED_YET: &[&str] = &[
     "proposal-decorators",
     "transform-classes",
@@ -11,7 +11,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use constants::PLUGINS;
+use constants::{OXC_PLUGINS, PLUGINS};
 use indexmap::IndexMap;
 use oxc_tasks_common::{Snapshot, normalize_path, project_root};
 use test_case::{TestCase, TestCaseKind};
@@ -83,7 +83,9 @@ impl TestRunner {
                 let _ = fs::remove_dir_all(&fixture_root);
                 let _ = fs::create_dir_all(&fixture_root);
             }
-            let transform_paths = Self::generate_test_cases(root, &self.options);
+            let plugins =
+                if *name == "oxc" { [PLUGINS, OXC_PLUGINS].concat() } else { PLUGINS.to_vec() };
+            let transform_paths = Self::generate_test_cases(root, &plugins, &self.options);
             self.generate_snapshot(root, &snap_root().join(snapshot), transform_paths);
             if self.options.exec {
                 self.run_vitest(&format!("./fixtures/{name}"), &snap_root().join(exec_snapshot));
@@ -93,13 +95,14 @@ impl TestRunner {
 
     fn generate_test_cases(
         root: &Path,
+        plugins: &[&str],
         options: &TestRunnerOptions,
     ) -> IndexMap<String, Vec<TestCase>> {
         let cwd = root.parent().unwrap_or(root);
         // use `IndexMap` to keep the order of the test cases the same in insert order.
         let mut transform_files = IndexMap::<String, Vec<TestCase>>::new();
 
-        for case in PLUGINS {
+        for case in plugins {
             let root = root.join(case).join("test/fixtures");
 
             let mut cases = WalkDir::new(root)
@@ -0,0 +1,13 @@
+let a = 1;
+const b = 2;
+{
+  let c = a + b;
+  const d = c;
+  console.log(d);
+}
+for (let i = 0; i < 3; i++) {
+  console.log(i);
+}
+for (const key in obj) {
+  let value = obj[key];
+}
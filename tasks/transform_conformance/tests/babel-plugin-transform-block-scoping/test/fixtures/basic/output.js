@@ -0,0 +1,13 @@
+var a = 1;
+var b = 2;
+{
+  var c = a + b;
+  var d = c;
+  console.log(d);
+}
+for (var i = 0; i < 3; i++) {
+  console.log(i);
+}
+for (var key in obj) {
+  var value = obj[key];
+}
@@ -0,0 +1,15 @@
+function f(items) {
+  outer: for (const item of items) {
+    for (let i = 0; i < item.length; i++) {
+      fns.push(() => i);
+      if (i === 1) continue;
+      if (i === 2) break;
+      if (i === 3) continue outer;
+      if (i === 4) break outer;
+      if (i === 5) return i;
+      for (let j = 0; j < 2; j++) {
+        if (j) break;
+      }
+    }
+  }
+}
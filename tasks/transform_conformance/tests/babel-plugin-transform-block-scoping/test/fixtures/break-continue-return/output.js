@@ -0,0 +1,22 @@
+function f(items) {
+  outer: for (var item of items) {
+    var _loop = function(i) {
+      fns.push(() => i);
+      if (i === 1) return;
+      if (i === 2) return 0;
+      if (i === 3) return 1;
+      if (i === 4) return 2;
+      if (i === 5) return { v: i };
+      for (var j = 0; j < 2; j++) {
+        if (j) break;
+      }
+    }, _ret;
+    for (var i = 0; i < item.length; i++) {
+      _ret = _loop(i);
+      if (_ret === 0) break;
+      if (_ret === 1) continue outer;
+      if (_ret === 2) break outer;
+      if (_ret) return _ret.v;
+    }
+  }
+}
@@ -0,0 +1,18 @@
+const a = 1;
+a = 2;
+
+function f() {
+  const b = 1;
+  b += 2;
+  b ||= 3;
+  b++;
+  --b;
+  [b] = arr;
+  let c = 1;
+  c = 2;
+}
+
+{
+  const d = 1;
+  ({ d } = obj);
+}
@@ -0,0 +1,16 @@
+var a = 1;
+2, babelHelpers.readOnlyError("a");
+function f() {
+  var b = 1;
+  b + 2, babelHelpers.readOnlyError("b");
+  b || (3, babelHelpers.readOnlyError("b"));
+  +b, babelHelpers.readOnlyError("b");
+  +b, babelHelpers.readOnlyError("b");
+  arr, babelHelpers.readOnlyError("b");
+  var c = 1;
+  c = 2;
+}
+{
+  var d = 1;
+  obj, babelHelpers.readOnlyError("d");
+}
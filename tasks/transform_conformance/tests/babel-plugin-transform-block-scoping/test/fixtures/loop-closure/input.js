@@ -0,0 +1,14 @@
+const fns = [];
+for (let i = 0; i < 3; i++) {
+  fns.push(() => i);
+}
+for (const item of items) {
+  let doubled = item * 2;
+  setTimeout(function () {
+    console.log(item, doubled, this, arguments);
+  });
+}
+while (cond) {
+  let x = next();
+  fns.push(() => x);
+}
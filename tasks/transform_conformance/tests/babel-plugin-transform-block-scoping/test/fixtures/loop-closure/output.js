@@ -0,0 +1,23 @@
+var fns = [];
+var _loop = function(i) {
+  fns.push(() => i);
+};
+for (var i = 0; i < 3; i++) {
+  _loop(i);
+}
+var _loop2 = function(item) {
+  var doubled = item * 2;
+  setTimeout(function() {
+    console.log(item, doubled, this, arguments);
+  });
+};
+for (var item of items) {
+  _loop2(item);
+}
+var _loop3 = function() {
+  var x = next();
+  fns.push(() => x);
+};
+while (cond) {
+  _loop3();
+}
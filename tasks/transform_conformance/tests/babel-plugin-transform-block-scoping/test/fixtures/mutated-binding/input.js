@@ -0,0 +1,6 @@
+for (let i = 0; i < 10; i++) {
+  fns.push(() => i);
+  i += 2;
+  if (i > 5) continue;
+  var x = i;
+}
@@ -0,0 +1,14 @@
+var x;
+var _loop = function(_i) {
+  fns.push(() => _i);
+  _i += 2;
+  if (_i > 5) {
+    i = _i;
+    return;
+  }
+  x = _i;
+  i = _i;
+};
+for (var i = 0; i < 10; i++) {
+  _loop(i);
+}
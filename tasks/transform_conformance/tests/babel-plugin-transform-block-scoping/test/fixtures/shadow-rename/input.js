@@ -0,0 +1,20 @@
+let a = 1;
+{
+  let a = 2;
+  console.log(a);
+  {
+    const a = 3;
+    console.log(a);
+  }
+}
+function f(b) {
+  if (b) {
+    let b = 2;
+    return b;
+  }
+  return b;
+}
+{
+  let x = 1;
+}
+console.log(x);
@@ -0,0 +1,20 @@
+var a = 1;
+{
+  var _a = 2;
+  console.log(_a);
+  {
+    var _a2 = 3;
+    console.log(_a2);
+  }
+}
+function f(b) {
+  if (b) {
+    var _b = 2;
+    return _b;
+  }
+  return b;
+}
+{
+  var _x = 1;
+}
+console.log(x);
@@ -0,0 +1,9 @@
+let x = 0;
+switch (y) {
+  case 1:
+    let x = 1;
+    console.log(x);
+    break;
+  default:
+    const z = 2;
+}
@@ -0,0 +1,8 @@
+var x = 0;
+switch (y) {
+  case 1:
+    var _x = 1;
+    console.log(_x);
+    break;
+  default: var z = 2;
+}
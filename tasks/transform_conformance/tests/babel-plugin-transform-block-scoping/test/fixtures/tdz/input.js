@@ -0,0 +1,10 @@
+f();
+function f() {
+  return a;
+}
+a;
+let a = 1;
+const g = () => b;
+g();
+let b = 2;
+console.log(a, b);
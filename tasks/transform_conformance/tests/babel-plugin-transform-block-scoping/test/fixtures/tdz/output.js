@@ -0,0 +1,12 @@
+a = babelHelpers.temporalUndefined;
+b = babelHelpers.temporalUndefined;
+f();
+function f() {
+  return babelHelpers.temporalRef(a, "a");
+}
+babelHelpers.tdz("a");
+var a = 1;
+var g = () => babelHelpers.temporalRef(b, "b");
+g();
+var b = 2;
+console.log(a, b);
@@ -0,0 +1,23 @@
+class A {
+  constructor(x) {
+    this.x = x;
+  }
+
+  method() {
+    return this.x;
+  }
+
+  get prop() {
+    return this.x;
+  }
+
+  set prop(value) {
+    this.x = value;
+  }
+
+  static create() {
+    return new A(1);
+  }
+}
+
+class Empty {}
@@ -0,0 +1,31 @@
+let A = /* @__PURE__ */ function() {
+  function A(x) {
+    babelHelpers.classCallCheck(this, A);
+    this.x = x;
+  }
+  return babelHelpers.createClass(A, [{
+    key: "method",
+    value: function method() {
+      return this.x;
+    }
+  }, {
+    key: "prop",
+    get: function() {
+      return this.x;
+    },
+    set: function(value) {
+      this.x = value;
+    }
+  }], [{
+    key: "create",
+    value: function create() {
+      return new A(1);
+    }
+  }]);
+}();
+let Empty = /* @__PURE__ */ function() {
+  function Empty() {
+    babelHelpers.classCallCheck(this, Empty);
+  }
+  return babelHelpers.createClass(Empty);
+}();
@@ -0,0 +1,11 @@
+const A = class {
+  method() {}
+};
+
+const B = class Named {
+  method() {
+    return Named;
+  }
+};
+
+foo(class {});
@@ -0,0 +1,26 @@
+const A = /* @__PURE__ */ function() {
+  function A() {
+    babelHelpers.classCallCheck(this, A);
+  }
+  return babelHelpers.createClass(A, [{
+    key: "method",
+    value: function method() {}
+  }]);
+}();
+const B = /* @__PURE__ */ function() {
+  function Named() {
+    babelHelpers.classCallCheck(this, Named);
+  }
+  return babelHelpers.createClass(Named, [{
+    key: "method",
+    value: function method() {
+      return Named;
+    }
+  }]);
+}();
+foo(/* @__PURE__ */ function() {
+  function _Class() {
+    babelHelpers.classCallCheck(this, _Class);
+  }
+  return babelHelpers.createClass(_Class);
+}());
@@ -0,0 +1,7 @@
+class A {
+  [foo]() {}
+  static [Symbol.iterator]() {}
+  get [bar]() {}
+  "quoted key"() {}
+  1() {}
+}
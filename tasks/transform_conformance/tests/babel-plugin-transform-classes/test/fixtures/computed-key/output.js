@@ -0,0 +1,26 @@
+let A = function() {
+  function A() {
+    babelHelpers.classCallCheck(this, A);
+  }
+  return babelHelpers.createClass(A, [
+    {
+      key: foo,
+      value: function() {}
+    },
+    {
+      key: bar,
+      get: function() {}
+    },
+    {
+      key: "quoted key",
+      value: function() {}
+    },
+    {
+      key: 1,
+      value: function() {}
+    }
+  ], [{
+    key: Symbol.iterator,
+    value: function() {}
+  }]);
+}();
@@ -0,0 +1,32 @@
+class A extends B {
+  constructor(x) {
+    super(x);
+    this.x = x;
+    const fn = () => this.x;
+    function inner() {
+      return this;
+    }
+  }
+}
+
+class C extends B {}
+
+class D extends B {
+  constructor() {
+    super(...arguments);
+  }
+}
+
+class E extends B {
+  constructor(x) {
+    if (x) {
+      return;
+    }
+    super();
+    return { x };
+  }
+}
+
+class F extends foo.bar {
+  method() {}
+}
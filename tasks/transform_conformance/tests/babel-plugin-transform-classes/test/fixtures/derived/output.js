@@ -0,0 +1,57 @@
+let A = /* @__PURE__ */ function(_B) {
+  function A(x) {
+    var _this;
+    babelHelpers.classCallCheck(this, A);
+    _this = babelHelpers.callSuper(this, A, [x]);
+    _this.x = x;
+    const fn = () => _this.x;
+    function inner() {
+      return this;
+    }
+    return _this;
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A);
+}(B);
+let C = /* @__PURE__ */ function(_B2) {
+  function C() {
+    babelHelpers.classCallCheck(this, C);
+    return babelHelpers.callSuper(this, C, arguments);
+  }
+  babelHelpers.inherits(C, _B2);
+  return babelHelpers.createClass(C);
+}(B);
+let D = /* @__PURE__ */ function(_B3) {
+  function D() {
+    var _this2;
+    babelHelpers.classCallCheck(this, D);
+    _this2 = babelHelpers.callSuper(this, D, arguments);
+    return _this2;
+  }
+  babelHelpers.inherits(D, _B3);
+  return babelHelpers.createClass(D);
+}(B);
+let E = /* @__PURE__ */ function(_B4) {
+  function E(x) {
+    var _this3;
+    babelHelpers.classCallCheck(this, E);
+    if (x) {
+      return babelHelpers.possibleConstructorReturn(_this3);
+    }
+    _this3 = babelHelpers.callSuper(this, E, []);
+    return babelHelpers.possibleConstructorReturn(_this3, { x });
+  }
+  babelHelpers.inherits(E, _B4);
+  return babelHelpers.createClass(E);
+}(B);
+let F = function(_foo$bar) {
+  function F() {
+    babelHelpers.classCallCheck(this, F);
+    return babelHelpers.callSuper(this, F, arguments);
+  }
+  babelHelpers.inherits(F, _foo$bar);
+  return babelHelpers.createClass(F, [{
+    key: "method",
+    value: function method() {}
+  }]);
+}(foo.bar);
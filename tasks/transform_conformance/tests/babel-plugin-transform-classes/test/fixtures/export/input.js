@@ -0,0 +1,7 @@
+export class A {}
+
+export default class B {
+  method() {
+    return B;
+  }
+}
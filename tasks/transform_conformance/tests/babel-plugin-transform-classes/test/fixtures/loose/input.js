@@ -0,0 +1,11 @@
+class A extends B {
+  method() {}
+
+  "not-identifier"() {}
+
+  get prop() {
+    return 1;
+  }
+
+  static create() {}
+}
@@ -0,0 +1,15 @@
+let A = /* @__PURE__ */ function(_B) {
+  function A() {
+    return babelHelpers.callSuper(this, A, arguments);
+  }
+  babelHelpers.inherits(A, _B);
+  A.prototype.method = function method() {};
+  A.prototype["not-identifier"] = function() {};
+  A.create = function create() {};
+  return babelHelpers.createClass(A, [{
+    key: "prop",
+    get: function() {
+      return 1;
+    }
+  }]);
+}(B);
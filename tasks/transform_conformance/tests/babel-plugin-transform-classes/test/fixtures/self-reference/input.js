@@ -0,0 +1,16 @@
+class A {
+  method() {
+    return new A();
+  }
+
+  static A() {
+    return A;
+  }
+
+  fn() {
+    function fn() {}
+    return fn;
+  }
+}
+
+let x = A;
@@ -0,0 +1,23 @@
+let A = /* @__PURE__ */ function() {
+  function A() {
+    babelHelpers.classCallCheck(this, A);
+  }
+  return babelHelpers.createClass(A, [{
+    key: "method",
+    value: function method() {
+      return new A();
+    }
+  }, {
+    key: "fn",
+    value: function() {
+      function fn() {}
+      return fn;
+    }
+  }], [{
+    key: "A",
+    value: function() {
+      return A;
+    }
+  }]);
+}();
+let x = A;
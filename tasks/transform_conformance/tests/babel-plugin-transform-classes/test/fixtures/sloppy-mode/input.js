@@ -0,0 +1,14 @@
+class A {
+  method() {
+    return () => function () {};
+  }
+}
+
+function outer() {
+  "use strict";
+  class B {
+    method() {
+      return () => function () {};
+    }
+  }
+}
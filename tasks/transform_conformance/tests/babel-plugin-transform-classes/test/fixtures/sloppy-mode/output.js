@@ -0,0 +1,25 @@
+let A = /* @__PURE__ */ function() {
+  function A() {
+    babelHelpers.classCallCheck(this, A);
+  }
+  return babelHelpers.createClass(A, [{
+    key: "method",
+    value: function method() {
+      return () => function() {};
+    }
+  }]);
+}();
+function outer() {
+  "use strict";
+  let B = /* @__PURE__ */ function() {
+    function B() {
+      babelHelpers.classCallCheck(this, B);
+    }
+    return babelHelpers.createClass(B, [{
+      key: "method",
+      value: function method() {
+        return () => function() {};
+      }
+    }]);
+  }();
+}
@@ -0,0 +1,37 @@
+class A extends B {
+  constructor() {
+    const f = () => super();
+    f();
+    const g = () => super.x;
+    const h = () => new.target;
+    this.x = 1;
+  }
+}
+
+class C extends B {
+  constructor() {
+    const f = () => {
+      super();
+      this.y = 1;
+    };
+    f();
+  }
+}
+
+class D extends B {
+  m() {
+    return () => super.m(this);
+  }
+
+  n() {
+    return () => super.x;
+  }
+
+  o() {
+    return () => new.target;
+  }
+
+  static p() {
+    return () => super.p();
+  }
+}
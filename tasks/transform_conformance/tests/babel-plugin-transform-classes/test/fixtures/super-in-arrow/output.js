@@ -0,0 +1,83 @@
+let A = /* @__PURE__ */ function(_B) {
+  function A() {
+    var _this;
+    babelHelpers.classCallCheck(this, A);
+    var _this2 = this, _newtarget = new.target;
+    const f = function() {
+      return _this = babelHelpers.callSuper(_this2, A, []);
+    };
+    f();
+    const g = function() {
+      return babelHelpers.superPropGet(A, "x", _this, 1);
+    };
+    const h = function() {
+      return _newtarget;
+    };
+    _this.x = 1;
+    return _this;
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A);
+}(B);
+let C = /* @__PURE__ */ function(_B2) {
+  function C() {
+    var _this4;
+    babelHelpers.classCallCheck(this, C);
+    var _this5 = this;
+    var _this3;
+    const f = function() {
+      _this4 = babelHelpers.callSuper(_this5, C, []);
+      _this3 = _this4;
+      _this3.y = 1;
+    };
+    f();
+    return _this4;
+  }
+  babelHelpers.inherits(C, _B2);
+  return babelHelpers.createClass(C);
+}(B);
+let D = /* @__PURE__ */ function(_B3) {
+  function D() {
+    babelHelpers.classCallCheck(this, D);
+    return babelHelpers.callSuper(this, D, arguments);
+  }
+  babelHelpers.inherits(D, _B3);
+  return babelHelpers.createClass(D, [
+    {
+      key: "m",
+      value: function m() {
+        var _this7 = this;
+        var _this6 = this;
+        return function() {
+          return babelHelpers.superPropGet(D, "m", _this7, 3)([_this6]);
+        };
+      }
+    },
+    {
+      key: "n",
+      value: function n() {
+        var _this8 = this;
+        return function() {
+          return babelHelpers.superPropGet(D, "x", _this8, 1);
+        };
+      }
+    },
+    {
+      key: "o",
+      value: function o() {
+        var _newtarget2 = new.target;
+        return function() {
+          return _newtarget2;
+        };
+      }
+    }
+  ], [{
+    key: "p",
+    value: function p() {
+      var _this9 = this;
+      return function() {
+        return babelHelpers.superPropGet(D, "p", _this9, 2)([]);
+      };
+    }
+  }]);
+}(B);
@@ -0,0 +1,20 @@
+class A extends B {
+  method() {
+    super.method();
+    super.method(1, 2);
+    super[key](1);
+    super.x;
+    super[key];
+    super.x = 1;
+    super.x += 1;
+    super.x ||= 1;
+    super.x++;
+    ++super[key];
+    const fn = () => super.x;
+  }
+
+  static method() {
+    super.method();
+    return super.x;
+  }
+}
@@ -0,0 +1,30 @@
+let A = /* @__PURE__ */ function(_B) {
+  function A() {
+    babelHelpers.classCallCheck(this, A);
+    return babelHelpers.callSuper(this, A, arguments);
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A, [{
+    key: "method",
+    value: function method() {
+      var _super$prop, _super$prop2, _key, _super$prop3;
+      babelHelpers.superPropGet(A, "method", this, 3)([]);
+      babelHelpers.superPropGet(A, "method", this, 3)([1, 2]);
+      babelHelpers.superPropGet(A, key, this, 3)([1]);
+      babelHelpers.superPropGet(A, "x", this, 1);
+      babelHelpers.superPropGet(A, key, this, 1);
+      babelHelpers.superPropSet(A, "x", 1, this, 1, 1);
+      babelHelpers.superPropSet(A, "x", babelHelpers.superPropGet(A, "x", this, 1) + 1, this, 1, 1);
+      babelHelpers.superPropGet(A, "x", this, 1) || babelHelpers.superPropSet(A, "x", 1, this, 1, 1);
+      babelHelpers.superPropSet(A, "x", (_super$prop = babelHelpers.superPropGet(A, "x", this, 1), _super$prop2 = _super$prop++, _super$prop), this, 1, 1), _super$prop2;
+      babelHelpers.superPropSet(A, _key = key, (_super$prop3 = babelHelpers.superPropGet(A, _key, this, 1), ++_super$prop3), this, 1, 1);
+      const fn = () => babelHelpers.superPropGet(A, "x", this, 1);
+    }
+  }], [{
+    key: "method",
+    value: function method() {
+      babelHelpers.superPropGet(A, "method", this, 2)([]);
+      return babelHelpers.superPropGet(A, "x", this);
+    }
+  }]);
+}(B);
@@ -0,0 +1,11 @@
+var obj = {
+  get [foo]() {
+    return this._foo;
+  },
+  set [foo](value) {
+    this._foo = value;
+  },
+  get bar() {
+    return 1;
+  },
+};
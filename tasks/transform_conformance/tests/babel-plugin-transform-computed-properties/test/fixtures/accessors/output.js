@@ -0,0 +1,7 @@
+var obj = babelHelpers.defineAccessor("get", babelHelpers.defineAccessor("set", babelHelpers.defineAccessor("get", {}, foo, function() {
+  return this._foo;
+}), foo, function(value) {
+  this._foo = value;
+}), "bar", function() {
+  return 1;
+});
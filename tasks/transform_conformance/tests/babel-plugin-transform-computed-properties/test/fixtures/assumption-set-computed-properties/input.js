@@ -0,0 +1,9 @@
+var obj = {
+  a: 1,
+  [foo]: 2,
+  bar: 3,
+  "quoted-key": 4,
+  get [baz]() {
+    return 5;
+  },
+};
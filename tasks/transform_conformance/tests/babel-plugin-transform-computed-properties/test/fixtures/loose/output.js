@@ -0,0 +1,4 @@
+var _obj;
+var obj = (_obj = { a: 1 }, _obj[foo] = 2, _obj.bar = 3, _obj["quoted-key"] = 4, babelHelpers.defineAccessor("get", _obj, baz, function() {
+  return 5;
+}), _obj);
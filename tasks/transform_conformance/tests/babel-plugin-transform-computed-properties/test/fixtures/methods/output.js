@@ -0,0 +1 @@
+var obj = babelHelpers.defineProperty(babelHelpers.defineProperty(babelHelpers.defineProperty({}, foo, function() {}), bar, async function* () {}), "baz", function() {});
@@ -0,0 +1,8 @@
+var obj = {
+  a: 1,
+  ["x" + foo]: "heh",
+  [bar]: "noo",
+  baz: 2,
+  "quoted-key": 3,
+  4: 5,
+};
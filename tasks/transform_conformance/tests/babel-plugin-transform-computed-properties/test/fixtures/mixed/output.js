@@ -0,0 +1 @@
+var obj = babelHelpers.defineProperty(babelHelpers.defineProperty(babelHelpers.defineProperty(babelHelpers.defineProperty(babelHelpers.defineProperty({ a: 1 }, "x" + foo, "heh"), bar, "noo"), "baz", 2), "quoted-key", 3), 4, 5);
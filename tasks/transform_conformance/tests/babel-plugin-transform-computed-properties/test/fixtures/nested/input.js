@@ -0,0 +1 @@
+var obj = { [a]: { [b]: c } };
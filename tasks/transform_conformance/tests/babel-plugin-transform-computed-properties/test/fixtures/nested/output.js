@@ -0,0 +1 @@
+var obj = babelHelpers.defineProperty({}, a, babelHelpers.defineProperty({}, b, c));
@@ -0,0 +1,2 @@
+var obj = { [foo]: bar };
+var noComputed = { foo: bar };
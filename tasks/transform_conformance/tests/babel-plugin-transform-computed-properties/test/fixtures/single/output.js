@@ -0,0 +1,2 @@
+var obj = babelHelpers.defineProperty({}, foo, bar);
+var noComputed = { foo: bar };
@@ -0,0 +1,4 @@
+var obj = babelHelpers.objectSpread2(babelHelpers.defineProperty({
+  a,
+  ...b
+}, c, d), e);
@@ -0,0 +1,5 @@
+var obj = {
+  [foo]() {
+    return super.foo();
+  },
+};
@@ -0,0 +1,3 @@
+var obj = { [foo]() {
+  return super.foo();
+} };
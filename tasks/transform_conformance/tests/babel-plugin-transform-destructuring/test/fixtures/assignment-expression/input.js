@@ -0,0 +1,4 @@
+({ x, y } = foo());
+const z = ({ x, y } = bar);
+[a, b] = [b, a];
+[a, b] = [1, 2];
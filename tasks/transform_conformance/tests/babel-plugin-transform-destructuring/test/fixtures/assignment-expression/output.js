@@ -0,0 +1,5 @@
+var _foo, _bar, _ref;
+_foo = foo(), x = _foo.x, y = _foo.y;
+const z = (_bar = bar, x = _bar.x, y = _bar.y, _bar);
+_ref = [b, a], a = _ref[0], b = _ref[1];
+a = 1, b = 2;
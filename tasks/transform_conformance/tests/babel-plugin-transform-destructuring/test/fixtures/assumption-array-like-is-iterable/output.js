@@ -0,0 +1 @@
+var _arr = arr, _arr2 = babelHelpers.maybeArrayLike(babelHelpers.slicedToArray, _arr, 2), a = _arr2[0], b = _arr2[1];
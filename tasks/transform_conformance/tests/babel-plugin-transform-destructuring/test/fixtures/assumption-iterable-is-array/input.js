@@ -0,0 +1,2 @@
+var [a, b, ...c] = arr;
+[x, y] = foo();
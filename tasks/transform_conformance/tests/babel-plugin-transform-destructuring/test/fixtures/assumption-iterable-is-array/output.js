@@ -0,0 +1,3 @@
+var _foo;
+var _arr = arr, a = _arr[0], b = _arr[1], c = _arr.slice(2);
+_foo = foo(), x = _foo[0], y = _foo[1];
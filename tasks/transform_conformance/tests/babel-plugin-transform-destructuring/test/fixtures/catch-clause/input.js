@@ -0,0 +1,3 @@
+try {} catch ({ message }) {
+  console.log(message);
+}
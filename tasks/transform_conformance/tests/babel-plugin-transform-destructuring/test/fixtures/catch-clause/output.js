@@ -0,0 +1,4 @@
+try {} catch (_ref) {
+  let message = _ref.message;
+  console.log(message);
+}
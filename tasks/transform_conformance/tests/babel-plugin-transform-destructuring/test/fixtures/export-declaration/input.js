@@ -0,0 +1,2 @@
+export const { e1, e2 } = obj;
+export let [f1, f2] = arr;
@@ -0,0 +1,4 @@
+const _obj = obj, e1 = _obj.e1, e2 = _obj.e2;
+export { e1, e2 };
+let _arr = arr, _arr2 = babelHelpers.slicedToArray(_arr, 2), f1 = _arr2[0], f2 = _arr2[1];
+export { f1, f2 };
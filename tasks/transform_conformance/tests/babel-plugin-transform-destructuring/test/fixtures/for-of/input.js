@@ -0,0 +1,3 @@
+for (const [k, v] of Object.entries(o)) console.log(k, v);
+for ([k, v] of entries) {}
+for (var { length } in obj) {}
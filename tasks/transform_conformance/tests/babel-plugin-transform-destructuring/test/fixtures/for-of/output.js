@@ -0,0 +1,11 @@
+for (const _ref of Object.entries(o)) {
+  const _ref2 = babelHelpers.slicedToArray(_ref, 2), k = _ref2[0], v = _ref2[1];
+  console.log(k, v);
+}
+for (var _ref3 of entries) {
+  var _ref4;
+  _ref4 = babelHelpers.slicedToArray(_ref3, 2), k = _ref4[0], v = _ref4[1];
+}
+for (var _ref5 in obj) {
+  var length = _ref5.length;
+}
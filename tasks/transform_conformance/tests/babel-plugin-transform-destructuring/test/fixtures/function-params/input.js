@@ -0,0 +1,2 @@
+function f({ a, b } = {}, [c], d = 1) {}
+const g = ({ a }) => a;
@@ -0,0 +1,9 @@
+function f() {
+  var _ref = arguments.length > 0 && arguments[0] !== undefined ? arguments[0] : {}, a = _ref.a, b = _ref.b;
+  var _ref2 = arguments.length > 1 ? arguments[1] : undefined, _ref3 = babelHelpers.slicedToArray(_ref2, 1), c = _ref3[0];
+  var d = arguments.length > 2 && arguments[2] !== undefined ? arguments[2] : 1;
+}
+const g = (_ref4) => {
+  var a = _ref4.a;
+  return a;
+};
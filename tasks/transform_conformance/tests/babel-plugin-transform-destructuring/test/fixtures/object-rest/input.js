@@ -0,0 +1,6 @@
+var { a, ...rest } = obj;
+var { ...copy } = obj;
+var { [key()]: kk, ...others } = obj;
+function f() {
+  var { b, ...inner } = obj;
+}
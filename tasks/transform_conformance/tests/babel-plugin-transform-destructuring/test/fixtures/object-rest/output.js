@@ -0,0 +1,7 @@
+const _excluded = ["b"];
+var _obj = obj, a = _obj.a, rest = babelHelpers.objectWithoutProperties(_obj, ["a"]);
+var _obj2 = obj, copy = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_obj2), _obj2));
+var _obj3 = obj, _key = key(), kk = _obj3[_key], others = babelHelpers.objectWithoutProperties(_obj3, [_key].map(babelHelpers.toPropertyKey));
+function f() {
+  var _obj4 = obj, b = _obj4.b, inner = babelHelpers.objectWithoutProperties(_obj4, _excluded);
+}
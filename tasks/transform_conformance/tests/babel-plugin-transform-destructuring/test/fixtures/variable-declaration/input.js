@@ -0,0 +1,4 @@
+var { a, b: [c, d = 1] } = obj;
+var { x, y } = foo();
+let [p, , q, ...r] = arr;
+const [m = 0, n] = [1, 2];
@@ -0,0 +1,4 @@
+var _obj = obj, a = _obj.a, _obj$b = babelHelpers.slicedToArray(_obj.b, 2), c = _obj$b[0], _obj$b$ = _obj$b[1], d = _obj$b$ === void 0 ? 1 : _obj$b$;
+var _foo = foo(), x = _foo.x, y = _foo.y;
+let _arr = arr, _arr2 = babelHelpers.toArray(_arr), p = _arr2[0], q = _arr2[2], r = _arr2.slice(3);
+const _ = 1, m = _ === void 0 ? 0 : _, n = 2;
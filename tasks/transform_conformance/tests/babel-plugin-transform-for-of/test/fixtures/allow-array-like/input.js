@@ -0,0 +1,3 @@
+for (const x of document.querySelectorAll("a")) {
+  foo(x);
+}
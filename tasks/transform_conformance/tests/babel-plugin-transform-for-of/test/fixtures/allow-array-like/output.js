@@ -0,0 +1,11 @@
+var _iterator = babelHelpers.createForOfIteratorHelper(document.querySelectorAll("a"), true), _step;
+try {
+  for (_iterator.s(); !(_step = _iterator.n()).done;) {
+    const x = _step.value;
+    foo(x);
+  }
+} catch (err) {
+  _iterator.e(err);
+} finally {
+  _iterator.f();
+}
@@ -0,0 +1,5 @@
+for (const x of [1, 2, 3]) {
+  foo(x);
+}
+
+for (let x of []) foo(x);
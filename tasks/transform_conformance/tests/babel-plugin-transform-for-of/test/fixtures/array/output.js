@@ -0,0 +1,12 @@
+for (var _i = 0, _arr = [
+  1,
+  2,
+  3
+]; _i < _arr.length; _i++) {
+  const x = _arr[_i];
+  foo(x);
+}
+for (var _i2 = 0, _arr2 = []; _i2 < _arr2.length; _i2++) {
+  let x = _arr2[_i2];
+  foo(x);
+}
@@ -0,0 +1,17 @@
+const arr = getArray();
+for (const x of arr) {
+  foo(x);
+}
+
+let mutated = getArray();
+for (const x of mutated) {
+  mutated = [];
+}
+
+for (const x of arr) {
+  let arr = 1;
+}
+
+for (const x of unbound) foo(x);
+
+for (const x of obj.arr) foo(x);
@@ -0,0 +1,22 @@
+const arr = getArray();
+for (var _i = 0; _i < arr.length; _i++) {
+  const x = arr[_i];
+  foo(x);
+}
+let mutated = getArray();
+for (var _i2 = 0, _mutated = mutated; _i2 < _mutated.length; _i2++) {
+  const x = _mutated[_i2];
+  mutated = [];
+}
+for (var _i3 = 0, _arr = arr; _i3 < _arr.length; _i3++) {
+  const x = _arr[_i3];
+  let arr = 1;
+}
+for (var _i4 = 0, _unbound = unbound; _i4 < _unbound.length; _i4++) {
+  const x = _unbound[_i4];
+  foo(x);
+}
+for (var _i5 = 0, _arr2 = obj.arr; _i5 < _arr2.length; _i5++) {
+  const x = _arr2[_i5];
+  foo(x);
+}
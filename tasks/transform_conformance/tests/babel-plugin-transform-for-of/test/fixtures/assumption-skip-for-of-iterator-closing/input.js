@@ -0,0 +1,7 @@
+for (const x of y) {
+  foo(x);
+}
+
+outer: for (z of y) {
+  continue outer;
+}
@@ -0,0 +1,8 @@
+for (var _iterator = babelHelpers.createForOfIteratorHelperLoose(y), _step; !(_step = _iterator()).done;) {
+  const x = _step.value;
+  foo(x);
+}
+outer: for (var _iterator2 = babelHelpers.createForOfIteratorHelperLoose(y), _step2; !(_step2 = _iterator2()).done;) {
+  z = _step2.value;
+  continue outer;
+}
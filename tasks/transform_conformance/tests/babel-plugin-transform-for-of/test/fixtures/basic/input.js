@@ -0,0 +1,17 @@
+for (const x of y) {
+  foo(x);
+}
+
+for (var [a, b] of entries) {
+  foo(a, b);
+}
+
+for (z of y) foo(z);
+
+for (obj.prop of y);
+
+function f() {
+  for (let x of y) {
+    if (x) return x;
+  }
+}
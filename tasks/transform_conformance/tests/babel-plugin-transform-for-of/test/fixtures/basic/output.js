@@ -0,0 +1,56 @@
+var _iterator = babelHelpers.createForOfIteratorHelper(y), _step;
+try {
+  for (_iterator.s(); !(_step = _iterator.n()).done;) {
+    const x = _step.value;
+    foo(x);
+  }
+} catch (err) {
+  _iterator.e(err);
+} finally {
+  _iterator.f();
+}
+var _iterator2 = babelHelpers.createForOfIteratorHelper(entries), _step2;
+try {
+  for (_iterator2.s(); !(_step2 = _iterator2.n()).done;) {
+    var [a, b] = _step2.value;
+    foo(a, b);
+  }
+} catch (err) {
+  _iterator2.e(err);
+} finally {
+  _iterator2.f();
+}
+var _iterator3 = babelHelpers.createForOfIteratorHelper(y), _step3;
+try {
+  for (_iterator3.s(); !(_step3 = _iterator3.n()).done;) {
+    z = _step3.value;
+    foo(z);
+  }
+} catch (err) {
+  _iterator3.e(err);
+} finally {
+  _iterator3.f();
+}
+var _iterator4 = babelHelpers.createForOfIteratorHelper(y), _step4;
+try {
+  for (_iterator4.s(); !(_step4 = _iterator4.n()).done;) {
+    obj.prop = _step4.value;
+  }
+} catch (err) {
+  _iterator4.e(err);
+} finally {
+  _iterator4.f();
+}
+function f() {
+  var _iterator5 = babelHelpers.createForOfIteratorHelper(y), _step5;
+  try {
+    for (_iterator5.s(); !(_step5 = _iterator5.n()).done;) {
+      let x = _step5.value;
+      if (x) return x;
+    }
+  } catch (err) {
+    _iterator5.e(err);
+  } finally {
+    _iterator5.f();
+  }
+}
@@ -0,0 +1,8 @@
+for (const x of y) {
+  fns.push(() => x);
+}
+
+for (let [a, b] of y) {
+  let c = a + b;
+  foo(c);
+}
@@ -0,0 +1,26 @@
+var _loop = function(x) {
+  fns.push(() => x);
+};
+var _iterator = babelHelpers.createForOfIteratorHelper(y), _step;
+try {
+  for (_iterator.s(); !(_step = _iterator.n()).done;) {
+    var x = _step.value;
+    _loop(x);
+  }
+} catch (err) {
+  _iterator.e(err);
+} finally {
+  _iterator.f();
+}
+var _iterator2 = babelHelpers.createForOfIteratorHelper(y), _step2;
+try {
+  for (_iterator2.s(); !(_step2 = _iterator2.n()).done;) {
+    var [a, b] = _step2.value;
+    var c = a + b;
+    foo(c);
+  }
+} catch (err) {
+  _iterator2.e(err);
+} finally {
+  _iterator2.f();
+}
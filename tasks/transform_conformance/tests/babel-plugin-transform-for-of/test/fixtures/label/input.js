@@ -0,0 +1,10 @@
+outer: for (const x of y) {
+  for (const z of x) {
+    if (z) continue outer;
+    break outer;
+  }
+}
+
+arr: for (const x of [1, 2]) {
+  if (x) continue arr;
+}
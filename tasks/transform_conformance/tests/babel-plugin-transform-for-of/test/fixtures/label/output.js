@@ -0,0 +1,26 @@
+var _iterator2 = babelHelpers.createForOfIteratorHelper(y), _step2;
+try {
+  outer: for (_iterator2.s(); !(_step2 = _iterator2.n()).done;) {
+    const x = _step2.value;
+    var _iterator = babelHelpers.createForOfIteratorHelper(x), _step;
+    try {
+      for (_iterator.s(); !(_step = _iterator.n()).done;) {
+        const z = _step.value;
+        if (z) continue outer;
+        break outer;
+      }
+    } catch (err) {
+      _iterator.e(err);
+    } finally {
+      _iterator.f();
+    }
+  }
+} catch (err) {
+  _iterator2.e(err);
+} finally {
+  _iterator2.f();
+}
+arr: for (var _i = 0, _arr = [1, 2]; _i < _arr.length; _i++) {
+  const x = _arr[_i];
+  if (x) continue arr;
+}
@@ -0,0 +1,4 @@
+if (a) for (const x of y) foo(x);
+else for (const x of z) bar(x);
+
+while (a) for (const x of y) foo(x);
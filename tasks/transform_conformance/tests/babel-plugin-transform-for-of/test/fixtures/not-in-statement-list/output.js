@@ -0,0 +1,38 @@
+if (a) {
+  var _iterator = babelHelpers.createForOfIteratorHelper(y), _step;
+  try {
+    for (_iterator.s(); !(_step = _iterator.n()).done;) {
+      const x = _step.value;
+      foo(x);
+    }
+  } catch (err) {
+    _iterator.e(err);
+  } finally {
+    _iterator.f();
+  }
+} else {
+  var _iterator2 = babelHelpers.createForOfIteratorHelper(z), _step2;
+  try {
+    for (_iterator2.s(); !(_step2 = _iterator2.n()).done;) {
+      const x = _step2.value;
+      bar(x);
+    }
+  } catch (err) {
+    _iterator2.e(err);
+  } finally {
+    _iterator2.f();
+  }
+}
+while (a) {
+  var _iterator3 = babelHelpers.createForOfIteratorHelper(y), _step3;
+  try {
+    for (_iterator3.s(); !(_step3 = _iterator3.n()).done;) {
+      const x = _step3.value;
+      foo(x);
+    }
+  } catch (err) {
+    _iterator3.e(err);
+  } finally {
+    _iterator3.f();
+  }
+}
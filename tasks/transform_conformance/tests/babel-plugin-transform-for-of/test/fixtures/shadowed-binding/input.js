@@ -0,0 +1,8 @@
+for (const x of y) {
+  let x = 1;
+  foo(x);
+}
+
+for (const x of y) {
+  const z = () => x;
+}
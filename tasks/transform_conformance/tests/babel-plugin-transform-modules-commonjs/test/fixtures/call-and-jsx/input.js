@@ -0,0 +1,9 @@
+import React from "react";
+import { Foo, fn, tag } from "foo";
+import * as ns from "ns";
+
+fn();
+fn?.();
+tag`x`;
+ns.fn();
+<Foo.Bar><Foo /><ns.Baz /></Foo.Bar>;
@@ -0,0 +1,9 @@
+"use strict";
+var _react = babelHelpers.interopRequireDefault(require("react"));
+var _foo = require("foo");
+var ns = babelHelpers.interopRequireWildcard(require("ns"));
+(0, _foo.fn)();
+(0, _foo.fn)?.();
+(0, _foo.tag)`x`;
+ns.fn();
+<_foo.Foo.Bar><_foo.Foo /><ns.Baz /></_foo.Foo.Bar>;
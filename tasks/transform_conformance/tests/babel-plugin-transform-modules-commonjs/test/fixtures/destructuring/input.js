@@ -0,0 +1,4 @@
+export let a, b;
+[a, b] = [1, 2];
+({ a, c: b = a } = obj);
+const result = ([a] = arr);
@@ -0,0 +1,8 @@
+"use strict";
+var _temp;
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.b = exports.a = void 0;
+let a, b;
+[a, b] = [1, 2], exports.a = a, exports.b = b;
+({a, c: b = a} = obj), exports.a = a, exports.b = b;
+const result = (_temp = [a] = arr, exports.a = a, _temp);
@@ -0,0 +1,7 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.A = void 0;
+exports.default = _default;
+function _default() {}
+class A {}
+exports.A = A;
@@ -0,0 +1,5 @@
+export * from "foo";
+export * from "bar";
+export const a = 1;
+export { b } from "b";
+export default 2;
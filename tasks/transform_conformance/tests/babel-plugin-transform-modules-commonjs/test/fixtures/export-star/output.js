@@ -0,0 +1,41 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+var _exportNames = {
+  a: true,
+  b: true
+};
+exports.a = void 0;
+Object.defineProperty(exports, "b", {
+  enumerable: true,
+  get: function() {
+    return _b.b;
+  }
+});
+exports.default = void 0;
+var _foo = require("foo");
+Object.keys(_foo).forEach(function(key) {
+  if (key === "default" || key === "__esModule") return;
+  if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+  if (key in exports && exports[key] === _foo[key]) return;
+  Object.defineProperty(exports, key, {
+    enumerable: true,
+    get: function() {
+      return _foo[key];
+    }
+  });
+});
+var _bar = require("bar");
+Object.keys(_bar).forEach(function(key) {
+  if (key === "default" || key === "__esModule") return;
+  if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+  if (key in exports && exports[key] === _bar[key]) return;
+  Object.defineProperty(exports, key, {
+    enumerable: true,
+    get: function() {
+      return _bar[key];
+    }
+  });
+});
+var _b = require("b");
+const a = exports.a = 1;
+exports.default = 2;
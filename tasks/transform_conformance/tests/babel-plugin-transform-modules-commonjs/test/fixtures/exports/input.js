@@ -0,0 +1,9 @@
+export var a = 1, b;
+export let c = 2;
+export const { d, e: [f] } = obj;
+export function g() {}
+export class H {}
+export async function* i() {}
+
+const j = 3;
+export { j, j as k, j as "l-m" };
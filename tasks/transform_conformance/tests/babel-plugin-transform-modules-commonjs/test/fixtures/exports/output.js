@@ -0,0 +1,16 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.f = exports.d = exports.c = exports.b = exports.a = exports.H = void 0;
+exports.g = g;
+exports.i = i;
+exports["l-m"] = exports.k = exports.j = void 0;
+var a = exports.a = 1, b;
+let c = exports.c = 2;
+const { d, e: [f] } = obj;
+exports.d = d;
+exports.f = f;
+function g() {}
+class H {}
+exports.H = H;
+async function* i() {}
+const j = exports["l-m"] = exports.k = exports.j = 3;
@@ -0,0 +1,19 @@
+import foo, { bar } from "foo";
+
+export let counter, a, b;
+
+for (counter of [1, 2]) {
+  console.log(counter);
+}
+
+for (counter in { x: 1 }) console.log(counter);
+
+for ([a, b] of [[1, 2]]);
+
+for (foo of [1]) {}
+for ([a, bar] of [[1, 2]]) {}
+
+foo = 1;
+[bar] = [1];
+({ bar = 1 } = {});
+bar++;
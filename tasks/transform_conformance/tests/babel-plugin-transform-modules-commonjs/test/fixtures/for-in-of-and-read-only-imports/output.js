@@ -0,0 +1,27 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.counter = exports.b = exports.a = void 0;
+var _foo = babelHelpers.interopRequireWildcard(require("foo"));
+let counter, a, b;
+for (let _counter of [1, 2]) {
+  exports.counter = counter = _counter;
+  console.log(counter);
+}
+for (let _counter2 in { x: 1 }) {
+  exports.counter = counter = _counter2;
+  console.log(counter);
+}
+for (let _ref of [[1, 2]]) {
+  [a, b] = _ref, exports.a = a, exports.b = b;
+}
+for (let _foo2 of [1]) {
+  babelHelpers.readOnlyError("foo");
+}
+for (let _ref2 of [[1, 2]]) {
+  babelHelpers.readOnlyError("bar");
+  [a, _foo.bar] = (_ref2, babelHelpers.readOnlyError("bar"));
+}
+_foo.default = (1, babelHelpers.readOnlyError("foo"));
+[_foo.bar] = ([1], babelHelpers.readOnlyError("bar"));
+({bar: _foo.bar = 1} = ({}, babelHelpers.readOnlyError("bar")));
+_foo.bar += babelHelpers.readOnlyError("bar");
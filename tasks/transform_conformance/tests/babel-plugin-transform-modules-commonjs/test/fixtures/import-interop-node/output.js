@@ -0,0 +1,6 @@
+"use strict";
+var _foo = require("foo");
+var _bar = require("bar");
+var _baz = babelHelpers.interopRequireWildcard(require("baz"), true);
+var ns = babelHelpers.interopRequireWildcard(require("ns"), true);
+_foo(_bar.bar, _baz.default, _baz.qux, ns);
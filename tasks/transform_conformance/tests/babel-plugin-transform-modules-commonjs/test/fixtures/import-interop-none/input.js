@@ -0,0 +1,6 @@
+import foo from "foo";
+import { bar } from "bar";
+import baz, { qux } from "baz";
+import * as ns from "ns";
+
+foo(bar, baz, qux, ns);
@@ -0,0 +1,6 @@
+"use strict";
+var _foo = require("foo");
+var _bar = require("bar");
+var _baz = require("baz");
+var ns = require("ns");
+(0, _foo.default)(_bar.bar, _baz.default, _baz.qux, ns);
@@ -0,0 +1,12 @@
+import foo from "foo";
+import { bar, baz as qux } from "bar";
+import * as ns from "ns";
+import def, { named } from "mixed";
+import "./side-effect";
+
+foo;
+bar + qux;
+ns.x;
+def(named);
+const obj = { bar, qux };
+typeof foo;
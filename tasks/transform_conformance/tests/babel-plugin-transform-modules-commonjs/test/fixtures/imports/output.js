@@ -0,0 +1,15 @@
+"use strict";
+var _foo = babelHelpers.interopRequireDefault(require("foo"));
+var _bar = require("bar");
+var ns = babelHelpers.interopRequireWildcard(require("ns"));
+var _mixed = babelHelpers.interopRequireWildcard(require("mixed"));
+require("./side-effect");
+_foo.default;
+_bar.bar + _bar.baz;
+ns.x;
+(0, _mixed.default)(_mixed.named);
+const obj = {
+  bar: _bar.bar,
+  qux: _bar.baz
+};
+typeof _foo.default;
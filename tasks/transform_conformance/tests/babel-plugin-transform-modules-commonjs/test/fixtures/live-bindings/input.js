@@ -0,0 +1,14 @@
+export let count = 0;
+export let other;
+
+export function increment() {
+  count++;
+  ++count;
+  count += 1;
+  other = count = 5;
+  return count++;
+}
+
+function shadow(count) {
+  count = 1;
+}
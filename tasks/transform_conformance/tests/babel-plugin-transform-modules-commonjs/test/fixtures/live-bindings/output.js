@@ -0,0 +1,18 @@
+"use strict";
+var _count;
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.count = void 0;
+exports.increment = increment;
+exports.other = void 0;
+let count = exports.count = 0;
+let other;
+function increment() {
+  exports.count = ++count;
+  exports.count = ++count;
+  exports.count = count += 1;
+  exports.other = other = exports.count = count = 5;
+  return _count = count++, exports.count = count, _count;
+}
+function shadow(count) {
+  count = 1;
+}
@@ -0,0 +1,6 @@
+export { foo, bar as baz } from "foo";
+export { default } from "default";
+export { default as named, other } from "mixed";
+export * as ns from "ns";
+import { imported } from "imported";
+export { imported, imported as alias };
@@ -0,0 +1,55 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+Object.defineProperty(exports, "alias", {
+  enumerable: true,
+  get: function() {
+    return _imported.imported;
+  }
+});
+Object.defineProperty(exports, "baz", {
+  enumerable: true,
+  get: function() {
+    return _foo.bar;
+  }
+});
+Object.defineProperty(exports, "default", {
+  enumerable: true,
+  get: function() {
+    return _default.default;
+  }
+});
+Object.defineProperty(exports, "foo", {
+  enumerable: true,
+  get: function() {
+    return _foo.foo;
+  }
+});
+Object.defineProperty(exports, "imported", {
+  enumerable: true,
+  get: function() {
+    return _imported.imported;
+  }
+});
+Object.defineProperty(exports, "named", {
+  enumerable: true,
+  get: function() {
+    return _mixed.default;
+  }
+});
+Object.defineProperty(exports, "ns", {
+  enumerable: true,
+  get: function() {
+    return _ns;
+  }
+});
+Object.defineProperty(exports, "other", {
+  enumerable: true,
+  get: function() {
+    return _mixed.other;
+  }
+});
+var _foo = require("foo");
+var _default = babelHelpers.interopRequireDefault(require("default"));
+var _mixed = babelHelpers.interopRequireWildcard(require("mixed"));
+var _ns = babelHelpers.interopRequireWildcard(require("ns"));
+var _imported = require("imported");
@@ -0,0 +1,2 @@
+import "foo";
+export {} from "bar";
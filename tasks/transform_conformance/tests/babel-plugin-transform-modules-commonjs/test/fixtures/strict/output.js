@@ -0,0 +1,3 @@
+exports.bar = void 0;
+var _foo = babelHelpers.interopRequireDefault(require("foo"));
+const bar = exports.bar = _foo.default;
@@ -0,0 +1,12 @@
+export const self = this;
+const arrow = () => this.foo;
+function fn() {
+  return this;
+}
+class A {
+  [this.key] = this;
+  method() {
+    return this;
+  }
+}
+this?.bar();
@@ -0,0 +1,15 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.self = void 0;
+const self = exports.self = void 0;
+const arrow = () => (void 0).foo;
+function fn() {
+  return this;
+}
+class A {
+  [(void 0).key] = this;
+  method() {
+    return this;
+  }
+}
+(void 0)?.bar();
@@ -0,0 +1,5 @@
+var obj = {
+  a() {
+    return () => super.a(this);
+  },
+};
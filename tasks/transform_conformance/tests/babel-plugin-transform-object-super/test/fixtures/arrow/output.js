@@ -0,0 +1,7 @@
+var _obj;
+var obj = _obj = { a() {
+  var _this = this;
+  return function() {
+    return babelHelpers.get(babelHelpers.getPrototypeOf(_obj), "a", _this).call(_this, _this);
+  };
+} };
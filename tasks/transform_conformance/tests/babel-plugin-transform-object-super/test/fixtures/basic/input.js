@@ -0,0 +1,32 @@
+var obj = {
+  a() {
+    return super.a(1, 2);
+  },
+  get b() {
+    return super.b + super["c"];
+  },
+  set b(v) {
+    super.b = v;
+    super[key] += v;
+    super.c ||= v;
+  },
+  d() {
+    super.d++;
+    ++super[key];
+  },
+  e() {
+    return function () {
+      return this;
+    };
+  },
+  f() {
+    return { g() { return super.g(); } };
+  },
+  h() {
+    return class extends super.h {
+      i() {
+        return super.i();
+      }
+    };
+  },
+};
@@ -0,0 +1,38 @@
+var _obj;
+var obj = _obj = {
+  a() {
+    return babelHelpers.get(babelHelpers.getPrototypeOf(_obj), "a", this).call(this, 1, 2);
+  },
+  get b() {
+    return babelHelpers.get(babelHelpers.getPrototypeOf(_obj), "b", this) + babelHelpers.get(babelHelpers.getPrototypeOf(_obj), "c", this);
+  },
+  set b(v) {
+    var _key;
+    babelHelpers.set(babelHelpers.getPrototypeOf(_obj), "b", v, this, false);
+    babelHelpers.set(babelHelpers.getPrototypeOf(_obj), _key = key, babelHelpers.get(babelHelpers.getPrototypeOf(_obj), _key, this) + v, this, false);
+    babelHelpers.get(babelHelpers.getPrototypeOf(_obj), "c", this) || babelHelpers.set(babelHelpers.getPrototypeOf(_obj), "c", v, this, false);
+  },
+  d() {
+    var _super$prop, _super$prop2, _key2, _super$prop3;
+    babelHelpers.set(babelHelpers.getPrototypeOf(_obj), "d", (_super$prop = babelHelpers.get(babelHelpers.getPrototypeOf(_obj), "d", this), _super$prop2 = _super$prop++, _super$prop), this, false), _super$prop2;
+    babelHelpers.set(babelHelpers.getPrototypeOf(_obj), _key2 = key, (_super$prop3 = babelHelpers.get(babelHelpers.getPrototypeOf(_obj), _key2, this), ++_super$prop3), this, false);
+  },
+  e() {
+    return function() {
+      return this;
+    };
+  },
+  f() {
+    var _obj2;
+    return _obj2 = { g() {
+      return babelHelpers.get(babelHelpers.getPrototypeOf(_obj2), "g", this).call(this);
+    } };
+  },
+  h() {
+    return class extends babelHelpers.get(babelHelpers.getPrototypeOf(_obj), "h", this) {
+      i() {
+        return super.i();
+      }
+    };
+  }
+};
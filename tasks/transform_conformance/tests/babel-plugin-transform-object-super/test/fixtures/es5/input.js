@@ -0,0 +1,7 @@
+var obj = {
+  a,
+  b() {
+    return super.b();
+  },
+  [c]: 1,
+};
@@ -0,0 +1,7 @@
+var _obj;
+var obj = _obj = babelHelpers.defineProperty({
+  a: a,
+  b: function() {
+    return babelHelpers.get(babelHelpers.getPrototypeOf(_obj), "b", this).call(this);
+  }
+}, c, 1);
@@ -0,0 +1,4 @@
+function f(a, b = 1, c = a + b) {
+  return a + b + c;
+}
+const g = (x = {}) => x;
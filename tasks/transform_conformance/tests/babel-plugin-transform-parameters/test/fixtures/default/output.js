@@ -0,0 +1,9 @@
+function f(a) {
+  var b = arguments.length > 1 && arguments[1] !== undefined ? arguments[1] : 1;
+  var c = arguments.length > 2 && arguments[2] !== undefined ? arguments[2] : a + b;
+  return a + b + c;
+}
+const g = (x) => {
+  if (x === void 0) x = {};
+  return x;
+};
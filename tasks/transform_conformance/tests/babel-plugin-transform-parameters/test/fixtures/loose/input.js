@@ -0,0 +1,3 @@
+function f(a, b = 1, ...rest) {
+  return a + b + rest.length;
+}
@@ -0,0 +1,7 @@
+function f(a, b) {
+  if (b === void 0) b = 1;
+  for (var _len = arguments.length, rest = new Array(_len > 2 ? _len - 2 : 0), _key = 2; _key < _len; _key++) {
+    rest[_key - 2] = arguments[_key];
+  }
+  return a + b + rest.length;
+}
@@ -0,0 +1,6 @@
+function f(a, ...rest) {
+  return rest;
+}
+function g(...args) {
+  return args.length;
+}
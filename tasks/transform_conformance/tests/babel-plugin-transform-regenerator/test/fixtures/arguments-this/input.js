@@ -0,0 +1,6 @@
+function* gen() {
+  yield arguments[0];
+  yield this.value;
+  const fn = () => arguments.length + this.count;
+  yield fn();
+}
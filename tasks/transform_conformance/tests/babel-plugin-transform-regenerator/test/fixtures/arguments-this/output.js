@@ -0,0 +1,20 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen() {
+  var fn, _args = arguments;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.next = 2;
+        return _args[0];
+      case 2:
+        _context.next = 4;
+        return this.value;
+      case 4:
+        fn = () => _args.length + this.count;
+        _context.next = 7;
+        return fn();
+      case 7:
+      case "end": return _context.stop();
+    }
+  }, _marked, this);
+}
@@ -0,0 +1,7 @@
+async function foo() {
+  const value = await bar();
+  return value;
+}
+const arrow = async () => {
+  await baz();
+};
@@ -0,0 +1,5 @@
+function* gen(x) {
+  const y = yield x;
+  yield;
+  return y + 1;
+}
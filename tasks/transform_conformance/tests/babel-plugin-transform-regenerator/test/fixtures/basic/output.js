@@ -0,0 +1,18 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen(x) {
+  var y;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.next = 2;
+        return x;
+      case 2:
+        y = _context.sent;
+        _context.next = 5;
+        return;
+      case 5: return _context.abrupt("return", y + 1);
+      case 6:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
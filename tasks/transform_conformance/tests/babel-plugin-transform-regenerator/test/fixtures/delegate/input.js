@@ -0,0 +1,5 @@
+function* gen(iterable) {
+  const result = yield* iterable;
+  yield* other();
+  return result;
+}
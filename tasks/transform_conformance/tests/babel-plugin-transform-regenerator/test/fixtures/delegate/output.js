@@ -0,0 +1,15 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen(iterable) {
+  var result;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0: return _context.delegateYield(iterable, "t0", 1);
+      case 1:
+        result = _context.t0;
+        return _context.delegateYield(other(), "t1", 3);
+      case 3: return _context.abrupt("return", result);
+      case 4:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
@@ -0,0 +1,10 @@
+function* gen(obj) {
+  const a = (yield 1) + (yield 2);
+  obj.method(yield 3);
+  const b = yield 4 ? yield 5 : 6;
+  const c = obj.x && (yield 7);
+  obj.count += yield 8;
+  const d = [1, yield 9, ...(yield 10)];
+  const e = { key: yield 11, ...obj };
+  return `${yield 12}-${a}`;
+}
@@ -0,0 +1,85 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen(obj) {
+  var a, b, c, d, e;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.next = 2;
+        return 1;
+      case 2:
+        _context.t0 = _context.sent;
+        _context.next = 5;
+        return 2;
+      case 5:
+        _context.t1 = _context.sent;
+        a = _context.t0 + _context.t1;
+        _context.t2 = obj;
+        _context.next = 10;
+        return 3;
+      case 10:
+        _context.t3 = _context.sent;
+        _context.t2.method.call(_context.t2, _context.t3);
+        if (!4) {
+          _context.next = 18;
+          break;
+        }
+        _context.next = 15;
+        return 5;
+      case 15:
+        _context.t4 = _context.sent;
+        _context.next = 19;
+        break;
+      case 18: _context.t4 = 6;
+      case 19:
+        _context.next = 21;
+        return _context.t4;
+      case 21:
+        b = _context.sent;
+        _context.t5 = obj.x;
+        if (!_context.t5) {
+          _context.next = 27;
+          break;
+        }
+        _context.next = 26;
+        return 7;
+      case 26: _context.t5 = _context.sent;
+      case 27:
+        c = _context.t5;
+        _context.t6 = obj.count;
+        _context.next = 31;
+        return 8;
+      case 31:
+        obj.count = _context.t6 += _context.sent;
+        _context.next = 34;
+        return 9;
+      case 34:
+        _context.t7 = _context.sent;
+        _context.next = 37;
+        return 10;
+      case 37:
+        _context.t8 = _context.sent;
+        d = [
+          1,
+          _context.t7,
+          ..._context.t8
+        ];
+        _context.next = 41;
+        return 11;
+      case 41:
+        _context.t9 = _context.sent;
+        _context.t10 = obj;
+        e = {
+          key: _context.t9,
+          ..._context.t10
+        };
+        _context.next = 46;
+        return 12;
+      case 46:
+        _context.t11 = _context.sent;
+        _context.t12 = a;
+        return _context.abrupt("return", `${_context.t11}-${_context.t12}`);
+      case 49:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
@@ -0,0 +1,22 @@
+const f = function* () {
+  yield 1;
+};
+const g = function* named() {
+  yield named;
+};
+const obj = {
+  *method() {
+    yield 2;
+  },
+};
+class A {
+  *method() {
+    yield this;
+  }
+  static *staticMethod() {
+    yield 3;
+  }
+}
+export default function* () {
+  yield 4;
+}
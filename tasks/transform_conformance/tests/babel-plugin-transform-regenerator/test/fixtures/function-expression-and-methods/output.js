@@ -0,0 +1,69 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(_callee5);
+const f = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(function _callee() {
+  return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.next = 2;
+        return 1;
+      case 2:
+      case "end": return _context.stop();
+    }
+  }, _callee);
+});
+const g = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(function named() {
+  return babelHelpers.regeneratorRuntime().wrap(function named$(_context2) {
+    while (1) switch (_context2.prev = _context2.next) {
+      case 0:
+        _context2.next = 2;
+        return named;
+      case 2:
+      case "end": return _context2.stop();
+    }
+  }, named);
+});
+const obj = { method() {
+  return babelHelpers.regeneratorRuntime().wrap(function _callee2$(_context3) {
+    while (1) switch (_context3.prev = _context3.next) {
+      case 0:
+        _context3.next = 2;
+        return 2;
+      case 2:
+      case "end": return _context3.stop();
+    }
+  });
+} };
+class A {
+  method() {
+    return babelHelpers.regeneratorRuntime().wrap(function _callee3$(_context4) {
+      while (1) switch (_context4.prev = _context4.next) {
+        case 0:
+          _context4.next = 2;
+          return this;
+        case 2:
+        case "end": return _context4.stop();
+      }
+    }, null, this);
+  }
+  static staticMethod() {
+    return babelHelpers.regeneratorRuntime().wrap(function _callee4$(_context5) {
+      while (1) switch (_context5.prev = _context5.next) {
+        case 0:
+          _context5.next = 2;
+          return 3;
+        case 2:
+        case "end": return _context5.stop();
+      }
+    });
+  }
+}
+export default function _callee5() {
+  return babelHelpers.regeneratorRuntime().wrap(function _callee5$(_context6) {
+    while (1) switch (_context6.prev = _context6.next) {
+      case 0:
+        _context6.next = 2;
+        return 4;
+      case 2:
+      case "end": return _context6.stop();
+    }
+  }, _marked);
+}
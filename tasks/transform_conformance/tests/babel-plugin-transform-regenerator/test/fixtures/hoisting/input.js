@@ -0,0 +1,12 @@
+function* gen() {
+  var a = 1;
+  let b = 2;
+  yield a + b;
+  function helper() {
+    return b;
+  }
+  {
+    const b = 3;
+    yield b + helper();
+  }
+}
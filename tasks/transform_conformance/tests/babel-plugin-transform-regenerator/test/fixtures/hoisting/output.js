@@ -0,0 +1,22 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen() {
+  var a, b, _b;
+  function helper() {
+    return b;
+  }
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        a = 1;
+        b = 2;
+        _context.next = 4;
+        return a + b;
+      case 4:
+        _b = 3;
+        _context.next = 7;
+        return _b + helper();
+      case 7:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
@@ -0,0 +1,20 @@
+function* gen(items) {
+  for (let i = 0; i < items.length; i++) {
+    if (items[i] === null) continue;
+    if (items[i] === undefined) break;
+    yield items[i];
+  }
+  let n = 0;
+  while (n < 3) {
+    yield n++;
+  }
+  do {
+    n--;
+  } while (yield n);
+  outer: for (const key in items) {
+    for (;;) {
+      if (yield key) break outer;
+      continue outer;
+    }
+  }
+}
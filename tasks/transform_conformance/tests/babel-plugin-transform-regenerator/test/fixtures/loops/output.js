@@ -0,0 +1,76 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen(items) {
+  var i, n, key;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0: i = 0;
+      case 1:
+        if (!(i < items.length)) {
+          _context.next = 11;
+          break;
+        }
+        if (!(items[i] === null)) {
+          _context.next = 4;
+          break;
+        }
+        return _context.abrupt("continue", 8);
+      case 4:
+        if (!(items[i] === undefined)) {
+          _context.next = 6;
+          break;
+        }
+        return _context.abrupt("break", 11);
+      case 6:
+        _context.next = 8;
+        return items[i];
+      case 8:
+        i++;
+        _context.next = 1;
+        break;
+      case 11: n = 0;
+      case 12:
+        if (!(n < 3)) {
+          _context.next = 17;
+          break;
+        }
+        _context.next = 15;
+        return n++;
+      case 15:
+        _context.next = 12;
+        break;
+      case 17: n--;
+      case 18:
+        _context.next = 20;
+        return n;
+      case 20: if (_context.sent) {
+        _context.next = 17;
+        break;
+      }
+      case 21: _context.t0 = babelHelpers.regeneratorRuntime().keys(items);
+      case 22:
+        if ((_context.t1 = _context.t0()).done) {
+          _context.next = 33;
+          break;
+        }
+        key = _context.t1.value;
+      case 24:
+        _context.next = 26;
+        return key;
+      case 26:
+        if (!_context.sent) {
+          _context.next = 28;
+          break;
+        }
+        return _context.abrupt("break", 33);
+      case 28: return _context.abrupt("continue", 22);
+      case 29:
+        _context.next = 24;
+        break;
+      case 31:
+        _context.next = 22;
+        break;
+      case 33:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
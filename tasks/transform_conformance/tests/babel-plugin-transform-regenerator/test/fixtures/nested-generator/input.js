@@ -0,0 +1,6 @@
+function* outer() {
+  function* inner() {
+    yield 1;
+  }
+  yield* inner();
+}
@@ -0,0 +1,19 @@
+function* gen() {
+  try {
+    yield 1;
+  } catch (err) {
+    yield err;
+  } finally {
+    cleanup();
+  }
+  try {
+    yield 2;
+  } catch {
+    log();
+  }
+  try {
+    yield 3;
+  } finally {
+    yield 4;
+  }
+}
@@ -0,0 +1,59 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen() {
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.prev = 0;
+        _context.next = 3;
+        return 1;
+      case 3:
+        _context.next = 9;
+        break;
+      case 5:
+        _context.prev = 5;
+        _context.t0 = _context["catch"](0);
+        _context.next = 9;
+        return _context.t0;
+      case 9:
+        _context.prev = 9;
+        cleanup();
+        return _context.finish(9);
+      case 12:
+        _context.prev = 12;
+        _context.next = 15;
+        return 2;
+      case 15:
+        _context.next = 20;
+        break;
+      case 17:
+        _context.prev = 17;
+        _context["catch"](12);
+        log();
+      case 20:
+        _context.prev = 20;
+        _context.next = 23;
+        return 3;
+      case 23:
+        _context.prev = 23;
+        _context.next = 26;
+        return 4;
+      case 26: return _context.finish(23);
+      case 27:
+      case "end": return _context.stop();
+    }
+  }, _marked, null, [
+    [
+      0,
+      5,
+      9,
+      12
+    ],
+    [12, 17],
+    [
+      20,
+      ,
+      23,
+      27
+    ]
+  ]);
+}
@@ -0,0 +1,6 @@
+function* gen() {
+  while (true) {
+    yield 1;
+    return;
+  }
+}
@@ -0,0 +1,17 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen() {
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        if (!true) {
+          _context.next = 6;
+          break;
+        }
+        _context.next = 3;
+        return 1;
+      case 3: return _context.abrupt("return");
+      case 6:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
@@ -0,0 +1,6 @@
+function* gen(x) {
+  switch (x) {
+    case yield:
+      break;
+  }
+}
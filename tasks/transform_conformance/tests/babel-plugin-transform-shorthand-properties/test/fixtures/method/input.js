@@ -0,0 +1,13 @@
+var obj = {
+  a() {},
+  async b() {
+    await this.a();
+  },
+  *c() {},
+  async *d() {},
+  ["e"]() {},
+  get f() {
+    return 1;
+  },
+  set f(v) {},
+};
@@ -0,0 +1,13 @@
+var obj = {
+  a: function() {},
+  b: async function() {
+    await this.a();
+  },
+  c: function* () {},
+  d: async function* () {},
+  ["e"]: function() {},
+  get f() {
+    return 1;
+  },
+  set f(v) {}
+};
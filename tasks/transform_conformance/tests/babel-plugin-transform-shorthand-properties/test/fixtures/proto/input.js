@@ -0,0 +1,6 @@
+var a = { __proto__ };
+var b = { __proto__() {} };
+var c = { "__proto__"() {} };
+var d = { __proto__: proto };
+var e = { ["__proto__"]: proto };
+var f = { __proto__: __proto__ };
@@ -0,0 +1,2 @@
+var obj = { a, b, c: c, ...d };
+var { e } = obj;
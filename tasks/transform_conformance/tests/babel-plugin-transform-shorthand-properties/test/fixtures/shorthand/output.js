@@ -0,0 +1,7 @@
+var obj = {
+  a: a,
+  b: b,
+  c: c,
+  ...d
+};
+var { e } = obj;
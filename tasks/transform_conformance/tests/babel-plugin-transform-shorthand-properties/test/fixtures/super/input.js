@@ -0,0 +1,23 @@
+var obj = {
+  a() {
+    return super.a();
+  },
+  b() {
+    return function () {
+      return 1;
+    };
+  },
+  c() {
+    return () => super.c;
+  },
+  d() {
+    return {
+      e() {
+        return super.e;
+      },
+    };
+  },
+  f() {
+    return class extends super.f {};
+  },
+};
@@ -0,0 +1,21 @@
+var obj = {
+  a() {
+    return super.a();
+  },
+  b: function() {
+    return function() {
+      return 1;
+    };
+  },
+  c() {
+    return () => super.c;
+  },
+  d: function() {
+    return { e() {
+      return super.e;
+    } };
+  },
+  f() {
+    return class extends super.f {};
+  }
+};
@@ -0,0 +1,3 @@
+var a = [1, ...b, 2];
+var c = [...d];
+var e = [...arguments];
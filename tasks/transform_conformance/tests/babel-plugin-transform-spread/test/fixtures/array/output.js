@@ -0,0 +1,3 @@
+var a = [1].concat(babelHelpers.toConsumableArray(b), [2]);
+var c = babelHelpers.toConsumableArray(d);
+var e = Array.prototype.slice.call(arguments);
@@ -0,0 +1 @@
+var a = babelHelpers.maybeArrayLike(babelHelpers.toConsumableArray, b);
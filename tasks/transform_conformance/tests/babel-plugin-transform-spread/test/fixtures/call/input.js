@@ -0,0 +1,4 @@
+f(...args);
+f(a, ...args);
+obj.method(...args);
+obj.foo().bar(...args);
@@ -0,0 +1,5 @@
+var _obj, _obj$foo;
+f.apply(void 0, babelHelpers.toConsumableArray(args));
+f.apply(void 0, [a].concat(babelHelpers.toConsumableArray(args)));
+(_obj = obj).method.apply(_obj, babelHelpers.toConsumableArray(args));
+(_obj$foo = obj.foo()).bar.apply(_obj$foo, babelHelpers.toConsumableArray(args));
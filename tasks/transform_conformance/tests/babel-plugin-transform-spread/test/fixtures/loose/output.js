@@ -0,0 +1,2 @@
+var a = [1].concat(b, [2]);
+f.apply(void 0, args);
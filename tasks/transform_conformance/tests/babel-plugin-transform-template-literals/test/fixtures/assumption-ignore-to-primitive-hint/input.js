@@ -0,0 +1,4 @@
+var mixed = `foo${bar}baz${qux}`;
+var first = `${a}b`;
+var adjacent = `${a}${b}`;
+tag`foo${bar}\n`;
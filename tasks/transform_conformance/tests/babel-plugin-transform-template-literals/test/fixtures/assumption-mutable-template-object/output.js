@@ -0,0 +1,5 @@
+var _templateObject;
+var mixed = "foo".concat(bar, "baz").concat(qux);
+var first = "".concat(a, "b");
+var adjacent = "".concat(a).concat(b);
+tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteralLoose(["foo", "\n"], ["foo", "\\n"])), bar);
@@ -0,0 +1,8 @@
+var empty = ``;
+var only = `foo`;
+var first = `${a}`;
+var mixed = `foo${bar}baz${qux}`;
+var literals = `${a}${1}${"b"}${c}${null}`;
+var adjacent = `${a}${b}`;
+var emptyString = `foo${""}bar`;
+var escaped = `line\n${a}\u{1F600}`;
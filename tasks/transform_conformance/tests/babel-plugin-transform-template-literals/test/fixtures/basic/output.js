@@ -0,0 +1,8 @@
+var empty = "";
+var only = "foo";
+var first = "".concat(a);
+var mixed = "foo".concat(bar, "baz").concat(qux);
+var literals = "".concat(a, 1, "b").concat(c, null);
+var adjacent = "".concat(a).concat(b);
+var emptyString = "foo".concat("bar");
+var escaped = "line\n".concat(a, "😀");
@@ -0,0 +1,5 @@
+var _templateObject;
+var mixed = "foo" + bar + "baz" + qux;
+var first = a + "b";
+var adjacent = "" + a + b;
+tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteralLoose(["foo", "\n"], ["foo", "\\n"])), bar);
@@ -0,0 +1,2 @@
+var nested = `a${`b${c}`}d`;
+tag`a${`b${c}`}${tag2`d`}`;
@@ -0,0 +1,7 @@
+var _templateObject, _templateObject2;
+var nested = "a".concat("b".concat(c), "d");
+tag(_templateObject2 || (_templateObject2 = babelHelpers.taggedTemplateLiteral([
+  "a",
+  "",
+  ""
+])), "b".concat(c), tag2(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["d"]))));
@@ -0,0 +1,8 @@
+tag`foo`;
+tag`foo${bar}baz`;
+tag`line\n${a}`;
+tag`\unicode and \u{55}`;
+obj.tag`foo${bar}`;
+function f() {
+  return tag`in function`;
+}
@@ -0,0 +1,9 @@
+var _templateObject, _templateObject2, _templateObject3, _templateObject4, _templateObject5, _templateObject6;
+tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["foo"])));
+tag(_templateObject2 || (_templateObject2 = babelHelpers.taggedTemplateLiteral(["foo", "baz"])), bar);
+tag(_templateObject3 || (_templateObject3 = babelHelpers.taggedTemplateLiteral(["line\n", ""], ["line\\n", ""])), a);
+tag(_templateObject4 || (_templateObject4 = babelHelpers.taggedTemplateLiteral([void 0], ["\\unicode and \\u{55}"])));
+obj.tag(_templateObject5 || (_templateObject5 = babelHelpers.taggedTemplateLiteral(["foo", ""])), bar);
+function f() {
+  return tag(_templateObject6 || (_templateObject6 = babelHelpers.taggedTemplateLiteral(["in function"])));
+}
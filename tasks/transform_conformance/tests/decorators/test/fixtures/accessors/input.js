@@ -0,0 +1,11 @@
+class Foo {
+  accessor a;
+  accessor #b = 1;
+  static accessor c = 2;
+  @dec accessor d = 3;
+  @dec accessor #e = 4;
+  @dec static accessor f;
+  @dec static accessor #g = 5;
+  @dec accessor [h()] = 6;
+  accessor ["i"] = 7;
+}
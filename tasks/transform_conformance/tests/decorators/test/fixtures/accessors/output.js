@@ -0,0 +1,101 @@
+var _init_d, _init_e, _get_e, _set_e, _init_f, _init_g, _get_g, _set_g, _computedKey, _init_computedKey, _initProto, _initStatic;
+_computedKey = babelHelpers.toPropertyKey(h());
+class Foo {
+  static {
+    [_init_f, _init_g, _get_g, _set_g, _init_d, _init_e, _get_e, _set_e, _init_computedKey, _initProto, _initStatic] = babelHelpers.applyDecs2305(this, [
+      [
+        dec,
+        9,
+        "f"
+      ],
+      [
+        dec,
+        9,
+        "g",
+        (o) => o.#G,
+        (o, v) => o.#G = v
+      ],
+      [
+        dec,
+        1,
+        "d"
+      ],
+      [
+        dec,
+        1,
+        "e",
+        (o) => o.#E,
+        (o, v) => o.#E = v
+      ],
+      [
+        dec,
+        1,
+        _computedKey
+      ]
+    ], []).e;
+    _initStatic(this);
+  }
+  #A = (_initProto(this), void 0);
+  get a() {
+    return this.#A;
+  }
+  set a(v) {
+    this.#A = v;
+  }
+  #B = 1;
+  get #b() {
+    return this.#B;
+  }
+  set #b(v) {
+    this.#B = v;
+  }
+  static #C = 2;
+  static get c() {
+    return this.#C;
+  }
+  static set c(v) {
+    this.#C = v;
+  }
+  #D = _init_d(this, 3);
+  get d() {
+    return this.#D;
+  }
+  set d(v) {
+    this.#D = v;
+  }
+  #E = _init_e(this, 4);
+  get #e() {
+    return _get_e(this);
+  }
+  set #e(v) {
+    _set_e(this, v);
+  }
+  static #F = _init_f(this);
+  static get f() {
+    return this.#F;
+  }
+  static set f(v) {
+    this.#F = v;
+  }
+  static #G = _init_g(this, 5);
+  static get #g() {
+    return _get_g(this);
+  }
+  static set #g(v) {
+    _set_g(this, v);
+  }
+  #H = _init_computedKey(this, 6);
+  get [_computedKey]() {
+    return this.#H;
+  }
+  set [_computedKey](v) {
+    this.#H = v;
+  }
+  #I = 7;
+  get ["i"]() {
+    return this.#I;
+  }
+  set ["i"](v) {
+    this.#I = v;
+  }
+}
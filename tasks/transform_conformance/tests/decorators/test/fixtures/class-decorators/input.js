@@ -0,0 +1,16 @@
+@dec
+class Foo {
+  static self = Foo;
+
+  method() {
+    return Foo;
+  }
+}
+
+const Bar = @dec class {};
+
+const Baz = @dec class Qux {
+  static self = Qux;
+};
+
+foo(@dec class {});
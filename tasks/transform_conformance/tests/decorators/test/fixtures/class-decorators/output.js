@@ -0,0 +1,38 @@
+var _Foo, _initClass, _Bar, _initClass2, _Qux, _initClass3, _Class, _initClass4;
+let Foo = (class Foo {
+  static {
+    [_Foo, _initClass] = babelHelpers.applyDecs2305(this, [], [dec]).c;
+  }
+  static self = _Foo;
+  method() {
+    return _Foo;
+  }
+  static {
+    _initClass();
+  }
+}, _Foo);
+const Bar = (class Bar {
+  static {
+    [_Bar, _initClass2] = babelHelpers.applyDecs2305(this, [], [dec]).c;
+  }
+  static {
+    _initClass2();
+  }
+}, _Bar);
+const Baz = (class Qux {
+  static {
+    [_Qux, _initClass3] = babelHelpers.applyDecs2305(this, [], [dec]).c;
+  }
+  static self = _Qux;
+  static {
+    _initClass3();
+  }
+}, _Qux);
+foo((class {
+  static {
+    [_Class, _initClass4] = babelHelpers.applyDecs2305(this, [], [dec]).c;
+  }
+  static {
+    _initClass4();
+  }
+}, _Class));
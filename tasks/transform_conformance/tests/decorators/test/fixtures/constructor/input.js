@@ -0,0 +1,33 @@
+class A {
+  @dec method() {}
+
+  constructor() {
+    foo();
+  }
+}
+
+class B extends Base {
+  @dec method() {}
+
+  constructor() {
+    const init = () => super();
+    if (condition) {
+      super(1);
+    } else {
+      init();
+    }
+    function inner() {
+      super.foo();
+    }
+  }
+}
+
+class C extends Base {
+  @dec method() {}
+}
+
+class D {
+  @dec method() {}
+  prop = 1;
+  @dec field;
+}
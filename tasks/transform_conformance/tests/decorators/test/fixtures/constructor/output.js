@@ -0,0 +1,66 @@
+var _initProto, _initProto2, _initProto3, _init_field, _initProto4;
+class A {
+  static {
+    [_initProto] = babelHelpers.applyDecs2305(this, [[
+      dec,
+      2,
+      "method"
+    ]], []).e;
+  }
+  method() {}
+  constructor() {
+    _initProto(this);
+    foo();
+  }
+}
+class B extends Base {
+  static {
+    [_initProto2] = babelHelpers.applyDecs2305(this, [[
+      dec,
+      2,
+      "method"
+    ]], [], 0, void 0, Base).e;
+  }
+  method() {}
+  constructor() {
+    const init = () => (super(), _initProto2(this));
+    if (condition) {
+      super(1), _initProto2(this);
+    } else {
+      init();
+    }
+    function inner() {
+      super.foo();
+    }
+  }
+}
+class C extends Base {
+  static {
+    [_initProto3] = babelHelpers.applyDecs2305(this, [[
+      dec,
+      2,
+      "method"
+    ]], [], 0, void 0, Base).e;
+  }
+  constructor(..._args) {
+    super(..._args);
+    _initProto3(this);
+  }
+  method() {}
+}
+class D {
+  static {
+    [_init_field, _initProto4] = babelHelpers.applyDecs2305(this, [[
+      dec,
+      2,
+      "method"
+    ], [
+      dec,
+      0,
+      "field"
+    ]], []).e;
+  }
+  method() {}
+  prop = (_initProto4(this), 1);
+  field = _init_field(this);
+}
@@ -0,0 +1,9 @@
+@obj.dec
+@dec()
+class Foo {
+  @obj.dec method() {}
+
+  @obj.nested.dec @dec prop;
+
+  @(obj[key]) @(dec) @(decorators[0]) static accessor value;
+}
@@ -0,0 +1,58 @@
+var _obj, _dec, _dec2, _obj2, _dec3, _obj$nested, _dec4, _init_prop, _obj3, _dec5, _decorators, _dec6, _init_value, _initProto, _initStatic, _Foo, _initClass;
+_dec = (_obj = obj).dec;
+_dec2 = dec();
+_dec3 = (_obj2 = obj).dec;
+_dec4 = (_obj$nested = obj.nested).dec;
+_dec5 = (_obj3 = obj)[key];
+_dec6 = (_decorators = decorators)[0];
+let Foo = (class Foo {
+  static {
+    ({e: [_init_value, _init_prop, _initProto, _initStatic], c: [_Foo, _initClass]} = babelHelpers.applyDecs2305(this, [
+      [
+        [
+          _obj3,
+          _dec5,
+          void 0,
+          dec,
+          _decorators,
+          _dec6
+        ],
+        25,
+        "value"
+      ],
+      [
+        [_obj2, _dec3],
+        18,
+        "method"
+      ],
+      [
+        [
+          _obj$nested,
+          _dec4,
+          void 0,
+          dec
+        ],
+        16,
+        "prop"
+      ]
+    ], [
+      _obj,
+      _dec,
+      void 0,
+      _dec2
+    ], 1));
+    _initStatic(this);
+  }
+  method() {}
+  prop = (_initProto(this), _init_prop(this));
+  static #A = _init_value(this);
+  static get value() {
+    return this.#A;
+  }
+  static set value(v) {
+    this.#A = v;
+  }
+  static {
+    _initClass();
+  }
+}, _Foo);
@@ -0,0 +1,4 @@
+@dec export class A { static a = A; @dec m() { return A; } }
+export @dec class B {}
+export default @dec class C { static c = C; }
+new A();
@@ -0,0 +1,39 @@
+var _initProto, _A, _initClass, _B, _initClass2, _C, _initClass3;
+export let A = (class A {
+  static {
+    ({e: [_initProto], c: [_A, _initClass]} = babelHelpers.applyDecs2305(this, [[
+      dec,
+      2,
+      "m"
+    ]], [dec]));
+  }
+  constructor() {
+    _initProto(this);
+  }
+  static a = _A;
+  m() {
+    return _A;
+  }
+  static {
+    _initClass();
+  }
+}, _A);
+export let B = (class B {
+  static {
+    [_B, _initClass2] = babelHelpers.applyDecs2305(this, [], [dec]).c;
+  }
+  static {
+    _initClass2();
+  }
+}, _B);
+let C = (class C {
+  static {
+    [_C, _initClass3] = babelHelpers.applyDecs2305(this, [], [dec]).c;
+  }
+  static c = _C;
+  static {
+    _initClass3();
+  }
+}, _C);
+export default C;
+new A();
@@ -0,0 +1,9 @@
+class Foo {
+  @dec a;
+  @dec b = 1;
+  @dec #c = 2;
+  @dec static d = 3;
+  @dec static #e;
+  @dec ["f"] = 4;
+  @dec [g()] = 5;
+}
@@ -0,0 +1,55 @@
+var _init_a, _init_b, _init_c, _init_d, _init_e, _init_f, _computedKey, _init_computedKey, _initProto, _initStatic;
+_computedKey = babelHelpers.toPropertyKey(g());
+class Foo {
+  static {
+    [_init_d, _init_e, _init_a, _init_b, _init_c, _init_f, _init_computedKey, _initProto, _initStatic] = babelHelpers.applyDecs2305(this, [
+      [
+        dec,
+        8,
+        "d"
+      ],
+      [
+        dec,
+        8,
+        "e",
+        (o) => o.#e,
+        (o, v) => o.#e = v
+      ],
+      [
+        dec,
+        0,
+        "a"
+      ],
+      [
+        dec,
+        0,
+        "b"
+      ],
+      [
+        dec,
+        0,
+        "c",
+        (o) => o.#c,
+        (o, v) => o.#c = v
+      ],
+      [
+        dec,
+        0,
+        "f"
+      ],
+      [
+        dec,
+        0,
+        _computedKey
+      ]
+    ], [], 0, (o) => #c in o).e;
+    _initStatic(this);
+  }
+  a = (_initProto(this), _init_a(this));
+  b = _init_b(this, 1);
+  #c = _init_c(this, 2);
+  static d = _init_d(this, 3);
+  static #e = _init_e(this);
+  ["f"] = _init_f(this, 4);
+  [_computedKey] = _init_computedKey(this, 5);
+}
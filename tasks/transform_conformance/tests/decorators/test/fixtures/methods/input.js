@@ -0,0 +1,10 @@
+class Foo {
+  @dec a() {}
+  @dec #b() { return this; }
+  @dec static c() {}
+  @dec get d() { return 1; }
+  @dec set d(v) {}
+  @dec get #e() { return 1; }
+  @dec set #e(v) {}
+  @dec static #f() {}
+}
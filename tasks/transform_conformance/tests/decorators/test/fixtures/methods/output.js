@@ -0,0 +1,77 @@
+var _call_b, _call_e, _call_e2, _call_f, _initProto, _initStatic;
+class Foo {
+  static {
+    [_call_f, _call_b, _call_e, _call_e2, _initProto, _initStatic] = babelHelpers.applyDecs2305(this, [
+      [
+        dec,
+        10,
+        "c"
+      ],
+      [
+        dec,
+        10,
+        "f",
+        function() {}
+      ],
+      [
+        dec,
+        2,
+        "a"
+      ],
+      [
+        dec,
+        2,
+        "b",
+        function() {
+          return this;
+        }
+      ],
+      [
+        dec,
+        3,
+        "d"
+      ],
+      [
+        dec,
+        4,
+        "d"
+      ],
+      [
+        dec,
+        3,
+        "e",
+        function() {
+          return 1;
+        }
+      ],
+      [
+        dec,
+        4,
+        "e",
+        function(v) {}
+      ]
+    ], [], 0, (o) => #b in o).e;
+    _initStatic(this);
+  }
+  constructor() {
+    _initProto(this);
+  }
+  a() {}
+  get #b() {
+    return _call_b;
+  }
+  static c() {}
+  get d() {
+    return 1;
+  }
+  set d(v) {}
+  get #e() {
+    return _call_e(this);
+  }
+  set #e(v) {
+    _call_e2(this, v);
+  }
+  static get #f() {
+    return _call_f;
+  }
+}
@@ -0,0 +1,7 @@
+class A extends Base {
+  @dec static method() {}
+}
+
+class B extends mixin(Base) {
+  @dec #method() {}
+}
@@ -0,0 +1,29 @@
+var _initStatic, _call_method, _initProto, _super;
+class A extends Base {
+  static {
+    [_initStatic] = babelHelpers.applyDecs2305(this, [[
+      dec,
+      10,
+      "method"
+    ]], [], 0, void 0, Base).e;
+    _initStatic(this);
+  }
+  static method() {}
+}
+class B extends (_super = mixin(Base)) {
+  static {
+    [_call_method, _initProto] = babelHelpers.applyDecs2305(this, [[
+      dec,
+      2,
+      "method",
+      function() {}
+    ]], [], 0, (o) => #method in o, _super).e;
+  }
+  constructor(..._args) {
+    super(..._args);
+    _initProto(this);
+  }
+  get #method() {
+    return _call_method;
+  }
+}
@@ -0,0 +1,8 @@
+abstract class Foo<T> extends Base {
+  declare x: number;
+  abstract y: string;
+  @dec private z: T = 1 as any;
+  @dec accessor w: number = 1;
+  constructor(public a: number) { super(); }
+  @dec method(p: T): void {}
+}
@@ -0,0 +1,35 @@
+var _init_z, _init_w, _initProto;
+class Foo extends Base {
+  static {
+    [_init_w, _init_z, _initProto] = babelHelpers.applyDecs2305(this, [
+      [
+        dec,
+        1,
+        "w"
+      ],
+      [
+        dec,
+        2,
+        "method"
+      ],
+      [
+        dec,
+        0,
+        "z"
+      ]
+    ], [], 0, void 0, Base).e;
+  }
+  z = (_initProto(this), _init_z(this, 1));
+  #A = _init_w(this, 1);
+  get w() {
+    return this.#A;
+  }
+  set w(v) {
+    this.#A = v;
+  }
+  constructor(a) {
+    super();
+    this.a = a;
+  }
+  method(p) {}
+}
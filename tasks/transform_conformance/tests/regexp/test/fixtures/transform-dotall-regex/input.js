@@ -1 +1,2 @@
 a1 = /a.b/s
+a2 = /a.b/su
@@ -1,2 +1,3 @@
 c1 = /(?<a>b)/
 c2 = /((?<a>b)){2}/
+c3 = /(?<year>\d{4})-(?<month>\d{2})\k<month>/
@@ -1,2 +1,6 @@
-c1 = new RegExp("(?<a>b)", "");
-c2 = new RegExp("((?<a>b)){2}", "");
+c1 = babelHelpers.wrapRegExp(/(b)/, { a: 1 });
+c2 = babelHelpers.wrapRegExp(/((b)){2}/, { a: 2 });
+c3 = babelHelpers.wrapRegExp(/(\d{4})-(\d{2})\2/, {
+  year: 1,
+  month: 2
+});
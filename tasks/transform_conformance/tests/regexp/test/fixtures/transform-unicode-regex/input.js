@@ -1 +1,5 @@
 x2 = /./u
+x3 = /😀{2}/u
+x4 = /[^a]/u
+x5 = /[\u{1F600}-\u{1F64F}a-c]/u
+x6 = /ſ/iu
@@ -1 +1,5 @@
-x2 = new RegExp(".", "u");
+x2 = /(?:[\x00-\x09\x0B\x0C\x0E-\u2027\u202A-\uD7FF\uE000-\uFFFF]|[\uD800-\uDBFF][\uDC00-\uDFFF]|[\uD800-\uDBFF](?![\uDC00-\uDFFF])|(?:[^\uD800-\uDBFF]|^)[\uDC00-\uDFFF])/;
+x3 = /(?:\uD83D\uDE00){2}/;
+x4 = /(?:[\x00-`b-\uD7FF\uE000-\uFFFF]|[\uD800-\uDBFF][\uDC00-\uDFFF]|[\uD800-\uDBFF](?![\uDC00-\uDFFF])|(?:[^\uD800-\uDBFF]|^)[\uDC00-\uDFFF])/;
+x5 = /(?:[a-c]|\uD83D[\uDE00-\uDE4F])/;
+x6 = /[Ss\u017F]/i;
@@ -1 +1,3 @@
 g1 = /[\p{White_Space}&&\p{ASCII}]/v
+g2 = /[\q{abc|d}x]/v
+g3 = /[[a-z]--[aeiou]]/v
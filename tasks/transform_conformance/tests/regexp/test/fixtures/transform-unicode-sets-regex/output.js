@@ -1 +1,3 @@
-g1 = new RegExp("[\\p{White_Space}&&\\p{ASCII}]", "v");
+g1 = /[\x09-\x0D ]/u;
+g2 = /(?:abc|[dx])/u;
+g3 = /[b-df-hj-np-tv-z]/u;